@@ -0,0 +1,215 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef BINANCE_FFI_H
+#define BINANCE_FFI_H
+
+#include "binance_ffi_generated.h"
+
+/*
+ * Binance spot and futures executor (Rust)
+ *
+ * Orders, cancels, balances, futures positions, open orders, order updates
+ * and book tickers of Binance spot and USD-M futures, with the conventions
+ * of polymarket_ffi.h: one global session set up by binance_init(),
+ * blocking calls that may be made from any number of host threads,
+ * negative error codes numbered as POLYMARKET_ERR_* where the meaning is
+ * shared, raw amounts with 6 decimals, and thread-local last error / last
+ * response strings. Every call names its market (BINANCE_MARKET_*), so one
+ * API key trades both through the same functions.
+ *
+ * Symbols are written as the venue lists them ("BTCUSDT"). Quantities are
+ * base asset units, rounded down to the symbol's step size; prices are
+ * rounded to its tick size. Raw values keep 6 decimals, so prices of
+ * symbols quoted below 0.000001 do not fit.
+ *
+ * binance_init() and binance_shutdown() must not race with each other.
+ * Blocking calls must not be made from a thread owned by the executor
+ * runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants, structs and callback types are generated from the Rust
+ * definitions, with their documentation, in binance_ffi_generated.h. The
+ * declarations below are checked against the generated ones.
+ */
+
+  /**
+ * Connect to both markets and measure the venue clock. Replaces a session
+ * already set up.
+ *
+ * @param api_key     API key
+ * @param secret_key  Its secret key; the library keeps no copy of the
+ *                    string
+ * @param testnet     Trade on the spot and futures testnets instead
+ * @return BINANCE_OK, BINANCE_ERR_INVALID_KEY, or the error of reaching
+ *         the venue. The key itself is checked by the first signed call
+ *         (BINANCE_ERR_AUTH if refused).
+ */
+  int32_t binance_init(const char* api_key, const char* secret_key, bool testnet);
+
+  /**
+ * Shut the session down and close every stream; binance_init() can be
+ * called again afterwards.
+ */
+  void binance_shutdown(void);
+
+  /**
+ * Market order.
+ *
+ * @param market    BINANCE_MARKET_*
+ * @param symbol    e.g. "BTCUSDT"
+ * @param is_buy    Buy or sell
+ * @param quantity  Base asset units, > 0
+ * @param flags     BINANCE_FLAG_REDUCE_ONLY (futures) or 0
+ * @param out       Result, filled on success and failure
+ * @return BINANCE_OK or negative error code (also in out->error_code)
+ */
+  int32_t binance_market_order(int32_t market, const char* symbol, bool is_buy, double quantity,
+                               uint32_t flags, BinanceOrderResult* out);
+
+  /**
+ * Limit order at price, resting until filled or cancelled unless
+ * BINANCE_FLAG_IOC or BINANCE_FLAG_FOK is set.
+ *
+ * @param flags  BINANCE_FLAG_* combined; POST_ONLY, IOC and FOK exclude
+ *               each other
+ * Other parameters and return as binance_market_order().
+ */
+  int32_t binance_limit_order(int32_t market, const char* symbol, bool is_buy, double quantity,
+                              double price, uint32_t flags, BinanceOrderResult* out);
+
+  /**
+ * Cancel an open order.
+ *
+ * @return BINANCE_OK or negative error code (BINANCE_ERR_REJECTED if the
+ *         order is no longer open)
+ */
+  int32_t binance_cancel(int32_t market, const char* symbol, uint64_t order_id);
+
+  /**
+ * Cancel every open order of a symbol.
+ *
+ * @return BINANCE_OK, also if none was open, or negative error code
+ */
+  int32_t binance_cancel_all(int32_t market, const char* symbol);
+
+  /**
+ * Amount of an asset free to trade on a market: spot free balance,
+ * futures available balance.
+ *
+ * @param asset  e.g. "USDT"
+ * @return Raw amount (6 decimals), 0 if none held, or negative error code
+ */
+  int64_t binance_get_balance(int32_t market, const char* asset);
+
+  /**
+ * Non-zero balances of a market as JSON: [{"asset", "free", "total"}].
+ * total is free + locked on spot, the wallet balance on futures.
+ *
+ * @return Size of the JSON, read with binance_last_response(), or
+ *         negative error code
+ */
+  int64_t binance_get_balances(int32_t market);
+
+  /**
+ * Open futures positions as JSON: [{"symbol", "size", "entry_price",
+ * "mark_price", "unrealized_pnl", "liquidation_price", "leverage"}].
+ * size is signed: positive long, negative short. Same return as
+ * binance_get_balances().
+ */
+  int64_t binance_get_positions(void);
+
+  /**
+ * Open orders of a market as JSON: [{"symbol", "order_id",
+ * "client_order_id", "side", "order_type", "price", "quantity",
+ * "executed_qty", "status", "time"}].
+ *
+ * @param symbol  Orders of this symbol, or of every symbol if NULL
+ * Same return as binance_get_balances().
+ */
+  int64_t binance_get_open_orders(int32_t market, const char* symbol);
+
+  /**
+ * Stream a market's order updates and fills from the user data stream to
+ * callback, replacing the callback set before: one callback serves both
+ * markets. The stream reconnects on its own and closes on shutdown.
+ *
+ * The callback runs on a runtime worker thread, one update at a time; it
+ * must not block or call back into the library.
+ *
+ * @return BINANCE_OK or negative error code
+ */
+  int32_t binance_subscribe_order_updates(int32_t market, BinanceOrderUpdateCallback callback,
+                                          void* user_data);
+
+  /**
+ * Close a market's user data stream. Once this returns for the last market
+ * subscribed, the callback is not invoked again. No-op if not subscribed.
+ */
+  int32_t binance_unsubscribe_order_updates(int32_t market);
+
+  /**
+ * Keep the best bid and ask of a symbol from its book ticker stream. The
+ * first subscription of a market opens its connection; it reconnects on
+ * its own and closes on shutdown.
+ *
+ * @return BINANCE_OK, also if already subscribed
+ */
+  int32_t binance_subscribe_book_ticker(int32_t market, const char* symbol);
+
+  /**
+ * Stop keeping a symbol's ticker; the connection stays open.
+ *
+ * @return BINANCE_OK, BINANCE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t binance_unsubscribe_book_ticker(int32_t market, const char* symbol);
+
+  /**
+ * Best bid and ask of a subscribed symbol.
+ *
+ * @return BINANCE_OK, BINANCE_PENDING until the venue sent the ticker (out
+ *         untouched), BINANCE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t binance_get_book_ticker(int32_t market, const char* symbol, BinanceBookTicker* out);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t binance_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the venue's own code and reason for refused requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t binance_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BINANCE_FFI_H */
@@ -0,0 +1,376 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/binance/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in binance_ffi.h.
+ */
+
+#ifndef BINANCE_FFI_GENERATED_H
+#define BINANCE_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define BINANCE_OK 0
+
+/**
+ * Not an error: no data yet
+ */
+#define BINANCE_PENDING 1
+
+#define BINANCE_ERR_NOT_INITIALIZED -1
+
+/**
+ * Key malformed
+ */
+#define BINANCE_ERR_INVALID_KEY -2
+
+/**
+ * Key refused, or lacks the permission
+ */
+#define BINANCE_ERR_AUTH -3
+
+/**
+ * Not a trading symbol of the market
+ */
+#define BINANCE_ERR_INVALID_SYMBOL -4
+
+#define BINANCE_ERR_ORDER_FAILED -5
+
+#define BINANCE_ERR_CANCEL_FAILED -6
+
+/**
+ * Not enough balance or margin
+ */
+#define BINANCE_ERR_INSUFFICIENT_BALANCE -9
+
+#define BINANCE_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define BINANCE_ERR_PANIC -14
+
+/**
+ * Venue refused the request, see last error
+ */
+#define BINANCE_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define BINANCE_ERR_NETWORK -20
+
+/**
+ * Venue answered 429, or 418 (IP banned)
+ */
+#define BINANCE_ERR_RATE_LIMITED -21
+
+/**
+ * Venue answered 5xx, order state unknown
+ */
+#define BINANCE_ERR_VENUE_ERROR -22
+
+/**
+ * Quantity below the minimum, or price not valid
+ */
+#define BINANCE_ERR_INVALID_ORDER -23
+
+#define BINANCE_MARKET_SPOT 0
+
+/**
+ * USD-M futures
+ */
+#define BINANCE_MARKET_FUTURES 1
+
+/**
+ * Futures only: only reduces the position held
+ */
+#define BINANCE_FLAG_REDUCE_ONLY 1
+
+/**
+ * Limit only: cancelled instead of taking
+ */
+#define BINANCE_FLAG_POST_ONLY 2
+
+/**
+ * Limit only: what does not fill at once is cancelled
+ */
+#define BINANCE_FLAG_IOC 4
+
+/**
+ * Limit only: fills in full at once or is cancelled
+ */
+#define BINANCE_FLAG_FOK 8
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Venue order id
+   */
+  uint64_t order_id;
+  /**
+   * Assigned by the venue
+   */
+  char client_order_id[40];
+  /**
+   * NEW, PARTIALLY_FILLED, FILLED, EXPIRED...
+   */
+  char status[24];
+  /**
+   * Quantity filled at once (6 decimals)
+   */
+  int64_t executed_qty_raw;
+  /**
+   * Average fill price (6 decimals), 0 if nothing filled
+   */
+  int64_t avg_price_raw;
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} BinanceOrderResult;
+
+/**
+ * Best bid and ask of a subscribed symbol, filled by
+ * binance_get_book_ticker()
+ */
+typedef struct {
+  int64_t bid_price_raw;
+  int64_t bid_qty_raw;
+  int64_t ask_price_raw;
+  int64_t ask_qty_raw;
+  /**
+   * Book update id of the venue
+   */
+  uint64_t update_id;
+  /**
+   * Since the ticker was received
+   */
+  uint64_t age_ms;
+} BinanceBookTicker;
+
+/**
+ * Order event of the user data stream, passed to BinanceOrderUpdateCallback;
+ * raw values in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  /**
+   * BINANCE_MARKET_*
+   */
+  int32_t market;
+  bool is_buy;
+  /**
+   * Fills: our order was resting on the book
+   */
+  bool maker;
+  uint64_t order_id;
+  /**
+   * -1 if the event is not a fill
+   */
+  int64_t trade_id;
+  /**
+   * Order limit price, 0 for market orders
+   */
+  int64_t price_raw;
+  /**
+   * Order quantity
+   */
+  int64_t quantity_raw;
+  /**
+   * Quantity of this fill
+   */
+  int64_t last_fill_qty_raw;
+  /**
+   * Price of this fill
+   */
+  int64_t last_fill_price_raw;
+  /**
+   * Quantity filled so far
+   */
+  int64_t cumulative_qty_raw;
+  /**
+   * Commission of this fill
+   */
+  int64_t commission_raw;
+  /**
+   * Venue time of the event
+   */
+  uint64_t time_ms;
+  char symbol[32];
+  /**
+   * Of the order, also for cancels
+   */
+  char client_order_id[40];
+  /**
+   * NEW, TRADE, CANCELED, EXPIRED, REJECTED...
+   */
+  char execution_type[24];
+  /**
+   * NEW, PARTIALLY_FILLED, FILLED, CANCELED...
+   */
+  char status[24];
+  char commission_asset[16];
+} BinanceOrderUpdate;
+
+/**
+ * Order update callback of binance_subscribe_order_updates(); `update` is
+ * only valid during the call, `user_data` is as passed when subscribing
+ */
+typedef void (*BinanceOrderUpdateCallback)(const BinanceOrderUpdate *update, void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect with an API key and its secret key, on the testnet of both
+ * markets if `testnet`. The key is checked by the first signed call.
+ * Replaces a session already set up. Returns 0 on success, negative error
+ * code on failure
+ */
+int32_t binance_init(const char *api_key, const char *secret_key, bool testnet);
+
+/**
+ * Shut the session down and close every stream; calls still running
+ * finish first. binance_init() can be called again afterwards
+ */
+void binance_shutdown(void);
+
+/**
+ * Market order of `quantity` base asset units
+ * `flags`: BINANCE_FLAG_REDUCE_ONLY (futures) or 0
+ */
+int32_t binance_market_order(int32_t market,
+                             const char *symbol,
+                             bool is_buy,
+                             double quantity,
+                             uint32_t flags,
+                             BinanceOrderResult *out);
+
+/**
+ * Limit order of `quantity` base asset units at `price`, rounded to the
+ * nearest tick
+ * `flags`: BINANCE_FLAG_* combined; POST_ONLY, IOC and FOK exclude each other
+ */
+int32_t binance_limit_order(int32_t market,
+                            const char *symbol,
+                            bool is_buy,
+                            double quantity,
+                            double price,
+                            uint32_t flags,
+                            BinanceOrderResult *out);
+
+/**
+ * Cancel an open order of `symbol` by its order id
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t binance_cancel(int32_t market, const char *symbol, uint64_t order_id);
+
+/**
+ * Cancel every open order of `symbol`
+ * Returns 0 on success (also if none was open), negative error code on
+ * failure
+ */
+int32_t binance_cancel_all(int32_t market, const char *symbol);
+
+/**
+ * Amount of `asset` ("USDT") free to trade on a market (raw, 6 decimals),
+ * 0 if none held, or negative error code
+ */
+int64_t binance_get_balance(int32_t market, const char *asset);
+
+/**
+ * Non-zero balances of a market as JSON [{"asset", "free", "total"}];
+ * returns its size (binance_last_response()) or a negative error code
+ */
+int64_t binance_get_balances(int32_t market);
+
+/**
+ * Futures positions as JSON [{"symbol", "size", "entry_price",
+ * "mark_price", "unrealized_pnl", "liquidation_price", "leverage"}];
+ * returns its size or a negative error code
+ */
+int64_t binance_get_positions(void);
+
+/**
+ * Open orders of a market as JSON [{"symbol", "order_id", "client_order_id",
+ * "side", "order_type", "price", "quantity", "executed_qty", "status",
+ * "time"}], of `symbol` or of every symbol if NULL; returns its size or a
+ * negative error code
+ */
+int64_t binance_get_open_orders(int32_t market, const char *symbol);
+
+/**
+ * Stream a market's order updates and fills to `callback`, replacing the
+ * callback set before (one callback serves both markets)
+ * The callback runs on a runtime worker thread, one update at a time; it
+ * must not block or call back into the library
+ */
+int32_t binance_subscribe_order_updates(int32_t market,
+                                        BinanceOrderUpdateCallback callback,
+                                        void *user_data);
+
+/**
+ * Close a market's user data stream. Once this returns for the last
+ * market subscribed, the callback is not invoked again. No-op if not
+ * subscribed
+ */
+int32_t binance_unsubscribe_order_updates(int32_t market);
+
+/**
+ * Keep the best bid and ask of a symbol from the book ticker stream
+ * The first subscription of a market opens its connection; it reconnects
+ * on its own and closes on shutdown. Returns BINANCE_OK, also if already
+ * subscribed
+ */
+int32_t binance_subscribe_book_ticker(int32_t market, const char *symbol);
+
+/**
+ * Stop keeping a symbol's ticker; the connection stays open
+ * Returns BINANCE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t binance_unsubscribe_book_ticker(int32_t market, const char *symbol);
+
+/**
+ * Best bid and ask of a subscribed symbol
+ * Returns BINANCE_PENDING until the venue sent its ticker (`out`
+ * untouched), BINANCE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t binance_get_book_ticker(int32_t market, const char *symbol, BinanceBookTicker *out);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t binance_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t binance_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* BINANCE_FFI_GENERATED_H */
@@ -0,0 +1,201 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef COINBASE_FFI_H
+#define COINBASE_FFI_H
+
+#include "coinbase_ffi_generated.h"
+
+/*
+ * Coinbase Advanced Trade executor (Rust)
+ *
+ * Orders, cancels, balances and open orders over the Advanced Trade REST
+ * API, and the best bid and ask of products from the market data ticker
+ * channel, with the conventions of polymarket_ffi.h: one global session
+ * set up by coinbase_init(), blocking calls that may be made from any
+ * number of host threads, negative error codes numbered as POLYMARKET_ERR_*
+ * where the meaning is shared, raw amounts with 6 decimals, thread-local
+ * last error / last response strings, and call metrics in the shapes of
+ * polymarket_get_metrics().
+ *
+ * Requests are signed with a CDP API key: a short-lived ES256 JWT per
+ * request. Only ECDSA (P-256) keys are supported, not Ed25519 ones.
+ *
+ * Products are written as the venue lists them ("BTC-USD"). Sizes are
+ * base asset units, rounded down to the product's base increment; prices
+ * are rounded to its price increment.
+ *
+ * coinbase_init() and coinbase_shutdown() must not race with each other.
+ * Blocking calls must not be made from a thread owned by the executor
+ * runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants and structs are generated from the Rust definitions, with
+ * their documentation, in coinbase_ffi_generated.h. The declarations below
+ * are checked against the generated ones.
+ */
+
+  /**
+ * Connect and check the key by reading the balances. Replaces a session
+ * already set up.
+ *
+ * @param key_name     CDP API key name ("organizations/{org}/apiKeys/{id}"),
+ *                     with the view and trade permissions
+ * @param private_key  Its EC private key (PEM; "\n" escapes are accepted);
+ *                     the library keeps no copy of the string
+ * @return COINBASE_OK or negative error code (COINBASE_ERR_AUTH if the key
+ *         is refused)
+ */
+  int32_t coinbase_init(const char* key_name, const char* private_key);
+
+  /**
+ * Shut the session down and close the ticker feed; coinbase_init() can be
+ * called again afterwards.
+ */
+  void coinbase_shutdown(void);
+
+  /**
+ * Market order.
+ *
+ * @param product_id  e.g. "BTC-USD"
+ * @param is_buy      Buy or sell
+ * @param size        Base asset units, > 0
+ * @param out         Result, filled on success and failure
+ * @return COINBASE_OK or negative error code (also in out->error_code)
+ */
+  int32_t coinbase_market_order(const char* product_id, bool is_buy, double size,
+                                CoinbaseOrderResult* out);
+
+  /**
+ * Limit order at price, resting until filled or cancelled unless
+ * COINBASE_FLAG_IOC or COINBASE_FLAG_FOK is set.
+ *
+ * @param flags  One of the COINBASE_FLAG_* values, or 0
+ * Other parameters and return as coinbase_market_order().
+ */
+  int32_t coinbase_limit_order(const char* product_id, bool is_buy, double size, double price,
+                               uint32_t flags, CoinbaseOrderResult* out);
+
+  /**
+ * Cancel an open order.
+ *
+ * @param order_id  Venue order id, as in CoinbaseOrderResult
+ * @return COINBASE_OK or negative error code (COINBASE_ERR_REJECTED if the
+ *         order is no longer open)
+ */
+  int32_t coinbase_cancel(const char* order_id);
+
+  /**
+ * Cancel every open order of a product, or of every product.
+ *
+ * @param product_id  e.g. "BTC-USD", or NULL for all
+ * @return Number of orders cancelled (0 if none was open), or negative
+ *         error code
+ */
+  int32_t coinbase_cancel_all(const char* product_id);
+
+  /**
+ * Amount of an asset available to trade.
+ *
+ * @param asset  e.g. "USD", "BTC"
+ * @return Raw amount (6 decimals), 0 if none held, or negative error code
+ */
+  int64_t coinbase_get_balance(const char* asset);
+
+  /**
+ * Non-zero balances as JSON: [{"asset", "free", "total"}].
+ *
+ * @return Size of the JSON, read with coinbase_last_response(), or
+ *         negative error code
+ */
+  int64_t coinbase_get_balances(void);
+
+  /**
+ * Open orders as JSON: [{"order_id", "client_order_id", "product_id",
+ * "side", "order_type", "price", "size", "filled_size", "status",
+ * "created_time"}], of a product or of every product (NULL). Same return
+ * as coinbase_get_balances().
+ */
+  int64_t coinbase_get_open_orders(const char* product_id);
+
+  /**
+ * Keep the best bid and ask of a product from the ticker channel. The
+ * first subscription opens the connection; it reconnects on its own and
+ * closes on shutdown.
+ *
+ * @return COINBASE_OK, also if already subscribed
+ */
+  int32_t coinbase_subscribe_ticker(const char* product_id);
+
+  /**
+ * Stop keeping a product's ticker; the connection stays open.
+ *
+ * @return COINBASE_OK, COINBASE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t coinbase_unsubscribe_ticker(const char* product_id);
+
+  /**
+ * Best bid and ask of a subscribed product.
+ *
+ * @return COINBASE_OK, COINBASE_PENDING until the venue sent the ticker
+ *         (out untouched), COINBASE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t coinbase_get_ticker(const char* product_id, CoinbaseTicker* out);
+
+  /**
+ * Latency and error counts of order posts, cancels and balance reads
+ * since the session started, as JSON: [{"op", "count", "errors",
+ * "mean_ms", "p50_ms", "p90_ms", "p99_ms", "max_ms"}].
+ *
+ * @return Buffer size needed (see String outputs), or negative error code
+ */
+  int64_t coinbase_get_metrics(char* buf, size_t len);
+
+  /**
+ * The counts of coinbase_get_metrics() in the Prometheus text format.
+ *
+ * @return Buffer size needed (see String outputs), or negative error code
+ */
+  int64_t coinbase_get_metrics_prometheus(char* buf, size_t len);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t coinbase_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the venue's own error ("INSUFFICIENT_FUND ...") for refused requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t coinbase_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* COINBASE_FFI_H */
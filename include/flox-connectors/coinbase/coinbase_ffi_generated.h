@@ -0,0 +1,275 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/coinbase/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in coinbase_ffi.h.
+ */
+
+#ifndef COINBASE_FFI_GENERATED_H
+#define COINBASE_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define COINBASE_OK 0
+
+/**
+ * Not an error: no data yet
+ */
+#define COINBASE_PENDING 1
+
+#define COINBASE_ERR_NOT_INITIALIZED -1
+
+/**
+ * Key name or private key malformed
+ */
+#define COINBASE_ERR_INVALID_KEY -2
+
+/**
+ * Key refused, or lacks the permission
+ */
+#define COINBASE_ERR_AUTH -3
+
+/**
+ * Not a trading product
+ */
+#define COINBASE_ERR_INVALID_PRODUCT -4
+
+#define COINBASE_ERR_ORDER_FAILED -5
+
+#define COINBASE_ERR_CANCEL_FAILED -6
+
+/**
+ * Not enough funds
+ */
+#define COINBASE_ERR_INSUFFICIENT_BALANCE -9
+
+#define COINBASE_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define COINBASE_ERR_PANIC -14
+
+/**
+ * Venue refused the request, see last error
+ */
+#define COINBASE_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define COINBASE_ERR_NETWORK -20
+
+/**
+ * Rate limit exceeded
+ */
+#define COINBASE_ERR_RATE_LIMITED -21
+
+/**
+ * Venue unavailable or busy, order state unknown
+ */
+#define COINBASE_ERR_VENUE_ERROR -22
+
+/**
+ * Size below the minimum, or price not valid
+ */
+#define COINBASE_ERR_INVALID_ORDER -23
+
+/**
+ * Cancelled instead of taking
+ */
+#define COINBASE_FLAG_POST_ONLY 1
+
+/**
+ * What does not fill at once is cancelled
+ */
+#define COINBASE_FLAG_IOC 2
+
+/**
+ * Fills entirely at once, or is cancelled
+ */
+#define COINBASE_FLAG_FOK 4
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Venue order id (UUID)
+   */
+  char order_id[40];
+  /**
+   * Our id of the order
+   */
+  char client_order_id[40];
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} CoinbaseOrderResult;
+
+/**
+ * Best bid and ask of a subscribed product, filled by
+ * coinbase_get_ticker(); raw values in 6 decimals (divide by 1M for
+ * actual)
+ */
+typedef struct {
+  /**
+   * Last trade price
+   */
+  int64_t price_raw;
+  int64_t bid_price_raw;
+  int64_t bid_qty_raw;
+  int64_t ask_price_raw;
+  int64_t ask_qty_raw;
+  /**
+   * Sequence number of the message on its connection
+   */
+  uint64_t sequence;
+  /**
+   * Since the ticker was received
+   */
+  uint64_t age_ms;
+} CoinbaseTicker;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect with a CDP API key name ("organizations/.../apiKeys/...") and
+ * its EC private key (PEM), checked by reading the balances
+ * Replaces a session already set up. Returns 0 on success, negative error
+ * code on failure
+ */
+int32_t coinbase_init(const char *key_name, const char *private_key);
+
+/**
+ * Shut the session down and close the ticker feed; calls still running
+ * finish first. coinbase_init() can be called again afterwards
+ */
+void coinbase_shutdown(void);
+
+/**
+ * Market order of `size` base asset units, rounded down to the product's
+ * base increment
+ */
+int32_t coinbase_market_order(const char *product_id,
+                              bool is_buy,
+                              double size,
+                              CoinbaseOrderResult *out);
+
+/**
+ * Limit order of `size` base asset units at `price`, rounded to the
+ * nearest price increment
+ * `flags`: one of COINBASE_FLAG_POST_ONLY, COINBASE_FLAG_IOC and
+ * COINBASE_FLAG_FOK, or 0 for good till cancelled
+ */
+int32_t coinbase_limit_order(const char *product_id,
+                             bool is_buy,
+                             double size,
+                             double price,
+                             uint32_t flags,
+                             CoinbaseOrderResult *out);
+
+/**
+ * Cancel an open order by its venue order id
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t coinbase_cancel(const char *order_id);
+
+/**
+ * Cancel every open order of `product_id`, or of every product if NULL
+ * Returns the number cancelled (0 if none was open), or negative error code
+ */
+int32_t coinbase_cancel_all(const char *product_id);
+
+/**
+ * Amount of `asset` ("USD", "BTC") available to trade (raw, 6 decimals),
+ * 0 if none held, or negative error code
+ */
+int64_t coinbase_get_balance(const char *asset);
+
+/**
+ * Non-zero balances as JSON [{"asset", "free", "total"}]; returns its size
+ * (coinbase_last_response()) or a negative error code
+ */
+int64_t coinbase_get_balances(void);
+
+/**
+ * Open orders as JSON [{"order_id", "client_order_id", "product_id", "side",
+ * "order_type", "price", "size", "filled_size", "status", "created_time"}],
+ * of `product_id` or of every product if NULL; returns its size or a
+ * negative error code
+ */
+int64_t coinbase_get_open_orders(const char *product_id);
+
+/**
+ * Keep the best bid and ask of a product from the ticker channel
+ * The first subscription opens the connection; it reconnects on its own
+ * and closes on shutdown. Returns COINBASE_OK, also if already subscribed
+ */
+int32_t coinbase_subscribe_ticker(const char *product_id);
+
+/**
+ * Stop keeping a product's ticker; the connection stays open
+ * Returns COINBASE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t coinbase_unsubscribe_ticker(const char *product_id);
+
+/**
+ * Best bid and ask of a subscribed product
+ * Returns COINBASE_PENDING until the venue sent its ticker (`out`
+ * untouched), COINBASE_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t coinbase_get_ticker(const char *product_id, CoinbaseTicker *out);
+
+/**
+ * Latency and error counts of order posts, cancels and balance reads since
+ * the session started, copied into `buf` as JSON:
+ * [{op, count, errors, mean_ms, p50_ms, p90_ms, p99_ms, max_ms}]
+ * Returns the required buffer size (including NUL), negative error code on
+ * failure; written only if `len` is large enough, pass NULL/0 to query
+ */
+int64_t coinbase_get_metrics(char *buf, size_t len);
+
+/**
+ * The counts of coinbase_get_metrics() in the Prometheus text format, to
+ * serve from a scrape endpoint of the host. Same buffer convention
+ */
+int64_t coinbase_get_metrics_prometheus(char *buf, size_t len);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t coinbase_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t coinbase_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* COINBASE_FFI_GENERATED_H */
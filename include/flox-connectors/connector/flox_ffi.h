@@ -0,0 +1,202 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef FLOX_FFI_H
+#define FLOX_FFI_H
+
+#include "flox_ffi_generated.h"
+
+/*
+ * Venue-neutral executor (Rust)
+ *
+ * One set of entry points for every venue the library was built with,
+ * picked by venue id: "kalshi", "drift", "hyperliquid", "binance",
+ * "kraken", "coinbase", "ibkr", "polymarket". flox_list_venues() tells
+ * which are in the library and flox_get_capabilities() what each can do,
+ * so a host written against this header drives a venue added to the
+ * library later without being rebuilt. "polymarket" is only in a library
+ * built with its feature; Polymarket's own ABI (polymarket_ffi.h) covers
+ * far more than the orders served here, and cannot be linked into the
+ * same binary.
+ *
+ * Conventions of the venue headers: one global session per venue set up
+ * by flox_init(), blocking calls that may be made from any number of host
+ * threads, negative error codes numbered as the venue headers' (and
+ * POLYMARKET_ERR_* where the meaning is shared), thread-local last error /
+ * last response strings. Quantities and prices are doubles in the venue's
+ * units; top of book comes back raw with 6 decimals.
+ *
+ * Instruments are the venue's own names:
+ *   kalshi       market ticker, YES side; ":NO" appended for the NO side
+ *   drift        market index ("12")
+ *   hyperliquid  perp coin ("BTC")
+ *   binance      spot symbol ("BTCUSDT"), "futures:BTCUSDT" for USD-M
+ *   kraken       pair as WebSocket v2 names it ("BTC/USD")
+ *   coinbase     product ("BTC-USD")
+ *   ibkr         contract id ("265598")
+ *   polymarket   CLOB token id of an outcome
+ *
+ * FloxConfig fields each venue reads:
+ *   kalshi       api_key (key id), secret (PEM private key), endpoint, testnet
+ *   drift        endpoint (gateway url), account (sub-account id)
+ *   hyperliquid  secret (hex private key), account, testnet
+ *   binance      api_key, secret, testnet
+ *   kraken       api_key, secret (base64 private key)
+ *   coinbase     api_key (CDP key name), secret (EC private key, PEM)
+ *   ibkr         endpoint (gateway address), account
+ *   polymarket   secret (hex private key), account (proxy wallet), endpoint
+ *
+ * flox_init() and flox_shutdown() of one venue must not race with each
+ * other. Blocking calls must not be made from a thread owned by the
+ * executor runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants and structs are generated from the Rust definitions, with
+ * their documentation, in flox_ffi_generated.h. The declarations below are
+ * checked against the generated ones.
+ */
+
+  /**
+ * Version of the ABI the library implements; a host built against
+ * another FLOX_ABI_VERSION must not use it.
+ */
+  uint32_t flox_abi_version(void);
+
+  /**
+ * Copy the ids of the venues the library was built with, comma separated
+ * ("kalshi,drift,hyperliquid").
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t flox_list_venues(char* buf, size_t len);
+
+  /**
+ * What a venue can do, known without a session.
+ *
+ * @return FLOX_CAP_* bits, or FLOX_ERR_UNSUPPORTED if the venue is not in
+ *         the library
+ */
+  int64_t flox_get_capabilities(const char* venue);
+
+  /**
+ * Open a session with a venue, replacing one already set up.
+ *
+ * @return FLOX_OK or negative error code (FLOX_ERR_AUTH if the venue
+ *         refused the credentials)
+ */
+  int32_t flox_init(const char* venue, const FloxConfig* config);
+
+  /**
+ * Close a venue's session and its feeds; calls still running finish first.
+ * flox_init() can be called again afterwards. No-op without a session.
+ */
+  void flox_shutdown(const char* venue);
+
+  /**
+ * Check and place an order.
+ *
+ * @param out  Result, filled on success and failure
+ * @return FLOX_OK or negative error code (also in out->error_code)
+ */
+  int32_t flox_place_order(const char* venue, const FloxOrderSpec* order, FloxOrderResult* out);
+
+  /**
+ * Cancel an open order by the id flox_place_order() returned. Binance and
+ * Hyperliquid need its instrument; the other venues ignore it (may be
+ * NULL).
+ *
+ * @return FLOX_OK or negative error code
+ */
+  int32_t flox_cancel(const char* venue, const char* instrument, const char* order_id);
+
+  /**
+ * Cancel every open order with a venue, of one instrument only if
+ * instrument is not NULL.
+ *
+ * @param cancelled  May be NULL; receives the number cancelled, -1 where
+ *                   the venue does not tell
+ * @return FLOX_OK or negative error code
+ */
+  int32_t flox_cancel_all(const char* venue, const char* instrument, int64_t* cancelled);
+
+  /**
+ * Balances as JSON: [{"asset", "free", "total"}], in units of the asset.
+ *
+ * @return Size of the JSON, read with flox_last_response(), or negative
+ *         error code
+ */
+  int64_t flox_get_balances(const char* venue);
+
+  /**
+ * Latency and error counts of the session's calls as JSON: [{"op",
+ * "count", "errors", "mean_ms", "p50_ms", "p90_ms", "p99_ms", "max_ms"}].
+ * Same return as flox_get_balances().
+ */
+  int64_t flox_get_metrics(const char* venue);
+
+  /**
+ * Keep the top of book of an instrument from the venue's stream. The
+ * first subscription opens the connection; it reconnects on its own and
+ * closes on shutdown.
+ *
+ * @return FLOX_OK, also if already subscribed; FLOX_ERR_UNSUPPORTED
+ *         without FLOX_CAP_TOP_OF_BOOK
+ */
+  int32_t flox_subscribe(const char* venue, const char* instrument);
+
+  /**
+ * Stop keeping an instrument's top of book; the connection stays open.
+ *
+ * @return FLOX_OK, FLOX_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t flox_unsubscribe(const char* venue, const char* instrument);
+
+  /**
+ * Best bid and ask of a subscribed instrument.
+ *
+ * @return FLOX_OK, FLOX_PENDING until the venue sent them (out
+ *         untouched), FLOX_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t flox_get_top_of_book(const char* venue, const char* instrument, FloxTopOfBook* out);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t flox_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread,
+ * with the venue's own reason for refused requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t flox_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FLOX_FFI_H */
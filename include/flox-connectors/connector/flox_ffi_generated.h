@@ -0,0 +1,366 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/connector/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in flox_ffi.h.
+ */
+
+#ifndef FLOX_FFI_GENERATED_H
+#define FLOX_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Version of the ABI, see flox_abi_version(); bumped when a struct or
+ * signature changes
+ */
+#define FLOX_ABI_VERSION 1
+
+#define FLOX_OK 0
+
+/**
+ * Not an error: no data yet
+ */
+#define FLOX_PENDING 1
+
+/**
+ * No session with the venue
+ */
+#define FLOX_ERR_NOT_INITIALIZED -1
+
+/**
+ * Key missing or malformed
+ */
+#define FLOX_ERR_INVALID_KEY -2
+
+/**
+ * Key refused, or the session was lost
+ */
+#define FLOX_ERR_AUTH -3
+
+#define FLOX_ERR_INVALID_INSTRUMENT -4
+
+#define FLOX_ERR_ORDER_FAILED -5
+
+#define FLOX_ERR_CANCEL_FAILED -6
+
+#define FLOX_ERR_INSUFFICIENT_BALANCE -9
+
+/**
+ * Endpoint, account or rate limit not valid
+ */
+#define FLOX_ERR_INVALID_CONFIG -10
+
+#define FLOX_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define FLOX_ERR_PANIC -14
+
+/**
+ * Venue refused the request, see last error
+ */
+#define FLOX_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define FLOX_ERR_NETWORK -20
+
+/**
+ * Venue or local rate limit exceeded
+ */
+#define FLOX_ERR_RATE_LIMITED -21
+
+/**
+ * Venue answered with a fault, state unknown
+ */
+#define FLOX_ERR_VENUE_ERROR -22
+
+/**
+ * Quantity or price not valid
+ */
+#define FLOX_ERR_INVALID_ORDER -23
+
+/**
+ * Venue cannot do it, or is not in the library
+ */
+#define FLOX_ERR_UNSUPPORTED -29
+
+#define FLOX_CAP_MARKET (1 << 0)
+
+/**
+ * Good till cancelled
+ */
+#define FLOX_CAP_LIMIT (1 << 1)
+
+/**
+ * Limit orders of these times in force
+ */
+#define FLOX_CAP_IOC (1 << 2)
+
+#define FLOX_CAP_FOK (1 << 3)
+
+#define FLOX_CAP_DAY (1 << 4)
+
+#define FLOX_CAP_POST_ONLY (1 << 5)
+
+#define FLOX_CAP_REDUCE_ONLY (1 << 6)
+
+#define FLOX_CAP_CANCEL_ALL (1 << 7)
+
+#define FLOX_CAP_BALANCES (1 << 8)
+
+/**
+ * flox_subscribe(), flox_get_top_of_book()
+ */
+#define FLOX_CAP_TOP_OF_BOOK (1 << 9)
+
+/**
+ * FloxConfig.testnet picks a testnet or sandbox
+ */
+#define FLOX_CAP_TESTNET (1 << 10)
+
+#define FLOX_ORDER_MARKET 0
+
+#define FLOX_ORDER_LIMIT 1
+
+#define FLOX_TIF_GTC 0
+
+/**
+ * What does not fill at once is cancelled
+ */
+#define FLOX_TIF_IOC 1
+
+/**
+ * Fills in full at once or is cancelled
+ */
+#define FLOX_TIF_FOK 2
+
+/**
+ * Cancelled at the end of the trading day
+ */
+#define FLOX_TIF_DAY 3
+
+/**
+ * Cancelled instead of taking
+ */
+#define FLOX_FLAG_POST_ONLY 1
+
+/**
+ * Only reduces the position held
+ */
+#define FLOX_FLAG_REDUCE_ONLY 2
+
+/**
+ * Session settings, read by flox_init(); NULL strings are not set. See
+ * flox_ffi.h for the fields each venue reads
+ */
+typedef struct {
+  const char *api_key;
+  /**
+   * The library keeps no copy of the string
+   */
+  const char *secret;
+  const char *endpoint;
+  const char *account;
+  bool testnet;
+  /**
+   * Local limit of the venue calls, 0 for none
+   */
+  double rate_per_sec;
+  /**
+   * Calls back to back, >= 1 with a limit
+   */
+  uint32_t rate_burst;
+  /**
+   * Wait for budget instead of failing at once
+   */
+  bool rate_queue;
+} FloxConfig;
+
+/**
+ * Order of any venue; what the venue cannot express is refused with
+ * FLOX_ERR_UNSUPPORTED before anything is sent
+ */
+typedef struct {
+  const char *instrument;
+  bool is_buy;
+  /**
+   * Shares, contracts or base asset units, > 0
+   */
+  double quantity;
+  /**
+   * FLOX_ORDER_*
+   */
+  uint32_t order_type;
+  /**
+   * Limit orders only
+   */
+  double price;
+  /**
+   * FLOX_TIF_*, limit orders only
+   */
+  uint32_t tif;
+  /**
+   * FLOX_FLAG_*
+   */
+  uint32_t flags;
+} FloxOrderSpec;
+
+/**
+ * Order result, filled by flox_place_order()
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Id to cancel the order with
+   */
+  char order_id[64];
+  /**
+   * Empty where the venue has none
+   */
+  char client_order_id[64];
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} FloxOrderResult;
+
+/**
+ * Best bid and ask of a subscribed instrument, in 6 decimals (divide by
+ * 1M for actual)
+ */
+typedef struct {
+  int64_t bid_raw;
+  int64_t bid_size_raw;
+  int64_t ask_raw;
+  int64_t ask_size_raw;
+  /**
+   * Since the venue's last update was received
+   */
+  uint64_t age_ms;
+} FloxTopOfBook;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Version of the ABI the library implements (FLOX_ABI_VERSION)
+ */
+uint32_t flox_abi_version(void);
+
+/**
+ * Copy the ids of the venues the library was built with, comma separated
+ * ("kalshi,drift,..."). Returns the buffer size needed (see out_buf)
+ */
+size_t flox_list_venues(char *buf, size_t len);
+
+/**
+ * FLOX_CAP_* bits of what a venue can do; no session needed
+ * Returns FLOX_ERR_UNSUPPORTED if the library was built without it
+ */
+int64_t flox_get_capabilities(const char *venue);
+
+/**
+ * Open a session with a venue, replacing one already set up
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t flox_init(const char *venue, const FloxConfig *config);
+
+/**
+ * Close a venue's session and its feeds; calls still running finish
+ * first. No-op without a session
+ */
+void flox_shutdown(const char *venue);
+
+/**
+ * Check and place an order with a venue and fill `out`
+ * Returns 0 on success, negative error code on failure (also in `out`);
+ * FLOX_ERR_UNSUPPORTED if the venue cannot express the order
+ */
+int32_t flox_place_order(const char *venue, const FloxOrderSpec *order, FloxOrderResult *out);
+
+/**
+ * Cancel an open order by the id flox_place_order() returned; some venues
+ * need its instrument, the others ignore it
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t flox_cancel(const char *venue, const char *instrument, const char *order_id);
+
+/**
+ * Cancel every open order with a venue, of `instrument` only if not NULL
+ * `cancelled` (may be NULL) receives the number cancelled, -1 where the
+ * venue does not tell. Returns 0 on success, negative error code on failure
+ */
+int32_t flox_cancel_all(const char *venue, const char *instrument, int64_t *cancelled);
+
+/**
+ * Balances held with a venue as JSON [{"asset", "free", "total"}], in
+ * units of the asset; returns its size (flox_last_response()) or a
+ * negative error code
+ */
+int64_t flox_get_balances(const char *venue);
+
+/**
+ * Latency and error counts of a venue session's calls as JSON [{"op",
+ * "count", "errors", "mean_ms", "p50_ms", "p90_ms", "p99_ms", "max_ms"}];
+ * returns its size (flox_last_response()) or a negative error code
+ */
+int64_t flox_get_metrics(const char *venue);
+
+/**
+ * Keep the top of book of an instrument from the venue's stream
+ * The first subscription opens the connection; it reconnects on its own
+ * and closes on shutdown. Returns FLOX_OK, also if already subscribed
+ */
+int32_t flox_subscribe(const char *venue, const char *instrument);
+
+/**
+ * Stop keeping an instrument's top of book; the connection stays open
+ * Returns FLOX_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t flox_unsubscribe(const char *venue, const char *instrument);
+
+/**
+ * Best bid and ask of a subscribed instrument
+ * Returns FLOX_PENDING until the venue sent them (`out` untouched),
+ * FLOX_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t flox_get_top_of_book(const char *venue, const char *instrument, FloxTopOfBook *out);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t flox_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t flox_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FLOX_FFI_GENERATED_H */
@@ -0,0 +1,164 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef DRIFT_FFI_H
+#define DRIFT_FFI_H
+
+#include "drift_ffi_generated.h"
+
+/*
+ * Drift prediction market executor
+ *
+ * Orders, cancels, collateral, positions and open orders on Drift's
+ * prediction markets (BET, Solana), with the conventions of
+ * polymarket_ffi.h: one global session set up by drift_init(), blocking
+ * calls that may be made from any number of host threads, negative error
+ * codes numbered as POLYMARKET_ERR_* where the meaning is shared, and
+ * thread-local last error / last response strings.
+ *
+ * A prediction market is a Drift perp market priced 0-1 USDC per YES
+ * share: long holds YES shares, short holds NO shares. Markets are named
+ * by their perp market index.
+ *
+ * Calls go through a Drift gateway (drift-labs/gateway), run next to the
+ * host. The gateway holds the wallet keypair, signs the program's
+ * transactions and sends them; this library holds no key. An order call
+ * returns once its transaction is sent: fills show in
+ * drift_get_positions() and drift_get_open_orders() once it lands.
+ *
+ * drift_init() and drift_shutdown() must not race with each other.
+ * Blocking calls must not be made from a thread owned by the executor
+ * runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants and structs are generated from the Rust definitions, with
+ * their documentation, in drift_ffi_generated.h. The declarations below
+ * are checked against the generated ones.
+ */
+
+  /**
+ * Connect to a Drift gateway. Replaces a session already set up.
+ *
+ * @param gateway_url     Gateway root, NULL for http://127.0.0.1:8080
+ * @param sub_account_id  Sub-account of the gateway's wallet to trade
+ * @return DRIFT_OK, DRIFT_ERR_INVALID_CONFIG, or the error of reading the
+ *         collateral (DRIFT_ERR_NETWORK if the gateway is not reachable)
+ */
+  int32_t drift_init(const char* gateway_url, uint16_t sub_account_id);
+
+  /**
+ * Shut the session down; drift_init() can be called again afterwards.
+ */
+  void drift_shutdown(void);
+
+  /**
+ * Market order, filled through the program's auction.
+ *
+ * User order ids wrap after 255 orders; a host keeping more orders open
+ * cancels them by market.
+ *
+ * @param market_index  Perp market index of the prediction market
+ * @param side          DRIFT_SIDE_LONG or DRIFT_SIDE_SHORT
+ * @param shares        YES shares, > 0
+ * @param worst_price   Worst price accepted (0-1 exclusive), 0 for no bound
+ * @param flags         DRIFT_FLAG_REDUCE_ONLY or 0
+ * @param out           Result, filled on success and failure
+ * @return DRIFT_OK or negative error code (also in out->error_code)
+ */
+  int32_t drift_market_order(uint16_t market_index, int32_t side, double shares,
+                             double worst_price, uint32_t flags, DriftOrderResult* out);
+
+  /**
+ * Limit order at price (0-1 exclusive), resting until filled or cancelled
+ * unless DRIFT_FLAG_IOC is set.
+ *
+ * @param flags  DRIFT_FLAG_* combined; POST_ONLY and IOC exclude each other
+ * Other parameters and return as drift_market_order().
+ */
+  int32_t drift_limit_order(uint16_t market_index, int32_t side, double shares, double price,
+                            uint32_t flags, DriftOrderResult* out);
+
+  /**
+ * Cancel an order by its user order id (DriftOrderResult.user_order_id).
+ * The cancel transaction's signature is kept for drift_last_response().
+ *
+ * @return DRIFT_OK or negative error code
+ */
+  int32_t drift_cancel(uint32_t user_order_id);
+
+  /**
+ * Cancel every order open in a market. Same return as drift_cancel().
+ */
+  int32_t drift_cancel_market(uint16_t market_index);
+
+  /**
+ * Cancel every order open on the sub-account. Same return as drift_cancel().
+ */
+  int32_t drift_cancel_all(void);
+
+  /**
+ * Free collateral of the sub-account.
+ *
+ * @return Raw USDC (6 decimals), or negative error code
+ */
+  int64_t drift_get_balance(void);
+
+  /**
+ * Perp positions held, prediction markets included, as JSON:
+ * [{"marketIndex", "amount", "averageEntry", "settledPnl"}]. amount is
+ * signed: positive for YES shares, negative for NO.
+ *
+ * @return Size of the JSON, read with drift_last_response(), or negative
+ *         error code
+ */
+  int64_t drift_get_positions(void);
+
+  /**
+ * Perp orders open, as JSON: [{"marketIndex", "marketType", "orderType",
+ * "amount", "filled", "price", "postOnly", "reduceOnly", "userOrderId",
+ * "orderId"}]. Same return as drift_get_positions().
+ */
+  int64_t drift_get_open_orders(void);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread, or
+ * the signature of its last cancel.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t drift_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the gateway's reason (with the program error) for refused requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t drift_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* DRIFT_FFI_H */
@@ -0,0 +1,215 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/drift/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in drift_ffi.h.
+ */
+
+#ifndef DRIFT_FFI_GENERATED_H
+#define DRIFT_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define DRIFT_OK 0
+
+#define DRIFT_ERR_NOT_INITIALIZED -1
+
+#define DRIFT_ERR_INVALID_MARKET -4
+
+#define DRIFT_ERR_ORDER_FAILED -5
+
+#define DRIFT_ERR_CANCEL_FAILED -6
+
+/**
+ * Not enough free collateral
+ */
+#define DRIFT_ERR_INSUFFICIENT_BALANCE -9
+
+/**
+ * Gateway url malformed
+ */
+#define DRIFT_ERR_INVALID_CONFIG -10
+
+#define DRIFT_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define DRIFT_ERR_PANIC -14
+
+/**
+ * Gateway or program refused the request
+ */
+#define DRIFT_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define DRIFT_ERR_NETWORK -20
+
+/**
+ * Gateway or its RPC answered 429
+ */
+#define DRIFT_ERR_RATE_LIMITED -21
+
+/**
+ * Gateway answered 5xx
+ */
+#define DRIFT_ERR_VENUE_ERROR -22
+
+/**
+ * Shares, price or flags not valid (checked locally)
+ */
+#define DRIFT_ERR_INVALID_ORDER -23
+
+/**
+ * Buy YES / sell NO
+ */
+#define DRIFT_SIDE_LONG 0
+
+/**
+ * Buy NO / sell YES
+ */
+#define DRIFT_SIDE_SHORT 1
+
+/**
+ * Limit only: cancelled instead of taking liquidity
+ */
+#define DRIFT_FLAG_POST_ONLY 1
+
+/**
+ * Only reduces the position held
+ */
+#define DRIFT_FLAG_REDUCE_ONLY 2
+
+/**
+ * Limit only: what does not fill at once is cancelled
+ */
+#define DRIFT_FLAG_IOC 4
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the transaction was sent
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Id to cancel the order with (1-255)
+   */
+  uint32_t user_order_id;
+  /**
+   * Base58 signature of the transaction sent
+   */
+  char tx_signature[96];
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} DriftOrderResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect to the Drift gateway at `gateway_url` (NULL for
+ * http://127.0.0.1:8080) trading `sub_account_id` of the wallet it signs
+ * with. Replaces a session already set up. Returns 0 on success, negative
+ * error code on failure
+ */
+int32_t drift_init(const char *gateway_url, uint16_t sub_account_id);
+
+/**
+ * Shut the session down; calls still running finish first
+ * drift_init() can be called again afterwards
+ */
+void drift_shutdown(void);
+
+/**
+ * Market order of `shares` YES shares on `side` (DRIFT_SIDE_*), filled
+ * through the program's auction no worse than `worst_price` (0: no bound)
+ * `flags`: DRIFT_FLAG_REDUCE_ONLY or 0
+ */
+int32_t drift_market_order(uint16_t market_index,
+                           int32_t side,
+                           double shares,
+                           double worst_price,
+                           uint32_t flags,
+                           DriftOrderResult *out);
+
+/**
+ * Limit order of `shares` YES shares on `side` at `price` (0-1 exclusive)
+ * `flags`: DRIFT_FLAG_* combined
+ */
+int32_t drift_limit_order(uint16_t market_index,
+                          int32_t side,
+                          double shares,
+                          double price,
+                          uint32_t flags,
+                          DriftOrderResult *out);
+
+/**
+ * Cancel an order by the user order id it was placed with
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t drift_cancel(uint32_t user_order_id);
+
+/**
+ * Cancel every order open in a market
+ */
+int32_t drift_cancel_market(uint16_t market_index);
+
+/**
+ * Cancel every order open on the sub-account
+ */
+int32_t drift_cancel_all(void);
+
+/**
+ * Free collateral in raw USDC (6 decimals), or negative error code
+ */
+int64_t drift_get_balance(void);
+
+/**
+ * Positions as JSON [{"marketIndex", "amount", "averageEntry",
+ * "settledPnl"}]; returns its size (drift_last_response()) or a negative
+ * error code
+ */
+int64_t drift_get_positions(void);
+
+/**
+ * Open orders as JSON; returns its size or a negative error code
+ */
+int64_t drift_get_open_orders(void);
+
+/**
+ * Copy the JSON result (or cancel signature) of the last query made from
+ * the calling thread. Returns the buffer size needed (see out_buf)
+ */
+size_t drift_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t drift_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DRIFT_FFI_GENERATED_H */
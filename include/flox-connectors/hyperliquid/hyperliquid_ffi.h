@@ -0,0 +1,192 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef HYPERLIQUID_FFI_H
+#define HYPERLIQUID_FFI_H
+
+#include "hyperliquid_ffi_generated.h"
+
+/*
+ * Hyperliquid perps executor (Rust)
+ *
+ * Orders (market, limit, reduce-only), cancels, margin, positions, open
+ * orders and L2 books of Hyperliquid perps, with the conventions of
+ * polymarket_ffi.h: one global session set up by hyperliquid_init(),
+ * blocking calls that may be made from any number of host threads,
+ * negative error codes numbered as POLYMARKET_ERR_* where the meaning is
+ * shared, raw amounts with 6 decimals, and thread-local last error / last
+ * response strings. It stands apart from HyperliquidOrderExecutor, which
+ * signs through an external signer process.
+ *
+ * Actions are signed in the library with the account's key or an API
+ * wallet's key. Coins are perp names ("BTC", "ETH"). Sizes are rounded to
+ * the coin's size decimals, prices to the nearest price the venue accepts
+ * (five significant figures, at most 6 - size decimals places).
+ *
+ * hyperliquid_init() and hyperliquid_shutdown() must not race with each
+ * other. Blocking calls must not be made from a thread owned by the
+ * executor runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants and structs are generated from the Rust definitions, with
+ * their documentation, in hyperliquid_ffi_generated.h. The declarations
+ * below are checked against the generated ones.
+ */
+
+  /**
+ * Connect and read the perp universe and the account. Replaces a session
+ * already set up.
+ *
+ * @param private_key      Hex private key signing the actions; the library
+ *                         keeps no copy of the string
+ * @param account_address  Account traded, NULL for the key's own address;
+ *                         set it when the key is an API wallet
+ * @param vault_address    Vault or subaccount to trade for, NULL for none
+ * @param testnet          Trade on testnet instead of mainnet
+ * @return HYPERLIQUID_OK, HYPERLIQUID_ERR_INVALID_KEY, or the error of
+ *         reading the account
+ */
+  int32_t hyperliquid_init(const char* private_key, const char* account_address,
+                           const char* vault_address, bool testnet);
+
+  /**
+ * Shut the session down and close the book feed; hyperliquid_init() can be
+ * called again afterwards.
+ */
+  void hyperliquid_shutdown(void);
+
+  /**
+ * Market order: immediate-or-cancel at the mid price moved by slippage, so
+ * it fills what the book holds up to that price and never rests.
+ *
+ * @param coin      Perp name, e.g. "ETH"
+ * @param is_buy    Buy (long) or sell (short)
+ * @param size      Coin units, > 0
+ * @param slippage  Worst price as a fraction of the mid (0.05 = 5%), 0 for 5%
+ * @param flags     HYPERLIQUID_FLAG_REDUCE_ONLY or 0
+ * @param out       Result, filled on success and failure
+ * @return HYPERLIQUID_OK or negative error code (also in out->error_code)
+ */
+  int32_t hyperliquid_market_order(const char* coin, bool is_buy, double size, double slippage,
+                                   uint32_t flags, HyperliquidOrderResult* out);
+
+  /**
+ * Limit order at price, resting until filled or cancelled unless
+ * HYPERLIQUID_FLAG_IOC is set.
+ *
+ * @param flags  HYPERLIQUID_FLAG_* combined; POST_ONLY and IOC exclude each
+ *               other
+ * Other parameters and return as hyperliquid_market_order().
+ */
+  int32_t hyperliquid_limit_order(const char* coin, bool is_buy, double size, double price,
+                                  uint32_t flags, HyperliquidOrderResult* out);
+
+  /**
+ * Cancel a resting order.
+ *
+ * @return HYPERLIQUID_OK or negative error code (HYPERLIQUID_ERR_REJECTED if
+ *         the order is no longer open)
+ */
+  int32_t hyperliquid_cancel(const char* coin, uint64_t oid);
+
+  /**
+ * Cancel every open order of coin, or of every coin if coin is NULL.
+ *
+ * @return Number of orders cancelled, or negative error code
+ */
+  int32_t hyperliquid_cancel_all(const char* coin);
+
+  /**
+ * USDC free to back new positions (withdrawable).
+ *
+ * @return Raw USDC (6 decimals), or negative error code
+ */
+  int64_t hyperliquid_get_balance(void);
+
+  /**
+ * Account value, unrealized PnL included. Same return as
+ * hyperliquid_get_balance().
+ */
+  int64_t hyperliquid_get_account_value(void);
+
+  /**
+ * Open positions as JSON: [{"coin", "size", "entry_price",
+ * "position_value", "unrealized_pnl", "liquidation_price", "margin_used"}].
+ * size is signed: positive long, negative short.
+ *
+ * @return Size of the JSON, read with hyperliquid_last_response(), or
+ *         negative error code
+ */
+  int64_t hyperliquid_get_positions(void);
+
+  /**
+ * Open orders as JSON: [{"coin", "side" ("B" bid, "A" ask), "price",
+ * "size", "oid", "timestamp"}]. Same return as hyperliquid_get_positions().
+ */
+  int64_t hyperliquid_get_open_orders(void);
+
+  /**
+ * Keep the L2 book of a coin from the WebSocket. The first subscription
+ * opens the connection; it reconnects on its own and closes on shutdown.
+ *
+ * @return HYPERLIQUID_OK, also if already subscribed
+ */
+  int32_t hyperliquid_subscribe_book(const char* coin);
+
+  /**
+ * Stop keeping a coin's book; the connection stays open.
+ *
+ * @return HYPERLIQUID_OK, HYPERLIQUID_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t hyperliquid_unsubscribe_book(const char* coin);
+
+  /**
+ * Top HYPERLIQUID_BOOK_DEPTH levels per side of a subscribed coin's book.
+ *
+ * @return HYPERLIQUID_OK, HYPERLIQUID_PENDING until the venue sent the book
+ *         (out untouched), HYPERLIQUID_ERR_INVALID_ARGUMENT if not
+ *         subscribed
+ */
+  int32_t hyperliquid_get_book_snapshot(const char* coin, HyperliquidBookSnapshot* out);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t hyperliquid_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the venue's own reason for refused actions.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t hyperliquid_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HYPERLIQUID_FFI_H */
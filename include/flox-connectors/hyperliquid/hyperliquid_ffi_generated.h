@@ -0,0 +1,288 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/hyperliquid/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in hyperliquid_ffi.h.
+ */
+
+#ifndef HYPERLIQUID_FFI_GENERATED_H
+#define HYPERLIQUID_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define HYPERLIQUID_OK 0
+
+/**
+ * Not an error: no data yet
+ */
+#define HYPERLIQUID_PENDING 1
+
+#define HYPERLIQUID_ERR_NOT_INITIALIZED -1
+
+/**
+ * Key or address malformed
+ */
+#define HYPERLIQUID_ERR_INVALID_KEY -2
+
+/**
+ * Not a listed perp
+ */
+#define HYPERLIQUID_ERR_INVALID_COIN -4
+
+#define HYPERLIQUID_ERR_ORDER_FAILED -5
+
+#define HYPERLIQUID_ERR_CANCEL_FAILED -6
+
+/**
+ * Not enough margin
+ */
+#define HYPERLIQUID_ERR_INSUFFICIENT_BALANCE -9
+
+#define HYPERLIQUID_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define HYPERLIQUID_ERR_PANIC -14
+
+/**
+ * Venue refused the action, see last error
+ */
+#define HYPERLIQUID_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define HYPERLIQUID_ERR_NETWORK -20
+
+/**
+ * Venue answered 429
+ */
+#define HYPERLIQUID_ERR_RATE_LIMITED -21
+
+/**
+ * Venue answered 5xx
+ */
+#define HYPERLIQUID_ERR_VENUE_ERROR -22
+
+/**
+ * Size, price or slippage not valid (checked locally)
+ */
+#define HYPERLIQUID_ERR_INVALID_ORDER -23
+
+/**
+ * Only reduces the position held
+ */
+#define HYPERLIQUID_FLAG_REDUCE_ONLY 1
+
+/**
+ * Limit only: cancelled instead of taking (ALO)
+ */
+#define HYPERLIQUID_FLAG_POST_ONLY 2
+
+/**
+ * Limit only: what does not fill at once is cancelled
+ */
+#define HYPERLIQUID_FLAG_IOC 4
+
+/**
+ * Levels per side in a HyperliquidBookSnapshot
+ */
+#define HYPERLIQUID_BOOK_DEPTH 10
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Venue order id
+   */
+  uint64_t oid;
+  /**
+   * "resting" or "filled"
+   */
+  char status[16];
+  /**
+   * Coin units filled (6 decimals)
+   */
+  int64_t filled_size_raw;
+  /**
+   * Average fill price (6 decimals), 0 if nothing filled
+   */
+  int64_t avg_price_raw;
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} HyperliquidOrderResult;
+
+typedef struct {
+  int64_t price_raw;
+  /**
+   * Coin units
+   */
+  int64_t size_raw;
+} HyperliquidBookLevel;
+
+/**
+ * Top levels of a subscribed coin's book, filled by
+ * hyperliquid_get_book_snapshot()
+ */
+typedef struct {
+  /**
+   * Levels set in bids, best (highest) first
+   */
+  uint32_t bid_count;
+  /**
+   * Levels set in asks, best (lowest) first
+   */
+  uint32_t ask_count;
+  /**
+   * Since the book was received
+   */
+  uint64_t age_ms;
+  /**
+   * Venue time of the book (ms since the epoch)
+   */
+  uint64_t time_ms;
+  HyperliquidBookLevel bids[HYPERLIQUID_BOOK_DEPTH];
+  HyperliquidBookLevel asks[HYPERLIQUID_BOOK_DEPTH];
+} HyperliquidBookSnapshot;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect with `private_key` (hex) on mainnet, or testnet if `testnet`
+ * `account_address`: account traded, NULL for the key's own; set it when
+ * the key is an API wallet. `vault_address`: vault or subaccount to trade
+ * for, NULL for none. Replaces a session already set up. Returns 0 on
+ * success, negative error code on failure
+ */
+int32_t hyperliquid_init(const char *private_key,
+                         const char *account_address,
+                         const char *vault_address,
+                         bool testnet);
+
+/**
+ * Shut the session down and close the book feed; calls still running
+ * finish first. hyperliquid_init() can be called again afterwards
+ */
+void hyperliquid_shutdown(void);
+
+/**
+ * Market order of `size` coin units: immediate-or-cancel at the mid moved
+ * by `slippage` (0.05 = 5%, 0 for the default 5%)
+ * `flags`: HYPERLIQUID_FLAG_REDUCE_ONLY or 0
+ */
+int32_t hyperliquid_market_order(const char *coin,
+                                 bool is_buy,
+                                 double size,
+                                 double slippage,
+                                 uint32_t flags,
+                                 HyperliquidOrderResult *out);
+
+/**
+ * Limit order of `size` coin units at `price`, rounded to the nearest
+ * price the venue accepts
+ * `flags`: HYPERLIQUID_FLAG_* combined; POST_ONLY and IOC exclude each other
+ */
+int32_t hyperliquid_limit_order(const char *coin,
+                                bool is_buy,
+                                double size,
+                                double price,
+                                uint32_t flags,
+                                HyperliquidOrderResult *out);
+
+/**
+ * Cancel a resting order of `coin` by its order id
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t hyperliquid_cancel(const char *coin, uint64_t oid);
+
+/**
+ * Cancel every open order of `coin`, or of every coin if NULL
+ * Returns the number cancelled, or negative error code
+ */
+int32_t hyperliquid_cancel_all(const char *coin);
+
+/**
+ * USDC free to back new positions (raw, 6 decimals), or negative error code
+ */
+int64_t hyperliquid_get_balance(void);
+
+/**
+ * Account value, unrealized PnL included (raw USDC, 6 decimals), or
+ * negative error code
+ */
+int64_t hyperliquid_get_account_value(void);
+
+/**
+ * Positions as JSON [{"coin", "size", "entry_price", "position_value",
+ * "unrealized_pnl", "liquidation_price", "margin_used"}]; returns its
+ * size (hyperliquid_last_response()) or a negative error code
+ */
+int64_t hyperliquid_get_positions(void);
+
+/**
+ * Open orders as JSON [{"coin", "side", "price", "size", "oid",
+ * "timestamp"}]; returns its size or a negative error code
+ */
+int64_t hyperliquid_get_open_orders(void);
+
+/**
+ * Keep the L2 book of a coin from the WebSocket
+ * The first subscription opens the connection; it reconnects on its own
+ * and closes on shutdown. Returns HYPERLIQUID_OK, also if already
+ * subscribed
+ */
+int32_t hyperliquid_subscribe_book(const char *coin);
+
+/**
+ * Stop keeping a coin's book; the connection stays open
+ * Returns HYPERLIQUID_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t hyperliquid_unsubscribe_book(const char *coin);
+
+/**
+ * Top HYPERLIQUID_BOOK_DEPTH levels per side of a subscribed coin's book
+ * Returns HYPERLIQUID_PENDING until the venue sent its book (`out`
+ * untouched), HYPERLIQUID_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t hyperliquid_get_book_snapshot(const char *coin, HyperliquidBookSnapshot *out);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t hyperliquid_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t hyperliquid_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HYPERLIQUID_FFI_GENERATED_H */
@@ -0,0 +1,205 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef IBKR_FFI_H
+#define IBKR_FFI_H
+
+#include "ibkr_ffi_generated.h"
+
+/*
+ * Interactive Brokers executor (Rust)
+ *
+ * Contract lookup, orders, cancels, open orders and positions, and the top
+ * of book of contracts, through a Client Portal gateway the host runs and
+ * has logged in, with the conventions of polymarket_ffi.h: one global
+ * session set up by ibkr_init(), blocking calls that may be made from any
+ * number of host threads, negative error codes numbered as POLYMARKET_ERR_*
+ * where the meaning is shared, raw amounts with 6 decimals, and
+ * thread-local last error / last response strings. The TWS socket API is
+ * not used.
+ *
+ * The gateway holds the brokerage login; no credentials pass through this
+ * library. A background task keeps the gateway session alive while the
+ * library is initialized. The gateway's self-signed certificate is
+ * accepted only when it listens on a loopback address.
+ *
+ * Contracts are named by their conid, found with ibkr_search_contracts()
+ * or, for futures of an index ("ES"), ibkr_get_futures(). Quantities are
+ * shares or contracts. Orders that the gateway answers with warnings are
+ * confirmed; orders are for regular trading hours.
+ *
+ * ibkr_init() and ibkr_shutdown() must not race with each other. Blocking
+ * calls must not be made from a thread owned by the executor runtime; they
+ * fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants and structs are generated from the Rust definitions, with
+ * their documentation, in ibkr_ffi_generated.h. The declarations below are
+ * checked against the generated ones.
+ */
+
+  /**
+ * Connect through the gateway and pick the account. Replaces a session
+ * already set up.
+ *
+ * @param gateway_url  e.g. "https://localhost:5000" (the default if NULL)
+ * @param account_id   e.g. "U1234567", or NULL for the gateway's selected
+ *                     account
+ * @return IBKR_OK or negative error code (IBKR_ERR_AUTH if the gateway is
+ *         not logged in)
+ */
+  int32_t ibkr_init(const char* gateway_url, const char* account_id);
+
+  /**
+ * Shut the session down and close the quote feed; the gateway stays
+ * logged in. ibkr_init() can be called again afterwards.
+ */
+  void ibkr_shutdown(void);
+
+  /**
+ * Market order, for the day.
+ *
+ * @param conid     Contract id
+ * @param is_buy    Buy or sell
+ * @param quantity  Shares or contracts, > 0
+ * @param out       Result, filled on success and failure
+ * @return IBKR_OK or negative error code (also in out->error_code)
+ */
+  int32_t ibkr_market_order(int64_t conid, bool is_buy, double quantity, IbkrOrderResult* out);
+
+  /**
+ * Limit order at price.
+ *
+ * @param flags  IBKR_FLAG_GTC or IBKR_FLAG_IOC, or 0 for the day
+ * Other parameters and return as ibkr_market_order().
+ */
+  int32_t ibkr_limit_order(int64_t conid, bool is_buy, double quantity, double price,
+                           uint32_t flags, IbkrOrderResult* out);
+
+  /**
+ * Cancel a working order.
+ *
+ * @param order_id  Venue order id, as in IbkrOrderResult
+ * @return IBKR_OK or negative error code (IBKR_ERR_REJECTED if the order
+ *         is no longer working)
+ */
+  int32_t ibkr_cancel(const char* order_id);
+
+  /**
+ * Cancel every working order of a contract, or of the account.
+ *
+ * @param conid  Contract id, or 0 for all
+ * @return Number of orders cancelled (0 if none was working), or negative
+ *         error code
+ */
+  int32_t ibkr_cancel_all(int64_t conid);
+
+  /**
+ * Position of the account in a contract.
+ *
+ * @param out_raw  Shares or contracts (6 decimals), negative if short, 0
+ *                 if none
+ * @return IBKR_OK or negative error code
+ */
+  int32_t ibkr_get_position(int64_t conid, int64_t* out_raw);
+
+  /**
+ * Non-zero positions as JSON: [{"conid", "description", "position",
+ * "market_price", "average_cost", "unrealized_pnl", "currency"}].
+ *
+ * @return Size of the JSON, read with ibkr_last_response(), or negative
+ *         error code
+ */
+  int64_t ibkr_get_positions(void);
+
+  /**
+ * Working orders as JSON: [{"order_id", "client_order_id", "conid",
+ * "symbol", "side", "order_type", "price", "quantity", "filled_quantity",
+ * "status"}]. Same return as ibkr_get_positions().
+ */
+  int64_t ibkr_get_open_orders(void);
+
+  /**
+ * Contracts of a symbol as JSON: [{"conid", "symbol", "name", "exchange",
+ * "sec_types"}]. Same return as ibkr_get_positions().
+ *
+ * @param symbol    e.g. "SPY"
+ * @param sec_type  e.g. "STK", "IND", or NULL for any
+ */
+  int64_t ibkr_search_contracts(const char* symbol, const char* sec_type);
+
+  /**
+ * Futures of an underlying as JSON: [{"conid", "symbol",
+ * "underlying_conid", "expiration_date", "last_trading_day"}], nearest
+ * expiry (the front month) first; dates are YYYYMMDD. Same return as
+ * ibkr_get_positions().
+ *
+ * @param symbol  e.g. "ES"
+ */
+  int64_t ibkr_get_futures(const char* symbol);
+
+  /**
+ * Keep the top of book of a contract from the gateway's stream. The first
+ * subscription opens the connection; it reconnects on its own and closes
+ * on shutdown. Needs a market data subscription for the contract.
+ *
+ * @return IBKR_OK, also if already subscribed
+ */
+  int32_t ibkr_subscribe_quote(int64_t conid);
+
+  /**
+ * Stop keeping a contract's quote; the connection stays open.
+ *
+ * @return IBKR_OK, IBKR_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t ibkr_unsubscribe_quote(int64_t conid);
+
+  /**
+ * Top of book of a subscribed contract.
+ *
+ * @return IBKR_OK, IBKR_PENDING until the gateway sent a quote (out
+ *         untouched), IBKR_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t ibkr_get_quote(int64_t conid, IbkrQuote* out);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t ibkr_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the gateway's own error for refused requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t ibkr_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* IBKR_FFI_H */
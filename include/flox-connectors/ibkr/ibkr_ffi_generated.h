@@ -0,0 +1,254 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/ibkr/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in ibkr_ffi.h.
+ */
+
+#ifndef IBKR_FFI_GENERATED_H
+#define IBKR_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define IBKR_OK 0
+
+/**
+ * Not an error: no data yet
+ */
+#define IBKR_PENDING 1
+
+#define IBKR_ERR_NOT_INITIALIZED -1
+
+/**
+ * Gateway not logged in, or session lost
+ */
+#define IBKR_ERR_AUTH -3
+
+/**
+ * Unknown conid or symbol
+ */
+#define IBKR_ERR_INVALID_CONTRACT -4
+
+#define IBKR_ERR_ORDER_FAILED -5
+
+#define IBKR_ERR_CANCEL_FAILED -6
+
+#define IBKR_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define IBKR_ERR_PANIC -14
+
+/**
+ * Venue refused the request, see last error
+ */
+#define IBKR_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define IBKR_ERR_NETWORK -20
+
+/**
+ * Gateway request limit exceeded
+ */
+#define IBKR_ERR_RATE_LIMITED -21
+
+/**
+ * Gateway unavailable or busy, order state unknown
+ */
+#define IBKR_ERR_VENUE_ERROR -22
+
+/**
+ * Quantity or price not valid
+ */
+#define IBKR_ERR_INVALID_ORDER -23
+
+/**
+ * Rests until filled or cancelled
+ */
+#define IBKR_FLAG_GTC 1
+
+/**
+ * What does not fill at once is cancelled
+ */
+#define IBKR_FLAG_IOC 2
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Venue order id
+   */
+  char order_id[32];
+  /**
+   * Our id of the order
+   */
+  char client_order_id[40];
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} IbkrOrderResult;
+
+/**
+ * Top of book of a subscribed contract, filled by ibkr_get_quote(); raw
+ * values in 6 decimals (divide by 1M for actual). Sizes are as the venue
+ * streams them: shares, lots of 100 shares for some US stocks, or
+ * contracts
+ */
+typedef struct {
+  /**
+   * Last trade price
+   */
+  int64_t last_raw;
+  int64_t bid_raw;
+  int64_t bid_size_raw;
+  int64_t ask_raw;
+  int64_t ask_size_raw;
+  /**
+   * Since the last update was received
+   */
+  uint64_t age_ms;
+} IbkrQuote;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect through a Client Portal gateway the host runs and has logged in
+ * `gateway_url`: "https://localhost:5000" if NULL; `account_id`: the
+ * gateway's selected account if NULL
+ * Replaces a session already set up. Returns 0 on success, negative error
+ * code on failure
+ */
+int32_t ibkr_init(const char *gateway_url, const char *account_id);
+
+/**
+ * Shut the session down and close the quote feed; calls still running
+ * finish first. The gateway stays logged in. ibkr_init() can be called
+ * again afterwards
+ */
+void ibkr_shutdown(void);
+
+/**
+ * Market order of `quantity` shares or contracts of `conid`, for the day
+ */
+int32_t ibkr_market_order(int64_t conid, bool is_buy, double quantity, IbkrOrderResult *out);
+
+/**
+ * Limit order of `quantity` shares or contracts of `conid` at `price`
+ * `flags`: IBKR_FLAG_GTC or IBKR_FLAG_IOC, or 0 for the day
+ */
+int32_t ibkr_limit_order(int64_t conid,
+                         bool is_buy,
+                         double quantity,
+                         double price,
+                         uint32_t flags,
+                         IbkrOrderResult *out);
+
+/**
+ * Cancel a working order by its venue order id
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t ibkr_cancel(const char *order_id);
+
+/**
+ * Cancel every working order of `conid`, or of the account if 0
+ * Returns the number cancelled (0 if none was working), or negative error
+ * code
+ */
+int32_t ibkr_cancel_all(int64_t conid);
+
+/**
+ * Position in `conid` into `out_raw` (raw, 6 decimals; negative if
+ * short), 0 if none
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t ibkr_get_position(int64_t conid, int64_t *out_raw);
+
+/**
+ * Non-zero positions as JSON [{"conid", "description", "position",
+ * "market_price", "average_cost", "unrealized_pnl", "currency"}]; returns
+ * its size (ibkr_last_response()) or a negative error code
+ */
+int64_t ibkr_get_positions(void);
+
+/**
+ * Working orders as JSON [{"order_id", "client_order_id", "conid",
+ * "symbol", "side", "order_type", "price", "quantity", "filled_quantity",
+ * "status"}]; returns its size or a negative error code
+ */
+int64_t ibkr_get_open_orders(void);
+
+/**
+ * Contracts of `symbol` as JSON [{"conid", "symbol", "name", "exchange",
+ * "sec_types"}], of security type `sec_type` ("STK", "IND") unless NULL;
+ * returns its size or a negative error code
+ */
+int64_t ibkr_search_contracts(const char *symbol, const char *sec_type);
+
+/**
+ * Futures of the underlying `symbol` ("ES") as JSON [{"conid", "symbol",
+ * "underlying_conid", "expiration_date", "last_trading_day"}], nearest
+ * expiry first; returns its size or a negative error code
+ */
+int64_t ibkr_get_futures(const char *symbol);
+
+/**
+ * Keep the top of book of a contract from the gateway's stream
+ * The first subscription opens the connection; it reconnects on its own
+ * and closes on shutdown. Returns IBKR_OK, also if already subscribed
+ */
+int32_t ibkr_subscribe_quote(int64_t conid);
+
+/**
+ * Stop keeping a contract's quote; the connection stays open
+ * Returns IBKR_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t ibkr_unsubscribe_quote(int64_t conid);
+
+/**
+ * Top of book of a subscribed contract
+ * Returns IBKR_PENDING until the gateway sent a quote (`out` untouched),
+ * IBKR_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t ibkr_get_quote(int64_t conid, IbkrQuote *out);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t ibkr_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t ibkr_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* IBKR_FFI_GENERATED_H */
@@ -0,0 +1,156 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef KALSHI_FFI_H
+#define KALSHI_FFI_H
+
+#include "kalshi_ffi_generated.h"
+
+/*
+ * Kalshi order executor
+ *
+ * Orders, cancels, the cash balance and positions on Kalshi's trade API,
+ * with the conventions of polymarket_ffi.h: one global session set up by
+ * kalshi_init(), blocking calls that may be made from any number of host
+ * threads, negative error codes numbered as POLYMARKET_ERR_* where the
+ * meaning is shared, and thread-local last error / last response strings.
+ *
+ * Requests are signed with the account's API key (RSA-PSS over timestamp,
+ * method and path). Prices are in cents (1-99) per contract, sizes in whole
+ * contracts; a contract pays 100 cents if its side resolves true.
+ *
+ * kalshi_init() and kalshi_shutdown() must not race with each other.
+ * Blocking calls must not be made from a thread owned by the executor
+ * runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants and structs are generated from the Rust definitions, with
+ * their documentation, in kalshi_ffi_generated.h. The declarations below
+ * are checked against the generated ones.
+ */
+
+  /**
+ * Connect with an API key. Replaces a session already set up.
+ *
+ * @param api_key_id       API key id
+ * @param private_key_pem  PEM private key of the API key (PKCS#8 or PKCS#1);
+ *                         the library keeps no copy of the string
+ * @param base_url         Trade API root, NULL for production
+ *                         (https://api.elections.kalshi.com/trade-api/v2);
+ *                         https://demo-api.kalshi.co/trade-api/v2 for demo
+ * @return KALSHI_OK, KALSHI_ERR_INVALID_KEY, or KALSHI_ERR_AUTH_FAILED if
+ *         the venue refused the key (checked by reading the balance)
+ */
+  int32_t kalshi_init(const char* api_key_id, const char* private_key_pem,
+                      const char* base_url);
+
+  /**
+ * Shut the session down; kalshi_init() can be called again afterwards.
+ */
+  void kalshi_shutdown(void);
+
+  /**
+ * Buy up to count contracts of side at once; what does not fill right away
+ * is cancelled (immediate-or-cancel).
+ *
+ * @param ticker           Market ticker, e.g. "KXBTCD-25DEC31-T100000"
+ * @param side             KALSHI_SIDE_YES or KALSHI_SIDE_NO
+ * @param count            Contracts, at least 1
+ * @param max_price_cents  Highest price paid per contract, 0 for up to 99
+ * @param out              Result, filled on success and failure
+ * @return KALSHI_OK or negative error code (also in out->error_code)
+ */
+  int32_t kalshi_market_buy(const char* ticker, int32_t side, int64_t count,
+                            uint32_t max_price_cents, KalshiOrderResult* out);
+
+  /**
+ * Sell up to count contracts of side at once; the rest is cancelled.
+ *
+ * @param min_price_cents  Lowest price taken per contract, 0 for down to 1
+ * Other parameters and return as kalshi_market_buy().
+ */
+  int32_t kalshi_market_sell(const char* ticker, int32_t side, int64_t count,
+                             uint32_t min_price_cents, KalshiOrderResult* out);
+
+  /**
+ * Limit buy of count contracts of side at price_cents (1-99); the part not
+ * filled rests on the book until filled or cancelled.
+ * Parameters and return as kalshi_market_buy().
+ */
+  int32_t kalshi_limit_buy(const char* ticker, int32_t side, int64_t count,
+                           uint32_t price_cents, KalshiOrderResult* out);
+
+  /**
+ * Limit sell of count contracts of side at price_cents (1-99).
+ * Parameters and return as kalshi_market_buy().
+ */
+  int32_t kalshi_limit_sell(const char* ticker, int32_t side, int64_t count,
+                            uint32_t price_cents, KalshiOrderResult* out);
+
+  /**
+ * Cancel a resting order.
+ *
+ * @param order_id  Venue order id (KalshiOrderResult.order_id)
+ * @return KALSHI_OK or negative error code
+ */
+  int32_t kalshi_cancel(const char* order_id);
+
+  /**
+ * Cash balance of the account.
+ *
+ * @return Balance in cents, or negative error code
+ */
+  int64_t kalshi_get_balance(void);
+
+  /**
+ * Positions of the account, as JSON:
+ * [{"ticker", "position", "market_exposure", "realized_pnl", "fees_paid",
+ *   "resting_orders_count"}]. position is signed: positive for YES
+ * contracts, negative for NO; money fields are in cents.
+ *
+ * @return Size of the JSON, read with kalshi_last_response(), or negative
+ *         error code
+ */
+  int64_t kalshi_get_positions(void);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t kalshi_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the venue's own message for refused requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t kalshi_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* KALSHI_FFI_H */
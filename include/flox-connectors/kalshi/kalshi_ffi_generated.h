@@ -0,0 +1,213 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/kalshi/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in kalshi_ffi.h.
+ */
+
+#ifndef KALSHI_FFI_GENERATED_H
+#define KALSHI_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define KALSHI_OK 0
+
+#define KALSHI_ERR_NOT_INITIALIZED -1
+
+/**
+ * Key id or PEM private key malformed
+ */
+#define KALSHI_ERR_INVALID_KEY -2
+
+/**
+ * Venue refused the key (401/403)
+ */
+#define KALSHI_ERR_AUTH_FAILED -3
+
+#define KALSHI_ERR_INVALID_TICKER -4
+
+#define KALSHI_ERR_ORDER_FAILED -5
+
+#define KALSHI_ERR_CANCEL_FAILED -6
+
+#define KALSHI_ERR_INSUFFICIENT_BALANCE -9
+
+#define KALSHI_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define KALSHI_ERR_PANIC -14
+
+/**
+ * Venue refused the request (4xx)
+ */
+#define KALSHI_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define KALSHI_ERR_NETWORK -20
+
+/**
+ * Venue answered 429
+ */
+#define KALSHI_ERR_RATE_LIMITED -21
+
+/**
+ * Venue answered 5xx
+ */
+#define KALSHI_ERR_VENUE_ERROR -22
+
+/**
+ * Count or price not valid (checked locally)
+ */
+#define KALSHI_ERR_INVALID_ORDER -23
+
+#define KALSHI_SIDE_YES 0
+
+#define KALSHI_SIDE_NO 1
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Venue order id
+   */
+  char order_id[64];
+  /**
+   * "resting", "executed" or "canceled"
+   */
+  char status[16];
+  /**
+   * Contracts filled
+   */
+  int64_t filled_count;
+  /**
+   * Contracts left resting on the book
+   */
+  int64_t remaining_count;
+  /**
+   * Cost of the filled contracts, fees excluded
+   */
+  int64_t fill_cost_cents;
+  /**
+   * Fees paid on the fills
+   */
+  int64_t fees_cents;
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} KalshiOrderResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect with the API key `api_key_id` and its PEM private key (PKCS#8
+ * or PKCS#1) against `base_url` (NULL for production)
+ * Replaces a session already set up. Returns 0 on success, negative error
+ * code on failure
+ */
+int32_t kalshi_init(const char *api_key_id, const char *private_key_pem, const char *base_url);
+
+/**
+ * Shut the session down; calls still running finish first
+ * kalshi_init() can be called again afterwards
+ */
+void kalshi_shutdown(void);
+
+/**
+ * Buy up to `count` contracts of `side` (KALSHI_SIDE_*) at once, paying at
+ * most `max_price_cents` each (0: up to 99); the rest is cancelled
+ */
+int32_t kalshi_market_buy(const char *ticker,
+                          int32_t side,
+                          int64_t count,
+                          uint32_t max_price_cents,
+                          KalshiOrderResult *out);
+
+/**
+ * Sell up to `count` contracts of `side` at once, for at least
+ * `min_price_cents` each (0: down to 1); the rest is cancelled
+ */
+int32_t kalshi_market_sell(const char *ticker,
+                           int32_t side,
+                           int64_t count,
+                           uint32_t min_price_cents,
+                           KalshiOrderResult *out);
+
+/**
+ * Limit buy of `count` contracts of `side` at `price_cents` (1-99), resting
+ * until filled or cancelled
+ */
+int32_t kalshi_limit_buy(const char *ticker,
+                         int32_t side,
+                         int64_t count,
+                         uint32_t price_cents,
+                         KalshiOrderResult *out);
+
+/**
+ * Limit sell of `count` contracts of `side` at `price_cents` (1-99)
+ */
+int32_t kalshi_limit_sell(const char *ticker,
+                          int32_t side,
+                          int64_t count,
+                          uint32_t price_cents,
+                          KalshiOrderResult *out);
+
+/**
+ * Cancel a resting order by id
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t kalshi_cancel(const char *order_id);
+
+/**
+ * Cash balance in cents, or negative error code
+ */
+int64_t kalshi_get_balance(void);
+
+/**
+ * Positions as JSON [{"ticker", "position", "market_exposure",
+ * "realized_pnl", "fees_paid", "resting_orders_count"}]; returns its size
+ * (kalshi_last_response()) or a negative error code
+ */
+int64_t kalshi_get_positions(void);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t kalshi_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t kalshi_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* KALSHI_FFI_GENERATED_H */
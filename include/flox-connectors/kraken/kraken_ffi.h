@@ -0,0 +1,198 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#ifndef KRAKEN_FFI_H
+#define KRAKEN_FFI_H
+
+#include "kraken_ffi_generated.h"
+
+/*
+ * Kraken spot executor (Rust)
+ *
+ * Orders, cancels, balances and open orders over the REST API, L2 books
+ * from the WebSocket v2 book channel and the account's fills from the
+ * WebSocket v2 executions channel, with the conventions of
+ * polymarket_ffi.h: one global session set up by kraken_init(), blocking
+ * calls that may be made from any number of host threads, negative error
+ * codes numbered as POLYMARKET_ERR_* where the meaning is shared, raw
+ * amounts with 6 decimals, and thread-local last error / last response
+ * strings.
+ *
+ * Pairs and assets are written as WebSocket v2 names them ("BTC/USD",
+ * "USDC/EUR", "BTC"), not with the X/Z prefixed REST names. Volumes are
+ * base asset units, rounded down to the pair's lot decimals; prices are
+ * rounded to its price decimals.
+ *
+ * kraken_init() and kraken_shutdown() must not race with each other.
+ * Blocking calls must not be made from a thread owned by the executor
+ * runtime; they fail with an error code instead of deadlocking it.
+ */
+
+/*
+ * String outputs
+ *
+ * Functions returning strings take a caller buffer and its length and
+ * return the size they need (bytes including the NUL terminator). The
+ * string is written only if it fits - never truncated. Pass NULL/0 to
+ * query the size, then call again with a large enough buffer.
+ */
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+/*
+ * Constants, structs and callback types are generated from the Rust
+ * definitions, with their documentation, in kraken_ffi_generated.h. The
+ * declarations below are checked against the generated ones.
+ */
+
+  /**
+ * Connect and check the key by reading the balances. Replaces a session
+ * already set up.
+ *
+ * @param api_key      API key, with the query and trade permissions
+ * @param private_key  Its private key (base64); the library keeps no copy
+ *                     of the string
+ * @return KRAKEN_OK or negative error code (KRAKEN_ERR_AUTH if the key is
+ *         refused)
+ */
+  int32_t kraken_init(const char* api_key, const char* private_key);
+
+  /**
+ * Shut the session down and close both feeds; kraken_init() can be called
+ * again afterwards.
+ */
+  void kraken_shutdown(void);
+
+  /**
+ * Market order.
+ *
+ * @param pair    e.g. "BTC/USD"
+ * @param is_buy  Buy or sell
+ * @param volume  Base asset units, > 0
+ * @param out     Result, filled on success and failure
+ * @return KRAKEN_OK or negative error code (also in out->error_code)
+ */
+  int32_t kraken_market_order(const char* pair, bool is_buy, double volume,
+                              KrakenOrderResult* out);
+
+  /**
+ * Limit order at price, resting until filled or cancelled unless
+ * KRAKEN_FLAG_IOC is set.
+ *
+ * @param flags  KRAKEN_FLAG_POST_ONLY or KRAKEN_FLAG_IOC, or 0
+ * Other parameters and return as kraken_market_order().
+ */
+  int32_t kraken_limit_order(const char* pair, bool is_buy, double volume, double price,
+                             uint32_t flags, KrakenOrderResult* out);
+
+  /**
+ * Cancel an open order.
+ *
+ * @return KRAKEN_OK or negative error code (KRAKEN_ERR_REJECTED if the
+ *         order is no longer open)
+ */
+  int32_t kraken_cancel(const char* txid);
+
+  /**
+ * Cancel every open order of the account.
+ *
+ * @return Number of orders cancelled (0 if none was open), or negative
+ *         error code
+ */
+  int32_t kraken_cancel_all(void);
+
+  /**
+ * Amount of an asset not held by open orders.
+ *
+ * @param asset  e.g. "USD", "BTC"
+ * @return Raw amount (6 decimals), 0 if none held, or negative error code
+ */
+  int64_t kraken_get_balance(const char* asset);
+
+  /**
+ * Non-zero balances as JSON: [{"asset", "free", "total"}].
+ *
+ * @return Size of the JSON, read with kraken_last_response(), or negative
+ *         error code
+ */
+  int64_t kraken_get_balances(void);
+
+  /**
+ * Open orders as JSON: [{"txid", "pair", "side", "order_type", "price",
+ * "volume", "volume_executed", "status", "open_time_ms"}]. Same return as
+ * kraken_get_balances().
+ */
+  int64_t kraken_get_open_orders(void);
+
+  /**
+ * Keep the L2 book of a pair from the WebSocket. The first subscription
+ * opens the connection; it reconnects on its own and closes on shutdown.
+ * The venue's checksums are not verified.
+ *
+ * @return KRAKEN_OK, also if already subscribed
+ */
+  int32_t kraken_subscribe_book(const char* pair);
+
+  /**
+ * Stop keeping a pair's book; the connection stays open.
+ *
+ * @return KRAKEN_OK, KRAKEN_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t kraken_unsubscribe_book(const char* pair);
+
+  /**
+ * Top KRAKEN_BOOK_DEPTH levels per side of a subscribed pair's book.
+ *
+ * @return KRAKEN_OK, KRAKEN_PENDING until the venue sent the snapshot (out
+ *         untouched), KRAKEN_ERR_INVALID_ARGUMENT if not subscribed
+ */
+  int32_t kraken_get_book_snapshot(const char* pair, KrakenBookSnapshot* out);
+
+  /**
+ * Stream the account's fills to callback, replacing the callback set
+ * before. The feed reconnects on its own and closes on shutdown; fills
+ * made while disconnected are not replayed.
+ *
+ * The callback runs on a runtime worker thread, one fill at a time; it
+ * must not block or call back into the library.
+ *
+ * @return KRAKEN_OK or negative error code
+ */
+  int32_t kraken_subscribe_fills(KrakenFillCallback callback, void* user_data);
+
+  /**
+ * Close the executions feed. Once this returns, the callback is not
+ * invoked again. No-op if not subscribed.
+ */
+  int32_t kraken_unsubscribe_fills(void);
+
+  /**
+ * Copy the JSON result of the last query made from the calling thread.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t kraken_last_response(char* buf, size_t len);
+
+  /**
+ * Copy the error message of the last failed call from the calling thread:
+ * the venue's own error ("EOrder:Insufficient funds") for refused
+ * requests.
+ *
+ * @return Buffer size needed (see String outputs)
+ */
+  size_t kraken_last_error_message(char* buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* KRAKEN_FFI_H */
@@ -0,0 +1,315 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/kraken/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in kraken_ffi.h.
+ */
+
+#ifndef KRAKEN_FFI_GENERATED_H
+#define KRAKEN_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define KRAKEN_OK 0
+
+/**
+ * Not an error: no data yet
+ */
+#define KRAKEN_PENDING 1
+
+#define KRAKEN_ERR_NOT_INITIALIZED -1
+
+/**
+ * Key or private key malformed
+ */
+#define KRAKEN_ERR_INVALID_KEY -2
+
+/**
+ * Key refused, or lacks the permission
+ */
+#define KRAKEN_ERR_AUTH -3
+
+/**
+ * Not a tradable pair
+ */
+#define KRAKEN_ERR_INVALID_PAIR -4
+
+#define KRAKEN_ERR_ORDER_FAILED -5
+
+#define KRAKEN_ERR_CANCEL_FAILED -6
+
+/**
+ * Not enough funds or margin
+ */
+#define KRAKEN_ERR_INSUFFICIENT_BALANCE -9
+
+#define KRAKEN_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Internal panic
+ */
+#define KRAKEN_ERR_PANIC -14
+
+/**
+ * Venue refused the request, see last error
+ */
+#define KRAKEN_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define KRAKEN_ERR_NETWORK -20
+
+/**
+ * Rate or request limit exceeded
+ */
+#define KRAKEN_ERR_RATE_LIMITED -21
+
+/**
+ * Venue unavailable or busy, order state unknown
+ */
+#define KRAKEN_ERR_VENUE_ERROR -22
+
+/**
+ * Volume below the minimum, or price not valid
+ */
+#define KRAKEN_ERR_INVALID_ORDER -23
+
+/**
+ * Cancelled instead of taking
+ */
+#define KRAKEN_FLAG_POST_ONLY 1
+
+/**
+ * What does not fill at once is cancelled
+ */
+#define KRAKEN_FLAG_IOC 2
+
+/**
+ * Levels per side in a KrakenBookSnapshot
+ */
+#define KRAKEN_BOOK_DEPTH 10
+
+/**
+ * Order result, filled by the order entry points
+ */
+typedef struct {
+  /**
+   * True if the venue accepted the order
+   */
+  bool success;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Transaction id of the order ("OU22CG-KLAF2-FWUDD7")
+   */
+  char txid[32];
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+} KrakenOrderResult;
+
+typedef struct {
+  int64_t price_raw;
+  /**
+   * Base asset units
+   */
+  int64_t size_raw;
+} KrakenBookLevel;
+
+/**
+ * Top levels of a subscribed pair's book, filled by
+ * kraken_get_book_snapshot()
+ */
+typedef struct {
+  /**
+   * Levels set in bids, best (highest) first
+   */
+  uint32_t bid_count;
+  /**
+   * Levels set in asks, best (lowest) first
+   */
+  uint32_t ask_count;
+  /**
+   * Since the last message for the book was received
+   */
+  uint64_t age_ms;
+  /**
+   * Venue time of the last update (ms since the epoch), 0 until one came
+   */
+  uint64_t time_ms;
+  KrakenBookLevel bids[KRAKEN_BOOK_DEPTH];
+  KrakenBookLevel asks[KRAKEN_BOOK_DEPTH];
+} KrakenBookSnapshot;
+
+/**
+ * Fill of an order of the account, passed to KrakenFillCallback; raw
+ * values in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  bool is_buy;
+  /**
+   * Our order was resting on the book
+   */
+  bool maker;
+  uint64_t trade_id;
+  int64_t price_raw;
+  /**
+   * Volume of this fill
+   */
+  int64_t volume_raw;
+  /**
+   * Volume of the order filled so far
+   */
+  int64_t cum_volume_raw;
+  int64_t fee_raw;
+  /**
+   * Venue time of the fill
+   */
+  uint64_t time_ms;
+  /**
+   * Transaction id of the order
+   */
+  char order_id[32];
+  char exec_id[32];
+  char pair[32];
+  char fee_asset[16];
+} KrakenFill;
+
+/**
+ * Fill callback of kraken_subscribe_fills(); `fill` is only valid during
+ * the call, `user_data` is as passed when subscribing
+ */
+typedef void (*KrakenFillCallback)(const KrakenFill *fill, void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Connect with an API key and its private key (base64), checked by
+ * reading the balances; the key needs the query and trade permissions.
+ * Replaces a session already set up. Returns 0 on success, negative error
+ * code on failure
+ */
+int32_t kraken_init(const char *api_key, const char *private_key);
+
+/**
+ * Shut the session down and close both feeds; calls still running finish
+ * first. kraken_init() can be called again afterwards
+ */
+void kraken_shutdown(void);
+
+/**
+ * Market order of `volume` base asset units, rounded down to the pair's
+ * lot decimals
+ */
+int32_t kraken_market_order(const char *pair, bool is_buy, double volume, KrakenOrderResult *out);
+
+/**
+ * Limit order of `volume` base asset units at `price`, rounded to the
+ * pair's price decimals
+ * `flags`: KRAKEN_FLAG_POST_ONLY or KRAKEN_FLAG_IOC, or 0 for good till
+ * cancelled
+ */
+int32_t kraken_limit_order(const char *pair,
+                           bool is_buy,
+                           double volume,
+                           double price,
+                           uint32_t flags,
+                           KrakenOrderResult *out);
+
+/**
+ * Cancel an open order by transaction id
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t kraken_cancel(const char *txid);
+
+/**
+ * Cancel every open order of the account
+ * Returns the number cancelled (0 if none was open), or negative error code
+ */
+int32_t kraken_cancel_all(void);
+
+/**
+ * Amount of `asset` ("USD", "BTC") not held by open orders (raw, 6
+ * decimals), 0 if none held, or negative error code
+ */
+int64_t kraken_get_balance(const char *asset);
+
+/**
+ * Non-zero balances as JSON [{"asset", "free", "total"}]; returns its size
+ * (kraken_last_response()) or a negative error code
+ */
+int64_t kraken_get_balances(void);
+
+/**
+ * Open orders as JSON [{"txid", "pair", "side", "order_type", "price",
+ * "volume", "volume_executed", "status", "open_time_ms"}]; returns its
+ * size or a negative error code
+ */
+int64_t kraken_get_open_orders(void);
+
+/**
+ * Stream the account's fills to `callback`, replacing the callback set
+ * before. Fills made while disconnected are not replayed
+ * The callback runs on a runtime worker thread, one fill at a time; it
+ * must not block or call back into the library
+ */
+int32_t kraken_subscribe_fills(KrakenFillCallback callback, void *user_data);
+
+/**
+ * Close the executions feed. Once this returns the callback is not
+ * invoked again. No-op if not subscribed
+ */
+int32_t kraken_unsubscribe_fills(void);
+
+/**
+ * Keep the L2 book of a pair ("BTC/USD") from the WebSocket
+ * The first subscription opens the connection; it reconnects on its own
+ * and closes on shutdown. Returns KRAKEN_OK, also if already subscribed
+ */
+int32_t kraken_subscribe_book(const char *pair);
+
+/**
+ * Stop keeping a pair's book; the connection stays open
+ * Returns KRAKEN_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t kraken_unsubscribe_book(const char *pair);
+
+/**
+ * Top KRAKEN_BOOK_DEPTH levels per side of a subscribed pair's book
+ * Returns KRAKEN_PENDING until the venue sent its snapshot (`out`
+ * untouched), KRAKEN_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t kraken_get_book_snapshot(const char *pair, KrakenBookSnapshot *out);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * Returns the buffer size needed (see out_buf)
+ */
+size_t kraken_last_response(char *buf, size_t len);
+
+/**
+ * Copy the error message of the last failed call from the calling thread
+ */
+size_t kraken_last_error_message(char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* KRAKEN_FFI_GENERATED_H */
@@ -22,6 +22,7 @@
 #include <simdjson.h>
 
 #include <atomic>
+#include <functional>
 #include <memory>
 #include <string>
 #include <unordered_map>
@@ -43,9 +44,20 @@ class PolymarketExchangeConnector : public IExchangeConnector
 
   SymbolId resolveSymbolId(std::string_view tokenId);
 
+  /// Called on the WebSocket thread when a market's tick size changes
+  using TickSizeChangeHandler = std::function<void(SymbolId symbol, std::string_view tokenId,
+                                                   double oldTick, double newTick)>;
+
+  /// Set before start(); resting orders priced off the new grid need re-quoting
+  void setTickSizeChangeHandler(TickSizeChangeHandler handler)
+  {
+    _onTickSizeChange = std::move(handler);
+  }
+
  private:
   void handleMessage(std::string_view payload);
   void processBookSnapshot(simdjson::ondemand::object obj, uint64_t recvNs);
+  void processTickSizeChange(simdjson::ondemand::object obj);
   void sendSubscribe(const std::vector<std::string>& tokenIds, const std::string& operation);
 
   PolymarketConfig _config;
@@ -62,6 +74,8 @@ class PolymarketExchangeConnector : public IExchangeConnector
   pool::Pool<BookUpdateEvent, config::DEFAULT_CONNECTOR_POOL_CAPACITY> _bookPool;
 
   std::unordered_map<std::string, SymbolId> _tokenToSymbol;
+
+  TickSizeChangeHandler _onTickSizeChange;
 };
 
 }  // namespace flox
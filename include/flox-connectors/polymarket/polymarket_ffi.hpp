@@ -0,0 +1,2336 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+#pragma once
+
+/**
+ * Header-only C++ wrapper over the Polymarket C ABI (polymarket_ffi.h).
+ *
+ * - RAII: Session owns the executor and shuts it down on destruction
+ * - std::string_view overloads for all string inputs
+ * - No exceptions: fallible calls return std::expected<T, polymarket::Error>
+ *
+ * Raw 6-decimal units are kept as-is; use the flox PolymarketOrderExecutor
+ * if you want Price/Quantity types instead.
+ */
+
+#include "polymarket_ffi.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <expected>
+#include <optional>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+namespace polymarket
+{
+
+/// Error code returned by the C ABI
+struct Error
+{
+  int32_t code{POLYMARKET_OK};
+  /// Message behind the code for order and cancel calls, e.g. the CLOB's
+  /// own error string; may be empty (see polymarket_last_error_message)
+  std::string detail{};
+
+  bool ok() const { return code == POLYMARKET_OK; }
+
+  const char* message() const
+  {
+    switch (code)
+    {
+      case POLYMARKET_OK:
+        return "OK";
+      case POLYMARKET_ERR_NOT_INITIALIZED:
+        return "Executor not initialized";
+      case POLYMARKET_ERR_INVALID_PK:
+        return "Invalid private key";
+      case POLYMARKET_ERR_AUTH_FAILED:
+        return "Authentication failed";
+      case POLYMARKET_ERR_INVALID_TOKEN:
+        return "Invalid token ID";
+      case POLYMARKET_ERR_ORDER_FAILED:
+        return "Order failed (check API response)";
+      case POLYMARKET_ERR_CANCEL_FAILED:
+        return "Cancel failed";
+      case POLYMARKET_ERR_MIN_ORDER_SIZE:
+        return "Order size below minimum ($1)";
+      case POLYMARKET_ERR_MIN_SHARES:
+        return "Shares below market minimum (call prefetch first)";
+      case POLYMARKET_ERR_INSUFFICIENT_BALANCE:
+        return "Insufficient USDC balance (cached balance minus buffer)";
+      case POLYMARKET_ERR_INVALID_CONFIG:
+        return "Invalid configuration";
+      case POLYMARKET_ERR_RISK_LIMIT:
+        return "Order rejected by risk limits";
+      case POLYMARKET_ERR_INVALID_ARGUMENT:
+        return "Invalid argument";
+      case POLYMARKET_ERR_ABORTED:
+        return "Aborted locally, venue state unknown";
+      case POLYMARKET_ERR_PANIC:
+        return "Internal panic (see polymarket_last_panic)";
+      case POLYMARKET_ERR_READ_ONLY:
+        return "Blocked by read-only mode";
+      case POLYMARKET_ERR_TICK_SIZE:
+        return "Price not on the market's tick size";
+      case POLYMARKET_ERR_LIQUIDITY:
+        return "Not enough liquidity within the price cap";
+      case POLYMARKET_ERR_VENUE_UNAVAILABLE:
+        return "Venue unavailable, orders paused";
+      case POLYMARKET_ERR_REJECTED:
+        return "Rejected by the venue (see detail)";
+      case POLYMARKET_ERR_NETWORK:
+        return "Network error or timeout, venue state unknown";
+      case POLYMARKET_ERR_RATE_LIMITED:
+        return "Rate limited by the venue or the local budget";
+      case POLYMARKET_ERR_VENUE_ERROR:
+        return "Venue server error";
+      case POLYMARKET_ERR_INVALID_ORDER:
+        return "Invalid amount, price or expiry";
+      case POLYMARKET_ERR_SLIPPAGE:
+        return "Expected slippage over the cap";
+      case POLYMARKET_ERR_DUPLICATE_ORDER:
+        return "Client order id already in use";
+      case POLYMARKET_ERR_USDC_ALLOWANCE:
+        return "USDC allowance not set for the exchange";
+      case POLYMARKET_ERR_TOKEN_APPROVAL:
+        return "Outcome token approval not set for the exchange";
+      case POLYMARKET_ERR_HALTED:
+        return "Trading halted";
+      default:
+        return "Unknown error";
+    }
+  }
+};
+
+template <typename T>
+using Result = std::expected<T, Error>;
+
+/// Order result with the order id as std::string
+struct OrderResult
+{
+  bool success{false};
+  int64_t filledQtyRaw{0};
+  int64_t avgPriceRaw{0};
+  uint64_t latencyMs{0};
+  std::string orderId;
+  int64_t remainingQtyRaw{0};
+  int64_t feeRaw{0};
+  /// Id passed with the order, empty if none
+  std::string clientOrderId;
+};
+
+/// Book levels per side, best first
+struct OrderBook
+{
+  std::vector<::PolymarketBookLevel> bids;
+  std::vector<::PolymarketBookLevel> asks;
+};
+
+namespace detail
+{
+
+inline Result<void> check(int32_t code)
+{
+  if (code != POLYMARKET_OK)
+  {
+    return std::unexpected(Error{code});
+  }
+  return {};
+}
+
+inline Result<int64_t> checkRaw(int64_t raw)
+{
+  if (raw < 0)
+  {
+    return std::unexpected(Error{POLYMARKET_ERR_ORDER_FAILED});
+  }
+  return raw;
+}
+
+/// Request id returned by polymarket_submit_*(), or the error it carries
+inline Result<uint64_t> checkRequest(int64_t id)
+{
+  if (id < 0)
+  {
+    return std::unexpected(Error{static_cast<int32_t>(id)});
+  }
+  return static_cast<uint64_t>(id);
+}
+
+/// Raw value (6 decimals) returned by a query, or the error code it carries
+inline Result<int64_t> checkValue(int64_t raw)
+{
+  if (raw < 0)
+  {
+    return std::unexpected(Error{static_cast<int32_t>(raw)});
+  }
+  return raw;
+}
+
+/// Read a string through the size-query / fill convention of the C ABI
+template <typename Fn>
+std::string readString(Fn&& fn)
+{
+  std::string out;
+  size_t required = fn(nullptr, 0);
+  while (required > out.size() + 1)
+  {
+    out.resize(required - 1);
+    required = fn(out.data(), out.size() + 1);
+  }
+  out.resize(required > 0 ? required - 1 : 0);
+  return out;
+}
+
+/// JSON left by a query that returned `size` (or an error code)
+inline Result<std::string> readResponse(int64_t size)
+{
+  if (size < 0)
+  {
+    return std::unexpected(Error{static_cast<int32_t>(size)});
+  }
+  return readString(polymarket_last_response);
+}
+
+/// String of a call following the size-query / fill convention that returns
+/// a negative error code instead of a size on failure
+template <typename Fn>
+Result<std::string> readChecked(Fn&& fn)
+{
+  int64_t error = 0;
+  std::string out = readString(
+      [&](char* buf, size_t len) -> size_t
+      {
+        int64_t size = fn(buf, len);
+        if (size < 0)
+        {
+          error = size;
+          return 0;
+        }
+        return static_cast<size_t>(size);
+      });
+  if (error < 0)
+  {
+    return std::unexpected(Error{static_cast<int32_t>(error)});
+  }
+  return out;
+}
+
+/// Empty versioned result with struct_size set, to pass to polymarket_*_ex()
+inline ::PolymarketOrderResultEx emptyResult()
+{
+  ::PolymarketOrderResultEx r{};
+  r.struct_size = sizeof(r);
+  return r;
+}
+
+inline Result<OrderResult> convert(int32_t code, const ::PolymarketOrderResultEx& r)
+{
+  if (code != POLYMARKET_OK)
+  {
+    return std::unexpected(Error{code, readString(polymarket_last_error_message)});
+  }
+  // Full id from the thread-local copy, order_id[] may be truncated
+  return OrderResult{r.success,
+                     r.filled_qty_raw,
+                     r.avg_price_raw,
+                     r.latency_ms,
+                     readString(polymarket_last_order_id),
+                     r.remaining_qty_raw,
+                     r.fee_raw,
+                     std::string(r.client_order_id)};
+}
+
+/// Run the order call `place` under `clientOrderId` (none if empty)
+template <typename Fn>
+Result<OrderResult> placeAs(std::string_view clientOrderId, Fn&& place)
+{
+  if (!clientOrderId.empty())
+  {
+    std::string id(clientOrderId);
+    int32_t code = polymarket_set_client_order_id(id.c_str());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+  }
+  ::PolymarketOrderResultEx r = emptyResult();
+  int32_t code = place(&r);
+  return convert(code, r);
+}
+
+/// Read an array through the count / fill convention of the C ABI; `fn` is
+/// called with (out, capacity, out_count) until the array fits
+template <typename T, typename Fn>
+Result<std::vector<T>> readArray(Fn&& fn)
+{
+  std::vector<T> out;
+  size_t count = 0;
+  for (;;)
+  {
+    int32_t code = fn(out.data(), out.size(), &count);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, readString(polymarket_last_error_message)});
+    }
+    if (count <= out.size())
+    {
+      out.resize(count);
+      return out;
+    }
+    out.resize(count);
+  }
+}
+
+/// C views of `strings`, valid while they are
+inline std::vector<const char*> cStrings(const std::vector<std::string>& strings)
+{
+  std::vector<const char*> out;
+  out.reserve(strings.size());
+  for (const auto& s : strings)
+  {
+    out.push_back(s.c_str());
+  }
+  return out;
+}
+
+/// Outcomes of a bulk cancel by id, also when some failed
+/// (POLYMARKET_CANCEL_FAILED); `fn` is called with (ids, count, out,
+/// capacity, out_count)
+template <typename Fn>
+Result<std::vector<PolymarketCancelOutcome>> cancelByIds(const std::vector<std::string>& orderIds,
+                                                         Fn&& fn)
+{
+  std::vector<const char*> ids = cStrings(orderIds);
+  // The venue answers once per id; one shot, like cancelAllDetailed()
+  std::vector<PolymarketCancelOutcome> outcomes(orderIds.size());
+  size_t count = 0;
+  int32_t code = fn(ids.data(), ids.size(), outcomes.data(), outcomes.size(), &count);
+  if (code != POLYMARKET_OK && (code != POLYMARKET_ERR_CANCEL_FAILED || count == 0))
+  {
+    return std::unexpected(Error{code});
+  }
+  if (count < outcomes.size())
+  {
+    outcomes.resize(count);
+  }
+  return outcomes;
+}
+
+/// Overwrite a copy of a secret before it is released
+/// Volatile writes, so the compiler can't drop them as dead stores
+inline void scrub(std::string& secret)
+{
+  volatile char* p = secret.data();
+  for (std::size_t i = 0; i < secret.size(); ++i)
+  {
+    p[i] = '\0';
+  }
+  secret.clear();
+}
+
+}  // namespace detail
+
+/// Named wallet beside the global executor (Session::addWallet); a name,
+/// not owning the wallet: removeWallet() or the Session's shutdown ends it
+class Wallet
+{
+ public:
+  explicit Wallet(std::string name) : _name(std::move(name)) {}
+
+  Result<OrderResult> marketBuy(std::string_view tokenId, double usdcAmount,
+                                std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_w_market_buy_ex(_name.c_str(), token.c_str(), usdcAmount, r); });
+  }
+
+  /// Market buy sweeping no higher than maxPrice instead of 0.99
+  Result<OrderResult> marketBuyCapped(std::string_view tokenId, double usdcAmount,
+                                      double maxPrice, std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_w_market_buy_capped_ex(_name.c_str(), token.c_str(), usdcAmount,
+                                                   maxPrice, r);
+        });
+  }
+
+  Result<OrderResult> marketSell(std::string_view tokenId, double size,
+                                 std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_w_market_sell_ex(_name.c_str(), token.c_str(), size, r); });
+  }
+
+  /// Market sell sweeping no lower than minPrice instead of 0.01
+  Result<OrderResult> marketSellCapped(std::string_view tokenId, double size, double minPrice,
+                                       std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_w_market_sell_capped_ex(_name.c_str(), token.c_str(), size, minPrice,
+                                                    r);
+        });
+  }
+
+  /// Market orders capped from the book, see Session::marketBuyProtected
+  Result<OrderResult> marketBuyProtected(std::string_view tokenId, double usdcAmount,
+                                         uint32_t bufferBps, uint32_t maxSlippageBps,
+                                         std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_w_market_buy_protected_ex(_name.c_str(), token.c_str(), usdcAmount,
+                                                      bufferBps, maxSlippageBps, r);
+        });
+  }
+
+  Result<OrderResult> marketSellProtected(std::string_view tokenId, double size,
+                                          uint32_t bufferBps, uint32_t maxSlippageBps,
+                                          std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_w_market_sell_protected_ex(_name.c_str(), token.c_str(), size,
+                                                       bufferBps, maxSlippageBps, r);
+        });
+  }
+
+  Result<OrderResult> marketSellUsdc(std::string_view tokenId, double usdcAmount, double minPrice,
+                                     std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_w_market_sell_usdc_ex(_name.c_str(), token.c_str(), usdcAmount,
+                                                  minPrice, r);
+        });
+  }
+
+  Result<OrderResult> limitBuy(std::string_view tokenId, double price, double usdcAmount,
+                               std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_w_limit_buy_ex(_name.c_str(), token.c_str(), price, usdcAmount, r); });
+  }
+
+  Result<OrderResult> limitSell(std::string_view tokenId, double price, double size,
+                                std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_w_limit_sell_ex(_name.c_str(), token.c_str(), price, size, r); });
+  }
+
+  Result<void> cancel(std::string_view orderId)
+  {
+    std::string id(orderId);
+    int32_t code = polymarket_w_cancel(_name.c_str(), id.c_str());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, detail::readString(polymarket_last_error_message)});
+    }
+    return {};
+  }
+
+  Result<void> cancelAll()
+  {
+    return detail::check(polymarket_w_cancel_all_ex(_name.c_str(), nullptr, 0, nullptr));
+  }
+
+  /// Cancel the open orders of one token only
+  Result<void> cancelMarket(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(
+        polymarket_w_cancel_market_ex(_name.c_str(), token.c_str(), nullptr, 0, nullptr));
+  }
+
+  /// Cancel orders by id in one request and return the outcome of each
+  Result<std::vector<PolymarketCancelOutcome>> cancelOrders(
+      const std::vector<std::string>& orderIds)
+  {
+    return detail::cancelByIds(
+        orderIds,
+        [this](const char* const* ids, size_t count, PolymarketCancelOutcome* out,
+               size_t capacity, size_t* outCount)
+        {
+          return polymarket_w_cancel_orders_ex(_name.c_str(), ids, count, out, capacity,
+                                               outCount);
+        });
+  }
+
+  /// Cancel a resting order and post it again at newPrice for newSize shares
+  /// (<= 0 keeps the unmatched size) in one call; nothing is posted if the
+  /// cancel fails (POLYMARKET_ERR_CANCEL_FAILED)
+  Result<OrderResult> replaceOrder(std::string_view orderId, double newPrice, double newSize,
+                                   std::string_view clientOrderId = {})
+  {
+    std::string id(orderId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_w_replace_order(_name.c_str(), id.c_str(), newPrice, newSize, r); });
+  }
+
+  /// Orders resting on the book, of one token or (empty) of every market
+  Result<std::vector<PolymarketOpenOrder>> openOrders(std::string_view tokenId = {})
+  {
+    std::string token(tokenId);
+    const char* filter = token.empty() ? nullptr : token.c_str();
+    return detail::readArray<PolymarketOpenOrder>(
+        [&](PolymarketOpenOrder* out, size_t capacity, size_t* count)
+        { return polymarket_w_get_open_orders(_name.c_str(), filter, out, capacity, count); });
+  }
+
+  /// Our fills matched since sinceMs (unix ms), of one token or (empty) of
+  /// every market, oldest first
+  Result<std::vector<PolymarketTrade>> trades(uint64_t sinceMs, std::string_view tokenId = {})
+  {
+    std::string token(tokenId);
+    const char* filter = token.empty() ? nullptr : token.c_str();
+    return detail::readArray<PolymarketTrade>(
+        [&](PolymarketTrade* out, size_t capacity, size_t* count)
+        { return polymarket_w_get_trades(_name.c_str(), filter, sinceMs, out, capacity, count); });
+  }
+
+  /// JSON {client_order_id, state, order_id} of the order placed under
+  /// `clientOrderId`
+  Result<std::string> clientOrder(std::string_view clientOrderId)
+  {
+    std::string id(clientOrderId);
+    return detail::readResponse(polymarket_w_get_client_order(_name.c_str(), id.c_str()));
+  }
+
+  /// State of an order and how much of it matched, asked from the venue
+  Result<::PolymarketOrderStatus> order(std::string_view orderId)
+  {
+    std::string id(orderId);
+    ::PolymarketOrderStatus status{};
+    status.struct_size = sizeof(status);
+    int32_t code = polymarket_w_get_order(_name.c_str(), id.c_str(), &status);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, detail::readString(polymarket_last_error_message)});
+    }
+    return status;
+  }
+
+  /// USDC balance in raw units (6 decimals)
+  Result<int64_t> balance() { return detail::checkRaw(polymarket_w_get_balance(_name.c_str())); }
+
+  /// Shares held in raw units (6 decimals)
+  Result<int64_t> tokenBalance(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::checkRaw(polymarket_w_get_token_balance(_name.c_str(), token.c_str()));
+  }
+
+  /// Every conditional token position of the funder wallet, as JSON
+  Result<std::string> positions()
+  {
+    return detail::readResponse(polymarket_w_get_positions(_name.c_str()));
+  }
+
+  /// Split amountRaw USDC into as many YES and NO shares on-chain; JSON {"tx_hash"}
+  Result<std::string> splitPosition(std::string_view conditionId, bool negRisk, int64_t amountRaw)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(
+        polymarket_w_split_position(_name.c_str(), condition.c_str(), negRisk, amountRaw));
+  }
+
+  /// Merge amountRaw YES and NO shares back into USDC on-chain; JSON {"tx_hash"}
+  Result<std::string> mergePositions(std::string_view conditionId, bool negRisk, int64_t amountRaw)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(
+        polymarket_w_merge_positions(_name.c_str(), condition.c_str(), negRisk, amountRaw));
+  }
+
+  /// Convert amountRaw NO shares of each question into YES of the event's
+  /// other questions on-chain; JSON {"tx_hash"}
+  Result<std::string> convertPositions(const std::vector<std::string>& questionIds,
+                                       int64_t amountRaw)
+  {
+    std::vector<const char*> ids = detail::cStrings(questionIds);
+    return detail::readResponse(
+        polymarket_w_convert_positions(_name.c_str(), ids.data(), ids.size(), amountRaw));
+  }
+
+  /// Redeem the shares of a resolved market for USDC on-chain; JSON {"tx_hash"}
+  Result<std::string> redeem(std::string_view conditionId)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(polymarket_w_redeem(_name.c_str(), condition.c_str()));
+  }
+
+  /// Token approvals of the funder wallet as JSON; POLYMARKET_ERR_USDC_ALLOWANCE
+  /// or POLYMARKET_ERR_TOKEN_APPROVAL if one is missing
+  Result<std::string> checkAllowances()
+  {
+    return detail::readResponse(polymarket_w_check_allowances(_name.c_str()));
+  }
+
+  /// Set the missing approvals on-chain; JSON {"tx_hash"}, null if none was
+  Result<std::string> approveAllowances()
+  {
+    return detail::readResponse(polymarket_w_approve_allowances(_name.c_str()));
+  }
+
+  /// POL balance of the signing key in raw units (6 decimals)
+  Result<int64_t> gasBalance()
+  {
+    int64_t raw = polymarket_w_get_gas_balance(_name.c_str());
+    if (raw < 0)
+    {
+      return std::unexpected(Error{static_cast<int32_t>(raw)});
+    }
+    return raw;
+  }
+
+  /// Log a warning when the signing key's POL balance falls below thresholdPol
+  Result<void> setLowGasThreshold(double thresholdPol)
+  {
+    return detail::check(polymarket_w_set_low_gas_threshold(_name.c_str(), thresholdPol));
+  }
+
+  /// Journal order and cancel requests to a JSONL file; empty path stops
+  Result<void> setJournal(std::string_view path)
+  {
+    if (path.empty())
+    {
+      return detail::check(polymarket_w_set_journal(_name.c_str(), nullptr));
+    }
+    std::string p(path);
+    return detail::check(polymarket_w_set_journal(_name.c_str(), p.c_str()));
+  }
+
+  /// Kill switch: refuse new orders until resumeTrading(); cancelOpen also pulls every order
+  Result<void> haltTrading(bool cancelOpen)
+  {
+    return detail::check(polymarket_w_halt_trading(_name.c_str(), cancelOpen));
+  }
+
+  Result<void> resumeTrading() { return detail::check(polymarket_w_resume_trading(_name.c_str())); }
+
+  Result<bool> tradingHalted()
+  {
+    int32_t halted = polymarket_w_is_trading_halted(_name.c_str());
+    if (halted < 0)
+    {
+      return std::unexpected(Error{POLYMARKET_ERR_NOT_INITIALIZED});
+    }
+    return halted != 0;
+  }
+
+  const std::string& name() const { return _name; }
+
+ private:
+  std::string _name;
+};
+
+/// Owns the (process-wide) executor: init on create(), shutdown on destruction
+/// Move-only; keep exactly one live Session per process
+class Session
+{
+ public:
+  static Result<Session> create(std::string_view privateKey, std::string_view funderWallet)
+  {
+    std::string pk(privateKey);
+    std::string funder(funderWallet);
+    polymarket_negotiate_abi(POLYMARKET_ABI_VERSION);
+    int32_t code = polymarket_init(pk.c_str(), funder.c_str());
+    detail::scrub(pk);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return Session();
+  }
+
+  /// Like create(), with the key read from a permission-checked file
+  static Result<Session> createFromKeyFile(std::string_view keyPath,
+                                           std::string_view funderWallet)
+  {
+    std::string path(keyPath);
+    std::string funder(funderWallet);
+    polymarket_negotiate_abi(POLYMARKET_ABI_VERSION);
+    int32_t code = polymarket_init_from_file(path.c_str(), funder.c_str());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return Session();
+  }
+
+  /// Like create(), with the key read from the OS keyring
+  static Result<Session> createFromKeyring(std::string_view service, std::string_view account,
+                                           std::string_view funderWallet)
+  {
+    std::string svc(service);
+    std::string acct(account);
+    std::string funder(funderWallet);
+    polymarket_negotiate_abi(POLYMARKET_ABI_VERSION);
+    int32_t code = polymarket_init_from_keyring(svc.c_str(), acct.c_str(), funder.c_str());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return Session();
+  }
+
+  /// Keyless market-data session; only in builds without the execution feature
+  static Result<Session> createKeyless()
+  {
+    polymarket_negotiate_abi(POLYMARKET_ABI_VERSION);
+    int32_t code = polymarket_init_keyless();
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return Session();
+  }
+
+  Session(Session&& other) noexcept : _owner(std::exchange(other._owner, false)) {}
+
+  Session& operator=(Session&& other) noexcept
+  {
+    if (this != &other)
+    {
+      reset();
+      _owner = std::exchange(other._owner, false);
+    }
+    return *this;
+  }
+
+  Session(const Session&) = delete;
+  Session& operator=(const Session&) = delete;
+
+  ~Session() { reset(); }
+
+  /// Shut the executor down early
+  void reset()
+  {
+    if (_owner)
+    {
+      polymarket_shutdown();
+      _owner = false;
+    }
+  }
+
+  /// Shut down gracefully: drain running calls, then POLYMARKET_SHUTDOWN_* flags
+  Result<void> shutdown(uint32_t flags, uint32_t drainTimeoutMs = 5000)
+  {
+    if (!_owner)
+    {
+      return std::unexpected(Error{POLYMARKET_ERR_NOT_INITIALIZED});
+    }
+    _owner = false;
+    return detail::check(polymarket_shutdown_ex(flags, drainTimeoutMs));
+  }
+
+  /// Connect another wallet beside this executor, under `name`
+  Result<Wallet> addWallet(std::string_view name, std::string_view privateKey,
+                           std::string_view funderWallet)
+  {
+    std::string n(name);
+    std::string pk(privateKey);
+    std::string funder(funderWallet);
+    int32_t code = polymarket_add_wallet(n.c_str(), pk.c_str(), funder.c_str());
+    detail::scrub(pk);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return Wallet(std::move(n));
+  }
+
+  /// Shut a wallet down: drain running calls, then POLYMARKET_SHUTDOWN_* flags
+  Result<void> removeWallet(std::string_view name, uint32_t flags = 0,
+                            uint32_t drainTimeoutMs = 5000)
+  {
+    std::string n(name);
+    return detail::check(polymarket_remove_wallet(n.c_str(), flags, drainTimeoutMs));
+  }
+
+  /// Registered wallets (JSON)
+  Result<std::string> wallets() { return detail::readResponse(polymarket_list_wallets()); }
+
+  /// Warm up per the warmup profile; returns the step timings (JSON)
+  Result<std::string> warmup()
+  {
+    int32_t code = polymarket_warmup();
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return detail::readString(polymarket_last_response);
+  }
+
+  Result<void> prefetch(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(polymarket_prefetch(token.c_str()));
+  }
+
+  /// Prefetch every token concurrently; one code per token, in order
+  Result<std::vector<int32_t>> prefetchMany(const std::vector<std::string>& tokenIds)
+  {
+    std::vector<const char*> ids = detail::cStrings(tokenIds);
+    std::vector<int32_t> codes(tokenIds.size());
+    int32_t code = polymarket_prefetch_many(ids.data(), ids.size(), codes.data());
+    if (code < 0)
+    {
+      return std::unexpected(Error{code});
+    }
+    return codes;
+  }
+
+  Result<OrderResult> marketBuy(std::string_view tokenId, double usdcAmount,
+                                std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_buy_ex(token.c_str(), usdcAmount, r); });
+  }
+
+  /// Market buy that fills in full or not at all (FOK)
+  Result<OrderResult> marketBuyFok(std::string_view tokenId, double usdcAmount,
+                                   std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_buy_fok_ex(token.c_str(), usdcAmount, r); });
+  }
+
+  /// Market buys sweeping no higher than maxPrice (on the tick grid)
+  /// instead of 0.99
+  Result<OrderResult> marketBuyCapped(std::string_view tokenId, double usdcAmount,
+                                      double maxPrice, std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_buy_capped_ex(token.c_str(), usdcAmount, maxPrice, r); });
+  }
+
+  Result<OrderResult> marketBuyFokCapped(std::string_view tokenId, double usdcAmount,
+                                         double maxPrice, std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_buy_fok_capped_ex(token.c_str(), usdcAmount, maxPrice, r); });
+  }
+
+  /// Buy `shares` (net of fee) at no worse than maxPrice, priced from the book
+  Result<OrderResult> marketBuyShares(std::string_view tokenId, double shares, double maxPrice,
+                                      std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_buy_shares_ex(token.c_str(), shares, maxPrice, r); });
+  }
+
+  /// Market orders capped from the book: the filling price plus bufferBps,
+  /// refused if the expected slippage is over maxSlippageBps
+  Result<OrderResult> marketBuyProtected(std::string_view tokenId, double usdcAmount,
+                                         uint32_t bufferBps, uint32_t maxSlippageBps,
+                                         std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_market_buy_protected_ex(token.c_str(), usdcAmount, bufferBps,
+                                                    maxSlippageBps, r);
+        });
+  }
+
+  Result<OrderResult> marketSellProtected(std::string_view tokenId, double size,
+                                          uint32_t bufferBps, uint32_t maxSlippageBps,
+                                          std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_market_sell_protected_ex(token.c_str(), size, bufferBps, maxSlippageBps,
+                                                     r);
+        });
+  }
+
+  /// Sell the shares that raise usdcAmount, sized from the book, at no worse
+  /// than minPrice
+  Result<OrderResult> marketSellUsdc(std::string_view tokenId, double usdcAmount, double minPrice,
+                                     std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_sell_usdc_ex(token.c_str(), usdcAmount, minPrice, r); });
+  }
+
+  Result<OrderResult> marketSell(std::string_view tokenId, double size,
+                                 std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_sell_ex(token.c_str(), size, r); });
+  }
+
+  /// Market sell that fills in full or not at all (FOK)
+  Result<OrderResult> marketSellFok(std::string_view tokenId, double size,
+                                    std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_sell_fok_ex(token.c_str(), size, r); });
+  }
+
+  /// Market sells sweeping no lower than minPrice (on the tick grid)
+  /// instead of 0.01
+  Result<OrderResult> marketSellCapped(std::string_view tokenId, double size, double minPrice,
+                                       std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_sell_capped_ex(token.c_str(), size, minPrice, r); });
+  }
+
+  Result<OrderResult> marketSellFokCapped(std::string_view tokenId, double size, double minPrice,
+                                          std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_market_sell_fok_capped_ex(token.c_str(), size, minPrice, r); });
+  }
+
+  Result<OrderResult> limitBuy(std::string_view tokenId, double price, double usdcAmount,
+                               std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_limit_buy_ex(token.c_str(), price, usdcAmount, r); });
+  }
+
+  Result<OrderResult> limitSell(std::string_view tokenId, double price, double size,
+                                std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_limit_sell_ex(token.c_str(), price, size, r); });
+  }
+
+  /// GTD limit orders resting until expiresAtMs (Unix ms); deadlines between
+  /// seconds are also enforced by a local cancel
+  Result<OrderResult> limitBuyGtd(std::string_view tokenId, double price, double usdcAmount,
+                                  uint64_t expiresAtMs, std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_limit_buy_gtd_ex(token.c_str(), price, usdcAmount, expiresAtMs, r); });
+  }
+
+  Result<OrderResult> limitSellGtd(std::string_view tokenId, double price, double size,
+                                   uint64_t expiresAtMs, std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_limit_sell_gtd_ex(token.c_str(), price, size, expiresAtMs, r); });
+  }
+
+  /// Place up to 15 limit orders in one request; one result per order, in
+  /// order, each with its own error
+  Result<std::vector<Result<OrderResult>>> postOrdersBatch(
+      const std::vector<PolymarketBatchOrder>& orders)
+  {
+    std::vector<::PolymarketOrderResultEx> raw(orders.size(), detail::emptyResult());
+    int32_t code = polymarket_post_orders_batch(orders.data(), orders.size(), raw.data());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    std::vector<Result<OrderResult>> results;
+    results.reserve(raw.size());
+    for (const auto& r : raw)
+    {
+      if (r.error_code != POLYMARKET_OK)
+      {
+        results.push_back(std::unexpected(Error{r.error_code}));
+        continue;
+      }
+      results.push_back(OrderResult{r.success, r.filled_qty_raw, r.avg_price_raw, r.latency_ms,
+                                    std::string(r.order_id), r.remaining_qty_raw, r.fee_raw,
+                                    std::string(r.client_order_id)});
+    }
+    return results;
+  }
+
+  Result<void> cancel(std::string_view orderId)
+  {
+    std::string id(orderId);
+    int32_t code = polymarket_cancel(id.c_str());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, detail::readString(polymarket_last_error_message)});
+    }
+    return {};
+  }
+
+  Result<void> cancelAll() { return detail::check(polymarket_cancel_all()); }
+
+  /// Cancel the open orders of one token only
+  Result<void> cancelMarket(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(polymarket_cancel_market(token.c_str()));
+  }
+
+  /// Cancel orders by id in one request and return the outcome of each
+  Result<std::vector<PolymarketCancelOutcome>> cancelOrders(
+      const std::vector<std::string>& orderIds)
+  {
+    return detail::cancelByIds(orderIds, polymarket_cancel_orders_ex);
+  }
+
+  /// Cancel a resting order and post it again at newPrice for newSize shares
+  /// (<= 0 keeps the unmatched size) in one call; nothing is posted if the
+  /// cancel fails (POLYMARKET_ERR_CANCEL_FAILED)
+  Result<OrderResult> replaceOrder(std::string_view orderId, double newPrice, double newSize,
+                                   std::string_view clientOrderId = {})
+  {
+    std::string id(orderId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_replace_order(id.c_str(), newPrice, newSize, r); });
+  }
+
+  /// Cancel all open orders and return the outcome of each, also when some
+  /// failed (POLYMARKET_CANCEL_FAILED); at most maxOrders are reported
+  Result<std::vector<PolymarketCancelOutcome>> cancelAllDetailed(size_t maxOrders = 512)
+  {
+    // One shot: a second call would find the orders already gone
+    std::vector<PolymarketCancelOutcome> outcomes(maxOrders);
+    size_t count = 0;
+    int32_t code = polymarket_cancel_all_ex(outcomes.data(), outcomes.size(), &count);
+    if (code != POLYMARKET_OK && (code != POLYMARKET_ERR_CANCEL_FAILED || count == 0))
+    {
+      return std::unexpected(Error{code});
+    }
+    if (count < outcomes.size())
+    {
+      outcomes.resize(count);
+    }
+    return outcomes;
+  }
+
+  /// Orders resting on the book, of one token or (empty) of every market
+  Result<std::vector<PolymarketOpenOrder>> openOrders(std::string_view tokenId = {})
+  {
+    std::string token(tokenId);
+    const char* filter = token.empty() ? nullptr : token.c_str();
+    return detail::readArray<PolymarketOpenOrder>(
+        [&](PolymarketOpenOrder* out, size_t capacity, size_t* count)
+        { return polymarket_get_open_orders(filter, out, capacity, count); });
+  }
+
+  /// Our fills matched since sinceMs (unix ms), of one token or (empty) of
+  /// every market, oldest first
+  Result<std::vector<PolymarketTrade>> trades(uint64_t sinceMs, std::string_view tokenId = {})
+  {
+    std::string token(tokenId);
+    const char* filter = token.empty() ? nullptr : token.c_str();
+    return detail::readArray<PolymarketTrade>(
+        [&](PolymarketTrade* out, size_t capacity, size_t* count)
+        { return polymarket_get_trades(filter, sinceMs, out, capacity, count); });
+  }
+
+  /// JSON {client_order_id, state, order_id} of the order placed under
+  /// `clientOrderId`
+  Result<std::string> clientOrder(std::string_view clientOrderId)
+  {
+    std::string id(clientOrderId);
+    return detail::readResponse(polymarket_get_client_order(id.c_str()));
+  }
+
+  /// State of an order and how much of it matched, asked from the venue
+  Result<::PolymarketOrderStatus> order(std::string_view orderId)
+  {
+    std::string id(orderId);
+    ::PolymarketOrderStatus status{};
+    status.struct_size = sizeof(status);
+    int32_t code = polymarket_get_order(id.c_str(), &status);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, detail::readString(polymarket_last_error_message)});
+    }
+    return status;
+  }
+
+  /// Non-blocking variants: return a request id, the result arrives as a
+  /// POLYMARKET_EVENT_ORDER event
+  Result<uint64_t> submitMarketBuy(std::string_view tokenId, double usdcAmount)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(polymarket_submit_market_buy(token.c_str(), usdcAmount));
+  }
+
+  Result<uint64_t> submitMarketBuyShares(std::string_view tokenId, double shares,
+                                         double maxPrice)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(
+        polymarket_submit_market_buy_shares(token.c_str(), shares, maxPrice));
+  }
+
+  Result<uint64_t> submitMarketSell(std::string_view tokenId, double size)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(polymarket_submit_market_sell(token.c_str(), size));
+  }
+
+  Result<uint64_t> submitLimitBuy(std::string_view tokenId, double price, double usdcAmount)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(polymarket_submit_limit_buy(token.c_str(), price, usdcAmount));
+  }
+
+  Result<uint64_t> submitLimitSell(std::string_view tokenId, double price, double size)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(polymarket_submit_limit_sell(token.c_str(), price, size));
+  }
+
+  Result<uint64_t> submitCancel(std::string_view orderId)
+  {
+    std::string id(orderId);
+    return detail::checkRequest(polymarket_submit_cancel(id.c_str()));
+  }
+
+  /// Non-blocking variants whose result goes to `callback` (on a runtime
+  /// thread, tagged with requestId); return the library's request id
+  Result<uint64_t> marketBuyAsync(std::string_view tokenId, double usdcAmount, uint64_t requestId,
+                                  PolymarketOrderCallback callback, void* userData = nullptr)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(
+        polymarket_market_buy_async(token.c_str(), usdcAmount, requestId, callback, userData));
+  }
+
+  Result<uint64_t> marketSellAsync(std::string_view tokenId, double size, uint64_t requestId,
+                                   PolymarketOrderCallback callback, void* userData = nullptr)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(
+        polymarket_market_sell_async(token.c_str(), size, requestId, callback, userData));
+  }
+
+  Result<uint64_t> limitBuyAsync(std::string_view tokenId, double price, double usdcAmount,
+                                 uint64_t requestId, PolymarketOrderCallback callback,
+                                 void* userData = nullptr)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(polymarket_limit_buy_async(token.c_str(), price, usdcAmount,
+                                                           requestId, callback, userData));
+  }
+
+  Result<uint64_t> limitSellAsync(std::string_view tokenId, double price, double size,
+                                  uint64_t requestId, PolymarketOrderCallback callback,
+                                  void* userData = nullptr)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(
+        polymarket_limit_sell_async(token.c_str(), price, size, requestId, callback, userData));
+  }
+
+  Result<uint64_t> cancelAsync(std::string_view orderId, uint64_t requestId,
+                               PolymarketOrderCallback callback, void* userData = nullptr)
+  {
+    std::string id(orderId);
+    return detail::checkRequest(polymarket_cancel_async(id.c_str(), requestId, callback, userData));
+  }
+
+  /// Swap in new credentials in the background; track it like a submitted request
+  Result<uint64_t> rotateCredentials(std::string_view privateKey, std::string_view funderWallet)
+  {
+    std::string pk(privateKey);
+    std::string funder(funderWallet);
+    int64_t id = polymarket_rotate_credentials(pk.c_str(), funder.c_str());
+    detail::scrub(pk);
+    return detail::checkRequest(id);
+  }
+
+  /// Credentials to fail over to when the current ones are rejected or rate-banned
+  Result<void> setBackupCredentials(std::string_view privateKey, std::string_view funderWallet)
+  {
+    std::string pk(privateKey);
+    std::string funder(funderWallet);
+    int32_t code = polymarket_set_backup_credentials(pk.c_str(), funder.c_str());
+    detail::scrub(pk);
+    return detail::check(code);
+  }
+
+  Result<void> clearBackupCredentials()
+  {
+    return detail::check(polymarket_set_backup_credentials(nullptr, nullptr));
+  }
+
+  /// Switch to new credentials (e.g. another wallet) in place, blocking until done
+  Result<void> switchCredentials(std::string_view privateKey, std::string_view funderWallet)
+  {
+    std::string pk(privateKey);
+    std::string funder(funderWallet);
+    int32_t code = polymarket_init(pk.c_str(), funder.c_str());
+    detail::scrub(pk);
+    return detail::check(code);
+  }
+
+  /// Result of a submitted request, std::nullopt while still in flight
+  Result<std::optional<OrderResult>> pollResult(uint64_t requestId)
+  {
+    ::PolymarketOrderResultEx r = detail::emptyResult();
+    int32_t code = polymarket_poll_result(requestId, &r);
+    if (code == POLYMARKET_PENDING)
+    {
+      return std::optional<OrderResult>{};
+    }
+    auto result = detail::convert(code, r);
+    if (!result)
+    {
+      return std::unexpected(result.error());
+    }
+    return std::optional<OrderResult>{std::move(*result)};
+  }
+
+  /// Abort a submitted request; venue state is unknown afterwards
+  Result<void> abortRequest(uint64_t requestId)
+  {
+    return detail::check(polymarket_abort_request(requestId));
+  }
+
+  /// USDC balance in raw units (6 decimals)
+  Result<int64_t> balance() { return detail::checkRaw(polymarket_get_balance()); }
+
+  /// Shares held in raw units (6 decimals)
+  Result<int64_t> tokenBalance(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::checkRaw(polymarket_get_token_balance(token.c_str()));
+  }
+
+  /// Every conditional token position of the funder wallet, as JSON
+  Result<std::string> positions() { return detail::readResponse(polymarket_get_positions()); }
+
+  /// Open positions marked to market, with unrealized PnL, as JSON
+  Result<std::string> markPositions() { return detail::readResponse(polymarket_mark_positions()); }
+
+  /// Price on the token's tick grid, rounded down for POLYMARKET_SIDE_BUY and
+  /// up for POLYMARKET_SIDE_SELL; the token must be prefetched
+  Result<double> roundPrice(std::string_view tokenId, double price, int32_t side)
+  {
+    std::string token(tokenId);
+    double rounded = 0.0;
+    int32_t code = polymarket_round_price(token.c_str(), price, side, &rounded);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return rounded;
+  }
+
+  /// Tick size, min order size, fee rate and neg-risk flag cached by prefetch
+  Result<::PolymarketMarketMeta> marketMeta(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    ::PolymarketMarketMeta meta{};
+    meta.struct_size = sizeof(meta);
+    int32_t code = polymarket_get_market_meta(token.c_str(), &meta);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return meta;
+  }
+
+  /// Re-fetch prefetched tokens' metadata every ttlSecs (>= 10); 0 stops
+  Result<void> setMetaTtlSecs(uint32_t ttlSecs)
+  {
+    return detail::check(polymarket_set_meta_ttl_secs(ttlSecs));
+  }
+
+  /// Expected fill of a market order right now: usdc for POLYMARKET_SIDE_BUY,
+  /// shares for POLYMARKET_SIDE_SELL
+  Result<::PolymarketFillEstimate> estimateFill(std::string_view tokenId, int32_t side,
+                                                double amount)
+  {
+    std::string token(tokenId);
+    ::PolymarketFillEstimate estimate{};
+    estimate.struct_size = sizeof(estimate);
+    int32_t code = polymarket_estimate_fill(token.c_str(), side, amount, &estimate);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return estimate;
+  }
+
+  /// Keep a local book of the token from the market channel
+  Result<void> subscribeBook(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(polymarket_subscribe_book(token.c_str()));
+  }
+
+  Result<void> unsubscribeBook(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(polymarket_unsubscribe_book(token.c_str()));
+  }
+
+  /// Public trades of the token, on a runtime thread; subscribes its book
+  Result<void> subscribeTradeTape(std::string_view tokenId, PolymarketTradeTapeCallback callback,
+                                  void* userData = nullptr)
+  {
+    std::string token(tokenId);
+    return detail::check(polymarket_subscribe_trade_tape(token.c_str(), callback, userData));
+  }
+
+  Result<void> unsubscribeTradeTape(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(polymarket_unsubscribe_trade_tape(token.c_str()));
+  }
+
+  /// Top of a subscribed book; nullopt until the venue sent it
+  Result<std::optional<::PolymarketBestBidAsk>> bestBidAsk(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    ::PolymarketBestBidAsk top{};
+    top.struct_size = sizeof(top);
+    int32_t code = polymarket_get_best_bid_ask(token.c_str(), &top);
+    if (code == POLYMARKET_PENDING)
+    {
+      return std::optional<::PolymarketBestBidAsk>{};
+    }
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return std::optional<::PolymarketBestBidAsk>{top};
+  }
+
+  /// Top levels of a subscribed book; nullopt until the venue sent it
+  Result<std::optional<::PolymarketBookSnapshot>> bookSnapshot(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    ::PolymarketBookSnapshot book{};
+    book.struct_size = sizeof(book);
+    int32_t code = polymarket_get_book_snapshot(token.c_str(), &book);
+    if (code == POLYMARKET_PENDING)
+    {
+      return std::optional<::PolymarketBookSnapshot>{};
+    }
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return std::optional<::PolymarketBookSnapshot>{book};
+  }
+
+  /// Generation and staleness of a subscribed book; nullopt until the venue sent it
+  Result<std::optional<::PolymarketBookState>> bookState(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    ::PolymarketBookState state{};
+    state.struct_size = sizeof(state);
+    int32_t code = polymarket_get_book_state(token.c_str(), &state);
+    if (code == POLYMARKET_PENDING)
+    {
+      return std::optional<::PolymarketBookState>{};
+    }
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return std::optional<::PolymarketBookState>{state};
+  }
+
+  /// Top `depth` levels per side of a book, subscribed or fetched
+  Result<OrderBook> orderBook(std::string_view tokenId, uint32_t depth)
+  {
+    std::string token(tokenId);
+    std::vector<::PolymarketBookLevel> levels(2 * size_t{depth});
+    int64_t count = polymarket_get_order_book(token.c_str(), depth, levels.data(), levels.size());
+    if (count < 0)
+    {
+      return std::unexpected(Error{static_cast<int32_t>(count)});
+    }
+    auto side = [&](size_t first)
+    {
+      std::vector<::PolymarketBookLevel> out;
+      for (size_t i = first; i < first + depth && levels[i].size_raw > 0; ++i)
+      {
+        out.push_back(levels[i]);
+      }
+      return out;
+    };
+    return OrderBook{side(0), side(depth)};
+  }
+
+  /// Midpoint of best bid and ask, raw (6 decimals)
+  Result<int64_t> midpoint(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::checkValue(polymarket_get_midpoint(token.c_str()));
+  }
+
+  /// Best ask less best bid, raw (6 decimals)
+  Result<int64_t> spread(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::checkValue(polymarket_get_spread(token.c_str()));
+  }
+
+  /// OHLC candles of intervalSecs between unix seconds fromTs and toTs, oldest first
+  Result<std::vector<::PolymarketCandle>> priceHistory(std::string_view tokenId,
+                                                       uint32_t intervalSecs, int64_t fromTs,
+                                                       int64_t toTs)
+  {
+    std::string token(tokenId);
+    // Candles are epoch-aligned: the range touches at most this many
+    size_t capacity = 0;
+    if (intervalSecs > 0 && toTs > fromTs)
+    {
+      capacity = static_cast<size_t>((toTs - fromTs) / intervalSecs) + 2;
+    }
+    std::vector<::PolymarketCandle> candles(capacity);
+    int64_t count = polymarket_get_price_history(token.c_str(), intervalSecs, fromTs, toTs,
+                                                 candles.data(), candles.size());
+    if (count < 0)
+    {
+      return std::unexpected(Error{static_cast<int32_t>(count)});
+    }
+    candles.resize(std::min(candles.size(), static_cast<size_t>(count)));
+    return candles;
+  }
+
+  /// Account order updates and fills from the user channel, on a runtime thread
+  Result<void> subscribeOrderUpdates(PolymarketOrderUpdateCallback callback,
+                                     void* userData = nullptr)
+  {
+    return detail::check(polymarket_subscribe_order_updates(callback, userData));
+  }
+
+  Result<void> unsubscribeOrderUpdates()
+  {
+    return detail::check(polymarket_unsubscribe_order_updates());
+  }
+
+  /// Whether a resting order qualifies for liquidity rewards
+  Result<bool> isOrderScoring(std::string_view orderId)
+  {
+    std::string id(orderId);
+    int32_t scoring = polymarket_is_order_scoring(id.c_str());
+    if (scoring < 0)
+    {
+      return std::unexpected(Error{scoring});
+    }
+    return scoring == 1;
+  }
+
+  /// Scoring status per order, in the order of orderIds
+  Result<std::vector<bool>> ordersScoring(const std::vector<std::string>& orderIds)
+  {
+    std::vector<const char*> ids = detail::cStrings(orderIds);
+    std::vector<uint8_t> flags(orderIds.size());
+    int32_t code = polymarket_orders_scoring(ids.data(), ids.size(), flags.data());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return std::vector<bool>(flags.begin(), flags.end());
+  }
+
+  /// Rewards earned per market and epoch as JSON (see polymarket_get_rewards_earnings)
+  Result<std::string> rewardsEarnings(std::string_view fromDate, std::string_view toDate)
+  {
+    std::string from(fromDate);
+    std::string to(toDate);
+    return detail::readResponse(polymarket_get_rewards_earnings(from.c_str(), to.c_str()));
+  }
+
+  /// Timeout of one class of venue calls (POLYMARKET_CALL_*)
+  Result<void> setTimeout(int32_t opClass, uint32_t timeoutMs)
+  {
+    return detail::check(polymarket_set_timeout_ms(opClass, timeoutMs));
+  }
+
+  /// Client-side rate limit of one class of venue calls; 0 perSec lifts it
+  Result<void> setRateLimit(int32_t opClass, double perSec, uint32_t burst, uint32_t flags = 0)
+  {
+    return detail::check(polymarket_set_rate_limit(opClass, perSec, burst, flags));
+  }
+
+  /// Rate limit of one class of venue calls and the budget left now
+  Result<::PolymarketRateBudget> rateBudget(int32_t opClass)
+  {
+    ::PolymarketRateBudget budget{};
+    budget.struct_size = sizeof(budget);
+    int32_t code = polymarket_get_rate_budget(opClass, &budget);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code});
+    }
+    return budget;
+  }
+
+  /// Resend posts and cancels that failed in transit; 0 retries turns it off
+  Result<void> setRetryPolicy(uint32_t maxRetries, uint32_t baseDelayMs, uint32_t maxDelayMs)
+  {
+    return detail::check(polymarket_set_retry_policy(maxRetries, baseDelayMs, maxDelayMs));
+  }
+
+  Result<void> setBalanceBuffer(double bufferUsdc)
+  {
+    return detail::check(polymarket_set_balance_buffer(bufferUsdc));
+  }
+
+  Result<void> loadRiskConfig(std::string_view path)
+  {
+    std::string p(path);
+    return detail::check(polymarket_load_risk_config(p.c_str()));
+  }
+
+  Result<void> setRiskConfig(std::string_view json)
+  {
+    std::string j(json);
+    return detail::check(polymarket_set_risk_config(j.c_str()));
+  }
+
+  /// Order notional, position and open orders caps (0 = no limit); other limits are kept
+  Result<void> setRiskLimits(double maxOrderUsdc, double maxPositionShares,
+                             uint32_t maxOpenOrders = 0)
+  {
+    return detail::check(
+        polymarket_set_risk_limits(maxOrderUsdc, maxPositionShares, maxOpenOrders));
+  }
+
+  /// Kill switch: refuse new orders until resumeTrading(); cancelOpen also pulls every order
+  Result<void> haltTrading(bool cancelOpen)
+  {
+    return detail::check(polymarket_halt_trading(cancelOpen));
+  }
+
+  Result<void> resumeTrading() { return detail::check(polymarket_resume_trading()); }
+
+  Result<bool> tradingHalted()
+  {
+    int32_t halted = polymarket_is_trading_halted();
+    if (halted < 0)
+    {
+      return std::unexpected(Error{POLYMARKET_ERR_NOT_INITIALIZED});
+    }
+    return halted != 0;
+  }
+
+  /// Publish account notifications as POLYMARKET_EVENT_NOTIFICATION events
+  Result<void> startNotifications(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_notifications(intervalMs));
+  }
+
+  Result<void> stopNotifications() { return detail::check(polymarket_stop_notifications()); }
+
+  /// Execution algos: return an algo id, progress arrives as
+  /// POLYMARKET_EVENT_ALGO events
+  Result<uint64_t> startTwap(std::string_view tokenId, int32_t side, double total, uint32_t slices,
+                             uint64_t intervalMs)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(
+        polymarket_start_twap(token.c_str(), side, total, slices, intervalMs));
+  }
+
+  Result<uint64_t> startIceberg(std::string_view tokenId, int32_t side, double price,
+                                double total, double showSize, uint32_t pollMs)
+  {
+    std::string token(tokenId);
+    return detail::checkRequest(
+        polymarket_start_iceberg(token.c_str(), side, price, total, showSize, pollMs));
+  }
+
+  Result<void> cancelAlgo(uint64_t algoId) { return detail::check(polymarket_cancel_algo(algoId)); }
+
+  /// Quoting: actions arrive as POLYMARKET_EVENT_QUOTE events
+  Result<void> startQuoting(uint32_t intervalMs, uint32_t minReplaceMs = 0)
+  {
+    return detail::check(polymarket_start_quoting(intervalMs, minReplaceMs));
+  }
+
+  /// Size <= 0 pulls that side
+  Result<void> setQuote(std::string_view tokenId, double bidPrice, double bidSize, double askPrice,
+                        double askSize)
+  {
+    std::string token(tokenId);
+    return detail::check(
+        polymarket_set_quote(token.c_str(), bidPrice, bidSize, askPrice, askSize));
+  }
+
+  /// Stop quoting and pull every quote
+  Result<void> stopQuoting() { return detail::check(polymarket_stop_quoting()); }
+
+  /// Basket of FAK legs (POLYMARKET_BASKET_* flags); returns the outcome JSON
+  Result<std::string> placeBasket(const std::vector<PolymarketBasketLeg>& legs, uint32_t flags)
+  {
+    return detail::readResponse(polymarket_place_basket(legs.data(), legs.size(), flags));
+  }
+
+  /// YES/NO pair merging: findings arrive as POLYMARKET_EVENT_MERGE events
+  Result<void> setAutoMerge(std::string_view conditionId, int32_t mode, double minShares = 0.0)
+  {
+    std::string condition(conditionId);
+    return detail::check(polymarket_set_auto_merge(condition.c_str(), mode, minShares));
+  }
+
+  Result<void> startAutoMerge(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_auto_merge(intervalMs));
+  }
+
+  Result<void> stopAutoMerge() { return detail::check(polymarket_stop_auto_merge()); }
+
+  /// Split amountRaw USDC into as many YES and NO shares on-chain; JSON {"tx_hash"}
+  Result<std::string> splitPosition(std::string_view conditionId, bool negRisk, int64_t amountRaw)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(polymarket_split_position(condition.c_str(), negRisk, amountRaw));
+  }
+
+  /// Merge amountRaw YES and NO shares back into USDC on-chain; JSON {"tx_hash"}
+  Result<std::string> mergePositions(std::string_view conditionId, bool negRisk, int64_t amountRaw)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(
+        polymarket_merge_positions(condition.c_str(), negRisk, amountRaw));
+  }
+
+  /// Convert amountRaw NO shares of each question into YES of the event's
+  /// other questions on-chain; JSON {"tx_hash"}
+  Result<std::string> convertPositions(const std::vector<std::string>& questionIds,
+                                       int64_t amountRaw)
+  {
+    std::vector<const char*> ids = detail::cStrings(questionIds);
+    return detail::readResponse(polymarket_convert_positions(ids.data(), ids.size(), amountRaw));
+  }
+
+  /// Redeem the shares of a resolved market for USDC on-chain; JSON {"tx_hash"}
+  Result<std::string> redeem(std::string_view conditionId)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(polymarket_redeem(condition.c_str()));
+  }
+
+  /// Token approvals of the funder wallet as JSON; POLYMARKET_ERR_USDC_ALLOWANCE
+  /// or POLYMARKET_ERR_TOKEN_APPROVAL if one is missing
+  Result<std::string> checkAllowances()
+  {
+    return detail::readResponse(polymarket_check_allowances());
+  }
+
+  /// Set the missing approvals on-chain; JSON {"tx_hash"}, null if none was
+  Result<std::string> approveAllowances()
+  {
+    return detail::readResponse(polymarket_approve_allowances());
+  }
+
+  /// POL balance of the signing key in raw units (6 decimals)
+  Result<int64_t> gasBalance()
+  {
+    int64_t raw = polymarket_get_gas_balance();
+    if (raw < 0)
+    {
+      return std::unexpected(Error{static_cast<int32_t>(raw)});
+    }
+    return raw;
+  }
+
+  /// Log a warning when the signing key's POL balance falls below thresholdPol
+  Result<void> setLowGasThreshold(double thresholdPol)
+  {
+    return detail::check(polymarket_set_low_gas_threshold(thresholdPol));
+  }
+
+  /// YES+NO mispricing: opportunities arrive as POLYMARKET_EVENT_ARBITRAGE events
+  Result<void> watchArbitrage(std::string_view conditionId, double minEdge)
+  {
+    std::string condition(conditionId);
+    return detail::check(polymarket_watch_arbitrage(condition.c_str(), minEdge));
+  }
+
+  Result<void> unwatchArbitrage(std::string_view conditionId)
+  {
+    std::string condition(conditionId);
+    return detail::check(polymarket_unwatch_arbitrage(condition.c_str()));
+  }
+
+  Result<void> startArbitrageScan(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_arbitrage_scan(intervalMs));
+  }
+
+  Result<void> stopArbitrageScan() { return detail::check(polymarket_stop_arbitrage_scan()); }
+
+  /// Market resolution: status changes arrive as POLYMARKET_EVENT_RESOLUTION events
+  Result<void> watchResolution(std::string_view conditionId)
+  {
+    std::string condition(conditionId);
+    return detail::check(polymarket_watch_resolution(condition.c_str()));
+  }
+
+  Result<void> unwatchResolution(std::string_view conditionId)
+  {
+    std::string condition(conditionId);
+    return detail::check(polymarket_unwatch_resolution(condition.c_str()));
+  }
+
+  Result<void> startResolutionWatch(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_resolution_watch(intervalMs));
+  }
+
+  Result<void> stopResolutionWatch() { return detail::check(polymarket_stop_resolution_watch()); }
+
+  /// Fill aggregation: POLYMARKET_EVENT_FILL and POLYMARKET_EVENT_ORDER_SUMMARY events
+  Result<void> startFillTracking(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_fill_tracking(intervalMs));
+  }
+
+  Result<void> stopFillTracking() { return detail::check(polymarket_stop_fill_tracking()); }
+
+  /// Route orders and cancels to the fastest healthy CLOB gateway
+  Result<void> startEndpointProbing(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_endpoint_probing(intervalMs));
+  }
+
+  Result<void> stopEndpointProbing() { return detail::check(polymarket_stop_endpoint_probing()); }
+
+  /// Per-gateway RTT, health and order counts as JSON
+  Result<std::string> endpointMetrics()
+  {
+    return detail::readResponse(polymarket_endpoint_metrics());
+  }
+
+  /// Ping the CLOB and gateways every intervalMs (>= 1000) so connections stay warm
+  Result<void> startKeepAlive(uint32_t intervalMs)
+  {
+    return detail::check(polymarket_start_keepalive(intervalMs));
+  }
+
+  Result<void> stopKeepAlive() { return detail::check(polymarket_stop_keepalive()); }
+
+  /// Per-operation latency percentiles and error counts as JSON
+  Result<std::string> metrics() { return detail::readChecked(polymarket_get_metrics); }
+
+  /// The same counts in the Prometheus text format
+  Result<std::string> metricsPrometheus()
+  {
+    return detail::readChecked(polymarket_get_metrics_prometheus);
+  }
+
+  /// Venue circuit breaker state as JSON
+  Result<std::string> venueStatus() { return detail::readResponse(polymarket_venue_status()); }
+
+  /// Market identifiers as JSON, by id of kind POLYMARKET_ID_*
+  Result<std::string> lookupMarket(std::string_view id, int32_t kind)
+  {
+    std::string key(id);
+    return detail::readResponse(polymarket_lookup_market(key.c_str(), kind));
+  }
+
+  /// Binary markets open for trading as a JSON array, `limit` (1 to 500)
+  /// from `offset`
+  Result<std::string> listMarkets(uint32_t limit, uint32_t offset)
+  {
+    return detail::readResponse(polymarket_list_markets(limit, offset));
+  }
+
+  /// Venue responses that drifted from the expected shape, per source, as JSON
+  Result<std::string> schemaDrift() { return detail::readResponse(polymarket_schema_drift()); }
+
+  /// Market id of kind `from` converted to kind `to` (POLYMARKET_ID_*)
+  Result<std::string> convertId(std::string_view id, int32_t from, int32_t to)
+  {
+    std::string key(id);
+    int64_t error = 0;
+    std::string out = detail::readString(
+        [&](char* buf, size_t len) -> size_t
+        {
+          int64_t size = polymarket_convert_id(key.c_str(), from, to, buf, len);
+          if (size < 0)
+          {
+            error = size;
+            return 0;
+          }
+          return static_cast<size_t>(size);
+        });
+    if (error < 0)
+    {
+      return std::unexpected(Error{static_cast<int32_t>(error)});
+    }
+    return out;
+  }
+
+  /// Record signed orders before posting; empty path stops recording
+  Result<void> setSignedLog(std::string_view path)
+  {
+    if (path.empty())
+    {
+      return detail::check(polymarket_set_signed_log(nullptr));
+    }
+    std::string p(path);
+    return detail::check(polymarket_set_signed_log(p.c_str()));
+  }
+
+ private:
+  Session() = default;
+
+  bool _owner{true};
+};
+
+/// Executor of its own (polymarket_create), e.g. one per wallet; any number
+/// can run beside each other and beside a Session
+class Account
+{
+ public:
+  /// optionsJson as for polymarket_set_connect_options(), empty for the defaults
+  static Result<Account> create(std::string_view privateKey, std::string_view funderWallet,
+                                std::string_view optionsJson = {}, uint32_t flags = 0)
+  {
+    std::string pk(privateKey);
+    std::string funder(funderWallet);
+    std::string options(optionsJson);
+    polymarket_negotiate_abi(POLYMARKET_ABI_VERSION);
+    int32_t code = POLYMARKET_OK;
+    ::PolymarketHandle* handle = polymarket_create(
+        pk.c_str(), funder.c_str(), options.empty() ? nullptr : options.c_str(), flags, &code);
+    detail::scrub(pk);
+    if (handle == nullptr)
+    {
+      return std::unexpected(Error{code});
+    }
+    return Account(handle);
+  }
+
+  Account(Account&& other) noexcept : _handle(std::exchange(other._handle, nullptr)) {}
+
+  Account& operator=(Account&& other) noexcept
+  {
+    if (this != &other)
+    {
+      reset();
+      _handle = std::exchange(other._handle, nullptr);
+    }
+    return *this;
+  }
+
+  Account(const Account&) = delete;
+  Account& operator=(const Account&) = delete;
+
+  ~Account() { reset(); }
+
+  /// Shut the executor down early
+  void reset()
+  {
+    if (_handle != nullptr)
+    {
+      polymarket_destroy(std::exchange(_handle, nullptr), 0, 5000);
+    }
+  }
+
+  /// Shut down gracefully: drain running calls, then POLYMARKET_SHUTDOWN_* flags
+  Result<void> shutdown(uint32_t flags, uint32_t drainTimeoutMs = 5000)
+  {
+    if (_handle == nullptr)
+    {
+      return std::unexpected(Error{POLYMARKET_ERR_NOT_INITIALIZED});
+    }
+    return detail::check(polymarket_destroy(std::exchange(_handle, nullptr), flags,
+                                            drainTimeoutMs));
+  }
+
+  Result<OrderResult> marketBuy(std::string_view tokenId, double usdcAmount,
+                                std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_h_market_buy_ex(_handle, token.c_str(), usdcAmount, r); });
+  }
+
+  /// Market buy sweeping no higher than maxPrice instead of 0.99
+  Result<OrderResult> marketBuyCapped(std::string_view tokenId, double usdcAmount,
+                                      double maxPrice, std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_h_market_buy_capped_ex(_handle, token.c_str(), usdcAmount, maxPrice, r);
+        });
+  }
+
+  Result<OrderResult> marketSell(std::string_view tokenId, double size,
+                                 std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_h_market_sell_ex(_handle, token.c_str(), size, r); });
+  }
+
+  /// Market sell sweeping no lower than minPrice instead of 0.01
+  Result<OrderResult> marketSellCapped(std::string_view tokenId, double size, double minPrice,
+                                       std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_h_market_sell_capped_ex(_handle, token.c_str(), size, minPrice, r); });
+  }
+
+  /// Market orders capped from the book, see Session::marketBuyProtected
+  Result<OrderResult> marketBuyProtected(std::string_view tokenId, double usdcAmount,
+                                         uint32_t bufferBps, uint32_t maxSlippageBps,
+                                         std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_h_market_buy_protected_ex(_handle, token.c_str(), usdcAmount, bufferBps,
+                                                      maxSlippageBps, r);
+        });
+  }
+
+  Result<OrderResult> marketSellProtected(std::string_view tokenId, double size,
+                                          uint32_t bufferBps, uint32_t maxSlippageBps,
+                                          std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_h_market_sell_protected_ex(_handle, token.c_str(), size, bufferBps,
+                                                       maxSlippageBps, r);
+        });
+  }
+
+  Result<OrderResult> marketSellUsdc(std::string_view tokenId, double usdcAmount, double minPrice,
+                                     std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        {
+          return polymarket_h_market_sell_usdc_ex(_handle, token.c_str(), usdcAmount, minPrice, r);
+        });
+  }
+
+  Result<OrderResult> limitBuy(std::string_view tokenId, double price, double usdcAmount,
+                               std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_h_limit_buy_ex(_handle, token.c_str(), price, usdcAmount, r); });
+  }
+
+  Result<OrderResult> limitSell(std::string_view tokenId, double price, double size,
+                                std::string_view clientOrderId = {})
+  {
+    std::string token(tokenId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_h_limit_sell_ex(_handle, token.c_str(), price, size, r); });
+  }
+
+  Result<void> cancel(std::string_view orderId)
+  {
+    std::string id(orderId);
+    int32_t code = polymarket_h_cancel(_handle, id.c_str());
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, detail::readString(polymarket_last_error_message)});
+    }
+    return {};
+  }
+
+  Result<void> cancelAll()
+  {
+    return detail::check(polymarket_h_cancel_all_ex(_handle, nullptr, 0, nullptr));
+  }
+
+  /// Cancel the open orders of one token only
+  Result<void> cancelMarket(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::check(
+        polymarket_h_cancel_market_ex(_handle, token.c_str(), nullptr, 0, nullptr));
+  }
+
+  /// Cancel orders by id in one request and return the outcome of each
+  Result<std::vector<PolymarketCancelOutcome>> cancelOrders(
+      const std::vector<std::string>& orderIds)
+  {
+    return detail::cancelByIds(
+        orderIds,
+        [this](const char* const* ids, size_t count, PolymarketCancelOutcome* out,
+               size_t capacity, size_t* outCount)
+        { return polymarket_h_cancel_orders_ex(_handle, ids, count, out, capacity, outCount); });
+  }
+
+  /// Cancel a resting order and post it again at newPrice for newSize shares
+  /// (<= 0 keeps the unmatched size) in one call; nothing is posted if the
+  /// cancel fails (POLYMARKET_ERR_CANCEL_FAILED)
+  Result<OrderResult> replaceOrder(std::string_view orderId, double newPrice, double newSize,
+                                   std::string_view clientOrderId = {})
+  {
+    std::string id(orderId);
+    return detail::placeAs(
+        clientOrderId,
+        [&](::PolymarketOrderResultEx* r)
+        { return polymarket_h_replace_order(_handle, id.c_str(), newPrice, newSize, r); });
+  }
+
+  /// Orders resting on the book, of one token or (empty) of every market
+  Result<std::vector<PolymarketOpenOrder>> openOrders(std::string_view tokenId = {})
+  {
+    std::string token(tokenId);
+    const char* filter = token.empty() ? nullptr : token.c_str();
+    return detail::readArray<PolymarketOpenOrder>(
+        [&](PolymarketOpenOrder* out, size_t capacity, size_t* count)
+        { return polymarket_h_get_open_orders(_handle, filter, out, capacity, count); });
+  }
+
+  /// Our fills matched since sinceMs (unix ms), of one token or (empty) of
+  /// every market, oldest first
+  Result<std::vector<PolymarketTrade>> trades(uint64_t sinceMs, std::string_view tokenId = {})
+  {
+    std::string token(tokenId);
+    const char* filter = token.empty() ? nullptr : token.c_str();
+    return detail::readArray<PolymarketTrade>(
+        [&](PolymarketTrade* out, size_t capacity, size_t* count)
+        { return polymarket_h_get_trades(_handle, filter, sinceMs, out, capacity, count); });
+  }
+
+  /// JSON {client_order_id, state, order_id} of the order placed under
+  /// `clientOrderId`
+  Result<std::string> clientOrder(std::string_view clientOrderId)
+  {
+    std::string id(clientOrderId);
+    return detail::readResponse(polymarket_h_get_client_order(_handle, id.c_str()));
+  }
+
+  /// State of an order and how much of it matched, asked from the venue
+  Result<::PolymarketOrderStatus> order(std::string_view orderId)
+  {
+    std::string id(orderId);
+    ::PolymarketOrderStatus status{};
+    status.struct_size = sizeof(status);
+    int32_t code = polymarket_h_get_order(_handle, id.c_str(), &status);
+    if (code != POLYMARKET_OK)
+    {
+      return std::unexpected(Error{code, detail::readString(polymarket_last_error_message)});
+    }
+    return status;
+  }
+
+  /// USDC balance in raw units (6 decimals)
+  Result<int64_t> balance() { return detail::checkRaw(polymarket_h_get_balance(_handle)); }
+
+  /// Shares held in raw units (6 decimals)
+  Result<int64_t> tokenBalance(std::string_view tokenId)
+  {
+    std::string token(tokenId);
+    return detail::checkRaw(polymarket_h_get_token_balance(_handle, token.c_str()));
+  }
+
+  /// Every conditional token position of the funder wallet, as JSON
+  Result<std::string> positions()
+  {
+    return detail::readResponse(polymarket_h_get_positions(_handle));
+  }
+
+  /// Split amountRaw USDC into as many YES and NO shares on-chain; JSON {"tx_hash"}
+  Result<std::string> splitPosition(std::string_view conditionId, bool negRisk, int64_t amountRaw)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(
+        polymarket_h_split_position(_handle, condition.c_str(), negRisk, amountRaw));
+  }
+
+  /// Merge amountRaw YES and NO shares back into USDC on-chain; JSON {"tx_hash"}
+  Result<std::string> mergePositions(std::string_view conditionId, bool negRisk, int64_t amountRaw)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(
+        polymarket_h_merge_positions(_handle, condition.c_str(), negRisk, amountRaw));
+  }
+
+  /// Convert amountRaw NO shares of each question into YES of the event's
+  /// other questions on-chain; JSON {"tx_hash"}
+  Result<std::string> convertPositions(const std::vector<std::string>& questionIds,
+                                       int64_t amountRaw)
+  {
+    std::vector<const char*> ids = detail::cStrings(questionIds);
+    return detail::readResponse(
+        polymarket_h_convert_positions(_handle, ids.data(), ids.size(), amountRaw));
+  }
+
+  /// Redeem the shares of a resolved market for USDC on-chain; JSON {"tx_hash"}
+  Result<std::string> redeem(std::string_view conditionId)
+  {
+    std::string condition(conditionId);
+    return detail::readResponse(polymarket_h_redeem(_handle, condition.c_str()));
+  }
+
+  /// Token approvals of the funder wallet as JSON; POLYMARKET_ERR_USDC_ALLOWANCE
+  /// or POLYMARKET_ERR_TOKEN_APPROVAL if one is missing
+  Result<std::string> checkAllowances()
+  {
+    return detail::readResponse(polymarket_h_check_allowances(_handle));
+  }
+
+  /// Set the missing approvals on-chain; JSON {"tx_hash"}, null if none was
+  Result<std::string> approveAllowances()
+  {
+    return detail::readResponse(polymarket_h_approve_allowances(_handle));
+  }
+
+  /// POL balance of the signing key in raw units (6 decimals)
+  Result<int64_t> gasBalance()
+  {
+    int64_t raw = polymarket_h_get_gas_balance(_handle);
+    if (raw < 0)
+    {
+      return std::unexpected(Error{static_cast<int32_t>(raw)});
+    }
+    return raw;
+  }
+
+  /// Log a warning when the signing key's POL balance falls below thresholdPol
+  Result<void> setLowGasThreshold(double thresholdPol)
+  {
+    return detail::check(polymarket_h_set_low_gas_threshold(_handle, thresholdPol));
+  }
+
+  /// Journal order and cancel requests to a JSONL file; empty path stops
+  Result<void> setJournal(std::string_view path)
+  {
+    if (path.empty())
+    {
+      return detail::check(polymarket_h_set_journal(_handle, nullptr));
+    }
+    std::string p(path);
+    return detail::check(polymarket_h_set_journal(_handle, p.c_str()));
+  }
+
+  /// Kill switch: refuse new orders until resumeTrading(); cancelOpen also pulls every order
+  Result<void> haltTrading(bool cancelOpen)
+  {
+    return detail::check(polymarket_h_halt_trading(_handle, cancelOpen));
+  }
+
+  Result<void> resumeTrading() { return detail::check(polymarket_h_resume_trading(_handle)); }
+
+  Result<bool> tradingHalted()
+  {
+    int32_t halted = polymarket_h_is_trading_halted(_handle);
+    if (halted < 0)
+    {
+      return std::unexpected(Error{POLYMARKET_ERR_NOT_INITIALIZED});
+    }
+    return halted != 0;
+  }
+
+  /// Raw handle, for the polymarket_h_*() calls not wrapped here
+  ::PolymarketHandle* handle() const { return _handle; }
+
+ private:
+  explicit Account(::PolymarketHandle* handle) : _handle(handle) {}
+
+  ::PolymarketHandle* _handle{nullptr};
+};
+
+/// Set POLYMARKET_INIT_* flags for the next Session::create*()
+inline Result<void> setInitFlags(uint32_t flags)
+{
+  return detail::check(polymarket_set_init_flags(flags));
+}
+
+/// Check a signed log's hash chain; returns the number of records
+inline Result<int64_t> verifySignedLog(std::string_view path)
+{
+  std::string p(path);
+  int64_t count = polymarket_verify_signed_log(p.c_str());
+  if (count < 0)
+  {
+    return std::unexpected(Error{static_cast<int32_t>(count)});
+  }
+  return count;
+}
+
+/// Set endpoints, proxy and TLS (JSON) for the next Session::create*()
+inline Result<void> setConnectOptions(std::string_view json)
+{
+  std::string j(json);
+  return detail::check(polymarket_set_connect_options(j.c_str()));
+}
+
+/// Restore the default endpoints, no proxy, system CAs only
+inline Result<void> resetConnectOptions()
+{
+  return detail::check(polymarket_set_connect_options(nullptr));
+}
+
+/// Journal order and cancel requests to a JSONL file, from the next
+/// Session::create*() on or at once on a live session; empty path stops
+inline Result<void> setJournal(std::string_view path)
+{
+  if (path.empty())
+  {
+    return detail::check(polymarket_set_journal(nullptr));
+  }
+  std::string p(path);
+  return detail::check(polymarket_set_journal(p.c_str()));
+}
+
+/// Choose what Session::warmup() warms (JSON); empty restores the default
+inline Result<void> setWarmupProfile(std::string_view json)
+{
+  if (json.empty())
+  {
+    return detail::check(polymarket_set_warmup_profile(nullptr));
+  }
+  std::string j(json);
+  return detail::check(polymarket_set_warmup_profile(j.c_str()));
+}
+
+/// Register an event callback; independent of Session lifetime
+/// Returns the subscription id
+inline Result<int32_t> registerCallback(uint32_t kindMask, PolymarketEventCallback callback,
+                                        void* userData,
+                                        int32_t delivery = POLYMARKET_DELIVER_THREAD)
+{
+  int32_t id = polymarket_register_callback(kindMask, callback, userData, delivery);
+  if (id < 0)
+  {
+    return std::unexpected(Error{id});
+  }
+  return id;
+}
+
+inline Result<void> unregisterCallback(int32_t subscriptionId)
+{
+  return detail::check(polymarket_unregister_callback(subscriptionId));
+}
+
+/// Route log lines at minLevel (1 = ERROR .. 5 = TRACE) and more severe to
+/// callback as JSON; nullptr stops
+inline Result<void> setLogCallback(PolymarketLogCallback callback, int32_t minLevel,
+                                   void* userData = nullptr)
+{
+  return detail::check(polymarket_set_log_callback(callback, minLevel, userData));
+}
+
+/// Message behind the error of the last order or cancel call on this thread
+inline std::string lastErrorMessage()
+{
+  return detail::readString(polymarket_last_error_message);
+}
+
+/// Message, location and backtrace of the last panic caught in the library
+inline std::string lastPanic() { return detail::readString(polymarket_last_panic); }
+
+/// Run queued POLYMARKET_DELIVER_POLL callbacks on this thread
+inline uint32_t pollEvents(uint32_t maxEvents) { return polymarket_poll_events(maxEvents); }
+
+}  // namespace polymarket
@@ -0,0 +1,2579 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+/*
+ * Generated by cbindgen from src/polymarket/ffi - do not edit. After changing
+ * the Rust definitions, build with FLOX_UPDATE_HEADERS=1 to update it.
+ * Documented in polymarket_ffi.h.
+ */
+
+#ifndef POLYMARKET_FFI_GENERATED_H
+#define POLYMARKET_FFI_GENERATED_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Levels per side in a PolymarketBookSnapshot
+ */
+#define POLYMARKET_BOOK_DEPTH 10
+
+/**
+ * Resting, nothing matched yet
+ */
+#define POLYMARKET_ORDER_STATUS_LIVE 0
+
+/**
+ * Resting, partly matched
+ */
+#define POLYMARKET_ORDER_STATUS_PARTIALLY_FILLED 1
+
+/**
+ * Fully matched
+ */
+#define POLYMARKET_ORDER_STATUS_MATCHED 2
+
+/**
+ * Cancelled, possibly partly matched
+ */
+#define POLYMARKET_ORDER_STATUS_CANCELLED 3
+
+/**
+ * See status_text
+ */
+#define POLYMARKET_ORDER_STATUS_OTHER 4
+
+#define POLYMARKET_ORDER_UPDATE_PLACED 0
+
+/**
+ * Resting order partly or fully matched
+ */
+#define POLYMARKET_ORDER_UPDATE_MATCHED 1
+
+#define POLYMARKET_ORDER_UPDATE_CANCELLED 2
+
+/**
+ * A fill, once per trade status
+ */
+#define POLYMARKET_ORDER_UPDATE_TRADE 3
+
+/**
+ * Order posts (single and batch) and cancels
+ */
+#define POLYMARKET_CALL_ORDER 0
+
+/**
+ * Books, prefetch, prices, market lookups, warmup
+ */
+#define POLYMARKET_CALL_MARKET_DATA 1
+
+/**
+ * Balances, order lookups, rewards, notifications
+ */
+#define POLYMARKET_CALL_ACCOUNT 2
+
+/**
+ * Rate limit flags (polymarket_set_rate_limit)
+ */
+#define POLYMARKET_RATE_REJECT 1
+
+#define POLYMARKET_SIDE_BUY 0
+
+#define POLYMARKET_SIDE_SELL 1
+
+/**
+ * Send only if every leg can fill, else unwind
+ */
+#define POLYMARKET_BASKET_ALL_OR_NOTHING 1
+
+/**
+ * Best effort: unwind every fill on any shortfall
+ */
+#define POLYMARKET_BASKET_UNWIND 2
+
+/**
+ * Cancelled
+ */
+#define POLYMARKET_CANCEL_CANCELLED 0
+
+/**
+ * Matched before the cancel arrived
+ */
+#define POLYMARKET_CANCEL_ALREADY_FILLED 1
+
+/**
+ * Unknown to the venue, or already cancelled
+ */
+#define POLYMARKET_CANCEL_NOT_FOUND 2
+
+/**
+ * Refused by the venue: may still be resting
+ */
+#define POLYMARKET_CANCEL_FAILED 3
+
+#define POLYMARKET_OK 0
+
+/**
+ * Not an error: still in flight, or no data yet
+ */
+#define POLYMARKET_PENDING 1
+
+#define POLYMARKET_ERR_NOT_INITIALIZED -1
+
+#define POLYMARKET_ERR_INVALID_PK -2
+
+#define POLYMARKET_ERR_AUTH_FAILED -3
+
+#define POLYMARKET_ERR_INVALID_TOKEN -4
+
+#define POLYMARKET_ERR_ORDER_FAILED -5
+
+#define POLYMARKET_ERR_CANCEL_FAILED -6
+
+/**
+ * Order below $1 minimum
+ */
+#define POLYMARKET_ERR_MIN_ORDER_SIZE -7
+
+/**
+ * Shares below market minimum
+ */
+#define POLYMARKET_ERR_MIN_SHARES -8
+
+/**
+ * Cached USDC can't fund the buy
+ */
+#define POLYMARKET_ERR_INSUFFICIENT_BALANCE -9
+
+/**
+ * Config rejected by validation
+ */
+#define POLYMARKET_ERR_INVALID_CONFIG -10
+
+/**
+ * Order rejected by risk limits
+ */
+#define POLYMARKET_ERR_RISK_LIMIT -11
+
+/**
+ * Null or malformed out-parameter
+ */
+#define POLYMARKET_ERR_INVALID_ARGUMENT -12
+
+/**
+ * Aborted locally, venue state unknown
+ */
+#define POLYMARKET_ERR_ABORTED -13
+
+/**
+ * Internal panic, see polymarket_last_panic()
+ */
+#define POLYMARKET_ERR_PANIC -14
+
+/**
+ * Blocked: initialized with POLYMARKET_INIT_READ_ONLY
+ */
+#define POLYMARKET_ERR_READ_ONLY -15
+
+/**
+ * Price not a multiple of the market's current tick size
+ */
+#define POLYMARKET_ERR_TICK_SIZE -16
+
+/**
+ * Book too thin for the shares within the price cap
+ */
+#define POLYMARKET_ERR_LIQUIDITY -17
+
+/**
+ * Orders paused while the venue is down
+ */
+#define POLYMARKET_ERR_VENUE_UNAVAILABLE -18
+
+/**
+ * Venue refused the request (4xx, or success=false)
+ */
+#define POLYMARKET_ERR_REJECTED -19
+
+/**
+ * Timeout or connection error, venue state unknown
+ */
+#define POLYMARKET_ERR_NETWORK -20
+
+/**
+ * Venue answered 429, or over the local budget
+ */
+#define POLYMARKET_ERR_RATE_LIMITED -21
+
+/**
+ * Venue answered 5xx
+ */
+#define POLYMARKET_ERR_VENUE_ERROR -22
+
+/**
+ * Amount, price or expiry not valid (checked locally)
+ */
+#define POLYMARKET_ERR_INVALID_ORDER -23
+
+/**
+ * Expected slippage on the book over the caller's cap
+ */
+#define POLYMARKET_ERR_SLIPPAGE -24
+
+/**
+ * Client order id in use by an order that may be live
+ */
+#define POLYMARKET_ERR_DUPLICATE_ORDER -25
+
+/**
+ * Wallet has not approved USDC.e for the exchanges
+ */
+#define POLYMARKET_ERR_USDC_ALLOWANCE -26
+
+/**
+ * Wallet has not approved outcome tokens for them
+ */
+#define POLYMARKET_ERR_TOKEN_APPROVAL -27
+
+/**
+ * Trading halted (polymarket_halt_trading)
+ */
+#define POLYMARKET_ERR_HALTED -28
+
+/**
+ * Queries and market data only; every order, cancel and on-chain call
+ * fails with POLYMARKET_ERR_READ_ONLY
+ */
+#define POLYMARKET_INIT_READ_ONLY 1
+
+/**
+ * Install no tracing subscriber, for hosts that install their own; nothing
+ * is logged to stdout, POLYMARKET_EVENT_LOG or the log callback. Only the
+ * first init of the process installs one
+ */
+#define POLYMARKET_INIT_NO_TRACING 2
+
+/**
+ * Paper trading; orders pass every local check and are filled against the
+ * order book (the market channel's if fresh, else a REST snapshot) instead
+ * of being posted, with order ids "paper-<n>". Limit orders fill what
+ * crosses and rest the remainder, which never fills later. Cancels,
+ * replace and polymarket_get_open_orders() see paper orders only; fills
+ * update positions and the cached balance. On-chain calls fail with
+ * POLYMARKET_ERR_READ_ONLY. Queries and market data stay live
+ */
+#define POLYMARKET_INIT_DRY_RUN 4
+
+/**
+ * Condition ID (0x-prefixed 32-byte hex)
+ */
+#define POLYMARKET_ID_CONDITION 0
+
+/**
+ * Question ID (0x-prefixed 32-byte hex)
+ */
+#define POLYMARKET_ID_QUESTION 1
+
+/**
+ * Market slug
+ */
+#define POLYMARKET_ID_SLUG 2
+
+/**
+ * YES token ID; as input, either token
+ */
+#define POLYMARKET_ID_YES_TOKEN 3
+
+/**
+ * NO token ID; as input, either token
+ */
+#define POLYMARKET_ID_NO_TOKEN 4
+
+/**
+ * Stop watching the market
+ */
+#define POLYMARKET_MERGE_OFF 0
+
+/**
+ * Report mergeable pairs only
+ */
+#define POLYMARKET_MERGE_DETECT 1
+
+/**
+ * Merge pairs on-chain into USDC
+ */
+#define POLYMARKET_MERGE_AUTO 2
+
+/**
+ * No outcome proposed, or a disputed proposal not yet replaced
+ */
+#define POLYMARKET_RESOLUTION_OPEN 0
+
+/**
+ * Outcome proposed to the oracle, final unless disputed
+ */
+#define POLYMARKET_RESOLUTION_PROPOSED 1
+
+/**
+ * Proposal disputed, outcome pending the oracle's vote
+ */
+#define POLYMARKET_RESOLUTION_DISPUTED 2
+
+/**
+ * Outcome final: winning shares can be redeemed
+ */
+#define POLYMARKET_RESOLUTION_RESOLVED 3
+
+/**
+ * Cancel every resting order once running calls have drained
+ */
+#define POLYMARKET_SHUTDOWN_CANCEL_ALL 1
+
+/**
+ * Latest layout version this library knows
+ * v1: PolymarketOrderResult fields
+ * v2: + remaining_qty_raw, fee_raw
+ * v3: + client_order_id
+ * v4: + generation, stale of market channel books
+ */
+#define POLYMARKET_ABI_VERSION 4
+
+/**
+ * Order result: code = error code
+ */
+#define POLYMARKET_EVENT_ORDER 1
+
+/**
+ * Book, trade or tick size update: code = 0
+ */
+#define POLYMARKET_EVENT_MARKET_DATA 2
+
+/**
+ * Log line: code = level, 1 (ERROR) .. 5 (TRACE)
+ */
+#define POLYMARKET_EVENT_LOG 4
+
+/**
+ * Risk rejection or limits change: code = error code
+ */
+#define POLYMARKET_EVENT_RISK 8
+
+/**
+ * Account notification: code = notification type
+ */
+#define POLYMARKET_EVENT_NOTIFICATION 16
+
+/**
+ * Execution algo progress: code = error code
+ */
+#define POLYMARKET_EVENT_ALGO 32
+
+/**
+ * Quoting action: code = error code
+ */
+#define POLYMARKET_EVENT_QUOTE 64
+
+/**
+ * YES/NO pair found or merged: code = error code
+ */
+#define POLYMARKET_EVENT_MERGE 128
+
+/**
+ * YES+NO mispricing opened, changed or closed
+ */
+#define POLYMARKET_EVENT_ARBITRAGE 256
+
+/**
+ * Fill of a tracked order, with running totals
+ */
+#define POLYMARKET_EVENT_FILL 512
+
+/**
+ * Tracked order completed: final totals
+ */
+#define POLYMARKET_EVENT_ORDER_SUMMARY 1024
+
+/**
+ * Credential failover: code = error code
+ */
+#define POLYMARKET_EVENT_CREDENTIALS 2048
+
+/**
+ * Venue circuit breaker opened or closed
+ */
+#define POLYMARKET_EVENT_VENUE 4096
+
+/**
+ * Watched market's resolution status changed: code = POLYMARKET_RESOLUTION_*
+ */
+#define POLYMARKET_EVENT_RESOLUTION 8192
+
+#define POLYMARKET_DELIVER_THREAD 0
+
+#define POLYMARKET_DELIVER_POLL 1
+
+/**
+ * "PMSHMQ01", set last once the region is initialized
+ */
+#define POLYMARKET_SHM_MAGIC 3544422299108330832
+
+#define POLYMARKET_SHM_VERSION 1
+
+#define POLYMARKET_SHM_CMD_MARKET_BUY 1
+
+#define POLYMARKET_SHM_CMD_MARKET_SELL 2
+
+#define POLYMARKET_SHM_CMD_LIMIT_BUY 3
+
+#define POLYMARKET_SHM_CMD_LIMIT_SELL 4
+
+#define POLYMARKET_SHM_CMD_CANCEL 5
+
+/**
+ * Executor created by polymarket_create(), opaque to C
+ */
+typedef struct PolymarketHandle PolymarketHandle;
+
+/**
+ * Result structure returned to C++
+ * All monetary values are in raw units (6 decimals)
+ */
+typedef struct {
+  /**
+   * True if order was successful
+   */
+  bool success;
+  /**
+   * Shares filled in raw units (divide by 1M for actual)
+   */
+  int64_t filled_qty_raw;
+  /**
+   * Average price in raw units (divide by 1M for actual)
+   */
+  int64_t avg_price_raw;
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Order ID string (see polymarket_last_order_id for the full id)
+   */
+  char order_id[128];
+} PolymarketOrderResult;
+
+/**
+ * Versioned order result, filled by the polymarket_*_ex() entry points
+ * Starts with the struct_size/version header (see abi.rs); new fields are
+ * only ever appended. The caller sets struct_size and the library writes
+ * at most that many bytes; version is the ABI version negotiated with
+ * polymarket_negotiate_abi(), fields added after it are left zeroed
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketOrderResultEx)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * True if order was successful
+   */
+  bool success;
+  /**
+   * Shares filled in raw units (divide by 1M for actual)
+   */
+  int64_t filled_qty_raw;
+  /**
+   * Average price in raw units (divide by 1M for actual)
+   */
+  int64_t avg_price_raw;
+  /**
+   * Total execution latency
+   */
+  uint64_t latency_ms;
+  /**
+   * Error code if failed
+   */
+  int32_t error_code;
+  /**
+   * Order ID string
+   */
+  char order_id[128];
+  /**
+   * Shares still resting on the book (0 for FAK)
+   */
+  int64_t remaining_qty_raw;
+  /**
+   * Taker fee in shares, 0 if none or unknown
+   */
+  int64_t fee_raw;
+  /**
+   * Client order id it was placed under, empty if none
+   */
+  char client_order_id[64];
+} PolymarketOrderResultEx;
+
+/**
+ * One limit order of a batch (polymarket_post_orders_batch)
+ */
+typedef struct {
+  /**
+   * Numeric token id
+   */
+  const char *token_id;
+  /**
+   * POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+   */
+  int32_t side;
+  /**
+   * Limit price
+   */
+  double price;
+  /**
+   * USDC to spend (buy) or shares to sell
+   */
+  double amount;
+  /**
+   * 0 for GTC, else GTD deadline (unix ms)
+   */
+  uint64_t expires_at_ms;
+} PolymarketBatchOrder;
+
+/**
+ * Outcome of one order in a bulk cancel (polymarket_cancel_all_ex)
+ */
+typedef struct {
+  /**
+   * NUL-terminated
+   */
+  char order_id[128];
+  /**
+   * POLYMARKET_CANCEL_*
+   */
+  int32_t status;
+  /**
+   * Venue's reason, truncated; empty when cancelled
+   */
+  char reason[128];
+} PolymarketCancelOutcome;
+
+/**
+ * Order resting on the book (polymarket_get_open_orders)
+ */
+typedef struct {
+  /**
+   * NUL-terminated
+   */
+  char order_id[128];
+  /**
+   * NUL-terminated
+   */
+  char token_id[128];
+  /**
+   * POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+   */
+  int32_t side;
+  /**
+   * Limit price (6 decimals)
+   */
+  int64_t price_raw;
+  /**
+   * Order size in shares (6 decimals)
+   */
+  int64_t original_size_raw;
+  /**
+   * Shares not matched yet (6 decimals)
+   */
+  int64_t remaining_size_raw;
+  /**
+   * Unix ms
+   */
+  uint64_t created_at_ms;
+} PolymarketOpenOrder;
+
+/**
+ * One fill of one of our orders (polymarket_get_trades)
+ */
+typedef struct {
+  /**
+   * NUL-terminated
+   */
+  char trade_id[128];
+  /**
+   * NUL-terminated
+   */
+  char order_id[128];
+  /**
+   * NUL-terminated
+   */
+  char token_id[128];
+  /**
+   * POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+   */
+  int32_t side;
+  /**
+   * Fill price (6 decimals)
+   */
+  int64_t price_raw;
+  /**
+   * Shares filled (6 decimals)
+   */
+  int64_t size_raw;
+  /**
+   * Taker fee in shares (buys), 0 if none
+   */
+  int64_t fee_raw;
+  /**
+   * Our order rested on the book
+   */
+  bool maker;
+  /**
+   * MATCHED, MINED, CONFIRMED, RETRYING, FAILED
+   */
+  char status[16];
+  /**
+   * Unix ms
+   */
+  uint64_t matched_at_ms;
+} PolymarketTrade;
+
+/**
+ * State of an order, filled by polymarket_get_order()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call;
+ * raw values in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketOrderStatus)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * POLYMARKET_ORDER_STATUS_*
+   */
+  int32_t status;
+  /**
+   * Limit price
+   */
+  int64_t price_raw;
+  /**
+   * Order size in shares
+   */
+  int64_t original_size_raw;
+  /**
+   * Shares matched so far
+   */
+  int64_t filled_size_raw;
+  /**
+   * Shares still resting, 0 once matched or cancelled
+   */
+  int64_t remaining_size_raw;
+  /**
+   * Venue status: LIVE, MATCHED, CANCELED, ...
+   */
+  char status_text[16];
+} PolymarketOrderStatus;
+
+/**
+ * Request budget of a call class, filled by polymarket_get_rate_budget()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketRateBudget)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * Sustained rate, 0 if the class is not limited
+   */
+  double per_sec;
+  /**
+   * Calls that can go back to back
+   */
+  uint32_t burst;
+  /**
+   * POLYMARKET_RATE_*
+   */
+  uint32_t flags;
+  /**
+   * Calls that can go now; below 0 while calls wait
+   */
+  double available;
+} PolymarketRateBudget;
+
+/**
+ * Pre-trade fill estimate, filled by polymarket_estimate_fill()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call;
+ * raw values in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketFillEstimate)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * Shares bought/sold, before fees
+   */
+  int64_t filled_qty_raw;
+  /**
+   * USDC spent (buy) or received (sell)
+   */
+  int64_t notional_raw;
+  /**
+   * 0 if nothing fills
+   */
+  int64_t avg_price_raw;
+  /**
+   * Deepest level reached, 0 if nothing fills
+   */
+  int64_t worst_price_raw;
+  /**
+   * Taker fee in shares (buys), 0 for sells
+   */
+  int64_t fee_raw;
+  /**
+   * Part of the amount that fills, 0.0 - 1.0
+   */
+  double filled_fraction;
+  /**
+   * Age of the book snapshot walked
+   */
+  uint64_t book_age_ms;
+} PolymarketFillEstimate;
+
+/**
+ * Top of a market channel book, filled by polymarket_get_best_bid_ask()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call;
+ * raw values in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketBestBidAsk)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * 0 if no bids
+   */
+  int64_t bid_price_raw;
+  /**
+   * Shares at the best bid
+   */
+  int64_t bid_size_raw;
+  /**
+   * 0 if no asks
+   */
+  int64_t ask_price_raw;
+  /**
+   * Shares at the best ask
+   */
+  int64_t ask_size_raw;
+  /**
+   * Since the last update of the book
+   */
+  uint64_t age_ms;
+  /**
+   * v4: generation of the book these were read from
+   */
+  uint64_t generation;
+  /**
+   * v4: the book missed updates and waits for a resync
+   */
+  bool stale;
+} PolymarketBestBidAsk;
+
+/**
+ * Consistency of a market channel book, filled by
+ * polymarket_get_book_state()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketBookState)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * Bumped by every change of the book, from 1 for the first snapshot
+   */
+  uint64_t generation;
+  /**
+   * Gaps detected since subscribed
+   */
+  uint64_t gaps;
+  /**
+   * Stale books restored from a REST snapshot since subscribed
+   */
+  uint64_t resyncs;
+  /**
+   * Venue time of the newest message applied, 0 if none sent one
+   */
+  uint64_t timestamp_ms;
+  /**
+   * Since the last update of the book
+   */
+  uint64_t age_ms;
+  /**
+   * Missed updates; waiting for the venue to resend the book or a resync
+   */
+  bool stale;
+} PolymarketBookState;
+
+typedef struct {
+  int64_t price_raw;
+  /**
+   * Shares
+   */
+  int64_t size_raw;
+} PolymarketBookLevel;
+
+/**
+ * Top levels of a market channel book, filled by
+ * polymarket_get_book_snapshot()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketBookSnapshot)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * Levels set in bids, best (highest) first
+   */
+  uint32_t bid_count;
+  /**
+   * Levels set in asks, best (lowest) first
+   */
+  uint32_t ask_count;
+  /**
+   * Since the last update of the book
+   */
+  uint64_t age_ms;
+  PolymarketBookLevel bids[POLYMARKET_BOOK_DEPTH];
+  PolymarketBookLevel asks[POLYMARKET_BOOK_DEPTH];
+  /**
+   * v4: generation of the book these were read from
+   */
+  uint64_t generation;
+  /**
+   * v4: the book missed updates and waits for a resync
+   */
+  bool stale;
+} PolymarketBookSnapshot;
+
+/**
+ * Candle of a token's price, filled by polymarket_get_price_history()
+ */
+typedef struct {
+  /**
+   * Unix seconds, a multiple of the candle length
+   */
+  int64_t start_ts;
+  int64_t open_raw;
+  int64_t high_raw;
+  int64_t low_raw;
+  int64_t close_raw;
+  /**
+   * Price samples it was built from, at least 1
+   */
+  uint32_t samples;
+} PolymarketCandle;
+
+/**
+ * One leg of a basket order (polymarket_place_basket)
+ */
+typedef struct {
+  /**
+   * Polymarket token ID (numeric string)
+   */
+  const char *token_id;
+  /**
+   * POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+   */
+  int32_t side;
+  /**
+   * Shares to buy (net of fees) or sell
+   */
+  double shares;
+  /**
+   * Highest price paid (buy) or lowest accepted (sell)
+   */
+  double limit_price;
+} PolymarketBasketLeg;
+
+/**
+ * Cached token metadata, filled by polymarket_get_market_meta()
+ * Versioned like PolymarketOrderResultEx: set struct_size before the call;
+ * raw values in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  /**
+   * Set by caller: sizeof(PolymarketMarketMeta)
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * Current tick size
+   */
+  int64_t tick_size_raw;
+  /**
+   * Smallest order in shares
+   */
+  int64_t min_order_size_raw;
+  /**
+   * Fee rate the orders are signed with
+   */
+  uint32_t fee_rate_bps;
+  /**
+   * Market of a neg-risk event
+   */
+  bool neg_risk;
+} PolymarketMarketMeta;
+
+/**
+ * Event passed to callbacks; pointers are valid only during the call
+ */
+typedef struct {
+  /**
+   * sizeof(PolymarketEvent) as known by the library
+   */
+  uint32_t struct_size;
+  /**
+   * Negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * One POLYMARKET_EVENT_* bit
+   */
+  uint32_t kind;
+  /**
+   * Kind-specific, see POLYMARKET_EVENT_*
+   */
+  int32_t code;
+  /**
+   * Unix time of emission
+   */
+  uint64_t timestamp_ms;
+  /**
+   * NULL if not token-specific
+   */
+  const char *token_id;
+  /**
+   * JSON object, never NULL
+   */
+  const char *payload;
+} PolymarketEvent;
+
+/**
+ * Order or fill of the account, from the user channel
+ * Passed to PolymarketOrderUpdateCallback; versioned like
+ * PolymarketOrderResultEx, struct_size is set by the library. Raw values
+ * in 6 decimals (divide by 1M for actual)
+ */
+typedef struct {
+  /**
+   * Set by library: sizeof the struct it was built with
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * POLYMARKET_ORDER_UPDATE_*
+   */
+  int32_t kind;
+  /**
+   * POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+   */
+  int32_t side;
+  /**
+   * Order limit price; fill price for trades
+   */
+  int64_t price_raw;
+  /**
+   * Order size in shares, 0 for trades
+   */
+  int64_t original_size_raw;
+  /**
+   * Shares matched so far, 0 for trades
+   */
+  int64_t size_matched_raw;
+  /**
+   * Shares of this fill, 0 for order events
+   */
+  int64_t trade_size_raw;
+  /**
+   * Venue time of the event
+   */
+  uint64_t timestamp_ms;
+  /**
+   * Trades: our order was resting on the book
+   */
+  bool maker;
+  char order_id[128];
+  char token_id[128];
+  /**
+   * Empty for order events
+   */
+  char trade_id[128];
+  /**
+   * Trades: MATCHED, MINED, CONFIRMED, RETRYING, FAILED
+   */
+  char trade_status[16];
+} PolymarketOrderUpdate;
+
+/**
+ * A trade printed on a token's public tape, from the market channel
+ * Passed to PolymarketTradeTapeCallback; versioned like
+ * PolymarketOrderUpdate. Raw values in 6 decimals
+ */
+typedef struct {
+  /**
+   * Set by library: sizeof the struct it was built with
+   */
+  uint32_t struct_size;
+  /**
+   * Set by library: negotiated ABI version
+   */
+  uint32_t version;
+  /**
+   * Taker side: POLYMARKET_SIDE_BUY lifted an ask, POLYMARKET_SIDE_SELL
+   * hit a bid
+   */
+  int32_t side;
+  int64_t price_raw;
+  /**
+   * Shares traded
+   */
+  int64_t size_raw;
+  /**
+   * Venue time of the trade, 0 if not sent
+   */
+  uint64_t timestamp_ms;
+  char token_id[128];
+} PolymarketTradePrint;
+
+/**
+ * Command record written by the host
+ */
+typedef struct {
+  /**
+   * Chosen by the host, echoed in the response
+   */
+  uint64_t tag;
+  /**
+   * POLYMARKET_SHM_CMD_*
+   */
+  uint32_t kind;
+  uint32_t _reserved;
+  /**
+   * Limit price (LIMIT_*), ignored otherwise
+   */
+  double price;
+  /**
+   * USDC for buys, shares for sells
+   */
+  double amount;
+  /**
+   * Token id, or order id for CANCEL (NUL-terminated)
+   */
+  char id[128];
+} PolymarketShmCommand;
+
+/**
+ * Response record written by the library
+ */
+typedef struct {
+  uint64_t tag;
+  int32_t error_code;
+  uint32_t success;
+  int64_t filled_qty_raw;
+  int64_t avg_price_raw;
+  int64_t remaining_qty_raw;
+  int64_t fee_raw;
+  uint64_t latency_ms;
+  char order_id[128];
+} PolymarketShmResponse;
+
+typedef void (*PolymarketEventCallback)(const PolymarketEvent *event, void *user_data);
+
+/**
+ * Host log sink: `line` is one JSON object, valid only during the call
+ */
+typedef void (*PolymarketLogCallback)(int32_t level, const char *line, void *user_data);
+
+/**
+ * Completion callback of the polymarket_*_async() entry points
+ * `request_id` is the caller's, passed through unchanged; `result` is only
+ * valid during the call, `user_data` is as passed with the request
+ */
+typedef void (*PolymarketOrderCallback)(uint64_t request_id,
+                                        const PolymarketOrderResult *result,
+                                        void *user_data);
+
+/**
+ * Order update callback of polymarket_subscribe_order_updates(); `update`
+ * is only valid during the call, `user_data` is as passed when subscribing
+ */
+typedef void (*PolymarketOrderUpdateCallback)(const PolymarketOrderUpdate *update, void *user_data);
+
+/**
+ * Trade tape callback of polymarket_subscribe_trade_tape(); `trade` is only
+ * valid during the call, `user_data` is as passed when subscribing
+ */
+typedef void (*PolymarketTradeTapeCallback)(const PolymarketTradePrint *trade, void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Set what polymarket_warmup() warms, as JSON: {"resolve", "connections",
+ * "signed_request", "presign_token_id"}, all optional
+ * NULL restores the default (3 plain requests)
+ */
+int32_t polymarket_set_warmup_profile(const char *json);
+
+/**
+ * Warm up the connections and request paths selected by the warmup profile
+ * Call this after init to pre-establish TLS connections; the step timings
+ * are left in polymarket_last_response()
+ */
+int32_t polymarket_warmup(void);
+
+/**
+ * Initialize a keyless executor for market data, lookups and estimates
+ * Only in builds without the `execution` feature, which have no
+ * polymarket_init*(). Returns 0 on success (and on a live executor),
+ * negative error code on failure
+ */
+int32_t polymarket_init_keyless(void);
+
+/**
+ * Set POLYMARKET_INIT_* flags for the next polymarket_init*() call
+ * They stay with that executor until shutdown; a live one is not affected
+ */
+int32_t polymarket_set_init_flags(uint32_t flags);
+
+/**
+ * Set endpoints, proxy and TLS for the next polymarket_init*() call, as JSON:
+ * {"clob_host", "clob_gateways", "ws_host", "gamma_host", "data_host", "rpc_url", "proxy",
+ * "ca_file", "client_cert_file", "client_key_file"}, all optional
+ * NULL restores the defaults; a live executor is not affected
+ */
+int32_t polymarket_set_connect_options(const char *json);
+
+/**
+ * Initialize the executor with a private key and funder wallet
+ * Returns 0 on success, negative error code on failure
+ * Can be called again after polymarket_shutdown() to re-initialize, or on
+ * a live executor to switch it to other credentials (see switch_credentials)
+ */
+int32_t polymarket_init(const char *private_key, const char *funder_wallet);
+
+/**
+ * Initialize the executor with the private key read from a file
+ * The file must be owned by the current user and not accessible to
+ * group/others (mode 600 or 400); otherwise POLYMARKET_ERR_INVALID_PK
+ */
+int32_t polymarket_init_from_file(const char *key_path, const char *funder_wallet);
+
+/**
+ * Initialize the executor with the private key stored in the OS keyring
+ * (macOS Keychain via `security`, libsecret via `secret-tool` on Linux)
+ * Returns POLYMARKET_ERR_INVALID_PK if the entry can't be read
+ */
+int32_t polymarket_init_from_keyring(const char *service,
+                                     const char *account,
+                                     const char *funder_wallet);
+
+/**
+ * Authenticate new credentials in the background and swap them in
+ * Every call keeps using the current session until the new one is ready;
+ * requests already running finish on the session they started on.
+ * Returns a request id (> 0) like polymarket_submit_*(), or negative error
+ * code; the outcome (op "ROTATE") is delivered the same way
+ */
+int64_t polymarket_rotate_credentials(const char *new_private_key, const char *new_funder_wallet);
+
+/**
+ * Set credentials to fail over to when the venue rejects (401/403) or
+ * rate-bans (repeated 429) the current ones; NULL private_key clears them
+ * Used once: after a failover, set another backup to be covered again
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_set_backup_credentials(const char *private_key, const char *funder_wallet);
+
+/**
+ * Prefetch token metadata to avoid HTTP calls during order execution
+ * Call this for each token before trading to warm up the cache
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_prefetch(const char *token_id);
+
+/**
+ * Prefetch `count` tokens in one call, up to 16 at a time: out_codes[i]
+ * gets the code polymarket_prefetch() would return for token_ids[i]
+ * Returns the number of tokens that failed, negative error code if none
+ * were fetched
+ */
+int32_t polymarket_prefetch_many(const char *const *token_ids, size_t count, int32_t *out_codes);
+
+/**
+ * Place the next order of this thread under `client_order_id`
+ * Taken by the next single order entry point (sync, _ex, submit or async)
+ * or polymarket_replace_order() called from this thread, whether or not
+ * it is accepted; NULL drops a staged id. The id is echoed in the
+ * PolymarketOrderResultEx (v3), POLYMARKET_EVENT_ORDER, fill and summary
+ * events. An id still in use by an order that may be live fails the order
+ * with POLYMARKET_ERR_DUPLICATE_ORDER, nothing sent
+ * POLYMARKET_ERR_INVALID_ARGUMENT if empty, longer than 63 bytes or not
+ * printable ASCII
+ */
+int32_t polymarket_set_client_order_id(const char *client_order_id);
+
+/**
+ * What came of the order placed under `client_order_id`
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds {client_order_id, state: "pending" | "placed" | "refused" |
+ * "unknown", order_id} (order_id once placed); POLYMARKET_ERR_INVALID_ARGUMENT
+ * if the id was never used or is forgotten. "unknown": the post failed
+ * without an answer and the order may rest on the venue
+ */
+int64_t polymarket_get_client_order(const char *client_order_id);
+
+/**
+ * Execute a market buy order (FAK - Fill and Kill)
+ * Sweeps orderbook at price 0.99 to fill immediately
+ * Returns result with filled quantity, average price, and latency
+ */
+PolymarketOrderResult polymarket_market_buy(const char *token_id, double usdc_amount);
+
+/**
+ * Same as polymarket_market_buy(), filling a versioned result
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_buy_ex(const char *token_id,
+                                 double usdc_amount,
+                                 PolymarketOrderResultEx *out);
+
+/**
+ * Same as polymarket_market_buy_ex(), sweeping the book no higher than `max_price`
+ * POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+ * POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_buy_capped_ex(const char *token_id,
+                                        double usdc_amount,
+                                        double max_price,
+                                        PolymarketOrderResultEx *out);
+
+/**
+ * Execute a market buy as FOK (Fill or Kill)
+ * Like polymarket_market_buy(), but the whole amount fills at once or
+ * nothing does: if the book cannot take it all up to 0.99 the venue kills
+ * the order and it fails with POLYMARKET_ERR_REJECTED, nothing filled
+ */
+PolymarketOrderResult polymarket_market_buy_fok(const char *token_id, double usdc_amount);
+
+/**
+ * Same as polymarket_market_buy_fok(), filling a versioned result
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_buy_fok_ex(const char *token_id,
+                                     double usdc_amount,
+                                     PolymarketOrderResultEx *out);
+
+/**
+ * Same as polymarket_market_buy_fok_ex(), sweeping the book no higher than `max_price`
+ * POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+ * POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_buy_fok_capped_ex(const char *token_id,
+                                            double usdc_amount,
+                                            double max_price,
+                                            PolymarketOrderResultEx *out);
+
+/**
+ * Buy a number of shares (net of the taker fee) with a FAK order spending
+ * what the book asks for them, sweeping no higher than `max_price`
+ * The book cached by prefetch is used if under 1s old, else refetched;
+ * fails with POLYMARKET_ERR_LIQUIDITY if it holds too few shares up to the cap
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_buy_shares_ex(const char *token_id,
+                                        double shares,
+                                        double max_price,
+                                        PolymarketOrderResultEx *out);
+
+/**
+ * Market buy (FAK) bounded by the book instead of sweeping to 0.99
+ * The book cached by prefetch or the market feed is used if under 1s old,
+ * else refetched: the order sweeps no higher than the price that fills
+ * `usdc_amount` on it, plus `buffer_bps`. Fails before signing with
+ * POLYMARKET_ERR_SLIPPAGE if the expected average price is more than
+ * `max_slippage_bps` above the best ask, POLYMARKET_ERR_LIQUIDITY if the book
+ * cannot fill `usdc_amount` at all
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_buy_protected_ex(const char *token_id,
+                                           double usdc_amount,
+                                           uint32_t buffer_bps,
+                                           uint32_t max_slippage_bps,
+                                           PolymarketOrderResultEx *out);
+
+/**
+ * Place a GTC limit buy order
+ * Returns result with order info
+ */
+PolymarketOrderResult polymarket_limit_buy(const char *token_id, double price, double usdc_amount);
+
+/**
+ * Same as polymarket_limit_buy(), filling a versioned result
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_limit_buy_ex(const char *token_id,
+                                double price,
+                                double usdc_amount,
+                                PolymarketOrderResultEx *out);
+
+/**
+ * Place a GTC limit sell order
+ * Returns result with order info
+ */
+PolymarketOrderResult polymarket_limit_sell(const char *token_id, double price, double size);
+
+/**
+ * Same as polymarket_limit_sell(), filling a versioned result
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_limit_sell_ex(const char *token_id,
+                                 double price,
+                                 double size,
+                                 PolymarketOrderResultEx *out);
+
+/**
+ * Place a GTD limit buy that expires `expires_in_secs` (at least 1) from now
+ * Returns result with order info
+ */
+PolymarketOrderResult polymarket_limit_buy_gtd(const char *token_id,
+                                               double price,
+                                               double usdc_amount,
+                                               uint32_t expires_in_secs);
+
+/**
+ * Place a GTD limit sell that expires `expires_in_secs` (at least 1) from now
+ * Returns result with order info
+ */
+PolymarketOrderResult polymarket_limit_sell_gtd(const char *token_id,
+                                                double price,
+                                                double size,
+                                                uint32_t expires_in_secs);
+
+/**
+ * Place a GTD limit buy resting until `expires_at_ms` (unix ms, in the future)
+ * The venue expires orders on whole seconds: a deadline between seconds is
+ * also enforced locally by cancelling the order at that millisecond
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_limit_buy_gtd_ex(const char *token_id,
+                                    double price,
+                                    double usdc_amount,
+                                    uint64_t expires_at_ms,
+                                    PolymarketOrderResultEx *out);
+
+/**
+ * Place a GTD limit sell resting until `expires_at_ms`, see
+ * polymarket_limit_buy_gtd_ex()
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_limit_sell_gtd_ex(const char *token_id,
+                                     double price,
+                                     double size,
+                                     uint64_t expires_at_ms,
+                                     PolymarketOrderResultEx *out);
+
+/**
+ * Place up to MAX_BATCH_ORDERS limit orders in one request, e.g. both
+ * sides of a quote; each runs the usual checks, those that pass are signed
+ * and posted together
+ * `results` holds `count` entries, each with struct_size set; entry i gets
+ * the outcome of order i, also published as a POLYMARKET_EVENT_ORDER event.
+ * The venue answers each order on its own, and a request failing in
+ * transport fails every order sent with it.
+ * Returns 0 once the batch ran (see each result's error_code), or a
+ * negative error code if it was refused as a whole
+ */
+int32_t polymarket_post_orders_batch(const PolymarketBatchOrder *orders,
+                                     size_t count,
+                                     PolymarketOrderResultEx *results);
+
+/**
+ * Copy the full id of the last order placed from the calling thread
+ * Returns the required buffer size (including NUL); the id is written only
+ * if `len` is large enough, pass NULL/0 to query the size.
+ * Empty string if the last order from this thread failed.
+ */
+size_t polymarket_last_order_id(char *buf, size_t len);
+
+/**
+ * Copy the message of the last failed order or cancel call from the
+ * calling thread (blocking calls, and polymarket_poll_result()): the
+ * venue's own error string for orders it refused, else the local reason.
+ * Same buffer convention as polymarket_last_order_id(); empty string if
+ * that call succeeded or its error code says it all
+ */
+size_t polymarket_last_error_message(char *buf, size_t len);
+
+/**
+ * Copy the report of the last panic caught at the FFI boundary: message,
+ * location and backtrace. Calls that panicked returned POLYMARKET_ERR_PANIC
+ * (or their documented error value). Same buffer convention as
+ * polymarket_last_order_id(); empty string if nothing panicked
+ */
+size_t polymarket_last_panic(char *buf, size_t len);
+
+/**
+ * Copy the JSON result of the last query made from the calling thread
+ * (polymarket_get_rewards_earnings() etc.). Same buffer convention as
+ * polymarket_last_order_id(); the result stays until the next query
+ */
+size_t polymarket_last_response(char *buf, size_t len);
+
+/**
+ * Cancel an order by ID
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_cancel(const char *order_id);
+
+/**
+ * Cancel all open orders
+ * Returns 0 on success, POLYMARKET_ERR_CANCEL_FAILED if the call or any
+ * order failed, other negative error code on failure
+ */
+int32_t polymarket_cancel_all(void);
+
+/**
+ * Cancel all open orders and report the outcome of each
+ * The first `capacity` outcomes are written to `out`; `out_count`, if not
+ * NULL, receives how many there are, which may exceed capacity
+ * Returns 0 if every order is gone (cancelled, filled or unknown),
+ * POLYMARKET_ERR_CANCEL_FAILED if the call or any order failed, other
+ * negative error code on failure
+ */
+int32_t polymarket_cancel_all_ex(PolymarketCancelOutcome *out, size_t capacity, size_t *out_count);
+
+/**
+ * Cancel the open orders of one token only, leaving other markets alone
+ * Returns like polymarket_cancel_all(); POLYMARKET_ERR_INVALID_TOKEN for a
+ * malformed token id
+ */
+int32_t polymarket_cancel_market(const char *token_id);
+
+/**
+ * Cancel the open orders of one token and report the outcome of each
+ * Outcomes and return value like polymarket_cancel_all_ex()
+ */
+int32_t polymarket_cancel_market_ex(const char *token_id,
+                                    PolymarketCancelOutcome *out,
+                                    size_t capacity,
+                                    size_t *out_count);
+
+/**
+ * Cancel `count` orders by id in one request, e.g. to pull a set of quotes
+ * Returns like polymarket_cancel_all()
+ */
+int32_t polymarket_cancel_orders(const char *const *order_ids, size_t count);
+
+/**
+ * Cancel `count` orders by id in one request and report the outcome of each
+ * Outcomes and return value like polymarket_cancel_all_ex()
+ */
+int32_t polymarket_cancel_orders_ex(const char *const *order_ids,
+                                    size_t count,
+                                    PolymarketCancelOutcome *out,
+                                    size_t capacity,
+                                    size_t *out_count);
+
+/**
+ * Move a resting order to `new_price` for `new_size` shares (<= 0 keeps the
+ * unmatched size): cancel it and post the replacement back to back in one
+ * runtime task, instead of two calls with a round trip between them
+ * Same token and side; a GTD order keeps its deadline. The replacement is
+ * posted only if the venue cancelled the old order, otherwise
+ * POLYMARKET_ERR_CANCEL_FAILED and nothing is posted
+ * Returns the error code, also stored in `out`; the new order id via
+ * polymarket_last_order_id()
+ */
+int32_t polymarket_replace_order(const char *order_id,
+                                 double new_price,
+                                 double new_size,
+                                 PolymarketOrderResultEx *out);
+
+/**
+ * List the account's orders resting on the book, of one token or (NULL)
+ * of every market, e.g. to reconcile local order state after a restart
+ * The first `capacity` orders are written to `out`; `out_count`, if not
+ * NULL, receives how many there are, which may exceed capacity
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_get_open_orders(const char *token_id,
+                                   PolymarketOpenOrder *out,
+                                   size_t capacity,
+                                   size_t *out_count);
+
+/**
+ * Our fills matched at or after `since_ms` (unix ms), of one token or
+ * (NULL) of every market, oldest first; recovers fills of orders matched
+ * while the user channel was down
+ * Array output like polymarket_get_open_orders()
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_get_trades(const char *token_id,
+                              uint64_t since_ms,
+                              PolymarketTrade *out,
+                              size_t capacity,
+                              size_t *out_count);
+
+/**
+ * Look up an order by id: its state and how much of it matched, e.g. to
+ * poll a resting GTC order
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_get_order(const char *order_id, PolymarketOrderStatus *out);
+
+/**
+ * Get USDC balance
+ * Returns raw balance (6 decimals), or negative on error
+ */
+int64_t polymarket_get_balance(void);
+
+/**
+ * Bound venue calls of `op_class` (POLYMARKET_CALL_*) started from now on
+ * by `timeout_ms` (100 - 120000); every class starts at 10s
+ * Kept across credential rotation, reset by a new polymarket_init*()
+ */
+int32_t polymarket_set_timeout_ms(int32_t op_class, uint32_t timeout_ms);
+
+/**
+ * Limit venue calls of `op_class` (POLYMARKET_CALL_*) to `per_sec` on
+ * average, `burst` back to back; 0 per_sec lifts the limit
+ * Over budget, calls wait for it (at most their timeout) or, with
+ * POLYMARKET_RATE_REJECT, fail at once; either way with
+ * POLYMARKET_ERR_RATE_LIMITED when refused, without reaching the venue
+ * Kept across credential rotation, reset by a new polymarket_init*()
+ */
+int32_t polymarket_set_rate_limit(int32_t op_class, double per_sec, uint32_t burst, uint32_t flags);
+
+/**
+ * Rate limit of `op_class` (POLYMARKET_CALL_*) and the budget left now
+ */
+int32_t polymarket_get_rate_budget(int32_t op_class, PolymarketRateBudget *out);
+
+/**
+ * Resend order posts and cancels that timed out, lost the connection or
+ * got a 429/5xx up to `max_retries` times, `base_delay_ms` apart at first
+ * and doubling up to `max_delay_ms`; 0 max_retries turns retries off
+ * A post resends the payload signed once, so it cannot fill twice
+ * Kept across credential rotation, reset by a new polymarket_init*()
+ */
+int32_t polymarket_set_retry_policy(uint32_t max_retries,
+                                    uint32_t base_delay_ms,
+                                    uint32_t max_delay_ms);
+
+/**
+ * Set the USDC safety buffer kept out of reach of buys
+ * Buys are rejected locally with POLYMARKET_ERR_INSUFFICIENT_BALANCE when
+ * cached balance - buffer - in-flight buys can't fund them.
+ * The check is active once polymarket_get_balance() has been called.
+ */
+int32_t polymarket_set_balance_buffer(double buffer_usdc);
+
+/**
+ * Get USDC available for new buys: cached balance - buffer - in-flight buys
+ * Returns raw value (6 decimals), or -1 if not initialized or balance never fetched
+ */
+int64_t polymarket_get_available_balance(void);
+
+/**
+ * Get token balance (shares held)
+ * Returns raw balance (6 decimals), or negative on error
+ */
+int64_t polymarket_get_token_balance(const char *token_id);
+
+/**
+ * Every conditional token position of the funder wallet, from the Data API
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds [{token_id, condition_id, outcome, shares, avg_price, redeemable}]
+ * (avg_price null if unknown), or negative error code
+ */
+int64_t polymarket_get_positions(void);
+
+/**
+ * Mark every tracked position to its midpoint (last trade if the book is
+ * one-sided) and total the unrealized PnL
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds {positions: [{token_id, shares, avg_price, mark_price, source,
+ * market_value, unrealized_pnl}], unrealized_pnl, market_value, unpriced}
+ */
+int64_t polymarket_mark_positions(void);
+
+/**
+ * Estimate a market order against the token's book without placing it
+ * `amount` is USDC for POLYMARKET_SIDE_BUY, shares for POLYMARKET_SIDE_SELL;
+ * the book cached by prefetch is used if under 1s old, else refetched
+ * Returns the error code; `out` is filled on success
+ */
+int32_t polymarket_estimate_fill(const char *token_id,
+                                 int32_t side,
+                                 double amount,
+                                 PolymarketFillEstimate *out);
+
+/**
+ * Keep a local book of a token from the CLOB market channel
+ * The first subscription opens the connection; it reconnects on its own
+ * and closes on shutdown. Updates go to POLYMARKET_EVENT_MARKET_DATA
+ * callbacks and refresh the book polymarket_estimate_fill() walks
+ * Returns POLYMARKET_OK, also if already subscribed
+ */
+int32_t polymarket_subscribe_book(const char *token_id);
+
+/**
+ * Stop keeping a token's book; the connection stays open
+ * Returns POLYMARKET_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t polymarket_unsubscribe_book(const char *token_id);
+
+/**
+ * Stream a token's public trades from the CLOB market channel to
+ * `callback`, replacing any callback set before for the token
+ * Subscribes the token like polymarket_subscribe_book(). The callback
+ * runs on a runtime worker thread, one trade at a time; it must not block
+ * or call back into the library
+ */
+int32_t polymarket_subscribe_trade_tape(const char *token_id,
+                                        PolymarketTradeTapeCallback callback,
+                                        void *user_data);
+
+/**
+ * Stop passing a token's trades to its callback; once this returns it is
+ * not invoked again. The token's book stays subscribed
+ * Returns POLYMARKET_ERR_INVALID_ARGUMENT if no callback was set
+ */
+int32_t polymarket_unsubscribe_trade_tape(const char *token_id);
+
+/**
+ * Best bid and ask of a token subscribed with polymarket_subscribe_book()
+ * Returns POLYMARKET_PENDING until the venue sent its book (`out`
+ * untouched), POLYMARKET_ERR_INVALID_ARGUMENT if not subscribed
+ */
+int32_t polymarket_get_best_bid_ask(const char *token_id, PolymarketBestBidAsk *out);
+
+/**
+ * Generation, staleness and gap counts of a subscribed token's book
+ * Returns like polymarket_get_best_bid_ask()
+ */
+int32_t polymarket_get_book_state(const char *token_id, PolymarketBookState *out);
+
+/**
+ * Top POLYMARKET_BOOK_DEPTH levels per side of a subscribed token's book
+ * Returns like polymarket_get_best_bid_ask()
+ */
+int32_t polymarket_get_book_snapshot(const char *token_id, PolymarketBookSnapshot *out);
+
+/**
+ * Top `depth` levels per side of a token's book: the subscribed one if
+ * the venue sent it, else the cached one if under 1s old, else fetched
+ * Bids go to out_levels[0, depth) and asks to out_levels[depth, 2 * depth),
+ * best first, slots past a side's last level zeroed; `max` is the slots
+ * out_levels holds, at least 2 * depth
+ * Returns the number of levels written, or a negative error code
+ */
+int64_t polymarket_get_order_book(const char *token_id,
+                                  uint32_t depth,
+                                  PolymarketBookLevel *out_levels,
+                                  size_t max);
+
+/**
+ * Midpoint of a token's best bid and ask
+ * From the subscribed or a recent cached book if it has both sides, else
+ * the CLOB midpoint endpoint
+ * Returns the price raw (6 decimals), or a negative error code
+ */
+int64_t polymarket_get_midpoint(const char *token_id);
+
+/**
+ * Best ask less best bid of a token, from the same sources as
+ * polymarket_get_midpoint()
+ * Returns the spread raw (6 decimals), or a negative error code
+ */
+int64_t polymarket_get_spread(const char *token_id);
+
+/**
+ * OHLC candles of `interval_secs` (a multiple of 60) of a token's price
+ * between unix seconds `from_ts` and `to_ts`, from the CLOB price history
+ * The newest `max` are written to `out`, oldest first
+ * Returns the number of candles, which may exceed max, or a negative
+ * error code
+ */
+int64_t polymarket_get_price_history(const char *token_id,
+                                     uint32_t interval_secs,
+                                     int64_t from_ts,
+                                     int64_t to_ts,
+                                     PolymarketCandle *out,
+                                     size_t max);
+
+/**
+ * Stream the account's order updates and fills from the CLOB user channel
+ * to `callback`, replacing any callback set before
+ * The callback runs on a runtime worker thread, one update at a time; it
+ * must not block or call back into the library
+ */
+int32_t polymarket_subscribe_order_updates(PolymarketOrderUpdateCallback callback, void *user_data);
+
+/**
+ * Close the user channel; once this returns the callback is not invoked
+ * again. No-op if not subscribed
+ */
+int32_t polymarket_unsubscribe_order_updates(void);
+
+/**
+ * Start a TWAP: `total` (USDC for buys, shares for sells) split into
+ * `slices` market orders sent `interval_ms` apart, the first one now
+ * What a slice leaves unfilled is carried into the next one
+ * Returns an algo id (> 0), or negative error code if rejected upfront.
+ * Progress goes to POLYMARKET_EVENT_ALGO callbacks after every order and
+ * once more when the algo ends
+ */
+int64_t polymarket_start_twap(const char *token_id,
+                              int32_t side,
+                              double total,
+                              uint32_t slices,
+                              uint64_t interval_ms);
+
+/**
+ * Start an iceberg: `total` shares at `price`, resting at most `show_size`
+ * at a time; the next clip is posted once the resting one has filled,
+ * checked every `poll_ms` (>= 500)
+ * Returns an algo id (> 0), or negative error code, see polymarket_start_twap()
+ */
+int64_t polymarket_start_iceberg(const char *token_id,
+                                 int32_t side,
+                                 double price,
+                                 double total,
+                                 double show_size,
+                                 uint32_t poll_ms);
+
+/**
+ * Stop a running algo; an iceberg's resting clip is cancelled on the venue
+ * Returns immediately; the final state arrives as a POLYMARKET_EVENT_ALGO
+ * event. POLYMARKET_ERR_INVALID_ARGUMENT if the id is unknown or done
+ */
+int32_t polymarket_cancel_algo(uint64_t algo_id);
+
+/**
+ * Start maintaining the quotes set with polymarket_set_quote()
+ * Every `interval_ms` (>= 50) resting quotes are brought in line with their
+ * targets; a side is changed at most once per `min_replace_ms` (0 = 250).
+ * Each action is published as a POLYMARKET_EVENT_QUOTE event.
+ * Restarts the loop if it is running
+ */
+int32_t polymarket_start_quoting(uint32_t interval_ms, uint32_t min_replace_ms);
+
+/**
+ * Set the quote to maintain for a token; a size <= 0 pulls that side
+ * Sizes are in shares, prices in (0, 1). Applied by the next quoting pass
+ */
+int32_t polymarket_set_quote(const char *token_id,
+                             double bid_price,
+                             double bid_size,
+                             double ask_price,
+                             double ask_size);
+
+/**
+ * Stop quoting and cancel every resting quote; targets are dropped
+ * Blocks until the cancels are done. POLYMARKET_ERR_CANCEL_FAILED if some
+ * quotes could not be pulled (see POLYMARKET_EVENT_QUOTE events)
+ */
+int32_t polymarket_stop_quoting(void);
+
+/**
+ * Buy or sell several outcome tokens together (e.g. candidates of one
+ * neg-risk event) as FAK orders, sent at once after a liquidity check
+ * POLYMARKET_BASKET_ALL_OR_NOTHING sends nothing unless every leg can fill
+ * within its limit and unwinds the fills if one still falls short;
+ * otherwise the legs that can fill are sent, and POLYMARKET_BASKET_UNWIND
+ * unwinds on any shortfall. Blocks until done, unwinds included.
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds {state, legs: [...]}; negative error code if rejected upfront
+ */
+int64_t polymarket_place_basket(const PolymarketBasketLeg *legs, size_t count, uint32_t flags);
+
+/**
+ * Apply a tick size change from the market channel (tick_size_change)
+ * Later limit orders off the new grid fail with POLYMARKET_ERR_TICK_SIZE;
+ * the change is published as a POLYMARKET_EVENT_MARKET_DATA event
+ */
+int32_t polymarket_update_tick_size(const char *token_id, double tick_size);
+
+/**
+ * Refresh the metadata of every prefetched token (tick size, fee rate,
+ * neg risk, min order size) each `ttl_secs` (>= 10) in the background;
+ * 0 stops refreshing. Restarts the refresher if it is running
+ */
+int32_t polymarket_set_meta_ttl_secs(uint32_t ttl_secs);
+
+/**
+ * Round a price onto a token's cached tick grid, e.g. before a limit order
+ * Buys round down and sells up, so the price never gets worse; limit orders
+ * themselves only absorb float noise and fail with POLYMARKET_ERR_TICK_SIZE
+ * further off the grid
+ * Returns 0 with the price in `out_price`, POLYMARKET_ERR_TICK_SIZE if the
+ * tick size is unknown (prefetch first), POLYMARKET_ERR_INVALID_ORDER if the
+ * price is not between 0 and 1
+ */
+int32_t polymarket_round_price(const char *token_id, double price, int32_t side, double *out_price);
+
+/**
+ * Metadata polymarket_prefetch() cached for a token
+ * Returns POLYMARKET_ERR_INVALID_ARGUMENT if the token was not prefetched
+ */
+int32_t polymarket_get_market_meta(const char *token_id, PolymarketMarketMeta *out);
+
+/**
+ * Whether a resting order qualifies for liquidity rewards
+ * Returns 1 if scoring, 0 if not, negative error code on failure
+ */
+int32_t polymarket_is_order_scoring(const char *order_id);
+
+/**
+ * Scoring status of `count` resting orders: out_scoring[i] is set to 1 if
+ * order_ids[i] qualifies for liquidity rewards, 0 if not
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_orders_scoring(const char *const *order_ids, size_t count, uint8_t *out_scoring);
+
+/**
+ * Fetch liquidity rewards earned per market and epoch (UTC day), dates
+ * YYYY-MM-DD inclusive, at most 31 days
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds a JSON array of {date, condition_id, asset_address, earnings_raw,
+ * asset_rate}; negative error code on failure
+ */
+int64_t polymarket_get_rewards_earnings(const char *from_date, const char *to_date);
+
+/**
+ * Look up a binary market by any identifier: condition id, question id,
+ * slug or either token id (`kind` = POLYMARKET_ID_*)
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds {condition_id, question_id, slug, yes_token_id, no_token_id,
+ * neg_risk, question, outcomes, end_date, end_date_ms};
+ * POLYMARKET_ERR_INVALID_TOKEN if malformed or unknown
+ */
+int64_t polymarket_lookup_market(const char *id, int32_t kind);
+
+/**
+ * List the binary markets open for trading, `limit` (1..=500) from
+ * `offset` in Gamma's order; page until an empty list
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds an array of what polymarket_lookup_market() returns
+ */
+int64_t polymarket_list_markets(uint32_t limit, uint32_t offset);
+
+/**
+ * Venue responses that no longer match the expected shape, per source
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds [{source, count, last_error, last_sample, last_at_ms}]
+ */
+int64_t polymarket_schema_drift(void);
+
+/**
+ * Convert a market identifier of kind `from` into kind `to`
+ * (POLYMARKET_ID_*), e.g. a slug into its YES token id
+ * Returns the required buffer size (including NUL), the id is written only
+ * if `len` is large enough; negative error code on failure
+ */
+int64_t polymarket_convert_id(const char *id, int32_t from, int32_t to, char *buf, size_t len);
+
+/**
+ * Start polling account notifications every `interval_ms` (>= 1000)
+ * Each new one is logged and published as a POLYMARKET_EVENT_NOTIFICATION
+ * event; the ones already held by the venue are published on the first poll.
+ * Restarts the poller if it is running; it stops on shutdown
+ */
+int32_t polymarket_start_notifications(uint32_t interval_ms);
+
+/**
+ * Stop polling account notifications; no-op if not started
+ */
+int32_t polymarket_stop_notifications(void);
+
+/**
+ * Watch a market (condition id, 0x hex) for offsetting YES and NO positions
+ * DETECT publishes the mergeable amount when it changes; AUTO merges pairs
+ * on-chain into USDC; OFF stops watching. Pairs below `min_shares` are ignored
+ */
+int32_t polymarket_set_auto_merge(const char *condition_id, int32_t mode, double min_shares);
+
+/**
+ * Start checking the markets set with polymarket_set_auto_merge() every
+ * `interval_ms` (>= 10000); findings and merges are published as
+ * POLYMARKET_EVENT_MERGE events
+ * Restarts the poller if it is running; it stops on shutdown
+ */
+int32_t polymarket_start_auto_merge(uint32_t interval_ms);
+
+/**
+ * Stop the auto-merge poller; no-op if not started
+ * A merge transaction already sent still completes on-chain
+ */
+int32_t polymarket_stop_auto_merge(void);
+
+/**
+ * Split `amount_raw` USDC (6 decimals) into as many YES and NO shares of a
+ * market (condition id, 0x hex); `neg_risk` markets go through the neg-risk
+ * adapter. Blocks until mined (at most 120 s)
+ * Returns the size of {"tx_hash"} (polymarket_last_response()), or a negative
+ * error code
+ */
+int64_t polymarket_split_position(const char *condition_id, bool neg_risk, int64_t amount_raw);
+
+/**
+ * Merge `amount_raw` (6 decimals) YES and NO shares of a market back into as
+ * much USDC; the wallet must hold both. Blocks until mined (at most 120 s)
+ * Returns the size of {"tx_hash"} (polymarket_last_response()), or a negative
+ * error code
+ */
+int64_t polymarket_merge_positions(const char *condition_id, bool neg_risk, int64_t amount_raw);
+
+/**
+ * Convert `amount_raw` (6 decimals) NO shares of each of `count` questions
+ * (question ids, 0x hex) of one neg-risk event into as many YES shares of
+ * every other question of the event, plus USDC for all but one of the NO
+ * shares. Blocks until mined (at most 120 s)
+ * Returns the size of {"tx_hash"} (polymarket_last_response()), or a negative
+ * error code
+ */
+int64_t polymarket_convert_positions(const char *const *question_ids,
+                                     size_t count,
+                                     int64_t amount_raw);
+
+/**
+ * Redeem the wallet's shares of a resolved market (condition id, 0x hex) for
+ * the USDC they pay out, then resync its positions and the cached balance
+ * Blocks until mined (at most 120 s). Returns the size of {"tx_hash"}
+ * (polymarket_last_response()), or a negative error code
+ */
+int64_t polymarket_redeem(const char *condition_id);
+
+/**
+ * Approvals the funder wallet gave the exchanges, the neg-risk adapter and
+ * the Conditional Tokens contract, read on Polygon; allowed in read-only mode
+ * Leaves {"wallet", "spenders": [{"spender", "address", "usdc", "tokens"}]}
+ * for polymarket_last_response() and returns its size, or
+ * POLYMARKET_ERR_USDC_ALLOWANCE / POLYMARKET_ERR_TOKEN_APPROVAL if one is
+ * missing (the JSON still tells which), or another negative error code
+ */
+int64_t polymarket_check_allowances(void);
+
+/**
+ * Set every approval polymarket_check_allowances() finds missing, unlimited,
+ * in one transaction sent through the proxy wallet; blocks until mined (at
+ * most 120 s). Returns the size of {"tx_hash"} (null if nothing was missing)
+ * for polymarket_last_response(), or a negative error code
+ */
+int64_t polymarket_approve_allowances(void);
+
+/**
+ * POL balance of the signing key, which pays the gas of on-chain calls
+ * Returns raw POL (6 decimals), or a negative error code; logs a warning if
+ * it is below the polymarket_set_low_gas_threshold() threshold
+ */
+int64_t polymarket_get_gas_balance(void);
+
+/**
+ * Warn (a WARN log line, once until the balance recovers) when the signing
+ * key's POL balance is below `threshold_pol`; 0 turns warnings off
+ * Checked after every on-chain transaction and polymarket_get_gas_balance()
+ */
+int32_t polymarket_set_low_gas_threshold(double threshold_pol);
+
+/**
+ * Watch a market (condition id, 0x hex) for YES+NO prices summing away
+ * from 1 by at least `min_edge` USDC per pair, fees counted
+ * Watching it again replaces `min_edge`
+ */
+int32_t polymarket_watch_arbitrage(const char *condition_id, double min_edge);
+
+/**
+ * Stop watching a market for mispricing
+ * POLYMARKET_ERR_INVALID_ARGUMENT if it was not watched
+ */
+int32_t polymarket_unwatch_arbitrage(const char *condition_id);
+
+/**
+ * Scan the markets set with polymarket_watch_arbitrage() every
+ * `interval_ms` (>= 500); opportunities that open, change or close are
+ * published as POLYMARKET_EVENT_ARBITRAGE events
+ * Restarts the scanner if it is running; it stops on shutdown
+ */
+int32_t polymarket_start_arbitrage_scan(uint32_t interval_ms);
+
+/**
+ * Stop the mispricing scanner; no-op if not started
+ */
+int32_t polymarket_stop_arbitrage_scan(void);
+
+/**
+ * Watch a market (condition id, 0x hex) for changes of its resolution
+ * status; no-op if already watched
+ */
+int32_t polymarket_watch_resolution(const char *condition_id);
+
+/**
+ * Stop watching a market for resolution
+ * POLYMARKET_ERR_INVALID_ARGUMENT if it was not watched
+ */
+int32_t polymarket_unwatch_resolution(const char *condition_id);
+
+/**
+ * Poll the markets set with polymarket_watch_resolution() every
+ * `interval_ms` (>= 1000); each status change is published as a
+ * POLYMARKET_EVENT_RESOLUTION event
+ * Restarts the watcher if it is running; it stops on shutdown
+ */
+int32_t polymarket_start_resolution_watch(uint32_t interval_ms);
+
+/**
+ * Stop the resolution watcher; no-op if not started
+ */
+int32_t polymarket_stop_resolution_watch(void);
+
+/**
+ * Follow every order placed from now on until it completes: fills go to
+ * POLYMARKET_EVENT_FILL callbacks with the order's running totals, and a
+ * final summary to POLYMARKET_EVENT_ORDER_SUMMARY callbacks
+ * Resting orders are polled every `interval_ms` (>= 500) for new fills;
+ * restarts the poller if it is running, keeping the orders followed
+ */
+int32_t polymarket_start_fill_tracking(uint32_t interval_ms);
+
+/**
+ * Stop fill tracking and forget the orders followed, without summaries;
+ * no-op if not started
+ */
+int32_t polymarket_stop_fill_tracking(void);
+
+/**
+ * Time a request to `clob_host` and every `clob_gateways` entry each
+ * `interval_ms` (>= 1000) and send orders and cancels to the fastest healthy
+ * one; a gateway also fails over at once when an order call to it times out
+ * or can't connect. Restarts the prober if it is running
+ */
+int32_t polymarket_start_endpoint_probing(uint32_t interval_ms);
+
+/**
+ * Stop probing; order traffic stays on the current gateway, failing over
+ * only on transport errors. No-op if not started
+ */
+int32_t polymarket_stop_endpoint_probing(void);
+
+/**
+ * Ping `clob_host` and every `clob_gateways` entry each `interval_ms`
+ * (>= 1000) so idle pooled connections stay open and the next order after
+ * a quiet spell pays no TLS handshake. Keep it under the pool's idle
+ * timeout (90s). Restarts the pinger if it is running
+ */
+int32_t polymarket_start_keepalive(uint32_t interval_ms);
+
+/**
+ * Stop the keep-alive pinger. No-op if not started
+ */
+int32_t polymarket_stop_keepalive(void);
+
+/**
+ * State of every CLOB gateway, `clob_host` first
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds [{host, active, healthy, rtt_ms, last_rtt_ms, probes, probe_failures,
+ * orders, order_failures, activations}]
+ */
+int64_t polymarket_endpoint_metrics(void);
+
+/**
+ * Latency and error counts of order posts, batch posts, cancels and
+ * balance reads since the session started, copied into `buf` as JSON:
+ * [{op, count, errors, mean_ms, p50_ms, p90_ms, p99_ms, max_ms}]
+ * Returns the required buffer size (including NUL), negative error code on
+ * failure; written only if `len` is large enough, pass NULL/0 to query
+ */
+int64_t polymarket_get_metrics(char *buf, size_t len);
+
+/**
+ * The counts of polymarket_get_metrics() in the Prometheus text format,
+ * to serve from a scrape endpoint of the host. Same buffer convention
+ */
+int64_t polymarket_get_metrics_prometheus(char *buf, size_t len);
+
+/**
+ * Venue circuit breaker state
+ * Returns the buffer size needed for polymarket_last_response(), which
+ * holds {open, reason, opened_at_ms, trips, probes, window_failures,
+ * window_calls}
+ */
+int64_t polymarket_venue_status(void);
+
+/**
+ * Load risk limits from a JSON file, replacing the active ones
+ * Can be called at any time to hot-reload; on validation failure the
+ * previous limits stay active and POLYMARKET_ERR_INVALID_CONFIG is returned
+ */
+int32_t polymarket_load_risk_config(const char *path);
+
+/**
+ * Replace risk limits from a JSON string (same schema as the config file)
+ * Omitted fields fall back to defaults (no limit)
+ */
+int32_t polymarket_set_risk_config(const char *json);
+
+/**
+ * Set the order notional (USDC), position (shares per token) and open
+ * orders caps, 0 = no limit; the other risk limits stay as they are
+ * POLYMARKET_ERR_INVALID_CONFIG for negative or non-finite caps
+ */
+int32_t polymarket_set_risk_limits(double max_order_usdc,
+                                   double max_position_shares,
+                                   uint32_t max_open_orders);
+
+/**
+ * Kill switch: refuse every new order (POLYMARKET_ERR_HALTED) until
+ * polymarket_resume_trading() and stop quoting; with `cancel_open`, also
+ * pull the quotes and cancel every open order
+ * Returns POLYMARKET_ERR_CANCEL_FAILED if some order could not be cancelled;
+ * trading stays halted either way
+ */
+int32_t polymarket_halt_trading(bool cancel_open);
+
+/**
+ * Lift the kill switch; quoting stopped by the halt is not restarted
+ */
+int32_t polymarket_resume_trading(void);
+
+/**
+ * 1 while trading is halted, 0 otherwise; -1 if not initialized
+ */
+int32_t polymarket_is_trading_halted(void);
+
+/**
+ * Get the version of the active risk limits (0 = defaults, +1 per accepted update)
+ * Returns version, or -1 if not initialized
+ */
+int64_t polymarket_get_risk_config_version(void);
+
+/**
+ * Record every signed order to an append-only, hash-chained file before it
+ * is posted; an existing file is continued. NULL stops recording
+ * If a line cannot be written the order is not sent (ORDER_FAILED)
+ */
+int32_t polymarket_set_signed_log(const char *path);
+
+/**
+ * Journal every order, batch, replace and cancel request and its outcome
+ * (response or error) to an append-only JSONL file; an existing file is
+ * continued. Before init, the path is kept for the next polymarket_init*()
+ * call, which fails with POLYMARKET_ERR_INVALID_CONFIG if it cannot open
+ * it. NULL stops journaling (or clears the staged path)
+ * If a request line cannot be written the request is not sent
+ */
+int32_t polymarket_set_journal(const char *path);
+
+/**
+ * Check the hash chain of a signed log file; works without init
+ * Returns the number of records, or POLYMARKET_ERR_INVALID_CONFIG if the
+ * file cannot be read or the chain is broken (details in the log)
+ */
+int64_t polymarket_verify_signed_log(const char *path);
+
+/**
+ * Execute a market sell order (FAK - Fill and Kill)
+ * Sells at price 0.01 to fill immediately
+ * Returns result with filled quantity, average price, and latency
+ */
+PolymarketOrderResult polymarket_market_sell(const char *token_id, double size);
+
+/**
+ * Same as polymarket_market_sell(), filling a versioned result
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_sell_ex(const char *token_id, double size, PolymarketOrderResultEx *out);
+
+/**
+ * Same as polymarket_market_sell_ex(), sweeping the book no lower than `min_price`
+ * POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+ * POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_sell_capped_ex(const char *token_id,
+                                         double size,
+                                         double min_price,
+                                         PolymarketOrderResultEx *out);
+
+/**
+ * Execute a market sell as FOK (Fill or Kill)
+ * Like polymarket_market_sell(), but all `size` shares fill at once or
+ * none do: if the book cannot take them all down to 0.01 the venue kills
+ * the order and it fails with POLYMARKET_ERR_REJECTED, nothing filled
+ */
+PolymarketOrderResult polymarket_market_sell_fok(const char *token_id, double size);
+
+/**
+ * Same as polymarket_market_sell_fok(), filling a versioned result
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_sell_fok_ex(const char *token_id,
+                                      double size,
+                                      PolymarketOrderResultEx *out);
+
+/**
+ * Same as polymarket_market_sell_fok_ex(), sweeping the book no lower than `min_price`
+ * POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+ * POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_sell_fok_capped_ex(const char *token_id,
+                                             double size,
+                                             double min_price,
+                                             PolymarketOrderResultEx *out);
+
+/**
+ * Market sell (FAK) bounded by the book instead of sweeping to 0.01, see
+ * polymarket_market_buy_protected_ex(); slippage is measured below the best bid
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_sell_protected_ex(const char *token_id,
+                                            double size,
+                                            uint32_t buffer_bps,
+                                            uint32_t max_slippage_bps,
+                                            PolymarketOrderResultEx *out);
+
+/**
+ * Sell (FAK) the shares that raise `usdc_amount`, sized from the book and
+ * sweeping no lower than `min_price`
+ * The book cached by prefetch or the market feed is used if under 1s old,
+ * else refetched; fails with POLYMARKET_ERR_LIQUIDITY if the bids down to
+ * the floor raise too little
+ * Returns the error code, also stored in `out`
+ */
+int32_t polymarket_market_sell_usdc_ex(const char *token_id,
+                                       double usdc_amount,
+                                       double min_price,
+                                       PolymarketOrderResultEx *out);
+
+/**
+ * Submit a market buy without waiting for it
+ * Returns a request id (> 0), or negative error code if rejected upfront.
+ * The result is delivered to POLYMARKET_EVENT_ORDER callbacks with
+ * "request_id" in the payload and kept for polymarket_poll_result();
+ * polymarket_abort_request() can abort it.
+ */
+int64_t polymarket_submit_market_buy(const char *token_id, double usdc_amount);
+
+/**
+ * Submit a share-targeted market buy without waiting for it, see
+ * polymarket_market_buy_shares_ex() and polymarket_submit_market_buy()
+ */
+int64_t polymarket_submit_market_buy_shares(const char *token_id, double shares, double max_price);
+
+/**
+ * Submit a market sell without waiting for it, see polymarket_submit_market_buy()
+ */
+int64_t polymarket_submit_market_sell(const char *token_id, double size);
+
+/**
+ * Submit a GTC limit buy without waiting for it, see polymarket_submit_market_buy()
+ */
+int64_t polymarket_submit_limit_buy(const char *token_id, double price, double usdc_amount);
+
+/**
+ * Submit a GTC limit sell without waiting for it, see polymarket_submit_market_buy()
+ */
+int64_t polymarket_submit_limit_sell(const char *token_id, double price, double size);
+
+/**
+ * Submit a cancel without waiting for it, see polymarket_submit_market_buy()
+ */
+int64_t polymarket_submit_cancel(const char *order_id);
+
+/**
+ * Submit a market buy and return at once; `callback` gets the result
+ * The callback runs once on a runtime worker thread with the caller's
+ * `request_id` (any tag, not interpreted), also when the request is aborted
+ * (POLYMARKET_ERR_ABORTED). It must not block or call blocking polymarket_*
+ * functions. POLYMARKET_EVENT_ORDER callbacks and polymarket_poll_result()
+ * see the request as for polymarket_submit_market_buy().
+ * Returns the library's id for the request (> 0, for
+ * polymarket_abort_request()), or negative error code if rejected upfront,
+ * in which case the callback is not called
+ */
+int64_t polymarket_market_buy_async(const char *token_id,
+                                    double usdc_amount,
+                                    uint64_t request_id,
+                                    PolymarketOrderCallback callback,
+                                    void *user_data);
+
+/**
+ * Submit a market sell and return at once, see polymarket_market_buy_async()
+ */
+int64_t polymarket_market_sell_async(const char *token_id,
+                                     double size,
+                                     uint64_t request_id,
+                                     PolymarketOrderCallback callback,
+                                     void *user_data);
+
+/**
+ * Submit a GTC limit buy and return at once, see polymarket_market_buy_async()
+ */
+int64_t polymarket_limit_buy_async(const char *token_id,
+                                   double price,
+                                   double usdc_amount,
+                                   uint64_t request_id,
+                                   PolymarketOrderCallback callback,
+                                   void *user_data);
+
+/**
+ * Submit a GTC limit sell and return at once, see polymarket_market_buy_async()
+ */
+int64_t polymarket_limit_sell_async(const char *token_id,
+                                    double price,
+                                    double size,
+                                    uint64_t request_id,
+                                    PolymarketOrderCallback callback,
+                                    void *user_data);
+
+/**
+ * Submit a cancel and return at once, see polymarket_market_buy_async()
+ */
+int64_t polymarket_cancel_async(const char *order_id,
+                                uint64_t request_id,
+                                PolymarketOrderCallback callback,
+                                void *user_data);
+
+/**
+ * Abort a submitted request that has not completed yet
+ * Its future is dropped mid-flight, so whether the order/cancel reached the
+ * venue is unknown: its POLYMARKET_EVENT_ORDER event carries
+ * POLYMARKET_ERR_ABORTED instead of a venue result, and the host should
+ * reconcile open orders and balances.
+ * Returns 0, or POLYMARKET_ERR_INVALID_ARGUMENT if the id is unknown or the
+ * request already completed (its result was or will be delivered)
+ */
+int32_t polymarket_abort_request(uint64_t request_id);
+
+/**
+ * Number of submitted requests not completed or aborted yet
+ * Returns count, or -1 if not initialized
+ */
+int64_t polymarket_requests_in_flight(void);
+
+/**
+ * Take the result of a submitted request, for hosts that poll from their
+ * own event loop instead of registering callbacks
+ * Returns POLYMARKET_PENDING while in flight (`out` untouched); once done,
+ * fills `out`, makes the full order id available via
+ * polymarket_last_order_id() (and its error message via
+ * polymarket_last_error_message()) and returns the result's error code.
+ * A result can be taken once; POLYMARKET_ERR_INVALID_ARGUMENT for unknown,
+ * already taken or evicted ids, or a NULL/too small `out`
+ */
+int32_t polymarket_poll_result(uint64_t request_id, PolymarketOrderResultEx *out);
+
+/**
+ * Register a callback for a set of event kinds (POLYMARKET_EVENT_* bit mask)
+ * `delivery` selects the invoking thread: POLYMARKET_DELIVER_THREAD (dedicated
+ * "polymarket-events" thread, serialized) or POLYMARKET_DELIVER_POLL (the
+ * thread calling polymarket_poll_events). Can be called before polymarket_init().
+ * Returns the subscription id (> 0), or negative error code
+ */
+int32_t polymarket_register_callback(uint32_t kind_mask,
+                                     PolymarketEventCallback callback,
+                                     void *user_data,
+                                     int32_t delivery);
+
+/**
+ * Route log lines at `min_level` (1=ERROR .. 5=TRACE) and more severe to
+ * `callback` as JSON lines, NULL to stop; stdout gets none while one is set
+ * Called on the thread that logged, runtime workers included: it must be
+ * quick and must not call into this library. Can be called before
+ * polymarket_init(). Returns 0, POLYMARKET_ERR_INVALID_ARGUMENT for a bad level
+ */
+int32_t polymarket_set_log_callback(PolymarketLogCallback callback,
+                                    int32_t min_level,
+                                    void *user_data);
+
+/**
+ * Unregister a callback; once this returns it is not invoked again
+ * Returns 0 on success, POLYMARKET_ERR_INVALID_ARGUMENT for unknown ids
+ */
+int32_t polymarket_unregister_callback(int32_t subscription_id);
+
+/**
+ * Deliver up to `max_events` queued events to POLYMARKET_DELIVER_POLL
+ * callbacks on the calling thread. Returns the number of events delivered
+ * Called from inside a POLYMARKET_DELIVER_POLL callback it delivers
+ * nothing and returns 0
+ */
+uint32_t polymarket_poll_events(uint32_t max_events);
+
+/**
+ * Number of events dropped because a delivery queue was full
+ */
+uint64_t polymarket_events_dropped(void);
+
+/**
+ * Negotiate the layout version of versioned structs (PolymarketOrderResultEx)
+ * Call once at startup, before or right after polymarket_init(), with the
+ * POLYMARKET_ABI_VERSION the host was compiled against.
+ * Returns the agreed version: min(host_version, library version)
+ */
+uint32_t polymarket_negotiate_abi(uint32_t host_version);
+
+/**
+ * Serve order entry and event streaming on a Unix domain socket
+ * (length-prefixed binary protocol, see ipc.rs) for co-located processes
+ * that want crash isolation. Replaces a running server and a stale socket
+ * file; the socket is created owner-only. Requires the `ipc` feature.
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_ipc_start(const char *path);
+
+/**
+ * Stop the socket server and remove its socket file
+ * Open connections are closed once the replies of their requests in
+ * flight are written, or after 15 seconds.
+ * Also done by polymarket_shutdown().
+ * Returns 0, or POLYMARKET_ERR_INVALID_ARGUMENT if no server was running
+ */
+int32_t polymarket_ipc_stop(void);
+
+/**
+ * Create the shared-memory order entry region `name` (POSIX shm name, e.g.
+ * "/polymarket-orders") with `capacity` records per ring (power of two) and
+ * start serving it; replaces an open region, and one of that name left by
+ * a process that has exited. Layout and protocol in shm.rs and
+ * polymarket_ffi.h. `out_base` (may be NULL) receives the mapping
+ * address for hosts in the same process. Requires the `shm` feature.
+ * Returns 0 on success, negative error code on failure
+ */
+int32_t polymarket_shm_open(const char *name, uint32_t capacity, void **out_base);
+
+/**
+ * Stop serving and unlink the shared-memory region
+ * Returns 0, or POLYMARKET_ERR_INVALID_ARGUMENT if none was open
+ */
+int32_t polymarket_shm_close(void);
+
+/**
+ * Shutdown and cleanup, waiting up to 5 s for running calls
+ * After calling this, polymarket_init() can be called again to re-initialize
+ */
+void polymarket_shutdown(void);
+
+/**
+ * Shutdown the executor gracefully
+ * New calls fail with POLYMARKET_ERR_NOT_INITIALIZED at once; running ones
+ * get `drain_timeout_ms` to finish, then the executor's remaining tasks are
+ * aborted and the signed log is synced. A callback may call this too
+ * (without the drain and cancels). Returns POLYMARKET_ERR_ABORTED if calls
+ * were still running at the deadline, POLYMARKET_ERR_CANCEL_FAILED if a
+ * cancel failed; the executor is shut down either way
+ */
+int32_t polymarket_shutdown_ex(uint32_t flags, uint32_t drain_timeout_ms);
+
+/**
+ * Connect an executor of its own
+ * `options_json` as for polymarket_set_connect_options() (NULL for the
+ * defaults), `flags` POLYMARKET_INIT_*. Staged options and init flags are
+ * not used. Returns NULL on failure, with the error code in `error` (if not
+ * NULL); POLYMARKET_OK there on success
+ */
+PolymarketHandle *polymarket_create(const char *private_key,
+                                    const char *funder_wallet,
+                                    const char *options_json,
+                                    uint32_t flags,
+                                    int32_t *error);
+
+/**
+ * Shut down and free a handle's executor, as polymarket_shutdown_ex() does
+ * for the global one; the handle is freed whatever the result, unless
+ * `flags` are invalid (POLYMARKET_ERR_INVALID_ARGUMENT)
+ * No call may use the handle once this starts; calls already running get
+ * `drain_timeout_ms` to finish. NULL returns POLYMARKET_ERR_NOT_INITIALIZED
+ */
+int32_t polymarket_destroy(PolymarketHandle *handle, uint32_t flags, uint32_t drain_timeout_ms);
+
+/**
+ * Connect `funder_wallet` with `private_key` as the wallet `name` (1-63
+ * printable ASCII characters) beside the global executor
+ * POLYMARKET_ERR_INVALID_ARGUMENT if the name is malformed or taken, or
+ * MAX_WALLETS are registered; connect errors as polymarket_init()
+ */
+int32_t polymarket_add_wallet(const char *name, const char *private_key, const char *funder_wallet);
+
+/**
+ * Shut the wallet `name` down and forget it, as polymarket_destroy() does
+ * for a handle: running calls get `drain_timeout_ms`, then
+ * POLYMARKET_SHUTDOWN_* `flags` apply. POLYMARKET_ERR_INVALID_ARGUMENT for
+ * an unknown name or invalid flags
+ */
+int32_t polymarket_remove_wallet(const char *name, uint32_t flags, uint32_t drain_timeout_ms);
+
+/**
+ * Registered wallets as JSON [{"name", "funder", "read_only", "dry_run"}],
+ * by name; returns its size (polymarket_last_response()) or a negative
+ * error code
+ */
+int64_t polymarket_list_wallets(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* POLYMARKET_FFI_GENERATED_H */
@@ -12,6 +12,7 @@
 #include <flox/common.h>
 #include <flox/log/abstract_logger.h>
 
+#include <chrono>
 #include <memory>
 #include <string>
 
@@ -37,7 +38,8 @@ struct PolymarketOrderResult
 class PolymarketOrderExecutor
 {
  public:
-  /// @param privateKey  Hex-encoded private key (with or without 0x prefix)
+  /// @param privateKey  Hex-encoded private key (with or without 0x prefix),
+  ///                    scrubbed from this object once init() succeeds
   /// @param funderWallet Hex-encoded funder/proxy wallet address (0x...)
   /// @param logger Optional logger for diagnostics
   explicit PolymarketOrderExecutor(std::string privateKey, std::string funderWallet,
@@ -68,6 +70,16 @@ class PolymarketOrderExecutor
   /// @param size Number of shares to sell
   PolymarketOrderResult sell(const std::string& tokenId, Quantity size);
 
+  /// Execute market buy as FOK (fills in full or not at all)
+  /// @param tokenId Polymarket token ID
+  /// @param usdcAmount Amount in USDC to spend
+  PolymarketOrderResult buyFok(const std::string& tokenId, Volume usdcAmount);
+
+  /// Execute market sell as FOK (fills in full or not at all)
+  /// @param tokenId Polymarket token ID
+  /// @param size Number of shares to sell
+  PolymarketOrderResult sellFok(const std::string& tokenId, Quantity size);
+
   /// Place GTC limit buy order
   /// @param tokenId Polymarket token ID
   /// @param price Limit price (0.01-0.99)
@@ -80,15 +92,42 @@ class PolymarketOrderExecutor
   /// @param size Number of shares to sell
   PolymarketOrderResult limitSell(const std::string& tokenId, Price price, Quantity size);
 
+  /// Place GTD limit buy order, cancelled by the venue once it expires
+  /// @param tokenId Polymarket token ID
+  /// @param price Limit price (0.01-0.99)
+  /// @param usdcAmount Amount in USDC to spend
+  /// @param expiresIn Lifetime, at least 1s
+  PolymarketOrderResult limitBuyGtd(const std::string& tokenId, Price price, Volume usdcAmount,
+                                    std::chrono::seconds expiresIn);
+
+  /// Place GTD limit sell order, cancelled by the venue once it expires
+  /// @param tokenId Polymarket token ID
+  /// @param price Limit price (0.01-0.99)
+  /// @param size Number of shares to sell
+  /// @param expiresIn Lifetime, at least 1s
+  PolymarketOrderResult limitSellGtd(const std::string& tokenId, Price price, Quantity size,
+                                     std::chrono::seconds expiresIn);
+
   /// Cancel specific order
   bool cancel(const std::string& orderId);
 
   /// Cancel all open orders
   bool cancelAll();
 
-  /// Get USDC balance
+  /// Get USDC balance (also refreshes the cache used by the pre-trade funds check)
   Volume getBalance();
 
+  /// Keep this much USDC out of reach of buys
+  /// Buys that don't fit into cached balance - buffer - in-flight buys
+  /// fail locally with POLYMARKET_ERR_INSUFFICIENT_BALANCE
+  void setBalanceBuffer(Volume buffer);
+
+  /// (Re)load risk limits from a JSON file; previous limits stay active on failure
+  bool loadRiskConfig(const std::string& path);
+
+  /// Replace risk limits from a JSON string; previous limits stay active on failure
+  bool setRiskConfig(const std::string& json);
+
   /// Get token balance (shares held)
   Quantity getTokenBalance(const std::string& tokenId);
 
@@ -0,0 +1,197 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Best bid and ask kept from the book ticker streams
+//!
+//! `TickerFeed` holds the subscribed symbols of one market and the last
+//! `<symbol>@bookTicker` message of each. `run` keeps a connection open
+//! until it fails; the caller reconnects, and the new connection subscribes
+//! every symbol again. Symbols added or removed while connected are sent on
+//! the live connection. Until its first message a symbol has no ticker.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::decimal;
+
+/// The venue pings every 3 minutes at most; a connection with no message
+/// (ping included) for this long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(5 * 60);
+
+/// How often the silence limit is checked
+const SILENCE_CHECK: Duration = Duration::from_secs(30);
+
+/// The venue's pings keep the connection alive, nothing to send
+const KEEPALIVE: Keepalive = Keepalive {
+    interval: SILENCE_CHECK,
+    ping: None,
+    silence_limit: SILENCE_LIMIT,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct BookTicker {
+    pub bid_price: f64,
+    pub bid_qty: f64,
+    pub ask_price: f64,
+    pub ask_qty: f64,
+    /// Book update id of the venue
+    pub update_id: u64,
+    pub received_at: Instant,
+}
+
+impl BookTicker {
+    /// Since the ticker was received
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTicker {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "u")]
+    update_id: u64,
+    #[serde(rename = "b", deserialize_with = "decimal")]
+    bid_price: f64,
+    #[serde(rename = "B", deserialize_with = "decimal")]
+    bid_qty: f64,
+    #[serde(rename = "a", deserialize_with = "decimal")]
+    ask_price: f64,
+    #[serde(rename = "A", deserialize_with = "decimal")]
+    ask_qty: f64,
+}
+
+#[derive(Default)]
+pub struct TickerFeed {
+    symbols: Mutex<BTreeSet<String>>,
+    tickers: RwLock<HashMap<String, BookTicker>>,
+    /// Subscription messages for the live connection, if any
+    commands: Commands,
+    /// Id of the next subscription message
+    next_id: AtomicU64,
+}
+
+impl TickerFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a symbol ("BTCUSDT"); false if it was already subscribed
+    pub fn subscribe(&self, symbol: &str) -> bool {
+        let added = self.lock_symbols().insert(symbol.to_string());
+        if added {
+            self.commands
+                .send(self.subscription(&[symbol.to_string()], "SUBSCRIBE"));
+        }
+        added
+    }
+
+    /// Remove a symbol and drop its ticker; false if it was not subscribed
+    pub fn unsubscribe(&self, symbol: &str) -> bool {
+        let removed = self.lock_symbols().remove(symbol);
+        if removed {
+            if let Ok(mut tickers) = self.tickers.write() {
+                tickers.remove(symbol);
+            }
+            self.commands
+                .send(self.subscription(&[symbol.to_string()], "UNSUBSCRIBE"));
+        }
+        removed
+    }
+
+    pub fn is_subscribed(&self, symbol: &str) -> bool {
+        self.lock_symbols().contains(symbol)
+    }
+
+    pub fn symbols(&self) -> Vec<String> {
+        self.lock_symbols().iter().cloned().collect()
+    }
+
+    /// Ticker of a symbol; None until the venue sent it
+    pub fn ticker(&self, symbol: &str) -> Option<BookTicker> {
+        self.tickers.read().ok()?.get(symbol).copied()
+    }
+
+    fn lock_symbols(&self) -> std::sync::MutexGuard<'_, BTreeSet<String>> {
+        self.symbols.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// (Un)subscription message of `symbols`' book ticker streams
+    fn subscription(&self, symbols: &[String], method: &str) -> String {
+        let streams: Vec<String> = symbols
+            .iter()
+            .map(|s| format!("{}@bookTicker", s.to_ascii_lowercase()))
+            .collect();
+        serde_json::json!({
+            "method": method,
+            "params": streams,
+            "id": self.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+        })
+        .to_string()
+    }
+
+    /// Apply one text message; returns the symbol whose ticker changed
+    pub fn apply(&self, text: &str) -> Option<String> {
+        let raw: RawTicker = match serde_json::from_str(text) {
+            Ok(raw) => raw,
+            Err(e) => {
+                // Answers to subscription messages land here too
+                debug!("[TICKER FEED] skipped message | {}", e);
+                return None;
+            }
+        };
+        // A message in flight when the symbol was removed
+        if !self.is_subscribed(&raw.symbol) {
+            return None;
+        }
+        let ticker = BookTicker {
+            bid_price: raw.bid_price,
+            bid_qty: raw.bid_qty,
+            ask_price: raw.ask_price,
+            ask_qty: raw.ask_qty,
+            update_id: raw.update_id,
+            received_at: Instant::now(),
+        };
+        let mut tickers = self.tickers.write().unwrap_or_else(|e| e.into_inner());
+        tickers.insert(raw.symbol.clone(), ticker);
+        Some(raw.symbol)
+    }
+
+    /// Connect to `url` and keep every subscribed ticker until the
+    /// connection fails; `on_update` gets each symbol whose ticker changed
+    pub async fn run(&self, url: &str, mut on_update: impl FnMut(&str)) -> Result<(), String> {
+        let connect = || async {
+            let (ws, _) = tokio_tungstenite::connect_async(url)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(ws)
+        };
+        let subscriptions = || {
+            let symbols = self.symbols();
+            if symbols.is_empty() {
+                return Vec::new();
+            }
+            vec![self.subscription(&symbols, "SUBSCRIBE")]
+        };
+        feed::run_ws(&self.commands, connect, subscriptions, KEEPALIVE, |text| {
+            if let Some(symbol) = self.apply(text) {
+                on_update(&symbol);
+            }
+            Ok(())
+        })
+        .await
+    }
+}
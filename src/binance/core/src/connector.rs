@@ -0,0 +1,347 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Binance behind the venue-neutral connector traits
+//!
+//! Instruments are spot symbols ("BTCUSDT"), or USD-M futures symbols with
+//! "futures:" in front ("futures:BTCUSDT"). Quantities are base asset units
+//! and prices quote units; order ids are the venue's. Balances hold the
+//! spot assets, then the futures assets named "futures:USDT" and so on.
+//! Top of book comes from the book ticker stream, one connection per
+//! market opened by its first subscribe.
+//!
+//! Config: `api_key` and `secret` are the API key and its secret key,
+//! `testnet` picks the testnet of both markets; `endpoint` is not used.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, TimeInForce as Tif, TopOfBook,
+};
+use tracing::info;
+
+use crate::{
+    valid_symbol, BinanceError, Market, OrderKind, OrderRequest, Side, TickerFeed, TimeInForce,
+    Trader, INVALID_SYMBOL,
+};
+
+const VENUE: &str = "binance";
+
+/// Prefix of futures instruments
+pub const FUTURES_PREFIX: &str = "futures:";
+
+// Venue codes mapped to their own error
+const AUTH_CODES: [i64; 3] = [-1022, -2014, -2015];
+const BALANCE_CODES: [i64; 3] = [-2010, -2018, -2019];
+const TOO_MANY_REQUESTS: i64 = -1003;
+
+impl From<BinanceError> for ConnectorError {
+    fn from(err: BinanceError) -> Self {
+        let message = err.to_string();
+        match err {
+            BinanceError::InvalidKey(_) => Self::InvalidKey(message),
+            BinanceError::InvalidSymbol(_) => Self::InvalidInstrument(message),
+            BinanceError::InvalidOrder(_) => Self::InvalidOrder(message),
+            BinanceError::Rejected {
+                code,
+                message: reason,
+            } => match code {
+                code if AUTH_CODES.contains(&code) => Self::Auth(message),
+                code if BALANCE_CODES.contains(&code)
+                    && reason.to_ascii_lowercase().contains("insufficient") =>
+                {
+                    Self::InsufficientBalance(message)
+                }
+                TOO_MANY_REQUESTS => Self::RateLimited(message),
+                INVALID_SYMBOL => Self::InvalidInstrument(message),
+                _ => Self::Rejected(message),
+            },
+            BinanceError::Api { status, .. } => match status {
+                401 => Self::Auth(message),
+                418 | 429 => Self::RateLimited(message),
+                500.. => Self::Venue(message),
+                _ => Self::Rejected(message),
+            },
+            BinanceError::Network(_) => Self::Network(message),
+            BinanceError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Market and symbol of an instrument
+fn parse_instrument(instrument: &str) -> Result<(Market, &str), ConnectorError> {
+    let (market, symbol) = match instrument.strip_prefix(FUTURES_PREFIX) {
+        Some(symbol) => (Market::Futures, symbol),
+        None => (Market::Spot, instrument),
+    };
+    if !valid_symbol(symbol) {
+        return Err(ConnectorError::InvalidInstrument(format!(
+            "not a symbol: {}",
+            instrument
+        )));
+    }
+    Ok((market, symbol))
+}
+
+/// Order request of a spec, refused if Binance cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    let kind = match order.order_type {
+        OrderType::Market if order.post_only => {
+            return Err(ConnectorError::InvalidOrder(
+                "post-only applies to limit orders".to_string(),
+            ))
+        }
+        OrderType::Market => OrderKind::Market,
+        OrderType::Limit { price } => {
+            let tif = match order.tif {
+                Tif::Gtc => TimeInForce::Gtc,
+                Tif::Ioc => TimeInForce::Ioc,
+                Tif::Fok => TimeInForce::Fok,
+                Tif::Day => return Err(ConnectorError::unsupported(VENUE, "day orders")),
+            };
+            OrderKind::Limit {
+                price,
+                tif,
+                post_only: order.post_only,
+            }
+        }
+    };
+    Ok(OrderRequest {
+        side: match order.side {
+            flox_connector::Side::Buy => Side::Buy,
+            flox_connector::Side::Sell => Side::Sell,
+        },
+        quantity: order.quantity,
+        kind,
+        reduce_only: order.reduce_only,
+    })
+}
+
+pub struct BinanceConnector {
+    trader: Arc<Trader>,
+    /// Book tickers, by market (spot, futures)
+    tickers: [Arc<TickerFeed>; 2],
+    /// Tasks keeping the ticker feeds connected, started by the first subscribe
+    ticker_feeds: [FeedTask; 2],
+    gate: CallGate,
+}
+
+impl BinanceConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        fok: true,
+        post_only: true,
+        reduce_only: true,
+        cancel_all: true,
+        balances: true,
+        top_of_book: true,
+        testnet: true,
+        ..Capabilities::NONE
+    };
+
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            tickers: [Arc::new(TickerFeed::new()), Arc::new(TickerFeed::new())],
+            ticker_feeds: [FeedTask::new(), FeedTask::new()],
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Book tickers of a market's subscribed symbols
+    pub fn tickers(&self, market: Market) -> &Arc<TickerFeed> {
+        &self.tickers[market.index()]
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+
+    /// Keep the book ticker of a symbol, opening the market's connection if
+    /// needed; it reconnects on its own until the connector is dropped
+    /// Panics outside a runtime, like `tokio::spawn`
+    pub fn subscribe_ticker(&self, market: Market, symbol: &str) {
+        let tickers = self.tickers(market).clone();
+        if tickers.subscribe(symbol) {
+            info!("[TICKER FEED] subscribed | {} {}", market.as_str(), symbol);
+        }
+        let url = self.trader.ws_url(market);
+        self.ticker_feeds[market.index()].ensure(|| async move {
+            feed::reconnect("TICKER FEED", || tickers.run(url, |_| {})).await
+        });
+    }
+
+    /// Stop keeping a symbol's ticker; false if it was not subscribed
+    pub fn unsubscribe_ticker(&self, market: Market, symbol: &str) -> bool {
+        let removed = self.tickers(market).unsubscribe(symbol);
+        if removed {
+            info!(
+                "[TICKER FEED] unsubscribed | {} {}",
+                market.as_str(),
+                symbol
+            );
+        }
+        removed
+    }
+}
+
+impl Connector for BinanceConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let trader = Trader::connect(
+                config.require_api_key()?,
+                config.require_secret()?,
+                config.testnet,
+            )
+            .await?;
+            Ok(Self::new(trader, CallGate::new(config.rate_limit)))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            let (market, symbol) = parse_instrument(instrument)?;
+            self.subscribe_ticker(market, symbol);
+            Ok(())
+        })
+    }
+
+    fn unsubscribe(&self, instrument: &str) -> Result<(), ConnectorError> {
+        let (market, symbol) = parse_instrument(instrument)?;
+        if !self.unsubscribe_ticker(market, symbol) {
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        }
+        Ok(())
+    }
+
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        let (market, symbol) = parse_instrument(instrument)?;
+        let tickers = self.tickers(market);
+        let Some(ticker) = tickers.ticker(symbol) else {
+            if tickers.is_subscribed(symbol) {
+                return Ok(None);
+            }
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        };
+        Ok(Some(TopOfBook {
+            bid: ticker.bid_price,
+            bid_size: ticker.bid_qty,
+            ask: ticker.ask_price,
+            ask_size: ticker.ask_qty,
+            age: ticker.age(),
+        }))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for BinanceConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let (market, symbol) = parse_instrument(&order.instrument)?;
+            let request = order_request(order)?;
+            let place = self.trader.place(market, symbol, request);
+            let outcome = self.gate.run(Call::OrderPost, place).await?;
+            Ok(PlacedOrder {
+                order_id: outcome.order_id.to_string(),
+                client_order_id: outcome.client_order_id,
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            let (market, symbol) = parse_instrument(instrument)?;
+            let order_id: u64 = order_id.parse().map_err(|_| {
+                ConnectorError::InvalidArgument(format!("not an order id: {}", order_id))
+            })?;
+            let cancel = self.trader.cancel(market, symbol, order_id);
+            self.gate.run(Call::Cancel, cancel).await
+        })
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            if let Some(instrument) = instrument {
+                let (market, symbol) = parse_instrument(instrument)?;
+                let cancel = self.trader.cancel_all(market, symbol);
+                self.gate.run(Call::Cancel, cancel).await?;
+                return Ok(None);
+            }
+            // The venue cancels by symbol: go over the symbols with orders
+            // open on either market
+            for market in [Market::Spot, Market::Futures] {
+                let open = self.trader.open_orders(market, None).await?;
+                let symbols: BTreeSet<_> = open.into_iter().map(|o| o.symbol).collect();
+                for symbol in symbols {
+                    let cancel = self.trader.cancel_all(market, &symbol);
+                    self.gate.run(Call::Cancel, cancel).await?;
+                }
+            }
+            Ok(None)
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let mut balances = Vec::new();
+            for market in [Market::Spot, Market::Futures] {
+                let held = self
+                    .gate
+                    .run(Call::Balance, self.trader.balances(market))
+                    .await?;
+                balances.extend(held.into_iter().map(|b| Balance {
+                    asset: match market {
+                        Market::Spot => b.asset,
+                        Market::Futures => format!("{}{}", FUTURES_PREFIX, b.asset),
+                    },
+                    free: b.free,
+                    total: b.total,
+                }));
+            }
+            Ok(balances)
+        })
+    }
+}
@@ -0,0 +1,505 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Binance spot and USD-M futures executor
+//!
+//! `Trader` is a session of one API key on both markets: orders, cancels,
+//! open orders, balances and futures positions over REST, and the order
+//! updates of the user data stream. `book_ticker::TickerFeed` keeps the
+//! best bid and ask of symbols from the WebSocket;
+//! `connector::BinanceConnector` puts both behind the shared connector
+//! traits. The FFI library wraps it in the same C ABI conventions as the
+//! Polymarket executor, so the engine can trade a CEX leg without another
+//! bridge.
+//!
+//! Quantities and prices are decimals of the symbol traded; quantities are
+//! rounded down to the symbol's step size and prices to its tick size.
+
+mod auth;
+pub mod book_ticker;
+pub mod connector;
+pub mod orders;
+pub mod user_stream;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use auth::{Signer, API_KEY_HEADER};
+pub use book_ticker::{BookTicker, TickerFeed};
+pub use connector::BinanceConnector;
+pub use orders::{OpenOrder, OrderKind, OrderOutcome, OrderRequest, Side, TimeInForce};
+pub use user_stream::OrderUpdate;
+
+pub const SPOT_URL: &str = "https://api.binance.com";
+pub const SPOT_TESTNET_URL: &str = "https://testnet.binance.vision";
+pub const FUTURES_URL: &str = "https://fapi.binance.com";
+pub const FUTURES_TESTNET_URL: &str = "https://testnet.binancefuture.com";
+pub const SPOT_WS_URL: &str = "wss://stream.binance.com:9443/ws";
+pub const SPOT_TESTNET_WS_URL: &str = "wss://stream.testnet.binance.vision/ws";
+pub const FUTURES_WS_URL: &str = "wss://fstream.binance.com/ws";
+pub const FUTURES_TESTNET_WS_URL: &str = "wss://fstream.binancefuture.com/ws";
+
+/// Timeout of one request, connect included
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Venue code of a request outside the receive window (clock drift)
+const TIMESTAMP_OUTSIDE_WINDOW: i64 = -1021;
+
+/// Venue code of an unknown symbol
+const INVALID_SYMBOL: i64 = -1121;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Market {
+    Spot,
+    /// USD-M perpetual and delivery futures
+    Futures,
+}
+
+impl Market {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Spot => "spot",
+            Self::Futures => "futures",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Spot => 0,
+            Self::Futures => 1,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BinanceError {
+    #[error("invalid API key: {0}")]
+    InvalidKey(String),
+    #[error("unknown symbol {0}")]
+    InvalidSymbol(String),
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// The venue refused the request, with its error code and reason
+    #[error("rejected: {code} {message}")]
+    Rejected { code: i64, message: String },
+    /// The venue answered with an error status and no error code
+    #[error("venue answered {status}: {message}")]
+    Api { status: u16, message: String },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: i64,
+    msg: String,
+}
+
+/// Decimal sent as a string or a number
+pub(crate) fn decimal<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Text(String),
+        Number(f64),
+    }
+    match Decimal::deserialize(d)? {
+        Decimal::Text(s) => s.parse().map_err(serde::de::Error::custom),
+        Decimal::Number(n) => Ok(n),
+    }
+}
+
+/// Decimal places of a step such as "0.00100000"
+fn step_decimals(step: &str) -> u32 {
+    match step.split_once('.') {
+        Some((_, fraction)) => fraction.trim_end_matches('0').len() as u32,
+        None => 0,
+    }
+}
+
+/// Order rules of a symbol, from its exchange info filters
+#[derive(Clone, Copy, Debug)]
+pub struct SymbolRules {
+    /// Quantities are multiples of this
+    pub step_size: f64,
+    pub qty_decimals: u32,
+    pub min_qty: f64,
+    /// Prices are multiples of this
+    pub tick_size: f64,
+    pub price_decimals: u32,
+}
+
+#[derive(Deserialize)]
+struct ExchangeInfo {
+    symbols: Vec<SymbolInfo>,
+}
+
+#[derive(Deserialize)]
+struct SymbolInfo {
+    symbol: String,
+    status: String,
+    filters: Vec<serde_json::Value>,
+}
+
+impl SymbolInfo {
+    fn rules(&self) -> Option<SymbolRules> {
+        let filter = |kind: &str, field: &str| {
+            self.filters
+                .iter()
+                .find(|f| f.get("filterType").and_then(|t| t.as_str()) == Some(kind))
+                .and_then(|f| f.get(field)?.as_str())
+                .map(str::to_string)
+        };
+        let step = filter("LOT_SIZE", "stepSize")?;
+        let tick = filter("PRICE_FILTER", "tickSize")?;
+        Some(SymbolRules {
+            step_size: step.parse().ok()?,
+            qty_decimals: step_decimals(&step),
+            min_qty: filter("LOT_SIZE", "minQty")?.parse().ok()?,
+            tick_size: tick.parse().ok()?,
+            price_decimals: step_decimals(&tick),
+        })
+    }
+}
+
+/// Asset held on one market
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Balance {
+    pub asset: String,
+    /// Free to trade: spot free, futures available balance
+    pub free: f64,
+    /// Spot free + locked, futures wallet balance
+    pub total: f64,
+}
+
+#[derive(Deserialize)]
+struct SpotAccount {
+    balances: Vec<SpotBalance>,
+}
+
+#[derive(Deserialize)]
+struct SpotBalance {
+    asset: String,
+    #[serde(deserialize_with = "decimal")]
+    free: f64,
+    #[serde(deserialize_with = "decimal")]
+    locked: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FuturesBalance {
+    asset: String,
+    #[serde(deserialize_with = "decimal")]
+    balance: f64,
+    #[serde(deserialize_with = "decimal")]
+    available_balance: f64,
+}
+
+/// Open futures position
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Position {
+    pub symbol: String,
+    /// Signed size: positive long, negative short
+    #[serde(rename(deserialize = "positionAmt"), deserialize_with = "decimal")]
+    pub size: f64,
+    #[serde(rename(deserialize = "entryPrice"), deserialize_with = "decimal")]
+    pub entry_price: f64,
+    #[serde(rename(deserialize = "markPrice"), deserialize_with = "decimal")]
+    pub mark_price: f64,
+    #[serde(rename(deserialize = "unRealizedProfit"), deserialize_with = "decimal")]
+    pub unrealized_pnl: f64,
+    /// 0 if the position cannot be liquidated
+    #[serde(rename(deserialize = "liquidationPrice"), deserialize_with = "decimal")]
+    pub liquidation_price: f64,
+    #[serde(deserialize_with = "decimal")]
+    pub leverage: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerTime {
+    server_time: i64,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    signer: Signer,
+    testnet: bool,
+    /// Venue clock minus local clock (ms), by market
+    clock_offsets: [AtomicI64; 2],
+    rules: RwLock<HashMap<(Market, String), SymbolRules>>,
+}
+
+/// Milliseconds since the epoch on the local clock
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// Symbol argument: 1-32 ASCII letters and digits, as the venue lists it
+pub fn valid_symbol(symbol: &str) -> bool {
+    (1..=32).contains(&symbol.len()) && symbol.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+impl Trader {
+    /// Open a session with an API key and its secret key, on the testnet
+    /// of both markets if `testnet`, and set the clock offsets
+    /// The key is checked by the first signed request
+    pub async fn connect(
+        api_key: &str,
+        secret_key: &str,
+        testnet: bool,
+    ) -> Result<Self, BinanceError> {
+        let signer = Signer::new(api_key.trim(), secret_key.trim())?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| BinanceError::Network(e.to_string()))?;
+        let trader = Self {
+            http,
+            signer,
+            testnet,
+            clock_offsets: [AtomicI64::new(0), AtomicI64::new(0)],
+            rules: RwLock::new(HashMap::new()),
+        };
+        let (spot, futures) = tokio::join!(
+            trader.sync_clock(Market::Spot),
+            trader.sync_clock(Market::Futures)
+        );
+        let (spot, futures) = (spot?, futures?);
+        info!(
+            "[CONNECT] binance | {} | clock offset spot={}ms futures={}ms",
+            if testnet { "testnet" } else { "mainnet" },
+            spot,
+            futures
+        );
+        Ok(trader)
+    }
+
+    /// REST base url of a market
+    fn base_url(&self, market: Market) -> &'static str {
+        match (market, self.testnet) {
+            (Market::Spot, false) => SPOT_URL,
+            (Market::Spot, true) => SPOT_TESTNET_URL,
+            (Market::Futures, false) => FUTURES_URL,
+            (Market::Futures, true) => FUTURES_TESTNET_URL,
+        }
+    }
+
+    /// WebSocket base url of a market
+    pub fn ws_url(&self, market: Market) -> &'static str {
+        match (market, self.testnet) {
+            (Market::Spot, false) => SPOT_WS_URL,
+            (Market::Spot, true) => SPOT_TESTNET_WS_URL,
+            (Market::Futures, false) => FUTURES_WS_URL,
+            (Market::Futures, true) => FUTURES_TESTNET_WS_URL,
+        }
+    }
+
+    /// Measure the offset of the venue clock; returns it (ms)
+    pub async fn sync_clock(&self, market: Market) -> Result<i64, BinanceError> {
+        let path = match market {
+            Market::Spot => "/api/v3/time",
+            Market::Futures => "/fapi/v1/time",
+        };
+        let sent = now_ms();
+        let time: ServerTime = self.send(market, Method::GET, path, &[], false).await?;
+        let received = now_ms();
+        let offset = time.server_time - (sent + received) / 2;
+        self.clock_offsets[market.index()].store(offset, Ordering::Relaxed);
+        Ok(offset)
+    }
+
+    /// Send a request to `path` of a market and decode the answer; signed
+    /// requests get timestamp, receive window and signature
+    /// Values are sent as they are: symbols, numbers and ids only
+    pub(crate) async fn send<T: DeserializeOwned>(
+        &self,
+        market: Market,
+        method: Method,
+        path: &str,
+        params: &[(&str, String)],
+        signed: bool,
+    ) -> Result<T, BinanceError> {
+        let mut query = params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("&");
+        if signed {
+            let offset = self.clock_offsets[market.index()].load(Ordering::Relaxed);
+            query = self.signer.sign(&query, (now_ms() + offset).max(0) as u64);
+        }
+        let mut url = format!("{}{}", self.base_url(market), path);
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&query);
+        }
+
+        let resp = self
+            .http
+            .request(method, url)
+            .header(API_KEY_HEADER, self.signer.api_key())
+            .send()
+            .await
+            .map_err(|e| BinanceError::Network(e.to_string()))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| BinanceError::Network(e.to_string()))?;
+        if !status.is_success() {
+            let err = match serde_json::from_str::<ErrorBody>(&text) {
+                // Rate limits (429, 418 once banned) keep their status
+                Ok(body) if status.is_client_error() && !matches!(status.as_u16(), 418 | 429) => {
+                    BinanceError::Rejected {
+                        code: body.code,
+                        message: body.msg,
+                    }
+                }
+                _ => BinanceError::Api {
+                    status: status.as_u16(),
+                    message: text,
+                },
+            };
+            if let BinanceError::Rejected { code, .. } = &err {
+                if *code == TIMESTAMP_OUTSIDE_WINDOW {
+                    // The request was not processed; the next one uses the
+                    // measured offset
+                    match Box::pin(self.sync_clock(market)).await {
+                        Ok(offset) => warn!("[CLOCK] {} offset now {}ms", market.as_str(), offset),
+                        Err(e) => warn!("[CLOCK] {} sync failed | {}", market.as_str(), e),
+                    }
+                }
+            }
+            return Err(err);
+        }
+        serde_json::from_str(&text).map_err(|e| BinanceError::Decode(e.to_string()))
+    }
+
+    /// Order rules of a symbol, read once from the exchange info
+    pub async fn rules(&self, market: Market, symbol: &str) -> Result<SymbolRules, BinanceError> {
+        let key = (market, symbol.to_string());
+        if let Some(rules) = self
+            .rules
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+        {
+            return Ok(*rules);
+        }
+
+        // Spot lists thousands of symbols: ask for the one needed
+        let info: Result<ExchangeInfo, _> = match market {
+            Market::Spot => {
+                let params = [("symbol", symbol.to_string())];
+                self.send(market, Method::GET, "/api/v3/exchangeInfo", &params, false)
+                    .await
+            }
+            Market::Futures => {
+                self.send(market, Method::GET, "/fapi/v1/exchangeInfo", &[], false)
+                    .await
+            }
+        };
+        let info = match info {
+            Err(BinanceError::Rejected { code, .. }) if code == INVALID_SYMBOL => {
+                return Err(BinanceError::InvalidSymbol(symbol.to_string()))
+            }
+            other => other?,
+        };
+        let mut cache = self.rules.write().unwrap_or_else(|e| e.into_inner());
+        for entry in &info.symbols {
+            if entry.status != "TRADING" {
+                cache.remove(&(market, entry.symbol.clone()));
+                continue;
+            }
+            if let Some(rules) = entry.rules() {
+                cache.insert((market, entry.symbol.clone()), rules);
+            }
+        }
+        cache
+            .get(&key)
+            .copied()
+            .ok_or_else(|| BinanceError::InvalidSymbol(symbol.to_string()))
+    }
+
+    /// Non-zero balances of a market
+    pub async fn balances(&self, market: Market) -> Result<Vec<Balance>, BinanceError> {
+        match market {
+            Market::Spot => {
+                let params = [("omitZeroBalances", "true".to_string())];
+                let account: SpotAccount = self
+                    .send(market, Method::GET, "/api/v3/account", &params, true)
+                    .await?;
+                Ok(account
+                    .balances
+                    .into_iter()
+                    .map(|b| Balance {
+                        asset: b.asset,
+                        free: b.free,
+                        total: b.free + b.locked,
+                    })
+                    .filter(|b| b.total != 0.0)
+                    .collect())
+            }
+            Market::Futures => {
+                let balances: Vec<FuturesBalance> = self
+                    .send(market, Method::GET, "/fapi/v2/balance", &[], true)
+                    .await?;
+                Ok(balances
+                    .into_iter()
+                    .map(|b| Balance {
+                        asset: b.asset,
+                        free: b.available_balance,
+                        total: b.balance,
+                    })
+                    .filter(|b| b.total != 0.0 || b.free != 0.0)
+                    .collect())
+            }
+        }
+    }
+
+    /// Amount of `asset` free to trade on a market, 0 if none held
+    pub async fn free_balance(&self, market: Market, asset: &str) -> Result<f64, BinanceError> {
+        Ok(self
+            .balances(market)
+            .await?
+            .into_iter()
+            .find(|b| b.asset == asset)
+            .map_or(0.0, |b| b.free))
+    }
+
+    /// Open futures positions
+    pub async fn positions(&self) -> Result<Vec<Position>, BinanceError> {
+        let positions: Vec<Position> = self
+            .send(
+                Market::Futures,
+                Method::GET,
+                "/fapi/v2/positionRisk",
+                &[],
+                true,
+            )
+            .await?;
+        Ok(positions.into_iter().filter(|p| p.size != 0.0).collect())
+    }
+}
@@ -0,0 +1,324 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders, cancels and open orders
+//!
+//! Spot and futures take the same request. Post-only is a LIMIT_MAKER order
+//! on spot and a GTX limit order on futures; reduce-only exists on futures
+//! only. Quantities are rounded down to the symbol's step size, prices to
+//! the nearest tick. Orders are placed with the RESULT answer, so what
+//! filled at once is known from the answer.
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::{decimal, BinanceError, Market, SymbolRules, Trader};
+
+/// Venue code of a cancel of an order that is not open
+const UNKNOWN_ORDER: i64 = -2011;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "BUY",
+            Self::Sell => "SELL",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeInForce {
+    /// Rests until filled or cancelled
+    Gtc,
+    /// What does not fill at once is cancelled
+    Ioc,
+    /// Fills in full at once or is cancelled
+    Fok,
+}
+
+impl TimeInForce {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Gtc => "GTC",
+            Self::Ioc => "IOC",
+            Self::Fok => "FOK",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderKind {
+    Market,
+    Limit {
+        price: f64,
+        tif: TimeInForce,
+        /// Cancelled instead of taking; GTC only
+        post_only: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub side: Side,
+    /// Base asset units (contracts' base units on futures), > 0
+    pub quantity: f64,
+    pub kind: OrderKind,
+    /// Futures only: only reduces the position held
+    pub reduce_only: bool,
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderOutcome {
+    pub order_id: u64,
+    pub client_order_id: String,
+    /// NEW, PARTIALLY_FILLED, FILLED, EXPIRED...
+    pub status: String,
+    pub executed_qty: f64,
+    /// Average fill price, 0 if nothing filled
+    pub avg_price: f64,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PlacedOrder {
+    order_id: u64,
+    client_order_id: String,
+    status: String,
+    #[serde(deserialize_with = "decimal")]
+    executed_qty: f64,
+    /// Futures
+    #[serde(deserialize_with = "decimal")]
+    avg_price: f64,
+    /// Spot (the venue's spelling)
+    #[serde(rename = "cummulativeQuoteQty", deserialize_with = "decimal")]
+    cumulative_quote_qty: f64,
+}
+
+/// Order open on the book
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OpenOrder {
+    pub symbol: String,
+    #[serde(rename(deserialize = "orderId"))]
+    pub order_id: u64,
+    #[serde(rename(deserialize = "clientOrderId"))]
+    pub client_order_id: String,
+    /// BUY or SELL
+    pub side: String,
+    /// LIMIT, LIMIT_MAKER...
+    #[serde(rename(deserialize = "type"))]
+    pub order_type: String,
+    #[serde(deserialize_with = "decimal")]
+    pub price: f64,
+    #[serde(rename(deserialize = "origQty"), deserialize_with = "decimal")]
+    pub quantity: f64,
+    #[serde(rename(deserialize = "executedQty"), deserialize_with = "decimal")]
+    pub executed_qty: f64,
+    pub status: String,
+    /// Creation time (ms since the epoch)
+    pub time: u64,
+}
+
+/// Quantity rounded down to the step size
+fn round_qty(quantity: f64, rules: &SymbolRules) -> f64 {
+    // The nudge keeps 0.3 / 0.1 from rounding down to 2
+    (quantity / rules.step_size + 1e-9).floor() * rules.step_size
+}
+
+/// Price rounded to the nearest tick
+pub fn round_price(price: f64, rules: &SymbolRules) -> f64 {
+    (price / rules.tick_size).round() * rules.tick_size
+}
+
+fn order_path(market: Market) -> &'static str {
+    match market {
+        Market::Spot => "/api/v3/order",
+        Market::Futures => "/fapi/v1/order",
+    }
+}
+
+impl Trader {
+    /// Check and place an order on `symbol` ("BTCUSDT")
+    pub async fn place(
+        &self,
+        market: Market,
+        symbol: &str,
+        request: OrderRequest,
+    ) -> Result<OrderOutcome, BinanceError> {
+        if request.reduce_only && market == Market::Spot {
+            return Err(BinanceError::InvalidOrder(
+                "reduce-only is for futures orders".to_string(),
+            ));
+        }
+        let rules = self.rules(market, symbol).await?;
+        let quantity = round_qty(request.quantity, &rules);
+        if !request.quantity.is_finite() || quantity <= 0.0 || quantity < rules.min_qty {
+            return Err(BinanceError::InvalidOrder(format!(
+                "quantity {} is below the minimum {} at step {}",
+                request.quantity, rules.min_qty, rules.step_size
+            )));
+        }
+
+        let mut params = vec![
+            ("symbol", symbol.to_string()),
+            ("side", request.side.as_str().to_string()),
+            (
+                "quantity",
+                format!("{:.*}", rules.qty_decimals as usize, quantity),
+            ),
+            ("newOrderRespType", "RESULT".to_string()),
+        ];
+        let mut price = None;
+        match request.kind {
+            OrderKind::Market => params.push(("type", "MARKET".to_string())),
+            OrderKind::Limit {
+                price: limit,
+                tif,
+                post_only,
+            } => {
+                let rounded = round_price(limit, &rules);
+                if !limit.is_finite() || rounded <= 0.0 {
+                    return Err(BinanceError::InvalidOrder(format!(
+                        "price must be > 0, got {}",
+                        limit
+                    )));
+                }
+                if post_only && tif != TimeInForce::Gtc {
+                    return Err(BinanceError::InvalidOrder(
+                        "post-only orders are GTC".to_string(),
+                    ));
+                }
+                let decimals = rules.price_decimals as usize;
+                params.push(("price", format!("{:.*}", decimals, rounded)));
+                match (market, post_only) {
+                    (Market::Spot, true) => params.push(("type", "LIMIT_MAKER".to_string())),
+                    (Market::Futures, true) => {
+                        params.push(("type", "LIMIT".to_string()));
+                        params.push(("timeInForce", "GTX".to_string()));
+                    }
+                    (_, false) => {
+                        params.push(("type", "LIMIT".to_string()));
+                        params.push(("timeInForce", tif.as_str().to_string()));
+                    }
+                }
+                price = Some(rounded);
+            }
+        }
+        if request.reduce_only {
+            params.push(("reduceOnly", "true".to_string()));
+        }
+
+        let placed: PlacedOrder = self
+            .send(market, Method::POST, order_path(market), &params, true)
+            .await?;
+        let avg_price = match market {
+            Market::Futures => placed.avg_price,
+            Market::Spot if placed.executed_qty > 0.0 => {
+                placed.cumulative_quote_qty / placed.executed_qty
+            }
+            Market::Spot => 0.0,
+        };
+        let outcome = OrderOutcome {
+            order_id: placed.order_id,
+            client_order_id: placed.client_order_id,
+            status: placed.status,
+            executed_qty: placed.executed_qty,
+            avg_price,
+        };
+        info!(
+            "[ORDER] {} {} {} | symbol={} | qty={} | price={:?} | reduce_only={} | id={} | \
+             status={} | executed={}",
+            market.as_str(),
+            match request.kind {
+                OrderKind::Market => "market",
+                OrderKind::Limit { .. } => "limit",
+            },
+            request.side.as_str(),
+            symbol,
+            quantity,
+            price,
+            request.reduce_only,
+            outcome.order_id,
+            outcome.status,
+            outcome.executed_qty
+        );
+        Ok(outcome)
+    }
+
+    /// Cancel an open order by symbol and order id
+    pub async fn cancel(
+        &self,
+        market: Market,
+        symbol: &str,
+        order_id: u64,
+    ) -> Result<(), BinanceError> {
+        let params = [
+            ("symbol", symbol.to_string()),
+            ("orderId", order_id.to_string()),
+        ];
+        let _: serde_json::Value = self
+            .send(market, Method::DELETE, order_path(market), &params, true)
+            .await?;
+        info!(
+            "[CANCEL] {} | symbol={} | id={}",
+            market.as_str(),
+            symbol,
+            order_id
+        );
+        Ok(())
+    }
+
+    /// Cancel every open order of `symbol`; no-op if there are none
+    pub async fn cancel_all(&self, market: Market, symbol: &str) -> Result<(), BinanceError> {
+        let params = [("symbol", symbol.to_string())];
+        let path = match market {
+            Market::Spot => "/api/v3/openOrders",
+            Market::Futures => "/fapi/v1/allOpenOrders",
+        };
+        let result: Result<serde_json::Value, _> =
+            self.send(market, Method::DELETE, path, &params, true).await;
+        match result {
+            // Spot refuses the cancel when nothing is open
+            Ok(_)
+            | Err(BinanceError::Rejected {
+                code: UNKNOWN_ORDER,
+                ..
+            }) => {
+                info!("[CANCEL_ALL] {} | symbol={}", market.as_str(), symbol);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Orders open on a market, of `symbol` or of every symbol if None
+    pub async fn open_orders(
+        &self,
+        market: Market,
+        symbol: Option<&str>,
+    ) -> Result<Vec<OpenOrder>, BinanceError> {
+        let params: Vec<_> = symbol
+            .map(|s| ("symbol", s.to_string()))
+            .into_iter()
+            .collect();
+        let path = match market {
+            Market::Spot => "/api/v3/openOrders",
+            Market::Futures => "/fapi/v1/openOrders",
+        };
+        self.send(market, Method::GET, path, &params, true).await
+    }
+}
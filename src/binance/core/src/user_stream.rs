@@ -0,0 +1,209 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Order updates from the user data stream
+//!
+//! `Trader::run_user_stream` opens a listen key on one market, streams
+//! `<ws_url>/<listen key>` and keeps the key alive until the connection
+//! fails; the caller reconnects with a new key. Each order event gives one
+//! `OrderUpdate`: `executionReport` on spot, `ORDER_TRADE_UPDATE` on
+//! futures, which carry the same fields. Balance and position events are
+//! skipped.
+
+use std::time::Duration;
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::{decimal, Market, Trader};
+
+/// Listen keys expire 60 minutes after they were last kept alive
+pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// The venue pings every 3 minutes at most; a connection with no message
+/// for this long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(10 * 60);
+
+/// How often the silence limit is checked
+const SILENCE_CHECK: Duration = Duration::from_secs(30);
+
+/// The venue's pings keep the connection alive, the listen key is kept
+/// alive over REST
+const STREAM_KEEPALIVE: Keepalive = Keepalive {
+    interval: SILENCE_CHECK,
+    ping: None,
+    silence_limit: SILENCE_LIMIT,
+};
+
+/// One order event of the account
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OrderUpdate {
+    #[serde(rename(deserialize = "s"))]
+    pub symbol: String,
+    #[serde(rename(deserialize = "i"))]
+    pub order_id: u64,
+    #[serde(rename(deserialize = "c"))]
+    pub client_order_id: String,
+    /// Spot cancels: client id of the order cancelled (`client_order_id` is
+    /// then the cancel's own)
+    #[serde(rename(deserialize = "C"), skip_serializing)]
+    pub original_client_order_id: String,
+    /// BUY or SELL
+    #[serde(rename(deserialize = "S"))]
+    pub side: String,
+    /// LIMIT, MARKET, LIMIT_MAKER...
+    #[serde(rename(deserialize = "o"))]
+    pub order_type: String,
+    /// NEW, CANCELED, REPLACED, REJECTED, TRADE, EXPIRED...
+    #[serde(rename(deserialize = "x"))]
+    pub execution_type: String,
+    /// NEW, PARTIALLY_FILLED, FILLED, CANCELED, EXPIRED...
+    #[serde(rename(deserialize = "X"))]
+    pub status: String,
+    #[serde(rename(deserialize = "p"), deserialize_with = "decimal")]
+    pub price: f64,
+    #[serde(rename(deserialize = "q"), deserialize_with = "decimal")]
+    pub quantity: f64,
+    /// Quantity of this fill, 0 if the event is not a fill
+    #[serde(rename(deserialize = "l"), deserialize_with = "decimal")]
+    pub last_fill_qty: f64,
+    #[serde(rename(deserialize = "L"), deserialize_with = "decimal")]
+    pub last_fill_price: f64,
+    /// Quantity filled so far
+    #[serde(rename(deserialize = "z"), deserialize_with = "decimal")]
+    pub cumulative_qty: f64,
+    /// Commission of this fill
+    #[serde(rename(deserialize = "n"), deserialize_with = "decimal")]
+    pub commission: f64,
+    #[serde(rename(deserialize = "N"), deserialize_with = "nullable")]
+    pub commission_asset: String,
+    /// -1 if the event is not a fill
+    #[serde(rename(deserialize = "t"))]
+    pub trade_id: i64,
+    /// The fill was against our resting order
+    #[serde(rename(deserialize = "m"))]
+    pub maker: bool,
+    /// Venue time of the event (ms since the epoch)
+    #[serde(rename(deserialize = "T"))]
+    pub time_ms: u64,
+}
+
+/// String that may be null
+fn nullable<'de, D: serde::Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    Ok(Option::<String>::deserialize(d)?.unwrap_or_default())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListenKey {
+    listen_key: String,
+}
+
+fn listen_key_path(market: Market) -> &'static str {
+    match market {
+        Market::Spot => "/api/v3/userDataStream",
+        Market::Futures => "/fapi/v1/listenKey",
+    }
+}
+
+/// Order update of one text message, None for other events
+/// Err if the venue expired the listen key
+fn parse(text: &str) -> Result<Option<OrderUpdate>, String> {
+    let message: serde_json::Value = match serde_json::from_str(text) {
+        Ok(message) => message,
+        Err(e) => {
+            debug!("[USER STREAM] unreadable message | {}", e);
+            return Ok(None);
+        }
+    };
+    let order = match message.get("e").and_then(|e| e.as_str()) {
+        Some("executionReport") => message,
+        Some("ORDER_TRADE_UPDATE") => match message.get("o") {
+            Some(order) => order.clone(),
+            None => return Ok(None),
+        },
+        Some("listenKeyExpired") => return Err("listen key expired".to_string()),
+        _ => return Ok(None),
+    };
+    match serde_json::from_value::<OrderUpdate>(order) {
+        Ok(mut update) => {
+            if update.execution_type == "CANCELED" && !update.original_client_order_id.is_empty() {
+                update.client_order_id = std::mem::take(&mut update.original_client_order_id);
+            }
+            Ok(Some(update))
+        }
+        Err(e) => {
+            debug!("[USER STREAM] unreadable order event | {}", e);
+            Ok(None)
+        }
+    }
+}
+
+impl Trader {
+    async fn new_listen_key(&self, market: Market) -> Result<String, String> {
+        let key: ListenKey = self
+            .send(market, Method::POST, listen_key_path(market), &[], false)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(key.listen_key)
+    }
+
+    async fn keep_alive(&self, market: Market, listen_key: &str) -> Result<(), String> {
+        let params = [("listenKey", listen_key.to_string())];
+        let _: serde_json::Value = self
+            .send(market, Method::PUT, listen_key_path(market), &params, false)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Stream the order updates of a market to `on_update` until the
+    /// connection fails
+    pub async fn run_user_stream(
+        &self,
+        market: Market,
+        mut on_update: impl FnMut(OrderUpdate),
+    ) -> Result<(), String> {
+        let listen_key = self.new_listen_key(market).await?;
+        let url = format!("{}/{}", self.ws_url(market), listen_key);
+        let connect = || async {
+            let (ws, _) = tokio_tungstenite::connect_async(url.as_str())
+                .await
+                .map_err(|e| e.to_string())?;
+            info!("[USER STREAM] {} connected", market.as_str());
+            Ok(ws)
+        };
+        // Nothing to subscribe or send, the key names the stream
+        let commands = Commands::new();
+        let stream = feed::run_ws(&commands, connect, Vec::new, STREAM_KEEPALIVE, |text| {
+            if let Some(update) = parse(text)? {
+                on_update(update);
+            }
+            Ok(())
+        });
+        let keep_key = async {
+            let mut keepalive = tokio::time::interval(KEEPALIVE_INTERVAL);
+            // The first tick is immediate, the key is fresh
+            keepalive.tick().await;
+            Err(loop {
+                keepalive.tick().await;
+                if let Err(e) = self.keep_alive(market, &listen_key).await {
+                    break e;
+                }
+            })
+        };
+        tokio::select! {
+            result = stream => result,
+            result = keep_key => result,
+        }
+    }
+}
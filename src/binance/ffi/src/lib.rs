@@ -0,0 +1,831 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Binance spot and futures executor - C FFI
+//!
+//! The C ABI of binance_ffi.h, laid out like the Polymarket executor's:
+//! one global `BinanceConnector` set up by binance_init(), blocking calls
+//! that run on the shared connector runtime, negative error codes (the same
+//! numbers as POLYMARKET_ERR_* where the meaning is shared), raw amounts
+//! with 6 decimals, thread-local last error and last response buffers.
+//! Every call names its market, so spot and futures share one set of
+//! functions. Order updates go to a callback as
+//! polymarket_subscribe_order_updates() does; book tickers are kept by
+//! background tasks of the connector, one connection per market.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use binance_executor_core::{
+    valid_symbol, BinanceConnector, BinanceError, Market, OrderKind, OrderOutcome, OrderRequest,
+    OrderUpdate, Side, TimeInForce, Trader,
+};
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, Connector, ConnectorConfig, ConnectorError};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+// Error codes
+pub const BINANCE_OK: i32 = 0;
+/// Not an error: no data yet
+pub const BINANCE_PENDING: i32 = 1;
+pub const BINANCE_ERR_NOT_INITIALIZED: i32 = -1;
+/// Key malformed
+pub const BINANCE_ERR_INVALID_KEY: i32 = -2;
+/// Key refused, or lacks the permission
+pub const BINANCE_ERR_AUTH: i32 = -3;
+/// Not a trading symbol of the market
+pub const BINANCE_ERR_INVALID_SYMBOL: i32 = -4;
+pub const BINANCE_ERR_ORDER_FAILED: i32 = -5;
+pub const BINANCE_ERR_CANCEL_FAILED: i32 = -6;
+/// Not enough balance or margin
+pub const BINANCE_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+pub const BINANCE_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const BINANCE_ERR_PANIC: i32 = -14;
+/// Venue refused the request, see last error
+pub const BINANCE_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const BINANCE_ERR_NETWORK: i32 = -20;
+/// Venue answered 429, or 418 (IP banned)
+pub const BINANCE_ERR_RATE_LIMITED: i32 = -21;
+/// Venue answered 5xx, order state unknown
+pub const BINANCE_ERR_VENUE_ERROR: i32 = -22;
+/// Quantity below the minimum, or price not valid
+pub const BINANCE_ERR_INVALID_ORDER: i32 = -23;
+
+// Markets
+pub const BINANCE_MARKET_SPOT: i32 = 0;
+/// USD-M futures
+pub const BINANCE_MARKET_FUTURES: i32 = 1;
+
+// Order flags
+/// Futures only: only reduces the position held
+pub const BINANCE_FLAG_REDUCE_ONLY: u32 = 1;
+/// Limit only: cancelled instead of taking
+pub const BINANCE_FLAG_POST_ONLY: u32 = 2;
+/// Limit only: what does not fill at once is cancelled
+pub const BINANCE_FLAG_IOC: u32 = 4;
+/// Limit only: fills in full at once or is cancelled
+pub const BINANCE_FLAG_FOK: u32 = 8;
+
+/// Raw units per asset unit
+const RAW_SCALE: f64 = 1_000_000.0;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct BinanceOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Venue order id
+    pub order_id: u64,
+    /// Assigned by the venue
+    pub client_order_id: [c_char; 40],
+    /// NEW, PARTIALLY_FILLED, FILLED, EXPIRED...
+    pub status: [c_char; 24],
+    /// Quantity filled at once (6 decimals)
+    pub executed_qty_raw: i64,
+    /// Average fill price (6 decimals), 0 if nothing filled
+    pub avg_price_raw: i64,
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl BinanceOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            order_id: 0,
+            client_order_id: [0; 40],
+            status: [0; 24],
+            executed_qty_raw: 0,
+            avg_price_raw: 0,
+            latency_ms: 0,
+        }
+    }
+
+    fn placed(outcome: &OrderOutcome, latency_ms: u64) -> Self {
+        let mut result = Self::failed(BINANCE_OK);
+        result.success = true;
+        result.order_id = outcome.order_id;
+        out_buf::write_fixed(&outcome.client_order_id, &mut result.client_order_id);
+        out_buf::write_fixed(&outcome.status, &mut result.status);
+        result.executed_qty_raw = to_raw(outcome.executed_qty);
+        result.avg_price_raw = to_raw(outcome.avg_price);
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+/// Best bid and ask of a subscribed symbol, filled by
+/// binance_get_book_ticker()
+#[repr(C)]
+#[derive(Default)]
+pub struct BinanceBookTicker {
+    pub bid_price_raw: i64,
+    pub bid_qty_raw: i64,
+    pub ask_price_raw: i64,
+    pub ask_qty_raw: i64,
+    /// Book update id of the venue
+    pub update_id: u64,
+    /// Since the ticker was received
+    pub age_ms: u64,
+}
+
+/// Order event of the user data stream, passed to BinanceOrderUpdateCallback;
+/// raw values in 6 decimals (divide by 1M for actual)
+#[repr(C)]
+pub struct BinanceOrderUpdate {
+    /// BINANCE_MARKET_*
+    pub market: i32,
+    pub is_buy: bool,
+    /// Fills: our order was resting on the book
+    pub maker: bool,
+    pub order_id: u64,
+    /// -1 if the event is not a fill
+    pub trade_id: i64,
+    /// Order limit price, 0 for market orders
+    pub price_raw: i64,
+    /// Order quantity
+    pub quantity_raw: i64,
+    /// Quantity of this fill
+    pub last_fill_qty_raw: i64,
+    /// Price of this fill
+    pub last_fill_price_raw: i64,
+    /// Quantity filled so far
+    pub cumulative_qty_raw: i64,
+    /// Commission of this fill
+    pub commission_raw: i64,
+    /// Venue time of the event
+    pub time_ms: u64,
+    pub symbol: [c_char; 32],
+    /// Of the order, also for cancels
+    pub client_order_id: [c_char; 40],
+    /// NEW, TRADE, CANCELED, EXPIRED, REJECTED...
+    pub execution_type: [c_char; 24],
+    /// NEW, PARTIALLY_FILLED, FILLED, CANCELED...
+    pub status: [c_char; 24],
+    pub commission_asset: [c_char; 16],
+}
+
+/// Order update callback of binance_subscribe_order_updates(); `update` is
+/// only valid during the call, `user_data` is as passed when subscribing
+pub type BinanceOrderUpdateCallback =
+    extern "C" fn(update: *const BinanceOrderUpdate, user_data: *mut std::ffi::c_void);
+
+struct OrderUpdateCallback {
+    callback: BinanceOrderUpdateCallback,
+    user_data: *mut std::ffi::c_void,
+}
+
+// SAFETY: user_data is opaque to us; the host owns its thread-safety
+unsafe impl Send for OrderUpdateCallback {}
+
+impl OrderUpdateCallback {
+    fn call(&self, market: Market, update: &OrderUpdate) {
+        let mut out = BinanceOrderUpdate {
+            market: market_code(market),
+            is_buy: update.side == "BUY",
+            maker: update.maker,
+            order_id: update.order_id,
+            trade_id: update.trade_id,
+            price_raw: to_raw(update.price),
+            quantity_raw: to_raw(update.quantity),
+            last_fill_qty_raw: to_raw(update.last_fill_qty),
+            last_fill_price_raw: to_raw(update.last_fill_price),
+            cumulative_qty_raw: to_raw(update.cumulative_qty),
+            commission_raw: to_raw(update.commission),
+            time_ms: update.time_ms,
+            symbol: [0; 32],
+            client_order_id: [0; 40],
+            execution_type: [0; 24],
+            status: [0; 24],
+            commission_asset: [0; 16],
+        };
+        out_buf::write_fixed(&update.symbol, &mut out.symbol);
+        out_buf::write_fixed(&update.client_order_id, &mut out.client_order_id);
+        out_buf::write_fixed(&update.execution_type, &mut out.execution_type);
+        out_buf::write_fixed(&update.status, &mut out.status);
+        out_buf::write_fixed(&update.commission_asset, &mut out.commission_asset);
+        (self.callback)(&out, self.user_data);
+    }
+}
+
+type SharedCallback = Arc<Mutex<Option<OrderUpdateCallback>>>;
+
+/// The connector and the user data streams, which only the C ABI has
+struct Executor {
+    connector: BinanceConnector,
+    order_updates: SharedCallback,
+    /// Tasks keeping the user data streams connected, by market
+    user_streams: [FeedTask; 2],
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "binance";
+
+fn get_executor() -> Option<Arc<Executor>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("binance_executor=info"))
+        .try_init();
+}
+
+fn to_raw(x: f64) -> i64 {
+    (x * RAW_SCALE).round() as i64
+}
+
+fn market_arg(market: i32) -> Option<Market> {
+    match market {
+        BINANCE_MARKET_SPOT => Some(Market::Spot),
+        BINANCE_MARKET_FUTURES => Some(Market::Futures),
+        _ => None,
+    }
+}
+
+fn market_code(market: Market) -> i32 {
+    match market {
+        Market::Spot => BINANCE_MARKET_SPOT,
+        Market::Futures => BINANCE_MARKET_FUTURES,
+    }
+}
+
+fn market_index(market: Market) -> usize {
+    match market {
+        Market::Spot => 0,
+        Market::Futures => 1,
+    }
+}
+
+/// Symbol argument as the venue lists it ("BTCUSDT")
+fn symbol_arg<'a>(symbol: *const c_char) -> Option<&'a str> {
+    str_arg(symbol).filter(|s| valid_symbol(s))
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect with an API key and its secret key, on the testnet of both
+/// markets if `testnet`. The key is checked by the first signed call.
+/// Replaces a session already set up. Returns 0 on success, negative error
+/// code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_init(
+    api_key: *const c_char,
+    secret_key: *const c_char,
+    testnet: bool,
+) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        init_logging();
+        let (Some(api_key), Some(secret_key)) = (str_arg(api_key), str_arg(secret_key)) else {
+            return BINANCE_ERR_INVALID_KEY;
+        };
+
+        let config = ConnectorConfig {
+            api_key: Some(api_key.to_string()),
+            secret: Some(Zeroizing::new(secret_key.to_string())),
+            testnet,
+            ..Default::default()
+        };
+
+        let connector = match runtime::block_on(BinanceConnector::connect(config)) {
+            Ok(Ok(connector)) => connector,
+            Ok(Err(e)) => return fail("INIT", &e, BINANCE_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return BINANCE_ERR_NOT_INITIALIZED;
+            }
+        };
+        let executor = Arc::new(Executor {
+            connector,
+            order_updates: Arc::new(Mutex::new(None)),
+            user_streams: [FeedTask::new(), FeedTask::new()],
+        });
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] binance executor ready");
+        BINANCE_OK
+    })
+}
+
+/// Shut the session down and close every stream; calls still running
+/// finish first. binance_init() can be called again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<Executor>>(VENUE);
+        if let Some(executor) = executor {
+            // Ticker feeds stop when the connector is dropped
+            for task in &executor.user_streams {
+                task.stop();
+            }
+            *executor.order_updates.lock().unwrap_or_else(|e| e.into_inner()) = None;
+            info!("[SHUTDOWN] binance executor stopped");
+        }
+    })
+}
+
+/// Place an order and fill `out`
+fn place(
+    op: &'static str,
+    market: i32,
+    symbol: *const c_char,
+    request: OrderRequest,
+    out: *mut BinanceOrderResult,
+) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return BINANCE_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = BinanceOrderResult::failed(BINANCE_ERR_NOT_INITIALIZED);
+            return BINANCE_ERR_NOT_INITIALIZED;
+        }
+    };
+    let Some(market) = market_arg(market) else {
+        *out = BinanceOrderResult::failed(BINANCE_ERR_INVALID_ARGUMENT);
+        return BINANCE_ERR_INVALID_ARGUMENT;
+    };
+    let Some(symbol) = symbol_arg(symbol) else {
+        *out = BinanceOrderResult::failed(BINANCE_ERR_INVALID_SYMBOL);
+        return BINANCE_ERR_INVALID_SYMBOL;
+    };
+
+    let started = Instant::now();
+    let symbol_owned = symbol.to_string();
+    let result = runtime::block_on(async move {
+        let connector = &executor.connector;
+        let place = connector.trader().place(market, &symbol_owned, request);
+        connector.gate().run(Call::OrderPost, place).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(outcome)) => BinanceOrderResult::placed(&outcome, latency_ms),
+        Ok(Err(e)) => BinanceOrderResult::failed(fail(op, &e, BINANCE_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | symbol={} | {}", op, symbol, e);
+            out_buf::set_last_error(&e);
+            BinanceOrderResult::failed(BINANCE_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+fn side(is_buy: bool) -> Side {
+    if is_buy {
+        Side::Buy
+    } else {
+        Side::Sell
+    }
+}
+
+/// Market order of `quantity` base asset units
+/// `flags`: BINANCE_FLAG_REDUCE_ONLY (futures) or 0
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_market_order(
+    market: i32,
+    symbol: *const c_char,
+    is_buy: bool,
+    quantity: f64,
+    flags: u32,
+    out: *mut BinanceOrderResult,
+) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        if flags & !BINANCE_FLAG_REDUCE_ONLY != 0 {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        }
+        let request = OrderRequest {
+            side: side(is_buy),
+            quantity,
+            kind: OrderKind::Market,
+            reduce_only: flags & BINANCE_FLAG_REDUCE_ONLY != 0,
+        };
+        place("MARKET", market, symbol, request, out)
+    })
+}
+
+/// Limit order of `quantity` base asset units at `price`, rounded to the
+/// nearest tick
+/// `flags`: BINANCE_FLAG_* combined; POST_ONLY, IOC and FOK exclude each other
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_limit_order(
+    market: i32,
+    symbol: *const c_char,
+    is_buy: bool,
+    quantity: f64,
+    price: f64,
+    flags: u32,
+    out: *mut BinanceOrderResult,
+) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let all = BINANCE_FLAG_REDUCE_ONLY | BINANCE_FLAG_POST_ONLY | BINANCE_FLAG_IOC
+            | BINANCE_FLAG_FOK;
+        if flags & !all != 0 {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        }
+        let (tif, post_only) =
+            match flags & (BINANCE_FLAG_POST_ONLY | BINANCE_FLAG_IOC | BINANCE_FLAG_FOK) {
+                0 => (TimeInForce::Gtc, false),
+                BINANCE_FLAG_POST_ONLY => (TimeInForce::Gtc, true),
+                BINANCE_FLAG_IOC => (TimeInForce::Ioc, false),
+                BINANCE_FLAG_FOK => (TimeInForce::Fok, false),
+                _ => return BINANCE_ERR_INVALID_ARGUMENT,
+            };
+        let request = OrderRequest {
+            side: side(is_buy),
+            quantity,
+            kind: OrderKind::Limit { price, tif, post_only },
+            reduce_only: flags & BINANCE_FLAG_REDUCE_ONLY != 0,
+        };
+        place("LIMIT", market, symbol, request, out)
+    })
+}
+
+/// Cancel an open order of `symbol` by its order id
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_cancel(market: i32, symbol: *const c_char, order_id: u64) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let Some(symbol) = symbol_arg(symbol) else {
+            return BINANCE_ERR_INVALID_SYMBOL;
+        };
+
+        let symbol_owned = symbol.to_string();
+        let result = runtime::block_on(async move {
+            let connector = &executor.connector;
+            let cancel = connector.trader().cancel(market, &symbol_owned, order_id);
+            connector.gate().run(Call::Cancel, cancel).await
+        });
+        match result {
+            Ok(Ok(())) => BINANCE_OK,
+            Ok(Err(e)) => fail("CANCEL", &e, BINANCE_ERR_CANCEL_FAILED),
+            Err(e) => {
+                error!("[CANCEL] failed | symbol={} | id={} | {}", symbol, order_id, e);
+                out_buf::set_last_error(&e);
+                BINANCE_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cancel every open order of `symbol`
+/// Returns 0 on success (also if none was open), negative error code on
+/// failure
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_cancel_all(market: i32, symbol: *const c_char) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let Some(symbol) = symbol_arg(symbol) else {
+            return BINANCE_ERR_INVALID_SYMBOL;
+        };
+
+        let symbol_owned = symbol.to_string();
+        let result = runtime::block_on(async move {
+            let connector = &executor.connector;
+            let cancel = connector.trader().cancel_all(market, &symbol_owned);
+            connector.gate().run(Call::Cancel, cancel).await
+        });
+        match result {
+            Ok(Ok(())) => BINANCE_OK,
+            Ok(Err(e)) => fail("CANCEL_ALL", &e, BINANCE_ERR_CANCEL_FAILED),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                BINANCE_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Amount of `asset` ("USDT") free to trade on a market (raw, 6 decimals),
+/// 0 if none held, or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_get_balance(market: i32, asset: *const c_char) -> i64 {
+    guard::catch(BINANCE_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED as i64,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT as i64;
+        };
+        let Some(asset) = symbol_arg(asset) else {
+            return BINANCE_ERR_INVALID_ARGUMENT as i64;
+        };
+
+        let asset = asset.to_string();
+        let result = runtime::block_on(async move {
+            let connector = &executor.connector;
+            let free = connector.trader().free_balance(market, &asset);
+            connector.gate().run(Call::Balance, free).await
+        });
+        match result {
+            Ok(Ok(free)) => to_raw(free),
+            Ok(Err(e)) => fail("BALANCE", &e, BINANCE_ERR_VENUE_ERROR) as i64,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                BINANCE_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// Run a query and keep its JSON for binance_last_response()
+fn query_json<Fut, T>(op: &str, query: impl FnOnce(Arc<Trader>) -> Fut) -> i64
+where
+    Fut: Future<Output = Result<T, BinanceError>> + Send + 'static,
+    T: serde::Serialize + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return BINANCE_ERR_NOT_INITIALIZED as i64,
+    };
+    let query = query(executor.connector.trader().clone());
+    match runtime::block_on(async move { query.await.map_err(ConnectorError::from) }) {
+        Ok(Ok(value)) => match serde_json::to_string(&value) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => BINANCE_ERR_VENUE_ERROR as i64,
+        },
+        Ok(Err(e)) => fail(op, &e, BINANCE_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            BINANCE_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// Non-zero balances of a market as JSON [{"asset", "free", "total"}];
+/// returns its size (binance_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_get_balances(market: i32) -> i64 {
+    guard::catch(BINANCE_ERR_PANIC as i64, || {
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT as i64;
+        };
+        query_json("BALANCES", |trader| async move { trader.balances(market).await })
+    })
+}
+
+/// Futures positions as JSON [{"symbol", "size", "entry_price",
+/// "mark_price", "unrealized_pnl", "liquidation_price", "leverage"}];
+/// returns its size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_get_positions() -> i64 {
+    guard::catch(BINANCE_ERR_PANIC as i64, || {
+        query_json("POSITIONS", |trader| async move { trader.positions().await })
+    })
+}
+
+/// Open orders of a market as JSON [{"symbol", "order_id", "client_order_id",
+/// "side", "order_type", "price", "quantity", "executed_qty", "status",
+/// "time"}], of `symbol` or of every symbol if NULL; returns its size or a
+/// negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_get_open_orders(market: i32, symbol: *const c_char) -> i64 {
+    guard::catch(BINANCE_ERR_PANIC as i64, || {
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT as i64;
+        };
+        let symbol = match symbol.is_null() {
+            true => None,
+            false => match symbol_arg(symbol) {
+                Some(symbol) => Some(symbol.to_string()),
+                None => return BINANCE_ERR_INVALID_SYMBOL as i64,
+            },
+        };
+        query_json("OPEN_ORDERS", |trader| async move {
+            trader.open_orders(market, symbol.as_deref()).await
+        })
+    })
+}
+
+/// Keep a market's user data stream connected until aborted, handing order
+/// updates to the registered callback
+async fn user_stream_task(trader: Arc<Trader>, market: Market, callback: SharedCallback) {
+    let label = format!("USER STREAM {}", market.as_str());
+    feed::reconnect(&label, || {
+        trader.run_user_stream(market, |update| {
+            let callback = callback.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(callback) = callback.as_ref() {
+                callback.call(market, &update);
+            }
+        })
+    })
+    .await
+}
+
+/// Stream a market's order updates and fills to `callback`, replacing the
+/// callback set before (one callback serves both markets)
+/// The callback runs on a runtime worker thread, one update at a time; it
+/// must not block or call back into the library
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_subscribe_order_updates(
+    market: i32,
+    callback: Option<BinanceOrderUpdateCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let Some(callback) = callback else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+
+        *executor.order_updates.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(OrderUpdateCallback { callback, user_data });
+        let _runtime = match runtime::enter() {
+            Ok(guard) => guard,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                return BINANCE_ERR_NOT_INITIALIZED;
+            }
+        };
+        let trader = executor.connector.trader().clone();
+        let callback = executor.order_updates.clone();
+        let spawned = executor.user_streams[market_index(market)]
+            .ensure(|| user_stream_task(trader, market, callback));
+        if spawned {
+            info!("[USER STREAM] {} subscribed", market.as_str());
+        }
+        BINANCE_OK
+    })
+}
+
+/// Close a market's user data stream. Once this returns for the last
+/// market subscribed, the callback is not invoked again. No-op if not
+/// subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_unsubscribe_order_updates(market: i32) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+
+        if executor.user_streams[market_index(market)].stop() {
+            info!("[USER STREAM] {} unsubscribed", market.as_str());
+        }
+        if executor.user_streams.iter().all(|task| !task.is_running()) {
+            // Waits for a callback running on the other stream's worker
+            *executor.order_updates.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        }
+        BINANCE_OK
+    })
+}
+
+/// Keep the best bid and ask of a symbol from the book ticker stream
+/// The first subscription of a market opens its connection; it reconnects
+/// on its own and closes on shutdown. Returns BINANCE_OK, also if already
+/// subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_subscribe_book_ticker(market: i32, symbol: *const c_char) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let Some(symbol) = symbol_arg(symbol) else {
+            return BINANCE_ERR_INVALID_SYMBOL;
+        };
+
+        let _runtime = match runtime::enter() {
+            Ok(guard) => guard,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                return BINANCE_ERR_NOT_INITIALIZED;
+            }
+        };
+        executor.connector.subscribe_ticker(market, symbol);
+        BINANCE_OK
+    })
+}
+
+/// Stop keeping a symbol's ticker; the connection stays open
+/// Returns BINANCE_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_unsubscribe_book_ticker(market: i32, symbol: *const c_char) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let Some(symbol) = symbol_arg(symbol) else {
+            return BINANCE_ERR_INVALID_SYMBOL;
+        };
+
+        if !executor.connector.unsubscribe_ticker(market, symbol) {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        }
+        BINANCE_OK
+    })
+}
+
+/// Best bid and ask of a subscribed symbol
+/// Returns BINANCE_PENDING until the venue sent its ticker (`out`
+/// untouched), BINANCE_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_get_book_ticker(
+    market: i32,
+    symbol: *const c_char,
+    out: *mut BinanceBookTicker,
+) -> i32 {
+    guard::catch(BINANCE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return BINANCE_ERR_NOT_INITIALIZED,
+        };
+        let Some(market) = market_arg(market) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let Some(symbol) = symbol_arg(symbol) else {
+            return BINANCE_ERR_INVALID_SYMBOL;
+        };
+        let Some(out) = args::out_arg(out) else {
+            return BINANCE_ERR_INVALID_ARGUMENT;
+        };
+        let tickers = executor.connector.tickers(market);
+        let ticker = match tickers.ticker(symbol) {
+            Some(ticker) => ticker,
+            None if tickers.is_subscribed(symbol) => return BINANCE_PENDING,
+            None => return BINANCE_ERR_INVALID_ARGUMENT,
+        };
+
+        *out = BinanceBookTicker {
+            bid_price_raw: to_raw(ticker.bid_price),
+            bid_qty_raw: to_raw(ticker.bid_qty),
+            ask_price_raw: to_raw(ticker.ask_price),
+            ask_qty_raw: to_raw(ticker.ask_qty),
+            update_id: ticker.update_id,
+            age_ms: ticker.age().as_millis() as u64,
+        };
+        BINANCE_OK
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn binance_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
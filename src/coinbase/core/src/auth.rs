@@ -0,0 +1,102 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Request auth
+//!
+//! Every REST request carries its own JWT as a bearer token: ES256 signed
+//! with the API key's P-256 private key, naming the key in `kid` and `sub`,
+//! valid for two minutes and bound to the method and path in `uri`. The
+//! nonce in the header only has to differ between tokens; it is built from
+//! the time and a counter.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdsa::signature::Signer as _;
+use p256::ecdsa::{Signature, SigningKey};
+use p256::pkcs8::DecodePrivateKey;
+use p256::SecretKey;
+
+use crate::CoinbaseError;
+
+/// Lifetime of a token (s)
+const TOKEN_LIFETIME: u64 = 120;
+
+pub(crate) struct Signer {
+    /// "organizations/{org_id}/apiKeys/{key_id}"
+    key_name: String,
+    /// Zeroed on drop
+    key: SigningKey,
+    nonces: AtomicU64,
+}
+
+impl Signer {
+    /// `private_key` is the key's PEM (SEC1 "EC PRIVATE KEY" as Coinbase
+    /// issues it, or PKCS#8); newlines may be written as \n, as in the
+    /// downloaded key file
+    pub(crate) fn new(key_name: &str, private_key: &str) -> Result<Self, CoinbaseError> {
+        if key_name.is_empty() || !key_name.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(CoinbaseError::InvalidKey(
+                "malformed API key name".to_string(),
+            ));
+        }
+        let pem = private_key.trim().replace("\\n", "\n");
+        let secret = SecretKey::from_sec1_pem(&pem)
+            .or_else(|_| SecretKey::from_pkcs8_pem(&pem))
+            .map_err(|_| {
+                CoinbaseError::InvalidKey("private key is not a P-256 key in PEM".to_string())
+            })?;
+        Ok(Self {
+            key_name: key_name.to_string(),
+            key: SigningKey::from(secret),
+            nonces: AtomicU64::new(0),
+        })
+    }
+
+    /// Token of a request, `uri` being "GET api.coinbase.com/api/..."
+    /// without the query
+    pub(crate) fn token(&self, uri: &str) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let nonce = format!(
+            "{:016x}{:016x}",
+            now.as_nanos() as u64,
+            self.nonces.fetch_add(1, Ordering::Relaxed)
+        );
+        let header = serde_json::json!({
+            "alg": "ES256",
+            "typ": "JWT",
+            "kid": self.key_name,
+            "nonce": nonce,
+        });
+        let claims = serde_json::json!({
+            "iss": "cdp",
+            "sub": self.key_name,
+            "nbf": now.as_secs(),
+            "exp": now.as_secs() + TOKEN_LIFETIME,
+            "uri": uri,
+        });
+
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        // Fixed-size r || s, as JWS wants it
+        let signature: Signature = self.key.sign(message.as_bytes());
+        format!(
+            "{}.{}",
+            message,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        )
+    }
+}
@@ -0,0 +1,277 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Coinbase behind the venue-neutral connector traits
+//!
+//! Instruments are products as the venue lists them ("BTC-USD");
+//! quantities are base asset units and prices quote units, rounded to the
+//! product's increments. Order ids are the venue's UUIDs. Spot orders
+//! cannot be reduce-only or good for the day. Top of book comes from the
+//! ticker channel, connected on the first subscribe.
+//!
+//! Config: `api_key` is the CDP API key name and `secret` its EC private
+//! key (PEM); `endpoint` and `testnet` are not used.
+
+use std::sync::Arc;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, TimeInForce as Tif, TopOfBook,
+};
+use tracing::info;
+
+use crate::{
+    valid_product, CoinbaseError, OrderKind, OrderRequest, Side, TickerFeed, TimeInForce, Trader,
+    WS_URL,
+};
+
+const VENUE: &str = "coinbase";
+
+impl From<CoinbaseError> for ConnectorError {
+    fn from(err: CoinbaseError) -> Self {
+        let message = err.to_string();
+        match err {
+            CoinbaseError::InvalidKey(_) => Self::InvalidKey(message),
+            CoinbaseError::InvalidProduct(_) => Self::InvalidInstrument(message),
+            CoinbaseError::InvalidOrder(_) => Self::InvalidOrder(message),
+            // PREVIEW_INSUFFICIENT_FUND and INSUFFICIENT_FUND
+            CoinbaseError::Rejected { reason, .. } if reason.contains("INSUFFICIENT_FUND") => {
+                Self::InsufficientBalance(message)
+            }
+            CoinbaseError::Rejected { reason, .. } if reason.contains("INVALID_PRODUCT_ID") => {
+                Self::InvalidInstrument(message)
+            }
+            CoinbaseError::Rejected { .. } => Self::Rejected(message),
+            CoinbaseError::Api { status, .. } => match status {
+                401 | 403 => Self::Auth(message),
+                429 => Self::RateLimited(message),
+                500.. => Self::Venue(message),
+                _ => Self::Rejected(message),
+            },
+            CoinbaseError::Network(_) => Self::Network(message),
+            CoinbaseError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Product of an instrument
+fn product(instrument: &str) -> Result<&str, ConnectorError> {
+    if !valid_product(instrument) {
+        return Err(ConnectorError::InvalidInstrument(format!(
+            "not a product: {}",
+            instrument
+        )));
+    }
+    Ok(instrument)
+}
+
+/// Order request of a spec, refused if Coinbase cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    if order.reduce_only {
+        return Err(ConnectorError::unsupported(
+            VENUE,
+            "reduce-only spot orders",
+        ));
+    }
+    let kind = match order.order_type {
+        OrderType::Market if order.post_only => {
+            return Err(ConnectorError::InvalidOrder(
+                "post-only applies to limit orders".to_string(),
+            ))
+        }
+        OrderType::Market => OrderKind::Market,
+        OrderType::Limit { price } => {
+            let tif = match order.tif {
+                Tif::Gtc => TimeInForce::Gtc,
+                Tif::Ioc => TimeInForce::Ioc,
+                Tif::Fok => TimeInForce::Fok,
+                Tif::Day => return Err(ConnectorError::unsupported(VENUE, "day orders")),
+            };
+            OrderKind::Limit {
+                price,
+                tif,
+                post_only: order.post_only,
+            }
+        }
+    };
+    Ok(OrderRequest {
+        side: match order.side {
+            flox_connector::Side::Buy => Side::Buy,
+            flox_connector::Side::Sell => Side::Sell,
+        },
+        size: order.quantity,
+        kind,
+    })
+}
+
+pub struct CoinbaseConnector {
+    trader: Arc<Trader>,
+    tickers: Arc<TickerFeed>,
+    /// Task keeping the ticker feed connected, started by the first subscribe
+    ticker_feed: FeedTask,
+    gate: CallGate,
+}
+
+impl CoinbaseConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        fok: true,
+        post_only: true,
+        cancel_all: true,
+        balances: true,
+        top_of_book: true,
+        ..Capabilities::NONE
+    };
+
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            tickers: Arc::new(TickerFeed::new()),
+            ticker_feed: FeedTask::new(),
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Tickers of the subscribed products
+    pub fn tickers(&self) -> &Arc<TickerFeed> {
+        &self.tickers
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+}
+
+impl Connector for CoinbaseConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let trader =
+                Trader::connect(config.require_api_key()?, config.require_secret()?).await?;
+            Ok(Self::new(trader, CallGate::new(config.rate_limit)))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            let product_id = product(instrument)?;
+            if self.tickers.subscribe(product_id) {
+                info!("[TICKER FEED] subscribed | product={}", product_id);
+            }
+            let tickers = self.tickers.clone();
+            self.ticker_feed.ensure(|| async move {
+                feed::reconnect("TICKER FEED", || tickers.run(WS_URL, |_| {})).await
+            });
+            Ok(())
+        })
+    }
+
+    fn unsubscribe(&self, instrument: &str) -> Result<(), ConnectorError> {
+        let product_id = product(instrument)?;
+        if !self.tickers.unsubscribe(product_id) {
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        }
+        info!("[TICKER FEED] unsubscribed | product={}", product_id);
+        Ok(())
+    }
+
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        let product_id = product(instrument)?;
+        let Some(ticker) = self.tickers.ticker(product_id) else {
+            if self.tickers.is_subscribed(product_id) {
+                return Ok(None);
+            }
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        };
+        Ok(Some(TopOfBook {
+            bid: ticker.bid_price,
+            bid_size: ticker.bid_qty,
+            ask: ticker.ask_price,
+            ask_size: ticker.ask_qty,
+            age: ticker.age(),
+        }))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for CoinbaseConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let product_id = product(&order.instrument)?;
+            let request = order_request(order)?;
+            let place = self.trader.place(product_id, request);
+            let outcome = self.gate.run(Call::OrderPost, place).await?;
+            Ok(PlacedOrder {
+                order_id: outcome.order_id,
+                client_order_id: outcome.client_order_id,
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        _instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(self.gate.run(Call::Cancel, self.trader.cancel(order_id)))
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            let product_id = instrument.map(product).transpose()?;
+            let cancel = self.trader.cancel_all(product_id);
+            let count = self.gate.run(Call::Cancel, cancel).await?;
+            Ok(Some(count))
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let held = self.gate.run(Call::Balance, self.trader.balances()).await?;
+            Ok(held
+                .into_iter()
+                .map(|b| Balance {
+                    asset: b.asset,
+                    free: b.free,
+                    total: b.total,
+                })
+                .collect())
+        })
+    }
+}
@@ -0,0 +1,347 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Coinbase Advanced Trade executor
+//!
+//! `Trader` is a session of one CDP API key: orders, cancels, open orders
+//! and balances over the Advanced Trade REST API, each request timed into
+//! the same latency histograms as the Polymarket executor keeps.
+//! `ticker::TickerFeed` keeps the best bid and ask of products from the
+//! market data WebSocket; `connector::CoinbaseConnector` puts both behind
+//! the shared connector traits. The FFI library wraps it in the same C ABI
+//! conventions as the Polymarket executor.
+//!
+//! Products are named as the venue lists them ("BTC-USD"). Sizes are base
+//! asset units rounded down to the product's base increment; prices are
+//! rounded to its price increment.
+
+mod auth;
+pub mod connector;
+pub mod metrics;
+pub mod orders;
+pub mod ticker;
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use auth::Signer;
+pub use connector::CoinbaseConnector;
+pub use metrics::OpMetrics;
+use metrics::{Metrics, Op};
+pub use orders::{OpenOrder, OrderKind, OrderOutcome, OrderRequest, Side, TimeInForce};
+pub use ticker::{Ticker, TickerFeed};
+
+pub const REST_HOST: &str = "api.coinbase.com";
+pub const WS_URL: &str = "wss://advanced-trade-ws.coinbase.com";
+
+/// Path prefix of the Advanced Trade endpoints
+const API_PREFIX: &str = "/api/v3/brokerage";
+
+/// Timeout of one request, connect included
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest page the list endpoints serve
+const PAGE_LIMIT: u32 = 250;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CoinbaseError {
+    #[error("invalid API key: {0}")]
+    InvalidKey(String),
+    #[error("unknown product {0}")]
+    InvalidProduct(String),
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// The venue refused the request, with its reason ("INSUFFICIENT_FUND")
+    #[error("rejected: {reason} {message}")]
+    Rejected { reason: String, message: String },
+    /// The venue answered with an error status
+    #[error("venue answered {status}: {message}")]
+    Api { status: u16, message: String },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// Decimal sent as a string or a number; empty strings read as 0
+pub(crate) fn decimal<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Text(String),
+        Number(f64),
+    }
+    match Decimal::deserialize(d)? {
+        Decimal::Text(s) if s.is_empty() => Ok(0.0),
+        Decimal::Text(s) => s.parse().map_err(serde::de::Error::custom),
+        Decimal::Number(n) => Ok(n),
+    }
+}
+
+/// Decimal places of an increment such as "0.00000001"
+fn increment_decimals(increment: &str) -> u32 {
+    match increment.split_once('.') {
+        Some((_, fraction)) => fraction.trim_end_matches('0').len() as u32,
+        None => 0,
+    }
+}
+
+/// Product argument: BASE-QUOTE in ASCII capitals and digits, as the venue
+/// lists it
+pub fn valid_product(product_id: &str) -> bool {
+    (3..=32).contains(&product_id.len())
+        && product_id.split_once('-').is_some_and(|(base, quote)| {
+            !base.is_empty() && !quote.is_empty() && !quote.contains('-')
+        })
+        && product_id
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// Order rules of a product
+#[derive(Clone, Copy, Debug)]
+pub struct ProductRules {
+    /// Sizes are multiples of this
+    pub base_increment: f64,
+    pub base_decimals: u32,
+    pub base_min_size: f64,
+    /// Prices are multiples of this
+    pub price_increment: f64,
+    pub price_decimals: u32,
+}
+
+#[derive(Deserialize)]
+struct ProductInfo {
+    base_increment: String,
+    #[serde(deserialize_with = "decimal")]
+    base_min_size: f64,
+    price_increment: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    trading_disabled: bool,
+    #[serde(default)]
+    cancel_only: bool,
+}
+
+/// Asset held
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Balance {
+    pub asset: String,
+    /// Available to trade
+    pub free: f64,
+    /// Available and on hold for open orders
+    pub total: f64,
+}
+
+#[derive(Deserialize)]
+struct Accounts {
+    accounts: Vec<Account>,
+    #[serde(default)]
+    has_next: bool,
+    #[serde(default)]
+    cursor: String,
+}
+
+#[derive(Deserialize)]
+struct Account {
+    currency: String,
+    available_balance: Amount,
+    #[serde(default)]
+    hold: Amount,
+}
+
+#[derive(Default, Deserialize)]
+struct Amount {
+    #[serde(deserialize_with = "decimal")]
+    value: f64,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    signer: Signer,
+    rules: RwLock<HashMap<String, ProductRules>>,
+    metrics: Metrics,
+}
+
+impl Trader {
+    /// Open a session with a CDP API key name and its private key (PEM),
+    /// checked by reading the balances
+    pub async fn connect(key_name: &str, private_key: &str) -> Result<Self, CoinbaseError> {
+        let signer = Signer::new(key_name.trim(), private_key)?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| CoinbaseError::Network(e.to_string()))?;
+        let trader = Self {
+            http,
+            signer,
+            rules: RwLock::new(HashMap::new()),
+            metrics: Metrics::new(),
+        };
+        let balances = trader.balances().await?;
+        info!("[CONNECT] coinbase | assets held={}", balances.len());
+        Ok(trader)
+    }
+
+    /// Send a request to `path` (below /api/v3/brokerage) and decode the
+    /// answer; every request carries a token of its own
+    /// Query values are sent as they are: ids, names and numbers only
+    pub(crate) async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<serde_json::Value>,
+    ) -> Result<T, CoinbaseError> {
+        let path = format!("{}{}", API_PREFIX, path);
+        let token = self
+            .signer
+            .token(&format!("{} {}{}", method.as_str(), REST_HOST, path));
+        let mut url = format!("https://{}{}", REST_HOST, path);
+        if !query.is_empty() {
+            let query: Vec<String> = query
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            url.push('?');
+            url.push_str(&query.join("&"));
+        }
+
+        let mut request = self.http.request(method, url).bearer_auth(token);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| CoinbaseError::Network(e.to_string()))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| CoinbaseError::Network(e.to_string()))?;
+        if !status.is_success() {
+            return Err(match serde_json::from_str::<ErrorBody>(&text) {
+                // Auth, permission and rate limits keep their status
+                Ok(body) if matches!(status.as_u16(), 400 | 404) => CoinbaseError::Rejected {
+                    reason: body.error,
+                    message: body.message,
+                },
+                _ => CoinbaseError::Api {
+                    status: status.as_u16(),
+                    message: text,
+                },
+            });
+        }
+        serde_json::from_str(&text).map_err(|e| CoinbaseError::Decode(e.to_string()))
+    }
+
+    /// Order rules of a product, read once
+    pub async fn rules(&self, product_id: &str) -> Result<ProductRules, CoinbaseError> {
+        if let Some(rules) = self
+            .rules
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(product_id)
+        {
+            return Ok(*rules);
+        }
+
+        let path = format!("/products/{}", product_id);
+        let info: ProductInfo = match self.send(Method::GET, &path, &[], None).await {
+            Err(CoinbaseError::Rejected { .. }) => {
+                return Err(CoinbaseError::InvalidProduct(product_id.to_string()))
+            }
+            other => other?,
+        };
+        if info.trading_disabled || info.cancel_only || info.status != "online" {
+            return Err(CoinbaseError::InvalidProduct(format!(
+                "{} is not trading",
+                product_id
+            )));
+        }
+        let parse = |increment: &str| {
+            increment
+                .parse::<f64>()
+                .ok()
+                .filter(|x| *x > 0.0)
+                .ok_or_else(|| CoinbaseError::Decode(format!("increment {:?}", increment)))
+        };
+        let rules = ProductRules {
+            base_increment: parse(&info.base_increment)?,
+            base_decimals: increment_decimals(&info.base_increment),
+            base_min_size: info.base_min_size,
+            price_increment: parse(&info.price_increment)?,
+            price_decimals: increment_decimals(&info.price_increment),
+        };
+        self.rules
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(product_id.to_string(), rules);
+        Ok(rules)
+    }
+
+    /// Non-zero balances
+    pub async fn balances(&self) -> Result<Vec<Balance>, CoinbaseError> {
+        let mut balances = Vec::new();
+        let mut cursor = String::new();
+        loop {
+            let mut query = vec![("limit", PAGE_LIMIT.to_string())];
+            if !cursor.is_empty() {
+                query.push(("cursor", cursor.clone()));
+            }
+            let page: Accounts = self
+                .timed(
+                    Op::Balance,
+                    self.send(Method::GET, "/accounts", &query, None),
+                )
+                .await?;
+            balances.extend(
+                page.accounts
+                    .into_iter()
+                    .map(|a| Balance {
+                        asset: a.currency,
+                        free: a.available_balance.value,
+                        total: a.available_balance.value + a.hold.value,
+                    })
+                    .filter(|b| b.total != 0.0),
+            );
+            if !page.has_next || page.cursor.is_empty() {
+                return Ok(balances);
+            }
+            cursor = page.cursor;
+        }
+    }
+
+    /// Amount of `asset` available to trade, 0 if none held
+    pub async fn free_balance(&self, asset: &str) -> Result<f64, CoinbaseError> {
+        Ok(self
+            .balances()
+            .await?
+            .into_iter()
+            .filter(|b| b.asset == asset)
+            .map(|b| b.free)
+            .sum())
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Latency and error counts of venue calls
+//!
+//! Every order post, cancel and balance read is timed, token signing and
+//! timeouts included, into the shared histogram of its operation (see
+//! `flox_connector::metrics`), rendered in the Prometheus text format as
+//! the `coinbase_call_*` series.
+
+use std::future::Future;
+
+pub use flox_connector::OpMetrics;
+use flox_connector::Operation;
+
+use crate::{CoinbaseError, Trader};
+
+/// Prefix of the Prometheus series
+const PROMETHEUS_PREFIX: &str = "coinbase";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    /// Market and limit orders
+    OrderPost,
+    /// Cancel requests, one per batch of ids
+    Cancel,
+    /// Account balance reads, one per page
+    Balance,
+}
+
+impl Operation for Op {
+    const ALL: &'static [Self] = &[Op::OrderPost, Op::Cancel, Op::Balance];
+
+    fn name(self) -> &'static str {
+        match self {
+            Op::OrderPost => "order_post",
+            Op::Cancel => "cancel",
+            Op::Balance => "balance",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Histograms of every `Op`
+pub(crate) type Metrics = flox_connector::Metrics<Op>;
+
+impl Trader {
+    /// Run a venue call of `op`, recording how long it took and whether it
+    /// failed
+    pub(crate) async fn timed<T>(
+        &self,
+        op: Op,
+        call: impl Future<Output = Result<T, CoinbaseError>>,
+    ) -> Result<T, CoinbaseError> {
+        self.metrics.timed(op, call).await
+    }
+
+    /// Latency and error counts of every operation so far
+    pub fn metrics(&self) -> Vec<OpMetrics> {
+        self.metrics.snapshot()
+    }
+
+    /// The same counts in the Prometheus text exposition format
+    pub fn metrics_prometheus(&self) -> String {
+        self.metrics.prometheus(PROMETHEUS_PREFIX)
+    }
+}
@@ -0,0 +1,431 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders, cancels and open orders
+//!
+//! The venue answers an order with its ids only; what it filled is read
+//! from the open orders, or from the venue's order history. Market orders
+//! are immediate-or-cancel by base size. Limit orders are good till
+//! cancelled (post-only on request), immediate-or-cancel through the smart
+//! order router, or fill-or-kill. Cancels go through the batch endpoint,
+//! which answers each id on its own.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::metrics::Op;
+use crate::{decimal, CoinbaseError, ProductRules, Trader, PAGE_LIMIT};
+
+/// Ids one cancel request takes at most
+const CANCEL_BATCH: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "BUY",
+            Self::Sell => "SELL",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeInForce {
+    /// Rests until filled or cancelled
+    Gtc,
+    /// What does not fill at once is cancelled
+    Ioc,
+    /// Fills in full at once or is cancelled
+    Fok,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderKind {
+    Market,
+    Limit {
+        price: f64,
+        tif: TimeInForce,
+        /// Cancelled instead of taking; GTC only
+        post_only: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub side: Side,
+    /// Base asset units, > 0
+    pub size: f64,
+    pub kind: OrderKind,
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderOutcome {
+    /// Venue order id (UUID)
+    pub order_id: String,
+    /// Our id of the order ("flox-...")
+    pub client_order_id: String,
+}
+
+#[derive(Deserialize)]
+struct CreateOrderResponse {
+    success: bool,
+    #[serde(default)]
+    success_response: Option<CreatedOrder>,
+    #[serde(default)]
+    error_response: Option<OrderError>,
+}
+
+#[derive(Deserialize)]
+struct CreatedOrder {
+    order_id: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OrderError {
+    error: String,
+    message: String,
+    error_details: String,
+    preview_failure_reason: String,
+    new_order_failure_reason: String,
+}
+
+impl OrderError {
+    fn into_error(self) -> CoinbaseError {
+        // The most specific reason given
+        let reason = [
+            self.new_order_failure_reason,
+            self.preview_failure_reason,
+            self.error,
+        ]
+        .into_iter()
+        .find(|r| !r.is_empty() && !r.starts_with("UNKNOWN"))
+        .unwrap_or_else(|| "UNKNOWN_FAILURE_REASON".to_string());
+        let message = match (self.message.is_empty(), self.error_details.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.error_details,
+            (false, false) => format!("{}: {}", self.message, self.error_details),
+        };
+        CoinbaseError::Rejected { reason, message }
+    }
+}
+
+#[derive(Deserialize)]
+struct CancelResponse {
+    results: Vec<CancelResult>,
+}
+
+#[derive(Deserialize)]
+struct CancelResult {
+    success: bool,
+    #[serde(default)]
+    failure_reason: String,
+    #[serde(default)]
+    order_id: String,
+}
+
+#[derive(Deserialize)]
+struct OrderList {
+    orders: Vec<ListedOrder>,
+    #[serde(default)]
+    has_next: bool,
+    #[serde(default)]
+    cursor: String,
+}
+
+#[derive(Deserialize)]
+struct ListedOrder {
+    order_id: String,
+    #[serde(default)]
+    client_order_id: String,
+    product_id: String,
+    side: String,
+    #[serde(default)]
+    order_type: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default, deserialize_with = "decimal")]
+    filled_size: f64,
+    #[serde(default)]
+    created_time: String,
+    /// One entry, named after the order type ("limit_limit_gtc")
+    #[serde(default)]
+    order_configuration: serde_json::Value,
+}
+
+impl ListedOrder {
+    /// Field of the order's configuration, 0 if absent
+    fn configured(&self, field: &str) -> f64 {
+        self.order_configuration
+            .as_object()
+            .and_then(|config| config.values().next())
+            .and_then(|config| config.get(field)?.as_str()?.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Order open on the book
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OpenOrder {
+    pub order_id: String,
+    pub client_order_id: String,
+    pub product_id: String,
+    /// BUY or SELL
+    pub side: String,
+    /// LIMIT, MARKET...
+    pub order_type: String,
+    /// Limit price, 0 if none
+    pub price: f64,
+    pub size: f64,
+    pub filled_size: f64,
+    /// OPEN, PENDING, QUEUED
+    pub status: String,
+    /// RFC 3339
+    pub created_time: String,
+}
+
+/// Round to a multiple of `increment`, down if `down`, else to the nearest
+fn round_to(x: f64, increment: f64, down: bool) -> f64 {
+    // The nudge keeps 0.3 / 0.1 from rounding down to 2
+    let steps = x / increment;
+    let steps = if down {
+        (steps + 1e-9).floor()
+    } else {
+        steps.round()
+    };
+    steps * increment
+}
+
+/// Counter of the client order ids of this process
+static NEXT_CLIENT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Client order id: the time (ms) and the counter, so ids of a restarted
+/// process do not repeat earlier ones
+fn client_order_id() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+    format!(
+        "flox-{:x}-{:x}",
+        now,
+        NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+impl Trader {
+    /// Check and place an order on `product_id` ("BTC-USD")
+    pub async fn place(
+        &self,
+        product_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderOutcome, CoinbaseError> {
+        let rules: ProductRules = self.rules(product_id).await?;
+        let size = round_to(request.size, rules.base_increment, true);
+        if !request.size.is_finite() || size <= 0.0 || size < rules.base_min_size {
+            return Err(CoinbaseError::InvalidOrder(format!(
+                "size {} is below the minimum {} in steps of {}",
+                request.size, rules.base_min_size, rules.base_increment
+            )));
+        }
+        let base_size = format!("{:.*}", rules.base_decimals as usize, size);
+
+        let (configuration, price) = match request.kind {
+            OrderKind::Market => (
+                serde_json::json!({ "market_market_ioc": { "base_size": base_size } }),
+                None,
+            ),
+            OrderKind::Limit {
+                price,
+                tif,
+                post_only,
+            } => {
+                let rounded = round_to(price, rules.price_increment, false);
+                if !price.is_finite() || rounded <= 0.0 {
+                    return Err(CoinbaseError::InvalidOrder(format!(
+                        "price must be > 0, got {}",
+                        price
+                    )));
+                }
+                if post_only && tif != TimeInForce::Gtc {
+                    return Err(CoinbaseError::InvalidOrder(
+                        "post-only orders are good till cancelled".to_string(),
+                    ));
+                }
+                let limit_price = format!("{:.*}", rules.price_decimals as usize, rounded);
+                let configuration = match tif {
+                    TimeInForce::Gtc => serde_json::json!({ "limit_limit_gtc": {
+                        "base_size": base_size,
+                        "limit_price": limit_price,
+                        "post_only": post_only,
+                    }}),
+                    TimeInForce::Ioc => serde_json::json!({ "sor_limit_ioc": {
+                        "base_size": base_size,
+                        "limit_price": limit_price,
+                    }}),
+                    TimeInForce::Fok => serde_json::json!({ "limit_limit_fok": {
+                        "base_size": base_size,
+                        "limit_price": limit_price,
+                    }}),
+                };
+                (configuration, Some(rounded))
+            }
+        };
+
+        let client_order_id = client_order_id();
+        let body = serde_json::json!({
+            "client_order_id": client_order_id,
+            "product_id": product_id,
+            "side": request.side.as_str(),
+            "order_configuration": configuration,
+        });
+        let post = async {
+            let response: CreateOrderResponse =
+                self.send(Method::POST, "/orders", &[], Some(body)).await?;
+            match (response.success, response.success_response) {
+                (true, Some(created)) => Ok(created.order_id),
+                _ => Err(response.error_response.unwrap_or_default().into_error()),
+            }
+        };
+        let order_id = self.timed(Op::OrderPost, post).await?;
+        info!(
+            "[ORDER] {} {} | product={} | size={} | price={:?} | id={}",
+            match request.kind {
+                OrderKind::Market => "market",
+                OrderKind::Limit { .. } => "limit",
+            },
+            request.side.as_str(),
+            product_id,
+            size,
+            price,
+            order_id
+        );
+        Ok(OrderOutcome {
+            order_id,
+            client_order_id,
+        })
+    }
+
+    /// Cancel orders by id; returns the ids cancelled and, for the others,
+    /// the venue's reason
+    async fn cancel_batch(
+        &self,
+        order_ids: &[String],
+    ) -> Result<Vec<(String, Result<(), String>)>, CoinbaseError> {
+        let body = serde_json::json!({ "order_ids": order_ids });
+        let response: CancelResponse = self
+            .timed(
+                Op::Cancel,
+                self.send(Method::POST, "/orders/batch_cancel", &[], Some(body)),
+            )
+            .await?;
+        Ok(response
+            .results
+            .into_iter()
+            .map(|r| {
+                let result = if r.success {
+                    Ok(())
+                } else {
+                    Err(r.failure_reason)
+                };
+                (r.order_id, result)
+            })
+            .collect())
+    }
+
+    /// Cancel an open order by id
+    pub async fn cancel(&self, order_id: &str) -> Result<(), CoinbaseError> {
+        let results = self.cancel_batch(&[order_id.to_string()]).await?;
+        match results.into_iter().next() {
+            Some((_, Ok(()))) => {
+                info!("[CANCEL] id={}", order_id);
+                Ok(())
+            }
+            Some((_, Err(reason))) => Err(CoinbaseError::Rejected {
+                reason,
+                message: format!("{} not cancelled", order_id),
+            }),
+            None => Err(CoinbaseError::Decode("cancel without result".to_string())),
+        }
+    }
+
+    /// Cancel every open order, of `product_id` or of every product if
+    /// None; returns the number cancelled
+    pub async fn cancel_all(&self, product_id: Option<&str>) -> Result<u32, CoinbaseError> {
+        let ids: Vec<String> = self
+            .open_orders(product_id)
+            .await?
+            .into_iter()
+            .map(|order| order.order_id)
+            .collect();
+        let mut cancelled = 0;
+        for chunk in ids.chunks(CANCEL_BATCH) {
+            let results = self.cancel_batch(chunk).await?;
+            cancelled += results.iter().filter(|(_, r)| r.is_ok()).count() as u32;
+        }
+        info!(
+            "[CANCEL_ALL] product={} | cancelled={} of {}",
+            product_id.unwrap_or("*"),
+            cancelled,
+            ids.len()
+        );
+        Ok(cancelled)
+    }
+
+    /// Orders open on the account, of `product_id` or of every product if
+    /// None
+    pub async fn open_orders(
+        &self,
+        product_id: Option<&str>,
+    ) -> Result<Vec<OpenOrder>, CoinbaseError> {
+        let mut orders = Vec::new();
+        let mut cursor = String::new();
+        loop {
+            let mut query = vec![
+                ("order_status", "OPEN".to_string()),
+                ("limit", PAGE_LIMIT.to_string()),
+            ];
+            if let Some(product_id) = product_id {
+                query.push(("product_ids", product_id.to_string()));
+            }
+            if !cursor.is_empty() {
+                query.push(("cursor", cursor.clone()));
+            }
+            let page: OrderList = self
+                .send(Method::GET, "/orders/historical/batch", &query, None)
+                .await?;
+            orders.extend(page.orders.into_iter().map(|order| OpenOrder {
+                price: order.configured("limit_price"),
+                size: order.configured("base_size"),
+                order_id: order.order_id,
+                client_order_id: order.client_order_id,
+                product_id: order.product_id,
+                side: order.side,
+                order_type: order.order_type,
+                filled_size: order.filled_size,
+                status: order.status,
+                created_time: order.created_time,
+            }));
+            if !page.has_next || page.cursor.is_empty() {
+                return Ok(orders);
+            }
+            cursor = page.cursor;
+        }
+    }
+}
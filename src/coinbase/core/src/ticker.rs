@@ -0,0 +1,227 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Best bid and ask kept from the market data ticker channel
+//!
+//! `TickerFeed` holds the subscribed products and the last ticker of each.
+//! `run` keeps a connection open until it fails; the caller reconnects, and
+//! the new connection subscribes every product again. Products added or
+//! removed while connected are sent on the live connection. The channel is
+//! public, so no token is sent. The connection also subscribes to the
+//! heartbeats channel, without which the venue closes a quiet connection.
+//! Until its first ticker a product has none.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::decimal;
+
+/// Heartbeats come every second; a connection with no message for this
+/// long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(30);
+
+/// How often the silence limit is checked
+const SILENCE_CHECK: Duration = Duration::from_secs(10);
+
+/// Heartbeats keep the connection alive, nothing to ping
+const KEEPALIVE: Keepalive = Keepalive {
+    interval: SILENCE_CHECK,
+    ping: None,
+    silence_limit: SILENCE_LIMIT,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ticker {
+    /// Last trade price
+    pub price: f64,
+    pub bid_price: f64,
+    pub bid_qty: f64,
+    pub ask_price: f64,
+    pub ask_qty: f64,
+    /// Sequence number of the message on its connection
+    pub sequence: u64,
+    pub received_at: Instant,
+}
+
+impl Ticker {
+    /// Since the ticker was received
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    channel: String,
+    #[serde(default, rename = "type")]
+    kind: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    sequence_num: u64,
+    #[serde(default)]
+    events: Vec<Event>,
+}
+
+#[derive(Deserialize)]
+struct Event {
+    #[serde(default)]
+    tickers: Vec<RawTicker>,
+}
+
+#[derive(Deserialize)]
+struct RawTicker {
+    product_id: String,
+    #[serde(default, deserialize_with = "decimal")]
+    price: f64,
+    #[serde(default, deserialize_with = "decimal")]
+    best_bid: f64,
+    #[serde(default, deserialize_with = "decimal")]
+    best_bid_quantity: f64,
+    #[serde(default, deserialize_with = "decimal")]
+    best_ask: f64,
+    #[serde(default, deserialize_with = "decimal")]
+    best_ask_quantity: f64,
+}
+
+#[derive(Default)]
+pub struct TickerFeed {
+    products: Mutex<BTreeSet<String>>,
+    tickers: RwLock<HashMap<String, Ticker>>,
+    /// Subscription messages for the live connection, if any
+    commands: Commands,
+}
+
+impl TickerFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a product ("BTC-USD"); false if it was already subscribed
+    pub fn subscribe(&self, product_id: &str) -> bool {
+        let added = self.lock_products().insert(product_id.to_string());
+        if added {
+            self.commands
+                .send(subscription(&[product_id.to_string()], "subscribe"));
+        }
+        added
+    }
+
+    /// Remove a product and drop its ticker; false if it was not subscribed
+    pub fn unsubscribe(&self, product_id: &str) -> bool {
+        let removed = self.lock_products().remove(product_id);
+        if removed {
+            if let Ok(mut tickers) = self.tickers.write() {
+                tickers.remove(product_id);
+            }
+            self.commands
+                .send(subscription(&[product_id.to_string()], "unsubscribe"));
+        }
+        removed
+    }
+
+    pub fn is_subscribed(&self, product_id: &str) -> bool {
+        self.lock_products().contains(product_id)
+    }
+
+    pub fn products(&self) -> Vec<String> {
+        self.lock_products().iter().cloned().collect()
+    }
+
+    /// Ticker of a product; None until the venue sent it
+    pub fn ticker(&self, product_id: &str) -> Option<Ticker> {
+        self.tickers.read().ok()?.get(product_id).copied()
+    }
+
+    fn lock_products(&self) -> std::sync::MutexGuard<'_, BTreeSet<String>> {
+        self.products.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Apply one text message; returns the products whose ticker changed
+    pub fn apply(&self, text: &str) -> Vec<String> {
+        let envelope: Envelope = match serde_json::from_str(text) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                debug!("[TICKER FEED] unreadable message | {}", e);
+                return Vec::new();
+            }
+        };
+        if envelope.kind == "error" {
+            // An unknown product, for one; the others keep streaming
+            warn!("[TICKER FEED] venue error | {}", envelope.message);
+            return Vec::new();
+        }
+        if envelope.channel != "ticker" {
+            return Vec::new();
+        }
+        let mut tickers = self.tickers.write().unwrap_or_else(|e| e.into_inner());
+        let mut changed = Vec::new();
+        for raw in envelope.events.into_iter().flat_map(|e| e.tickers) {
+            // A message in flight when the product was removed
+            if !self.is_subscribed(&raw.product_id) {
+                continue;
+            }
+            let ticker = Ticker {
+                price: raw.price,
+                bid_price: raw.best_bid,
+                bid_qty: raw.best_bid_quantity,
+                ask_price: raw.best_ask,
+                ask_qty: raw.best_ask_quantity,
+                sequence: envelope.sequence_num,
+                received_at: Instant::now(),
+            };
+            tickers.insert(raw.product_id.clone(), ticker);
+            changed.push(raw.product_id);
+        }
+        changed
+    }
+
+    /// Connect to `url` and keep every subscribed ticker until the
+    /// connection fails; `on_update` gets each product whose ticker changed
+    pub async fn run(&self, url: &str, mut on_update: impl FnMut(&str)) -> Result<(), String> {
+        let connect = || async {
+            let (ws, _) = tokio_tungstenite::connect_async(url)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(ws)
+        };
+        let subscriptions = || {
+            let heartbeats = serde_json::json!({ "type": "subscribe", "channel": "heartbeats" });
+            let mut messages = vec![heartbeats.to_string()];
+            let products = self.products();
+            if !products.is_empty() {
+                messages.push(subscription(&products, "subscribe"));
+            }
+            messages
+        };
+        feed::run_ws(&self.commands, connect, subscriptions, KEEPALIVE, |text| {
+            for product_id in self.apply(text) {
+                on_update(&product_id);
+            }
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// (Un)subscription message of `products`' tickers
+fn subscription(products: &[String], kind: &str) -> String {
+    serde_json::json!({
+        "type": kind,
+        "product_ids": products,
+        "channel": "ticker",
+    })
+    .to_string()
+}
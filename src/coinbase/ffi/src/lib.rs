@@ -0,0 +1,588 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Coinbase Advanced Trade executor - C FFI
+//!
+//! The C ABI of coinbase_ffi.h, laid out like the Polymarket executor's:
+//! one global `CoinbaseConnector` set up by coinbase_init(), blocking calls
+//! that run on the shared connector runtime, negative error codes (the same
+//! numbers as POLYMARKET_ERR_* where the meaning is shared), raw amounts
+//! with 6 decimals, thread-local last error and last response buffers, and
+//! the call metrics of polymarket_get_metrics() in the same JSON and
+//! Prometheus shapes. Tickers are kept by a background task of the
+//! connector, as polymarket_subscribe_book() does.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Instant;
+
+use coinbase_executor_core::{
+    valid_product, CoinbaseConnector, CoinbaseError, OrderKind, OrderOutcome, OrderRequest, Side,
+    TimeInForce, Trader,
+};
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, Connector, ConnectorConfig, ConnectorError};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+// Error codes
+pub const COINBASE_OK: i32 = 0;
+/// Not an error: no data yet
+pub const COINBASE_PENDING: i32 = 1;
+pub const COINBASE_ERR_NOT_INITIALIZED: i32 = -1;
+/// Key name or private key malformed
+pub const COINBASE_ERR_INVALID_KEY: i32 = -2;
+/// Key refused, or lacks the permission
+pub const COINBASE_ERR_AUTH: i32 = -3;
+/// Not a trading product
+pub const COINBASE_ERR_INVALID_PRODUCT: i32 = -4;
+pub const COINBASE_ERR_ORDER_FAILED: i32 = -5;
+pub const COINBASE_ERR_CANCEL_FAILED: i32 = -6;
+/// Not enough funds
+pub const COINBASE_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+pub const COINBASE_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const COINBASE_ERR_PANIC: i32 = -14;
+/// Venue refused the request, see last error
+pub const COINBASE_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const COINBASE_ERR_NETWORK: i32 = -20;
+/// Rate limit exceeded
+pub const COINBASE_ERR_RATE_LIMITED: i32 = -21;
+/// Venue unavailable or busy, order state unknown
+pub const COINBASE_ERR_VENUE_ERROR: i32 = -22;
+/// Size below the minimum, or price not valid
+pub const COINBASE_ERR_INVALID_ORDER: i32 = -23;
+
+// Order flags
+/// Cancelled instead of taking
+pub const COINBASE_FLAG_POST_ONLY: u32 = 1;
+/// What does not fill at once is cancelled
+pub const COINBASE_FLAG_IOC: u32 = 2;
+/// Fills entirely at once, or is cancelled
+pub const COINBASE_FLAG_FOK: u32 = 4;
+
+/// Raw units per asset unit
+const RAW_SCALE: f64 = 1_000_000.0;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct CoinbaseOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Venue order id (UUID)
+    pub order_id: [c_char; 40],
+    /// Our id of the order
+    pub client_order_id: [c_char; 40],
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl CoinbaseOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            order_id: [0; 40],
+            client_order_id: [0; 40],
+            latency_ms: 0,
+        }
+    }
+
+    fn placed(outcome: &OrderOutcome, latency_ms: u64) -> Self {
+        let mut result = Self::failed(COINBASE_OK);
+        result.success = true;
+        out_buf::write_fixed(&outcome.order_id, &mut result.order_id);
+        out_buf::write_fixed(&outcome.client_order_id, &mut result.client_order_id);
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+/// Best bid and ask of a subscribed product, filled by
+/// coinbase_get_ticker(); raw values in 6 decimals (divide by 1M for
+/// actual)
+#[repr(C)]
+#[derive(Default)]
+pub struct CoinbaseTicker {
+    /// Last trade price
+    pub price_raw: i64,
+    pub bid_price_raw: i64,
+    pub bid_qty_raw: i64,
+    pub ask_price_raw: i64,
+    pub ask_qty_raw: i64,
+    /// Sequence number of the message on its connection
+    pub sequence: u64,
+    /// Since the ticker was received
+    pub age_ms: u64,
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "coinbase";
+
+fn get_executor() -> Option<Arc<CoinbaseConnector>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("coinbase_executor=info"))
+        .try_init();
+}
+
+fn to_raw(x: f64) -> i64 {
+    (x * RAW_SCALE).round() as i64
+}
+
+/// Product argument as the venue lists it ("BTC-USD")
+fn product_arg<'a>(product_id: *const c_char) -> Option<&'a str> {
+    str_arg(product_id).filter(|p| valid_product(p))
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect with a CDP API key name ("organizations/.../apiKeys/...") and
+/// its EC private key (PEM), checked by reading the balances
+/// Replaces a session already set up. Returns 0 on success, negative error
+/// code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_init(key_name: *const c_char, private_key: *const c_char) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        init_logging();
+        let (Some(key_name), Some(private_key)) = (str_arg(key_name), str_arg(private_key)) else {
+            return COINBASE_ERR_INVALID_KEY;
+        };
+        let config = ConnectorConfig {
+            api_key: Some(key_name.to_string()),
+            secret: Some(Zeroizing::new(private_key.to_string())),
+            ..Default::default()
+        };
+
+        let connector = match runtime::block_on(CoinbaseConnector::connect(config)) {
+            Ok(Ok(connector)) => connector,
+            Ok(Err(e)) => return fail("INIT", &e, COINBASE_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return COINBASE_ERR_NOT_INITIALIZED;
+            }
+        };
+        let executor = Arc::new(connector);
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] coinbase executor ready");
+        COINBASE_OK
+    })
+}
+
+/// Shut the session down and close the ticker feed; calls still running
+/// finish first. coinbase_init() can be called again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<CoinbaseConnector>>(VENUE);
+        if executor.is_some() {
+            info!("[SHUTDOWN] coinbase executor stopped");
+        }
+    })
+}
+
+/// Place an order and fill `out`
+fn place(
+    op: &'static str,
+    product_id: *const c_char,
+    request: OrderRequest,
+    out: *mut CoinbaseOrderResult,
+) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return COINBASE_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = CoinbaseOrderResult::failed(COINBASE_ERR_NOT_INITIALIZED);
+            return COINBASE_ERR_NOT_INITIALIZED;
+        }
+    };
+    let Some(product_id) = product_arg(product_id) else {
+        *out = CoinbaseOrderResult::failed(COINBASE_ERR_INVALID_PRODUCT);
+        return COINBASE_ERR_INVALID_PRODUCT;
+    };
+
+    let started = Instant::now();
+    let product_owned = product_id.to_string();
+    let result = runtime::block_on(async move {
+        let place = executor.trader().place(&product_owned, request);
+        executor.gate().run(Call::OrderPost, place).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(outcome)) => CoinbaseOrderResult::placed(&outcome, latency_ms),
+        Ok(Err(e)) => CoinbaseOrderResult::failed(fail(op, &e, COINBASE_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | product={} | {}", op, product_id, e);
+            out_buf::set_last_error(&e);
+            CoinbaseOrderResult::failed(COINBASE_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+fn side(is_buy: bool) -> Side {
+    if is_buy {
+        Side::Buy
+    } else {
+        Side::Sell
+    }
+}
+
+/// Market order of `size` base asset units, rounded down to the product's
+/// base increment
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_market_order(
+    product_id: *const c_char,
+    is_buy: bool,
+    size: f64,
+    out: *mut CoinbaseOrderResult,
+) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let request = OrderRequest {
+            side: side(is_buy),
+            size,
+            kind: OrderKind::Market,
+        };
+        place("MARKET", product_id, request, out)
+    })
+}
+
+/// Limit order of `size` base asset units at `price`, rounded to the
+/// nearest price increment
+/// `flags`: one of COINBASE_FLAG_POST_ONLY, COINBASE_FLAG_IOC and
+/// COINBASE_FLAG_FOK, or 0 for good till cancelled
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_limit_order(
+    product_id: *const c_char,
+    is_buy: bool,
+    size: f64,
+    price: f64,
+    flags: u32,
+    out: *mut CoinbaseOrderResult,
+) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let (tif, post_only) = match flags {
+            0 => (TimeInForce::Gtc, false),
+            COINBASE_FLAG_POST_ONLY => (TimeInForce::Gtc, true),
+            COINBASE_FLAG_IOC => (TimeInForce::Ioc, false),
+            COINBASE_FLAG_FOK => (TimeInForce::Fok, false),
+            _ => return COINBASE_ERR_INVALID_ARGUMENT,
+        };
+        let request = OrderRequest {
+            side: side(is_buy),
+            size,
+            kind: OrderKind::Limit { price, tif, post_only },
+        };
+        place("LIMIT", product_id, request, out)
+    })
+}
+
+/// Cancel an open order by its venue order id
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_cancel(order_id: *const c_char) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED,
+        };
+        let Some(order_id) = str_arg(order_id).filter(|id| {
+            (1..=64).contains(&id.len())
+                && id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        }) else {
+            return COINBASE_ERR_INVALID_ARGUMENT;
+        };
+
+        let id_owned = order_id.to_string();
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Cancel, executor.trader().cancel(&id_owned)).await
+        });
+        match result {
+            Ok(Ok(())) => COINBASE_OK,
+            Ok(Err(e)) => fail("CANCEL", &e, COINBASE_ERR_CANCEL_FAILED),
+            Err(e) => {
+                error!("[CANCEL] failed | id={} | {}", order_id, e);
+                out_buf::set_last_error(&e);
+                COINBASE_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cancel every open order of `product_id`, or of every product if NULL
+/// Returns the number cancelled (0 if none was open), or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_cancel_all(product_id: *const c_char) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED,
+        };
+        let product_id = match product_id.is_null() {
+            true => None,
+            false => match product_arg(product_id) {
+                Some(product_id) => Some(product_id.to_string()),
+                None => return COINBASE_ERR_INVALID_PRODUCT,
+            },
+        };
+
+        let result = runtime::block_on(async move {
+            let cancel = executor.trader().cancel_all(product_id.as_deref());
+            executor.gate().run(Call::Cancel, cancel).await
+        });
+        match result {
+            Ok(Ok(count)) => count.min(i32::MAX as u32) as i32,
+            Ok(Err(e)) => fail("CANCEL_ALL", &e, COINBASE_ERR_CANCEL_FAILED),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                COINBASE_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Amount of `asset` ("USD", "BTC") available to trade (raw, 6 decimals),
+/// 0 if none held, or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_get_balance(asset: *const c_char) -> i64 {
+    guard::catch(COINBASE_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED as i64,
+        };
+        let Some(asset) = str_arg(asset)
+            .filter(|a| (1..=16).contains(&a.len()) && a.bytes().all(|b| b.is_ascii_alphanumeric()))
+        else {
+            return COINBASE_ERR_INVALID_ARGUMENT as i64;
+        };
+
+        let asset = asset.to_string();
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Balance, executor.trader().free_balance(&asset)).await
+        });
+        match result {
+            Ok(Ok(free)) => to_raw(free),
+            Ok(Err(e)) => fail("BALANCE", &e, COINBASE_ERR_VENUE_ERROR) as i64,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                COINBASE_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// Run a query and keep its JSON for coinbase_last_response()
+fn query_json<Fut, T>(op: &str, query: impl FnOnce(Arc<Trader>) -> Fut) -> i64
+where
+    Fut: Future<Output = Result<T, CoinbaseError>> + Send + 'static,
+    T: serde::Serialize + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return COINBASE_ERR_NOT_INITIALIZED as i64,
+    };
+    let query = query(executor.trader().clone());
+    match runtime::block_on(async move { query.await.map_err(ConnectorError::from) }) {
+        Ok(Ok(value)) => match serde_json::to_string(&value) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => COINBASE_ERR_VENUE_ERROR as i64,
+        },
+        Ok(Err(e)) => fail(op, &e, COINBASE_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            COINBASE_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// Non-zero balances as JSON [{"asset", "free", "total"}]; returns its size
+/// (coinbase_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_get_balances() -> i64 {
+    guard::catch(COINBASE_ERR_PANIC as i64, || {
+        query_json("BALANCES", |trader| async move { trader.balances().await })
+    })
+}
+
+/// Open orders as JSON [{"order_id", "client_order_id", "product_id", "side",
+/// "order_type", "price", "size", "filled_size", "status", "created_time"}],
+/// of `product_id` or of every product if NULL; returns its size or a
+/// negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_get_open_orders(product_id: *const c_char) -> i64 {
+    guard::catch(COINBASE_ERR_PANIC as i64, || {
+        let product_id = match product_id.is_null() {
+            true => None,
+            false => match product_arg(product_id) {
+                Some(product_id) => Some(product_id.to_string()),
+                None => return COINBASE_ERR_INVALID_PRODUCT as i64,
+            },
+        };
+        query_json("OPEN_ORDERS", |trader| async move {
+            trader.open_orders(product_id.as_deref()).await
+        })
+    })
+}
+
+/// Keep the best bid and ask of a product from the ticker channel
+/// The first subscription opens the connection; it reconnects on its own
+/// and closes on shutdown. Returns COINBASE_OK, also if already subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_subscribe_ticker(product_id: *const c_char) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED,
+        };
+        let Some(product_id) = product_arg(product_id) else {
+            return COINBASE_ERR_INVALID_PRODUCT;
+        };
+
+        let product_owned = product_id.to_string();
+        match runtime::block_on(async move { executor.subscribe(&product_owned).await }) {
+            Ok(Ok(())) => COINBASE_OK,
+            Ok(Err(e)) => fail("SUBSCRIBE", &e, COINBASE_ERR_INVALID_ARGUMENT),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                COINBASE_ERR_NOT_INITIALIZED
+            }
+        }
+    })
+}
+
+/// Stop keeping a product's ticker; the connection stays open
+/// Returns COINBASE_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_unsubscribe_ticker(product_id: *const c_char) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED,
+        };
+        let Some(product_id) = product_arg(product_id) else {
+            return COINBASE_ERR_INVALID_PRODUCT;
+        };
+
+        match executor.unsubscribe(product_id) {
+            Ok(()) => COINBASE_OK,
+            Err(e) => e.code(COINBASE_ERR_INVALID_ARGUMENT),
+        }
+    })
+}
+
+/// Best bid and ask of a subscribed product
+/// Returns COINBASE_PENDING until the venue sent its ticker (`out`
+/// untouched), COINBASE_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_get_ticker(product_id: *const c_char, out: *mut CoinbaseTicker) -> i32 {
+    guard::catch(COINBASE_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED,
+        };
+        let Some(product_id) = product_arg(product_id) else {
+            return COINBASE_ERR_INVALID_PRODUCT;
+        };
+        let Some(out) = args::out_arg(out) else {
+            return COINBASE_ERR_INVALID_ARGUMENT;
+        };
+        let tickers = executor.tickers();
+        let ticker = match tickers.ticker(product_id) {
+            Some(ticker) => ticker,
+            None if tickers.is_subscribed(product_id) => return COINBASE_PENDING,
+            None => return COINBASE_ERR_INVALID_ARGUMENT,
+        };
+
+        *out = CoinbaseTicker {
+            price_raw: to_raw(ticker.price),
+            bid_price_raw: to_raw(ticker.bid_price),
+            bid_qty_raw: to_raw(ticker.bid_qty),
+            ask_price_raw: to_raw(ticker.ask_price),
+            ask_qty_raw: to_raw(ticker.ask_qty),
+            sequence: ticker.sequence,
+            age_ms: ticker.age().as_millis() as u64,
+        };
+        COINBASE_OK
+    })
+}
+
+/// Latency and error counts of order posts, cancels and balance reads since
+/// the session started, copied into `buf` as JSON:
+/// [{op, count, errors, mean_ms, p50_ms, p90_ms, p99_ms, max_ms}]
+/// Returns the required buffer size (including NUL), negative error code on
+/// failure; written only if `len` is large enough, pass NULL/0 to query
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_get_metrics(buf: *mut c_char, len: usize) -> i64 {
+    guard::catch(COINBASE_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED as i64,
+        };
+
+        match serde_json::to_string(&executor.trader().metrics()) {
+            Ok(json) => args::write_str(&json, buf, len) as i64,
+            Err(e) => {
+                error!("[METRICS ERROR] {}", e);
+                COINBASE_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// The counts of coinbase_get_metrics() in the Prometheus text format, to
+/// serve from a scrape endpoint of the host. Same buffer convention
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_get_metrics_prometheus(buf: *mut c_char, len: usize) -> i64 {
+    guard::catch(COINBASE_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return COINBASE_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let text = executor.trader().metrics_prometheus();
+        args::write_str(&text, buf, len) as i64
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn coinbase_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
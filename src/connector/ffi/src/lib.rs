@@ -0,0 +1,728 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Venue-neutral executor - C FFI
+//!
+//! The C ABI of flox_ffi.h: every venue connector of the library behind
+//! one set of entry points, picked by venue id ("kraken", "binance"...).
+//! The host discovers the venues and what each can do at run time
+//! (flox_list_venues(), flox_get_capabilities()), so a library built with
+//! more venues serves them without the host being rebuilt.
+//!
+//! Otherwise laid out like the venue libraries: one global session per
+//! venue, blocking calls that run on the shared connector runtime, the
+//! error codes of `flox_connector::codes`, thread-local last error and last
+//! response buffers. Amounts are doubles in human units going in; top of
+//! book comes back raw with 6 decimals, as the venue libraries return it.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Instant;
+
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{
+    codes, runtime, BoxFuture, Capabilities, ConnectorConfig, ConnectorError, OrderExecutor,
+    OrderSpec, OrderType, RateLimit, Side, TimeInForce,
+};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+#[cfg(not(any(
+    feature = "kalshi",
+    feature = "drift",
+    feature = "hyperliquid",
+    feature = "binance",
+    feature = "kraken",
+    feature = "coinbase",
+    feature = "ibkr",
+    feature = "polymarket"
+)))]
+compile_error!("build with at least one venue feature");
+
+/// Version of the ABI, see flox_abi_version(); bumped when a struct or
+/// signature changes
+pub const FLOX_ABI_VERSION: u32 = 1;
+
+// Error codes, the numbers of `flox_connector::codes` (checked below)
+pub const FLOX_OK: i32 = 0;
+/// Not an error: no data yet
+pub const FLOX_PENDING: i32 = 1;
+/// No session with the venue
+pub const FLOX_ERR_NOT_INITIALIZED: i32 = -1;
+/// Key missing or malformed
+pub const FLOX_ERR_INVALID_KEY: i32 = -2;
+/// Key refused, or the session was lost
+pub const FLOX_ERR_AUTH: i32 = -3;
+pub const FLOX_ERR_INVALID_INSTRUMENT: i32 = -4;
+pub const FLOX_ERR_ORDER_FAILED: i32 = -5;
+pub const FLOX_ERR_CANCEL_FAILED: i32 = -6;
+pub const FLOX_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+/// Endpoint, account or rate limit not valid
+pub const FLOX_ERR_INVALID_CONFIG: i32 = -10;
+pub const FLOX_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const FLOX_ERR_PANIC: i32 = -14;
+/// Venue refused the request, see last error
+pub const FLOX_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const FLOX_ERR_NETWORK: i32 = -20;
+/// Venue or local rate limit exceeded
+pub const FLOX_ERR_RATE_LIMITED: i32 = -21;
+/// Venue answered with a fault, state unknown
+pub const FLOX_ERR_VENUE_ERROR: i32 = -22;
+/// Quantity or price not valid
+pub const FLOX_ERR_INVALID_ORDER: i32 = -23;
+/// Venue cannot do it, or is not in the library
+pub const FLOX_ERR_UNSUPPORTED: i32 = -29;
+
+// Literals so the generated header carries the numbers
+const _: () = assert!(
+    FLOX_OK == codes::OK
+        && FLOX_PENDING == codes::PENDING
+        && FLOX_ERR_NOT_INITIALIZED == codes::NOT_INITIALIZED
+        && FLOX_ERR_INVALID_KEY == codes::INVALID_KEY
+        && FLOX_ERR_AUTH == codes::AUTH
+        && FLOX_ERR_INVALID_INSTRUMENT == codes::INVALID_INSTRUMENT
+        && FLOX_ERR_ORDER_FAILED == codes::ORDER_FAILED
+        && FLOX_ERR_CANCEL_FAILED == codes::CANCEL_FAILED
+        && FLOX_ERR_INSUFFICIENT_BALANCE == codes::INSUFFICIENT_BALANCE
+        && FLOX_ERR_INVALID_CONFIG == codes::INVALID_CONFIG
+        && FLOX_ERR_INVALID_ARGUMENT == codes::INVALID_ARGUMENT
+        && FLOX_ERR_PANIC == codes::PANIC
+        && FLOX_ERR_REJECTED == codes::REJECTED
+        && FLOX_ERR_NETWORK == codes::NETWORK
+        && FLOX_ERR_RATE_LIMITED == codes::RATE_LIMITED
+        && FLOX_ERR_VENUE_ERROR == codes::VENUE_ERROR
+        && FLOX_ERR_INVALID_ORDER == codes::INVALID_ORDER
+        && FLOX_ERR_UNSUPPORTED == codes::UNSUPPORTED
+);
+
+// Capabilities, flox_get_capabilities()
+pub const FLOX_CAP_MARKET: u32 = 1 << 0;
+/// Good till cancelled
+pub const FLOX_CAP_LIMIT: u32 = 1 << 1;
+/// Limit orders of these times in force
+pub const FLOX_CAP_IOC: u32 = 1 << 2;
+pub const FLOX_CAP_FOK: u32 = 1 << 3;
+pub const FLOX_CAP_DAY: u32 = 1 << 4;
+pub const FLOX_CAP_POST_ONLY: u32 = 1 << 5;
+pub const FLOX_CAP_REDUCE_ONLY: u32 = 1 << 6;
+pub const FLOX_CAP_CANCEL_ALL: u32 = 1 << 7;
+pub const FLOX_CAP_BALANCES: u32 = 1 << 8;
+/// flox_subscribe(), flox_get_top_of_book()
+pub const FLOX_CAP_TOP_OF_BOOK: u32 = 1 << 9;
+/// FloxConfig.testnet picks a testnet or sandbox
+pub const FLOX_CAP_TESTNET: u32 = 1 << 10;
+
+// FloxOrderSpec.order_type
+pub const FLOX_ORDER_MARKET: u32 = 0;
+pub const FLOX_ORDER_LIMIT: u32 = 1;
+
+// FloxOrderSpec.tif
+pub const FLOX_TIF_GTC: u32 = 0;
+/// What does not fill at once is cancelled
+pub const FLOX_TIF_IOC: u32 = 1;
+/// Fills in full at once or is cancelled
+pub const FLOX_TIF_FOK: u32 = 2;
+/// Cancelled at the end of the trading day
+pub const FLOX_TIF_DAY: u32 = 3;
+
+// FloxOrderSpec.flags
+/// Cancelled instead of taking
+pub const FLOX_FLAG_POST_ONLY: u32 = 1;
+/// Only reduces the position held
+pub const FLOX_FLAG_REDUCE_ONLY: u32 = 2;
+
+/// Raw units per unit
+const RAW_SCALE: f64 = 1_000_000.0;
+
+/// Session settings, read by flox_init(); NULL strings are not set. See
+/// flox_ffi.h for the fields each venue reads
+#[repr(C)]
+pub struct FloxConfig {
+    pub api_key: *const c_char,
+    /// The library keeps no copy of the string
+    pub secret: *const c_char,
+    pub endpoint: *const c_char,
+    pub account: *const c_char,
+    pub testnet: bool,
+    /// Local limit of the venue calls, 0 for none
+    pub rate_per_sec: f64,
+    /// Calls back to back, >= 1 with a limit
+    pub rate_burst: u32,
+    /// Wait for budget instead of failing at once
+    pub rate_queue: bool,
+}
+
+/// Order of any venue; what the venue cannot express is refused with
+/// FLOX_ERR_UNSUPPORTED before anything is sent
+#[repr(C)]
+pub struct FloxOrderSpec {
+    pub instrument: *const c_char,
+    pub is_buy: bool,
+    /// Shares, contracts or base asset units, > 0
+    pub quantity: f64,
+    /// FLOX_ORDER_*
+    pub order_type: u32,
+    /// Limit orders only
+    pub price: f64,
+    /// FLOX_TIF_*, limit orders only
+    pub tif: u32,
+    /// FLOX_FLAG_*
+    pub flags: u32,
+}
+
+/// Order result, filled by flox_place_order()
+#[repr(C)]
+pub struct FloxOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Id to cancel the order with
+    pub order_id: [c_char; 64],
+    /// Empty where the venue has none
+    pub client_order_id: [c_char; 64],
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl FloxOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            order_id: [0; 64],
+            client_order_id: [0; 64],
+            latency_ms: 0,
+        }
+    }
+}
+
+/// Best bid and ask of a subscribed instrument, in 6 decimals (divide by
+/// 1M for actual)
+#[repr(C)]
+#[derive(Default)]
+pub struct FloxTopOfBook {
+    pub bid_raw: i64,
+    pub bid_size_raw: i64,
+    pub ask_raw: i64,
+    pub ask_size_raw: i64,
+    /// Since the venue's last update was received
+    pub age_ms: u64,
+}
+
+type Session = Arc<dyn OrderExecutor>;
+
+/// Venue the library was built with
+struct Venue {
+    id: &'static str,
+    capabilities: Capabilities,
+    connect: fn(ConnectorConfig) -> BoxFuture<'static, Result<Session, ConnectorError>>,
+}
+
+fn connect<C: OrderExecutor + 'static>(
+    config: ConnectorConfig,
+) -> BoxFuture<'static, Result<Session, ConnectorError>> {
+    Box::pin(async move { Ok(Arc::new(C::connect(config).await?) as Session) })
+}
+
+macro_rules! venue {
+    ($id:literal, $connector:ty) => {
+        Venue {
+            id: $id,
+            capabilities: <$connector>::CAPABILITIES,
+            connect: connect::<$connector>,
+        }
+    };
+}
+
+static VENUES: &[Venue] = &[
+    #[cfg(feature = "kalshi")]
+    venue!("kalshi", kalshi_executor_core::KalshiConnector),
+    #[cfg(feature = "drift")]
+    venue!("drift", drift_executor_core::DriftConnector),
+    #[cfg(feature = "hyperliquid")]
+    venue!("hyperliquid", hyperliquid_executor_core::HyperliquidConnector),
+    #[cfg(feature = "binance")]
+    venue!("binance", binance_executor_core::BinanceConnector),
+    #[cfg(feature = "kraken")]
+    venue!("kraken", kraken_executor_core::KrakenConnector),
+    #[cfg(feature = "coinbase")]
+    venue!("coinbase", coinbase_executor_core::CoinbaseConnector),
+    #[cfg(feature = "ibkr")]
+    venue!("ibkr", ibkr_executor_core::IbkrConnector),
+    #[cfg(feature = "polymarket")]
+    venue!("polymarket", polymarket_executor_core::PolymarketConnector),
+];
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let mut filter = "flox_executor=info,flox_connector=info".to_string();
+    for venue in VENUES {
+        filter.push_str(&format!(",{}_executor_core=info", venue.id));
+    }
+    let _ = tracing_subscriber::fmt().with_env_filter(EnvFilter::new(filter)).try_init();
+}
+
+fn to_raw(x: f64) -> i64 {
+    (x * RAW_SCALE).round() as i64
+}
+
+/// Venue of an id; Err(FLOX_ERR_UNSUPPORTED) if the library was built
+/// without it, with the reason in the last error
+fn venue_arg(venue: *const c_char) -> Result<&'static Venue, i32> {
+    let Some(id) = str_arg(venue) else {
+        return Err(FLOX_ERR_INVALID_ARGUMENT);
+    };
+    VENUES.iter().find(|v| v.id == id).ok_or_else(|| {
+        out_buf::set_last_error(&format!("venue not in this library: {}", id));
+        FLOX_ERR_UNSUPPORTED
+    })
+}
+
+/// Session of a venue id
+fn session_arg(venue: *const c_char) -> Result<Session, i32> {
+    let venue = venue_arg(venue)?;
+    session::get(venue.id).ok_or(FLOX_ERR_NOT_INITIALIZED)
+}
+
+/// Instrument argument; the venue checks the name itself
+fn instrument_arg<'a>(instrument: *const c_char) -> Option<&'a str> {
+    str_arg(instrument).filter(|i| !i.is_empty())
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Run a call on the shared runtime and map its error
+fn run<T>(
+    op: &str,
+    call: impl Future<Output = Result<T, ConnectorError>> + Send + 'static,
+    fallback: i32,
+) -> Result<T, i32>
+where
+    T: Send + 'static,
+{
+    match runtime::block_on(call) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(fail(op, &e, fallback)),
+        Err(e) => {
+            error!("[{}] failed | {}", op, e);
+            out_buf::set_last_error(&e);
+            Err(fallback)
+        }
+    }
+}
+
+fn capability_bits(caps: &Capabilities) -> u32 {
+    [
+        (caps.market, FLOX_CAP_MARKET),
+        (caps.limit, FLOX_CAP_LIMIT),
+        (caps.ioc, FLOX_CAP_IOC),
+        (caps.fok, FLOX_CAP_FOK),
+        (caps.day, FLOX_CAP_DAY),
+        (caps.post_only, FLOX_CAP_POST_ONLY),
+        (caps.reduce_only, FLOX_CAP_REDUCE_ONLY),
+        (caps.cancel_all, FLOX_CAP_CANCEL_ALL),
+        (caps.balances, FLOX_CAP_BALANCES),
+        (caps.top_of_book, FLOX_CAP_TOP_OF_BOOK),
+        (caps.testnet, FLOX_CAP_TESTNET),
+    ]
+    .into_iter()
+    .filter(|(has, _)| *has)
+    .fold(0, |bits, (_, bit)| bits | bit)
+}
+
+/// Version of the ABI the library implements (FLOX_ABI_VERSION)
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_abi_version() -> u32 {
+    FLOX_ABI_VERSION
+}
+
+/// Copy the ids of the venues the library was built with, comma separated
+/// ("kalshi,drift,..."). Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_list_venues(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        let ids: Vec<&str> = VENUES.iter().map(|v| v.id).collect();
+        args::write_str(&ids.join(","), buf, len)
+    })
+}
+
+/// FLOX_CAP_* bits of what a venue can do; no session needed
+/// Returns FLOX_ERR_UNSUPPORTED if the library was built without it
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_get_capabilities(venue: *const c_char) -> i64 {
+    guard::catch(FLOX_ERR_PANIC as i64, || match venue_arg(venue) {
+        Ok(venue) => capability_bits(&venue.capabilities) as i64,
+        Err(code) => code as i64,
+    })
+}
+
+/// Open a session with a venue, replacing one already set up
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_init(venue: *const c_char, config: *const FloxConfig) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        init_logging();
+        let venue = match venue_arg(venue) {
+            Ok(venue) => venue,
+            Err(code) => return code,
+        };
+        let Some(config) = args::ref_arg(config) else {
+            return FLOX_ERR_INVALID_ARGUMENT;
+        };
+        // NULL is not set; a string that is not UTF-8 is refused
+        let field = |s: *const c_char| match s.is_null() {
+            true => Ok(None),
+            false => str_arg(s).map(|s| Some(s.to_string())).ok_or(FLOX_ERR_INVALID_ARGUMENT),
+        };
+        let fields = (
+            field(config.api_key),
+            field(config.secret),
+            field(config.endpoint),
+            field(config.account),
+        );
+        let (Ok(api_key), Ok(secret), Ok(endpoint), Ok(account)) = fields else {
+            return FLOX_ERR_INVALID_ARGUMENT;
+        };
+        let rate_limit = RateLimit {
+            per_sec: config.rate_per_sec,
+            burst: config.rate_burst,
+            queue: config.rate_queue,
+        };
+        if let Err(e) = rate_limit.validate() {
+            out_buf::set_last_error(&e);
+            return FLOX_ERR_INVALID_CONFIG;
+        }
+        let config = ConnectorConfig {
+            api_key,
+            secret: secret.map(Zeroizing::new),
+            endpoint,
+            account,
+            testnet: config.testnet,
+            rate_limit,
+        };
+
+        let session = match runtime::block_on((venue.connect)(config)) {
+            Ok(Ok(session)) => session,
+            Ok(Err(e)) => return fail("INIT", &e, FLOX_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return FLOX_ERR_NOT_INITIALIZED;
+            }
+        };
+        let previous = session::install(venue.id, session);
+        drop(previous);
+        info!("[INIT] {} session ready", venue.id);
+        FLOX_OK
+    })
+}
+
+/// Close a venue's session and its feeds; calls still running finish
+/// first. No-op without a session
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_shutdown(venue: *const c_char) {
+    guard::catch((), || {
+        let Ok(venue) = venue_arg(venue) else {
+            return;
+        };
+        let session = session::take::<Session>(venue.id);
+        if session.is_some() {
+            info!("[SHUTDOWN] {} session closed", venue.id);
+        }
+    })
+}
+
+/// Order spec of a FloxOrderSpec; Err with the code to return
+fn order_spec(order: &FloxOrderSpec) -> Result<OrderSpec, i32> {
+    let Some(instrument) = instrument_arg(order.instrument) else {
+        return Err(FLOX_ERR_INVALID_INSTRUMENT);
+    };
+    if order.flags & !(FLOX_FLAG_POST_ONLY | FLOX_FLAG_REDUCE_ONLY) != 0 {
+        return Err(FLOX_ERR_INVALID_ARGUMENT);
+    }
+    if !(order.quantity.is_finite() && order.quantity > 0.0) {
+        return Err(FLOX_ERR_INVALID_ORDER);
+    }
+    let order_type = match order.order_type {
+        FLOX_ORDER_MARKET => OrderType::Market,
+        FLOX_ORDER_LIMIT if order.price.is_finite() && order.price > 0.0 => {
+            OrderType::Limit { price: order.price }
+        }
+        FLOX_ORDER_LIMIT => return Err(FLOX_ERR_INVALID_ORDER),
+        _ => return Err(FLOX_ERR_INVALID_ARGUMENT),
+    };
+    let tif = match order.tif {
+        FLOX_TIF_GTC => TimeInForce::Gtc,
+        FLOX_TIF_IOC => TimeInForce::Ioc,
+        FLOX_TIF_FOK => TimeInForce::Fok,
+        FLOX_TIF_DAY => TimeInForce::Day,
+        _ => return Err(FLOX_ERR_INVALID_ARGUMENT),
+    };
+    Ok(OrderSpec {
+        instrument: instrument.to_string(),
+        side: if order.is_buy { Side::Buy } else { Side::Sell },
+        quantity: order.quantity,
+        order_type,
+        tif,
+        post_only: order.flags & FLOX_FLAG_POST_ONLY != 0,
+        reduce_only: order.flags & FLOX_FLAG_REDUCE_ONLY != 0,
+    })
+}
+
+/// Check and place an order with a venue and fill `out`
+/// Returns 0 on success, negative error code on failure (also in `out`);
+/// FLOX_ERR_UNSUPPORTED if the venue cannot express the order
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_place_order(
+    venue: *const c_char,
+    order: *const FloxOrderSpec,
+    out: *mut FloxOrderResult,
+) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        let Some(out) = args::out_arg(out) else {
+            return FLOX_ERR_INVALID_ARGUMENT;
+        };
+        let spec = args::ref_arg(order)
+            .ok_or(FLOX_ERR_INVALID_ARGUMENT)
+            .and_then(order_spec);
+        let (session, spec) = match (session_arg(venue), spec) {
+            (Ok(session), Ok(spec)) => (session, spec),
+            (Err(code), _) | (_, Err(code)) => {
+                *out = FloxOrderResult::failed(code);
+                return code;
+            }
+        };
+
+        let started = Instant::now();
+        let place = async move { session.place(&spec).await };
+        let placed = run("ORDER", place, FLOX_ERR_ORDER_FAILED);
+        *out = match placed {
+            Ok(placed) => {
+                let mut result = FloxOrderResult::failed(FLOX_OK);
+                result.success = true;
+                out_buf::write_fixed(&placed.order_id, &mut result.order_id);
+                out_buf::write_fixed(&placed.client_order_id, &mut result.client_order_id);
+                result
+            }
+            Err(code) => FloxOrderResult::failed(code),
+        };
+        out.latency_ms = started.elapsed().as_millis() as u64;
+        out.error_code
+    })
+}
+
+/// Cancel an open order by the id flox_place_order() returned; some venues
+/// need its instrument, the others ignore it
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_cancel(
+    venue: *const c_char,
+    instrument: *const c_char,
+    order_id: *const c_char,
+) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code,
+        };
+        let Some(order_id) = str_arg(order_id).filter(|o| !o.is_empty()) else {
+            return FLOX_ERR_INVALID_ARGUMENT;
+        };
+        let instrument = str_arg(instrument).unwrap_or_default().to_string();
+
+        let order_id = order_id.to_string();
+        let cancel = async move { session.cancel(&instrument, &order_id).await };
+        match run("CANCEL", cancel, FLOX_ERR_CANCEL_FAILED) {
+            Ok(()) => FLOX_OK,
+            Err(code) => code,
+        }
+    })
+}
+
+/// Cancel every open order with a venue, of `instrument` only if not NULL
+/// `cancelled` (may be NULL) receives the number cancelled, -1 where the
+/// venue does not tell. Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_cancel_all(
+    venue: *const c_char,
+    instrument: *const c_char,
+    cancelled: *mut i64,
+) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code,
+        };
+        let instrument = match instrument.is_null() {
+            true => None,
+            false => match instrument_arg(instrument) {
+                Some(instrument) => Some(instrument.to_string()),
+                None => return FLOX_ERR_INVALID_INSTRUMENT,
+            },
+        };
+
+        let cancel = async move { session.cancel_all(instrument.as_deref()).await };
+        match run("CANCEL_ALL", cancel, FLOX_ERR_CANCEL_FAILED) {
+            Ok(count) => {
+                if let Some(cancelled) = args::out_arg(cancelled) {
+                    *cancelled = count.map_or(-1, i64::from);
+                }
+                FLOX_OK
+            }
+            Err(code) => code,
+        }
+    })
+}
+
+/// Balances held with a venue as JSON [{"asset", "free", "total"}], in
+/// units of the asset; returns its size (flox_last_response()) or a
+/// negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_get_balances(venue: *const c_char) -> i64 {
+    guard::catch(FLOX_ERR_PANIC as i64, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code as i64,
+        };
+        let balances = async move { session.balances().await };
+        match run("BALANCES", balances, FLOX_ERR_VENUE_ERROR) {
+            Ok(balances) => match serde_json::to_string(&balances) {
+                Ok(json) => out_buf::set_last_response(json) as i64,
+                Err(_) => FLOX_ERR_VENUE_ERROR as i64,
+            },
+            Err(code) => code as i64,
+        }
+    })
+}
+
+/// Latency and error counts of a venue session's calls as JSON [{"op",
+/// "count", "errors", "mean_ms", "p50_ms", "p90_ms", "p99_ms", "max_ms"}];
+/// returns its size (flox_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_get_metrics(venue: *const c_char) -> i64 {
+    guard::catch(FLOX_ERR_PANIC as i64, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code as i64,
+        };
+        match serde_json::to_string(&session.metrics()) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => FLOX_ERR_VENUE_ERROR as i64,
+        }
+    })
+}
+
+/// Keep the top of book of an instrument from the venue's stream
+/// The first subscription opens the connection; it reconnects on its own
+/// and closes on shutdown. Returns FLOX_OK, also if already subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_subscribe(venue: *const c_char, instrument: *const c_char) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code,
+        };
+        let Some(instrument) = instrument_arg(instrument) else {
+            return FLOX_ERR_INVALID_INSTRUMENT;
+        };
+
+        let instrument = instrument.to_string();
+        let subscribe = async move { session.subscribe(&instrument).await };
+        match run("SUBSCRIBE", subscribe, FLOX_ERR_INVALID_ARGUMENT) {
+            Ok(()) => FLOX_OK,
+            Err(code) => code,
+        }
+    })
+}
+
+/// Stop keeping an instrument's top of book; the connection stays open
+/// Returns FLOX_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_unsubscribe(venue: *const c_char, instrument: *const c_char) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code,
+        };
+        let Some(instrument) = instrument_arg(instrument) else {
+            return FLOX_ERR_INVALID_INSTRUMENT;
+        };
+
+        match session.unsubscribe(instrument) {
+            Ok(()) => FLOX_OK,
+            Err(e) => fail("UNSUBSCRIBE", &e, FLOX_ERR_INVALID_ARGUMENT),
+        }
+    })
+}
+
+/// Best bid and ask of a subscribed instrument
+/// Returns FLOX_PENDING until the venue sent them (`out` untouched),
+/// FLOX_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_get_top_of_book(
+    venue: *const c_char,
+    instrument: *const c_char,
+    out: *mut FloxTopOfBook,
+) -> i32 {
+    guard::catch(FLOX_ERR_PANIC, || {
+        let session = match session_arg(venue) {
+            Ok(session) => session,
+            Err(code) => return code,
+        };
+        let Some(instrument) = instrument_arg(instrument) else {
+            return FLOX_ERR_INVALID_INSTRUMENT;
+        };
+        let Some(out) = args::out_arg(out) else {
+            return FLOX_ERR_INVALID_ARGUMENT;
+        };
+
+        match session.top_of_book(instrument) {
+            Ok(Some(top)) => {
+                *out = FloxTopOfBook {
+                    bid_raw: to_raw(top.bid),
+                    bid_size_raw: to_raw(top.bid_size),
+                    ask_raw: to_raw(top.ask),
+                    ask_size_raw: to_raw(top.ask_size),
+                    age_ms: top.age.as_millis() as u64,
+                };
+                FLOX_OK
+            }
+            Ok(None) => FLOX_PENDING,
+            Err(e) => e.code(FLOX_ERR_INVALID_ARGUMENT),
+        }
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn flox_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
@@ -0,0 +1,109 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! C headers of the FFI libraries, generated from their Rust definitions
+//!
+//! Every FFI crate's build script calls `generate` with its checked-in
+//! `<lib>_ffi_generated.h`: cbindgen reads the crate's sources with the
+//! crate's cbindgen.toml, and the build fails if the header differs from
+//! what they define. The generated header holds the constants, structs,
+//! callback types and prototypes; the hand-written `<lib>_ffi.h` includes
+//! it and keeps the documentation, and the compiler checks every prototype
+//! it declares against the generated one. So the C side cannot drift from
+//! the Rust ABI.
+//!
+//! After changing the ABI, build once with FLOX_UPDATE_HEADERS=1 to write
+//! the header, and commit it with the change. `ship` copies the headers
+//! next to the built library, for consumers building the crate with cargo
+//! alone.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Set (to anything but "0") to write the generated header instead of
+/// checking it
+pub const UPDATE_ENV: &str = "FLOX_UPDATE_HEADERS";
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set"))
+}
+
+fn update_requested() -> bool {
+    env::var_os(UPDATE_ENV).is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Generate the header of the crate being built from src/lib.rs and the
+/// modules it declares, and check it against `header` (relative to the
+/// crate), or write it there with FLOX_UPDATE_HEADERS
+/// Panics, failing the build, if the header is out of date
+pub fn generate(header: &str) {
+    let crate_dir = manifest_dir();
+    let config_path = crate_dir.join("cbindgen.toml");
+    println!("cargo:rerun-if-changed={}", config_path.display());
+    println!("cargo:rerun-if-changed={}", crate_dir.join("src").display());
+    println!("cargo:rerun-if-env-changed={}", UPDATE_ENV);
+
+    let config = cbindgen::Config::from_file(&config_path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", config_path.display(), e));
+    // Sources only: no cargo metadata, so no dependency needs resolving
+    let bindings = cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(crate_dir.join("src").join("lib.rs"))
+        .generate()
+        .unwrap_or_else(|e| panic!("cannot generate the C header: {}", e));
+    let mut generated = Vec::new();
+    bindings.write(&mut generated);
+
+    let path = crate_dir.join(header);
+    println!("cargo:rerun-if-changed={}", path.display());
+    // Ignoring carriage returns: git may check the header out with CRLF
+    if fs::read(&path).is_ok_and(|current| {
+        current.iter().filter(|&&b| b != b'\r').eq(generated.iter())
+    }) {
+        return;
+    }
+    if update_requested() {
+        fs::write(&path, &generated)
+            .unwrap_or_else(|e| panic!("cannot write {}: {}", path.display(), e));
+        println!("cargo:warning=updated {}", path.display());
+        return;
+    }
+    panic!(
+        "{} is out of date with the Rust definitions; build with {}=1 to update it \
+         and commit it with the change",
+        path.display(),
+        UPDATE_ENV
+    );
+}
+
+/// Copy `headers` of `header_dir` (relative to the crate) next to the
+/// built library, into `<target>/<profile>/include/`
+pub fn ship(header_dir: &str, headers: &[&str]) {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+    // OUT_DIR = <target>/<profile>/build/<pkg>-<hash>/out
+    let profile_dir = match out_dir.ancestors().nth(3) {
+        Some(dir) => dir.to_path_buf(),
+        None => return,
+    };
+    let include_dir = profile_dir.join("include");
+    if fs::create_dir_all(&include_dir).is_err() {
+        println!("cargo:warning=cannot create {}", include_dir.display());
+        return;
+    }
+
+    let header_dir = manifest_dir().join(header_dir);
+    for header in headers {
+        let src = header_dir.join(header);
+        println!("cargo:rerun-if-changed={}", src.display());
+        if let Err(e) = fs::copy(&src, include_dir.join(header)) {
+            println!("cargo:warning=cannot copy {}: {}", src.display(), e);
+        }
+    }
+}
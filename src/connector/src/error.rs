@@ -0,0 +1,109 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Errors of the connector traits and the C ABI codes they map to
+//!
+//! Every venue error converts into a `ConnectorError`; `code` gives the
+//! negative code the venue FFI libraries return, numbered as
+//! POLYMARKET_ERR_* where the meaning is shared.
+
+/// Codes of the venue C ABIs
+pub mod codes {
+    pub const OK: i32 = 0;
+    /// Not an error: no data yet
+    pub const PENDING: i32 = 1;
+    pub const NOT_INITIALIZED: i32 = -1;
+    pub const INVALID_KEY: i32 = -2;
+    pub const AUTH: i32 = -3;
+    /// Unknown symbol, ticker, product, market or contract
+    pub const INVALID_INSTRUMENT: i32 = -4;
+    pub const ORDER_FAILED: i32 = -5;
+    pub const CANCEL_FAILED: i32 = -6;
+    pub const INSUFFICIENT_BALANCE: i32 = -9;
+    /// Endpoint or gateway address malformed
+    pub const INVALID_CONFIG: i32 = -10;
+    pub const INVALID_ARGUMENT: i32 = -12;
+    pub const PANIC: i32 = -14;
+    /// Venue refused the request
+    pub const REJECTED: i32 = -19;
+    /// Timeout or connection error, venue state unknown
+    pub const NETWORK: i32 = -20;
+    pub const RATE_LIMITED: i32 = -21;
+    /// Venue answered with a fault, state unknown
+    pub const VENUE_ERROR: i32 = -22;
+    pub const INVALID_ORDER: i32 = -23;
+    /// The venue cannot do what was asked
+    pub const UNSUPPORTED: i32 = -29;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConnectorError {
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
+    /// Key missing or malformed
+    #[error("invalid key: {0}")]
+    InvalidKey(String),
+    /// The venue refused the credentials, or the session was lost
+    #[error("not authenticated: {0}")]
+    Auth(String),
+    #[error("unknown instrument {0}")]
+    InvalidInstrument(String),
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    #[error("insufficient balance: {0}")]
+    InsufficientBalance(String),
+    /// The venue cannot do what was asked
+    #[error("not supported by {venue}: {what}")]
+    Unsupported { venue: &'static str, what: String },
+    /// The venue refused the request, with its reason
+    #[error("rejected: {0}")]
+    Rejected(String),
+    /// Refused by the venue (429) or by the local rate limit
+    #[error("rate limited: {0}")]
+    RateLimited(String),
+    /// The venue answered with a fault, state unknown
+    #[error("venue error: {0}")]
+    Venue(String),
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+impl ConnectorError {
+    pub fn unsupported(venue: &'static str, what: &str) -> Self {
+        Self::Unsupported {
+            venue,
+            what: what.to_string(),
+        }
+    }
+
+    /// C ABI code of the error; `fallback` for answers that could not be
+    /// read, which depends on the call
+    pub fn code(&self, fallback: i32) -> i32 {
+        match self {
+            Self::InvalidArgument(_) => codes::INVALID_ARGUMENT,
+            Self::InvalidConfig(_) => codes::INVALID_CONFIG,
+            Self::InvalidKey(_) => codes::INVALID_KEY,
+            Self::Auth(_) => codes::AUTH,
+            Self::InvalidInstrument(_) => codes::INVALID_INSTRUMENT,
+            Self::InvalidOrder(_) => codes::INVALID_ORDER,
+            Self::InsufficientBalance(_) => codes::INSUFFICIENT_BALANCE,
+            Self::Unsupported { .. } => codes::UNSUPPORTED,
+            Self::Rejected(_) => codes::REJECTED,
+            Self::RateLimited(_) => codes::RATE_LIMITED,
+            Self::Venue(_) => codes::VENUE_ERROR,
+            Self::Network(_) => codes::NETWORK,
+            Self::Decode(_) => fallback,
+        }
+    }
+}
@@ -0,0 +1,249 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Venue streams kept connected in the background
+//!
+//! `reconnect` runs a connection until it fails and opens it again after a
+//! backoff that doubles from `MIN_BACKOFF` up to `MAX_BACKOFF`; a
+//! connection that held longer than `MAX_BACKOFF` starts the backoff over.
+//! `FeedTask` holds the task running it: started on first use, aborted
+//! when stopped or dropped.
+//!
+//! With the `ws` feature, `run_ws` is one such connection of a WebSocket
+//! feed: it subscribes, keeps the stream alive, sends the subscription
+//! changes queued in `Commands` and hands every message to the venue's
+//! parser, until the connection fails.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "ws")]
+use futures::{SinkExt, StreamExt};
+#[cfg(feature = "ws")]
+use tokio::io::{AsyncRead, AsyncWrite};
+#[cfg(feature = "ws")]
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+#[cfg(feature = "ws")]
+use tokio_tungstenite::tungstenite::Message;
+#[cfg(feature = "ws")]
+use tokio_tungstenite::WebSocketStream;
+use tracing::warn;
+
+/// First reconnect delay of a feed
+pub const MIN_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Longest reconnect delay of a feed
+pub const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Next reconnect delay: a connection that held for a while starts over
+pub fn next_backoff(backoff: Duration, started: Instant) -> Duration {
+    if started.elapsed() > MAX_BACKOFF {
+        MIN_BACKOFF
+    } else {
+        (backoff * 2).min(MAX_BACKOFF)
+    }
+}
+
+/// Run `connect` again each time it fails, until the task is aborted
+/// `label` names the feed in the log ("BOOK FEED")
+pub async fn reconnect<F, Fut, E>(label: &str, mut connect: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: Display,
+{
+    let mut backoff = MIN_BACKOFF / 2;
+    loop {
+        let started = Instant::now();
+        let result = connect().await;
+        backoff = next_backoff(backoff, started);
+        if let Err(e) = result {
+            warn!("[{}] disconnected | {} | retry in {:?}", label, e, backoff);
+        }
+        tokio::time::sleep(backoff).await;
+    }
+}
+
+/// Background task of a feed
+#[derive(Default)]
+pub struct FeedTask(Mutex<Option<JoinHandle<()>>>);
+
+impl FeedTask {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawn `task` on the current runtime unless one is already running;
+    /// true if it was spawned
+    /// Panics outside a runtime, like `tokio::spawn`
+    pub fn ensure<F>(&self, task: impl FnOnce() -> F) -> bool
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let mut slot = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if slot.as_ref().is_some_and(|task| !task.is_finished()) {
+            return false;
+        }
+        *slot = Some(tokio::spawn(task()));
+        true
+    }
+
+    pub fn is_running(&self) -> bool {
+        let slot = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        slot.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    /// Abort the task; true if one was running
+    pub fn stop(&self) -> bool {
+        let task = self.0.lock().unwrap_or_else(|e| e.into_inner()).take();
+        match task {
+            Some(task) => {
+                task.abort();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Drop for FeedTask {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Subscription changes for the live connection of a feed
+#[cfg(feature = "ws")]
+#[derive(Default)]
+pub struct Commands(Mutex<Option<mpsc::UnboundedSender<String>>>);
+
+#[cfg(feature = "ws")]
+impl Commands {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send `message` on the live connection; dropped if there is none, the
+    /// next connection subscribes to everything anew
+    pub fn send(&self, message: String) {
+        let tx = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(tx) = tx.as_ref() {
+            let _ = tx.send(message);
+        }
+    }
+
+    fn open(&self) -> mpsc::UnboundedReceiver<String> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(tx);
+        rx
+    }
+
+    fn close(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+/// How a WebSocket feed tells a live connection from a dead one
+#[cfg(feature = "ws")]
+#[derive(Clone, Copy, Debug)]
+pub struct Keepalive {
+    /// Between two checks for silence
+    pub interval: Duration,
+    /// Text sent at every check, for venues that drop quiet clients
+    pub ping: Option<&'static str>,
+    /// A connection with no message (pong included) for this long is dead
+    pub silence_limit: Duration,
+}
+
+/// Run one connection of a WebSocket feed until it fails
+/// `commands` is installed before `subscriptions` are built, so an
+/// instrument added meanwhile is not missed; `connect` opens the socket and
+/// the subscription messages go out first. Then every text message goes to
+/// `on_text` (binary frames read as UTF-8, as some gateways send their
+/// JSON), venue pings are answered and `commands` are sent. An error from
+/// `on_text` ends the connection
+#[cfg(feature = "ws")]
+pub async fn run_ws<S, C, Fut>(
+    commands: &Commands,
+    connect: C,
+    subscriptions: impl FnOnce() -> Vec<String>,
+    keepalive: Keepalive,
+    on_text: impl FnMut(&str) -> Result<(), String>,
+) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+    C: FnOnce() -> Fut,
+    Fut: Future<Output = Result<WebSocketStream<S>, String>>,
+{
+    let mut rx = commands.open();
+    let result = serve_ws(&mut rx, connect, subscriptions, keepalive, on_text).await;
+    commands.close();
+    result
+}
+
+#[cfg(feature = "ws")]
+async fn serve_ws<S, C, Fut>(
+    commands: &mut mpsc::UnboundedReceiver<String>,
+    connect: C,
+    subscriptions: impl FnOnce() -> Vec<String>,
+    keepalive: Keepalive,
+    mut on_text: impl FnMut(&str) -> Result<(), String>,
+) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+    C: FnOnce() -> Fut,
+    Fut: Future<Output = Result<WebSocketStream<S>, String>>,
+{
+    let (mut write, mut read) = connect().await?.split();
+    for message in subscriptions() {
+        write
+            .send(Message::Text(message.into()))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    let mut check = tokio::time::interval(keepalive.interval);
+    let mut last_message = Instant::now();
+    loop {
+        tokio::select! {
+            message = read.next() => {
+                let message = match message {
+                    Some(message) => message.map_err(|e| e.to_string())?,
+                    None => return Err("connection closed".to_string()),
+                };
+                last_message = Instant::now();
+                match message {
+                    Message::Text(text) => on_text(text.as_str())?,
+                    Message::Binary(data) => on_text(&String::from_utf8_lossy(&data))?,
+                    Message::Ping(data) => {
+                        write.send(Message::Pong(data)).await.map_err(|e| e.to_string())?
+                    }
+                    Message::Close(frame) => {
+                        return Err(format!("closed by venue: {:?}", frame));
+                    }
+                    _ => {}
+                }
+            }
+            Some(command) = commands.recv() => {
+                write.send(Message::Text(command.into())).await.map_err(|e| e.to_string())?;
+            }
+            _ = check.tick() => {
+                if last_message.elapsed() > keepalive.silence_limit {
+                    return Err(format!("no message for {:?}", keepalive.silence_limit));
+                }
+                if let Some(ping) = keepalive.ping {
+                    write.send(Message::Text(ping.into())).await.map_err(|e| e.to_string())?;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Pointer arguments of the C entry points
+//!
+//! An `extern "C"` entry point is safe to call from Rust, yet reads the
+//! pointers its C caller passes. It reads them through these helpers, which
+//! check for NULL; that the rest is valid for the call is the caller's
+//! contract, as the headers document. They are the one place clippy's
+//! not_unsafe_ptr_arg_deref is allowed.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use super::out_buf;
+
+/// String argument; None if NULL or not UTF-8
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn str_arg<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(s) }.to_str().ok()
+}
+
+/// Struct argument; None if NULL
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn ref_arg<'a, T>(p: *const T) -> Option<&'a T> {
+    unsafe { p.as_ref() }
+}
+
+/// Struct the result is written to; None if NULL
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn out_arg<'a, T>(p: *mut T) -> Option<&'a mut T> {
+    unsafe { p.as_mut() }
+}
+
+/// out_buf::write_str into a buffer argument of `len` bytes
+/// Returns the required buffer size
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn write_str(s: &str, buf: *mut c_char, len: usize) -> usize {
+    unsafe { out_buf::write_str(s, buf, len) }
+}
@@ -0,0 +1,24 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Panic containment for FFI entry points
+//!
+//! A panic unwinding out of an `extern "C"` function aborts the host
+//! process. Every entry point runs its body through `catch`, which turns a
+//! panic into a fallback return value (the library's ERR_PANIC code where
+//! the signature allows it).
+//!
+//! Requires `panic = "unwind"`; with `panic = "abort"` nothing can be caught.
+
+use std::panic::{self, AssertUnwindSafe};
+
+/// Run an FFI body, returning `fallback` if it panics
+pub fn catch<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(fallback)
+}
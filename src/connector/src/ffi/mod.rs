@@ -0,0 +1,20 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Helpers of the venue C ABIs
+//!
+//! Every venue FFI library wraps its entry points in `guard::catch`,
+//! reads pointer arguments through `args`, returns strings through
+//! `out_buf` and keeps its session in `session`, so the conventions of
+//! polymarket_ffi.h hold for all of them.
+
+pub mod args;
+pub mod guard;
+pub mod out_buf;
+pub mod session;
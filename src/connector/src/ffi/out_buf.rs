@@ -0,0 +1,73 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Caller-provided output buffers
+//!
+//! Same convention as the Polymarket executor: the caller passes a buffer
+//! and its length, the function returns the size it needs (bytes including
+//! the NUL terminator) and writes the string only if it fits. The last
+//! error and last response are kept per thread, and per library: each FFI
+//! library links its own copy.
+
+use std::cell::RefCell;
+use std::os::raw::c_char;
+
+thread_local! {
+    /// Error message of the last failed call from this thread
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+    /// JSON result of the last query made from this thread
+    static LAST_RESPONSE: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Copy `s` into `buf` as a NUL-terminated string if it fits
+/// Returns the required buffer size (s.len() + 1)
+///
+/// # Safety
+/// `buf` must be null or valid for writes of `len` bytes
+pub unsafe fn write_str(s: &str, buf: *mut c_char, len: usize) -> usize {
+    let required = s.len() + 1;
+    if !buf.is_null() && len >= required {
+        std::ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, buf, s.len());
+        *buf.add(s.len()) = 0;
+    }
+    required
+}
+
+/// Copy `s` into a fixed-size field, truncated to fit with its terminator
+pub fn write_fixed<const N: usize>(s: &str, field: &mut [c_char; N]) {
+    let n = s.len().min(N - 1);
+    for (dst, src) in field.iter_mut().zip(&s.as_bytes()[..n]) {
+        *dst = *src as c_char;
+    }
+    field[n] = 0;
+}
+
+pub fn set_last_error(message: &str) {
+    LAST_ERROR.with(|last| {
+        let mut last = last.borrow_mut();
+        last.clear();
+        last.push_str(message);
+    });
+}
+
+pub fn with_last_error<R>(f: impl FnOnce(&str) -> R) -> R {
+    LAST_ERROR.with(|last| f(&last.borrow()))
+}
+
+/// Keep a query result for the library's last_response call
+/// Returns the buffer size needed to read it
+pub fn set_last_response(json: String) -> usize {
+    let required = json.len() + 1;
+    LAST_RESPONSE.with(|last| *last.borrow_mut() = json);
+    required
+}
+
+pub fn with_last_response<R>(f: impl FnOnce(&str) -> R) -> R {
+    LAST_RESPONSE.with(|last| f(&last.borrow()))
+}
@@ -0,0 +1,59 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Global sessions of the venue C ABIs
+//!
+//! A library's init entry point builds a session (its executor) and
+//! installs it under the venue id; every later call takes a clone and
+//! releases the lock before it runs, so a shutdown taking the session out
+//! never waits for a call in flight. The session type is the library's
+//! own: a lookup with another type finds nothing.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+type Slot = Box<dyn Any + Send + Sync>;
+
+static SESSIONS: RwLock<BTreeMap<&'static str, Slot>> = RwLock::new(BTreeMap::new());
+
+fn read() -> RwLockReadGuard<'static, BTreeMap<&'static str, Slot>> {
+    SESSIONS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn write() -> RwLockWriteGuard<'static, BTreeMap<&'static str, Slot>> {
+    SESSIONS.write().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Install the session of `venue`, returning the one it replaces
+/// The caller drops that outside the lock, so closing it can take its time
+pub fn install<S>(venue: &'static str, session: S) -> Option<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let previous = write().insert(venue, Box::new(session))?;
+    previous.downcast::<S>().ok().map(|previous| *previous)
+}
+
+/// The session of `venue`, if one is installed
+pub fn get<S>(venue: &str) -> Option<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    read().get(venue)?.downcast_ref::<S>().cloned()
+}
+
+/// Take the session of `venue` out; calls started before keep their clone
+pub fn take<S>(venue: &str) -> Option<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let session = write().remove(venue)?;
+    session.downcast::<S>().ok().map(|session| *session)
+}
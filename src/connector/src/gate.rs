@@ -0,0 +1,104 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Rate limit and timing of a connector's calls
+//!
+//! Every `OrderExecutor` call of a connector goes through its `CallGate`:
+//! it takes a token of the connector's rate limit, then runs and is timed
+//! under its `Call`. A call refused by the limit fails with `RateLimited`,
+//! is not sent and is not counted.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::metrics::{Metrics, OpMetrics, Operation};
+use crate::rate_limit::{RateLimit, Throttle};
+use crate::ConnectorError;
+
+/// Longest a call queues for its rate limit token
+pub const MAX_QUEUE_WAIT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Call {
+    /// Orders of any kind
+    OrderPost,
+    /// Cancels by id and of everything
+    Cancel,
+    /// Balance reads
+    Balance,
+}
+
+impl Operation for Call {
+    const ALL: &'static [Self] = &[Call::OrderPost, Call::Cancel, Call::Balance];
+
+    fn name(self) -> &'static str {
+        match self {
+            Call::OrderPost => "order_post",
+            Call::Cancel => "cancel",
+            Call::Balance => "balance",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+pub struct CallGate {
+    metrics: Metrics<Call>,
+    throttle: Throttle,
+}
+
+impl CallGate {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            metrics: Metrics::new(),
+            throttle: Throttle::new(limit, MAX_QUEUE_WAIT),
+        }
+    }
+
+    /// Run a venue call of `call` within the rate limit, timing it
+    pub async fn run<T, E>(
+        &self,
+        call: Call,
+        fut: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, ConnectorError>
+    where
+        E: Into<ConnectorError>,
+    {
+        if !self.throttle.acquire().await {
+            return Err(ConnectorError::RateLimited(format!(
+                "no {} budget left",
+                call.name()
+            )));
+        }
+        self.metrics.timed(call, fut).await.map_err(Into::into)
+    }
+
+    pub fn rate_limit(&self) -> RateLimit {
+        self.throttle.limit()
+    }
+
+    /// Limit the calls to `limit` from now on
+    pub fn set_rate_limit(&self, limit: RateLimit) -> Result<(), ConnectorError> {
+        limit.validate().map_err(ConnectorError::InvalidArgument)?;
+        self.throttle.set(limit);
+        Ok(())
+    }
+
+    pub fn metrics(&self) -> Vec<OpMetrics> {
+        self.metrics.snapshot()
+    }
+
+    /// The counts of `metrics` in the Prometheus text format, named
+    /// `<prefix>_call_*`
+    pub fn metrics_prometheus(&self, prefix: &str) -> String {
+        self.metrics.prometheus(prefix)
+    }
+}
@@ -0,0 +1,293 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Venue-neutral connector layer
+//!
+//! Every venue core exposes a connector implementing `Connector` (session
+//! setup, top of book subscriptions) and `OrderExecutor` (orders, cancels,
+//! balances) over the same order, balance and error types, so a host can
+//! drive any venue through one interface. The venue crates keep their own
+//! richer APIs; the traits cover what every venue can do.
+//! `Capabilities` tell what that is per venue before connecting; the
+//! flox_executor library (ffi/, flox_ffi.h) serves every connector through
+//! one C ABI picked by venue id.
+//!
+//! The pieces the venues used to carry each on their own live here too:
+//! call latency histograms (`metrics`), token bucket rate limits
+//! (`rate_limit`), a `CallGate` that applies both to every call of a
+//! connector, reconnecting feed tasks and, with the `ws` feature, the
+//! WebSocket loop they run (`feed`), the process-wide runtime
+//! the FFI libraries block on (`runtime`, with the `runtime` feature) and
+//! the FFI helpers (`ffi`): panic containment and the string buffers of
+//! the C ABI.
+//!
+//! Instruments are the venue's own names ("BTC-USD", "KXBTC-25DEC31"...);
+//! each connector documents its naming. Amounts are in human units.
+
+pub mod error;
+pub mod feed;
+pub mod ffi;
+pub mod gate;
+pub mod metrics;
+pub mod rate_limit;
+#[cfg(feature = "runtime")]
+pub mod runtime;
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+pub use error::{codes, ConnectorError};
+pub use futures::future::BoxFuture;
+pub use gate::{Call, CallGate};
+pub use metrics::{Metrics, OpMetrics, Operation};
+pub use rate_limit::{RateLimit, TokenBucket};
+
+pub type Result<T> = std::result::Result<T, ConnectorError>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum OrderType {
+    /// Fills at once against the book, the rest is cancelled
+    Market,
+    Limit {
+        price: f64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub enum TimeInForce {
+    /// Rests until filled or cancelled
+    #[default]
+    Gtc,
+    /// What does not fill at once is cancelled
+    Ioc,
+    /// Fills in full at once or is cancelled
+    Fok,
+    /// Cancelled at the end of the trading day
+    Day,
+}
+
+/// Order of any venue; what a venue cannot express is refused with
+/// `ConnectorError::Unsupported` before anything is sent
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OrderSpec {
+    /// Venue's name of what is traded
+    pub instrument: String,
+    pub side: Side,
+    /// Shares, contracts or base asset units, > 0
+    pub quantity: f64,
+    pub order_type: OrderType,
+    /// Limit orders only; market orders ignore it
+    pub tif: TimeInForce,
+    /// Limit orders only: cancelled instead of taking liquidity
+    pub post_only: bool,
+    /// Only reduces the position held
+    pub reduce_only: bool,
+}
+
+impl OrderSpec {
+    pub fn market(instrument: &str, side: Side, quantity: f64) -> Self {
+        Self {
+            instrument: instrument.to_string(),
+            side,
+            quantity,
+            order_type: OrderType::Market,
+            tif: TimeInForce::Gtc,
+            post_only: false,
+            reduce_only: false,
+        }
+    }
+
+    pub fn limit(instrument: &str, side: Side, quantity: f64, price: f64) -> Self {
+        Self {
+            order_type: OrderType::Limit { price },
+            ..Self::market(instrument, side, quantity)
+        }
+    }
+
+    /// Limit price, None for market orders
+    pub fn price(&self) -> Option<f64> {
+        match self.order_type {
+            OrderType::Market => None,
+            OrderType::Limit { price } => Some(price),
+        }
+    }
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PlacedOrder {
+    /// Id to cancel the order with
+    pub order_id: String,
+    /// Our id of the order, empty where the venue has none
+    pub client_order_id: String,
+}
+
+/// Holding of one asset
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Balance {
+    pub asset: String,
+    /// Free to back new orders
+    pub free: f64,
+    pub total: f64,
+}
+
+/// Best bid and ask of an instrument
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TopOfBook {
+    pub bid: f64,
+    pub bid_size: f64,
+    pub ask: f64,
+    pub ask_size: f64,
+    /// Since the venue's last update was received
+    pub age: Duration,
+}
+
+/// What a venue's connector can do, known before connecting
+///
+/// Orders asking for a missing capability are refused with
+/// `ConnectorError::Unsupported`, calls of a missing one likewise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    pub market: bool,
+    pub limit: bool,
+    /// Limit orders of these times in force; Gtc with `limit`
+    pub ioc: bool,
+    pub fok: bool,
+    pub day: bool,
+    pub post_only: bool,
+    pub reduce_only: bool,
+    pub cancel_all: bool,
+    pub balances: bool,
+    /// `subscribe` and `top_of_book`
+    pub top_of_book: bool,
+    /// `ConnectorConfig::testnet` picks a testnet or sandbox
+    pub testnet: bool,
+}
+
+impl Capabilities {
+    pub const NONE: Capabilities = Capabilities {
+        market: false,
+        limit: false,
+        ioc: false,
+        fok: false,
+        day: false,
+        post_only: false,
+        reduce_only: false,
+        cancel_all: false,
+        balances: false,
+        top_of_book: false,
+        testnet: false,
+    };
+}
+
+/// Session settings of a connector; each venue reads the fields it needs
+/// and documents them
+#[derive(Clone, Default)]
+pub struct ConnectorConfig {
+    /// API key, key id or key name
+    pub api_key: Option<String>,
+    /// API secret or private key, scrubbed on drop
+    pub secret: Option<Zeroizing<String>>,
+    /// Base URL or gateway address; the venue's default if None
+    pub endpoint: Option<String>,
+    /// Account, sub-account or funder address
+    pub account: Option<String>,
+    /// Testnet or sandbox of the venue, where it has one
+    pub testnet: bool,
+    /// Local limit of the connector's venue calls; off by default
+    pub rate_limit: RateLimit,
+}
+
+impl ConnectorConfig {
+    /// `api_key`, or InvalidKey if missing
+    pub fn require_api_key(&self) -> Result<&str> {
+        self.api_key
+            .as_deref()
+            .ok_or_else(|| ConnectorError::InvalidKey("API key missing".to_string()))
+    }
+
+    /// `secret`, or InvalidKey if missing
+    pub fn require_secret(&self) -> Result<&str> {
+        self.secret
+            .as_deref()
+            .map(String::as_str)
+            .ok_or_else(|| ConnectorError::InvalidKey("secret missing".to_string()))
+    }
+}
+
+impl fmt::Debug for ConnectorConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectorConfig")
+            .field("api_key", &self.api_key)
+            .field("secret", &self.secret.as_ref().map(|_| "<redacted>"))
+            .field("endpoint", &self.endpoint)
+            .field("account", &self.account)
+            .field("testnet", &self.testnet)
+            .field("rate_limit", &self.rate_limit)
+            .finish()
+    }
+}
+
+/// Session with one venue
+///
+/// Methods returning futures borrow the connector; the futures run on the
+/// caller's runtime. Feeds started by `subscribe` run on the runtime of the
+/// call that started them until the connector is dropped.
+pub trait Connector: Send + Sync {
+    /// "kalshi", "binance"...
+    fn venue(&self) -> &'static str;
+
+    /// What the venue can do here; the connector's `CAPABILITIES`
+    fn capabilities(&self) -> Capabilities;
+
+    /// Open a session with `config`
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self>>
+    where
+        Self: Sized;
+
+    /// Keep the top of book of an instrument from the venue's stream; Ok
+    /// if already subscribed. Unsupported where the venue has no feed here
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<()>>;
+
+    /// Stop keeping an instrument's top of book; InvalidArgument if not
+    /// subscribed
+    fn unsubscribe(&self, instrument: &str) -> Result<()>;
+
+    /// Top of book of a subscribed instrument; None until the venue sent
+    /// it, InvalidArgument if not subscribed
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>>;
+
+    /// Latency and error counts of the calls made through the connector
+    fn metrics(&self) -> Vec<OpMetrics>;
+}
+
+/// Orders, cancels and balances of a venue session
+pub trait OrderExecutor: Connector {
+    /// Check and place an order
+    fn place<'a>(&'a self, order: &'a OrderSpec) -> BoxFuture<'a, Result<PlacedOrder>>;
+
+    /// Cancel an open order by the id `place` returned
+    fn cancel<'a>(&'a self, instrument: &'a str, order_id: &'a str) -> BoxFuture<'a, Result<()>>;
+
+    /// Cancel every open order, of `instrument` only if given; the number
+    /// cancelled where the venue tells it
+    fn cancel_all<'a>(&'a self, instrument: Option<&'a str>) -> BoxFuture<'a, Result<Option<u32>>>;
+
+    /// Balances of the account
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>>>;
+}
@@ -0,0 +1,258 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Latency and error counts of venue calls
+//!
+//! Each operation of an `Operation` enum gets a fixed-bucket histogram its
+//! calls are timed into, timeouts included. Recording is lock-free.
+//! Percentiles are read off the buckets, as the upper bound of the bucket
+//! they fall in (capped by the slowest call), so they are as coarse as the
+//! buckets around them. The same counts render in the Prometheus text
+//! format for a scrape endpoint of the host.
+
+use std::fmt::Write;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+/// Upper bounds of the latency buckets (ms); slower calls land in one more
+const BUCKETS_MS: [u64; 18] = [
+    1, 2, 5, 10, 20, 30, 50, 75, 100, 150, 200, 300, 500, 750, 1_000, 2_000, 5_000, 10_000,
+];
+
+/// Operations timed separately, a fieldless enum
+pub trait Operation: Copy + 'static {
+    /// Every operation, each at the position of its `index`
+    const ALL: &'static [Self];
+
+    /// Label in snapshots and Prometheus output ("order_post")
+    fn name(self) -> &'static str;
+
+    fn index(self) -> usize;
+}
+
+/// Snapshot of one operation's calls
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OpMetrics {
+    pub op: &'static str,
+    /// Calls, failed ones included
+    pub count: u64,
+    pub errors: u64,
+    /// 0.0 until the first call
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Default)]
+struct Histogram {
+    buckets: [AtomicU64; BUCKETS_MS.len() + 1],
+    count: AtomicU64,
+    errors: AtomicU64,
+    sum_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl Histogram {
+    fn record(&self, elapsed: Duration, ok: bool) {
+        let us = elapsed.as_micros() as u64;
+        let bucket = BUCKETS_MS
+            .iter()
+            .position(|bound| us <= bound * 1000)
+            .unwrap_or(BUCKETS_MS.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        if !ok {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.sum_us.fetch_add(us, Ordering::Relaxed);
+        self.max_us.fetch_max(us, Ordering::Relaxed);
+    }
+
+    fn counts(&self) -> [u64; BUCKETS_MS.len() + 1] {
+        std::array::from_fn(|i| self.buckets[i].load(Ordering::Relaxed))
+    }
+
+    /// Upper bound (ms) of the bucket holding the `q` quantile
+    fn quantile(counts: &[u64], q: f64, max_ms: f64) -> f64 {
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let rank = ((total as f64 * q).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return BUCKETS_MS
+                    .get(bucket)
+                    .map_or(max_ms, |bound| (*bound as f64).min(max_ms));
+            }
+        }
+        max_ms
+    }
+
+    fn snapshot(&self, op: &'static str) -> OpMetrics {
+        let counts = self.counts();
+        let count = self.count.load(Ordering::Relaxed);
+        let max_ms = self.max_us.load(Ordering::Relaxed) as f64 / 1000.0;
+        let mean_ms = if count == 0 {
+            0.0
+        } else {
+            self.sum_us.load(Ordering::Relaxed) as f64 / 1000.0 / count as f64
+        };
+        OpMetrics {
+            op,
+            count,
+            errors: self.errors.load(Ordering::Relaxed),
+            mean_ms,
+            p50_ms: Self::quantile(&counts, 0.50, max_ms),
+            p90_ms: Self::quantile(&counts, 0.90, max_ms),
+            p99_ms: Self::quantile(&counts, 0.99, max_ms),
+            max_ms,
+        }
+    }
+
+    fn add(&self, other: &Histogram) {
+        for (bucket, count) in self.buckets.iter().zip(other.counts()) {
+            bucket.fetch_add(count, Ordering::Relaxed);
+        }
+        for (to, from) in [
+            (&self.count, &other.count),
+            (&self.errors, &other.errors),
+            (&self.sum_us, &other.sum_us),
+        ] {
+            to.fetch_add(from.load(Ordering::Relaxed), Ordering::Relaxed);
+        }
+        self.max_us
+            .fetch_max(other.max_us.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+}
+
+/// Histograms of every operation of `O`
+pub struct Metrics<O: Operation> {
+    histograms: Box<[Histogram]>,
+    ops: PhantomData<O>,
+}
+
+impl<O: Operation> Default for Metrics<O> {
+    fn default() -> Self {
+        Self {
+            histograms: O::ALL.iter().map(|_| Histogram::default()).collect(),
+            ops: PhantomData,
+        }
+    }
+}
+
+impl<O: Operation> Metrics<O> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn histogram(&self, op: O) -> &Histogram {
+        &self.histograms[op.index()]
+    }
+
+    pub fn record(&self, op: O, elapsed: Duration, ok: bool) {
+        self.histogram(op).record(elapsed, ok);
+    }
+
+    /// Run a call of `op`, recording how long it took and whether it failed
+    pub async fn timed<T, E>(
+        &self,
+        op: O,
+        call: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E> {
+        let start = Instant::now();
+        let result = call.await;
+        self.record(op, start.elapsed(), result.is_ok());
+        result
+    }
+
+    /// Add the counts of a session being replaced
+    pub fn inherit(&self, from: &Metrics<O>) {
+        for op in O::ALL {
+            self.histogram(*op).add(from.histogram(*op));
+        }
+    }
+
+    /// Latency and error counts of every operation so far
+    pub fn snapshot(&self) -> Vec<OpMetrics> {
+        O::ALL
+            .iter()
+            .map(|op| self.histogram(*op).snapshot(op.name()))
+            .collect()
+    }
+
+    /// The same counts in the Prometheus text exposition format, as the
+    /// `<prefix>_call_latency_ms` histogram and the
+    /// `<prefix>_call_errors_total` counter
+    pub fn prometheus(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# HELP {}_call_latency_ms Latency of venue calls",
+            prefix
+        );
+        let _ = writeln!(out, "# TYPE {}_call_latency_ms histogram", prefix);
+        for op in O::ALL {
+            let histogram = self.histogram(*op);
+            let mut cumulative = 0;
+            for (bucket, count) in histogram.counts().iter().enumerate() {
+                cumulative += count;
+                let le = BUCKETS_MS
+                    .get(bucket)
+                    .map_or("+Inf".to_string(), u64::to_string);
+                let _ = writeln!(
+                    out,
+                    "{}_call_latency_ms_bucket{{op=\"{}\",le=\"{}\"}} {}",
+                    prefix,
+                    op.name(),
+                    le,
+                    cumulative
+                );
+            }
+            let _ = writeln!(
+                out,
+                "{}_call_latency_ms_sum{{op=\"{}\"}} {}",
+                prefix,
+                op.name(),
+                histogram.sum_us.load(Ordering::Relaxed) as f64 / 1000.0
+            );
+            let _ = writeln!(
+                out,
+                "{}_call_latency_ms_count{{op=\"{}\"}} {}",
+                prefix,
+                op.name(),
+                cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "# HELP {}_call_errors_total Failed venue calls",
+            prefix
+        );
+        let _ = writeln!(out, "# TYPE {}_call_errors_total counter", prefix);
+        for op in O::ALL {
+            let _ = writeln!(
+                out,
+                "{}_call_errors_total{{op=\"{}\"}} {}",
+                prefix,
+                op.name(),
+                self.histogram(*op).errors.load(Ordering::Relaxed)
+            );
+        }
+        out
+    }
+}
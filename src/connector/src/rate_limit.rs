@@ -0,0 +1,238 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Client-side rate limits of venue calls
+//!
+//! A `TokenBucket` holds up to `burst` tokens and refills at `per_sec`; a
+//! call takes one before it is sent. With none left, a call of a queueing
+//! limit waits for its token and a call of a rejecting limit fails at once;
+//! so does a queued call that would wait longer than it may. A call refused
+//! here never reached the venue.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Rate of a class of calls
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    /// Sustained requests per second; 0 turns the limit off
+    pub per_sec: f64,
+    /// Requests that can go back to back after a quiet spell
+    pub burst: u32,
+    /// Wait for budget (true) or fail at once (false)
+    pub queue: bool,
+}
+
+impl RateLimit {
+    /// No limit
+    pub const OFF: RateLimit = RateLimit {
+        per_sec: 0.0,
+        burst: 0,
+        queue: true,
+    };
+
+    /// Err if the rate is negative or not finite, or a limit has no burst
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.per_sec.is_finite() || self.per_sec < 0.0 {
+            return Err(format!("invalid rate {}", self.per_sec));
+        }
+        if self.per_sec > 0.0 && self.burst == 0 {
+            return Err("burst must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self::OFF
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenBucket {
+    limit: RateLimit,
+    /// Below 0 while queued calls wait for tokens not refilled yet
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl TokenBucket {
+    /// Full bucket of `limit`
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f64,
+            refilled: Instant::now(),
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.per_sec).min(self.limit.burst as f64);
+        self.refilled = now;
+    }
+
+    /// Calls that can be sent right now without waiting; below 0 while
+    /// queued calls wait. None if the bucket does not limit
+    pub fn tokens(&mut self) -> Option<f64> {
+        if self.limit.per_sec <= 0.0 {
+            return None;
+        }
+        self.refill();
+        Some(self.tokens)
+    }
+
+    /// Limit calls to `limit` from now on, keeping the budget spent
+    pub fn set(&mut self, limit: RateLimit) {
+        self.refill();
+        self.limit = limit;
+        self.tokens = self.tokens.min(limit.burst as f64);
+    }
+
+    /// Take over the limit and the budget spent of another bucket
+    pub fn inherit(&mut self, from: &TokenBucket) {
+        self.limit = from.limit;
+        self.tokens = from.tokens;
+        self.refilled = from.refilled;
+    }
+
+    /// Take a token; the time to wait for it, or None if none is to be had
+    /// within `max_wait`
+    pub fn take(&mut self, max_wait: Duration) -> Option<Duration> {
+        if self.limit.per_sec <= 0.0 {
+            return Some(Duration::ZERO);
+        }
+        self.refill();
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Some(Duration::ZERO);
+        }
+        if !self.limit.queue {
+            return None;
+        }
+        let wait = Duration::from_secs_f64((1.0 - self.tokens) / self.limit.per_sec);
+        if wait > max_wait {
+            return None;
+        }
+        self.tokens -= 1.0;
+        Some(wait)
+    }
+}
+
+/// A token bucket shared by concurrent calls
+pub struct Throttle {
+    bucket: Mutex<TokenBucket>,
+    /// Longest a queued call waits for its token
+    max_wait: Duration,
+}
+
+impl Throttle {
+    pub fn new(limit: RateLimit, max_wait: Duration) -> Self {
+        Self {
+            bucket: Mutex::new(TokenBucket::new(limit)),
+            max_wait,
+        }
+    }
+
+    fn bucket(&self) -> std::sync::MutexGuard<'_, TokenBucket> {
+        self.bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.bucket().limit()
+    }
+
+    pub fn set(&self, limit: RateLimit) {
+        self.bucket().set(limit);
+    }
+
+    /// Wait for the budget of one call; false if it is refused
+    pub async fn acquire(&self) -> bool {
+        let wait = self.bucket().take(self.max_wait);
+        match wait {
+            Some(wait) => {
+                if !wait.is_zero() {
+                    tokio::time::sleep(wait).await;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(queue: bool) -> RateLimit {
+        RateLimit {
+            per_sec: 10.0,
+            burst: 2,
+            queue,
+        }
+    }
+
+    /// Pretend the bucket was last refilled `ms` ago
+    fn age(bucket: &mut TokenBucket, ms: u64) {
+        bucket.refilled -= Duration::from_millis(ms);
+    }
+
+    #[test]
+    fn burst_then_deny() {
+        let mut bucket = TokenBucket::new(limit(false));
+        assert_eq!(bucket.take(Duration::ZERO), Some(Duration::ZERO));
+        assert_eq!(bucket.take(Duration::ZERO), Some(Duration::ZERO));
+        assert_eq!(bucket.take(Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn queued_call_waits_for_its_token() {
+        let mut bucket = TokenBucket::new(limit(true));
+        bucket.take(Duration::ZERO);
+        bucket.take(Duration::ZERO);
+        // Longer than allowed: refused and not charged
+        assert_eq!(bucket.take(Duration::from_millis(50)), None);
+        let wait = bucket.take(Duration::from_secs(1)).unwrap();
+        assert!(wait > Duration::from_millis(90) && wait <= Duration::from_millis(100));
+        assert!(bucket.tokens().unwrap() < 0.0);
+    }
+
+    #[test]
+    fn refills_up_to_burst() {
+        let mut bucket = TokenBucket::new(limit(false));
+        bucket.take(Duration::ZERO);
+        bucket.take(Duration::ZERO);
+        age(&mut bucket, 150);
+        let tokens = bucket.tokens().unwrap();
+        assert!((1.5..1.6).contains(&tokens));
+        assert_eq!(bucket.take(Duration::ZERO), Some(Duration::ZERO));
+        assert_eq!(bucket.take(Duration::ZERO), None);
+
+        age(&mut bucket, 10_000);
+        assert_eq!(bucket.tokens(), Some(2.0));
+    }
+
+    #[test]
+    fn off_never_limits() {
+        let mut bucket = TokenBucket::new(RateLimit::OFF);
+        assert_eq!(bucket.tokens(), None);
+        for _ in 0..100 {
+            assert_eq!(bucket.take(Duration::ZERO), Some(Duration::ZERO));
+        }
+    }
+}
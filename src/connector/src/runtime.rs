@@ -0,0 +1,79 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Process-wide runtime of the FFI libraries
+//!
+//! One multi-threaded runtime, built on first use and never shut down,
+//! runs the calls and feeds of every venue session of the library. Blocking
+//! entry points hand their work to it with `block_on` and wait on the
+//! calling thread; sessions can be set up and torn down any number of times
+//! without starting threads again. Feeds of a session stop when its
+//! connector is dropped, not with the runtime.
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::task::JoinHandle;
+
+/// Worker threads of the runtime; venue calls spend their time waiting on
+/// the network, so a few serve many sessions
+pub const WORKER_THREADS: usize = 4;
+
+static RUNTIME: OnceLock<Result<Runtime, String>> = OnceLock::new();
+
+/// The shared runtime; Err if it could not be built, with the reason
+pub fn shared() -> Result<&'static Runtime, String> {
+    RUNTIME
+        .get_or_init(|| {
+            Builder::new_multi_thread()
+                .worker_threads(WORKER_THREADS)
+                .thread_name("flox-connector")
+                .enable_all()
+                .build()
+                .map_err(|e| format!("runtime failed: {}", e))
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// Run `op` on the shared runtime and wait for it on the calling thread
+/// Calling from a runtime thread would deadlock the worker, so it is
+/// rejected instead. A panic of `op` is raised again on the calling thread,
+/// where the FFI guard reports it
+pub fn block_on<Fut, T>(op: Fut) -> Result<T, String>
+where
+    Fut: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+{
+    if Handle::try_current().is_ok() {
+        return Err("blocking FFI call from inside the executor runtime".to_string());
+    }
+    let runtime = shared()?;
+    match runtime.block_on(runtime.spawn(op)) {
+        Ok(result) => Ok(result),
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => Err(format!("task aborted: {}", e)),
+    }
+}
+
+/// Spawn `task` on the shared runtime
+pub fn spawn<F>(task: F) -> Result<JoinHandle<F::Output>, String>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    Ok(shared()?.spawn(task))
+}
+
+/// Enter the shared runtime on the calling thread, so tasks can be
+/// spawned with `tokio::spawn` until the guard is dropped
+pub fn enter() -> Result<tokio::runtime::EnterGuard<'static>, String> {
+    Ok(shared()?.enter())
+}
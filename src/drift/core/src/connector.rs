@@ -0,0 +1,237 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Drift behind the venue-neutral connector traits
+//!
+//! Instruments are market indexes ("12"); quantities are YES shares and
+//! prices USDC per YES share. Buying goes long, selling short. Market
+//! orders fill through the program's auction without a price bound; limit
+//! orders rest until cancelled, or are cancelled at once with IOC. Order
+//! ids are the user order ids the orders were sent with. There is no feed.
+//!
+//! Config: `endpoint` is the gateway url, DEFAULT_GATEWAY_URL if None;
+//! `account` the sub-account id, 0 if None. The gateway holds the wallet
+//! key, so there is no key or secret.
+
+use std::sync::Arc;
+
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, TimeInForce, TopOfBook,
+};
+
+use crate::{DriftError, OrderKind, OrderRequest, Side, Trader};
+
+const VENUE: &str = "drift";
+
+impl From<DriftError> for ConnectorError {
+    fn from(err: DriftError) -> Self {
+        let message = err.to_string();
+        match err {
+            DriftError::InvalidOrder(_) => Self::InvalidOrder(message),
+            DriftError::InvalidUrl(_) => Self::InvalidConfig(message),
+            DriftError::Gateway { status, reason } => {
+                // Program errors come back by name in the reason
+                let reason = reason.to_ascii_lowercase().replace(' ', "");
+                if reason.contains("insufficientcollateral") {
+                    Self::InsufficientBalance(message)
+                } else if reason.contains("marketindex") || reason.contains("marketnotfound") {
+                    Self::InvalidInstrument(message)
+                } else {
+                    match status {
+                        429 => Self::RateLimited(message),
+                        500.. => Self::Venue(message),
+                        _ => Self::Rejected(message),
+                    }
+                }
+            }
+            DriftError::Network(_) => Self::Network(message),
+            DriftError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Market index of an instrument
+fn market_index(instrument: &str) -> Result<u16, ConnectorError> {
+    instrument.parse().map_err(|_| {
+        ConnectorError::InvalidInstrument(format!("not a market index: {}", instrument))
+    })
+}
+
+/// Order request of a spec, refused if Drift cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    let side = match order.side {
+        flox_connector::Side::Buy => Side::Long,
+        flox_connector::Side::Sell => Side::Short,
+    };
+    let (kind, price, immediate_or_cancel) = match (order.order_type, order.tif) {
+        (OrderType::Market, _) => (OrderKind::Market, 0.0, false),
+        (OrderType::Limit { price }, TimeInForce::Gtc) => (OrderKind::Limit, price, false),
+        (OrderType::Limit { price }, TimeInForce::Ioc) => (OrderKind::Limit, price, true),
+        (OrderType::Limit { .. }, tif) => {
+            return Err(ConnectorError::unsupported(
+                VENUE,
+                &format!("{:?} orders", tif),
+            ))
+        }
+    };
+    Ok(OrderRequest {
+        market_index: market_index(&order.instrument)?,
+        side,
+        kind,
+        shares: order.quantity,
+        price,
+        post_only: order.post_only,
+        immediate_or_cancel,
+        reduce_only: order.reduce_only,
+    })
+}
+
+pub struct DriftConnector {
+    trader: Arc<Trader>,
+    gate: CallGate,
+}
+
+impl DriftConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        post_only: true,
+        reduce_only: true,
+        cancel_all: true,
+        balances: true,
+        ..Capabilities::NONE
+    };
+
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+}
+
+impl Connector for DriftConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let sub_account_id = match config.account.as_deref() {
+                Some(account) => account.parse().map_err(|_| {
+                    ConnectorError::InvalidConfig(format!("not a sub-account id: {}", account))
+                })?,
+                None => 0,
+            };
+            let trader = Trader::connect(config.endpoint.as_deref(), sub_account_id).await?;
+            Ok(Self::new(trader, CallGate::new(config.rate_limit)))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, _instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async { Err(ConnectorError::unsupported(VENUE, "market data feed")) })
+    }
+
+    fn unsubscribe(&self, _instrument: &str) -> Result<(), ConnectorError> {
+        Err(ConnectorError::unsupported(VENUE, "market data feed"))
+    }
+
+    fn top_of_book(&self, _instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        Err(ConnectorError::unsupported(VENUE, "market data feed"))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for DriftConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let request = order_request(order)?;
+            let ack = self
+                .gate
+                .run(Call::OrderPost, self.trader.place(request))
+                .await?;
+            let id = ack.user_order_id.to_string();
+            Ok(PlacedOrder {
+                order_id: id.clone(),
+                client_order_id: id,
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        _instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            let id: u8 = order_id.parse().map_err(|_| {
+                ConnectorError::InvalidArgument(format!("not a user order id: {}", order_id))
+            })?;
+            self.gate.run(Call::Cancel, self.trader.cancel(id)).await?;
+            Ok(())
+        })
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            match instrument {
+                Some(instrument) => {
+                    let index = market_index(instrument)?;
+                    let cancel = self.trader.cancel_market(index);
+                    self.gate.run(Call::Cancel, cancel).await?;
+                }
+                None => {
+                    self.gate
+                        .run(Call::Cancel, self.trader.cancel_all())
+                        .await?;
+                }
+            }
+            // The gateway only returns the transaction
+            Ok(None)
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let collateral = self
+                .gate
+                .run(Call::Balance, self.trader.collateral())
+                .await?;
+            Ok(vec![Balance {
+                asset: "USDC".to_string(),
+                free: collateral.free,
+                total: collateral.total,
+            }])
+        })
+    }
+}
@@ -0,0 +1,206 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Drift prediction market (BET) executor
+//!
+//! Drift's prediction markets are perp markets on Solana priced 0-1 USDC:
+//! a long position holds YES shares, a short one NO shares. `Trader` goes
+//! through a Drift gateway (drift-labs/gateway), the self-hosted HTTP
+//! server that builds the program's transactions, signs them with the
+//! wallet keypair it is started with and sends them. The wallet key stays
+//! in the gateway process; this crate holds no key.
+//!
+//! Orders land on-chain asynchronously: placing one returns the signature
+//! of the transaction sent, and fills show in open orders and positions
+//! once it lands. `connector::DriftConnector` puts the session behind the
+//! shared connector traits.
+
+pub mod connector;
+pub mod orders;
+
+use std::time::Duration;
+
+use reqwest::{Method, Url};
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+pub use connector::DriftConnector;
+pub use orders::{OpenOrder, OrderAck, OrderKind, OrderRequest, Side};
+
+/// Address a gateway listens on by default
+pub const DEFAULT_GATEWAY_URL: &str = "http://127.0.0.1:8080";
+
+/// Timeout of one request, connect included; the gateway answers an order
+/// once its transaction is sent
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// USDC amounts in raw units (6 decimals)
+pub const USDC_SCALE: f64 = 1_000_000.0;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DriftError {
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    #[error("invalid gateway url: {0}")]
+    InvalidUrl(String),
+    /// The gateway answered with an error status; `reason` carries the
+    /// program error of a failed transaction
+    #[error("gateway answered {status}: {reason}")]
+    Gateway { status: u16, reason: String },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ErrorBody {
+    reason: String,
+}
+
+/// Decimal sent as a string or a number
+pub(crate) fn decimal<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Text(String),
+        Number(f64),
+    }
+    match Decimal::deserialize(d)? {
+        Decimal::Text(s) => s.parse().map_err(serde::de::Error::custom),
+        Decimal::Number(n) => Ok(n),
+    }
+}
+
+/// Collateral of the sub-account (USDC)
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct Collateral {
+    #[serde(deserialize_with = "decimal")]
+    pub total: f64,
+    /// Free to back new orders
+    #[serde(deserialize_with = "decimal")]
+    pub free: f64,
+}
+
+/// Holding in one prediction market
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Position {
+    pub market_index: u16,
+    /// Shares held: positive YES, negative NO
+    #[serde(deserialize_with = "decimal")]
+    pub amount: f64,
+    /// Average entry price (USDC per YES share)
+    #[serde(deserialize_with = "decimal")]
+    pub average_entry: f64,
+    #[serde(deserialize_with = "decimal")]
+    pub settled_pnl: f64,
+}
+
+#[derive(Deserialize)]
+struct PositionsResponse {
+    #[serde(default)]
+    perp: Vec<Position>,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    base_url: Url,
+    sub_account_id: u16,
+}
+
+impl Trader {
+    /// Open a session on the gateway at `gateway_url` (DEFAULT_GATEWAY_URL
+    /// if None) trading `sub_account_id` of its wallet, checked by reading
+    /// the collateral
+    pub async fn connect(
+        gateway_url: Option<&str>,
+        sub_account_id: u16,
+    ) -> Result<Self, DriftError> {
+        let gateway_url = gateway_url
+            .unwrap_or(DEFAULT_GATEWAY_URL)
+            .trim_end_matches('/');
+        let base_url = Url::parse(gateway_url)
+            .map_err(|e| DriftError::InvalidUrl(format!("{}: {}", gateway_url, e)))?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| DriftError::Network(e.to_string()))?;
+        let trader = Self {
+            http,
+            base_url,
+            sub_account_id,
+        };
+        let collateral = trader.collateral().await?;
+        info!(
+            "[CONNECT] drift gateway | url={} | sub_account={} | collateral=${:.2} | free=${:.2}",
+            trader.base_url, sub_account_id, collateral.total, collateral.free
+        );
+        Ok(trader)
+    }
+
+    pub fn sub_account_id(&self) -> u16 {
+        self.sub_account_id
+    }
+
+    /// Send a request to `path` (below the gateway url) for the session's
+    /// sub-account and decode the answer
+    pub(crate) async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, DriftError> {
+        let mut url = self.base_url.clone();
+        url.set_path(&format!("{}{}", self.base_url.path(), path));
+        let mut request = self
+            .http
+            .request(method, url)
+            .query(&[("subAccountId", self.sub_account_id)]);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| DriftError::Network(e.to_string()))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| DriftError::Network(e.to_string()))?;
+        if !status.is_success() {
+            let reason = serde_json::from_str::<ErrorBody>(&text)
+                .map(|body| body.reason)
+                .ok()
+                .filter(|reason| !reason.is_empty())
+                .unwrap_or(text);
+            return Err(DriftError::Gateway {
+                status: status.as_u16(),
+                reason,
+            });
+        }
+        let text = if text.is_empty() { "null" } else { &text };
+        serde_json::from_str(text).map_err(|e| DriftError::Decode(e.to_string()))
+    }
+
+    pub async fn collateral(&self) -> Result<Collateral, DriftError> {
+        self.send(Method::GET, "/v2/collateral", None).await
+    }
+
+    /// Open positions in perp markets, prediction markets included
+    pub async fn positions(&self) -> Result<Vec<Position>, DriftError> {
+        let resp: PositionsResponse = self.send(Method::GET, "/v2/positions", None).await?;
+        Ok(resp.perp.into_iter().filter(|p| p.amount != 0.0).collect())
+    }
+}
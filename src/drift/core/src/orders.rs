@@ -0,0 +1,252 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders and cancels
+//!
+//! Buying YES goes long the market, buying NO short; selling YES shares
+//! held is a short of the same size. Each order carries a user order id
+//! (1-255, the range the program keeps per sub-account) drawn from a
+//! rolling counter, so it can be cancelled before the order id the program
+//! assigns is known. Ids wrap after 255 orders: a host keeping more open
+//! cancels by market instead.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::{decimal, DriftError, Trader};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// Buy YES / sell NO
+    Long,
+    /// Buy NO / sell YES
+    Short,
+}
+
+impl Side {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderKind {
+    /// Fills through the program's auction, no worse than the price bound
+    /// if one is set
+    Market,
+    /// Rests on the book until filled or cancelled
+    Limit,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub market_index: u16,
+    pub side: Side,
+    pub kind: OrderKind,
+    /// YES shares, > 0
+    pub shares: f64,
+    /// Limit price (USDC per YES share, 0-1 exclusive); for market orders
+    /// the worst price accepted, 0 for none
+    pub price: f64,
+    /// Limit orders only: cancelled instead of taking liquidity
+    pub post_only: bool,
+    /// Limit orders only: what does not fill at once is cancelled
+    pub immediate_or_cancel: bool,
+    /// Only reduces the position held
+    pub reduce_only: bool,
+}
+
+impl OrderRequest {
+    fn validate(&self) -> Result<(), DriftError> {
+        if !self.shares.is_finite() || self.shares <= 0.0 {
+            return Err(DriftError::InvalidOrder(format!(
+                "shares must be > 0, got {}",
+                self.shares
+            )));
+        }
+        let price_ok = self.price > 0.0 && self.price < 1.0;
+        let unbounded = self.kind == OrderKind::Market && self.price == 0.0;
+        if !price_ok && !unbounded {
+            return Err(DriftError::InvalidOrder(format!(
+                "price must be between 0 and 1, got {}",
+                self.price
+            )));
+        }
+        if self.kind == OrderKind::Market && (self.post_only || self.immediate_or_cancel) {
+            return Err(DriftError::InvalidOrder(
+                "post-only and immediate-or-cancel apply to limit orders".to_string(),
+            ));
+        }
+        if self.post_only && self.immediate_or_cancel {
+            return Err(DriftError::InvalidOrder(
+                "post-only and immediate-or-cancel exclude each other".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn body(&self, user_order_id: u8) -> serde_json::Value {
+        let amount = match self.side {
+            Side::Long => self.shares,
+            Side::Short => -self.shares,
+        };
+        let mut order = serde_json::json!({
+            "marketIndex": self.market_index,
+            "marketType": "perp",
+            "amount": amount,
+            "orderType": match self.kind {
+                OrderKind::Market => "market",
+                OrderKind::Limit => "limit",
+            },
+            "postOnly": self.post_only,
+            "immediateOrCancel": self.immediate_or_cancel,
+            "reduceOnly": self.reduce_only,
+            "userOrderId": user_order_id,
+        });
+        if self.price > 0.0 {
+            order["price"] = serde_json::json!(self.price);
+        }
+        serde_json::json!({ "orders": [order] })
+    }
+}
+
+/// Gateway's answer to an order: the transaction was sent, not yet landed
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderAck {
+    pub tx_signature: String,
+    pub user_order_id: u8,
+}
+
+#[derive(Deserialize)]
+struct TxResponse {
+    #[serde(default)]
+    tx: String,
+}
+
+/// Order open on the book
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OpenOrder {
+    pub market_index: u16,
+    pub market_type: String,
+    pub order_type: String,
+    /// YES shares: positive long, negative short
+    #[serde(deserialize_with = "decimal")]
+    pub amount: f64,
+    #[serde(deserialize_with = "decimal")]
+    pub filled: f64,
+    #[serde(deserialize_with = "decimal")]
+    pub price: f64,
+    pub post_only: bool,
+    pub reduce_only: bool,
+    pub user_order_id: u8,
+    pub order_id: u32,
+}
+
+#[derive(Deserialize)]
+struct OrdersResponse {
+    #[serde(default)]
+    orders: Vec<OpenOrder>,
+}
+
+/// Next user order id, 1-255
+fn next_user_order_id() -> u8 {
+    static SEQ: AtomicU8 = AtomicU8::new(0);
+    loop {
+        let id = SEQ.fetch_add(1, Ordering::Relaxed).wrapping_add(1);
+        // 0 means "no user order id" to the program
+        if id != 0 {
+            return id;
+        }
+    }
+}
+
+impl Trader {
+    /// Check and send an order
+    pub async fn place(&self, request: OrderRequest) -> Result<OrderAck, DriftError> {
+        request.validate()?;
+        let user_order_id = next_user_order_id();
+        let resp: TxResponse = self
+            .send(
+                Method::POST,
+                "/v2/orders",
+                Some(request.body(user_order_id)),
+            )
+            .await?;
+        if resp.tx.is_empty() {
+            return Err(DriftError::Decode("no transaction signature".to_string()));
+        }
+        info!(
+            "[ORDER] {} {} | market={} | shares={} | price={} | user_id={} | tx={}",
+            match request.kind {
+                OrderKind::Market => "market",
+                OrderKind::Limit => "limit",
+            },
+            request.side.as_str(),
+            request.market_index,
+            request.shares,
+            request.price,
+            user_order_id,
+            resp.tx
+        );
+        Ok(OrderAck {
+            tx_signature: resp.tx,
+            user_order_id,
+        })
+    }
+
+    /// Cancel an order by the user order id it was placed with; returns
+    /// the transaction signature
+    pub async fn cancel(&self, user_order_id: u8) -> Result<String, DriftError> {
+        if user_order_id == 0 {
+            return Err(DriftError::InvalidOrder("user order id 0".to_string()));
+        }
+        let body = serde_json::json!({ "userIds": [user_order_id] });
+        let tx = self.cancel_with(body).await?;
+        info!("[CANCEL] user_id={} | tx={}", user_order_id, tx);
+        Ok(tx)
+    }
+
+    /// Cancel every order open in a market
+    pub async fn cancel_market(&self, market_index: u16) -> Result<String, DriftError> {
+        let body = serde_json::json!({ "marketIndex": market_index, "marketType": "perp" });
+        let tx = self.cancel_with(body).await?;
+        info!("[CANCEL] market={} | tx={}", market_index, tx);
+        Ok(tx)
+    }
+
+    /// Cancel every order open on the sub-account, in every market
+    pub async fn cancel_all(&self) -> Result<String, DriftError> {
+        let tx = self.cancel_with(serde_json::json!({})).await?;
+        info!("[CANCEL_ALL] tx={}", tx);
+        Ok(tx)
+    }
+
+    async fn cancel_with(&self, body: serde_json::Value) -> Result<String, DriftError> {
+        // Nothing to cancel may answer without a transaction
+        let resp: Option<TxResponse> = self.send(Method::DELETE, "/v2/orders", Some(body)).await?;
+        Ok(resp.map(|r| r.tx).unwrap_or_default())
+    }
+
+    /// Orders open in perp markets, prediction markets included
+    pub async fn open_orders(&self) -> Result<Vec<OpenOrder>, DriftError> {
+        let resp: OrdersResponse = self.send(Method::GET, "/v2/orders", None).await?;
+        Ok(resp
+            .orders
+            .into_iter()
+            .filter(|o| o.market_type == "perp")
+            .collect())
+    }
+}
@@ -0,0 +1,18 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Check drift_ffi_generated.h against the Rust definitions (see
+//! flox_headers), then ship the headers next to the built library.
+
+const HEADER_DIR: &str = "../../../include/flox-connectors/drift";
+
+fn main() {
+    flox_headers::generate(&format!("{}/drift_ffi_generated.h", HEADER_DIR));
+    flox_headers::ship(HEADER_DIR, &["drift_ffi.h", "drift_ffi_generated.h"]);
+}
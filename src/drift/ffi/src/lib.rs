@@ -0,0 +1,417 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Drift prediction market executor - C FFI
+//!
+//! The C ABI of drift_ffi.h, laid out like the Polymarket and Kalshi
+//! executors': one global `DriftConnector` set up by drift_init(), blocking
+//! calls that run on the shared connector runtime, negative error codes
+//! (the same numbers as POLYMARKET_ERR_* where the meaning is shared),
+//! thread-local last error and last response buffers.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Instant;
+
+use drift_executor_core::{
+    DriftConnector, DriftError, OrderAck, OrderKind, OrderRequest, Side, Trader, USDC_SCALE,
+};
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, Connector, ConnectorConfig, ConnectorError};
+use tracing::{error, info};
+
+// Error codes
+pub const DRIFT_OK: i32 = 0;
+pub const DRIFT_ERR_NOT_INITIALIZED: i32 = -1;
+pub const DRIFT_ERR_INVALID_MARKET: i32 = -4;
+pub const DRIFT_ERR_ORDER_FAILED: i32 = -5;
+pub const DRIFT_ERR_CANCEL_FAILED: i32 = -6;
+/// Not enough free collateral
+pub const DRIFT_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+/// Gateway url malformed
+pub const DRIFT_ERR_INVALID_CONFIG: i32 = -10;
+pub const DRIFT_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const DRIFT_ERR_PANIC: i32 = -14;
+/// Gateway or program refused the request
+pub const DRIFT_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const DRIFT_ERR_NETWORK: i32 = -20;
+/// Gateway or its RPC answered 429
+pub const DRIFT_ERR_RATE_LIMITED: i32 = -21;
+/// Gateway answered 5xx
+pub const DRIFT_ERR_VENUE_ERROR: i32 = -22;
+/// Shares, price or flags not valid (checked locally)
+pub const DRIFT_ERR_INVALID_ORDER: i32 = -23;
+
+// Sides
+/// Buy YES / sell NO
+pub const DRIFT_SIDE_LONG: i32 = 0;
+/// Buy NO / sell YES
+pub const DRIFT_SIDE_SHORT: i32 = 1;
+
+// Order flags
+/// Limit only: cancelled instead of taking liquidity
+pub const DRIFT_FLAG_POST_ONLY: u32 = 1;
+/// Only reduces the position held
+pub const DRIFT_FLAG_REDUCE_ONLY: u32 = 2;
+/// Limit only: what does not fill at once is cancelled
+pub const DRIFT_FLAG_IOC: u32 = 4;
+const DRIFT_FLAGS_ALL: u32 = DRIFT_FLAG_POST_ONLY | DRIFT_FLAG_REDUCE_ONLY | DRIFT_FLAG_IOC;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct DriftOrderResult {
+    /// True if the transaction was sent
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Id to cancel the order with (1-255)
+    pub user_order_id: u32,
+    /// Base58 signature of the transaction sent
+    pub tx_signature: [c_char; 96],
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl DriftOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            user_order_id: 0,
+            tx_signature: [0; 96],
+            latency_ms: 0,
+        }
+    }
+
+    fn sent(ack: &OrderAck, latency_ms: u64) -> Self {
+        let mut result = Self::failed(DRIFT_OK);
+        result.success = true;
+        result.user_order_id = ack.user_order_id as u32;
+        out_buf::write_fixed(&ack.tx_signature, &mut result.tx_signature);
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "drift";
+
+fn get_executor() -> Option<Arc<DriftConnector>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("drift_executor=info"))
+        .try_init();
+}
+
+fn side_of(side: i32) -> Option<Side> {
+    match side {
+        DRIFT_SIDE_LONG => Some(Side::Long),
+        DRIFT_SIDE_SHORT => Some(Side::Short),
+        _ => None,
+    }
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect to the Drift gateway at `gateway_url` (NULL for
+/// http://127.0.0.1:8080) trading `sub_account_id` of the wallet it signs
+/// with. Replaces a session already set up. Returns 0 on success, negative
+/// error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_init(gateway_url: *const c_char, sub_account_id: u16) -> i32 {
+    guard::catch(DRIFT_ERR_PANIC, || {
+        init_logging();
+        let gateway_url = match gateway_url.is_null() {
+            true => None,
+            false => match str_arg(gateway_url) {
+                Some(url) => Some(url.to_string()),
+                None => return DRIFT_ERR_INVALID_CONFIG,
+            },
+        };
+
+        let config = ConnectorConfig {
+            endpoint: gateway_url,
+            account: Some(sub_account_id.to_string()),
+            ..Default::default()
+        };
+
+        let executor = match runtime::block_on(DriftConnector::connect(config)) {
+            Ok(Ok(connector)) => Arc::new(connector),
+            Ok(Err(e)) => return fail("INIT", &e, DRIFT_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return DRIFT_ERR_NOT_INITIALIZED;
+            }
+        };
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] drift executor ready");
+        DRIFT_OK
+    })
+}
+
+/// Shut the session down; calls still running finish first
+/// drift_init() can be called again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<DriftConnector>>(VENUE);
+        if executor.is_some() {
+            info!("[SHUTDOWN] drift executor stopped");
+        }
+    })
+}
+
+/// Send an order and fill `out`
+fn place(op: &'static str, request: OrderRequest, out: *mut DriftOrderResult) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return DRIFT_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = DriftOrderResult::failed(DRIFT_ERR_NOT_INITIALIZED);
+            return DRIFT_ERR_NOT_INITIALIZED;
+        }
+    };
+
+    let started = Instant::now();
+    let result = runtime::block_on(async move {
+        executor.gate().run(Call::OrderPost, executor.trader().place(request)).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(ack)) => DriftOrderResult::sent(&ack, latency_ms),
+        Ok(Err(e)) => DriftOrderResult::failed(fail(op, &e, DRIFT_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | market={} | {}", op, request.market_index, e);
+            out_buf::set_last_error(&e);
+            DriftOrderResult::failed(DRIFT_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+/// Order arguments shared by the entry points
+fn order_request(
+    kind: OrderKind,
+    market_index: u16,
+    side: i32,
+    shares: f64,
+    price: f64,
+    flags: u32,
+) -> Option<OrderRequest> {
+    if flags & !DRIFT_FLAGS_ALL != 0 {
+        return None;
+    }
+    Some(OrderRequest {
+        market_index,
+        side: side_of(side)?,
+        kind,
+        shares,
+        price,
+        post_only: flags & DRIFT_FLAG_POST_ONLY != 0,
+        immediate_or_cancel: flags & DRIFT_FLAG_IOC != 0,
+        reduce_only: flags & DRIFT_FLAG_REDUCE_ONLY != 0,
+    })
+}
+
+/// Market order of `shares` YES shares on `side` (DRIFT_SIDE_*), filled
+/// through the program's auction no worse than `worst_price` (0: no bound)
+/// `flags`: DRIFT_FLAG_REDUCE_ONLY or 0
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_market_order(
+    market_index: u16,
+    side: i32,
+    shares: f64,
+    worst_price: f64,
+    flags: u32,
+    out: *mut DriftOrderResult,
+) -> i32 {
+    guard::catch(DRIFT_ERR_PANIC, || {
+        match order_request(OrderKind::Market, market_index, side, shares, worst_price, flags) {
+            Some(request) => place("MARKET", request, out),
+            None => DRIFT_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Limit order of `shares` YES shares on `side` at `price` (0-1 exclusive)
+/// `flags`: DRIFT_FLAG_* combined
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_limit_order(
+    market_index: u16,
+    side: i32,
+    shares: f64,
+    price: f64,
+    flags: u32,
+    out: *mut DriftOrderResult,
+) -> i32 {
+    guard::catch(DRIFT_ERR_PANIC, || {
+        match order_request(OrderKind::Limit, market_index, side, shares, price, flags) {
+            Some(request) => place("LIMIT", request, out),
+            None => DRIFT_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Run a cancel; the transaction signature goes to drift_last_response()
+fn cancel_with<Fut>(op: &str, cancel: impl FnOnce(Arc<Trader>) -> Fut) -> i32
+where
+    Fut: Future<Output = Result<String, DriftError>> + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return DRIFT_ERR_NOT_INITIALIZED,
+    };
+    let cancel = cancel(executor.trader().clone());
+    match runtime::block_on(async move { executor.gate().run(Call::Cancel, cancel).await }) {
+        Ok(Ok(tx)) => {
+            out_buf::set_last_response(tx);
+            DRIFT_OK
+        }
+        Ok(Err(e)) => fail(op, &e, DRIFT_ERR_CANCEL_FAILED),
+        Err(e) => {
+            error!("[{}] failed | {}", op, e);
+            out_buf::set_last_error(&e);
+            DRIFT_ERR_CANCEL_FAILED
+        }
+    }
+}
+
+/// Cancel an order by the user order id it was placed with
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_cancel(user_order_id: u32) -> i32 {
+    guard::catch(DRIFT_ERR_PANIC, || {
+        let Ok(id) = u8::try_from(user_order_id) else {
+            return DRIFT_ERR_INVALID_ARGUMENT;
+        };
+        if id == 0 {
+            return DRIFT_ERR_INVALID_ARGUMENT;
+        }
+        cancel_with("CANCEL", |trader| async move { trader.cancel(id).await })
+    })
+}
+
+/// Cancel every order open in a market
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_cancel_market(market_index: u16) -> i32 {
+    guard::catch(DRIFT_ERR_PANIC, || {
+        cancel_with("CANCEL_MARKET", |trader| async move {
+            trader.cancel_market(market_index).await
+        })
+    })
+}
+
+/// Cancel every order open on the sub-account
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_cancel_all() -> i32 {
+    guard::catch(DRIFT_ERR_PANIC, || {
+        cancel_with("CANCEL_ALL", |trader| async move { trader.cancel_all().await })
+    })
+}
+
+/// Free collateral in raw USDC (6 decimals), or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_get_balance() -> i64 {
+    guard::catch(DRIFT_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return DRIFT_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Balance, executor.trader().collateral()).await
+        });
+        match result {
+            Ok(Ok(collateral)) => (collateral.free * USDC_SCALE).round() as i64,
+            Ok(Err(e)) => fail("BALANCE", &e, DRIFT_ERR_VENUE_ERROR) as i64,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                DRIFT_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// Run a query and keep its JSON for drift_last_response()
+fn query_json<Fut, T>(op: &str, query: impl FnOnce(Arc<Trader>) -> Fut) -> i64
+where
+    Fut: Future<Output = Result<T, DriftError>> + Send + 'static,
+    T: serde::Serialize + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return DRIFT_ERR_NOT_INITIALIZED as i64,
+    };
+    let query = query(executor.trader().clone());
+    match runtime::block_on(async move { query.await.map_err(ConnectorError::from) }) {
+        Ok(Ok(value)) => match serde_json::to_string(&value) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => DRIFT_ERR_VENUE_ERROR as i64,
+        },
+        Ok(Err(e)) => fail(op, &e, DRIFT_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            DRIFT_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// Positions as JSON [{"marketIndex", "amount", "averageEntry",
+/// "settledPnl"}]; returns its size (drift_last_response()) or a negative
+/// error code
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_get_positions() -> i64 {
+    guard::catch(DRIFT_ERR_PANIC as i64, || {
+        query_json("POSITIONS", |trader| async move { trader.positions().await })
+    })
+}
+
+/// Open orders as JSON; returns its size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_get_open_orders() -> i64 {
+    guard::catch(DRIFT_ERR_PANIC as i64, || {
+        query_json("OPEN_ORDERS", |trader| async move { trader.open_orders().await })
+    })
+}
+
+/// Copy the JSON result (or cancel signature) of the last query made from
+/// the calling thread. Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn drift_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
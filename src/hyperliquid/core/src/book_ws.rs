@@ -0,0 +1,205 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! L2 books kept from the WebSocket
+//!
+//! `BookFeed` holds the subscribed coins and one book per coin. `run`
+//! keeps a connection open until it fails; the caller reconnects, and the
+//! new connection subscribes every coin again. Coins added or removed while
+//! connected are sent on the live connection.
+//!
+//! Every `l2Book` message is a full book (top 20 levels per side), so a
+//! coin's book is replaced as a whole. Until its first message a coin has
+//! no book.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::decimal;
+
+/// The venue drops connections that send nothing for 60s
+pub const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A connection with no message (pong included) for this long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(75);
+
+/// Pings keep the connection alive, pongs show it is
+const KEEPALIVE: Keepalive = Keepalive {
+    interval: PING_INTERVAL,
+    ping: Some(r#"{"method":"ping"}"#),
+    silence_limit: SILENCE_LIMIT,
+};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct Level {
+    #[serde(rename = "px", deserialize_with = "decimal")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "decimal")]
+    pub size: f64,
+    /// Orders at the level
+    #[serde(rename = "n", default)]
+    pub orders: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct BookSnapshot {
+    /// Best (highest) first
+    pub bids: Vec<Level>,
+    /// Best (lowest) first
+    pub asks: Vec<Level>,
+    /// Venue time of the book (ms since the epoch)
+    pub time_ms: u64,
+    pub received_at: Instant,
+}
+
+impl BookSnapshot {
+    /// Since the book was received
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    channel: String,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct L2Book {
+    coin: String,
+    #[serde(default)]
+    time: u64,
+    levels: (Vec<Level>, Vec<Level>),
+}
+
+#[derive(Default)]
+pub struct BookFeed {
+    coins: Mutex<BTreeSet<String>>,
+    books: RwLock<HashMap<String, BookSnapshot>>,
+    /// Subscription messages for the live connection, if any
+    commands: Commands,
+}
+
+impl BookFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a coin; false if it was already subscribed
+    pub fn subscribe(&self, coin: &str) -> bool {
+        let added = self.lock_coins().insert(coin.to_string());
+        if added {
+            self.commands.send(subscription(coin, "subscribe"));
+        }
+        added
+    }
+
+    /// Remove a coin and drop its book; false if it was not subscribed
+    pub fn unsubscribe(&self, coin: &str) -> bool {
+        let removed = self.lock_coins().remove(coin);
+        if removed {
+            if let Ok(mut books) = self.books.write() {
+                books.remove(coin);
+            }
+            self.commands.send(subscription(coin, "unsubscribe"));
+        }
+        removed
+    }
+
+    pub fn is_subscribed(&self, coin: &str) -> bool {
+        self.lock_coins().contains(coin)
+    }
+
+    pub fn coins(&self) -> Vec<String> {
+        self.lock_coins().iter().cloned().collect()
+    }
+
+    /// Book of a coin; None until the venue sent it
+    pub fn book(&self, coin: &str) -> Option<BookSnapshot> {
+        self.books.read().ok()?.get(coin).cloned()
+    }
+
+    fn lock_coins(&self) -> std::sync::MutexGuard<'_, BTreeSet<String>> {
+        self.coins.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Apply one text message; returns the coin whose book changed
+    pub fn apply(&self, text: &str) -> Option<String> {
+        let envelope: Envelope = match serde_json::from_str(text) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                debug!("[BOOK FEED] unreadable message | {}", e);
+                return None;
+            }
+        };
+        if envelope.channel != "l2Book" {
+            return None;
+        }
+        let book: L2Book = match serde_json::from_value(envelope.data?) {
+            Ok(book) => book,
+            Err(e) => {
+                debug!("[BOOK FEED] unreadable l2Book | {}", e);
+                return None;
+            }
+        };
+        // A message in flight when the coin was removed
+        if !self.is_subscribed(&book.coin) {
+            return None;
+        }
+        let snapshot = BookSnapshot {
+            bids: book.levels.0,
+            asks: book.levels.1,
+            time_ms: book.time,
+            received_at: Instant::now(),
+        };
+        let mut books = self.books.write().unwrap_or_else(|e| e.into_inner());
+        books.insert(book.coin.clone(), snapshot);
+        Some(book.coin)
+    }
+
+    /// Connect to `url` and keep every subscribed book until the
+    /// connection fails; `on_update` gets each coin whose book changed
+    pub async fn run(&self, url: &str, mut on_update: impl FnMut(&str)) -> Result<(), String> {
+        let connect = || async {
+            let (ws, _) = tokio_tungstenite::connect_async(url)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(ws)
+        };
+        let subscriptions = || {
+            let coins = self.coins();
+            coins
+                .iter()
+                .map(|coin| subscription(coin, "subscribe"))
+                .collect()
+        };
+        feed::run_ws(&self.commands, connect, subscriptions, KEEPALIVE, |text| {
+            if let Some(coin) = self.apply(text) {
+                on_update(&coin);
+            }
+            Ok(())
+        })
+        .await
+    }
+}
+
+fn subscription(coin: &str, method: &str) -> String {
+    serde_json::json!({
+        "method": method,
+        "subscription": { "type": "l2Book", "coin": coin },
+    })
+    .to_string()
+}
@@ -0,0 +1,271 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Hyperliquid behind the venue-neutral connector traits
+//!
+//! Instruments are perp coins ("BTC", "kPEPE"); quantities are coin units
+//! and prices USDC. Market orders are immediate-or-cancel at the mid moved
+//! by DEFAULT_SLIPPAGE; post-only limit orders go as ALO. Order ids are the
+//! venue's oids. Top of book comes from the L2 book feed, connected on the
+//! first subscribe.
+//!
+//! Config: `secret` is the hex private key, `account` the account traded
+//! (the key's own if None), `testnet` picks the network; `endpoint` is not
+//! used. Trading for a vault needs `Trader::connect` and
+//! `HyperliquidConnector::new`.
+
+use std::sync::Arc;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, Side, TimeInForce as Tif,
+    TopOfBook,
+};
+use tracing::info;
+
+use crate::{
+    BookFeed, HyperliquidError, OrderKind, OrderRequest, TimeInForce, Trader, DEFAULT_SLIPPAGE,
+};
+
+const VENUE: &str = "hyperliquid";
+
+impl From<HyperliquidError> for ConnectorError {
+    fn from(err: HyperliquidError) -> Self {
+        let message = err.to_string();
+        match err {
+            HyperliquidError::InvalidKey(_) => Self::InvalidKey(message),
+            HyperliquidError::InvalidCoin(_) => Self::InvalidInstrument(message),
+            HyperliquidError::InvalidOrder(_) => Self::InvalidOrder(message),
+            HyperliquidError::Rejected(reason) => {
+                if reason.to_ascii_lowercase().contains("insufficient margin") {
+                    Self::InsufficientBalance(message)
+                } else {
+                    Self::Rejected(message)
+                }
+            }
+            HyperliquidError::Api { status, .. } => match status {
+                429 => Self::RateLimited(message),
+                500.. => Self::Venue(message),
+                _ => Self::Rejected(message),
+            },
+            HyperliquidError::Network(_) => Self::Network(message),
+            HyperliquidError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Order request of a spec, refused if Hyperliquid cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    let kind = match order.order_type {
+        OrderType::Market if order.post_only => {
+            return Err(ConnectorError::InvalidOrder(
+                "post-only applies to limit orders".to_string(),
+            ))
+        }
+        OrderType::Market => OrderKind::Market {
+            slippage: DEFAULT_SLIPPAGE,
+        },
+        OrderType::Limit { price } => {
+            let tif = match (order.tif, order.post_only) {
+                (Tif::Gtc, false) => TimeInForce::Gtc,
+                (Tif::Gtc, true) => TimeInForce::Alo,
+                (Tif::Ioc, false) => TimeInForce::Ioc,
+                (tif, _) => {
+                    return Err(ConnectorError::unsupported(
+                        VENUE,
+                        &format!("{:?} orders (post-only: {})", tif, order.post_only),
+                    ))
+                }
+            };
+            OrderKind::Limit { price, tif }
+        }
+    };
+    Ok(OrderRequest {
+        is_buy: order.side == Side::Buy,
+        size: order.quantity,
+        kind,
+        reduce_only: order.reduce_only,
+    })
+}
+
+pub struct HyperliquidConnector {
+    trader: Arc<Trader>,
+    books: Arc<BookFeed>,
+    /// Task keeping the book feed connected, started by the first subscribe
+    book_feed: FeedTask,
+    gate: CallGate,
+}
+
+impl HyperliquidConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        post_only: true,
+        reduce_only: true,
+        cancel_all: true,
+        balances: true,
+        top_of_book: true,
+        testnet: true,
+        ..Capabilities::NONE
+    };
+
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            books: Arc::new(BookFeed::new()),
+            book_feed: FeedTask::new(),
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Books of the subscribed coins
+    pub fn books(&self) -> &Arc<BookFeed> {
+        &self.books
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+}
+
+impl Connector for HyperliquidConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let trader = Trader::connect(
+                config.require_secret()?,
+                config.account.as_deref(),
+                None,
+                config.testnet,
+            )
+            .await?;
+            Ok(Self::new(trader, CallGate::new(config.rate_limit)))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            if self.books.subscribe(instrument) {
+                info!("[BOOK FEED] subscribed | coin={}", instrument);
+            }
+            let books = self.books.clone();
+            let url = self.trader.ws_url();
+            self.book_feed.ensure(|| async move {
+                feed::reconnect("BOOK FEED", || books.run(url, |_| {})).await
+            });
+            Ok(())
+        })
+    }
+
+    fn unsubscribe(&self, instrument: &str) -> Result<(), ConnectorError> {
+        if !self.books.unsubscribe(instrument) {
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        }
+        info!("[BOOK FEED] unsubscribed | coin={}", instrument);
+        Ok(())
+    }
+
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        let Some(book) = self.books.book(instrument) else {
+            if self.books.is_subscribed(instrument) {
+                return Ok(None);
+            }
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        };
+        let bid = book.bids.first().copied().unwrap_or_default();
+        let ask = book.asks.first().copied().unwrap_or_default();
+        Ok(Some(TopOfBook {
+            bid: bid.price,
+            bid_size: bid.size,
+            ask: ask.price,
+            ask_size: ask.size,
+            age: book.age(),
+        }))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for HyperliquidConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let request = order_request(order)?;
+            let place = self.trader.place(&order.instrument, request);
+            let outcome = self.gate.run(Call::OrderPost, place).await?;
+            Ok(PlacedOrder {
+                order_id: outcome.oid.to_string(),
+                client_order_id: String::new(),
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            let oid: u64 = order_id.parse().map_err(|_| {
+                ConnectorError::InvalidArgument(format!("not an order id: {}", order_id))
+            })?;
+            let cancel = self.trader.cancel(instrument, oid);
+            self.gate.run(Call::Cancel, cancel).await
+        })
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            let cancel = self.trader.cancel_all(instrument);
+            let count = self.gate.run(Call::Cancel, cancel).await?;
+            Ok(Some(count.min(u32::MAX as usize) as u32))
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let state = self
+                .gate
+                .run(Call::Balance, self.trader.account_state())
+                .await?;
+            Ok(vec![Balance {
+                asset: "USDC".to_string(),
+                free: state.withdrawable,
+                total: state.account_value,
+            }])
+        })
+    }
+}
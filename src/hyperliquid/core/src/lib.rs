@@ -0,0 +1,390 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Hyperliquid perps executor
+//!
+//! `Trader` signs exchange actions (orders, cancels) with the account's
+//! key, or an API wallet's key approved for it, and reads the account from
+//! the info endpoint: margin, positions, open orders. `book_ws::BookFeed`
+//! keeps L2 books from the WebSocket; `connector::HyperliquidConnector`
+//! puts both behind the shared connector traits. The FFI library wraps it
+//! in the same C ABI conventions as the Polymarket executor, so the engine
+//! can hedge prediction market exposure with perps.
+//!
+//! Prices and sizes are decimals of the coin traded; sizes are rounded to
+//! the coin's size decimals and prices to what the venue accepts (five
+//! significant figures, at most 6 - size decimals places).
+
+pub mod book_ws;
+pub mod connector;
+pub mod orders;
+mod signing;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use alloy::primitives::Address;
+use alloy::signers::local::PrivateKeySigner;
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+pub use book_ws::{BookFeed, BookSnapshot, Level};
+pub use connector::HyperliquidConnector;
+pub use orders::{OpenOrder, OrderKind, OrderOutcome, OrderRequest, TimeInForce, DEFAULT_SLIPPAGE};
+
+pub const MAINNET_URL: &str = "https://api.hyperliquid.xyz";
+pub const TESTNET_URL: &str = "https://api.hyperliquid-testnet.xyz";
+pub const MAINNET_WS_URL: &str = "wss://api.hyperliquid.xyz/ws";
+pub const TESTNET_WS_URL: &str = "wss://api.hyperliquid-testnet.xyz/ws";
+
+/// Timeout of one request, connect included
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, thiserror::Error)]
+pub enum HyperliquidError {
+    #[error("invalid key or address: {0}")]
+    InvalidKey(String),
+    #[error("unknown coin {0}")]
+    InvalidCoin(String),
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// The venue refused the action, with its reason
+    #[error("rejected: {0}")]
+    Rejected(String),
+    /// The venue answered with an error status
+    #[error("venue answered {status}: {message}")]
+    Api { status: u16, message: String },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+/// Decimal sent as a string or a number
+pub(crate) fn decimal<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Text(String),
+        Number(f64),
+    }
+    match Decimal::deserialize(d)? {
+        Decimal::Text(s) => s.parse().map_err(serde::de::Error::custom),
+        Decimal::Number(n) => Ok(n),
+    }
+}
+
+/// Decimal that may be null
+fn optional_decimal<'de, D: Deserializer<'de>>(d: D) -> Result<Option<f64>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapped(#[serde(deserialize_with = "decimal")] f64);
+    Ok(Option::<Wrapped>::deserialize(d)?.map(|Wrapped(n)| n))
+}
+
+/// Perp of the venue's universe
+#[derive(Clone, Copy, Debug)]
+pub struct Asset {
+    /// Asset id used in actions: index in the universe
+    pub index: u32,
+    pub sz_decimals: u32,
+}
+
+#[derive(Deserialize)]
+struct Meta {
+    universe: Vec<UniverseEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UniverseEntry {
+    name: String,
+    sz_decimals: u32,
+    #[serde(default)]
+    is_delisted: bool,
+}
+
+/// Open perp position
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Position {
+    pub coin: String,
+    /// Signed size: positive long, negative short
+    #[serde(rename(deserialize = "szi"), deserialize_with = "decimal")]
+    pub size: f64,
+    #[serde(rename(deserialize = "entryPx"), deserialize_with = "optional_decimal")]
+    pub entry_price: Option<f64>,
+    #[serde(rename(deserialize = "positionValue"), deserialize_with = "decimal")]
+    pub position_value: f64,
+    #[serde(rename(deserialize = "unrealizedPnl"), deserialize_with = "decimal")]
+    pub unrealized_pnl: f64,
+    #[serde(
+        rename(deserialize = "liquidationPx"),
+        deserialize_with = "optional_decimal"
+    )]
+    pub liquidation_price: Option<f64>,
+    #[serde(rename(deserialize = "marginUsed"), deserialize_with = "decimal")]
+    pub margin_used: f64,
+}
+
+/// Margin and positions of the account (USDC)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AccountState {
+    pub account_value: f64,
+    /// Free to withdraw or back new positions
+    pub withdrawable: f64,
+    pub positions: Vec<Position>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClearinghouseState {
+    margin_summary: MarginSummary,
+    #[serde(deserialize_with = "decimal")]
+    withdrawable: f64,
+    #[serde(default)]
+    asset_positions: Vec<AssetPosition>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarginSummary {
+    #[serde(deserialize_with = "decimal")]
+    account_value: f64,
+}
+
+#[derive(Deserialize)]
+struct AssetPosition {
+    position: Position,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    base_url: String,
+    mainnet: bool,
+    signer: PrivateKeySigner,
+    /// Account whose margin and positions are read
+    account: Address,
+    /// Vault or subaccount traded for, if any
+    vault: Option<Address>,
+    assets: RwLock<HashMap<String, Asset>>,
+    last_nonce: AtomicU64,
+}
+
+/// Address argument
+fn address(text: &str) -> Result<Address, HyperliquidError> {
+    Address::from_str(text).map_err(|e| HyperliquidError::InvalidKey(format!("{}: {}", text, e)))
+}
+
+impl Trader {
+    /// Open a session signing with `private_key` (hex) for `account` (the
+    /// key's own address if None; set it when the key is an API wallet),
+    /// trading for `vault` if set, checked by reading the account
+    /// The key string is not retained
+    pub async fn connect(
+        private_key: &str,
+        account: Option<&str>,
+        vault: Option<&str>,
+        testnet: bool,
+    ) -> Result<Self, HyperliquidError> {
+        let signer = PrivateKeySigner::from_str(private_key.trim())
+            .map_err(|_| HyperliquidError::InvalidKey("malformed private key".to_string()))?;
+        let account = match account {
+            Some(account) => address(account)?,
+            None => signer.address(),
+        };
+        let vault = vault.map(address).transpose()?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
+        let trader = Self {
+            http,
+            base_url: if testnet { TESTNET_URL } else { MAINNET_URL }.to_string(),
+            mainnet: !testnet,
+            signer,
+            account,
+            vault,
+            assets: RwLock::new(HashMap::new()),
+            last_nonce: AtomicU64::new(0),
+        };
+        trader.load_assets().await?;
+        let state = trader.account_state().await?;
+        info!(
+            "[CONNECT] hyperliquid | {} | signer={} | account={} | value=${:.2} | positions={}",
+            if testnet { "testnet" } else { "mainnet" },
+            trader.signer.address(),
+            trader.user(),
+            state.account_value,
+            state.positions.len()
+        );
+        Ok(trader)
+    }
+
+    /// WebSocket url of the network the session trades on
+    pub fn ws_url(&self) -> &'static str {
+        if self.mainnet {
+            MAINNET_WS_URL
+        } else {
+            TESTNET_WS_URL
+        }
+    }
+
+    /// Address the account reads go to: the vault if trading for one
+    fn user(&self) -> Address {
+        self.vault.unwrap_or(self.account)
+    }
+
+    /// Milliseconds since the epoch, strictly increasing per session
+    pub(crate) fn next_nonce(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let mut last = self.last_nonce.load(Ordering::Relaxed);
+        loop {
+            let nonce = now.max(last + 1);
+            match self.last_nonce.compare_exchange_weak(
+                last,
+                nonce,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return nonce,
+                Err(current) => last = current,
+            }
+        }
+    }
+
+    async fn post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &impl Serialize,
+    ) -> Result<T, HyperliquidError> {
+        let resp = self
+            .http
+            .post(format!("{}{}", self.base_url, path))
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
+        if !status.is_success() {
+            return Err(HyperliquidError::Api {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+        serde_json::from_str(&text).map_err(|e| HyperliquidError::Decode(e.to_string()))
+    }
+
+    /// Query the info endpoint
+    pub(crate) async fn info<T: DeserializeOwned>(
+        &self,
+        body: Value,
+    ) -> Result<T, HyperliquidError> {
+        self.post("/info", &body).await
+    }
+
+    /// Sign and send an action; returns the venue's `response` field
+    pub(crate) async fn exchange<A: Serialize>(
+        &self,
+        action: &A,
+    ) -> Result<Value, HyperliquidError> {
+        let nonce = self.next_nonce();
+        let signature =
+            signing::sign_action(&self.signer, action, nonce, self.vault, self.mainnet)?;
+        let mut body = serde_json::json!({
+            "action": action,
+            "nonce": nonce,
+            "signature": signature,
+        });
+        if let Some(vault) = self.vault {
+            body["vaultAddress"] = vault.to_string().into();
+        }
+        let resp: Value = self.post("/exchange", &body).await?;
+        match resp.get("status").and_then(Value::as_str) {
+            Some("ok") => Ok(resp.get("response").cloned().unwrap_or(Value::Null)),
+            _ => Err(HyperliquidError::Rejected(match resp.get("response") {
+                Some(Value::String(reason)) => reason.clone(),
+                _ => resp.to_string(),
+            })),
+        }
+    }
+
+    /// Read the perp universe: asset ids and size decimals
+    async fn load_assets(&self) -> Result<(), HyperliquidError> {
+        let meta: Meta = self.info(serde_json::json!({ "type": "meta" })).await?;
+        let assets: HashMap<String, Asset> = meta
+            .universe
+            .into_iter()
+            .enumerate()
+            .filter(|(_, entry)| !entry.is_delisted)
+            .map(|(index, entry)| {
+                let asset = Asset {
+                    index: index as u32,
+                    sz_decimals: entry.sz_decimals,
+                };
+                (entry.name, asset)
+            })
+            .collect();
+        *self.assets.write().unwrap_or_else(|e| e.into_inner()) = assets;
+        Ok(())
+    }
+
+    /// Asset of a perp by coin name ("BTC"); the universe is read again
+    /// once for a coin listed since connecting
+    pub async fn asset(&self, coin: &str) -> Result<Asset, HyperliquidError> {
+        let cached = |trader: &Self| {
+            let assets = trader.assets.read().unwrap_or_else(|e| e.into_inner());
+            assets.get(coin).copied()
+        };
+        if let Some(asset) = cached(self) {
+            return Ok(asset);
+        }
+        self.load_assets().await?;
+        cached(self).ok_or_else(|| HyperliquidError::InvalidCoin(coin.to_string()))
+    }
+
+    /// Margin summary and open positions
+    pub async fn account_state(&self) -> Result<AccountState, HyperliquidError> {
+        let state: ClearinghouseState = self
+            .info(serde_json::json!({ "type": "clearinghouseState", "user": self.user() }))
+            .await?;
+        Ok(AccountState {
+            account_value: state.margin_summary.account_value,
+            withdrawable: state.withdrawable,
+            positions: state
+                .asset_positions
+                .into_iter()
+                .map(|p| p.position)
+                .filter(|p| p.size != 0.0)
+                .collect(),
+        })
+    }
+
+    /// Mid price of every perp, by coin
+    pub async fn mids(&self) -> Result<HashMap<String, f64>, HyperliquidError> {
+        let mids: HashMap<String, String> =
+            self.info(serde_json::json!({ "type": "allMids" })).await?;
+        Ok(mids
+            .into_iter()
+            .filter_map(|(coin, mid)| Some((coin, mid.parse().ok()?)))
+            .collect())
+    }
+}
@@ -0,0 +1,361 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders, cancels and open orders
+//!
+//! Every order is a limit order. A market order is sent immediate-or-cancel
+//! at the mid price moved by the caller's slippage: it takes what the book
+//! holds up to that price and never rests. Limit prices are rounded to the
+//! nearest price the venue accepts, sizes to the coin's size decimals.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+use crate::{decimal, HyperliquidError, Trader};
+
+/// Significant figures a price may have
+const PRICE_SIG_FIGS: i32 = 5;
+
+/// Decimal places a perp price may have, less the coin's size decimals
+const MAX_PRICE_DECIMALS: i32 = 6;
+
+/// Slippage of market orders when the caller gives none
+pub const DEFAULT_SLIPPAGE: f64 = 0.05;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeInForce {
+    /// Rests until filled or cancelled
+    Gtc,
+    /// What does not fill at once is cancelled
+    Ioc,
+    /// Add liquidity only: cancelled instead of taking
+    Alo,
+}
+
+impl TimeInForce {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Gtc => "Gtc",
+            Self::Ioc => "Ioc",
+            Self::Alo => "Alo",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderKind {
+    /// Immediate-or-cancel at the mid moved by `slippage` (0.05 = 5%)
+    Market {
+        slippage: f64,
+    },
+    Limit {
+        price: f64,
+        tif: TimeInForce,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub is_buy: bool,
+    /// Coin units, > 0
+    pub size: f64,
+    pub kind: OrderKind,
+    /// Only reduces the position held
+    pub reduce_only: bool,
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderOutcome {
+    pub oid: u64,
+    /// resting or filled
+    pub status: String,
+    pub filled_size: f64,
+    /// Average fill price, 0 if nothing filled
+    pub avg_price: f64,
+}
+
+/// Order open on the book
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OpenOrder {
+    pub coin: String,
+    /// "B" bid, "A" ask
+    pub side: String,
+    #[serde(rename(deserialize = "limitPx"), deserialize_with = "decimal")]
+    pub price: f64,
+    /// Size left
+    #[serde(rename(deserialize = "sz"), deserialize_with = "decimal")]
+    pub size: f64,
+    pub oid: u64,
+    pub timestamp: u64,
+}
+
+// Actions, with fields in the order the venue hashes them
+
+#[derive(Serialize)]
+struct OrderAction {
+    r#type: &'static str,
+    orders: Vec<OrderWire>,
+    grouping: &'static str,
+}
+
+#[derive(Serialize)]
+struct OrderWire {
+    a: u32,
+    b: bool,
+    p: String,
+    s: String,
+    r: bool,
+    t: OrderTypeWire,
+}
+
+#[derive(Serialize)]
+struct OrderTypeWire {
+    limit: LimitWire,
+}
+
+#[derive(Serialize)]
+struct LimitWire {
+    tif: &'static str,
+}
+
+#[derive(Serialize)]
+struct CancelAction {
+    r#type: &'static str,
+    cancels: Vec<CancelWire>,
+}
+
+#[derive(Serialize)]
+struct CancelWire {
+    a: u32,
+    o: u64,
+}
+
+/// Round to `decimals` places (negative: to tens, hundreds...)
+fn round_to(x: f64, decimals: i32) -> f64 {
+    let scale = 10f64.powi(decimals);
+    (x * scale).round() / scale
+}
+
+/// Nearest price the venue accepts for a coin with `sz_decimals`
+pub fn round_price(price: f64, sz_decimals: u32) -> f64 {
+    if price <= 0.0 {
+        return 0.0;
+    }
+    let magnitude = price.log10().floor() as i32;
+    let significant = round_to(price, PRICE_SIG_FIGS - 1 - magnitude);
+    round_to(significant, MAX_PRICE_DECIMALS - sz_decimals as i32)
+}
+
+/// Decimal as the venue reads it: no exponent, no trailing zeros
+fn wire(x: f64) -> String {
+    let text = format!("{:.8}", x);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" | "" => "0".to_string(),
+        _ => text.to_string(),
+    }
+}
+
+/// Statuses of an order or cancel response, one per entry sent
+fn statuses(response: &Value) -> Result<&Vec<Value>, HyperliquidError> {
+    response
+        .pointer("/data/statuses")
+        .and_then(Value::as_array)
+        .ok_or_else(|| HyperliquidError::Decode(format!("no statuses in {}", response)))
+}
+
+fn parse_fill(status: &Value) -> Result<OrderOutcome, HyperliquidError> {
+    if let Some(error) = status.get("error").and_then(Value::as_str) {
+        return Err(HyperliquidError::Rejected(error.to_string()));
+    }
+    let number = |v: Option<&Value>| -> f64 {
+        match v {
+            Some(Value::String(s)) => s.parse().unwrap_or(0.0),
+            Some(Value::Number(n)) => n.as_f64().unwrap_or(0.0),
+            _ => 0.0,
+        }
+    };
+    if let Some(resting) = status.get("resting") {
+        return Ok(OrderOutcome {
+            oid: resting.get("oid").and_then(Value::as_u64).unwrap_or(0),
+            status: "resting".to_string(),
+            ..Default::default()
+        });
+    }
+    if let Some(filled) = status.get("filled") {
+        return Ok(OrderOutcome {
+            oid: filled.get("oid").and_then(Value::as_u64).unwrap_or(0),
+            status: "filled".to_string(),
+            filled_size: number(filled.get("totalSz")),
+            avg_price: number(filled.get("avgPx")),
+        });
+    }
+    Err(HyperliquidError::Decode(format!("order status {}", status)))
+}
+
+impl Trader {
+    /// Check and place an order on the perp `coin`
+    pub async fn place(
+        &self,
+        coin: &str,
+        request: OrderRequest,
+    ) -> Result<OrderOutcome, HyperliquidError> {
+        let asset = self.asset(coin).await?;
+        let size = round_to(request.size, asset.sz_decimals as i32);
+        if !request.size.is_finite() || size <= 0.0 {
+            return Err(HyperliquidError::InvalidOrder(format!(
+                "size {} is 0 at {} decimals",
+                request.size, asset.sz_decimals
+            )));
+        }
+        let (price, tif) = match request.kind {
+            OrderKind::Limit { price, tif } => {
+                if !price.is_finite() || price <= 0.0 {
+                    return Err(HyperliquidError::InvalidOrder(format!(
+                        "price must be > 0, got {}",
+                        price
+                    )));
+                }
+                (price, tif)
+            }
+            OrderKind::Market { slippage } => {
+                if !(0.0..1.0).contains(&slippage) {
+                    return Err(HyperliquidError::InvalidOrder(format!(
+                        "slippage must be 0-1, got {}",
+                        slippage
+                    )));
+                }
+                let mid = self
+                    .mids()
+                    .await?
+                    .get(coin)
+                    .copied()
+                    .ok_or_else(|| HyperliquidError::InvalidCoin(coin.to_string()))?;
+                let bound = if request.is_buy {
+                    1.0 + slippage
+                } else {
+                    1.0 - slippage
+                };
+                (mid * bound, TimeInForce::Ioc)
+            }
+        };
+        let price = round_price(price, asset.sz_decimals);
+
+        let action = OrderAction {
+            r#type: "order",
+            orders: vec![OrderWire {
+                a: asset.index,
+                b: request.is_buy,
+                p: wire(price),
+                s: wire(size),
+                r: request.reduce_only,
+                t: OrderTypeWire {
+                    limit: LimitWire { tif: tif.as_str() },
+                },
+            }],
+            grouping: "na",
+        };
+        let response = self.exchange(&action).await?;
+        let outcome = match statuses(&response)?.first() {
+            Some(status) => parse_fill(status)?,
+            None => return Err(HyperliquidError::Decode("empty statuses".to_string())),
+        };
+        info!(
+            "[ORDER] {} {} | coin={} | size={} | price={} | tif={} | reduce_only={} | oid={} | \
+             status={} | filled={}",
+            match request.kind {
+                OrderKind::Market { .. } => "market",
+                OrderKind::Limit { .. } => "limit",
+            },
+            if request.is_buy { "buy" } else { "sell" },
+            coin,
+            size,
+            price,
+            tif.as_str(),
+            request.reduce_only,
+            outcome.oid,
+            outcome.status,
+            outcome.filled_size
+        );
+        Ok(outcome)
+    }
+
+    /// Cancel a resting order by coin and order id
+    pub async fn cancel(&self, coin: &str, oid: u64) -> Result<(), HyperliquidError> {
+        let asset = self.asset(coin).await?;
+        let cancelled = self
+            .cancel_batch(vec![CancelWire {
+                a: asset.index,
+                o: oid,
+            }])
+            .await?;
+        if let Some(Err(reason)) = cancelled.into_iter().next() {
+            return Err(HyperliquidError::Rejected(reason));
+        }
+        info!("[CANCEL] coin={} | oid={}", coin, oid);
+        Ok(())
+    }
+
+    /// Cancel every open order of `coin`, or of every coin if None
+    /// Returns the number cancelled; orders the venue refused to cancel
+    /// (filled meanwhile) are left out
+    pub async fn cancel_all(&self, coin: Option<&str>) -> Result<usize, HyperliquidError> {
+        let mut cancels = Vec::new();
+        for order in self.open_orders().await? {
+            if coin.is_some_and(|coin| coin != order.coin) {
+                continue;
+            }
+            let asset = self.asset(&order.coin).await?;
+            cancels.push(CancelWire {
+                a: asset.index,
+                o: order.oid,
+            });
+        }
+        if cancels.is_empty() {
+            return Ok(0);
+        }
+        let cancelled = self.cancel_batch(cancels).await?;
+        let count = cancelled.iter().filter(|c| c.is_ok()).count();
+        info!(
+            "[CANCEL_ALL] coin={} | cancelled={}/{}",
+            coin.unwrap_or("*"),
+            count,
+            cancelled.len()
+        );
+        Ok(count)
+    }
+
+    /// Send one cancel action; the outcome of each entry, in order
+    async fn cancel_batch(
+        &self,
+        cancels: Vec<CancelWire>,
+    ) -> Result<Vec<Result<(), String>>, HyperliquidError> {
+        let action = CancelAction {
+            r#type: "cancel",
+            cancels,
+        };
+        let response = self.exchange(&action).await?;
+        Ok(statuses(&response)?
+            .iter()
+            .map(|status| match status.get("error").and_then(Value::as_str) {
+                Some(error) => Err(error.to_string()),
+                None => Ok(()),
+            })
+            .collect())
+    }
+
+    /// Orders open on the account
+    pub async fn open_orders(&self) -> Result<Vec<OpenOrder>, HyperliquidError> {
+        self.info(serde_json::json!({ "type": "openOrders", "user": self.user() }))
+            .await
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Signing of exchange actions
+//!
+//! An action (order, cancel) is hashed as MessagePack, followed by the
+//! nonce (8 bytes, big-endian) and the vault address (0x00 if none, else
+//! 0x01 and its 20 bytes). The key signs, by EIP-712, a "phantom agent"
+//! carrying that hash: source "a" on mainnet, "b" on testnet, in the
+//! domain "Exchange" version 1 of chain 1337. Field order matters: the
+//! action structs serialize their fields in the order the venue hashes.
+
+use alloy::primitives::{keccak256, Address, B256};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::SignerSync;
+use alloy::sol;
+use alloy::sol_types::{eip712_domain, SolStruct};
+use serde::Serialize;
+
+use crate::HyperliquidError;
+
+sol! {
+    struct Agent {
+        string source;
+        bytes32 connectionId;
+    }
+}
+
+/// Signature as the exchange endpoint takes it
+#[derive(Clone, Debug, Serialize)]
+pub struct ActionSignature {
+    pub r: String,
+    pub s: String,
+    pub v: u8,
+}
+
+/// Hash of an action with its nonce and vault
+fn action_hash<T: Serialize>(
+    action: &T,
+    nonce: u64,
+    vault: Option<Address>,
+) -> Result<B256, HyperliquidError> {
+    let mut data = rmp_serde::to_vec_named(action)
+        .map_err(|e| HyperliquidError::InvalidOrder(format!("encoding: {}", e)))?;
+    data.extend_from_slice(&nonce.to_be_bytes());
+    match vault {
+        Some(vault) => {
+            data.push(1);
+            data.extend_from_slice(vault.as_slice());
+        }
+        None => data.push(0),
+    }
+    Ok(keccak256(data))
+}
+
+/// Sign an action as an L1 action of `signer`
+pub(crate) fn sign_action<T: Serialize>(
+    signer: &PrivateKeySigner,
+    action: &T,
+    nonce: u64,
+    vault: Option<Address>,
+    mainnet: bool,
+) -> Result<ActionSignature, HyperliquidError> {
+    let agent = Agent {
+        source: if mainnet { "a" } else { "b" }.to_string(),
+        connectionId: action_hash(action, nonce, vault)?,
+    };
+    let domain = eip712_domain! {
+        name: "Exchange",
+        version: "1",
+        chain_id: 1337,
+        verifying_contract: Address::ZERO,
+    };
+    let signature = signer
+        .sign_hash_sync(&agent.eip712_signing_hash(&domain))
+        .map_err(|e| HyperliquidError::InvalidKey(e.to_string()))?;
+    Ok(ActionSignature {
+        r: format!("0x{:064x}", signature.r()),
+        s: format!("0x{:064x}", signature.s()),
+        v: 27 + signature.v() as u8,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{address, b256};
+
+    use super::*;
+
+    /// Action of the venue SDK's L1 signing test (1000 scaled by 1e8)
+    #[derive(Serialize)]
+    struct Dummy {
+        #[serde(rename = "type")]
+        kind: &'static str,
+        num: u64,
+    }
+
+    const ACTION: Dummy = Dummy {
+        kind: "dummy",
+        num: 100_000_000_000,
+    };
+
+    fn signer() -> PrivateKeySigner {
+        "0x0123456789012345678901234567890123456789012345678901234567890123"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn action_hash_is_stable() {
+        assert_eq!(
+            action_hash(&ACTION, 0, None).unwrap(),
+            b256!("f528daee6a0bd11407b483cfcd9a48c56884180b70ee86f124053e5fc1bf4d57")
+        );
+        let vault = address!("1719884eb866cb12b2287399b15f7db5e7d775ea");
+        assert_eq!(
+            action_hash(&ACTION, 0, Some(vault)).unwrap(),
+            b256!("de9e09a7a3da45cc694096d4bfdcd89bc1b892d05497c5ecc1f56c335945184c")
+        );
+    }
+
+    #[test]
+    fn signs_like_the_venue_sdk() {
+        let mainnet = sign_action(&signer(), &ACTION, 0, None, true).unwrap();
+        assert_eq!(
+            mainnet.r,
+            "0x053749d5b30552aeb2fca34b530185976545bb22d0b3ce6f62e31be961a59298"
+        );
+        assert_eq!(
+            mainnet.s,
+            "0x755c40ba9bf05223521753995abb2f73ab3229be8ec921f350cb447e384d8ed8"
+        );
+        assert_eq!(mainnet.v, 27);
+
+        let testnet = sign_action(&signer(), &ACTION, 0, None, false).unwrap();
+        assert_eq!(
+            testnet.r,
+            "0x542af61ef1f429707e3c76c5293c80d01f74ef853e34b76efffcb57e574f9510"
+        );
+        assert_eq!(
+            testnet.s,
+            "0x17b8b32f086e8cdede991f1e2c529f5dd5297cbe8128500e00cbaf766204a613"
+        );
+        assert_eq!(testnet.v, 28);
+    }
+}
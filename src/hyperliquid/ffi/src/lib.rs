@@ -0,0 +1,592 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Hyperliquid perps executor - C FFI
+//!
+//! The C ABI of hyperliquid_ffi.h, laid out like the Polymarket executor's:
+//! one global `HyperliquidConnector` set up by hyperliquid_init(), blocking
+//! calls that run on the shared connector runtime, negative error codes
+//! (the same numbers as POLYMARKET_ERR_* where the meaning is shared), raw
+//! amounts with 6 decimals, thread-local last error and last response
+//! buffers. Books are kept by a background task of the connector, as
+//! polymarket_subscribe_book() does.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Instant;
+
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, CallGate, Connector, ConnectorError, RateLimit};
+use hyperliquid_executor_core::{
+    HyperliquidConnector, HyperliquidError, Level, OrderKind, OrderOutcome, OrderRequest,
+    TimeInForce, Trader, DEFAULT_SLIPPAGE,
+};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+// Error codes
+pub const HYPERLIQUID_OK: i32 = 0;
+/// Not an error: no data yet
+pub const HYPERLIQUID_PENDING: i32 = 1;
+pub const HYPERLIQUID_ERR_NOT_INITIALIZED: i32 = -1;
+/// Key or address malformed
+pub const HYPERLIQUID_ERR_INVALID_KEY: i32 = -2;
+/// Not a listed perp
+pub const HYPERLIQUID_ERR_INVALID_COIN: i32 = -4;
+pub const HYPERLIQUID_ERR_ORDER_FAILED: i32 = -5;
+pub const HYPERLIQUID_ERR_CANCEL_FAILED: i32 = -6;
+/// Not enough margin
+pub const HYPERLIQUID_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+pub const HYPERLIQUID_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const HYPERLIQUID_ERR_PANIC: i32 = -14;
+/// Venue refused the action, see last error
+pub const HYPERLIQUID_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const HYPERLIQUID_ERR_NETWORK: i32 = -20;
+/// Venue answered 429
+pub const HYPERLIQUID_ERR_RATE_LIMITED: i32 = -21;
+/// Venue answered 5xx
+pub const HYPERLIQUID_ERR_VENUE_ERROR: i32 = -22;
+/// Size, price or slippage not valid (checked locally)
+pub const HYPERLIQUID_ERR_INVALID_ORDER: i32 = -23;
+
+// Order flags
+/// Only reduces the position held
+pub const HYPERLIQUID_FLAG_REDUCE_ONLY: u32 = 1;
+/// Limit only: cancelled instead of taking (ALO)
+pub const HYPERLIQUID_FLAG_POST_ONLY: u32 = 2;
+/// Limit only: what does not fill at once is cancelled
+pub const HYPERLIQUID_FLAG_IOC: u32 = 4;
+
+/// Levels per side in a HyperliquidBookSnapshot
+pub const HYPERLIQUID_BOOK_DEPTH: usize = 10;
+
+/// Raw units per coin or USDC unit
+const RAW_SCALE: f64 = 1_000_000.0;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct HyperliquidOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Venue order id
+    pub oid: u64,
+    /// "resting" or "filled"
+    pub status: [c_char; 16],
+    /// Coin units filled (6 decimals)
+    pub filled_size_raw: i64,
+    /// Average fill price (6 decimals), 0 if nothing filled
+    pub avg_price_raw: i64,
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl HyperliquidOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            oid: 0,
+            status: [0; 16],
+            filled_size_raw: 0,
+            avg_price_raw: 0,
+            latency_ms: 0,
+        }
+    }
+
+    fn placed(outcome: &OrderOutcome, latency_ms: u64) -> Self {
+        let mut result = Self::failed(HYPERLIQUID_OK);
+        result.success = true;
+        result.oid = outcome.oid;
+        out_buf::write_fixed(&outcome.status, &mut result.status);
+        result.filled_size_raw = to_raw(outcome.filled_size);
+        result.avg_price_raw = to_raw(outcome.avg_price);
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct HyperliquidBookLevel {
+    pub price_raw: i64,
+    /// Coin units
+    pub size_raw: i64,
+}
+
+/// Top levels of a subscribed coin's book, filled by
+/// hyperliquid_get_book_snapshot()
+#[repr(C)]
+#[derive(Default)]
+pub struct HyperliquidBookSnapshot {
+    /// Levels set in bids, best (highest) first
+    pub bid_count: u32,
+    /// Levels set in asks, best (lowest) first
+    pub ask_count: u32,
+    /// Since the book was received
+    pub age_ms: u64,
+    /// Venue time of the book (ms since the epoch)
+    pub time_ms: u64,
+    pub bids: [HyperliquidBookLevel; HYPERLIQUID_BOOK_DEPTH],
+    pub asks: [HyperliquidBookLevel; HYPERLIQUID_BOOK_DEPTH],
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "hyperliquid";
+
+fn get_executor() -> Option<Arc<HyperliquidConnector>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("hyperliquid_executor=info"))
+        .try_init();
+}
+
+fn to_raw(x: f64) -> i64 {
+    (x * RAW_SCALE).round() as i64
+}
+
+/// Coin argument: 1-32 printable ASCII characters ("BTC", "kPEPE")
+fn coin_arg<'a>(coin: *const c_char) -> Option<&'a str> {
+    str_arg(coin).filter(|c| (1..=32).contains(&c.len()) && c.bytes().all(|b| b.is_ascii_graphic()))
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect with `private_key` (hex) on mainnet, or testnet if `testnet`
+/// `account_address`: account traded, NULL for the key's own; set it when
+/// the key is an API wallet. `vault_address`: vault or subaccount to trade
+/// for, NULL for none. Replaces a session already set up. Returns 0 on
+/// success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_init(
+    private_key: *const c_char,
+    account_address: *const c_char,
+    vault_address: *const c_char,
+    testnet: bool,
+) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        init_logging();
+        let Some(key) = str_arg(private_key) else {
+            return HYPERLIQUID_ERR_INVALID_KEY;
+        };
+        let key = Zeroizing::new(key.to_string());
+        let optional = |arg: *const c_char| match arg.is_null() {
+            true => Ok(None),
+            false => str_arg(arg).map(|s| Some(s.to_string())).ok_or(()),
+        };
+        let (Ok(account), Ok(vault)) = (optional(account_address), optional(vault_address)) else {
+            return HYPERLIQUID_ERR_INVALID_KEY;
+        };
+
+        // Connected directly rather than through Connector::connect, which
+        // has no vault
+        let connected = runtime::block_on(async move {
+            Trader::connect(&key, account.as_deref(), vault.as_deref(), testnet).await
+        });
+        let trader = match connected {
+            Ok(Ok(trader)) => trader,
+            Ok(Err(e)) => return fail("INIT", &e.into(), HYPERLIQUID_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return HYPERLIQUID_ERR_NOT_INITIALIZED;
+            }
+        };
+
+        let gate = CallGate::new(RateLimit::OFF);
+        let executor = Arc::new(HyperliquidConnector::new(trader, gate));
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] hyperliquid executor ready");
+        HYPERLIQUID_OK
+    })
+}
+
+/// Shut the session down and close the book feed; calls still running
+/// finish first. hyperliquid_init() can be called again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<HyperliquidConnector>>(VENUE);
+        if executor.is_some() {
+            info!("[SHUTDOWN] hyperliquid executor stopped");
+        }
+    })
+}
+
+/// Place an order and fill `out`
+fn place(
+    op: &'static str,
+    coin: *const c_char,
+    request: OrderRequest,
+    out: *mut HyperliquidOrderResult,
+) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return HYPERLIQUID_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = HyperliquidOrderResult::failed(HYPERLIQUID_ERR_NOT_INITIALIZED);
+            return HYPERLIQUID_ERR_NOT_INITIALIZED;
+        }
+    };
+    let Some(coin) = coin_arg(coin) else {
+        *out = HyperliquidOrderResult::failed(HYPERLIQUID_ERR_INVALID_COIN);
+        return HYPERLIQUID_ERR_INVALID_COIN;
+    };
+
+    let started = Instant::now();
+    let coin_owned = coin.to_string();
+    let result = runtime::block_on(async move {
+        let place = executor.trader().place(&coin_owned, request);
+        executor.gate().run(Call::OrderPost, place).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(outcome)) => HyperliquidOrderResult::placed(&outcome, latency_ms),
+        Ok(Err(e)) => HyperliquidOrderResult::failed(fail(op, &e, HYPERLIQUID_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | coin={} | {}", op, coin, e);
+            out_buf::set_last_error(&e);
+            HyperliquidOrderResult::failed(HYPERLIQUID_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+/// Market order of `size` coin units: immediate-or-cancel at the mid moved
+/// by `slippage` (0.05 = 5%, 0 for the default 5%)
+/// `flags`: HYPERLIQUID_FLAG_REDUCE_ONLY or 0
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_market_order(
+    coin: *const c_char,
+    is_buy: bool,
+    size: f64,
+    slippage: f64,
+    flags: u32,
+    out: *mut HyperliquidOrderResult,
+) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        if flags & !HYPERLIQUID_FLAG_REDUCE_ONLY != 0 {
+            return HYPERLIQUID_ERR_INVALID_ARGUMENT;
+        }
+        let slippage = if slippage == 0.0 { DEFAULT_SLIPPAGE } else { slippage };
+        let request = OrderRequest {
+            is_buy,
+            size,
+            kind: OrderKind::Market { slippage },
+            reduce_only: flags & HYPERLIQUID_FLAG_REDUCE_ONLY != 0,
+        };
+        place("MARKET", coin, request, out)
+    })
+}
+
+/// Limit order of `size` coin units at `price`, rounded to the nearest
+/// price the venue accepts
+/// `flags`: HYPERLIQUID_FLAG_* combined; POST_ONLY and IOC exclude each other
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_limit_order(
+    coin: *const c_char,
+    is_buy: bool,
+    size: f64,
+    price: f64,
+    flags: u32,
+    out: *mut HyperliquidOrderResult,
+) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        let all = HYPERLIQUID_FLAG_REDUCE_ONLY | HYPERLIQUID_FLAG_POST_ONLY | HYPERLIQUID_FLAG_IOC;
+        let tif = match flags & (HYPERLIQUID_FLAG_POST_ONLY | HYPERLIQUID_FLAG_IOC) {
+            0 => TimeInForce::Gtc,
+            HYPERLIQUID_FLAG_POST_ONLY => TimeInForce::Alo,
+            HYPERLIQUID_FLAG_IOC => TimeInForce::Ioc,
+            _ => return HYPERLIQUID_ERR_INVALID_ARGUMENT,
+        };
+        if flags & !all != 0 {
+            return HYPERLIQUID_ERR_INVALID_ARGUMENT;
+        }
+        let request = OrderRequest {
+            is_buy,
+            size,
+            kind: OrderKind::Limit { price, tif },
+            reduce_only: flags & HYPERLIQUID_FLAG_REDUCE_ONLY != 0,
+        };
+        place("LIMIT", coin, request, out)
+    })
+}
+
+/// Cancel a resting order of `coin` by its order id
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_cancel(coin: *const c_char, oid: u64) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return HYPERLIQUID_ERR_NOT_INITIALIZED,
+        };
+        let Some(coin) = coin_arg(coin) else {
+            return HYPERLIQUID_ERR_INVALID_COIN;
+        };
+
+        let coin_owned = coin.to_string();
+        let result = runtime::block_on(async move {
+            let cancel = executor.trader().cancel(&coin_owned, oid);
+            executor.gate().run(Call::Cancel, cancel).await
+        });
+        match result {
+            Ok(Ok(())) => HYPERLIQUID_OK,
+            Ok(Err(e)) => fail("CANCEL", &e, HYPERLIQUID_ERR_CANCEL_FAILED),
+            Err(e) => {
+                error!("[CANCEL] failed | coin={} | oid={} | {}", coin, oid, e);
+                out_buf::set_last_error(&e);
+                HYPERLIQUID_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cancel every open order of `coin`, or of every coin if NULL
+/// Returns the number cancelled, or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_cancel_all(coin: *const c_char) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return HYPERLIQUID_ERR_NOT_INITIALIZED,
+        };
+        let coin = match coin.is_null() {
+            true => None,
+            false => match coin_arg(coin) {
+                Some(coin) => Some(coin.to_string()),
+                None => return HYPERLIQUID_ERR_INVALID_COIN,
+            },
+        };
+
+        let result = runtime::block_on(async move {
+            let cancel = executor.trader().cancel_all(coin.as_deref());
+            executor.gate().run(Call::Cancel, cancel).await
+        });
+        match result {
+            Ok(Ok(count)) => count.min(i32::MAX as usize) as i32,
+            Ok(Err(e)) => fail("CANCEL_ALL", &e, HYPERLIQUID_ERR_CANCEL_FAILED),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                HYPERLIQUID_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Read the account's margin summary; `pick` selects the amount returned
+fn account_amount(op: &str, pick: fn(f64, f64) -> f64) -> i64 {
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return HYPERLIQUID_ERR_NOT_INITIALIZED as i64,
+    };
+
+    let result = runtime::block_on(async move {
+        executor.gate().run(Call::Balance, executor.trader().account_state()).await
+    });
+    match result {
+        Ok(Ok(state)) => to_raw(pick(state.account_value, state.withdrawable)),
+        Ok(Err(e)) => fail(op, &e, HYPERLIQUID_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            HYPERLIQUID_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// USDC free to back new positions (raw, 6 decimals), or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_get_balance() -> i64 {
+    guard::catch(HYPERLIQUID_ERR_PANIC as i64, || {
+        account_amount("BALANCE", |_, withdrawable| withdrawable)
+    })
+}
+
+/// Account value, unrealized PnL included (raw USDC, 6 decimals), or
+/// negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_get_account_value() -> i64 {
+    guard::catch(HYPERLIQUID_ERR_PANIC as i64, || {
+        account_amount("ACCOUNT_VALUE", |value, _| value)
+    })
+}
+
+/// Run a query and keep its JSON for hyperliquid_last_response()
+fn query_json<Fut, T>(op: &str, query: impl FnOnce(Arc<Trader>) -> Fut) -> i64
+where
+    Fut: Future<Output = Result<T, HyperliquidError>> + Send + 'static,
+    T: serde::Serialize + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return HYPERLIQUID_ERR_NOT_INITIALIZED as i64,
+    };
+    let query = query(executor.trader().clone());
+    match runtime::block_on(async move { query.await.map_err(ConnectorError::from) }) {
+        Ok(Ok(value)) => match serde_json::to_string(&value) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => HYPERLIQUID_ERR_VENUE_ERROR as i64,
+        },
+        Ok(Err(e)) => fail(op, &e, HYPERLIQUID_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            HYPERLIQUID_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// Positions as JSON [{"coin", "size", "entry_price", "position_value",
+/// "unrealized_pnl", "liquidation_price", "margin_used"}]; returns its
+/// size (hyperliquid_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_get_positions() -> i64 {
+    guard::catch(HYPERLIQUID_ERR_PANIC as i64, || {
+        query_json("POSITIONS", |trader| async move {
+            trader.account_state().await.map(|state| state.positions)
+        })
+    })
+}
+
+/// Open orders as JSON [{"coin", "side", "price", "size", "oid",
+/// "timestamp"}]; returns its size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_get_open_orders() -> i64 {
+    guard::catch(HYPERLIQUID_ERR_PANIC as i64, || {
+        query_json("OPEN_ORDERS", |trader| async move { trader.open_orders().await })
+    })
+}
+
+/// Keep the L2 book of a coin from the WebSocket
+/// The first subscription opens the connection; it reconnects on its own
+/// and closes on shutdown. Returns HYPERLIQUID_OK, also if already
+/// subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_subscribe_book(coin: *const c_char) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return HYPERLIQUID_ERR_NOT_INITIALIZED,
+        };
+        let Some(coin) = coin_arg(coin) else {
+            return HYPERLIQUID_ERR_INVALID_COIN;
+        };
+
+        let coin_owned = coin.to_string();
+        match runtime::block_on(async move { executor.subscribe(&coin_owned).await }) {
+            Ok(Ok(())) => HYPERLIQUID_OK,
+            Ok(Err(e)) => fail("SUBSCRIBE", &e, HYPERLIQUID_ERR_INVALID_ARGUMENT),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                HYPERLIQUID_ERR_NOT_INITIALIZED
+            }
+        }
+    })
+}
+
+/// Stop keeping a coin's book; the connection stays open
+/// Returns HYPERLIQUID_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_unsubscribe_book(coin: *const c_char) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return HYPERLIQUID_ERR_NOT_INITIALIZED,
+        };
+        let Some(coin) = coin_arg(coin) else {
+            return HYPERLIQUID_ERR_INVALID_COIN;
+        };
+
+        match executor.unsubscribe(coin) {
+            Ok(()) => HYPERLIQUID_OK,
+            Err(e) => e.code(HYPERLIQUID_ERR_INVALID_ARGUMENT),
+        }
+    })
+}
+
+/// Top HYPERLIQUID_BOOK_DEPTH levels per side of a subscribed coin's book
+/// Returns HYPERLIQUID_PENDING until the venue sent its book (`out`
+/// untouched), HYPERLIQUID_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_get_book_snapshot(
+    coin: *const c_char,
+    out: *mut HyperliquidBookSnapshot,
+) -> i32 {
+    guard::catch(HYPERLIQUID_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return HYPERLIQUID_ERR_NOT_INITIALIZED,
+        };
+        let Some(coin) = coin_arg(coin) else {
+            return HYPERLIQUID_ERR_INVALID_COIN;
+        };
+        let Some(out) = args::out_arg(out) else {
+            return HYPERLIQUID_ERR_INVALID_ARGUMENT;
+        };
+        let book = match executor.books().book(coin) {
+            Some(book) => book,
+            None if executor.books().is_subscribed(coin) => return HYPERLIQUID_PENDING,
+            None => return HYPERLIQUID_ERR_INVALID_ARGUMENT,
+        };
+
+        let mut value = HyperliquidBookSnapshot {
+            age_ms: book.age().as_millis() as u64,
+            time_ms: book.time_ms,
+            ..Default::default()
+        };
+        let fill = |out: &mut [HyperliquidBookLevel], levels: &[Level]| {
+            for (slot, level) in out.iter_mut().zip(levels) {
+                *slot = HyperliquidBookLevel {
+                    price_raw: to_raw(level.price),
+                    size_raw: to_raw(level.size),
+                };
+            }
+            levels.len().min(HYPERLIQUID_BOOK_DEPTH) as u32
+        };
+        value.bid_count = fill(&mut value.bids, &book.bids);
+        value.ask_count = fill(&mut value.asks, &book.asks);
+        *out = value;
+        HYPERLIQUID_OK
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn hyperliquid_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
@@ -0,0 +1,285 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Interactive Brokers behind the venue-neutral connector traits
+//!
+//! Instruments are contract ids as text ("265598"); quantities are shares
+//! or contracts and prices the contract's currency. Order ids are the
+//! venue's. Orders cannot be reduce-only, post-only or fill-or-kill here.
+//! Balances are not served: read positions with `Trader::positions`. Top
+//! of book comes from the gateway's stream, connected on the first
+//! subscribe. A background task keeps the gateway session alive while the
+//! connector lives.
+//!
+//! Config: `endpoint` is the gateway address, DEFAULT_GATEWAY if None;
+//! `account` the account traded, the gateway's selected one if None. The
+//! gateway holds the brokerage login, so there is no key or secret.
+
+use std::sync::Arc;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, TimeInForce as Tif, TopOfBook,
+};
+use tracing::{info, warn};
+
+use crate::{
+    IbkrError, OrderKind, OrderRequest, QuoteFeed, Side, TimeInForce, Trader, DEFAULT_GATEWAY,
+    TICKLE_INTERVAL,
+};
+
+const VENUE: &str = "ibkr";
+
+impl From<IbkrError> for ConnectorError {
+    fn from(err: IbkrError) -> Self {
+        let message = err.to_string();
+        match err {
+            IbkrError::InvalidGateway(_) => Self::InvalidConfig(message),
+            IbkrError::NotAuthenticated(_) => Self::Auth(message),
+            IbkrError::InvalidContract(_) => Self::InvalidInstrument(message),
+            IbkrError::InvalidOrder(_) => Self::InvalidOrder(message),
+            IbkrError::Rejected(_) => Self::Rejected(message),
+            IbkrError::Api { status, .. } => match status {
+                403 => Self::Auth(message),
+                429 => Self::RateLimited(message),
+                500.. => Self::Venue(message),
+                _ => Self::Rejected(message),
+            },
+            IbkrError::Network(_) => Self::Network(message),
+            IbkrError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Contract id of an instrument
+fn conid(instrument: &str) -> Result<i64, ConnectorError> {
+    instrument
+        .parse()
+        .ok()
+        .filter(|conid| *conid > 0)
+        .ok_or_else(|| {
+            ConnectorError::InvalidInstrument(format!("not a contract id: {}", instrument))
+        })
+}
+
+/// Order request of a spec, refused if the gateway cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    if order.reduce_only || order.post_only {
+        return Err(ConnectorError::unsupported(
+            VENUE,
+            "reduce-only and post-only orders",
+        ));
+    }
+    let (kind, tif) = match order.order_type {
+        OrderType::Market => (OrderKind::Market, TimeInForce::Day),
+        OrderType::Limit { price } => {
+            let tif = match order.tif {
+                Tif::Day => TimeInForce::Day,
+                Tif::Gtc => TimeInForce::Gtc,
+                Tif::Ioc => TimeInForce::Ioc,
+                Tif::Fok => return Err(ConnectorError::unsupported(VENUE, "fill-or-kill orders")),
+            };
+            (OrderKind::Limit { price }, tif)
+        }
+    };
+    Ok(OrderRequest {
+        conid: conid(&order.instrument)?,
+        side: match order.side {
+            flox_connector::Side::Buy => Side::Buy,
+            flox_connector::Side::Sell => Side::Sell,
+        },
+        quantity: order.quantity,
+        kind,
+        tif,
+    })
+}
+
+pub struct IbkrConnector {
+    trader: Arc<Trader>,
+    quotes: Arc<QuoteFeed>,
+    /// Task keeping the quote feed connected, started by the first subscribe
+    quote_feed: FeedTask,
+    /// Task keeping the gateway session alive
+    keepalive: FeedTask,
+    gate: CallGate,
+}
+
+impl IbkrConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        day: true,
+        cancel_all: true,
+        top_of_book: true,
+        ..Capabilities::NONE
+    };
+
+    /// Connector over a session; `keep_alive` starts its keepalive task
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            quotes: Arc::new(QuoteFeed::new()),
+            quote_feed: FeedTask::new(),
+            keepalive: FeedTask::new(),
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Quotes of the subscribed contracts
+    pub fn quotes(&self) -> &Arc<QuoteFeed> {
+        &self.quotes
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+
+    /// Tickle the gateway every TICKLE_INTERVAL until the connector is
+    /// dropped; `connect` starts it
+    /// Panics outside a runtime, like `tokio::spawn`
+    pub fn keep_alive(&self) {
+        let trader = self.trader.clone();
+        self.keepalive.ensure(|| async move {
+            let mut interval = tokio::time::interval(TICKLE_INTERVAL);
+            // The first tick is immediate; the session just talked to the
+            // gateway
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if let Err(e) = trader.tickle().await {
+                    warn!("[KEEPALIVE] failed | {}", e);
+                }
+            }
+        });
+    }
+}
+
+impl Connector for IbkrConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let gateway = config.endpoint.as_deref().unwrap_or(DEFAULT_GATEWAY);
+            let trader = Trader::connect(gateway, config.account.as_deref()).await?;
+            let connector = Self::new(trader, CallGate::new(config.rate_limit));
+            connector.keep_alive();
+            Ok(connector)
+        })
+    }
+
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            let conid = conid(instrument)?;
+            if self.quotes.subscribe(conid) {
+                info!("[QUOTE FEED] subscribed | conid={}", conid);
+            }
+            let quotes = self.quotes.clone();
+            let trader = self.trader.clone();
+            self.quote_feed.ensure(|| async move {
+                feed::reconnect("QUOTE FEED", || quotes.run(&trader, |_| {})).await
+            });
+            Ok(())
+        })
+    }
+
+    fn unsubscribe(&self, instrument: &str) -> Result<(), ConnectorError> {
+        let conid = conid(instrument)?;
+        if !self.quotes.unsubscribe(conid) {
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        }
+        info!("[QUOTE FEED] unsubscribed | conid={}", conid);
+        Ok(())
+    }
+
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        let conid = conid(instrument)?;
+        let Some(quote) = self.quotes.quote(conid) else {
+            if self.quotes.is_subscribed(conid) {
+                return Ok(None);
+            }
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        };
+        Ok(Some(TopOfBook {
+            bid: quote.bid,
+            bid_size: quote.bid_size,
+            ask: quote.ask,
+            ask_size: quote.ask_size,
+            age: quote.age(),
+        }))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for IbkrConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let request = order_request(order)?;
+            let place = self.trader.place(request);
+            let outcome = self.gate.run(Call::OrderPost, place).await?;
+            Ok(PlacedOrder {
+                order_id: outcome.order_id,
+                client_order_id: outcome.client_order_id,
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        _instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(self.gate.run(Call::Cancel, self.trader.cancel(order_id)))
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            let conid = instrument.map(conid).transpose()?;
+            let cancel = self.trader.cancel_all(conid);
+            let count = self.gate.run(Call::Cancel, cancel).await?;
+            Ok(Some(count))
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async {
+            Err(ConnectorError::unsupported(
+                VENUE,
+                "balances (positions are read with Trader::positions)",
+            ))
+        })
+    }
+}
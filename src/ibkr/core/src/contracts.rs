@@ -0,0 +1,130 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Contract lookup
+//!
+//! Orders and quotes name a contract by its conid. `search` finds the
+//! contracts of a symbol with the security types they trade as; `futures`
+//! lists the futures of an underlying ("ES", "NQ") by expiry, the first one
+//! left being the front month.
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{id_text, IbkrError, Trader};
+
+/// Contract found by a symbol search
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Contract {
+    pub conid: i64,
+    pub symbol: String,
+    /// Company or index name
+    pub name: String,
+    /// Listing exchange
+    pub exchange: String,
+    /// Security types it trades as ("STK", "FUT", "OPT"...)
+    pub sec_types: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct SearchResult {
+    #[serde(default, deserialize_with = "id_text")]
+    conid: String,
+    #[serde(default)]
+    symbol: String,
+    #[serde(default, rename = "companyName")]
+    company_name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    sections: Vec<Section>,
+}
+
+#[derive(Deserialize)]
+struct Section {
+    #[serde(default, rename = "secType")]
+    sec_type: String,
+}
+
+/// Future of an underlying
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Future {
+    pub conid: i64,
+    pub symbol: String,
+    #[serde(rename(deserialize = "underlyingConid"), default)]
+    pub underlying_conid: i64,
+    /// YYYYMMDD
+    #[serde(rename(deserialize = "expirationDate"))]
+    pub expiration_date: u32,
+    /// Last trading day, YYYYMMDD
+    #[serde(rename(deserialize = "ltd"), default)]
+    pub last_trading_day: u32,
+}
+
+/// Symbol argument: ASCII letters, digits, '.' and ' ', as the venue lists it
+pub fn valid_symbol(symbol: &str) -> bool {
+    (1..=16).contains(&symbol.len())
+        && symbol
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b' ')
+}
+
+impl Trader {
+    /// Contracts of `symbol`, of security type `sec_type` ("STK", "IND")
+    /// if given
+    pub async fn search(
+        &self,
+        symbol: &str,
+        sec_type: Option<&str>,
+    ) -> Result<Vec<Contract>, IbkrError> {
+        if !valid_symbol(symbol) {
+            return Err(IbkrError::InvalidContract(symbol.to_string()));
+        }
+        let mut query = vec![("symbol", symbol.to_string())];
+        if let Some(sec_type) = sec_type {
+            query.push(("secType", sec_type.to_string()));
+        }
+        let found: Vec<SearchResult> = self
+            .send(Method::GET, "/iserver/secdef/search", &query, None)
+            .await?;
+        Ok(found
+            .into_iter()
+            // Rows without a conid are headings of derivatives only
+            .filter_map(|r| {
+                Some(Contract {
+                    conid: r.conid.parse().ok()?,
+                    symbol: r.symbol,
+                    name: r.company_name,
+                    exchange: r.description,
+                    sec_types: r.sections.into_iter().map(|s| s.sec_type).collect(),
+                })
+            })
+            .collect())
+    }
+
+    /// Futures of the underlying `symbol` ("ES"), nearest expiry first
+    pub async fn futures(&self, symbol: &str) -> Result<Vec<Future>, IbkrError> {
+        if !valid_symbol(symbol) {
+            return Err(IbkrError::InvalidContract(symbol.to_string()));
+        }
+        let query = [("symbols", symbol.to_string())];
+        let mut listed: std::collections::HashMap<String, Vec<Future>> = self
+            .send(Method::GET, "/trsrv/futures", &query, None)
+            .await?;
+        let mut futures = listed.remove(symbol).unwrap_or_default();
+        if futures.is_empty() {
+            return Err(IbkrError::InvalidContract(format!(
+                "no futures of {}",
+                symbol
+            )));
+        }
+        futures.sort_by_key(|f| f.expiration_date);
+        Ok(futures)
+    }
+}
@@ -0,0 +1,361 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Interactive Brokers executor over the Client Portal gateway
+//!
+//! `Trader` is a session of one account through a Client Portal gateway
+//! the host runs and has logged in (the gateway holds the brokerage
+//! session; no credentials pass through here): contract lookup, orders,
+//! cancels, open orders and positions over its REST API. The session times
+//! out without traffic, so the owner calls `tickle` about once a minute.
+//! `quotes::QuoteFeed` keeps the top of book of contracts from the
+//! gateway's WebSocket; `connector::IbkrConnector` puts both behind the
+//! shared connector traits and keeps the session alive. The FFI library
+//! wraps it in the same C ABI conventions as the Polymarket executor. The
+//! TWS socket API is not used.
+//!
+//! Contracts are named by their IBKR contract id (conid), found with
+//! `search` or, for futures, `futures`. Quantities are shares or contracts.
+
+pub mod connector;
+pub mod contracts;
+pub mod orders;
+pub mod quotes;
+
+use std::time::Duration;
+
+use reqwest::{Method, Url};
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+pub use connector::IbkrConnector;
+pub use contracts::{Contract, Future};
+pub use orders::{OpenOrder, OrderKind, OrderOutcome, OrderRequest, Side, TimeInForce};
+pub use quotes::{Quote, QuoteFeed};
+
+/// Address the gateway listens on unless configured otherwise
+pub const DEFAULT_GATEWAY: &str = "https://localhost:5000";
+
+/// Path prefix of the Client Portal endpoints
+const API_PREFIX: &str = "/v1/api";
+
+/// Timeout of one request, connect included
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often the owner should call `tickle`; the gateway drops a session
+/// left without requests for about five minutes
+pub const TICKLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Positions the portfolio endpoint serves per page
+const POSITIONS_PAGE: usize = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IbkrError {
+    #[error("invalid gateway address: {0}")]
+    InvalidGateway(String),
+    /// The gateway is not logged in, or lost its brokerage session
+    #[error("gateway not authenticated: {0}")]
+    NotAuthenticated(String),
+    #[error("unknown contract {0}")]
+    InvalidContract(String),
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// The venue refused the request, with its reason
+    #[error("rejected: {0}")]
+    Rejected(String),
+    /// The gateway answered with an error status
+    #[error("gateway answered {status}: {message}")]
+    Api { status: u16, message: String },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: String,
+}
+
+/// Decimal sent as a string or a number; empty strings read as 0
+/// Quote fields come as text such as "1,300" or "C193.18" (a closing price),
+/// so thousands separators and a leading letter are dropped
+pub(crate) fn decimal<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Text(String),
+        Number(f64),
+    }
+    match Decimal::deserialize(d)? {
+        Decimal::Text(s) => parse_decimal(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("not a number: {:?}", s))),
+        Decimal::Number(n) => Ok(n),
+    }
+}
+
+pub(crate) fn parse_decimal(text: &str) -> Option<f64> {
+    let text = text.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    if text.is_empty() {
+        return Some(0.0);
+    }
+    let (digits, scale) = match text.strip_suffix('K') {
+        Some(digits) => (digits, 1e3),
+        None => match text.strip_suffix('M') {
+            Some(digits) => (digits, 1e6),
+            None => (text, 1.0),
+        },
+    };
+    digits
+        .replace(',', "")
+        .parse::<f64>()
+        .ok()
+        .map(|x| x * scale)
+}
+
+/// Id sent as a string or a number, kept as text
+pub(crate) fn id_text<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    match serde_json::Value::deserialize(d)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!("not an id: {}", other))),
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthStatus {
+    #[serde(default)]
+    authenticated: bool,
+    #[serde(default)]
+    competing: bool,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct Accounts {
+    accounts: Vec<String>,
+    #[serde(default, rename = "selectedAccount")]
+    selected_account: String,
+}
+
+#[derive(Deserialize)]
+struct Tickle {
+    #[serde(default)]
+    session: String,
+}
+
+/// Position of the account in one contract
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Position {
+    pub conid: i64,
+    #[serde(default, rename(deserialize = "contractDesc"))]
+    pub description: String,
+    /// Shares or contracts, negative if short
+    #[serde(default, deserialize_with = "decimal")]
+    pub position: f64,
+    #[serde(
+        default,
+        rename(deserialize = "mktPrice"),
+        deserialize_with = "decimal"
+    )]
+    pub market_price: f64,
+    /// Per share, or per contract including its multiplier
+    #[serde(default, rename(deserialize = "avgCost"), deserialize_with = "decimal")]
+    pub average_cost: f64,
+    #[serde(
+        default,
+        rename(deserialize = "unrealizedPnl"),
+        deserialize_with = "decimal"
+    )]
+    pub unrealized_pnl: f64,
+    #[serde(default)]
+    pub currency: String,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    /// "https://localhost:5000"
+    gateway: Url,
+    /// The gateway's self-signed certificate is accepted (loopback only)
+    insecure_tls: bool,
+    account_id: String,
+}
+
+/// A gateway on this host, whose self-signed certificate is accepted
+fn is_loopback(gateway: &Url) -> bool {
+    match gateway.host_str() {
+        Some("localhost") => true,
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback()),
+        None => false,
+    }
+}
+
+impl Trader {
+    /// Open a session through the gateway at `gateway` ("https://localhost:5000")
+    /// for `account_id`, or for the gateway's selected account if None
+    /// The gateway must already be logged in
+    pub async fn connect(gateway: &str, account_id: Option<&str>) -> Result<Self, IbkrError> {
+        let gateway = Url::parse(gateway)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https") && url.host_str().is_some())
+            .ok_or_else(|| IbkrError::InvalidGateway(gateway.to_string()))?;
+        let insecure_tls = gateway.scheme() == "https" && is_loopback(&gateway);
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            // The gateway refuses requests without one
+            .user_agent("flox-connectors")
+            .danger_accept_invalid_certs(insecure_tls)
+            .build()
+            .map_err(|e| IbkrError::Network(e.to_string()))?;
+        let mut trader = Self {
+            http,
+            gateway,
+            insecure_tls,
+            account_id: String::new(),
+        };
+
+        let status: AuthStatus = trader
+            .send(Method::GET, "/iserver/auth/status", &[], None)
+            .await?;
+        if !status.authenticated {
+            let reason = match (status.competing, status.message.is_empty()) {
+                (true, _) => "another session of the user is active".to_string(),
+                (false, true) => "log in to the gateway first".to_string(),
+                (false, false) => status.message,
+            };
+            return Err(IbkrError::NotAuthenticated(reason));
+        }
+
+        // Both account lists must be read before the order and portfolio
+        // endpoints answer
+        let accounts: Accounts = trader
+            .send(Method::GET, "/iserver/accounts", &[], None)
+            .await?;
+        let _: serde_json::Value = trader
+            .send(Method::GET, "/portfolio/accounts", &[], None)
+            .await?;
+        trader.account_id = match account_id {
+            Some(id) if accounts.accounts.iter().any(|a| a == id) => id.to_string(),
+            Some(id) => {
+                return Err(IbkrError::NotAuthenticated(format!(
+                    "account {} is not served by this login",
+                    id
+                )))
+            }
+            None if !accounts.selected_account.is_empty() => accounts.selected_account,
+            None => accounts
+                .accounts
+                .into_iter()
+                .next()
+                .ok_or_else(|| IbkrError::NotAuthenticated("no account".to_string()))?,
+        };
+        info!(
+            "[CONNECT] ibkr | gateway={} | account={}",
+            trader.gateway, trader.account_id
+        );
+        Ok(trader)
+    }
+
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// Send a request to `path` (below /v1/api) and decode the answer
+    /// Query values are sent as they are: ids, symbols and numbers only
+    pub(crate) async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<serde_json::Value>,
+    ) -> Result<T, IbkrError> {
+        let mut url = self.gateway.clone();
+        url.set_path(&format!("{}{}", API_PREFIX, path));
+        if !query.is_empty() {
+            url.query_pairs_mut()
+                .extend_pairs(query.iter().map(|(name, value)| (*name, value.as_str())));
+        }
+
+        let mut request = self.http.request(method, url);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| IbkrError::Network(e.to_string()))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| IbkrError::Network(e.to_string()))?;
+        if !status.is_success() {
+            return Err(match serde_json::from_str::<ErrorBody>(&text) {
+                _ if status.as_u16() == 401 => IbkrError::NotAuthenticated(text),
+                // Rate limits and gateway faults keep their status
+                Ok(body) if matches!(status.as_u16(), 400 | 404) => IbkrError::Rejected(body.error),
+                _ => IbkrError::Api {
+                    status: status.as_u16(),
+                    message: text,
+                },
+            });
+        }
+        serde_json::from_str(&text).map_err(|e| IbkrError::Decode(e.to_string()))
+    }
+
+    /// Keep the gateway session alive; returns the session token the
+    /// WebSocket wants
+    pub async fn tickle(&self) -> Result<String, IbkrError> {
+        let tickle: Tickle = self.send(Method::POST, "/tickle", &[], None).await?;
+        Ok(tickle.session)
+    }
+
+    /// Positions of the account, zero ones left out
+    pub async fn positions(&self) -> Result<Vec<Position>, IbkrError> {
+        let mut positions = Vec::new();
+        for page in 0.. {
+            let path = format!("/portfolio/{}/positions/{}", self.account_id, page);
+            let batch: Vec<Position> = self.send(Method::GET, &path, &[], None).await?;
+            let last = batch.len() < POSITIONS_PAGE;
+            positions.extend(batch.into_iter().filter(|p| p.position != 0.0));
+            if last {
+                break;
+            }
+        }
+        Ok(positions)
+    }
+
+    /// Position in `conid`, 0 if none
+    pub async fn position(&self, conid: i64) -> Result<f64, IbkrError> {
+        Ok(self
+            .positions()
+            .await?
+            .into_iter()
+            .filter(|p| p.conid == conid)
+            .map(|p| p.position)
+            .sum())
+    }
+
+    /// URL of the gateway's WebSocket
+    fn ws_url(&self) -> String {
+        let mut url = self.gateway.clone();
+        let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+        // http(s) to ws(s) is always accepted
+        let _ = url.set_scheme(scheme);
+        url.set_path(&format!("{}/ws", API_PREFIX));
+        url.to_string()
+    }
+}
@@ -0,0 +1,352 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders, cancels and open orders
+//!
+//! The gateway may answer an order with questions ("the order size exceeds
+//! the size limit...") instead of placing it; they are logged and
+//! confirmed, as an unattended strategy cannot answer them, up to
+//! `MAX_REPLIES` in a row. The answer carries the order's id and status
+//! only; fills are read from the open orders or the positions. Orders are
+//! for the regular trading hours of their contract.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::{decimal, id_text, IbkrError, Trader};
+
+/// Questions confirmed for one order at most
+const MAX_REPLIES: usize = 5;
+
+/// Statuses of an order still working
+const OPEN_STATUSES: [&str; 3] = ["PendingSubmit", "PreSubmitted", "Submitted"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "BUY",
+            Self::Sell => "SELL",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeInForce {
+    /// Cancelled at the end of the trading day
+    Day,
+    /// Rests until filled or cancelled
+    Gtc,
+    /// What does not fill at once is cancelled
+    Ioc,
+}
+
+impl TimeInForce {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Day => "DAY",
+            Self::Gtc => "GTC",
+            Self::Ioc => "IOC",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderKind {
+    Market,
+    Limit { price: f64 },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub conid: i64,
+    pub side: Side,
+    /// Shares or contracts, > 0
+    pub quantity: f64,
+    pub kind: OrderKind,
+    pub tif: TimeInForce,
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderOutcome {
+    pub order_id: String,
+    /// Our id of the order ("flox-...")
+    pub client_order_id: String,
+    /// "Submitted", "PreSubmitted", "Filled"...
+    pub status: String,
+}
+
+/// One entry of the answer to an order or to a reply
+#[derive(Deserialize)]
+struct OrderReply {
+    /// Id of a question to confirm
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    message: Vec<String>,
+    #[serde(default)]
+    order_id: Option<String>,
+    #[serde(default)]
+    order_status: String,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorReply {
+    error: String,
+}
+
+#[derive(Deserialize)]
+struct CancelReply {
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OrderList {
+    #[serde(default)]
+    orders: Vec<ListedOrder>,
+}
+
+#[derive(Deserialize)]
+struct ListedOrder {
+    #[serde(rename = "orderId", deserialize_with = "id_text")]
+    order_id: String,
+    #[serde(default)]
+    order_ref: String,
+    #[serde(default)]
+    acct: String,
+    #[serde(default)]
+    conid: i64,
+    #[serde(default)]
+    ticker: String,
+    #[serde(default)]
+    side: String,
+    #[serde(default, rename = "orderType")]
+    order_type: String,
+    #[serde(default, deserialize_with = "decimal")]
+    price: f64,
+    #[serde(default, rename = "totalSize", deserialize_with = "decimal")]
+    total_size: f64,
+    #[serde(default, rename = "filledQuantity", deserialize_with = "decimal")]
+    filled_quantity: f64,
+    #[serde(default)]
+    status: String,
+}
+
+/// Order working at the venue
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OpenOrder {
+    pub order_id: String,
+    /// Our id of the order, empty for orders placed elsewhere
+    pub client_order_id: String,
+    pub conid: i64,
+    pub symbol: String,
+    /// BUY or SELL
+    pub side: String,
+    /// Limit, Market...
+    pub order_type: String,
+    /// Limit price, 0 if none
+    pub price: f64,
+    pub quantity: f64,
+    pub filled_quantity: f64,
+    pub status: String,
+}
+
+/// Counter of the client order ids of this process
+static NEXT_CLIENT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Client order id: the time (ms) and the counter, so ids of a restarted
+/// process do not repeat earlier ones
+fn client_order_id() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+    format!(
+        "flox-{:x}-{:x}",
+        now,
+        NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+impl Trader {
+    /// Check and place an order
+    pub async fn place(&self, request: OrderRequest) -> Result<OrderOutcome, IbkrError> {
+        if request.conid <= 0 {
+            return Err(IbkrError::InvalidContract(request.conid.to_string()));
+        }
+        if !request.quantity.is_finite() || request.quantity <= 0.0 {
+            return Err(IbkrError::InvalidOrder(format!(
+                "quantity must be > 0, got {}",
+                request.quantity
+            )));
+        }
+        let client_order_id = client_order_id();
+        let mut order = serde_json::json!({
+            "acctId": self.account_id,
+            "conid": request.conid,
+            "cOID": client_order_id,
+            "side": request.side.as_str(),
+            "quantity": request.quantity,
+            "tif": request.tif.as_str(),
+            "outsideRTH": false,
+        });
+        match request.kind {
+            OrderKind::Market => order["orderType"] = "MKT".into(),
+            OrderKind::Limit { price } => {
+                if !price.is_finite() || price <= 0.0 {
+                    return Err(IbkrError::InvalidOrder(format!(
+                        "price must be > 0, got {}",
+                        price
+                    )));
+                }
+                order["orderType"] = "LMT".into();
+                order["price"] = price.into();
+            }
+        }
+
+        let path = format!("/iserver/account/{}/orders", self.account_id);
+        let body = serde_json::json!({ "orders": [order] });
+        let mut reply: serde_json::Value = self.send(Method::POST, &path, &[], Some(body)).await?;
+        for _ in 0..=MAX_REPLIES {
+            // Refusals come as one object instead of a list
+            if let Ok(refused) = serde_json::from_value::<ErrorReply>(reply.clone()) {
+                return Err(IbkrError::Rejected(refused.error));
+            }
+            let entries: Vec<OrderReply> =
+                serde_json::from_value(reply).map_err(|e| IbkrError::Decode(e.to_string()))?;
+            let Some(entry) = entries.into_iter().next() else {
+                return Err(IbkrError::Decode("empty order answer".to_string()));
+            };
+            if let Some(error) = entry.error {
+                return Err(IbkrError::Rejected(error));
+            }
+            if let Some(order_id) = entry.order_id {
+                info!(
+                    "[ORDER] {} {} | conid={} | qty={} | price={:?} | id={} | status={}",
+                    match request.kind {
+                        OrderKind::Market => "market",
+                        OrderKind::Limit { .. } => "limit",
+                    },
+                    request.side.as_str(),
+                    request.conid,
+                    request.quantity,
+                    match request.kind {
+                        OrderKind::Market => None,
+                        OrderKind::Limit { price } => Some(price),
+                    },
+                    order_id,
+                    entry.order_status
+                );
+                return Ok(OrderOutcome {
+                    order_id,
+                    client_order_id,
+                    status: entry.order_status,
+                });
+            }
+            let Some(question) = entry.id else {
+                return Err(IbkrError::Decode("order answer without id".to_string()));
+            };
+            warn!(
+                "[ORDER] confirming | conid={} | {}",
+                request.conid,
+                entry.message.join(" ")
+            );
+            let path = format!("/iserver/reply/{}", question);
+            let body = serde_json::json!({ "confirmed": true });
+            reply = self.send(Method::POST, &path, &[], Some(body)).await?;
+        }
+        Err(IbkrError::Rejected(format!(
+            "more than {} questions for one order",
+            MAX_REPLIES
+        )))
+    }
+
+    /// Cancel a working order by id
+    pub async fn cancel(&self, order_id: &str) -> Result<(), IbkrError> {
+        let path = format!("/iserver/account/{}/order/{}", self.account_id, order_id);
+        let reply: CancelReply = self.send(Method::DELETE, &path, &[], None).await?;
+        if let Some(error) = reply.error {
+            return Err(IbkrError::Rejected(error));
+        }
+        info!("[CANCEL] id={}", order_id);
+        Ok(())
+    }
+
+    /// Cancel every working order of the account, of `conid` only if given;
+    /// returns the number cancelled
+    pub async fn cancel_all(&self, conid: Option<i64>) -> Result<u32, IbkrError> {
+        let orders: Vec<OpenOrder> = self
+            .open_orders()
+            .await?
+            .into_iter()
+            .filter(|o| conid.is_none_or(|conid| o.conid == conid))
+            .collect();
+        let mut cancelled = 0;
+        for order in &orders {
+            match self.cancel(&order.order_id).await {
+                Ok(()) => cancelled += 1,
+                // Filled or cancelled meanwhile
+                Err(IbkrError::Rejected(reason)) => {
+                    warn!(
+                        "[CANCEL_ALL] id={} not cancelled | {}",
+                        order.order_id, reason
+                    )
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        info!(
+            "[CANCEL_ALL] conid={:?} | cancelled={} of {}",
+            conid,
+            cancelled,
+            orders.len()
+        );
+        Ok(cancelled)
+    }
+
+    /// Orders working on the account
+    /// The gateway fills its order list in the background; the first call
+    /// of a session may miss orders placed elsewhere
+    pub async fn open_orders(&self) -> Result<Vec<OpenOrder>, IbkrError> {
+        let list: OrderList = self
+            .send(Method::GET, "/iserver/account/orders", &[], None)
+            .await?;
+        Ok(list
+            .orders
+            .into_iter()
+            .filter(|o| o.acct.is_empty() || o.acct == self.account_id)
+            .filter(|o| OPEN_STATUSES.contains(&o.status.as_str()))
+            .map(|o| OpenOrder {
+                order_id: o.order_id,
+                client_order_id: o.order_ref,
+                conid: o.conid,
+                symbol: o.ticker,
+                side: o.side,
+                order_type: o.order_type,
+                price: o.price,
+                quantity: o.total_size,
+                filled_quantity: o.filled_quantity,
+                status: o.status,
+            })
+            .collect())
+    }
+}
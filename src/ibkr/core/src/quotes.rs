@@ -0,0 +1,209 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Top of book kept from the gateway's market data stream
+//!
+//! `QuoteFeed` holds the subscribed contracts and the last quote of each.
+//! `run` keeps a connection open until it fails; the caller reconnects, and
+//! the new connection subscribes every contract again. Contracts added or
+//! removed while connected are sent on the live connection. The gateway
+//! sends only the fields that changed, so each update is merged into the
+//! quote kept. Until its first update a contract has no quote.
+//!
+//! Sizes are as the venue streams them: shares, lots of 100 shares for
+//! some US stocks, or contracts.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use serde::Deserialize;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::Connector;
+use tracing::debug;
+
+use crate::{parse_decimal, Trader};
+
+/// Fields asked for: last price, bid, ask size, ask, bid size
+const FIELDS: [&str; 5] = ["31", "84", "85", "86", "88"];
+
+/// The gateway sends a heartbeat every few seconds; a connection with no
+/// message for this long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(60);
+
+/// How often the silence limit is checked and the session kept alive
+const HEARTBEAT: Duration = Duration::from_secs(20);
+
+/// "tic" keeps the gateway session alive
+const KEEPALIVE: Keepalive = Keepalive {
+    interval: HEARTBEAT,
+    ping: Some("tic"),
+    silence_limit: SILENCE_LIMIT,
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Quote {
+    /// Last trade price
+    pub last: f64,
+    pub bid: f64,
+    pub bid_size: f64,
+    pub ask: f64,
+    pub ask_size: f64,
+    pub received_at: Option<Instant>,
+}
+
+impl Quote {
+    /// Since the last update was received
+    pub fn age(&self) -> Duration {
+        self.received_at.map(|t| t.elapsed()).unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize)]
+struct Update {
+    #[serde(default)]
+    topic: String,
+    #[serde(default)]
+    conid: i64,
+    #[serde(flatten)]
+    fields: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Default)]
+pub struct QuoteFeed {
+    conids: Mutex<BTreeSet<i64>>,
+    quotes: RwLock<HashMap<i64, Quote>>,
+    /// Subscription messages for the live connection, if any
+    commands: Commands,
+}
+
+impl QuoteFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a contract; false if it was already subscribed
+    pub fn subscribe(&self, conid: i64) -> bool {
+        let added = self.lock_conids().insert(conid);
+        if added {
+            self.commands.send(subscription(conid));
+        }
+        added
+    }
+
+    /// Remove a contract and drop its quote; false if it was not subscribed
+    pub fn unsubscribe(&self, conid: i64) -> bool {
+        let removed = self.lock_conids().remove(&conid);
+        if removed {
+            if let Ok(mut quotes) = self.quotes.write() {
+                quotes.remove(&conid);
+            }
+            self.commands.send(format!("umd+{}+{{}}", conid));
+        }
+        removed
+    }
+
+    pub fn is_subscribed(&self, conid: i64) -> bool {
+        self.lock_conids().contains(&conid)
+    }
+
+    pub fn conids(&self) -> Vec<i64> {
+        self.lock_conids().iter().copied().collect()
+    }
+
+    /// Quote of a contract; None until the venue sent it
+    pub fn quote(&self, conid: i64) -> Option<Quote> {
+        self.quotes.read().ok()?.get(&conid).copied()
+    }
+
+    fn lock_conids(&self) -> std::sync::MutexGuard<'_, BTreeSet<i64>> {
+        self.conids.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Apply one text message; returns the contract whose quote changed
+    pub fn apply(&self, text: &str) -> Option<i64> {
+        let update: Update = match serde_json::from_str(text) {
+            Ok(update) => update,
+            Err(e) => {
+                debug!("[QUOTE FEED] unreadable message | {}", e);
+                return None;
+            }
+        };
+        // System messages, heartbeats and order updates
+        if !update.topic.starts_with("smd+") {
+            return None;
+        }
+        // A message in flight when the contract was removed
+        if !self.is_subscribed(update.conid) {
+            return None;
+        }
+        let mut quotes = self.quotes.write().unwrap_or_else(|e| e.into_inner());
+        let quote = quotes.entry(update.conid).or_default();
+        for (field, value) in &update.fields {
+            let Some(value) = value.as_str().and_then(parse_decimal) else {
+                continue;
+            };
+            match field.as_str() {
+                "31" => quote.last = value,
+                "84" => quote.bid = value,
+                "85" => quote.ask_size = value,
+                "86" => quote.ask = value,
+                "88" => quote.bid_size = value,
+                _ => {}
+            }
+        }
+        quote.received_at = Some(Instant::now());
+        Some(update.conid)
+    }
+
+    /// Connect to the gateway of `trader` and keep every subscribed quote
+    /// until the connection fails; `on_update` gets each contract whose
+    /// quote changed
+    pub async fn run(&self, trader: &Trader, mut on_update: impl FnMut(i64)) -> Result<(), String> {
+        let connect = || async {
+            let session = trader.tickle().await.map_err(|e| e.to_string())?;
+            let mut request = trader
+                .ws_url()
+                .into_client_request()
+                .map_err(|e| e.to_string())?;
+            let cookie =
+                HeaderValue::from_str(&format!("api={}", session)).map_err(|e| e.to_string())?;
+            request.headers_mut().insert("Cookie", cookie);
+            let connector = native_tls::TlsConnector::builder()
+                .danger_accept_invalid_certs(trader.insecure_tls)
+                .build()
+                .map_err(|e| e.to_string())?;
+            let (ws, _) = tokio_tungstenite::connect_async_tls_with_config(
+                request,
+                None,
+                false,
+                Some(Connector::NativeTls(connector)),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+            Ok(ws)
+        };
+        let subscriptions = || self.conids().into_iter().map(subscription).collect();
+        // The gateway sends its JSON in binary frames, read as text
+        feed::run_ws(&self.commands, connect, subscriptions, KEEPALIVE, |text| {
+            if let Some(conid) = self.apply(text) {
+                on_update(conid);
+            }
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Subscription message of a contract's quote
+fn subscription(conid: i64) -> String {
+    format!("smd+{}+{}", conid, serde_json::json!({ "fields": FIELDS }))
+}
@@ -0,0 +1,571 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Interactive Brokers executor - C FFI
+//!
+//! The C ABI of ibkr_ffi.h, laid out like the Polymarket executor's: one
+//! global `IbkrConnector` set up by ibkr_init(), blocking calls that run
+//! on the shared connector runtime, negative error codes (the same numbers
+//! as POLYMARKET_ERR_* where the meaning is shared), raw amounts with 6
+//! decimals, and thread-local last error and last response buffers. A
+//! background task of the connector keeps the gateway session alive;
+//! quotes are kept by another, as polymarket_subscribe_book() does.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Instant;
+
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, Connector, ConnectorConfig, ConnectorError};
+use ibkr_executor_core::contracts::valid_symbol;
+use ibkr_executor_core::{
+    IbkrConnector, IbkrError, OrderKind, OrderOutcome, OrderRequest, Side, TimeInForce, Trader,
+};
+use tracing::{error, info};
+
+// Error codes
+pub const IBKR_OK: i32 = 0;
+/// Not an error: no data yet
+pub const IBKR_PENDING: i32 = 1;
+pub const IBKR_ERR_NOT_INITIALIZED: i32 = -1;
+/// Gateway not logged in, or session lost
+pub const IBKR_ERR_AUTH: i32 = -3;
+/// Unknown conid or symbol
+pub const IBKR_ERR_INVALID_CONTRACT: i32 = -4;
+pub const IBKR_ERR_ORDER_FAILED: i32 = -5;
+pub const IBKR_ERR_CANCEL_FAILED: i32 = -6;
+pub const IBKR_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const IBKR_ERR_PANIC: i32 = -14;
+/// Venue refused the request, see last error
+pub const IBKR_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const IBKR_ERR_NETWORK: i32 = -20;
+/// Gateway request limit exceeded
+pub const IBKR_ERR_RATE_LIMITED: i32 = -21;
+/// Gateway unavailable or busy, order state unknown
+pub const IBKR_ERR_VENUE_ERROR: i32 = -22;
+/// Quantity or price not valid
+pub const IBKR_ERR_INVALID_ORDER: i32 = -23;
+
+// Order flags; orders are for the day without one
+/// Rests until filled or cancelled
+pub const IBKR_FLAG_GTC: u32 = 1;
+/// What does not fill at once is cancelled
+pub const IBKR_FLAG_IOC: u32 = 2;
+
+/// Raw units per unit
+const RAW_SCALE: f64 = 1_000_000.0;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct IbkrOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Venue order id
+    pub order_id: [c_char; 32],
+    /// Our id of the order
+    pub client_order_id: [c_char; 40],
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl IbkrOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            order_id: [0; 32],
+            client_order_id: [0; 40],
+            latency_ms: 0,
+        }
+    }
+
+    fn placed(outcome: &OrderOutcome, latency_ms: u64) -> Self {
+        let mut result = Self::failed(IBKR_OK);
+        result.success = true;
+        out_buf::write_fixed(&outcome.order_id, &mut result.order_id);
+        out_buf::write_fixed(&outcome.client_order_id, &mut result.client_order_id);
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+/// Top of book of a subscribed contract, filled by ibkr_get_quote(); raw
+/// values in 6 decimals (divide by 1M for actual). Sizes are as the venue
+/// streams them: shares, lots of 100 shares for some US stocks, or
+/// contracts
+#[repr(C)]
+#[derive(Default)]
+pub struct IbkrQuote {
+    /// Last trade price
+    pub last_raw: i64,
+    pub bid_raw: i64,
+    pub bid_size_raw: i64,
+    pub ask_raw: i64,
+    pub ask_size_raw: i64,
+    /// Since the last update was received
+    pub age_ms: u64,
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "ibkr";
+
+fn get_executor() -> Option<Arc<IbkrConnector>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("ibkr_executor=info"))
+        .try_init();
+}
+
+fn to_raw(x: f64) -> i64 {
+    (x * RAW_SCALE).round() as i64
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect through a Client Portal gateway the host runs and has logged in
+/// `gateway_url`: "https://localhost:5000" if NULL; `account_id`: the
+/// gateway's selected account if NULL
+/// Replaces a session already set up. Returns 0 on success, negative error
+/// code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_init(gateway_url: *const c_char, account_id: *const c_char) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        init_logging();
+        let gateway = match gateway_url.is_null() {
+            true => None,
+            false => match str_arg(gateway_url) {
+                Some(gateway) => Some(gateway.to_string()),
+                None => return IBKR_ERR_INVALID_ARGUMENT,
+            },
+        };
+        let account_id = match account_id.is_null() {
+            true => None,
+            false => match str_arg(account_id).filter(|a| {
+                (1..=32).contains(&a.len()) && a.bytes().all(|b| b.is_ascii_alphanumeric())
+            }) {
+                Some(account_id) => Some(account_id.to_string()),
+                None => return IBKR_ERR_INVALID_ARGUMENT,
+            },
+        };
+
+        let config = ConnectorConfig {
+            endpoint: gateway,
+            account: account_id,
+            ..Default::default()
+        };
+
+        let connector = match runtime::block_on(IbkrConnector::connect(config)) {
+            Ok(Ok(connector)) => connector,
+            // The gateway address is an argument of this call
+            Ok(Err(e @ ConnectorError::InvalidConfig(_))) => {
+                fail("INIT", &e, IBKR_ERR_NOT_INITIALIZED);
+                return IBKR_ERR_INVALID_ARGUMENT;
+            }
+            Ok(Err(e)) => return fail("INIT", &e, IBKR_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return IBKR_ERR_NOT_INITIALIZED;
+            }
+        };
+        let executor = Arc::new(connector);
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] ibkr executor ready");
+        IBKR_OK
+    })
+}
+
+/// Shut the session down and close the quote feed; calls still running
+/// finish first. The gateway stays logged in. ibkr_init() can be called
+/// again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<IbkrConnector>>(VENUE);
+        if executor.is_some() {
+            info!("[SHUTDOWN] ibkr executor stopped");
+        }
+    })
+}
+
+/// Place an order and fill `out`
+fn place(op: &'static str, request: OrderRequest, out: *mut IbkrOrderResult) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return IBKR_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = IbkrOrderResult::failed(IBKR_ERR_NOT_INITIALIZED);
+            return IBKR_ERR_NOT_INITIALIZED;
+        }
+    };
+
+    let started = Instant::now();
+    let result = runtime::block_on(async move {
+        executor.gate().run(Call::OrderPost, executor.trader().place(request)).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(outcome)) => IbkrOrderResult::placed(&outcome, latency_ms),
+        Ok(Err(e)) => IbkrOrderResult::failed(fail(op, &e, IBKR_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | conid={} | {}", op, request.conid, e);
+            out_buf::set_last_error(&e);
+            IbkrOrderResult::failed(IBKR_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+fn side(is_buy: bool) -> Side {
+    if is_buy {
+        Side::Buy
+    } else {
+        Side::Sell
+    }
+}
+
+/// Market order of `quantity` shares or contracts of `conid`, for the day
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_market_order(
+    conid: i64,
+    is_buy: bool,
+    quantity: f64,
+    out: *mut IbkrOrderResult,
+) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let request = OrderRequest {
+            conid,
+            side: side(is_buy),
+            quantity,
+            kind: OrderKind::Market,
+            tif: TimeInForce::Day,
+        };
+        place("MARKET", request, out)
+    })
+}
+
+/// Limit order of `quantity` shares or contracts of `conid` at `price`
+/// `flags`: IBKR_FLAG_GTC or IBKR_FLAG_IOC, or 0 for the day
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_limit_order(
+    conid: i64,
+    is_buy: bool,
+    quantity: f64,
+    price: f64,
+    flags: u32,
+    out: *mut IbkrOrderResult,
+) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let tif = match flags {
+            0 => TimeInForce::Day,
+            IBKR_FLAG_GTC => TimeInForce::Gtc,
+            IBKR_FLAG_IOC => TimeInForce::Ioc,
+            _ => return IBKR_ERR_INVALID_ARGUMENT,
+        };
+        let request = OrderRequest {
+            conid,
+            side: side(is_buy),
+            quantity,
+            kind: OrderKind::Limit { price },
+            tif,
+        };
+        place("LIMIT", request, out)
+    })
+}
+
+/// Cancel a working order by its venue order id
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_cancel(order_id: *const c_char) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return IBKR_ERR_NOT_INITIALIZED,
+        };
+        let Some(order_id) = str_arg(order_id)
+            .filter(|id| (1..=31).contains(&id.len()) && id.bytes().all(|b| b.is_ascii_digit()))
+        else {
+            return IBKR_ERR_INVALID_ARGUMENT;
+        };
+
+        let id_owned = order_id.to_string();
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Cancel, executor.trader().cancel(&id_owned)).await
+        });
+        match result {
+            Ok(Ok(())) => IBKR_OK,
+            Ok(Err(e)) => fail("CANCEL", &e, IBKR_ERR_CANCEL_FAILED),
+            Err(e) => {
+                error!("[CANCEL] failed | id={} | {}", order_id, e);
+                out_buf::set_last_error(&e);
+                IBKR_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cancel every working order of `conid`, or of the account if 0
+/// Returns the number cancelled (0 if none was working), or negative error
+/// code
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_cancel_all(conid: i64) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return IBKR_ERR_NOT_INITIALIZED,
+        };
+        let conid = match conid {
+            0 => None,
+            1.. => Some(conid),
+            _ => return IBKR_ERR_INVALID_CONTRACT,
+        };
+
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Cancel, executor.trader().cancel_all(conid)).await
+        });
+        match result {
+            Ok(Ok(count)) => count.min(i32::MAX as u32) as i32,
+            Ok(Err(e)) => fail("CANCEL_ALL", &e, IBKR_ERR_CANCEL_FAILED),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                IBKR_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Position in `conid` into `out_raw` (raw, 6 decimals; negative if
+/// short), 0 if none
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_get_position(conid: i64, out_raw: *mut i64) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return IBKR_ERR_NOT_INITIALIZED,
+        };
+        if conid <= 0 {
+            return IBKR_ERR_INVALID_CONTRACT;
+        }
+        let Some(out_raw) = args::out_arg(out_raw) else {
+            return IBKR_ERR_INVALID_ARGUMENT;
+        };
+
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Balance, executor.trader().position(conid)).await
+        });
+        match result {
+            Ok(Ok(position)) => {
+                *out_raw = to_raw(position);
+                IBKR_OK
+            }
+            Ok(Err(e)) => fail("POSITION", &e, IBKR_ERR_VENUE_ERROR),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                IBKR_ERR_VENUE_ERROR
+            }
+        }
+    })
+}
+
+/// Run a query and keep its JSON for ibkr_last_response()
+fn query_json<Fut, T>(op: &str, query: impl FnOnce(Arc<Trader>) -> Fut) -> i64
+where
+    Fut: Future<Output = Result<T, IbkrError>> + Send + 'static,
+    T: serde::Serialize + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return IBKR_ERR_NOT_INITIALIZED as i64,
+    };
+    let query = query(executor.trader().clone());
+    match runtime::block_on(async move { query.await.map_err(ConnectorError::from) }) {
+        Ok(Ok(value)) => match serde_json::to_string(&value) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => IBKR_ERR_VENUE_ERROR as i64,
+        },
+        Ok(Err(e)) => fail(op, &e, IBKR_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            IBKR_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// Non-zero positions as JSON [{"conid", "description", "position",
+/// "market_price", "average_cost", "unrealized_pnl", "currency"}]; returns
+/// its size (ibkr_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_get_positions() -> i64 {
+    guard::catch(IBKR_ERR_PANIC as i64, || {
+        query_json("POSITIONS", |trader| async move { trader.positions().await })
+    })
+}
+
+/// Working orders as JSON [{"order_id", "client_order_id", "conid",
+/// "symbol", "side", "order_type", "price", "quantity", "filled_quantity",
+/// "status"}]; returns its size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_get_open_orders() -> i64 {
+    guard::catch(IBKR_ERR_PANIC as i64, || {
+        query_json("OPEN_ORDERS", |trader| async move { trader.open_orders().await })
+    })
+}
+
+/// Contracts of `symbol` as JSON [{"conid", "symbol", "name", "exchange",
+/// "sec_types"}], of security type `sec_type` ("STK", "IND") unless NULL;
+/// returns its size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_search_contracts(symbol: *const c_char, sec_type: *const c_char) -> i64 {
+    guard::catch(IBKR_ERR_PANIC as i64, || {
+        let Some(symbol) = str_arg(symbol).filter(|s| valid_symbol(s)) else {
+            return IBKR_ERR_INVALID_ARGUMENT as i64;
+        };
+        let sec_type = match sec_type.is_null() {
+            true => None,
+            false => match str_arg(sec_type)
+                .filter(|t| (1..=8).contains(&t.len()) && t.bytes().all(|b| b.is_ascii_uppercase()))
+            {
+                Some(sec_type) => Some(sec_type.to_string()),
+                None => return IBKR_ERR_INVALID_ARGUMENT as i64,
+            },
+        };
+        let symbol = symbol.to_string();
+        query_json("SEARCH", |trader| async move {
+            trader.search(&symbol, sec_type.as_deref()).await
+        })
+    })
+}
+
+/// Futures of the underlying `symbol` ("ES") as JSON [{"conid", "symbol",
+/// "underlying_conid", "expiration_date", "last_trading_day"}], nearest
+/// expiry first; returns its size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_get_futures(symbol: *const c_char) -> i64 {
+    guard::catch(IBKR_ERR_PANIC as i64, || {
+        let Some(symbol) = str_arg(symbol).filter(|s| valid_symbol(s)) else {
+            return IBKR_ERR_INVALID_ARGUMENT as i64;
+        };
+        let symbol = symbol.to_string();
+        query_json("FUTURES", |trader| async move { trader.futures(&symbol).await })
+    })
+}
+
+/// Keep the top of book of a contract from the gateway's stream
+/// The first subscription opens the connection; it reconnects on its own
+/// and closes on shutdown. Returns IBKR_OK, also if already subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_subscribe_quote(conid: i64) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return IBKR_ERR_NOT_INITIALIZED,
+        };
+        if conid <= 0 {
+            return IBKR_ERR_INVALID_CONTRACT;
+        }
+
+        let instrument = conid.to_string();
+        match runtime::block_on(async move { executor.subscribe(&instrument).await }) {
+            Ok(Ok(())) => IBKR_OK,
+            Ok(Err(e)) => fail("SUBSCRIBE", &e, IBKR_ERR_INVALID_ARGUMENT),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                IBKR_ERR_NOT_INITIALIZED
+            }
+        }
+    })
+}
+
+/// Stop keeping a contract's quote; the connection stays open
+/// Returns IBKR_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_unsubscribe_quote(conid: i64) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return IBKR_ERR_NOT_INITIALIZED,
+        };
+
+        if !executor.quotes().unsubscribe(conid) {
+            return IBKR_ERR_INVALID_ARGUMENT;
+        }
+        info!("[QUOTE FEED] unsubscribed | conid={}", conid);
+        IBKR_OK
+    })
+}
+
+/// Top of book of a subscribed contract
+/// Returns IBKR_PENDING until the gateway sent a quote (`out` untouched),
+/// IBKR_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_get_quote(conid: i64, out: *mut IbkrQuote) -> i32 {
+    guard::catch(IBKR_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return IBKR_ERR_NOT_INITIALIZED,
+        };
+        let Some(out) = args::out_arg(out) else {
+            return IBKR_ERR_INVALID_ARGUMENT;
+        };
+        let quotes = executor.quotes();
+        let quote = match quotes.quote(conid) {
+            Some(quote) => quote,
+            None if quotes.is_subscribed(conid) => return IBKR_PENDING,
+            None => return IBKR_ERR_INVALID_ARGUMENT,
+        };
+
+        *out = IbkrQuote {
+            last_raw: to_raw(quote.last),
+            bid_raw: to_raw(quote.bid),
+            bid_size_raw: to_raw(quote.bid_size),
+            ask_raw: to_raw(quote.ask),
+            ask_size_raw: to_raw(quote.ask_size),
+            age_ms: quote.age().as_millis() as u64,
+        };
+        IBKR_OK
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn ibkr_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
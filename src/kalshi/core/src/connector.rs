@@ -0,0 +1,235 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Kalshi behind the venue-neutral connector traits
+//!
+//! Instruments are market tickers, trading their YES side, or a ticker
+//! with ":NO" appended for the NO side. Quantities are whole contracts and
+//! prices dollars of the side traded (0.01-0.99). Market orders take what
+//! fills at once, up to 99 cents buying and down to 1 cent selling; limit
+//! orders rest until cancelled, or are cancelled at once with IOC. There is
+//! no feed and no cancel-all here.
+//!
+//! Config: `api_key` is the key id and `secret` its PEM private key;
+//! `endpoint` is the API base URL, PRODUCTION_URL or, with `testnet`,
+//! DEMO_URL if None.
+
+use std::sync::Arc;
+
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, Side, TimeInForce, TopOfBook,
+};
+
+use crate::orders::{MAX_PRICE_CENTS, MIN_PRICE_CENTS};
+use crate::{
+    Action, KalshiError, OrderKind, OrderRequest, OutcomeSide, Trader, DEMO_URL, PRODUCTION_URL,
+};
+
+const VENUE: &str = "kalshi";
+
+/// Suffix of the NO side of a ticker
+const NO_SUFFIX: &str = ":NO";
+
+impl From<KalshiError> for ConnectorError {
+    fn from(err: KalshiError) -> Self {
+        let message = err.to_string();
+        match err {
+            KalshiError::InvalidKey(_) => Self::InvalidKey(message),
+            KalshiError::InvalidTicker => Self::InvalidInstrument(message),
+            KalshiError::InvalidOrder(_) => Self::InvalidOrder(message),
+            KalshiError::Api { status, code, .. } => match status {
+                401 | 403 => Self::Auth(message),
+                429 => Self::RateLimited(message),
+                500.. => Self::Venue(message),
+                _ if code.contains("insufficient") => Self::InsufficientBalance(message),
+                _ => Self::Rejected(message),
+            },
+            KalshiError::Network(_) => Self::Network(message),
+            KalshiError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Ticker and side of an instrument
+fn outcome(instrument: &str) -> (&str, OutcomeSide) {
+    match instrument.strip_suffix(NO_SUFFIX) {
+        Some(ticker) => (ticker, OutcomeSide::No),
+        None => (instrument, OutcomeSide::Yes),
+    }
+}
+
+/// Order request of a spec, refused if Kalshi cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    if order.post_only || order.reduce_only {
+        return Err(ConnectorError::unsupported(
+            VENUE,
+            "post-only and reduce-only orders",
+        ));
+    }
+    if order.quantity.fract() != 0.0 {
+        return Err(ConnectorError::InvalidOrder(format!(
+            "whole contracts only, got {}",
+            order.quantity
+        )));
+    }
+    let action = match order.side {
+        Side::Buy => Action::Buy,
+        Side::Sell => Action::Sell,
+    };
+    let (kind, price_cents) = match (order.order_type, order.tif) {
+        (OrderType::Market, _) => match action {
+            Action::Buy => (OrderKind::Market, MAX_PRICE_CENTS),
+            Action::Sell => (OrderKind::Market, MIN_PRICE_CENTS),
+        },
+        (OrderType::Limit { price }, TimeInForce::Gtc) => (OrderKind::Limit, cents(price)),
+        // A market order is an IOC order at its bound
+        (OrderType::Limit { price }, TimeInForce::Ioc) => (OrderKind::Market, cents(price)),
+        (OrderType::Limit { .. }, tif) => {
+            return Err(ConnectorError::unsupported(
+                VENUE,
+                &format!("{:?} orders", tif),
+            ))
+        }
+    };
+    Ok(OrderRequest {
+        action,
+        side: outcome(&order.instrument).1,
+        kind,
+        count: order.quantity as i64,
+        price_cents,
+    })
+}
+
+/// Dollar price in cents; out of range prices stay out of range
+fn cents(price: f64) -> u32 {
+    (price * 100.0).round().clamp(0.0, u32::MAX as f64) as u32
+}
+
+pub struct KalshiConnector {
+    trader: Arc<Trader>,
+    gate: CallGate,
+}
+
+impl KalshiConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        balances: true,
+        testnet: true,
+        ..Capabilities::NONE
+    };
+
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+}
+
+impl Connector for KalshiConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let base_url = match (&config.endpoint, config.testnet) {
+                (Some(url), _) => url.as_str(),
+                (None, false) => PRODUCTION_URL,
+                (None, true) => DEMO_URL,
+            };
+            let trader = Trader::connect(
+                config.require_api_key()?,
+                config.require_secret()?,
+                Some(base_url),
+            )
+            .await?;
+            Ok(Self::new(trader, CallGate::new(config.rate_limit)))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, _instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async { Err(ConnectorError::unsupported(VENUE, "market data feed")) })
+    }
+
+    fn unsubscribe(&self, _instrument: &str) -> Result<(), ConnectorError> {
+        Err(ConnectorError::unsupported(VENUE, "market data feed"))
+    }
+
+    fn top_of_book(&self, _instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        Err(ConnectorError::unsupported(VENUE, "market data feed"))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for KalshiConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let request = order_request(order)?;
+            let (ticker, _) = outcome(&order.instrument);
+            let fill = self
+                .gate
+                .run(Call::OrderPost, self.trader.place(ticker, request))
+                .await?;
+            Ok(PlacedOrder {
+                order_id: fill.order_id,
+                client_order_id: fill.client_order_id,
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        _instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(self.gate.run(Call::Cancel, self.trader.cancel(order_id)))
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        _instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async { Err(ConnectorError::unsupported(VENUE, "cancel all")) })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let cents = self.gate.run(Call::Balance, self.trader.balance()).await?;
+            let usd = cents as f64 / 100.0;
+            Ok(vec![Balance {
+                asset: "USD".to_string(),
+                free: usd,
+                total: usd,
+            }])
+        })
+    }
+}
@@ -0,0 +1,232 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Kalshi order executor
+//!
+//! `Trader` is an authenticated session of the Kalshi trade API (v2):
+//! orders, cancels, the cash balance and positions. Prices are cents of
+//! the side traded (1-99), sizes whole contracts, amounts cents.
+//! `connector::KalshiConnector` puts it behind the shared connector traits;
+//! the FFI library wraps that in the same C ABI conventions as the
+//! Polymarket executor.
+
+mod auth;
+pub mod connector;
+pub mod orders;
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use auth::Signer;
+pub use connector::KalshiConnector;
+pub use orders::{valid_ticker, Action, OrderFill, OrderKind, OrderRequest, OutcomeSide};
+
+pub const PRODUCTION_URL: &str = "https://api.elections.kalshi.com/trade-api/v2";
+pub const DEMO_URL: &str = "https://demo-api.kalshi.co/trade-api/v2";
+
+/// Timeout of one request, connect included
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Pages fetched at most by listings
+pub const MAX_PAGES: usize = 20;
+
+/// Entries asked for per page
+const PAGE_LIMIT: u32 = 1000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum KalshiError {
+    #[error("invalid API key: {0}")]
+    InvalidKey(String),
+    #[error("invalid market ticker")]
+    InvalidTicker,
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// The venue answered with an error status
+    #[error("venue answered {status}: {code} {message}")]
+    Api {
+        status: u16,
+        code: String,
+        message: String,
+    },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ErrorDetail,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ErrorDetail {
+    code: String,
+    message: String,
+}
+
+/// Holding in one market
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Position {
+    pub ticker: String,
+    /// Contracts held: positive YES, negative NO
+    pub position: i64,
+    /// Cost of the position (cents)
+    pub market_exposure: i64,
+    pub realized_pnl: i64,
+    pub fees_paid: i64,
+    pub resting_orders_count: i64,
+}
+
+#[derive(Deserialize)]
+struct PositionsPage {
+    #[serde(default)]
+    market_positions: Vec<Position>,
+    #[serde(default)]
+    cursor: String,
+}
+
+#[derive(Deserialize)]
+struct BalanceResponse {
+    balance: i64,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    base_url: Url,
+    signer: Signer,
+}
+
+impl Trader {
+    /// Open a session with the API key `key_id` and its PEM private key
+    /// against `base_url` (PRODUCTION_URL if None), checked by reading the
+    /// balance
+    pub async fn connect(
+        key_id: &str,
+        private_key_pem: &str,
+        base_url: Option<&str>,
+    ) -> Result<Self, KalshiError> {
+        let signer = Signer::new(key_id, private_key_pem)?;
+        let base_url = base_url.unwrap_or(PRODUCTION_URL).trim_end_matches('/');
+        let base_url = Url::parse(base_url)
+            .map_err(|e| KalshiError::InvalidOrder(format!("base url {}: {}", base_url, e)))?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| KalshiError::Network(e.to_string()))?;
+        let trader = Self {
+            http,
+            base_url,
+            signer,
+        };
+        let balance = trader.balance().await?;
+        info!(
+            "[CONNECT] kalshi | url={} | balance=${:.2}",
+            trader.base_url,
+            balance as f64 / 100.0
+        );
+        Ok(trader)
+    }
+
+    /// Send a signed request to `path` (below the base url) and decode the
+    /// answer
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<serde_json::Value>,
+    ) -> Result<T, KalshiError> {
+        let mut url = self.base_url.clone();
+        url.set_path(&format!("{}{}", self.base_url.path(), path));
+        // Signed without the query string
+        let headers = self.signer.headers(method.as_str(), url.path());
+        let mut request = self.http.request(method, url).query(query);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| KalshiError::Network(e.to_string()))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| KalshiError::Network(e.to_string()))?;
+        if !status.is_success() {
+            let detail = serde_json::from_str::<ErrorBody>(&text)
+                .map(|body| body.error)
+                .unwrap_or_else(|_| ErrorDetail {
+                    code: String::new(),
+                    message: text,
+                });
+            return Err(KalshiError::Api {
+                status: status.as_u16(),
+                code: detail.code,
+                message: detail.message,
+            });
+        }
+        // DELETE answers may be empty
+        let text = if text.is_empty() && status == StatusCode::NO_CONTENT {
+            "null".to_string()
+        } else {
+            text
+        };
+        serde_json::from_str(&text).map_err(|e| KalshiError::Decode(e.to_string()))
+    }
+
+    /// Cash available to trade (cents)
+    pub async fn balance(&self) -> Result<i64, KalshiError> {
+        let resp: BalanceResponse = self
+            .send(Method::GET, "/portfolio/balance", &[], None)
+            .await?;
+        Ok(resp.balance)
+    }
+
+    /// Markets with contracts held or orders resting
+    pub async fn positions(&self) -> Result<Vec<Position>, KalshiError> {
+        let mut positions = Vec::new();
+        let mut cursor = String::new();
+        for _ in 0..MAX_PAGES {
+            let mut query = vec![("limit", PAGE_LIMIT.to_string())];
+            if !cursor.is_empty() {
+                query.push(("cursor", cursor.clone()));
+            }
+            let page: PositionsPage = self
+                .send(Method::GET, "/portfolio/positions", &query, None)
+                .await?;
+            positions.extend(
+                page.market_positions
+                    .into_iter()
+                    .filter(|p| p.position != 0 || p.resting_orders_count > 0),
+            );
+            if page.cursor.is_empty() {
+                return Ok(positions);
+            }
+            cursor = page.cursor;
+        }
+        // A partial list would pass held positions off as closed
+        Err(KalshiError::Decode(format!(
+            "more than {} pages of positions",
+            MAX_PAGES
+        )))
+    }
+}
@@ -0,0 +1,262 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders, cancels and what the venue answers to them
+//!
+//! Every order is a limit order on one side (YES or NO) of a market. A
+//! market order is sent immediate-or-cancel at the caller's price bound:
+//! it takes what the book holds up to that price and never rests. Each
+//! order carries a client order id generated here, so a retried request
+//! cannot trade twice.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::{KalshiError, Trader};
+
+/// Price range of a contract (cents)
+pub const MIN_PRICE_CENTS: u32 = 1;
+pub const MAX_PRICE_CENTS: u32 = 99;
+
+/// Longest market ticker accepted
+const MAX_TICKER_LEN: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutcomeSide {
+    Yes,
+    No,
+}
+
+impl OutcomeSide {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Yes => "yes",
+            Self::No => "no",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Buy,
+    Sell,
+}
+
+impl Action {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderKind {
+    /// Immediate-or-cancel at the price bound
+    Market,
+    /// Rests on the book until filled or cancelled
+    Limit,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub action: Action,
+    pub side: OutcomeSide,
+    pub kind: OrderKind,
+    /// Whole contracts
+    pub count: i64,
+    /// Limit price, or the market order's bound (cents of the side traded)
+    pub price_cents: u32,
+}
+
+impl OrderRequest {
+    fn validate(&self) -> Result<(), KalshiError> {
+        if self.count < 1 {
+            return Err(KalshiError::InvalidOrder(format!(
+                "count must be >= 1, got {}",
+                self.count
+            )));
+        }
+        if !(MIN_PRICE_CENTS..=MAX_PRICE_CENTS).contains(&self.price_cents) {
+            return Err(KalshiError::InvalidOrder(format!(
+                "price must be {}-{} cents, got {}",
+                MIN_PRICE_CENTS, MAX_PRICE_CENTS, self.price_cents
+            )));
+        }
+        Ok(())
+    }
+
+    fn body(&self, ticker: &str, client_order_id: &str) -> serde_json::Value {
+        let price_field = match self.side {
+            OutcomeSide::Yes => "yes_price",
+            OutcomeSide::No => "no_price",
+        };
+        let time_in_force = match self.kind {
+            OrderKind::Market => "immediate_or_cancel",
+            OrderKind::Limit => "good_till_canceled",
+        };
+        serde_json::json!({
+            "ticker": ticker,
+            "client_order_id": client_order_id,
+            "action": self.action.as_str(),
+            "side": self.side.as_str(),
+            "count": self.count,
+            "type": "limit",
+            price_field: self.price_cents,
+            "time_in_force": time_in_force,
+        })
+    }
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderFill {
+    pub order_id: String,
+    pub client_order_id: String,
+    /// resting, executed or canceled
+    pub status: String,
+    pub filled_count: i64,
+    /// Contracts left resting on the book
+    pub remaining_count: i64,
+    /// What the filled contracts cost, fees excluded (cents)
+    pub fill_cost_cents: i64,
+    pub fees_cents: i64,
+}
+
+impl OrderFill {
+    /// Average fill price (cents), 0 if nothing filled
+    pub fn avg_price_cents(&self) -> f64 {
+        if self.filled_count > 0 {
+            self.fill_cost_cents as f64 / self.filled_count as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OrderResponse {
+    order: VenueOrder,
+}
+
+/// Order fields read from the venue; absent ones count as 0
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct VenueOrder {
+    order_id: String,
+    client_order_id: String,
+    status: String,
+    fill_count: i64,
+    remaining_count: i64,
+    taker_fill_cost: i64,
+    maker_fill_cost: i64,
+    taker_fees: i64,
+    maker_fees: i64,
+}
+
+impl From<VenueOrder> for OrderFill {
+    fn from(order: VenueOrder) -> Self {
+        Self {
+            order_id: order.order_id,
+            client_order_id: order.client_order_id,
+            status: order.status,
+            filled_count: order.fill_count,
+            remaining_count: order.remaining_count,
+            fill_cost_cents: order.taker_fill_cost + order.maker_fill_cost,
+            fees_cents: order.taker_fees + order.maker_fees,
+        }
+    }
+}
+
+/// Market ticker: 1-64 characters of A-Z, 0-9, '-', '.', '_'
+pub fn valid_ticker(ticker: &str) -> bool {
+    (1..=MAX_TICKER_LEN).contains(&ticker.len())
+        && ticker
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_'))
+}
+
+/// Order id as it goes into a URL path
+fn valid_order_id(order_id: &str) -> bool {
+    !order_id.is_empty()
+        && order_id.len() <= 128
+        && order_id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// Unique per process and across restarts
+fn next_client_order_id() -> String {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    format!(
+        "flox-{}-{}-{}",
+        std::process::id(),
+        now_ms,
+        SEQ.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+impl Trader {
+    /// Check and place an order on the market `ticker`
+    pub async fn place(
+        &self,
+        ticker: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, KalshiError> {
+        if !valid_ticker(ticker) {
+            return Err(KalshiError::InvalidTicker);
+        }
+        request.validate()?;
+        let client_order_id = next_client_order_id();
+        let body = request.body(ticker, &client_order_id);
+        let resp: OrderResponse = self
+            .send(Method::POST, "/portfolio/orders", &[], Some(body))
+            .await?;
+        let fill = OrderFill::from(resp.order);
+        info!(
+            "[ORDER] {} {} {} | ticker={} | count={} | price={}c | id={} | status={} | filled={}",
+            match request.kind {
+                OrderKind::Market => "market",
+                OrderKind::Limit => "limit",
+            },
+            request.action.as_str(),
+            request.side.as_str(),
+            ticker,
+            request.count,
+            request.price_cents,
+            fill.order_id,
+            fill.status,
+            fill.filled_count
+        );
+        Ok(fill)
+    }
+
+    /// Cancel a resting order by id
+    pub async fn cancel(&self, order_id: &str) -> Result<(), KalshiError> {
+        if !valid_order_id(order_id) {
+            return Err(KalshiError::InvalidOrder(format!(
+                "malformed order id {:?}",
+                order_id
+            )));
+        }
+        let path = format!("/portfolio/orders/{}", order_id);
+        let _: serde_json::Value = self.send(Method::DELETE, &path, &[], None).await?;
+        info!("[CANCEL] id={}", order_id);
+        Ok(())
+    }
+}
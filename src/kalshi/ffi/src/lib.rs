@@ -0,0 +1,426 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Kalshi order executor - C FFI
+//!
+//! The C ABI of kalshi_ffi.h, laid out like the Polymarket executor's:
+//! one global `KalshiConnector` set up by kalshi_init(), blocking calls
+//! that run on the shared connector runtime, negative error codes (the
+//! same numbers as POLYMARKET_ERR_* where the meaning is shared),
+//! thread-local last error and last response buffers.
+
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Instant;
+
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, Connector, ConnectorConfig, ConnectorError};
+use kalshi_executor_core::{
+    Action, KalshiConnector, OrderFill, OrderKind, OrderRequest, OutcomeSide,
+};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+// Error codes
+pub const KALSHI_OK: i32 = 0;
+pub const KALSHI_ERR_NOT_INITIALIZED: i32 = -1;
+/// Key id or PEM private key malformed
+pub const KALSHI_ERR_INVALID_KEY: i32 = -2;
+/// Venue refused the key (401/403)
+pub const KALSHI_ERR_AUTH_FAILED: i32 = -3;
+pub const KALSHI_ERR_INVALID_TICKER: i32 = -4;
+pub const KALSHI_ERR_ORDER_FAILED: i32 = -5;
+pub const KALSHI_ERR_CANCEL_FAILED: i32 = -6;
+pub const KALSHI_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+pub const KALSHI_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const KALSHI_ERR_PANIC: i32 = -14;
+/// Venue refused the request (4xx)
+pub const KALSHI_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const KALSHI_ERR_NETWORK: i32 = -20;
+/// Venue answered 429
+pub const KALSHI_ERR_RATE_LIMITED: i32 = -21;
+/// Venue answered 5xx
+pub const KALSHI_ERR_VENUE_ERROR: i32 = -22;
+/// Count or price not valid (checked locally)
+pub const KALSHI_ERR_INVALID_ORDER: i32 = -23;
+
+// Sides
+pub const KALSHI_SIDE_YES: i32 = 0;
+pub const KALSHI_SIDE_NO: i32 = 1;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct KalshiOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Venue order id
+    pub order_id: [c_char; 64],
+    /// "resting", "executed" or "canceled"
+    pub status: [c_char; 16],
+    /// Contracts filled
+    pub filled_count: i64,
+    /// Contracts left resting on the book
+    pub remaining_count: i64,
+    /// Cost of the filled contracts, fees excluded
+    pub fill_cost_cents: i64,
+    /// Fees paid on the fills
+    pub fees_cents: i64,
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl KalshiOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            order_id: [0; 64],
+            status: [0; 16],
+            filled_count: 0,
+            remaining_count: 0,
+            fill_cost_cents: 0,
+            fees_cents: 0,
+            latency_ms: 0,
+        }
+    }
+
+    fn filled(fill: &OrderFill, latency_ms: u64) -> Self {
+        let mut result = Self::failed(KALSHI_OK);
+        result.success = true;
+        out_buf::write_fixed(&fill.order_id, &mut result.order_id);
+        out_buf::write_fixed(&fill.status, &mut result.status);
+        result.filled_count = fill.filled_count;
+        result.remaining_count = fill.remaining_count;
+        result.fill_cost_cents = fill.fill_cost_cents;
+        result.fees_cents = fill.fees_cents;
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "kalshi";
+
+fn get_executor() -> Option<Arc<KalshiConnector>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("kalshi_executor=info"))
+        .try_init();
+}
+
+fn side_of(side: i32) -> Option<OutcomeSide> {
+    match side {
+        KALSHI_SIDE_YES => Some(OutcomeSide::Yes),
+        KALSHI_SIDE_NO => Some(OutcomeSide::No),
+        _ => None,
+    }
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect with the API key `api_key_id` and its PEM private key (PKCS#8
+/// or PKCS#1) against `base_url` (NULL for production)
+/// Replaces a session already set up. Returns 0 on success, negative error
+/// code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_init(
+    api_key_id: *const c_char,
+    private_key_pem: *const c_char,
+    base_url: *const c_char,
+) -> i32 {
+    guard::catch(KALSHI_ERR_PANIC, || {
+        init_logging();
+        let (Some(key_id), Some(pem)) = (str_arg(api_key_id), str_arg(private_key_pem)) else {
+            return KALSHI_ERR_INVALID_KEY;
+        };
+        let base_url = match base_url.is_null() {
+            true => None,
+            false => match str_arg(base_url) {
+                Some(url) => Some(url.to_string()),
+                None => return KALSHI_ERR_INVALID_ARGUMENT,
+            },
+        };
+        let config = ConnectorConfig {
+            api_key: Some(key_id.to_string()),
+            secret: Some(Zeroizing::new(pem.to_string())),
+            endpoint: base_url,
+            ..Default::default()
+        };
+
+        let executor = match runtime::block_on(KalshiConnector::connect(config)) {
+            Ok(Ok(connector)) => Arc::new(connector),
+            Ok(Err(e)) => return fail("INIT", &e, KALSHI_ERR_AUTH_FAILED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return KALSHI_ERR_NOT_INITIALIZED;
+            }
+        };
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] kalshi executor ready");
+        KALSHI_OK
+    })
+}
+
+/// Shut the session down; calls still running finish first
+/// kalshi_init() can be called again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<KalshiConnector>>(VENUE);
+        if executor.is_some() {
+            info!("[SHUTDOWN] kalshi executor stopped");
+        }
+    })
+}
+
+/// Place an order and fill `out`
+fn place(
+    op: &'static str,
+    ticker: *const c_char,
+    request: OrderRequest,
+    out: *mut KalshiOrderResult,
+) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return KALSHI_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = KalshiOrderResult::failed(KALSHI_ERR_NOT_INITIALIZED);
+            return KALSHI_ERR_NOT_INITIALIZED;
+        }
+    };
+    let Some(ticker) = str_arg(ticker) else {
+        *out = KalshiOrderResult::failed(KALSHI_ERR_INVALID_TICKER);
+        return KALSHI_ERR_INVALID_TICKER;
+    };
+
+    let started = Instant::now();
+    let ticker_owned = ticker.to_string();
+    let result = runtime::block_on(async move {
+        let place = executor.trader().place(&ticker_owned, request);
+        executor.gate().run(Call::OrderPost, place).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(fill)) => KalshiOrderResult::filled(&fill, latency_ms),
+        Ok(Err(e)) => KalshiOrderResult::failed(fail(op, &e, KALSHI_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | ticker={} | {}", op, ticker, e);
+            out_buf::set_last_error(&e);
+            KalshiOrderResult::failed(KALSHI_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+/// Order arguments shared by the entry points
+fn order_request(
+    action: Action,
+    kind: OrderKind,
+    side: i32,
+    count: i64,
+    price_cents: u32,
+) -> Option<OrderRequest> {
+    Some(OrderRequest {
+        action,
+        side: side_of(side)?,
+        kind,
+        count,
+        price_cents,
+    })
+}
+
+/// Buy up to `count` contracts of `side` (KALSHI_SIDE_*) at once, paying at
+/// most `max_price_cents` each (0: up to 99); the rest is cancelled
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_market_buy(
+    ticker: *const c_char,
+    side: i32,
+    count: i64,
+    max_price_cents: u32,
+    out: *mut KalshiOrderResult,
+) -> i32 {
+    guard::catch(KALSHI_ERR_PANIC, || {
+        let bound = if max_price_cents == 0 { 99 } else { max_price_cents };
+        match order_request(Action::Buy, OrderKind::Market, side, count, bound) {
+            Some(request) => place("MARKET_BUY", ticker, request, out),
+            None => KALSHI_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Sell up to `count` contracts of `side` at once, for at least
+/// `min_price_cents` each (0: down to 1); the rest is cancelled
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_market_sell(
+    ticker: *const c_char,
+    side: i32,
+    count: i64,
+    min_price_cents: u32,
+    out: *mut KalshiOrderResult,
+) -> i32 {
+    guard::catch(KALSHI_ERR_PANIC, || {
+        let bound = if min_price_cents == 0 { 1 } else { min_price_cents };
+        match order_request(Action::Sell, OrderKind::Market, side, count, bound) {
+            Some(request) => place("MARKET_SELL", ticker, request, out),
+            None => KALSHI_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Limit buy of `count` contracts of `side` at `price_cents` (1-99), resting
+/// until filled or cancelled
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_limit_buy(
+    ticker: *const c_char,
+    side: i32,
+    count: i64,
+    price_cents: u32,
+    out: *mut KalshiOrderResult,
+) -> i32 {
+    guard::catch(KALSHI_ERR_PANIC, || {
+        match order_request(Action::Buy, OrderKind::Limit, side, count, price_cents) {
+            Some(request) => place("LIMIT_BUY", ticker, request, out),
+            None => KALSHI_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Limit sell of `count` contracts of `side` at `price_cents` (1-99)
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_limit_sell(
+    ticker: *const c_char,
+    side: i32,
+    count: i64,
+    price_cents: u32,
+    out: *mut KalshiOrderResult,
+) -> i32 {
+    guard::catch(KALSHI_ERR_PANIC, || {
+        match order_request(Action::Sell, OrderKind::Limit, side, count, price_cents) {
+            Some(request) => place("LIMIT_SELL", ticker, request, out),
+            None => KALSHI_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Cancel a resting order by id
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_cancel(order_id: *const c_char) -> i32 {
+    guard::catch(KALSHI_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KALSHI_ERR_NOT_INITIALIZED,
+        };
+        let Some(order_id) = str_arg(order_id) else {
+            return KALSHI_ERR_INVALID_ARGUMENT;
+        };
+
+        let id = order_id.to_string();
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Cancel, executor.trader().cancel(&id)).await
+        });
+        match result {
+            Ok(Ok(())) => KALSHI_OK,
+            Ok(Err(e)) => fail("CANCEL", &e, KALSHI_ERR_CANCEL_FAILED),
+            Err(e) => {
+                error!("[CANCEL] failed | id={} | {}", order_id, e);
+                out_buf::set_last_error(&e);
+                KALSHI_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cash balance in cents, or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_get_balance() -> i64 {
+    guard::catch(KALSHI_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KALSHI_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let result = runtime::block_on(async move {
+            executor.gate().run(Call::Balance, executor.trader().balance()).await
+        });
+        match result {
+            Ok(Ok(cents)) => cents,
+            Ok(Err(e)) => fail("BALANCE", &e, KALSHI_ERR_VENUE_ERROR) as i64,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                KALSHI_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// Positions as JSON [{"ticker", "position", "market_exposure",
+/// "realized_pnl", "fees_paid", "resting_orders_count"}]; returns its size
+/// (kalshi_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_get_positions() -> i64 {
+    guard::catch(KALSHI_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KALSHI_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let result = runtime::block_on(async move {
+            executor.trader().positions().await.map_err(ConnectorError::from)
+        });
+        match result {
+            Ok(Ok(positions)) => match serde_json::to_string(&positions) {
+                Ok(json) => out_buf::set_last_response(json) as i64,
+                Err(_) => KALSHI_ERR_VENUE_ERROR as i64,
+            },
+            Ok(Err(e)) => fail("POSITIONS", &e, KALSHI_ERR_VENUE_ERROR) as i64,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                KALSHI_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn kalshi_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
@@ -0,0 +1,253 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! L2 books kept from the WebSocket v2 book channel
+//!
+//! `BookFeed` holds the subscribed pairs and one book per pair. `run`
+//! keeps a connection open until it fails; the caller reconnects, and the
+//! new connection subscribes every pair again. Pairs added or removed while
+//! connected are sent on the live connection.
+//!
+//! A subscription starts with a snapshot of `BOOK_DEPTH` levels per side;
+//! updates then replace levels, a zero quantity removing one, and the book
+//! is cut back to `BOOK_DEPTH`. Until its snapshot a pair has no book. The
+//! venue's checksums are not verified.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use serde::Deserialize;
+use tracing::debug;
+
+/// Levels per side kept for each pair
+pub const BOOK_DEPTH: usize = 10;
+
+/// Client ping period, so an idle connection shows it is alive
+pub const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The venue sends a heartbeat every second while subscribed; a connection
+/// with no message for this long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(60);
+
+pub(crate) const KEEPALIVE: Keepalive = Keepalive {
+    interval: PING_INTERVAL,
+    ping: Some(r#"{"method":"ping"}"#),
+    silence_limit: SILENCE_LIMIT,
+};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct Level {
+    pub price: f64,
+    #[serde(rename = "qty")]
+    pub size: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct BookSnapshot {
+    /// Best (highest) first
+    pub bids: Vec<Level>,
+    /// Best (lowest) first
+    pub asks: Vec<Level>,
+    /// Venue time of the last update (ms since the epoch), 0 until one came
+    pub time_ms: u64,
+    pub received_at: Instant,
+}
+
+impl BookSnapshot {
+    /// Since the last message for the book was received
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    channel: String,
+    #[serde(default, rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: Vec<BookData>,
+}
+
+#[derive(Deserialize)]
+struct BookData {
+    symbol: String,
+    #[serde(default)]
+    bids: Vec<Level>,
+    #[serde(default)]
+    asks: Vec<Level>,
+    #[serde(default)]
+    timestamp: Option<String>,
+}
+
+/// Milliseconds since the epoch of an RFC 3339 time, 0 if unreadable
+pub(crate) fn rfc3339_ms(text: &str) -> u64 {
+    chrono::DateTime::parse_from_rfc3339(text)
+        .map(|t| t.timestamp_millis().max(0) as u64)
+        .unwrap_or_default()
+}
+
+/// Apply level changes to one side; `better` orders prices best first
+fn apply_side(side: &mut Vec<Level>, changes: &[Level], better: fn(f64, f64) -> bool) {
+    for change in changes {
+        let existing = side.iter().position(|level| level.price == change.price);
+        match (existing, change.size == 0.0) {
+            (Some(index), true) => {
+                side.remove(index);
+            }
+            (Some(index), false) => side[index].size = change.size,
+            (None, true) => {}
+            (None, false) => {
+                let index = side
+                    .iter()
+                    .position(|level| better(change.price, level.price))
+                    .unwrap_or(side.len());
+                side.insert(index, *change);
+            }
+        }
+    }
+    side.truncate(BOOK_DEPTH);
+}
+
+#[derive(Default)]
+pub struct BookFeed {
+    pairs: Mutex<BTreeSet<String>>,
+    books: RwLock<HashMap<String, BookSnapshot>>,
+    /// Subscription messages for the live connection, if any
+    commands: Commands,
+}
+
+impl BookFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a pair ("BTC/USD"); false if it was already subscribed
+    pub fn subscribe(&self, pair: &str) -> bool {
+        let added = self.lock_pairs().insert(pair.to_string());
+        if added {
+            self.commands
+                .send(subscription(&[pair.to_string()], "subscribe"));
+        }
+        added
+    }
+
+    /// Remove a pair and drop its book; false if it was not subscribed
+    pub fn unsubscribe(&self, pair: &str) -> bool {
+        let removed = self.lock_pairs().remove(pair);
+        if removed {
+            if let Ok(mut books) = self.books.write() {
+                books.remove(pair);
+            }
+            self.commands
+                .send(subscription(&[pair.to_string()], "unsubscribe"));
+        }
+        removed
+    }
+
+    pub fn is_subscribed(&self, pair: &str) -> bool {
+        self.lock_pairs().contains(pair)
+    }
+
+    pub fn pairs(&self) -> Vec<String> {
+        self.lock_pairs().iter().cloned().collect()
+    }
+
+    /// Book of a pair; None until the venue sent its snapshot
+    pub fn book(&self, pair: &str) -> Option<BookSnapshot> {
+        self.books.read().ok()?.get(pair).cloned()
+    }
+
+    fn lock_pairs(&self) -> std::sync::MutexGuard<'_, BTreeSet<String>> {
+        self.pairs.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Apply one text message; returns the pairs whose book changed
+    pub fn apply(&self, text: &str) -> Vec<String> {
+        let envelope: Envelope = match serde_json::from_str(text) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                debug!("[BOOK FEED] unreadable message | {}", e);
+                return Vec::new();
+            }
+        };
+        if envelope.channel != "book" {
+            return Vec::new();
+        }
+        let mut books = self.books.write().unwrap_or_else(|e| e.into_inner());
+        let mut changed = Vec::new();
+        for data in envelope.data {
+            // A message in flight when the pair was removed
+            if !self.is_subscribed(&data.symbol) {
+                continue;
+            }
+            let time_ms = data.timestamp.as_deref().map_or(0, rfc3339_ms);
+            if envelope.kind == "snapshot" {
+                let mut book = BookSnapshot {
+                    bids: Vec::new(),
+                    asks: Vec::new(),
+                    time_ms,
+                    received_at: Instant::now(),
+                };
+                apply_side(&mut book.bids, &data.bids, |a, b| a > b);
+                apply_side(&mut book.asks, &data.asks, |a, b| a < b);
+                books.insert(data.symbol.clone(), book);
+            } else {
+                // Updates before the snapshot have nothing to apply to
+                let Some(book) = books.get_mut(&data.symbol) else {
+                    continue;
+                };
+                apply_side(&mut book.bids, &data.bids, |a, b| a > b);
+                apply_side(&mut book.asks, &data.asks, |a, b| a < b);
+                if time_ms > 0 {
+                    book.time_ms = time_ms;
+                }
+                book.received_at = Instant::now();
+            }
+            changed.push(data.symbol);
+        }
+        changed
+    }
+
+    /// Connect to `url` and keep every subscribed book until the
+    /// connection fails; `on_update` gets each pair whose book changed
+    pub async fn run(&self, url: &str, mut on_update: impl FnMut(&str)) -> Result<(), String> {
+        let connect = || async {
+            let (ws, _) = tokio_tungstenite::connect_async(url)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(ws)
+        };
+        let subscriptions = || {
+            let pairs = self.pairs();
+            if pairs.is_empty() {
+                return Vec::new();
+            }
+            vec![subscription(&pairs, "subscribe")]
+        };
+        feed::run_ws(&self.commands, connect, subscriptions, KEEPALIVE, |text| {
+            for pair in self.apply(text) {
+                on_update(&pair);
+            }
+            Ok(())
+        })
+        .await
+    }
+}
+
+fn subscription(pairs: &[String], method: &str) -> String {
+    serde_json::json!({
+        "method": method,
+        "params": { "channel": "book", "symbol": pairs, "depth": BOOK_DEPTH },
+    })
+    .to_string()
+}
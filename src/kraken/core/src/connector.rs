@@ -0,0 +1,291 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Kraken behind the venue-neutral connector traits
+//!
+//! Instruments are pairs as WebSocket v2 names them ("BTC/USD");
+//! quantities are base asset units and prices quote units. Order ids are
+//! transaction ids. Spot orders cannot be reduce-only. Cancelling the
+//! orders of one pair cancels its open orders one by one. Top of book
+//! comes from the L2 book feed, connected on the first subscribe.
+//!
+//! Config: `api_key` and `secret` are the API key and its private key
+//! (base64); `endpoint` and `testnet` are not used.
+
+use std::sync::Arc;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, TimeInForce, TopOfBook,
+};
+use tracing::info;
+
+use crate::{BookFeed, KrakenError, OrderKind, OrderRequest, Side, Trader, WS_URL};
+
+const VENUE: &str = "kraken";
+
+// Venue errors mapped to their own error, by prefix
+const AUTH_ERRORS: [&str; 3] = [
+    "EAPI:Invalid key",
+    "EAPI:Invalid signature",
+    "EGeneral:Permission denied",
+];
+const BALANCE_ERRORS: [&str; 2] = ["EOrder:Insufficient funds", "EOrder:Insufficient margin"];
+const RATE_ERRORS: [&str; 1] = ["EGeneral:Too many requests"];
+const SERVICE_ERRORS: [&str; 2] = ["EService:Unavailable", "EService:Busy"];
+const PAIR_ERRORS: [&str; 1] = ["EQuery:Unknown asset pair"];
+
+impl From<KrakenError> for ConnectorError {
+    fn from(err: KrakenError) -> Self {
+        let starts =
+            |reason: &str, prefixes: &[&str]| prefixes.iter().any(|p| reason.starts_with(p));
+        let message = err.to_string();
+        match err {
+            KrakenError::InvalidKey(_) => Self::InvalidKey(message),
+            KrakenError::InvalidPair(_) => Self::InvalidInstrument(message),
+            KrakenError::InvalidOrder(_) => Self::InvalidOrder(message),
+            KrakenError::Rejected(reason) => match reason.as_str() {
+                r if starts(r, &AUTH_ERRORS) => Self::Auth(message),
+                r if starts(r, &BALANCE_ERRORS) => Self::InsufficientBalance(message),
+                // EAPI:, EOrder: and EGeneral: variants of the same limit
+                r if starts(r, &RATE_ERRORS) || r.contains("Rate limit exceeded") => {
+                    Self::RateLimited(message)
+                }
+                r if starts(r, &SERVICE_ERRORS) => Self::Venue(message),
+                r if starts(r, &PAIR_ERRORS) => Self::InvalidInstrument(message),
+                _ => Self::Rejected(message),
+            },
+            KrakenError::Api { status, .. } => match status {
+                401 | 403 => Self::Auth(message),
+                429 => Self::RateLimited(message),
+                500.. => Self::Venue(message),
+                _ => Self::Rejected(message),
+            },
+            KrakenError::Network(_) => Self::Network(message),
+            KrakenError::Decode(_) => Self::Decode(message),
+        }
+    }
+}
+
+/// Order request of a spec, refused if Kraken cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    if order.reduce_only {
+        return Err(ConnectorError::unsupported(
+            VENUE,
+            "reduce-only spot orders",
+        ));
+    }
+    let kind = match order.order_type {
+        OrderType::Market if order.post_only => {
+            return Err(ConnectorError::InvalidOrder(
+                "post-only applies to limit orders".to_string(),
+            ))
+        }
+        OrderType::Market => OrderKind::Market,
+        OrderType::Limit { price } => {
+            let immediate_or_cancel = match order.tif {
+                TimeInForce::Gtc => false,
+                TimeInForce::Ioc => true,
+                tif => {
+                    return Err(ConnectorError::unsupported(
+                        VENUE,
+                        &format!("{:?} orders", tif),
+                    ))
+                }
+            };
+            OrderKind::Limit {
+                price,
+                post_only: order.post_only,
+                immediate_or_cancel,
+            }
+        }
+    };
+    Ok(OrderRequest {
+        side: match order.side {
+            flox_connector::Side::Buy => Side::Buy,
+            flox_connector::Side::Sell => Side::Sell,
+        },
+        volume: order.quantity,
+        kind,
+    })
+}
+
+pub struct KrakenConnector {
+    trader: Arc<Trader>,
+    books: Arc<BookFeed>,
+    /// Task keeping the book feed connected, started by the first subscribe
+    book_feed: FeedTask,
+    gate: CallGate,
+}
+
+impl KrakenConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        post_only: true,
+        cancel_all: true,
+        balances: true,
+        top_of_book: true,
+        ..Capabilities::NONE
+    };
+
+    pub fn new(trader: Trader, gate: CallGate) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            books: Arc::new(BookFeed::new()),
+            book_feed: FeedTask::new(),
+            gate,
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Books of the subscribed pairs
+    pub fn books(&self) -> &Arc<BookFeed> {
+        &self.books
+    }
+
+    /// Rate limit and metrics of the session's calls
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+}
+
+impl Connector for KrakenConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let trader =
+                Trader::connect(config.require_api_key()?, config.require_secret()?).await?;
+            Ok(Self::new(trader, CallGate::new(config.rate_limit)))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            if self.books.subscribe(instrument) {
+                info!("[BOOK FEED] subscribed | pair={}", instrument);
+            }
+            let books = self.books.clone();
+            self.book_feed.ensure(|| async move {
+                feed::reconnect("BOOK FEED", || books.run(WS_URL, |_| {})).await
+            });
+            Ok(())
+        })
+    }
+
+    fn unsubscribe(&self, instrument: &str) -> Result<(), ConnectorError> {
+        if !self.books.unsubscribe(instrument) {
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        }
+        info!("[BOOK FEED] unsubscribed | pair={}", instrument);
+        Ok(())
+    }
+
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        let Some(book) = self.books.book(instrument) else {
+            if self.books.is_subscribed(instrument) {
+                return Ok(None);
+            }
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        };
+        let bid = book.bids.first().copied().unwrap_or_default();
+        let ask = book.asks.first().copied().unwrap_or_default();
+        Ok(Some(TopOfBook {
+            bid: bid.price,
+            bid_size: bid.size,
+            ask: ask.price,
+            ask_size: ask.size,
+            age: book.age(),
+        }))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for KrakenConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let request = order_request(order)?;
+            let place = self.trader.place(&order.instrument, request);
+            let outcome = self.gate.run(Call::OrderPost, place).await?;
+            Ok(PlacedOrder {
+                order_id: outcome.txid,
+                client_order_id: String::new(),
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        _instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(self.gate.run(Call::Cancel, self.trader.cancel(order_id)))
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            let Some(pair) = instrument else {
+                let count = self
+                    .gate
+                    .run(Call::Cancel, self.trader.cancel_all())
+                    .await?;
+                return Ok(Some(count));
+            };
+            let open = self.trader.open_orders().await?;
+            let mut count = 0;
+            for order in open.iter().filter(|o| o.pair == pair) {
+                let cancel = self.trader.cancel(&order.txid);
+                self.gate.run(Call::Cancel, cancel).await?;
+                count += 1;
+            }
+            Ok(Some(count))
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let held = self.gate.run(Call::Balance, self.trader.balances()).await?;
+            Ok(held
+                .into_iter()
+                .map(|b| Balance {
+                    asset: b.asset,
+                    free: b.free,
+                    total: b.total,
+                })
+                .collect())
+        })
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Fills of the account from the WebSocket v2 executions channel
+//!
+//! The executions channel replaces the v1 ownTrades and openOrders feeds.
+//! `Trader::run_fills` gets a token over REST, subscribes on the
+//! authenticated endpoint without snapshots and hands every `trade`
+//! execution to the caller as a `Fill`; order status events are skipped.
+//! It returns when the connection fails; the caller reconnects with a new
+//! token.
+
+use flox_connector::feed::{self, Commands};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::book_ws::{rfc3339_ms, KEEPALIVE};
+use crate::{Trader, WS_AUTH_URL};
+
+/// One fill of an order of the account
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Fill {
+    /// Transaction id of the order
+    pub order_id: String,
+    pub exec_id: String,
+    pub trade_id: u64,
+    /// WebSocket v2 name
+    pub pair: String,
+    /// buy or sell
+    pub side: String,
+    pub price: f64,
+    pub volume: f64,
+    /// Volume of the order filled so far
+    pub cum_volume: f64,
+    pub fee: f64,
+    pub fee_asset: String,
+    /// Our order was resting on the book
+    pub maker: bool,
+    /// Venue time of the fill (ms since the epoch)
+    pub time_ms: u64,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    channel: String,
+    #[serde(default)]
+    method: String,
+    #[serde(default)]
+    success: Option<bool>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    data: Vec<serde_json::Value>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Execution {
+    exec_type: String,
+    order_id: String,
+    exec_id: String,
+    trade_id: u64,
+    symbol: String,
+    side: String,
+    last_qty: f64,
+    last_price: f64,
+    cum_qty: f64,
+    liquidity_ind: String,
+    fees: Vec<FeeEntry>,
+    timestamp: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct FeeEntry {
+    asset: String,
+    qty: f64,
+}
+
+/// Fills of one text message; Err if the venue refused the subscription
+fn parse(text: &str) -> Result<Vec<Fill>, String> {
+    let envelope: Envelope = match serde_json::from_str(text) {
+        Ok(envelope) => envelope,
+        Err(e) => {
+            debug!("[FILLS] unreadable message | {}", e);
+            return Ok(Vec::new());
+        }
+    };
+    if envelope.method == "subscribe" && envelope.success == Some(false) {
+        return Err(format!(
+            "subscription refused: {}",
+            envelope.error.unwrap_or_default()
+        ));
+    }
+    if envelope.channel != "executions" {
+        return Ok(Vec::new());
+    }
+    let mut fills = Vec::new();
+    for data in envelope.data {
+        let execution: Execution = match serde_json::from_value(data) {
+            Ok(execution) => execution,
+            Err(e) => {
+                debug!("[FILLS] unreadable execution | {}", e);
+                continue;
+            }
+        };
+        if execution.exec_type != "trade" {
+            continue;
+        }
+        let fee = execution.fees.first();
+        fills.push(Fill {
+            order_id: execution.order_id,
+            exec_id: execution.exec_id,
+            trade_id: execution.trade_id,
+            pair: execution.symbol,
+            side: execution.side,
+            price: execution.last_price,
+            volume: execution.last_qty,
+            cum_volume: execution.cum_qty,
+            fee: fee.map_or(0.0, |f| f.qty),
+            fee_asset: fee.map(|f| f.asset.clone()).unwrap_or_default(),
+            maker: execution.liquidity_ind == "m",
+            time_ms: rfc3339_ms(&execution.timestamp),
+        });
+    }
+    Ok(fills)
+}
+
+impl Trader {
+    /// Stream the account's fills to `on_fill` until the connection fails
+    pub async fn run_fills(&self, mut on_fill: impl FnMut(Fill)) -> Result<(), String> {
+        let token = self.websockets_token().await.map_err(|e| e.to_string())?;
+        let connect = || async {
+            let (ws, _) = tokio_tungstenite::connect_async(WS_AUTH_URL)
+                .await
+                .map_err(|e| e.to_string())?;
+            info!("[FILLS] connected");
+            Ok(ws)
+        };
+        let subscribe = || {
+            let subscribe = serde_json::json!({
+                "method": "subscribe",
+                "params": {
+                    "channel": "executions",
+                    "token": token,
+                    "snap_orders": false,
+                    "snap_trades": false,
+                },
+            });
+            vec![subscribe.to_string()]
+        };
+        // Nothing to send once subscribed
+        let commands = Commands::new();
+        feed::run_ws(&commands, connect, subscribe, KEEPALIVE, |text| {
+            for fill in parse(text)? {
+                on_fill(fill);
+            }
+            Ok(())
+        })
+        .await
+    }
+}
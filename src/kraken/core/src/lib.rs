@@ -0,0 +1,361 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Kraken spot executor
+//!
+//! `Trader` is a session of one API key: orders, cancels, open orders and
+//! balances over the REST API, and the account's fills from the WebSocket
+//! v2 executions channel. `book_ws::BookFeed` keeps L2 books from the
+//! WebSocket v2 book channel; `connector::KrakenConnector` puts both
+//! behind the shared connector traits. The FFI library wraps it in the
+//! same C ABI conventions as the Polymarket executor, so the engine can
+//! hedge USDC exposure against fiat.
+//!
+//! Pairs are named as WebSocket v2 names them ("BTC/USD", "USDC/EUR");
+//! REST requests use the pair's REST name. Volumes are rounded down to the
+//! pair's lot decimals, prices to its price decimals.
+
+mod auth;
+pub mod book_ws;
+pub mod connector;
+pub mod executions_ws;
+pub mod orders;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use auth::Signer;
+pub use book_ws::{BookFeed, BookSnapshot, Level};
+pub use connector::KrakenConnector;
+pub use executions_ws::Fill;
+pub use orders::{OpenOrder, OrderKind, OrderOutcome, OrderRequest, Side};
+
+pub const REST_URL: &str = "https://api.kraken.com";
+pub const WS_URL: &str = "wss://ws.kraken.com/v2";
+pub const WS_AUTH_URL: &str = "wss://ws-auth.kraken.com/v2";
+
+/// Timeout of one request, connect included
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, thiserror::Error)]
+pub enum KrakenError {
+    #[error("invalid API key: {0}")]
+    InvalidKey(String),
+    #[error("unknown pair {0}")]
+    InvalidPair(String),
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// The venue refused the request, with its error ("EOrder:Insufficient
+    /// funds")
+    #[error("rejected: {0}")]
+    Rejected(String),
+    /// The venue answered with an error status
+    #[error("venue answered {status}: {message}")]
+    Api { status: u16, message: String },
+    /// No answer: timeout or connection error, venue state unknown
+    #[error("network: {0}")]
+    Network(String),
+    #[error("unexpected answer: {0}")]
+    Decode(String),
+}
+
+/// Every answer: errors, or the result
+#[derive(Deserialize)]
+struct Envelope<T> {
+    #[serde(default)]
+    error: Vec<String>,
+    result: Option<T>,
+}
+
+/// Decimal sent as a string or a number
+pub(crate) fn decimal<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Text(String),
+        Number(f64),
+    }
+    match Decimal::deserialize(d)? {
+        Decimal::Text(s) => s.parse().map_err(serde::de::Error::custom),
+        Decimal::Number(n) => Ok(n),
+    }
+}
+
+/// Asset name as WebSocket v2 writes it: XBT is BTC, XDG is DOGE
+fn v2_asset(name: &str) -> &str {
+    match name {
+        "XBT" => "BTC",
+        "XDG" => "DOGE",
+        _ => name,
+    }
+}
+
+/// Tradable pair
+#[derive(Clone, Debug)]
+pub struct Pair {
+    /// Name used by the REST API ("XBTUSD")
+    pub rest_name: String,
+    /// Decimal places of prices
+    pub price_decimals: u32,
+    /// Decimal places of volumes
+    pub lot_decimals: u32,
+    /// Smallest volume of an order
+    pub min_volume: f64,
+}
+
+#[derive(Deserialize)]
+struct PairInfo {
+    altname: String,
+    #[serde(default)]
+    wsname: String,
+    pair_decimals: u32,
+    lot_decimals: u32,
+    #[serde(deserialize_with = "decimal")]
+    ordermin: f64,
+    #[serde(default)]
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct AssetInfo {
+    altname: String,
+}
+
+/// Asset held
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Balance {
+    /// As WebSocket v2 names it ("BTC", "USD")
+    pub asset: String,
+    /// Not held by open orders
+    pub free: f64,
+    pub total: f64,
+}
+
+#[derive(Deserialize)]
+struct BalanceEntry {
+    #[serde(deserialize_with = "decimal")]
+    balance: f64,
+    #[serde(default, deserialize_with = "decimal")]
+    hold_trade: f64,
+}
+
+#[derive(Deserialize)]
+struct WebSocketsToken {
+    token: String,
+}
+
+pub struct Trader {
+    http: reqwest::Client,
+    signer: Signer,
+    last_nonce: AtomicU64,
+    /// By WebSocket v2 name
+    pairs: RwLock<HashMap<String, Pair>>,
+    /// WebSocket v2 asset name by REST asset name ("XXBT" -> "BTC")
+    assets: RwLock<HashMap<String, String>>,
+}
+
+impl Trader {
+    /// Open a session with an API key and its private key (base64),
+    /// checked by reading the balances
+    pub async fn connect(api_key: &str, private_key: &str) -> Result<Self, KrakenError> {
+        let signer = Signer::new(api_key.trim(), private_key.trim())?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| KrakenError::Network(e.to_string()))?;
+        let trader = Self {
+            http,
+            signer,
+            last_nonce: AtomicU64::new(0),
+            pairs: RwLock::new(HashMap::new()),
+            assets: RwLock::new(HashMap::new()),
+        };
+        trader.load_pairs().await?;
+        let balances = trader.balances().await?;
+        info!(
+            "[CONNECT] kraken | pairs={} | assets held={}",
+            trader.pairs.read().map(|p| p.len()).unwrap_or_default(),
+            balances.len()
+        );
+        Ok(trader)
+    }
+
+    /// Milliseconds since the epoch, strictly increasing per session
+    fn next_nonce(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let mut last = self.last_nonce.load(Ordering::Relaxed);
+        loop {
+            let nonce = now.max(last + 1);
+            match self.last_nonce.compare_exchange_weak(
+                last,
+                nonce,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return nonce,
+                Err(current) => last = current,
+            }
+        }
+    }
+
+    /// Decode an answer; the venue's errors come with status 200
+    async fn decode<T: DeserializeOwned>(resp: reqwest::Response) -> Result<T, KrakenError> {
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| KrakenError::Network(e.to_string()))?;
+        if !status.is_success() {
+            return Err(KrakenError::Api {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+        let envelope: Envelope<T> =
+            serde_json::from_str(&text).map_err(|e| KrakenError::Decode(e.to_string()))?;
+        if let Some(error) = envelope.error.into_iter().next() {
+            return Err(KrakenError::Rejected(error));
+        }
+        envelope
+            .result
+            .ok_or_else(|| KrakenError::Decode("answer without result".to_string()))
+    }
+
+    /// Query a public endpoint ("AssetPairs")
+    async fn public<T: DeserializeOwned>(&self, method: &str) -> Result<T, KrakenError> {
+        let resp = self
+            .http
+            .get(format!("{}/0/public/{}", REST_URL, method))
+            .send()
+            .await
+            .map_err(|e| KrakenError::Network(e.to_string()))?;
+        Self::decode(resp).await
+    }
+
+    /// Send a signed request to a private endpoint ("AddOrder")
+    /// Values are sent as they are: names, numbers and ids only
+    pub(crate) async fn private<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: &[(&str, String)],
+    ) -> Result<T, KrakenError> {
+        let path = format!("/0/private/{}", method);
+        let nonce = self.next_nonce();
+        let mut body = format!("nonce={}", nonce);
+        for (name, value) in params {
+            body.push_str(&format!("&{}={}", name, value));
+        }
+        let signature = self.signer.sign(&path, nonce, &body);
+        let resp = self
+            .http
+            .post(format!("{}{}", REST_URL, path))
+            .header("API-Key", self.signer.api_key())
+            .header("API-Sign", signature)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| KrakenError::Network(e.to_string()))?;
+        Self::decode(resp).await
+    }
+
+    /// Read the tradable pairs and the asset names
+    async fn load_pairs(&self) -> Result<(), KrakenError> {
+        let pairs: HashMap<String, PairInfo> = self.public("AssetPairs").await?;
+        let pairs: HashMap<String, Pair> = pairs
+            .into_values()
+            .filter(|info| info.status.is_empty() || info.status == "online")
+            .filter_map(|info| {
+                let (base, quote) = info.wsname.split_once('/')?;
+                let name = format!("{}/{}", v2_asset(base), v2_asset(quote));
+                let pair = Pair {
+                    rest_name: info.altname,
+                    price_decimals: info.pair_decimals,
+                    lot_decimals: info.lot_decimals,
+                    min_volume: info.ordermin,
+                };
+                Some((name, pair))
+            })
+            .collect();
+        *self.pairs.write().unwrap_or_else(|e| e.into_inner()) = pairs;
+
+        let assets: HashMap<String, AssetInfo> = self.public("Assets").await?;
+        let assets = assets
+            .into_iter()
+            .map(|(name, info)| (name, v2_asset(&info.altname).to_string()))
+            .collect();
+        *self.assets.write().unwrap_or_else(|e| e.into_inner()) = assets;
+        Ok(())
+    }
+
+    /// Pair by WebSocket v2 name ("BTC/USD"); the pairs are read again once
+    /// for a pair listed since connecting
+    pub async fn pair(&self, name: &str) -> Result<Pair, KrakenError> {
+        let cached = |trader: &Self| {
+            let pairs = trader.pairs.read().unwrap_or_else(|e| e.into_inner());
+            pairs.get(name).cloned()
+        };
+        if let Some(pair) = cached(self) {
+            return Ok(pair);
+        }
+        self.load_pairs().await?;
+        cached(self).ok_or_else(|| KrakenError::InvalidPair(name.to_string()))
+    }
+
+    /// WebSocket v2 name of a pair by its REST name, or the REST name if
+    /// not known
+    pub(crate) fn pair_name(&self, rest_name: &str) -> String {
+        let pairs = self.pairs.read().unwrap_or_else(|e| e.into_inner());
+        pairs
+            .iter()
+            .find(|(_, pair)| pair.rest_name == rest_name)
+            .map_or_else(|| rest_name.to_string(), |(name, _)| name.clone())
+    }
+
+    /// Non-zero balances
+    pub async fn balances(&self) -> Result<Vec<Balance>, KrakenError> {
+        let entries: HashMap<String, BalanceEntry> = self.private("BalanceEx", &[]).await?;
+        let assets = self.assets.read().unwrap_or_else(|e| e.into_inner());
+        Ok(entries
+            .into_iter()
+            .filter(|(_, entry)| entry.balance != 0.0)
+            .map(|(name, entry)| Balance {
+                asset: assets.get(&name).cloned().unwrap_or(name),
+                free: entry.balance - entry.hold_trade,
+                total: entry.balance,
+            })
+            .collect())
+    }
+
+    /// Amount of `asset` ("USD", "BTC") not held by open orders, 0 if none
+    pub async fn free_balance(&self, asset: &str) -> Result<f64, KrakenError> {
+        Ok(self
+            .balances()
+            .await?
+            .into_iter()
+            .filter(|b| b.asset == asset)
+            .map(|b| b.free)
+            .sum())
+    }
+
+    /// Token authenticating the private WebSocket channels
+    pub(crate) async fn websockets_token(&self) -> Result<String, KrakenError> {
+        let token: WebSocketsToken = self.private("GetWebSocketsToken", &[]).await?;
+        Ok(token.token)
+    }
+}
@@ -0,0 +1,261 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Orders, cancels and open orders
+//!
+//! AddOrder answers with the transaction id of the order only; what it
+//! filled comes from the executions feed, or from the open orders.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::{decimal, KrakenError, Pair, Trader};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderKind {
+    Market,
+    Limit {
+        price: f64,
+        /// Cancelled instead of taking
+        post_only: bool,
+        /// What does not fill at once is cancelled
+        immediate_or_cancel: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRequest {
+    pub side: Side,
+    /// Base asset units, > 0
+    pub volume: f64,
+    pub kind: OrderKind,
+}
+
+/// Venue's answer to an order
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderOutcome {
+    /// Transaction id ("OU22CG-KLAF2-FWUDD7")
+    pub txid: String,
+    /// The order as the venue read it ("buy 1.25 XBTUSD @ limit 27500.0")
+    pub description: String,
+}
+
+#[derive(Deserialize)]
+struct AddOrderResult {
+    #[serde(default)]
+    descr: Description,
+    txid: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct Description {
+    #[serde(default)]
+    order: String,
+}
+
+#[derive(Deserialize)]
+struct CancelResult {
+    count: u32,
+}
+
+#[derive(Deserialize)]
+struct OpenOrders {
+    open: HashMap<String, OpenOrderEntry>,
+}
+
+#[derive(Deserialize)]
+struct OpenOrderEntry {
+    #[serde(default)]
+    status: String,
+    opentm: f64,
+    descr: OpenOrderDescription,
+    #[serde(deserialize_with = "decimal")]
+    vol: f64,
+    #[serde(deserialize_with = "decimal")]
+    vol_exec: f64,
+}
+
+#[derive(Deserialize)]
+struct OpenOrderDescription {
+    pair: String,
+    r#type: String,
+    ordertype: String,
+    #[serde(deserialize_with = "decimal")]
+    price: f64,
+}
+
+/// Order open on the book
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OpenOrder {
+    pub txid: String,
+    /// WebSocket v2 name
+    pub pair: String,
+    /// buy or sell
+    pub side: String,
+    /// limit, market...
+    pub order_type: String,
+    pub price: f64,
+    pub volume: f64,
+    pub volume_executed: f64,
+    /// pending or open
+    pub status: String,
+    /// Creation time (ms since the epoch)
+    pub open_time_ms: u64,
+}
+
+/// Round to `decimals` places
+fn round_to(x: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    (x * scale).round() / scale
+}
+
+/// Volume rounded down to the pair's lot decimals
+fn round_volume(volume: f64, pair: &Pair) -> f64 {
+    let scale = 10f64.powi(pair.lot_decimals as i32);
+    // The nudge keeps 0.3 * 10 from rounding down to 2
+    (volume * scale + 1e-9).floor() / scale
+}
+
+impl Trader {
+    /// Check and place an order on `pair` ("BTC/USD")
+    pub async fn place(
+        &self,
+        pair_name: &str,
+        request: OrderRequest,
+    ) -> Result<OrderOutcome, KrakenError> {
+        let pair = self.pair(pair_name).await?;
+        let volume = round_volume(request.volume, &pair);
+        if !request.volume.is_finite() || volume <= 0.0 || volume < pair.min_volume {
+            return Err(KrakenError::InvalidOrder(format!(
+                "volume {} is below the minimum {} at {} decimals",
+                request.volume, pair.min_volume, pair.lot_decimals
+            )));
+        }
+
+        let mut params = vec![
+            ("pair", pair.rest_name.clone()),
+            ("type", request.side.as_str().to_string()),
+            (
+                "volume",
+                format!("{:.*}", pair.lot_decimals as usize, volume),
+            ),
+        ];
+        let mut price = None;
+        match request.kind {
+            OrderKind::Market => params.push(("ordertype", "market".to_string())),
+            OrderKind::Limit {
+                price: limit,
+                post_only,
+                immediate_or_cancel,
+            } => {
+                let rounded = round_to(limit, pair.price_decimals);
+                if !limit.is_finite() || rounded <= 0.0 {
+                    return Err(KrakenError::InvalidOrder(format!(
+                        "price must be > 0, got {}",
+                        limit
+                    )));
+                }
+                if post_only && immediate_or_cancel {
+                    return Err(KrakenError::InvalidOrder(
+                        "post-only orders cannot be immediate-or-cancel".to_string(),
+                    ));
+                }
+                params.push(("ordertype", "limit".to_string()));
+                let decimals = pair.price_decimals as usize;
+                params.push(("price", format!("{:.*}", decimals, rounded)));
+                if post_only {
+                    params.push(("oflags", "post".to_string()));
+                }
+                if immediate_or_cancel {
+                    params.push(("timeinforce", "IOC".to_string()));
+                }
+                price = Some(rounded);
+            }
+        }
+
+        let added: AddOrderResult = self.private("AddOrder", &params).await?;
+        let outcome = OrderOutcome {
+            txid: added
+                .txid
+                .into_iter()
+                .next()
+                .ok_or_else(|| KrakenError::Decode("order without txid".to_string()))?,
+            description: added.descr.order,
+        };
+        info!(
+            "[ORDER] {} {} | pair={} | volume={} | price={:?} | txid={}",
+            match request.kind {
+                OrderKind::Market => "market",
+                OrderKind::Limit { .. } => "limit",
+            },
+            request.side.as_str(),
+            pair_name,
+            volume,
+            price,
+            outcome.txid
+        );
+        Ok(outcome)
+    }
+
+    /// Cancel an open order by transaction id
+    pub async fn cancel(&self, txid: &str) -> Result<(), KrakenError> {
+        let result: CancelResult = self
+            .private("CancelOrder", &[("txid", txid.to_string())])
+            .await?;
+        if result.count == 0 {
+            return Err(KrakenError::Rejected(format!("{} not cancelled", txid)));
+        }
+        info!("[CANCEL] txid={}", txid);
+        Ok(())
+    }
+
+    /// Cancel every open order; returns the number cancelled
+    pub async fn cancel_all(&self) -> Result<u32, KrakenError> {
+        let result: CancelResult = self.private("CancelAll", &[]).await?;
+        info!("[CANCEL_ALL] cancelled={}", result.count);
+        Ok(result.count)
+    }
+
+    /// Orders open on the account
+    pub async fn open_orders(&self) -> Result<Vec<OpenOrder>, KrakenError> {
+        let orders: OpenOrders = self.private("OpenOrders", &[]).await?;
+        Ok(orders
+            .open
+            .into_iter()
+            .map(|(txid, entry)| OpenOrder {
+                txid,
+                pair: self.pair_name(&entry.descr.pair),
+                side: entry.descr.r#type,
+                order_type: entry.descr.ordertype,
+                price: entry.descr.price,
+                volume: entry.vol,
+                volume_executed: entry.vol_exec,
+                status: entry.status,
+                open_time_ms: (entry.opentm * 1000.0) as u64,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,702 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Kraken spot executor - C FFI
+//!
+//! The C ABI of kraken_ffi.h, laid out like the Polymarket executor's: one
+//! global `KrakenConnector` set up by kraken_init(), blocking calls that
+//! run on the shared connector runtime, negative error codes (the same
+//! numbers as POLYMARKET_ERR_* where the meaning is shared), raw amounts
+//! with 6 decimals, thread-local last error and last response buffers.
+//! Books are kept by a background task of the connector, as
+//! polymarket_subscribe_book() does; fills go to a callback as
+//! polymarket_subscribe_order_updates() does.
+
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::ffi::args::{self, str_arg};
+use flox_connector::ffi::{guard, out_buf, session};
+use flox_connector::{runtime, Call, Connector, ConnectorConfig, ConnectorError};
+use kraken_executor_core::{
+    Fill, KrakenConnector, KrakenError, Level, OrderKind, OrderOutcome, OrderRequest, Side, Trader,
+};
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+// Error codes
+pub const KRAKEN_OK: i32 = 0;
+/// Not an error: no data yet
+pub const KRAKEN_PENDING: i32 = 1;
+pub const KRAKEN_ERR_NOT_INITIALIZED: i32 = -1;
+/// Key or private key malformed
+pub const KRAKEN_ERR_INVALID_KEY: i32 = -2;
+/// Key refused, or lacks the permission
+pub const KRAKEN_ERR_AUTH: i32 = -3;
+/// Not a tradable pair
+pub const KRAKEN_ERR_INVALID_PAIR: i32 = -4;
+pub const KRAKEN_ERR_ORDER_FAILED: i32 = -5;
+pub const KRAKEN_ERR_CANCEL_FAILED: i32 = -6;
+/// Not enough funds or margin
+pub const KRAKEN_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+pub const KRAKEN_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Internal panic
+pub const KRAKEN_ERR_PANIC: i32 = -14;
+/// Venue refused the request, see last error
+pub const KRAKEN_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const KRAKEN_ERR_NETWORK: i32 = -20;
+/// Rate or request limit exceeded
+pub const KRAKEN_ERR_RATE_LIMITED: i32 = -21;
+/// Venue unavailable or busy, order state unknown
+pub const KRAKEN_ERR_VENUE_ERROR: i32 = -22;
+/// Volume below the minimum, or price not valid
+pub const KRAKEN_ERR_INVALID_ORDER: i32 = -23;
+
+// Order flags
+/// Cancelled instead of taking
+pub const KRAKEN_FLAG_POST_ONLY: u32 = 1;
+/// What does not fill at once is cancelled
+pub const KRAKEN_FLAG_IOC: u32 = 2;
+
+/// Levels per side in a KrakenBookSnapshot
+pub const KRAKEN_BOOK_DEPTH: usize = 10;
+
+/// Raw units per asset unit
+const RAW_SCALE: f64 = 1_000_000.0;
+
+/// Order result, filled by the order entry points
+#[repr(C)]
+pub struct KrakenOrderResult {
+    /// True if the venue accepted the order
+    pub success: bool,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Transaction id of the order ("OU22CG-KLAF2-FWUDD7")
+    pub txid: [c_char; 32],
+    /// Total execution latency
+    pub latency_ms: u64,
+}
+
+impl KrakenOrderResult {
+    fn failed(code: i32) -> Self {
+        Self {
+            success: false,
+            error_code: code,
+            txid: [0; 32],
+            latency_ms: 0,
+        }
+    }
+
+    fn placed(outcome: &OrderOutcome, latency_ms: u64) -> Self {
+        let mut result = Self::failed(KRAKEN_OK);
+        result.success = true;
+        out_buf::write_fixed(&outcome.txid, &mut result.txid);
+        result.latency_ms = latency_ms;
+        result
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct KrakenBookLevel {
+    pub price_raw: i64,
+    /// Base asset units
+    pub size_raw: i64,
+}
+
+/// Top levels of a subscribed pair's book, filled by
+/// kraken_get_book_snapshot()
+#[repr(C)]
+#[derive(Default)]
+pub struct KrakenBookSnapshot {
+    /// Levels set in bids, best (highest) first
+    pub bid_count: u32,
+    /// Levels set in asks, best (lowest) first
+    pub ask_count: u32,
+    /// Since the last message for the book was received
+    pub age_ms: u64,
+    /// Venue time of the last update (ms since the epoch), 0 until one came
+    pub time_ms: u64,
+    pub bids: [KrakenBookLevel; KRAKEN_BOOK_DEPTH],
+    pub asks: [KrakenBookLevel; KRAKEN_BOOK_DEPTH],
+}
+
+/// Fill of an order of the account, passed to KrakenFillCallback; raw
+/// values in 6 decimals (divide by 1M for actual)
+#[repr(C)]
+pub struct KrakenFill {
+    pub is_buy: bool,
+    /// Our order was resting on the book
+    pub maker: bool,
+    pub trade_id: u64,
+    pub price_raw: i64,
+    /// Volume of this fill
+    pub volume_raw: i64,
+    /// Volume of the order filled so far
+    pub cum_volume_raw: i64,
+    pub fee_raw: i64,
+    /// Venue time of the fill
+    pub time_ms: u64,
+    /// Transaction id of the order
+    pub order_id: [c_char; 32],
+    pub exec_id: [c_char; 32],
+    pub pair: [c_char; 32],
+    pub fee_asset: [c_char; 16],
+}
+
+/// Fill callback of kraken_subscribe_fills(); `fill` is only valid during
+/// the call, `user_data` is as passed when subscribing
+pub type KrakenFillCallback =
+    extern "C" fn(fill: *const KrakenFill, user_data: *mut std::ffi::c_void);
+
+struct FillCallback {
+    callback: KrakenFillCallback,
+    user_data: *mut std::ffi::c_void,
+}
+
+// SAFETY: user_data is opaque to us; the host owns its thread-safety
+unsafe impl Send for FillCallback {}
+
+impl FillCallback {
+    fn call(&self, fill: &Fill) {
+        let mut out = KrakenFill {
+            is_buy: fill.side == "buy",
+            maker: fill.maker,
+            trade_id: fill.trade_id,
+            price_raw: to_raw(fill.price),
+            volume_raw: to_raw(fill.volume),
+            cum_volume_raw: to_raw(fill.cum_volume),
+            fee_raw: to_raw(fill.fee),
+            time_ms: fill.time_ms,
+            order_id: [0; 32],
+            exec_id: [0; 32],
+            pair: [0; 32],
+            fee_asset: [0; 16],
+        };
+        out_buf::write_fixed(&fill.order_id, &mut out.order_id);
+        out_buf::write_fixed(&fill.exec_id, &mut out.exec_id);
+        out_buf::write_fixed(&fill.pair, &mut out.pair);
+        out_buf::write_fixed(&fill.fee_asset, &mut out.fee_asset);
+        (self.callback)(&out, self.user_data);
+    }
+}
+
+type SharedCallback = Arc<Mutex<Option<FillCallback>>>;
+
+/// The connector and the fills feed, which only the C ABI has
+struct Executor {
+    connector: KrakenConnector,
+    fills: SharedCallback,
+    /// Task keeping the executions feed connected
+    fill_feed: FeedTask,
+}
+
+/// Venue id the session is kept under
+const VENUE: &str = "kraken";
+
+fn get_executor() -> Option<Arc<Executor>> {
+    session::get(VENUE)
+}
+
+fn init_logging() {
+    use tracing_subscriber::filter::EnvFilter;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("kraken_executor=info"))
+        .try_init();
+}
+
+fn to_raw(x: f64) -> i64 {
+    (x * RAW_SCALE).round() as i64
+}
+
+/// Pair argument as WebSocket v2 names it ("BTC/USD")
+fn pair_arg<'a>(pair: *const c_char) -> Option<&'a str> {
+    str_arg(pair).filter(|p| {
+        p.split_once('/').is_some_and(|(base, quote)| !base.is_empty() && !quote.is_empty())
+            && p.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'/' || b == b'.')
+    })
+}
+
+/// Keep the message of a failed call and return its code; `fallback` for
+/// answers that could not be read
+fn fail(op: &str, err: &ConnectorError, fallback: i32) -> i32 {
+    error!("[{}] failed | {}", op, err);
+    out_buf::set_last_error(&err.to_string());
+    err.code(fallback)
+}
+
+/// Connect with an API key and its private key (base64), checked by
+/// reading the balances; the key needs the query and trade permissions.
+/// Replaces a session already set up. Returns 0 on success, negative error
+/// code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_init(api_key: *const c_char, private_key: *const c_char) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        init_logging();
+        let (Some(api_key), Some(private_key)) = (str_arg(api_key), str_arg(private_key)) else {
+            return KRAKEN_ERR_INVALID_KEY;
+        };
+        let config = ConnectorConfig {
+            api_key: Some(api_key.to_string()),
+            secret: Some(Zeroizing::new(private_key.to_string())),
+            ..Default::default()
+        };
+
+        let connector = match runtime::block_on(KrakenConnector::connect(config)) {
+            Ok(Ok(connector)) => connector,
+            Ok(Err(e)) => return fail("INIT", &e, KRAKEN_ERR_NOT_INITIALIZED),
+            Err(e) => {
+                error!("[INIT] failed | {}", e);
+                out_buf::set_last_error(&e);
+                return KRAKEN_ERR_NOT_INITIALIZED;
+            }
+        };
+        let executor = Arc::new(Executor {
+            connector,
+            fills: Arc::new(Mutex::new(None)),
+            fill_feed: FeedTask::new(),
+        });
+        let previous = session::install(VENUE, executor);
+        drop(previous);
+        info!("[INIT] kraken executor ready");
+        KRAKEN_OK
+    })
+}
+
+/// Shut the session down and close both feeds; calls still running finish
+/// first. kraken_init() can be called again afterwards
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_shutdown() {
+    guard::catch((), || {
+        let executor = session::take::<Arc<Executor>>(VENUE);
+        if let Some(executor) = executor {
+            // The book feed stops when the connector is dropped
+            executor.fill_feed.stop();
+            *executor.fills.lock().unwrap_or_else(|e| e.into_inner()) = None;
+            info!("[SHUTDOWN] kraken executor stopped");
+        }
+    })
+}
+
+/// Place an order and fill `out`
+fn place(
+    op: &'static str,
+    pair: *const c_char,
+    request: OrderRequest,
+    out: *mut KrakenOrderResult,
+) -> i32 {
+    let Some(out) = args::out_arg(out) else {
+        return KRAKEN_ERR_INVALID_ARGUMENT;
+    };
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => {
+            *out = KrakenOrderResult::failed(KRAKEN_ERR_NOT_INITIALIZED);
+            return KRAKEN_ERR_NOT_INITIALIZED;
+        }
+    };
+    let Some(pair) = pair_arg(pair) else {
+        *out = KrakenOrderResult::failed(KRAKEN_ERR_INVALID_PAIR);
+        return KRAKEN_ERR_INVALID_PAIR;
+    };
+
+    let started = Instant::now();
+    let pair_owned = pair.to_string();
+    let result = runtime::block_on(async move {
+        let connector = &executor.connector;
+        let place = connector.trader().place(&pair_owned, request);
+        connector.gate().run(Call::OrderPost, place).await
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+    *out = match result {
+        Ok(Ok(outcome)) => KrakenOrderResult::placed(&outcome, latency_ms),
+        Ok(Err(e)) => KrakenOrderResult::failed(fail(op, &e, KRAKEN_ERR_ORDER_FAILED)),
+        Err(e) => {
+            error!("[{}] failed | pair={} | {}", op, pair, e);
+            out_buf::set_last_error(&e);
+            KrakenOrderResult::failed(KRAKEN_ERR_ORDER_FAILED)
+        }
+    };
+    out.latency_ms = latency_ms;
+    out.error_code
+}
+
+fn side(is_buy: bool) -> Side {
+    if is_buy {
+        Side::Buy
+    } else {
+        Side::Sell
+    }
+}
+
+/// Market order of `volume` base asset units, rounded down to the pair's
+/// lot decimals
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_market_order(
+    pair: *const c_char,
+    is_buy: bool,
+    volume: f64,
+    out: *mut KrakenOrderResult,
+) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let request = OrderRequest {
+            side: side(is_buy),
+            volume,
+            kind: OrderKind::Market,
+        };
+        place("MARKET", pair, request, out)
+    })
+}
+
+/// Limit order of `volume` base asset units at `price`, rounded to the
+/// pair's price decimals
+/// `flags`: KRAKEN_FLAG_POST_ONLY or KRAKEN_FLAG_IOC, or 0 for good till
+/// cancelled
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_limit_order(
+    pair: *const c_char,
+    is_buy: bool,
+    volume: f64,
+    price: f64,
+    flags: u32,
+    out: *mut KrakenOrderResult,
+) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        if flags & !(KRAKEN_FLAG_POST_ONLY | KRAKEN_FLAG_IOC) != 0 {
+            return KRAKEN_ERR_INVALID_ARGUMENT;
+        }
+        let request = OrderRequest {
+            side: side(is_buy),
+            volume,
+            kind: OrderKind::Limit {
+                price,
+                post_only: flags & KRAKEN_FLAG_POST_ONLY != 0,
+                immediate_or_cancel: flags & KRAKEN_FLAG_IOC != 0,
+            },
+        };
+        place("LIMIT", pair, request, out)
+    })
+}
+
+/// Cancel an open order by transaction id
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_cancel(txid: *const c_char) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+        let Some(txid) = str_arg(txid).filter(|t| {
+            !t.is_empty() && t.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        }) else {
+            return KRAKEN_ERR_INVALID_ARGUMENT;
+        };
+
+        let txid_owned = txid.to_string();
+        let result = runtime::block_on(async move {
+            let connector = &executor.connector;
+            connector.gate().run(Call::Cancel, connector.trader().cancel(&txid_owned)).await
+        });
+        match result {
+            Ok(Ok(())) => KRAKEN_OK,
+            Ok(Err(e)) => fail("CANCEL", &e, KRAKEN_ERR_CANCEL_FAILED),
+            Err(e) => {
+                error!("[CANCEL] failed | txid={} | {}", txid, e);
+                out_buf::set_last_error(&e);
+                KRAKEN_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cancel every open order of the account
+/// Returns the number cancelled (0 if none was open), or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_cancel_all() -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+
+        let result = runtime::block_on(async move {
+            let connector = &executor.connector;
+            connector.gate().run(Call::Cancel, connector.trader().cancel_all()).await
+        });
+        match result {
+            Ok(Ok(count)) => count.min(i32::MAX as u32) as i32,
+            Ok(Err(e)) => fail("CANCEL_ALL", &e, KRAKEN_ERR_CANCEL_FAILED),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                KRAKEN_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Amount of `asset` ("USD", "BTC") not held by open orders (raw, 6
+/// decimals), 0 if none held, or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_get_balance(asset: *const c_char) -> i64 {
+    guard::catch(KRAKEN_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED as i64,
+        };
+        let Some(asset) = str_arg(asset)
+            .filter(|a| !a.is_empty() && a.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'.'))
+        else {
+            return KRAKEN_ERR_INVALID_ARGUMENT as i64;
+        };
+
+        let asset = asset.to_string();
+        let result = runtime::block_on(async move {
+            let connector = &executor.connector;
+            connector.gate().run(Call::Balance, connector.trader().free_balance(&asset)).await
+        });
+        match result {
+            Ok(Ok(free)) => to_raw(free),
+            Ok(Err(e)) => fail("BALANCE", &e, KRAKEN_ERR_VENUE_ERROR) as i64,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                KRAKEN_ERR_VENUE_ERROR as i64
+            }
+        }
+    })
+}
+
+/// Run a query and keep its JSON for kraken_last_response()
+fn query_json<Fut, T>(op: &str, query: impl FnOnce(Arc<Trader>) -> Fut) -> i64
+where
+    Fut: Future<Output = Result<T, KrakenError>> + Send + 'static,
+    T: serde::Serialize + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return KRAKEN_ERR_NOT_INITIALIZED as i64,
+    };
+    let query = query(executor.connector.trader().clone());
+    match runtime::block_on(async move { query.await.map_err(ConnectorError::from) }) {
+        Ok(Ok(value)) => match serde_json::to_string(&value) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(_) => KRAKEN_ERR_VENUE_ERROR as i64,
+        },
+        Ok(Err(e)) => fail(op, &e, KRAKEN_ERR_VENUE_ERROR) as i64,
+        Err(e) => {
+            out_buf::set_last_error(&e);
+            KRAKEN_ERR_VENUE_ERROR as i64
+        }
+    }
+}
+
+/// Non-zero balances as JSON [{"asset", "free", "total"}]; returns its size
+/// (kraken_last_response()) or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_get_balances() -> i64 {
+    guard::catch(KRAKEN_ERR_PANIC as i64, || {
+        query_json("BALANCES", |trader| async move { trader.balances().await })
+    })
+}
+
+/// Open orders as JSON [{"txid", "pair", "side", "order_type", "price",
+/// "volume", "volume_executed", "status", "open_time_ms"}]; returns its
+/// size or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_get_open_orders() -> i64 {
+    guard::catch(KRAKEN_ERR_PANIC as i64, || {
+        query_json("OPEN_ORDERS", |trader| async move { trader.open_orders().await })
+    })
+}
+
+/// Keep the executions feed connected until aborted, handing fills to the
+/// registered callback
+async fn fill_feed_task(trader: Arc<Trader>, callback: SharedCallback) {
+    feed::reconnect("FILLS", || {
+        trader.run_fills(|fill| {
+            let callback = callback.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(callback) = callback.as_ref() {
+                callback.call(&fill);
+            }
+        })
+    })
+    .await
+}
+
+/// Stream the account's fills to `callback`, replacing the callback set
+/// before. Fills made while disconnected are not replayed
+/// The callback runs on a runtime worker thread, one fill at a time; it
+/// must not block or call back into the library
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_subscribe_fills(
+    callback: Option<KrakenFillCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+        let Some(callback) = callback else {
+            return KRAKEN_ERR_INVALID_ARGUMENT;
+        };
+
+        *executor.fills.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(FillCallback { callback, user_data });
+        let _runtime = match runtime::enter() {
+            Ok(guard) => guard,
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                return KRAKEN_ERR_NOT_INITIALIZED;
+            }
+        };
+        let trader = executor.connector.trader().clone();
+        let callback = executor.fills.clone();
+        if executor.fill_feed.ensure(|| fill_feed_task(trader, callback)) {
+            info!("[FILLS] subscribed");
+        }
+        KRAKEN_OK
+    })
+}
+
+/// Close the executions feed. Once this returns the callback is not
+/// invoked again. No-op if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_unsubscribe_fills() -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+
+        if executor.fill_feed.stop() {
+            info!("[FILLS] unsubscribed");
+        }
+        // Waits for a callback running on a worker
+        *executor.fills.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        KRAKEN_OK
+    })
+}
+
+/// Keep the L2 book of a pair ("BTC/USD") from the WebSocket
+/// The first subscription opens the connection; it reconnects on its own
+/// and closes on shutdown. Returns KRAKEN_OK, also if already subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_subscribe_book(pair: *const c_char) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+        let Some(pair) = pair_arg(pair) else {
+            return KRAKEN_ERR_INVALID_PAIR;
+        };
+
+        let pair_owned = pair.to_string();
+        let subscribed = runtime::block_on(async move {
+            executor.connector.subscribe(&pair_owned).await
+        });
+        match subscribed {
+            Ok(Ok(())) => KRAKEN_OK,
+            Ok(Err(e)) => fail("SUBSCRIBE", &e, KRAKEN_ERR_INVALID_ARGUMENT),
+            Err(e) => {
+                out_buf::set_last_error(&e);
+                KRAKEN_ERR_NOT_INITIALIZED
+            }
+        }
+    })
+}
+
+/// Stop keeping a pair's book; the connection stays open
+/// Returns KRAKEN_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_unsubscribe_book(pair: *const c_char) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+        let Some(pair) = pair_arg(pair) else {
+            return KRAKEN_ERR_INVALID_PAIR;
+        };
+
+        match executor.connector.unsubscribe(pair) {
+            Ok(()) => KRAKEN_OK,
+            Err(e) => e.code(KRAKEN_ERR_INVALID_ARGUMENT),
+        }
+    })
+}
+
+/// Top KRAKEN_BOOK_DEPTH levels per side of a subscribed pair's book
+/// Returns KRAKEN_PENDING until the venue sent its snapshot (`out`
+/// untouched), KRAKEN_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_get_book_snapshot(
+    pair: *const c_char,
+    out: *mut KrakenBookSnapshot,
+) -> i32 {
+    guard::catch(KRAKEN_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return KRAKEN_ERR_NOT_INITIALIZED,
+        };
+        let Some(pair) = pair_arg(pair) else {
+            return KRAKEN_ERR_INVALID_PAIR;
+        };
+        let Some(out) = args::out_arg(out) else {
+            return KRAKEN_ERR_INVALID_ARGUMENT;
+        };
+        let books = executor.connector.books();
+        let book = match books.book(pair) {
+            Some(book) => book,
+            None if books.is_subscribed(pair) => return KRAKEN_PENDING,
+            None => return KRAKEN_ERR_INVALID_ARGUMENT,
+        };
+
+        let mut value = KrakenBookSnapshot {
+            age_ms: book.age().as_millis() as u64,
+            time_ms: book.time_ms,
+            ..Default::default()
+        };
+        let fill = |out: &mut [KrakenBookLevel], levels: &[Level]| {
+            for (slot, level) in out.iter_mut().zip(levels) {
+                *slot = KrakenBookLevel {
+                    price_raw: to_raw(level.price),
+                    size_raw: to_raw(level.size),
+                };
+            }
+            levels.len().min(KRAKEN_BOOK_DEPTH) as u32
+        };
+        value.bid_count = fill(&mut value.bids, &book.bids);
+        value.ask_count = fill(&mut value.asks, &book.asks);
+        *out = value;
+        KRAKEN_OK
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// Returns the buffer size needed (see out_buf)
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| args::write_str(json, buf, len))
+    })
+}
+
+/// Copy the error message of the last failed call from the calling thread
+#[unsafe(no_mangle)]
+pub extern "C" fn kraken_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| args::write_str(message, buf, len))
+    })
+}
@@ -0,0 +1,350 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Execution algos: work a large order as a series of small ones
+//!
+//! - TWAP splits the total into equal market orders (FAK) sent at a fixed
+//!   interval; whatever a slice leaves unfilled is carried into the next.
+//! - Iceberg rests one GTC clip of at most `show_size` shares at a fixed
+//!   price and posts the next once it has filled, so the book never shows
+//!   more than a clip.
+//!
+//! Every order goes through `Trader::place` and its pre-trade checks. An algo
+//! stops on the first order that is rejected or fails.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use polymarket_client_sdk::clob::types::Side;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::{OrderError, OrderRequest, Trader, DECIMAL_SCALE, MARKET_BUY_PRICE, MARKET_SELL_PRICE};
+
+/// How often a wait checks for cancellation
+const CANCEL_POLL: Duration = Duration::from_millis(100);
+
+/// Shortest iceberg clip status poll accepted
+pub const MIN_ICEBERG_POLL: Duration = Duration::from_millis(500);
+
+/// Smallest order quantity the venue accepts (shares are in 0.01 steps)
+const MIN_CLIP: f64 = 0.01;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlgoSpec {
+    /// `total` (USDC for buys, shares for sells) in `slices` market orders
+    /// sent `interval` apart, the first one immediately
+    Twap {
+        side: Side,
+        total: f64,
+        slices: u32,
+        interval: Duration,
+    },
+    /// `total` shares at `price`, at most `show_size` resting at a time;
+    /// the resting clip is checked for fills every `poll`
+    Iceberg {
+        side: Side,
+        price: f64,
+        total: f64,
+        show_size: f64,
+        poll: Duration,
+    },
+}
+
+impl AlgoSpec {
+    /// Name used in logs and events
+    pub fn name(&self) -> &'static str {
+        match self {
+            AlgoSpec::Twap { .. } => "twap",
+            AlgoSpec::Iceberg { .. } => "iceberg",
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), OrderError> {
+        let valid = match *self {
+            AlgoSpec::Twap { total, slices, .. } => total > 0.0 && slices > 0,
+            AlgoSpec::Iceberg {
+                price,
+                total,
+                show_size,
+                poll,
+                ..
+            } => {
+                price > 0.0
+                    && price < 1.0
+                    && total >= MIN_CLIP
+                    && show_size >= MIN_CLIP
+                    && poll >= MIN_ICEBERG_POLL
+            }
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(OrderError::InvalidAmount)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlgoState {
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AlgoProgress {
+    pub state: AlgoState,
+    /// Amount to work: USDC for TWAP buys, shares otherwise
+    pub total: f64,
+    /// Orders placed so far
+    pub orders: u32,
+    /// Shares bought or sold so far (buys net of taker fees)
+    pub filled_qty_raw: i64,
+    /// Part of the total worked off, in its units
+    pub executed: f64,
+    /// Part of the total left
+    pub remaining: f64,
+    pub last_order_id: String,
+}
+
+impl AlgoProgress {
+    fn new(total: f64) -> Self {
+        Self {
+            state: AlgoState::Running,
+            total,
+            orders: 0,
+            filled_qty_raw: 0,
+            executed: 0.0,
+            remaining: total,
+            last_order_id: String::new(),
+        }
+    }
+}
+
+/// Algo stopped by a rejected or failed order
+#[derive(Debug, thiserror::Error)]
+#[error("{error}")]
+pub struct AlgoFailed {
+    /// Progress up to the failure, state `Failed`
+    pub progress: AlgoProgress,
+    pub error: OrderError,
+}
+
+/// Cancel flag shared between a running algo and its owner
+#[derive(Debug, Default)]
+pub struct AlgoControl {
+    cancelled: AtomicBool,
+}
+
+impl AlgoControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop at the next check; a resting iceberg clip is cancelled
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Sleep for `duration`; false if cancelled meanwhile
+    async fn wait(&self, duration: Duration) -> bool {
+        let deadline = tokio::time::Instant::now() + duration;
+        loop {
+            if self.is_cancelled() {
+                return false;
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return true;
+            }
+            tokio::time::sleep(CANCEL_POLL.min(deadline - now)).await;
+        }
+    }
+}
+
+fn raw_to_f64(raw: i64) -> f64 {
+    raw as f64 / DECIMAL_SCALE as f64
+}
+
+/// A running algo and what it reports to
+struct Run<'a, F> {
+    trader: &'a Trader,
+    token_id: &'a str,
+    control: &'a AlgoControl,
+    progress: AlgoProgress,
+    on_progress: F,
+}
+
+impl Trader {
+    /// Run an algo to completion, cancellation or the first failed order
+    /// `on_progress` is called after every order placed (or clip done)
+    pub async fn run_algo(
+        &self,
+        token_id: &str,
+        spec: AlgoSpec,
+        control: &AlgoControl,
+        on_progress: impl FnMut(&AlgoProgress),
+    ) -> Result<AlgoProgress, AlgoFailed> {
+        let total = match spec {
+            AlgoSpec::Twap { total, .. } | AlgoSpec::Iceberg { total, .. } => total,
+        };
+        let mut run = Run {
+            trader: self,
+            token_id,
+            control,
+            progress: AlgoProgress::new(total),
+            on_progress,
+        };
+
+        let result = match spec.validate() {
+            Err(e) => Err(e),
+            Ok(()) => match spec {
+                AlgoSpec::Twap {
+                    side,
+                    slices,
+                    interval,
+                    ..
+                } => run.twap(side, slices, interval).await,
+                AlgoSpec::Iceberg {
+                    side,
+                    price,
+                    show_size,
+                    poll,
+                    ..
+                } => run.iceberg(side, price, show_size, poll).await,
+            },
+        };
+        let mut progress = run.progress;
+        match result {
+            Ok(()) => {
+                if progress.state == AlgoState::Running {
+                    progress.state = AlgoState::Completed;
+                }
+                Ok(progress)
+            }
+            Err(error) => {
+                progress.state = AlgoState::Failed;
+                Err(AlgoFailed { progress, error })
+            }
+        }
+    }
+}
+
+impl<F: FnMut(&AlgoProgress)> Run<'_, F> {
+    fn record(&mut self, order_id: &str, filled_qty_raw: i64, executed: f64) {
+        let progress = &mut self.progress;
+        progress.filled_qty_raw += filled_qty_raw;
+        progress.executed += executed;
+        progress.remaining = (progress.total - progress.executed).max(0.0);
+        progress.last_order_id = order_id.to_string();
+        (self.on_progress)(progress);
+    }
+
+    async fn twap(
+        &mut self,
+        side: Side,
+        slices: u32,
+        interval: Duration,
+    ) -> Result<(), OrderError> {
+        for slice in 0..slices {
+            let waited = slice == 0 || self.control.wait(interval).await;
+            if !waited || self.control.is_cancelled() {
+                self.progress.state = AlgoState::Cancelled;
+                return Ok(());
+            }
+
+            let amount = self.progress.remaining / (slices - slice) as f64;
+            let request = match side {
+                Side::Buy => OrderRequest::MarketBuy {
+                    usdc_amount: amount,
+                    max_price: MARKET_BUY_PRICE,
+                },
+                _ => OrderRequest::MarketSell {
+                    size: amount,
+                    min_price: MARKET_SELL_PRICE,
+                },
+            };
+            let fill = self.trader.place(self.token_id, request).await?;
+            self.progress.orders += 1;
+            // Buys are worked in USDC: shares before fee times their price
+            let executed = match side {
+                Side::Buy => {
+                    raw_to_f64(fill.filled_qty_raw + fill.fee_raw) * raw_to_f64(fill.avg_price_raw)
+                }
+                _ => raw_to_f64(fill.filled_qty_raw),
+            };
+            self.record(&fill.order_id, fill.filled_qty_raw, executed);
+        }
+        Ok(())
+    }
+
+    async fn iceberg(
+        &mut self,
+        side: Side,
+        price: f64,
+        show_size: f64,
+        poll: Duration,
+    ) -> Result<(), OrderError> {
+        while self.progress.remaining >= MIN_CLIP {
+            if self.control.is_cancelled() {
+                self.progress.state = AlgoState::Cancelled;
+                return Ok(());
+            }
+
+            let clip = (show_size.min(self.progress.remaining) * 100.0).floor() / 100.0;
+            let request = match side {
+                Side::Buy => OrderRequest::LimitBuy {
+                    price,
+                    usdc_amount: clip * price,
+                },
+                _ => OrderRequest::LimitSell { price, size: clip },
+            };
+            let fill = self.trader.place(self.token_id, request).await?;
+            if !fill.success {
+                return Err(OrderError::Api(anyhow::anyhow!(
+                    "clip rejected by the venue"
+                )));
+            }
+            self.progress.orders += 1;
+
+            let mut matched_raw = fill.filled_qty_raw;
+            let mut live = fill.remaining_qty_raw > 0;
+            while live {
+                if !self.control.wait(poll).await {
+                    // Pull the clip, then count what filled before it went
+                    if let Err(e) = self.trader.cancel(&fill.order_id).await {
+                        warn!("[ALGO] iceberg clip cancel failed | {}", e);
+                    }
+                    self.progress.state = AlgoState::Cancelled;
+                }
+                let status = self.trader.order_status(&fill.order_id).await?;
+                matched_raw = status.matched_qty_raw;
+                live = status.is_live() && self.progress.state == AlgoState::Running;
+            }
+            self.record(&fill.order_id, matched_raw, raw_to_f64(matched_raw));
+
+            if self.progress.state != AlgoState::Running {
+                return Ok(());
+            }
+            // Done without filling: cancelled on the venue or by the host
+            if matched_raw < fill.filled_qty_raw + fill.remaining_qty_raw {
+                self.progress.state = AlgoState::Cancelled;
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
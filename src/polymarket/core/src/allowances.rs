@@ -0,0 +1,159 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Token approvals of the funder wallet
+//!
+//! Orders settle on-chain: an exchange contract moves USDC.e out of the
+//! wallet on a buy and outcome tokens on a sell, which it can only do once
+//! the wallet approved it. A fresh wallet has approved nothing, and the
+//! venue then refuses its orders with a balance/allowance error. Splits,
+//! merges and conversions need approvals of their own.
+//!
+//! Both exchanges (binary and neg-risk) and the neg-risk adapter need a
+//! USDC.e allowance and the approval for every outcome token (ERC-1155);
+//! the Conditional Tokens contract needs a USDC.e allowance to split. A
+//! USDC.e allowance counts as set if it covers the wallet's USDC.e balance.
+//! Approving sets every missing one, unlimited, in one transaction.
+
+use alloy::primitives::{address, Address, U256};
+use alloy::providers::ProviderBuilder;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+use serde::Serialize;
+
+use crate::chain::{CTF_ADDRESS, NEG_RISK_ADAPTER_ADDRESS, USDC_ADDRESS};
+use crate::{OrderError, Trader};
+
+pub const CTF_EXCHANGE_ADDRESS: Address = address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+pub const NEG_RISK_CTF_EXCHANGE_ADDRESS: Address =
+    address!("C5d563A36AE78145C45a50134d48A1215220f80a");
+
+/// Contracts the wallet approves: name, address, and whether it needs the
+/// outcome token approval
+const SPENDERS: [(&str, Address, bool); 4] = [
+    ("ctf_exchange", CTF_EXCHANGE_ADDRESS, true),
+    ("neg_risk_ctf_exchange", NEG_RISK_CTF_EXCHANGE_ADDRESS, true),
+    ("neg_risk_adapter", NEG_RISK_ADAPTER_ADDRESS, true),
+    ("conditional_tokens", CTF_ADDRESS, false),
+];
+
+sol! {
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address owner) view returns (uint256);
+        function allowance(address owner, address spender) view returns (uint256);
+        function approve(address spender, uint256 amount) returns (bool);
+    }
+
+    #[sol(rpc)]
+    interface IERC1155 {
+        function isApprovedForAll(address owner, address operator) view returns (bool);
+        function setApprovalForAll(address operator, bool approved);
+    }
+}
+
+/// Approvals the wallet gave one contract
+#[derive(Clone, Debug, Serialize)]
+pub struct Allowance {
+    pub spender: &'static str,
+    pub address: String,
+    /// USDC.e allowance covers the wallet's balance
+    pub usdc: bool,
+    /// Approved for every outcome token; None if the contract needs no
+    /// approval
+    pub tokens: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Allowances {
+    pub wallet: String,
+    pub spenders: Vec<Allowance>,
+}
+
+impl Allowances {
+    /// Some contract lacks a USDC.e allowance: buys fail
+    pub fn usdc_missing(&self) -> bool {
+        self.spenders.iter().any(|s| !s.usdc)
+    }
+
+    /// Some contract lacks the outcome token approval: sells fail
+    pub fn tokens_missing(&self) -> bool {
+        self.spenders.iter().any(|s| s.tokens == Some(false))
+    }
+}
+
+impl Trader {
+    /// Read the approvals the funder wallet gave each contract it trades
+    /// through; a read on Polygon, allowed in read-only and dry-run mode
+    pub async fn check_allowances(&self) -> Result<Allowances, OrderError> {
+        let provider = ProviderBuilder::new().connect_http(self.rpc_url()?);
+        let usdc = IERC20::new(USDC_ADDRESS, &provider);
+        let ctf = IERC1155::new(CTF_ADDRESS, &provider);
+        let api = |e: alloy::contract::Error| OrderError::Api(e.into());
+
+        let balance = usdc.balanceOf(self.funder).call().await.map_err(api)?;
+        let mut spenders = Vec::with_capacity(SPENDERS.len());
+        for (name, spender, needs_tokens) in SPENDERS {
+            let allowance = usdc
+                .allowance(self.funder, spender)
+                .call()
+                .await
+                .map_err(api)?;
+            let tokens = if needs_tokens {
+                let approved = ctf
+                    .isApprovedForAll(self.funder, spender)
+                    .call()
+                    .await
+                    .map_err(api)?;
+                Some(approved)
+            } else {
+                None
+            };
+            spenders.push(Allowance {
+                spender: name,
+                address: spender.to_string(),
+                usdc: allowance >= balance.max(U256::from(1)),
+                tokens,
+            });
+        }
+        Ok(Allowances {
+            wallet: self.funder.to_string(),
+            spenders,
+        })
+    }
+
+    /// Set every missing approval, unlimited, in one transaction; returns
+    /// its hash once mined, or None if nothing was missing
+    pub async fn approve_allowances(&self) -> Result<Option<String>, OrderError> {
+        self.ensure_chain()?;
+        let allowances = self.check_allowances().await?;
+
+        let mut calls = Vec::new();
+        for (allowance, (_, spender, _)) in allowances.spenders.iter().zip(SPENDERS) {
+            if !allowance.usdc {
+                let call = IERC20::approveCall {
+                    spender,
+                    amount: U256::MAX,
+                };
+                calls.push((USDC_ADDRESS, call.abi_encode()));
+            }
+            if allowance.tokens == Some(false) {
+                let call = IERC1155::setApprovalForAllCall {
+                    operator: spender,
+                    approved: true,
+                };
+                calls.push((CTF_ADDRESS, call.abi_encode()));
+            }
+        }
+        if calls.is_empty() {
+            return Ok(None);
+        }
+        self.proxy_calls(calls).await.map(Some)
+    }
+}
@@ -0,0 +1,308 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! YES+NO mispricing detector
+//!
+//! A YES and a NO share of a binary market always pay 1 USDC together, so
+//! their prices should sum to 1. When the asks sum below 1 net of taker
+//! fees, buying both and merging the pair locks in the difference; when the
+//! bids sum above 1, selling both (from inventory, or after splitting USDC)
+//! does. `ArbScanner` walks both books of each watched market and reports
+//! how many pairs trade at an edge of at least `min_edge` per share.
+//!
+//! Taker fees are charged in shares on buys; the detector counts every fee
+//! share as a full USDC lost, so the edge it reports is a lower bound.
+//! Books are the cached snapshots if under a second old, refetched otherwise.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::Side;
+use serde::Serialize;
+use tracing::warn;
+
+#[cfg(feature = "execution")]
+use crate::basket::BasketLeg;
+use crate::book::{BookSnapshot, Level};
+use crate::gamma::{normalize_condition_id, MarketTokens};
+use crate::orders::taker_fee_factor;
+use crate::{OrderError, Trader};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArbDirection {
+    /// Asks sum below 1: buy both outcomes, then merge
+    BuyBoth,
+    /// Bids sum above 1: sell both outcomes
+    SellBoth,
+}
+
+/// Pairs tradable at an edge, as of one pass
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ArbOpportunity {
+    pub condition_id: String,
+    pub yes_token_id: String,
+    pub no_token_id: String,
+    pub direction: ArbDirection,
+    /// Pairs executable at an edge of at least `min_edge`
+    pub shares: f64,
+    /// Deepest YES and NO levels needed: the legs' limit prices
+    pub yes_price: f64,
+    pub no_price: f64,
+    /// Profit per pair over all `shares`, fees counted, USDC
+    pub edge: f64,
+    /// Expected profit of trading all `shares`, USDC
+    pub profit: f64,
+}
+
+#[cfg(feature = "execution")]
+impl ArbOpportunity {
+    /// The two legs that trade it, for `Trader::place_basket`
+    pub fn basket_legs(&self) -> Vec<BasketLeg> {
+        let side = match self.direction {
+            ArbDirection::BuyBoth => Side::Buy,
+            ArbDirection::SellBoth => Side::Sell,
+        };
+        let leg = |token_id: &str, limit_price| BasketLeg {
+            token_id: token_id.to_string(),
+            side,
+            shares: self.shares,
+            limit_price,
+        };
+        vec![
+            leg(&self.yes_token_id, self.yes_price),
+            leg(&self.no_token_id, self.no_price),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArbAction {
+    /// New, or its size or prices changed
+    Open,
+    /// Gone since the last pass
+    Closed,
+}
+
+struct Watched {
+    min_edge: f64,
+    /// Looked up on the first pass
+    tokens: Option<MarketTokens>,
+    /// Last reported, so each change is reported once
+    open: Option<ArbOpportunity>,
+}
+
+#[derive(Default)]
+pub struct ArbScanner {
+    markets: Mutex<HashMap<String, Watched>>,
+}
+
+impl ArbScanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watch a market (condition id) for pairs at an edge of at least
+    /// `min_edge` USDC per pair
+    pub fn watch(&self, condition_id: &str, min_edge: f64) -> Result<(), OrderError> {
+        let condition_id = normalize_condition_id(condition_id).ok_or(OrderError::InvalidToken)?;
+        if !min_edge.is_finite() || !(0.0..1.0).contains(&min_edge) {
+            return Err(OrderError::InvalidAmount);
+        }
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        let tokens = markets.remove(&condition_id).and_then(|w| w.tokens);
+        markets.insert(
+            condition_id,
+            Watched {
+                min_edge,
+                tokens,
+                open: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stop watching a market; false if it was not watched
+    pub fn unwatch(&self, condition_id: &str) -> bool {
+        let Some(condition_id) = normalize_condition_id(condition_id) else {
+            return false;
+        };
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        markets.remove(&condition_id).is_some()
+    }
+
+    /// Number of markets watched
+    pub fn watched(&self) -> usize {
+        self.markets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Check every watched market once, reporting opportunities that
+    /// opened, changed or closed since the last pass
+    pub async fn scan(
+        &self,
+        trader: &Trader,
+        mut on_event: impl FnMut(ArbAction, &ArbOpportunity),
+    ) {
+        let watched: Vec<(String, f64, Option<MarketTokens>)> = {
+            let markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+            markets
+                .iter()
+                .map(|(id, w)| (id.clone(), w.min_edge, w.tokens.clone()))
+                .collect()
+        };
+
+        for (condition_id, min_edge, tokens) in watched {
+            let market = match tokens {
+                Some(market) => market,
+                None => match trader.market_tokens(&condition_id).await {
+                    Ok(market) => {
+                        self.update(&condition_id, |w| w.tokens = Some(market.clone()));
+                        market
+                    }
+                    Err(e) => {
+                        warn!("[ARB] market lookup failed | {} | {}", condition_id, e);
+                        continue;
+                    }
+                },
+            };
+            let books = futures::future::try_join(
+                trader.recent_book(&market.yes_token_id),
+                trader.recent_book(&market.no_token_id),
+            )
+            .await;
+            let (yes, no) = match books {
+                Ok(books) => books,
+                Err(e) => {
+                    warn!("[ARB] book fetch failed | {} | {}", condition_id, e);
+                    continue;
+                }
+            };
+
+            let found = find_opportunity(&market, &yes, &no, min_edge);
+            let mut previous = None;
+            self.update(&condition_id, |w| {
+                if w.open != found {
+                    previous = Some(std::mem::replace(&mut w.open, found.clone()));
+                }
+            });
+            match (previous, &found) {
+                (Some(_), Some(opportunity)) => on_event(ArbAction::Open, opportunity),
+                (Some(Some(closed)), None) => on_event(ArbAction::Closed, &closed),
+                _ => {}
+            }
+        }
+    }
+
+    fn update(&self, condition_id: &str, f: impl FnOnce(&mut Watched)) {
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(watched) = markets.get_mut(condition_id) {
+            f(watched);
+        }
+    }
+}
+
+/// Best opportunity in either direction, if any clears `min_edge`
+pub fn find_opportunity(
+    market: &MarketTokens,
+    yes: &BookSnapshot,
+    no: &BookSnapshot,
+    min_edge: f64,
+) -> Option<ArbOpportunity> {
+    // Profit per pair at a pair of level prices
+    let buy_edge = |y: f64, n: f64| 1.0 - y - n - taker_fee_factor(y) - taker_fee_factor(n);
+    let sell_edge = |y: f64, n: f64| y + n - 1.0;
+
+    let buy = walk_pair(&yes.asks, &no.asks, min_edge, buy_edge);
+    let sell = walk_pair(&yes.bids, &no.bids, min_edge, sell_edge);
+    let (direction, walk) = match (buy, sell) {
+        (Some(b), Some(s)) if s.profit > b.profit => (ArbDirection::SellBoth, s),
+        (Some(b), _) => (ArbDirection::BuyBoth, b),
+        (None, Some(s)) => (ArbDirection::SellBoth, s),
+        (None, None) => return None,
+    };
+    Some(ArbOpportunity {
+        condition_id: market.condition_id.clone(),
+        yes_token_id: market.yes_token_id.clone(),
+        no_token_id: market.no_token_id.clone(),
+        direction,
+        shares: walk.shares,
+        yes_price: walk.yes_price,
+        no_price: walk.no_price,
+        edge: walk.profit / walk.shares,
+        profit: walk.profit,
+    })
+}
+
+struct PairWalk {
+    shares: f64,
+    yes_price: f64,
+    no_price: f64,
+    profit: f64,
+}
+
+/// Take both ladders (best first) level by level while a pair still earns
+/// at least `min_edge`
+fn walk_pair(
+    yes: &[Level],
+    no: &[Level],
+    min_edge: f64,
+    edge: impl Fn(f64, f64) -> f64,
+) -> Option<PairWalk> {
+    let as_f64 = |level: &Level| -> (f64, f64) {
+        (
+            level.price.try_into().unwrap_or(0.0),
+            level.size.try_into().unwrap_or(0.0),
+        )
+    };
+    let (mut i, mut j) = (0, 0);
+    let (mut yes_left, mut no_left) = (0.0, 0.0);
+    let mut walk = PairWalk {
+        shares: 0.0,
+        yes_price: 0.0,
+        no_price: 0.0,
+        profit: 0.0,
+    };
+    while i < yes.len() && j < no.len() {
+        let (y, y_size) = as_f64(&yes[i]);
+        let (n, n_size) = as_f64(&no[j]);
+        if yes_left <= 0.0 {
+            yes_left = y_size;
+        }
+        if no_left <= 0.0 {
+            no_left = n_size;
+        }
+        let pair_edge = edge(y, n);
+        if pair_edge < min_edge || pair_edge <= 0.0 {
+            break;
+        }
+        let take = yes_left.min(no_left);
+        walk.shares += take;
+        walk.profit += take * pair_edge;
+        walk.yes_price = y;
+        walk.no_price = n;
+        yes_left -= take;
+        no_left -= take;
+        if yes_left <= 0.0 {
+            i += 1;
+        }
+        if no_left <= 0.0 {
+            j += 1;
+        }
+    }
+    // Venue share step
+    let shares = (walk.shares * 100.0).floor() / 100.0;
+    if shares <= 0.0 {
+        return None;
+    }
+    walk.profit *= shares / walk.shares;
+    walk.shares = shares;
+    Some(walk)
+}
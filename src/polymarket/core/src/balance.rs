@@ -0,0 +1,124 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Local pre-trade balance check
+//!
+//! Tracks the last known USDC balance and the notional of buys that are
+//! currently in flight, so a buy that cannot be funded fails locally
+//! instead of waiting a full round trip for the venue rejection.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// Sentinel for "balance never fetched" - the check is skipped until then
+const UNKNOWN: i64 = -1;
+
+pub struct BalanceGuard {
+    /// Last known USDC balance (raw, 6 decimals)
+    cached_raw: AtomicI64,
+    /// Safety buffer that buys are not allowed to consume (raw, 6 decimals)
+    buffer_raw: AtomicI64,
+    /// Notional of buys sent but not answered yet (raw, 6 decimals)
+    in_flight_raw: AtomicI64,
+}
+
+impl Default for BalanceGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BalanceGuard {
+    pub fn new() -> Self {
+        Self {
+            cached_raw: AtomicI64::new(UNKNOWN),
+            buffer_raw: AtomicI64::new(0),
+            in_flight_raw: AtomicI64::new(0),
+        }
+    }
+
+    /// Store a freshly fetched balance
+    pub fn set_cached(&self, raw: i64) {
+        self.cached_raw.store(raw.max(0), Ordering::Release);
+    }
+
+    pub fn set_buffer(&self, raw: i64) {
+        self.buffer_raw.store(raw.max(0), Ordering::Release);
+    }
+
+    pub fn buffer(&self) -> i64 {
+        self.buffer_raw.load(Ordering::Acquire)
+    }
+
+    /// Balance left for new buys: cached - buffer - in-flight
+    /// Returns None if the balance was never fetched
+    pub fn available(&self) -> Option<i64> {
+        let cached = self.cached_raw.load(Ordering::Acquire);
+        if cached == UNKNOWN {
+            return None;
+        }
+        let buffer = self.buffer_raw.load(Ordering::Acquire);
+        let in_flight = self.in_flight_raw.load(Ordering::Acquire);
+        Some(cached - buffer - in_flight)
+    }
+
+    /// Reserve notional for a buy about to be sent
+    /// Fails with the currently available amount if the buy does not fit
+    pub fn reserve(&self, amount_raw: i64) -> Result<Reservation<'_>, i64> {
+        // Add first, then check: concurrent buys see each other's reservations
+        let in_flight = self.in_flight_raw.fetch_add(amount_raw, Ordering::AcqRel) + amount_raw;
+        let reservation = Reservation {
+            guard: self,
+            amount_raw,
+        };
+
+        let cached = self.cached_raw.load(Ordering::Acquire);
+        if cached == UNKNOWN {
+            return Ok(reservation);
+        }
+
+        let buffer = self.buffer_raw.load(Ordering::Acquire);
+        if cached - buffer - in_flight < 0 {
+            // Dropping the reservation rolls back the in-flight amount
+            drop(reservation);
+            return Err(cached - buffer - (in_flight - amount_raw));
+        }
+        Ok(reservation)
+    }
+}
+
+/// In-flight notional held for one buy, released on drop
+pub struct Reservation<'a> {
+    guard: &'a BalanceGuard,
+    amount_raw: i64,
+}
+
+impl Reservation<'_> {
+    /// Order accepted by the venue: debit the spent amount from the cache
+    /// until the next balance fetch replaces it
+    pub fn commit(self, spent_raw: i64) {
+        let _ = self
+            .guard
+            .cached_raw
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |cached| {
+                if cached == UNKNOWN {
+                    None
+                } else {
+                    Some((cached - spent_raw).max(0))
+                }
+            });
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.guard
+            .in_flight_raw
+            .fetch_sub(self.amount_raw, Ordering::AcqRel);
+    }
+}
@@ -0,0 +1,306 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Multi-leg basket orders
+//!
+//! A basket buys or sells several outcome tokens together, typically the
+//! candidates of one neg-risk event. Legs are sized in shares and capped by
+//! a limit price; they are checked against the books, then sent at once as
+//! FAK orders.
+//!
+//! The venue has no atomic multi-order, so "all or nothing" is enforced in
+//! two steps: nothing is sent unless every leg can fill within its limit,
+//! and if a leg still comes up short, whatever the others filled is unwound
+//! (sold back, or bought back for sell legs) at market. Best effort sends
+//! the legs that can fill and, if asked, unwinds the same way. Unwinds go
+//! through the usual pre-trade checks, so a tight order rate limit can
+//! block them.
+
+use std::collections::HashSet;
+
+use futures::future::join_all;
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::Decimal;
+use serde::Serialize;
+
+use crate::orders::to_decimal;
+use crate::{
+    decimal_to_raw, parse_token, OrderError, OrderFill, OrderRequest, Trader, DECIMAL_SCALE,
+    MARKET_SELL_PRICE,
+};
+
+/// Most legs in one basket (at least two)
+pub const MAX_BASKET_LEGS: usize = 20;
+
+/// Share step of the venue: a leg this close to its size counts as filled
+const SHARE_STEP_RAW: i64 = 10_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BasketLeg {
+    pub token_id: String,
+    pub side: Side,
+    /// Shares to buy (net of fees) or sell
+    pub shares: f64,
+    /// Highest price paid (buys) or lowest accepted (sells)
+    pub limit_price: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BasketMode {
+    /// Send only if every leg can fill; unwind if one still falls short
+    AllOrNothing,
+    /// Send the legs that can fill; optionally unwind if any falls short
+    BestEffort { unwind_on_partial: bool },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegState {
+    Filled,
+    Partial,
+    Failed,
+    /// Not sent: not enough liquidity within the limit (best effort)
+    Skipped,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BasketState {
+    /// Every leg filled
+    Completed,
+    /// Some legs fell short and their fills were kept
+    Partial,
+    /// Some legs fell short and every fill was unwound
+    Unwound,
+    /// An unwind failed: some fills are still held
+    UnwindFailed,
+}
+
+#[derive(Debug)]
+pub struct LegOutcome {
+    pub leg: BasketLeg,
+    pub state: LegState,
+    /// Venue answer, if the leg was sent
+    pub fill: Option<OrderFill>,
+    pub error: Option<OrderError>,
+    /// Shares sold (buy legs) or bought (sell legs) back by the unwind
+    pub unwound_qty_raw: i64,
+    pub unwind_error: Option<OrderError>,
+}
+
+impl LegOutcome {
+    fn filled_raw(&self) -> i64 {
+        self.fill.as_ref().map_or(0, |f| f.filled_qty_raw)
+    }
+}
+
+#[derive(Debug)]
+pub struct BasketOutcome {
+    pub state: BasketState,
+    pub legs: Vec<LegOutcome>,
+}
+
+impl Trader {
+    /// Place a basket; Err if it is malformed or (all or nothing) a leg
+    /// cannot fill within its limit, in which case nothing was sent
+    pub async fn place_basket(
+        &self,
+        legs: Vec<BasketLeg>,
+        mode: BasketMode,
+    ) -> Result<BasketOutcome, OrderError> {
+        self.ensure_trading()?;
+        self.breaker.admit()?;
+        validate_legs(&legs)?;
+
+        let mut checks = join_all(legs.iter().map(|leg| self.check_leg(leg))).await;
+        if mode == BasketMode::AllOrNothing {
+            // Nothing sent: report the first shortfall
+            if let Some(i) = checks.iter().position(Result::is_err) {
+                return Err(checks.swap_remove(i).unwrap_err());
+            }
+        }
+
+        let sends = legs.iter().zip(&checks).map(|(leg, check)| async move {
+            match check {
+                Ok(()) => Some(self.place(&leg.token_id, leg_request(leg)).await),
+                Err(_) => None,
+            }
+        });
+        let results = join_all(sends).await;
+
+        let mut outcomes: Vec<LegOutcome> = legs
+            .into_iter()
+            .zip(checks)
+            .zip(results)
+            .map(|((leg, check), result)| leg_outcome(leg, check, result))
+            .collect();
+
+        let short = outcomes.iter().any(|o| o.state != LegState::Filled);
+        let unwind = match mode {
+            BasketMode::AllOrNothing => short,
+            BasketMode::BestEffort { unwind_on_partial } => short && unwind_on_partial,
+        };
+        let state = if !short {
+            BasketState::Completed
+        } else if !unwind {
+            BasketState::Partial
+        } else if self.unwind(&mut outcomes).await {
+            BasketState::Unwound
+        } else {
+            BasketState::UnwindFailed
+        };
+        Ok(BasketOutcome {
+            state,
+            legs: outcomes,
+        })
+    }
+
+    /// Whether the cached (or a fresh) book fills the leg within its limit
+    async fn check_leg(&self, leg: &BasketLeg) -> Result<(), OrderError> {
+        let book = self.recent_book(&leg.token_id).await?;
+        let shares = to_decimal(leg.shares)?;
+        let limit = to_decimal(leg.limit_price)?;
+        match leg.side {
+            Side::Buy => book
+                .cost_of_shares(shares, limit)
+                .map(|_| ())
+                .map_err(|available| OrderError::InsufficientLiquidity { available }),
+            _ => {
+                let estimate = book.estimate(Side::Sell, shares, limit);
+                if estimate.filled_shares >= shares {
+                    Ok(())
+                } else {
+                    Err(OrderError::InsufficientLiquidity {
+                        available: estimate.filled_shares,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Reverse every fill at market; true if all of it was reversed
+    async fn unwind(&self, outcomes: &mut [LegOutcome]) -> bool {
+        let unwinds = outcomes.iter().map(|outcome| async move {
+            let filled_raw = outcome.filled_raw();
+            if filled_raw <= 0 {
+                return None;
+            }
+            let token_id = &outcome.leg.token_id;
+            let shares = filled_raw as f64 / DECIMAL_SCALE as f64;
+            let request = match outcome.leg.side {
+                Side::Buy => OrderRequest::MarketSell {
+                    size: shares,
+                    min_price: MARKET_SELL_PRICE,
+                },
+                _ => {
+                    // Highest price on the token's grid
+                    let tick = self.tick_size(token_id).unwrap_or(Decimal::new(1, 2));
+                    OrderRequest::MarketBuyShares {
+                        shares,
+                        max_price: (Decimal::ONE - tick).try_into().unwrap_or(0.99),
+                    }
+                }
+            };
+            Some(self.place(token_id, request).await)
+        });
+        let results = join_all(unwinds).await;
+
+        let mut complete = true;
+        for (outcome, result) in outcomes.iter_mut().zip(results) {
+            let filled_raw = outcome.filled_raw();
+            match result {
+                None => {}
+                Some(Ok(fill)) if fill.success => {
+                    outcome.unwound_qty_raw = fill.filled_qty_raw;
+                    complete &= fill.filled_qty_raw + SHARE_STEP_RAW > filled_raw;
+                }
+                Some(Ok(_)) => {
+                    outcome.unwind_error = Some(OrderError::Api(anyhow::anyhow!(
+                        "unwind rejected by the venue"
+                    )));
+                    complete = false;
+                }
+                Some(Err(e)) => {
+                    outcome.unwind_error = Some(e);
+                    complete = false;
+                }
+            }
+        }
+        complete
+    }
+}
+
+fn validate_legs(legs: &[BasketLeg]) -> Result<(), OrderError> {
+    if legs.len() < 2 || legs.len() > MAX_BASKET_LEGS {
+        return Err(OrderError::InvalidAmount);
+    }
+    let mut tokens = HashSet::with_capacity(legs.len());
+    for leg in legs {
+        parse_token(&leg.token_id)?;
+        if !tokens.insert(leg.token_id.as_str()) {
+            return Err(OrderError::InvalidToken);
+        }
+        let sized = leg.shares.is_finite() && leg.shares > 0.0;
+        let priced = leg.limit_price > 0.0 && leg.limit_price < 1.0;
+        if !sized || !priced {
+            return Err(OrderError::InvalidAmount);
+        }
+    }
+    Ok(())
+}
+
+fn leg_request(leg: &BasketLeg) -> OrderRequest {
+    match leg.side {
+        Side::Buy => OrderRequest::MarketBuyShares {
+            shares: leg.shares,
+            max_price: leg.limit_price,
+        },
+        _ => OrderRequest::MarketSell {
+            size: leg.shares,
+            min_price: MARKET_SELL_PRICE,
+        },
+    }
+}
+
+fn leg_outcome(
+    leg: BasketLeg,
+    check: Result<(), OrderError>,
+    result: Option<Result<OrderFill, OrderError>>,
+) -> LegOutcome {
+    let mut outcome = LegOutcome {
+        state: LegState::Failed,
+        fill: None,
+        error: None,
+        unwound_qty_raw: 0,
+        unwind_error: None,
+        leg,
+    };
+    match (check, result) {
+        (Err(e), _) => {
+            outcome.state = LegState::Skipped;
+            outcome.error = Some(e);
+        }
+        (Ok(()), Some(Ok(fill))) => {
+            let wanted_raw = to_decimal(outcome.leg.shares)
+                .map(decimal_to_raw)
+                .unwrap_or(i64::MAX);
+            outcome.state = if !fill.success || fill.filled_qty_raw <= 0 {
+                LegState::Failed
+            } else if fill.filled_qty_raw + SHARE_STEP_RAW > wanted_raw {
+                LegState::Filled
+            } else {
+                LegState::Partial
+            };
+            outcome.fill = Some(fill);
+        }
+        (Ok(()), Some(Err(e))) => outcome.error = Some(e),
+        (Ok(()), None) => {}
+    }
+    outcome
+}
@@ -0,0 +1,139 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Several limit orders in one request
+//!
+//! Each order runs the same local checks as when placed alone; those that
+//! pass are signed and posted together in a single round trip, e.g. to
+//! refresh both sides of a quote. The venue answers each order on its own,
+//! so some can be accepted and others rejected.
+//!
+//! Only GTC/GTD limit orders are batched: market orders depend on the book
+//! at the time they land and go through `Trader::place`.
+
+use crate::metrics::Op;
+use crate::orders::LimitOrder;
+use crate::{parse_token, OrderError, OrderFill, OrderRequest, Trader};
+
+/// Most orders the venue takes in one request
+pub const MAX_BATCH_ORDERS: usize = 15;
+
+impl Trader {
+    /// Check, sign and post limit orders in one request
+    /// Err if the batch as a whole is refused (empty or over
+    /// `MAX_BATCH_ORDERS`, read-only, venue paused); otherwise one result
+    /// per order, in order. A request failing in transport fails every
+    /// order sent with it.
+    pub async fn place_batch(
+        &self,
+        orders: &[(String, OrderRequest)],
+    ) -> Result<Vec<Result<OrderFill, OrderError>>, OrderError> {
+        let request: Vec<_> = orders
+            .iter()
+            .map(
+                |(token_id, request)| serde_json::json!({ "token_id": token_id, "order": request }),
+            )
+            .collect();
+        self.journaled(
+            "BATCH",
+            "",
+            serde_json::json!(request),
+            self.place_batch_unjournaled(orders),
+            |results| {
+                let results: Vec<_> = results
+                    .iter()
+                    .map(|result| match result {
+                        Ok(fill) => serde_json::json!(fill),
+                        Err(e) => serde_json::json!({ "error": e.to_string() }),
+                    })
+                    .collect();
+                serde_json::json!(results)
+            },
+        )
+        .await
+    }
+
+    async fn place_batch_unjournaled(
+        &self,
+        orders: &[(String, OrderRequest)],
+    ) -> Result<Vec<Result<OrderFill, OrderError>>, OrderError> {
+        if orders.is_empty() || orders.len() > MAX_BATCH_ORDERS {
+            return Err(OrderError::InvalidAmount);
+        }
+        self.ensure_trading()?;
+        self.breaker.admit()?;
+
+        let mut results: Vec<Option<Result<OrderFill, OrderError>>> =
+            orders.iter().map(|_| None).collect();
+        let mut checked: Vec<(usize, LimitOrder<'_>)> = Vec::with_capacity(orders.len());
+        for (index, (token_id, request)) in orders.iter().enumerate() {
+            let order = self
+                .risk
+                .check_open_orders(checked.len() + 1)
+                .map_err(OrderError::RiskLimit)
+                .and_then(|()| parse_token(token_id))
+                .and_then(|token| self.check_limit(token_id, token, *request));
+            match order {
+                Ok(order) => checked.push((index, order)),
+                Err(e) => results[index] = Some(Err(e)),
+            }
+        }
+
+        if !checked.is_empty() && self.is_dry_run() {
+            for (index, order) in checked {
+                results[index] = Some(match self.paper_post(&order).await {
+                    Ok(resp) => Ok(self.settle_limit(order, resp)),
+                    Err(e) => Err(e),
+                });
+            }
+        } else if !checked.is_empty() {
+            let (endpoint, client) = self.order_client();
+            let resp = self
+                .timed(
+                    Op::BatchPost,
+                    self.signed_post(
+                        "BATCH",
+                        async {
+                            let mut signed = Vec::with_capacity(checked.len());
+                            for (_, order) in &checked {
+                                signed.push(self.sign_limit(client, order).await?);
+                            }
+                            Ok(signed)
+                        },
+                        |signed| client.post_orders(signed),
+                    ),
+                )
+                .await;
+            match self.routed(endpoint, resp) {
+                Ok(responses) => {
+                    let mut responses = responses.into_iter();
+                    for (index, order) in checked {
+                        results[index] = Some(match responses.next() {
+                            Some(resp) => Ok(self.settle_limit(order, resp.into())),
+                            None => Err(OrderError::Api(anyhow::anyhow!(
+                                "no answer for order {} of the batch",
+                                index
+                            ))),
+                        });
+                    }
+                }
+                Err(e) => {
+                    for (index, _) in checked {
+                        results[index] = Some(Err(OrderError::Api(anyhow::anyhow!("{}", e))));
+                    }
+                }
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.unwrap_or(Err(OrderError::InvalidAmount)))
+            .collect())
+    }
+}
@@ -0,0 +1,353 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Order book snapshots and fill estimates
+//!
+//! Books are cached per token by prefetch and refreshed on demand once they
+//! are older than `BOOK_MAX_AGE`. Estimates walk a snapshot the way a FAK
+//! order sweeps the venue book; they ignore other orders racing for the same
+//! liquidity, so treat them as a best case.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+use polymarket_client_sdk::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::Decimal;
+
+use crate::orders::{taker_fee_factor, to_decimal, MARKET_BUY_PRICE, MARKET_SELL_PRICE};
+use crate::timeouts::CallClass;
+use crate::{parse_token, OrderError, Trader};
+
+/// Snapshots older than this are refetched before estimating
+pub const BOOK_MAX_AGE: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Level {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+#[derive(Clone, Debug)]
+pub struct BookSnapshot {
+    /// Best (highest) first
+    pub bids: Vec<Level>,
+    /// Best (lowest) first
+    pub asks: Vec<Level>,
+    pub fetched_at: Instant,
+}
+
+/// Expected outcome of sweeping a snapshot
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FillEstimate {
+    /// Shares bought or sold, before fees
+    pub filled_shares: Decimal,
+    /// USDC spent (buy) or received (sell)
+    pub notional: Decimal,
+    /// 0 if nothing fills
+    pub avg_price: Decimal,
+    /// Last level reached, 0 if nothing fills
+    pub worst_price: Decimal,
+    /// Part of the requested amount that fills, 0.0 - 1.0
+    pub filled_fraction: f64,
+    /// Taker fee in shares (buys; sells report none, like their fills)
+    pub fee_shares: Decimal,
+}
+
+impl BookSnapshot {
+    /// Snapshot from venue levels in any order
+    pub fn new(mut bids: Vec<Level>, mut asks: Vec<Level>) -> Self {
+        bids.sort_by_key(|l| std::cmp::Reverse(l.price));
+        asks.sort_by_key(|l| l.price);
+        Self {
+            bids,
+            asks,
+            fetched_at: Instant::now(),
+        }
+    }
+
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
+    /// Halfway between the best bid and ask; None if a side is empty
+    pub fn midpoint(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.bids.first()?, self.asks.first()?);
+        Some((bid.price + ask.price) / Decimal::TWO)
+    }
+
+    /// Best ask less best bid; None if a side is empty
+    pub fn spread(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.bids.first()?, self.asks.first()?);
+        Some(ask.price - bid.price)
+    }
+
+    /// Sweep the opposite side: a buy spends `amount` USDC on asks priced up
+    /// to `limit`, a sell sells `amount` shares into bids down to `limit`
+    pub fn estimate(&self, side: Side, amount: Decimal, limit: Decimal) -> FillEstimate {
+        let (levels, in_range): (_, fn(Decimal, Decimal) -> bool) = match side {
+            Side::Buy => (&self.asks, |price, limit| price <= limit),
+            _ => (&self.bids, |price, limit| price >= limit),
+        };
+
+        let mut remaining = amount;
+        let mut shares = Decimal::ZERO;
+        let mut notional = Decimal::ZERO;
+        let mut worst_price = Decimal::ZERO;
+        for level in levels {
+            if remaining <= Decimal::ZERO || !in_range(level.price, limit) {
+                break;
+            }
+            let take = match side {
+                // Remaining is USDC
+                Side::Buy => level.size.min(remaining / level.price),
+                _ => level.size.min(remaining),
+            };
+            shares += take;
+            notional += take * level.price;
+            remaining -= match side {
+                Side::Buy => take * level.price,
+                _ => take,
+            };
+            worst_price = level.price;
+        }
+
+        if shares.is_zero() {
+            return FillEstimate::default();
+        }
+        let avg_price = notional / shares;
+        let filled: f64 = (amount - remaining.max(Decimal::ZERO))
+            .try_into()
+            .unwrap_or(0.0);
+        let requested: f64 = amount.try_into().unwrap_or(0.0);
+        let fee_shares = match side {
+            Side::Buy => {
+                let factor = taker_fee_factor(avg_price.try_into().unwrap_or(0.0));
+                shares * Decimal::try_from(factor).unwrap_or(Decimal::ZERO)
+            }
+            _ => Decimal::ZERO,
+        };
+        FillEstimate {
+            filled_shares: shares,
+            notional,
+            avg_price,
+            worst_price,
+            filled_fraction: if requested > 0.0 {
+                (filled / requested).min(1.0)
+            } else {
+                0.0
+            },
+            fee_shares,
+        }
+    }
+
+    /// Buy `shares` from the asks priced up to `limit`
+    /// Err holds the shares available within `limit` if that is fewer
+    pub fn cost_of_shares(&self, shares: Decimal, limit: Decimal) -> Result<FillEstimate, Decimal> {
+        let mut remaining = shares;
+        let mut notional = Decimal::ZERO;
+        let mut worst_price = Decimal::ZERO;
+        for level in self.asks.iter().take_while(|l| l.price <= limit) {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+            let take = level.size.min(remaining);
+            notional += take * level.price;
+            remaining -= take;
+            worst_price = level.price;
+        }
+        if remaining > Decimal::ZERO || shares <= Decimal::ZERO {
+            return Err(shares - remaining.max(Decimal::ZERO));
+        }
+
+        let avg_price = notional / shares;
+        let factor = taker_fee_factor(avg_price.try_into().unwrap_or(0.0));
+        Ok(FillEstimate {
+            filled_shares: shares,
+            notional,
+            avg_price,
+            worst_price,
+            filled_fraction: 1.0,
+            fee_shares: shares * Decimal::try_from(factor).unwrap_or(Decimal::ZERO),
+        })
+    }
+
+    /// Sell into the bids priced down to `limit` until the proceeds reach
+    /// `usdc`
+    /// Err holds the shares the bids within `limit` take if they raise less
+    pub fn shares_for_proceeds(
+        &self,
+        usdc: Decimal,
+        limit: Decimal,
+    ) -> Result<FillEstimate, Decimal> {
+        let mut remaining = usdc;
+        let mut shares = Decimal::ZERO;
+        let mut worst_price = Decimal::ZERO;
+        for level in self.bids.iter().take_while(|l| l.price >= limit) {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+            let take = level.size.min(remaining / level.price);
+            shares += take;
+            remaining -= take * level.price;
+            worst_price = level.price;
+        }
+        // Decimal division can leave dust short of the target
+        if remaining > Decimal::new(1, 6) || usdc <= Decimal::ZERO {
+            return Err(shares);
+        }
+
+        Ok(FillEstimate {
+            filled_shares: shares,
+            notional: usdc,
+            avg_price: usdc / shares,
+            worst_price,
+            filled_fraction: 1.0,
+            fee_shares: Decimal::ZERO,
+        })
+    }
+}
+
+impl Trader {
+    /// Fetch the book of a token and cache it
+    pub async fn refresh_book(&self, token_id: &str) -> Result<Arc<BookSnapshot>, OrderError> {
+        let token = parse_token(token_id)?;
+        let req = OrderBookSummaryRequest::builder().token_id(token).build();
+        let result = self
+            .limited(CallClass::MarketData, self.client.order_book(&req))
+            .await;
+        let book = self
+            .drift
+            .record_sdk("clob.book", result)
+            .map_err(OrderError::Api)?;
+        let snapshot = Arc::new(snapshot_of(&book));
+        self.cache_book(token_id, snapshot.clone());
+        Ok(snapshot)
+    }
+
+    /// Cache a snapshot taken elsewhere, e.g. a market channel book
+    pub fn cache_book(&self, token_id: &str, snapshot: Arc<BookSnapshot>) {
+        if let Ok(mut books) = self.books.write() {
+            books.insert(token_id.to_string(), snapshot);
+        }
+    }
+
+    /// Last snapshot of a token, however old
+    pub fn cached_book(&self, token_id: &str) -> Option<Arc<BookSnapshot>> {
+        self.books.read().ok()?.get(token_id).cloned()
+    }
+
+    /// Cached snapshot if younger than `BOOK_MAX_AGE`, else a fresh one
+    pub async fn recent_book(&self, token_id: &str) -> Result<Arc<BookSnapshot>, OrderError> {
+        match self.cached_book(token_id) {
+            Some(book) if book.age() <= BOOK_MAX_AGE => Ok(book),
+            _ => self.refresh_book(token_id).await,
+        }
+    }
+
+    /// Expected result of a market order right now: `amount` is USDC for a
+    /// buy and shares for a sell, swept within the market order price bounds
+    /// Returns the estimate and the age of the snapshot it used
+    pub async fn estimate_fill(
+        &self,
+        token_id: &str,
+        side: Side,
+        amount: f64,
+    ) -> Result<(FillEstimate, Duration), OrderError> {
+        let amount = to_decimal(amount)?;
+        if amount <= Decimal::ZERO {
+            return Err(OrderError::InvalidAmount);
+        }
+        let book = self.recent_book(token_id).await?;
+        let limit = match side {
+            Side::Buy => to_decimal(MARKET_BUY_PRICE)?,
+            _ => to_decimal(MARKET_SELL_PRICE)?,
+        };
+        Ok((book.estimate(side, amount, limit), book.age()))
+    }
+}
+
+pub(crate) fn snapshot_of(book: &OrderBookSummaryResponse) -> BookSnapshot {
+    let levels = |side: &[OrderSummary]| {
+        side.iter()
+            .map(|l| Level {
+                price: l.price,
+                size: l.size,
+            })
+            .collect()
+    };
+    BookSnapshot::new(levels(&book.bids), levels(&book.asks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: &str, size: &str) -> Level {
+        Level {
+            price: price.parse().unwrap(),
+            size: size.parse().unwrap(),
+        }
+    }
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn book() -> BookSnapshot {
+        BookSnapshot::new(
+            vec![level("0.4", "10"), level("0.45", "10")],
+            vec![level("0.6", "100"), level("0.5", "100")],
+        )
+    }
+
+    #[test]
+    fn buy_sweeps_levels_at_vwap() {
+        let fill = book().estimate(Side::Buy, dec("80"), dec("0.7"));
+        assert_eq!(fill.filled_shares, dec("150"));
+        assert_eq!(fill.notional, dec("80"));
+        assert_eq!(fill.avg_price, dec("80") / dec("150"));
+        assert_eq!(fill.worst_price, dec("0.6"));
+        assert_eq!(fill.filled_fraction, 1.0);
+    }
+
+    #[test]
+    fn sell_sweeps_levels_at_vwap() {
+        let fill = book().estimate(Side::Sell, dec("15"), dec("0.1"));
+        assert_eq!(fill.filled_shares, dec("15"));
+        assert_eq!(fill.notional, dec("6.5"));
+        assert_eq!(fill.avg_price, dec("6.5") / dec("15"));
+        assert_eq!(fill.worst_price, dec("0.4"));
+        assert_eq!(fill.fee_shares, Decimal::ZERO);
+    }
+
+    #[test]
+    fn insufficient_depth_fills_partially() {
+        let book = book();
+        let fill = book.estimate(Side::Buy, dec("200"), dec("0.55"));
+        assert_eq!(fill.filled_shares, dec("100"));
+        assert_eq!(fill.notional, dec("50"));
+        assert_eq!(fill.worst_price, dec("0.5"));
+        assert_eq!(fill.filled_fraction, 0.25);
+
+        assert_eq!(
+            book.cost_of_shares(dec("150"), dec("0.55")),
+            Err(dec("100"))
+        );
+        assert_eq!(
+            book.shares_for_proceeds(dec("10"), dec("0.1")),
+            Err(dec("20"))
+        );
+        assert_eq!(
+            book.estimate(Side::Buy, dec("10"), dec("0.45")),
+            FillEstimate::default()
+        );
+    }
+}
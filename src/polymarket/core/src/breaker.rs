@@ -0,0 +1,271 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Circuit breaker for venue maintenance and outages
+//!
+//! Every order and cancel call feeds the breaker. It trips when the venue
+//! looks down, rather than merely refusing an order:
+//! - an answer that says the venue is under maintenance
+//! - `UNAVAILABLE_TO_TRIP` 503 answers in a row
+//! - of the last `WINDOW` calls, at least `WINDOW_MIN_CALLS` made and more
+//!   than `FAILURE_RATIO_TO_TRIP` of them failed by a 5xx or in transport
+//!
+//! While open, orders fail at once with `OrderError::VenueUnavailable`.
+//! Cancels still go out: pulling risk is worth a wasted call.
+//! `Trader::probe_venue` asks the CLOB health endpoint once the backoff has
+//! passed; a healthy answer closes the breaker, a failed one doubles the
+//! backoff, up to `MAX_PROBE_BACKOFF`.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::endpoints::{is_transport_error, PROBE_TIMEOUT};
+use crate::failover::http_status;
+use crate::{OrderError, Trader};
+
+/// 503 answers in a row that trip the breaker
+pub const UNAVAILABLE_TO_TRIP: u32 = 3;
+
+/// Calls the error rate is taken over
+pub const WINDOW: usize = 20;
+
+/// Calls needed in the window before the error rate counts
+pub const WINDOW_MIN_CALLS: usize = 10;
+
+/// Share of failed calls in the window that trips the breaker
+pub const FAILURE_RATIO_TO_TRIP: f64 = 0.5;
+
+/// Wait before the first recovery probe
+pub const MIN_PROBE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Longest wait between two recovery probes
+pub const MAX_PROBE_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Why the breaker tripped
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TripReason {
+    Maintenance,
+    /// 503 answers in a row
+    Unavailable,
+    /// 5xx and transport failures over the window
+    ErrorRate,
+}
+
+/// Breaker state, as reported by `CircuitBreaker::state`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BreakerState {
+    pub open: bool,
+    /// Set while open
+    pub reason: Option<TripReason>,
+    /// Unix ms the breaker opened, set while open
+    pub opened_at_ms: Option<u64>,
+    /// Times it tripped this session
+    pub trips: u64,
+    /// Recovery probes made, healthy or not
+    pub probes: u64,
+    /// Failed calls in the window, and calls in it
+    pub window_failures: usize,
+    pub window_calls: usize,
+}
+
+#[derive(Clone)]
+struct Open {
+    reason: TripReason,
+    opened_at_ms: u64,
+    next_probe: Instant,
+    backoff: Duration,
+}
+
+#[derive(Clone, Default)]
+struct Inner {
+    /// true for each failed call, oldest first
+    window: VecDeque<bool>,
+    unavailable_in_row: u32,
+    open: Option<Open>,
+    trips: u64,
+    probes: u64,
+}
+
+#[derive(Default)]
+pub struct CircuitBreaker {
+    inner: Mutex<Inner>,
+    /// Mirrors `inner.open`, for the order path
+    open: AtomicBool,
+}
+
+/// How a call reflects on the venue's health
+enum Verdict {
+    Healthy,
+    Failed,
+    Unavailable,
+    Maintenance,
+}
+
+fn verdict(result: Option<&anyhow::Error>) -> Verdict {
+    let Some(e) = result else {
+        return Verdict::Healthy;
+    };
+    if e.to_string().to_ascii_lowercase().contains("maintenance") {
+        return Verdict::Maintenance;
+    }
+    match http_status(e) {
+        Some(503) => Verdict::Unavailable,
+        Some(500..=599) => Verdict::Failed,
+        _ if is_transport_error(e) => Verdict::Failed,
+        // The venue answered: an order refused is no sign of an outage
+        _ => Verdict::Healthy,
+    }
+}
+
+impl CircuitBreaker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn update<R>(&self, f: impl FnOnce(&mut Inner) -> R) -> R {
+        f(&mut self.inner.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.load(Ordering::Acquire)
+    }
+
+    /// Gate for every order: VenueUnavailable while open
+    pub(crate) fn admit(&self) -> Result<(), OrderError> {
+        if self.is_open() {
+            return Err(OrderError::VenueUnavailable);
+        }
+        Ok(())
+    }
+
+    /// Feed the outcome of an order or cancel call
+    pub(crate) fn record(&self, result: Option<&anyhow::Error>) {
+        let verdict = verdict(result);
+        let tripped = self.update(|inner| {
+            let failed = !matches!(verdict, Verdict::Healthy);
+            inner.window.push_back(failed);
+            if inner.window.len() > WINDOW {
+                inner.window.pop_front();
+            }
+            match verdict {
+                Verdict::Unavailable => inner.unavailable_in_row += 1,
+                _ => inner.unavailable_in_row = 0,
+            }
+            if inner.open.is_some() {
+                return None;
+            }
+
+            let failures = inner.window.iter().filter(|&&f| f).count();
+            let reason = if matches!(verdict, Verdict::Maintenance) {
+                TripReason::Maintenance
+            } else if inner.unavailable_in_row >= UNAVAILABLE_TO_TRIP {
+                TripReason::Unavailable
+            } else if inner.window.len() >= WINDOW_MIN_CALLS
+                && failures as f64 > inner.window.len() as f64 * FAILURE_RATIO_TO_TRIP
+            {
+                TripReason::ErrorRate
+            } else {
+                return None;
+            };
+            inner.open = Some(Open {
+                reason,
+                opened_at_ms: chrono::Utc::now().timestamp_millis() as u64,
+                next_probe: Instant::now() + MIN_PROBE_BACKOFF,
+                backoff: MIN_PROBE_BACKOFF,
+            });
+            inner.trips += 1;
+            self.open.store(true, Ordering::Release);
+            Some((reason, failures, inner.window.len()))
+        });
+        if let Some((reason, failures, calls)) = tripped {
+            warn!(
+                "[BREAKER] open, orders paused | reason={:?} failures={}/{}",
+                reason, failures, calls
+            );
+        }
+    }
+
+    /// Whether a recovery probe is due
+    pub fn probe_due(&self) -> bool {
+        self.is_open()
+            && self.update(|inner| {
+                inner
+                    .open
+                    .as_ref()
+                    .is_some_and(|open| Instant::now() >= open.next_probe)
+            })
+    }
+
+    fn record_probe(&self, healthy: bool) {
+        let closed = self.update(|inner| {
+            inner.probes += 1;
+            let open = inner.open.as_mut()?;
+            if !healthy {
+                open.backoff = (open.backoff * 2).min(MAX_PROBE_BACKOFF);
+                open.next_probe = Instant::now() + open.backoff;
+                return None;
+            }
+            let reason = open.reason;
+            inner.open = None;
+            inner.window.clear();
+            inner.unavailable_in_row = 0;
+            self.open.store(false, Ordering::Release);
+            Some(reason)
+        });
+        if let Some(reason) = closed {
+            info!("[BREAKER] closed, venue healthy | was {:?}", reason);
+        }
+    }
+
+    pub fn state(&self) -> BreakerState {
+        self.update(|inner| BreakerState {
+            open: inner.open.is_some(),
+            reason: inner.open.as_ref().map(|open| open.reason),
+            opened_at_ms: inner.open.as_ref().map(|open| open.opened_at_ms),
+            trips: inner.trips,
+            probes: inner.probes,
+            window_failures: inner.window.iter().filter(|&&f| f).count(),
+            window_calls: inner.window.len(),
+        })
+    }
+
+    /// Take over the state of the session being replaced: an outage does
+    /// not end with new credentials
+    pub(crate) fn inherit(&self, from: &CircuitBreaker) {
+        let state = from.update(|inner| inner.clone());
+        let open = state.open.is_some();
+        self.update(|inner| *inner = state);
+        self.open.store(open, Ordering::Release);
+    }
+}
+
+impl Trader {
+    /// Venue circuit breaker of this session
+    pub fn venue_breaker(&self) -> &CircuitBreaker {
+        &self.breaker
+    }
+
+    /// Ask the CLOB health endpoint whether the venue is back; closes the
+    /// breaker if so
+    /// Returns whether the venue answered healthy
+    pub async fn probe_venue(&self) -> bool {
+        let healthy = matches!(
+            tokio::time::timeout(PROBE_TIMEOUT, self.client.ok()).await,
+            Ok(Ok(_))
+        );
+        self.breaker.record_probe(healthy);
+        healthy
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Per-order outcome of bulk cancels
+//!
+//! The venue answers a bulk cancel with the ids it cancelled and, for every
+//! other order, the reason it did not. The reason is free text, so it is
+//! sorted by what it says: an order that matched before the cancel arrived
+//! is not a failure to act on, unlike one the venue refused to cancel.
+
+use polymarket_client_sdk::clob::types::response::CancelOrdersResponse;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelStatus {
+    Cancelled,
+    /// Matched before the cancel arrived
+    AlreadyFilled,
+    /// Unknown to the venue, or already cancelled
+    NotFound,
+    /// The venue refused; the order may still be resting
+    Failed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CancelOutcome {
+    pub order_id: String,
+    pub status: CancelStatus,
+    /// Venue's reason, empty when cancelled
+    pub reason: String,
+}
+
+impl CancelOutcome {
+    pub fn is_failure(&self) -> bool {
+        self.status == CancelStatus::Failed
+    }
+}
+
+/// Sort a not-cancelled reason
+fn classify(reason: &str) -> CancelStatus {
+    let reason = reason.to_ascii_lowercase();
+    if reason.contains("matched") || reason.contains("filled") {
+        CancelStatus::AlreadyFilled
+    } else if reason.contains("not found")
+        || reason.contains("can't be found")
+        || reason.contains("already canceled")
+        || reason.contains("already cancelled")
+    {
+        CancelStatus::NotFound
+    } else {
+        CancelStatus::Failed
+    }
+}
+
+/// Outcomes of a bulk cancel response, cancelled orders first, each group
+/// sorted by order id
+pub(crate) fn outcomes(resp: CancelOrdersResponse) -> Vec<CancelOutcome> {
+    let mut cancelled = resp.canceled;
+    cancelled.sort();
+    let mut rest: Vec<(String, String)> = resp.not_canceled.into_iter().collect();
+    rest.sort();
+
+    let mut outcomes = Vec::with_capacity(cancelled.len() + rest.len());
+    outcomes.extend(cancelled.into_iter().map(|order_id| CancelOutcome {
+        order_id,
+        status: CancelStatus::Cancelled,
+        reason: String::new(),
+    }));
+    outcomes.extend(rest.into_iter().map(|(order_id, reason)| CancelOutcome {
+        order_id,
+        status: classify(&reason),
+        reason,
+    }));
+    outcomes
+}
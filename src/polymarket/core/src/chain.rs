@@ -0,0 +1,344 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! On-chain position management (Polygon)
+//!
+//! Outcome tokens live in the funder's proxy wallet, so every call is routed
+//! through the Polymarket proxy wallet factory: the signing key (the wallet
+//! owner) sends the transaction and pays gas in POL, the proxy wallet makes
+//! the call. Gnosis Safe funders are not supported.
+//!
+//! Splitting turns USDC.e into as many YES and NO shares; merging burns
+//! equal amounts of YES and NO and returns as much USDC.e to the wallet.
+//! Neg-risk markets split and merge through the neg-risk adapter, which
+//! must be approved for the wallet's USDC.e and outcome tokens (the venue's
+//! onboarding does this). Converting, neg-risk only, turns NO shares of some
+//! questions of an event into YES shares of every other question plus USDC.
+//!
+//! Redeeming pays out the wallet's shares of a resolved market: each share
+//! of the winning outcome for 1 USDC, the losing ones for nothing.
+//!
+//! Positions tracked for risk limits are not updated by splits, merges and
+//! conversions; reading the token balances afterwards resyncs them.
+//!
+//! Gas is estimated before sending, so a call that would revert (an
+//! unresolved market, a missing approval) fails without spending any.
+
+use std::time::Duration;
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::{address, Address, Bytes, B256, U256};
+use alloy::providers::ProviderBuilder;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+use tracing::warn;
+
+use crate::{OrderError, Trader};
+
+/// Conditional Tokens Framework (ERC-1155 outcome tokens)
+pub const CTF_ADDRESS: Address = address!("4D97DCd97eC945f40cF65F87097ACe5EA0476045");
+pub const NEG_RISK_ADAPTER_ADDRESS: Address = address!("d91E80cF2E7be2e162c6513ceD06f1dD0dA35296");
+/// Collateral of every market (bridged USDC)
+pub const USDC_ADDRESS: Address = address!("2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+pub const PROXY_WALLET_FACTORY_ADDRESS: Address =
+    address!("aB45c5A4B0c941a2F231C04C3f49182e1A254052");
+
+/// Longest wait for a transaction to be mined
+pub const CHAIN_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Proxy call type: plain CALL
+const PROXY_CALL: u8 = 1;
+
+/// Gas limit above the estimate, percent, for state changing between the
+/// estimate and the block
+const GAS_HEADROOM_PERCENT: u64 = 20;
+
+sol! {
+    interface IConditionalTokens {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        );
+
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        );
+
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        );
+    }
+
+    interface INegRiskAdapter {
+        function splitPosition(bytes32 conditionId, uint256 amount);
+
+        function mergePositions(bytes32 conditionId, uint256 amount);
+
+        function convertPositions(bytes32 marketId, uint256 indexSet, uint256 amount);
+
+        function redeemPositions(bytes32 conditionId, uint256[] amounts);
+    }
+
+    #[sol(rpc)]
+    interface IProxyWalletFactory {
+        struct ProxyCall {
+            uint8 typeCode;
+            address to;
+            uint256 value;
+            bytes data;
+        }
+
+        function proxy(ProxyCall[] calls) payable returns (bytes[] returnValues);
+    }
+}
+
+/// Questions a neg-risk event can hold: a question id is its event's market
+/// id with the question's index in the last byte
+const MAX_NEG_RISK_QUESTIONS: usize = 256;
+
+/// Index sets of YES and NO
+fn binary_partition() -> Vec<U256> {
+    vec![U256::from(1), U256::from(2)]
+}
+
+impl Trader {
+    /// Split `amount_raw` (6 decimals) of USDC into as many shares of each
+    /// outcome of a binary market; returns the transaction hash once mined
+    /// The wallet must hold that much USDC.e
+    pub async fn split_position(
+        &self,
+        condition_id: &str,
+        neg_risk: bool,
+        amount_raw: i64,
+    ) -> Result<String, OrderError> {
+        let (condition, amount) = self.chain_args(condition_id, amount_raw)?;
+
+        let (to, data) = if neg_risk {
+            let call = INegRiskAdapter::splitPositionCall {
+                conditionId: condition,
+                amount,
+            };
+            (NEG_RISK_ADAPTER_ADDRESS, call.abi_encode())
+        } else {
+            let call = IConditionalTokens::splitPositionCall {
+                collateralToken: USDC_ADDRESS,
+                parentCollectionId: B256::ZERO,
+                conditionId: condition,
+                partition: binary_partition(),
+                amount,
+            };
+            (CTF_ADDRESS, call.abi_encode())
+        };
+        self.proxy_call(to, data).await
+    }
+
+    /// Merge `amount_raw` (6 decimals) of each outcome of a binary market
+    /// back into as much USDC; returns the transaction hash once mined
+    /// The wallet must hold that amount of both outcomes
+    pub async fn merge_positions(
+        &self,
+        condition_id: &str,
+        neg_risk: bool,
+        amount_raw: i64,
+    ) -> Result<String, OrderError> {
+        let (condition, amount) = self.chain_args(condition_id, amount_raw)?;
+
+        let (to, data) = if neg_risk {
+            let call = INegRiskAdapter::mergePositionsCall {
+                conditionId: condition,
+                amount,
+            };
+            (NEG_RISK_ADAPTER_ADDRESS, call.abi_encode())
+        } else {
+            let call = IConditionalTokens::mergePositionsCall {
+                collateralToken: USDC_ADDRESS,
+                parentCollectionId: B256::ZERO,
+                conditionId: condition,
+                partition: binary_partition(),
+                amount,
+            };
+            (CTF_ADDRESS, call.abi_encode())
+        };
+        self.proxy_call(to, data).await
+    }
+
+    /// Convert `amount_raw` (6 decimals) NO shares of each of `question_ids`,
+    /// questions of one neg-risk event, into as many YES shares of each
+    /// other question of the event, plus USDC for all but one of the NO
+    /// shares given up; returns the transaction hash once mined
+    /// Err(InvalidToken) if the ids are not of one event
+    pub async fn convert_positions(
+        &self,
+        question_ids: &[&str],
+        amount_raw: i64,
+    ) -> Result<String, OrderError> {
+        let first = question_ids.first().ok_or(OrderError::InvalidToken)?;
+        let (first, amount) = self.chain_args(first, amount_raw)?;
+        let mut market_id = first;
+        market_id.0[31] = 0;
+
+        let mut index_set = U256::ZERO;
+        for id in question_ids {
+            let question: B256 = id.parse().map_err(|_| OrderError::InvalidToken)?;
+            let index = question.0[31] as usize;
+            let mut event = question;
+            event.0[31] = 0;
+            if event != market_id || index >= MAX_NEG_RISK_QUESTIONS {
+                return Err(OrderError::InvalidToken);
+            }
+            index_set.set_bit(index, true);
+        }
+
+        let call = INegRiskAdapter::convertPositionsCall {
+            marketId: market_id,
+            indexSet: index_set,
+            amount,
+        };
+        self.proxy_call(NEG_RISK_ADAPTER_ADDRESS, call.abi_encode())
+            .await
+    }
+
+    /// Redeem the wallet's shares of both outcomes of a resolved market for
+    /// the USDC they pay out; returns the transaction hash once mined
+    /// Err(InvalidAmount) if the wallet holds none; the transaction fails
+    /// before it is sent if the market is not resolved yet
+    pub async fn redeem_positions(&self, condition_id: &str) -> Result<String, OrderError> {
+        self.ensure_chain()?;
+        let condition: B256 = condition_id.parse().map_err(|_| OrderError::InvalidToken)?;
+        let market = self.market_tokens(condition_id).await?;
+        let yes = self.token_balance(&market.yes_token_id).await?.max(0);
+        let no = self.token_balance(&market.no_token_id).await?.max(0);
+        if yes == 0 && no == 0 {
+            return Err(OrderError::InvalidAmount);
+        }
+
+        let (to, data) = if market.neg_risk {
+            // The adapter redeems the amounts given, YES then NO
+            let call = INegRiskAdapter::redeemPositionsCall {
+                conditionId: condition,
+                amounts: vec![U256::from(yes as u64), U256::from(no as u64)],
+            };
+            (NEG_RISK_ADAPTER_ADDRESS, call.abi_encode())
+        } else {
+            // The CTF redeems everything held of the index sets
+            let call = IConditionalTokens::redeemPositionsCall {
+                collateralToken: USDC_ADDRESS,
+                parentCollectionId: B256::ZERO,
+                conditionId: condition,
+                indexSets: binary_partition(),
+            };
+            (CTF_ADDRESS, call.abi_encode())
+        };
+        let hash = self.proxy_call(to, data).await?;
+
+        // Resync both positions and the cached USDC balance
+        for token in [&market.yes_token_id, &market.no_token_id] {
+            if let Err(e) = self.token_balance(token).await {
+                warn!("[REDEEM] position resync failed | {}", e);
+            }
+        }
+        if let Err(e) = self.usdc_balance().await {
+            warn!("[REDEEM] balance resync failed | {}", e);
+        }
+        Ok(hash)
+    }
+
+    /// Err unless the session may send transactions
+    pub(crate) fn ensure_chain(&self) -> Result<(), OrderError> {
+        self.ensure_writable()?;
+        // Paper trading never touches the chain
+        if self.is_dry_run() {
+            return Err(OrderError::ReadOnly);
+        }
+        Ok(())
+    }
+
+    /// Check that the session may send transactions and parse the id and
+    /// amount of one
+    fn chain_args(&self, id: &str, amount_raw: i64) -> Result<(B256, U256), OrderError> {
+        self.ensure_chain()?;
+        let id: B256 = id.parse().map_err(|_| OrderError::InvalidToken)?;
+        if amount_raw <= 0 {
+            return Err(OrderError::InvalidAmount);
+        }
+        Ok((id, U256::from(amount_raw as u64)))
+    }
+
+    /// Polygon RPC endpoint of the session (connect option `rpc_url`)
+    pub(crate) fn rpc_url(&self) -> Result<reqwest::Url, OrderError> {
+        self.options
+            .rpc_url
+            .parse()
+            .map_err(|e| OrderError::Api(anyhow::anyhow!("rpc_url: {}", e)))
+    }
+
+    /// Have the proxy wallet call `to` with `data`; returns the tx hash
+    async fn proxy_call(&self, to: Address, data: Vec<u8>) -> Result<String, OrderError> {
+        self.proxy_calls(vec![(to, data)]).await
+    }
+
+    /// Have the proxy wallet make `calls` (target, data) in order, in one
+    /// transaction; returns the tx hash once mined
+    pub(crate) async fn proxy_calls(
+        &self,
+        calls: Vec<(Address, Vec<u8>)>,
+    ) -> Result<String, OrderError> {
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(self.signer.clone()))
+            .connect_http(self.rpc_url()?);
+        let factory = IProxyWalletFactory::new(PROXY_WALLET_FACTORY_ADDRESS, provider);
+        let calls = calls
+            .into_iter()
+            .map(|(to, data)| IProxyWalletFactory::ProxyCall {
+                typeCode: PROXY_CALL,
+                to,
+                value: U256::ZERO,
+                data: Bytes::from(data),
+            })
+            .collect();
+
+        let call = factory.proxy(calls);
+        let gas = call
+            .estimate_gas()
+            .await
+            .map_err(|e| OrderError::Api(anyhow::anyhow!("gas estimate: {}", e)))?;
+        let pending = call
+            .gas(gas.saturating_add(gas * GAS_HEADROOM_PERCENT / 100))
+            .send()
+            .await
+            .map_err(|e| OrderError::Api(e.into()))?;
+        let hash = pending.tx_hash().to_string();
+        let receipt = pending
+            .with_timeout(Some(CHAIN_TIMEOUT))
+            .get_receipt()
+            .await
+            .map_err(|e| OrderError::Api(anyhow::anyhow!("transaction {}: {}", hash, e)))?;
+        if !receipt.status() {
+            return Err(OrderError::Api(anyhow::anyhow!(
+                "transaction {} reverted",
+                hash
+            )));
+        }
+        // Warns if the gas paid left the key low
+        if let Err(e) = self.gas_balance().await {
+            warn!("[GAS] balance check failed | {}", e);
+        }
+        Ok(hash)
+    }
+}
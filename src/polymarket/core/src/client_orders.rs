@@ -0,0 +1,218 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Client order ids
+//!
+//! An order placed with `place_with_id` (or `replace_with_id`) carries an
+//! id of the caller's choosing. The venue has no field for it: the id is
+//! kept here, journaled with the order and returned in its fill. It is
+//! claimed before the order is checked and stays taken while the order may
+//! be on the venue, so placing it again (a host retrying after a lost
+//! answer) fails with `DuplicateClientOrderId` instead of trading twice.
+//! The id is free again once the order failed before it was sent or the
+//! venue refused it.
+//!
+//! `client_order` tells what came of an id: in flight, placed (with the
+//! venue order id), refused, or unknown when the post failed without an
+//! answer; the open orders and the signed log then tell whether it landed.
+//! The newest `MAX_CLIENT_ORDERS` ids are kept.
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::Mutex;
+
+use crate::venue_error::{classify, ApiFailure};
+use crate::{OrderError, OrderFill, OrderRequest, Trader};
+
+/// Ids remembered; the oldest is forgotten past this
+pub const MAX_CLIENT_ORDERS: usize = 10_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientOrderState {
+    /// Placed, not answered yet
+    Pending,
+    /// Accepted by the venue under this order id
+    Placed(String),
+    /// Failed before it was sent or refused by the venue; the id is free
+    Refused,
+    /// The post failed without an answer: the order may rest on the venue
+    Unknown,
+}
+
+impl ClientOrderState {
+    fn of(result: &Result<OrderFill, OrderError>) -> Self {
+        match result {
+            Ok(fill) if fill.success => Self::Placed(fill.order_id.clone()),
+            Ok(_) => Self::Refused,
+            Err(OrderError::Api(e)) => match classify(e) {
+                ApiFailure::RateLimited | ApiFailure::Rejected => Self::Refused,
+                ApiFailure::Network | ApiFailure::Server | ApiFailure::Unknown => Self::Unknown,
+            },
+            Err(_) => Self::Refused,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Inner {
+    states: HashMap<String, ClientOrderState>,
+    /// Ids from the oldest
+    order: VecDeque<String>,
+}
+
+#[derive(Default)]
+pub(crate) struct ClientOrders(Mutex<Inner>);
+
+impl ClientOrders {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    fn inner(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Take `id` for a new order; Err if it is in use
+    fn claim(&self, id: &str) -> Result<(), OrderError> {
+        let mut inner = self.inner();
+        match inner.states.get(id) {
+            None => {}
+            Some(ClientOrderState::Refused) => {
+                inner.order.retain(|known| known != id);
+            }
+            Some(_) => return Err(OrderError::DuplicateClientOrderId(id.to_string())),
+        }
+        inner
+            .states
+            .insert(id.to_string(), ClientOrderState::Pending);
+        inner.order.push_back(id.to_string());
+        while inner.order.len() > MAX_CLIENT_ORDERS {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.states.remove(&oldest);
+            }
+        }
+        Ok(())
+    }
+
+    fn settle(&self, id: &str, state: ClientOrderState) {
+        if let Some(slot) = self.inner().states.get_mut(id) {
+            *slot = state;
+        }
+    }
+
+    /// Free `id` if its order never started
+    fn release(&self, id: &str) {
+        if let Some(slot) = self.inner().states.get_mut(id) {
+            if *slot == ClientOrderState::Pending {
+                *slot = ClientOrderState::Refused;
+            }
+        }
+    }
+
+    /// Ids of the session being replaced; the newer win
+    pub(crate) fn inherit(&self, from: &ClientOrders) {
+        let taken = std::mem::take(&mut *from.inner());
+        let mut inner = self.inner();
+        let newer = std::mem::take(&mut *inner);
+        *inner = taken;
+        for id in newer.order {
+            if let Some(state) = newer.states.get(&id) {
+                inner.order.retain(|known| *known != id);
+                inner.states.insert(id.clone(), state.clone());
+                inner.order.push_back(id);
+            }
+        }
+    }
+}
+
+impl Trader {
+    /// Check and place an order under `client_order_id`, which comes back
+    /// in the fill
+    /// Fails with `DuplicateClientOrderId`, sending nothing, if the id is in use
+    pub async fn place_with_id(
+        &self,
+        client_order_id: &str,
+        token_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, OrderError> {
+        let journaled = serde_json::json!({ "client_order_id": client_order_id, "order": request });
+        self.under_client_id(
+            client_order_id,
+            request.op(),
+            token_id,
+            journaled,
+            self.place_unjournaled(token_id, request),
+        )
+        .await
+    }
+
+    /// `replace` posting the replacement under `client_order_id`
+    pub async fn replace_with_id(
+        &self,
+        client_order_id: &str,
+        order_id: &str,
+        token_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, OrderError> {
+        let journaled = serde_json::json!({
+            "client_order_id": client_order_id,
+            "order_id": order_id,
+            "order": request,
+        });
+        self.under_client_id(
+            client_order_id,
+            "REPLACE",
+            token_id,
+            journaled,
+            self.replace_unjournaled(order_id, token_id, request),
+        )
+        .await
+    }
+
+    /// Run the journaled order `call` with `client_order_id` claimed
+    async fn under_client_id(
+        &self,
+        client_order_id: &str,
+        op: &str,
+        token_id: &str,
+        journaled: serde_json::Value,
+        call: impl Future<Output = Result<OrderFill, OrderError>>,
+    ) -> Result<OrderFill, OrderError> {
+        self.client_orders.claim(client_order_id)?;
+        let call = async {
+            let result = call.await;
+            self.client_orders
+                .settle(client_order_id, ClientOrderState::of(&result));
+            result.map(|fill| OrderFill {
+                client_order_id: Some(client_order_id.to_string()),
+                ..fill
+            })
+        };
+        let result = self
+            .journaled(op, token_id, journaled, call, |fill| {
+                serde_json::json!(fill)
+            })
+            .await;
+        // Never sent if the journal refused the request line
+        self.client_orders.release(client_order_id);
+        result
+    }
+
+    /// What came of the order placed under `client_order_id`; None if the
+    /// id was never used or is forgotten
+    pub fn client_order(&self, client_order_id: &str) -> Option<ClientOrderState> {
+        self.client_orders
+            .inner()
+            .states
+            .get(client_order_id)
+            .cloned()
+    }
+}
@@ -0,0 +1,362 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Polymarket behind the venue-neutral connector traits
+//!
+//! Instruments are CLOB token ids, one per outcome; quantities are shares
+//! and prices USDC per share (0-1). Market orders take what fills at once,
+//! up to MARKET_BUY_PRICE buying and down to MARKET_SELL_PRICE selling;
+//! limit orders rest until cancelled, or are cancelled at once with IOC.
+//! Limit buys are sized in USDC on the venue: the shares times the price.
+//! The only balance is USDC. Top of book comes from the market channel,
+//! connected on the first subscribe; a book that missed an update is
+//! restored over REST.
+//!
+//! Config: `secret` is the wallet's private key (hex) and `account` the
+//! proxy wallet the orders trade for; `endpoint` is the CLOB host,
+//! DEFAULT_CLOB_HOST if None. `rate_limit`, when on, replaces the Trader's
+//! order and account limits. `api_key` and `testnet` are not used.
+
+use std::sync::Arc;
+
+use flox_connector::feed::{self, FeedTask};
+use flox_connector::{
+    Balance, BoxFuture, Call, CallGate, Capabilities, Connector, ConnectorConfig, ConnectorError,
+    OpMetrics, OrderExecutor, OrderSpec, OrderType, PlacedOrder, RateLimit, TimeInForce, TopOfBook,
+};
+use tracing::{info, warn};
+
+use crate::cancels::CancelStatus;
+use crate::market_ws::{BookFeed, FeedUpdate, MARKET_CHANNEL_PATH};
+use crate::options::ConnectOptions;
+use crate::timeouts::CallClass;
+use crate::venue_error::{classify, ApiFailure};
+use crate::{
+    ConnectError, OrderError, OrderRequest, Trader, DECIMAL_SCALE, MARKET_BUY_PRICE,
+    MARKET_SELL_PRICE,
+};
+
+const VENUE: &str = "polymarket";
+
+/// Limit order sizes are whole hundredths of a share
+const SHARE_STEP: f64 = 0.01;
+
+impl From<OrderError> for ConnectorError {
+    fn from(err: OrderError) -> Self {
+        let message = err.to_string();
+        match err {
+            OrderError::InvalidToken => Self::InvalidInstrument(message),
+            OrderError::InvalidAmount
+            | OrderError::MinOrderSize
+            | OrderError::MinShares
+            | OrderError::TickSize { .. }
+            | OrderError::InsufficientLiquidity { .. }
+            | OrderError::Slippage { .. } => Self::InvalidOrder(message),
+            OrderError::InsufficientBalance(_) => Self::InsufficientBalance(message),
+            OrderError::DuplicateClientOrderId(_) => Self::InvalidArgument(message),
+            OrderError::VenueUnavailable => Self::Venue(message),
+            OrderError::RiskLimit(_)
+            | OrderError::ReadOnly
+            | OrderError::Halted
+            | OrderError::NotCancelled(_) => Self::Rejected(message),
+            OrderError::Api(e) => match classify(&e) {
+                ApiFailure::Network => Self::Network(message),
+                ApiFailure::RateLimited => Self::RateLimited(message),
+                ApiFailure::Rejected => Self::Rejected(message),
+                ApiFailure::Server | ApiFailure::Unknown => Self::Venue(message),
+            },
+        }
+    }
+}
+
+impl From<ConnectError> for ConnectorError {
+    fn from(err: ConnectError) -> Self {
+        let message = err.to_string();
+        match err {
+            ConnectError::InvalidKey => Self::InvalidKey(message),
+            ConnectError::InvalidFunder | ConnectError::InvalidOptions(_) => {
+                Self::InvalidConfig(message)
+            }
+            ConnectError::Auth(_) => Self::Auth(message),
+        }
+    }
+}
+
+/// Order request of a spec, refused if Polymarket cannot express it
+fn order_request(order: &OrderSpec) -> Result<OrderRequest, ConnectorError> {
+    if order.post_only || order.reduce_only {
+        return Err(ConnectorError::unsupported(
+            VENUE,
+            "post-only and reduce-only orders",
+        ));
+    }
+    let buy = order.side == flox_connector::Side::Buy;
+    let shares = order.quantity;
+    Ok(match (order.order_type, order.tif) {
+        (OrderType::Market, _) if buy => OrderRequest::MarketBuyShares {
+            shares,
+            max_price: MARKET_BUY_PRICE,
+        },
+        (OrderType::Market, _) => OrderRequest::MarketSell {
+            size: shares,
+            min_price: MARKET_SELL_PRICE,
+        },
+        // The venue rounds the shares a USDC amount buys up to the step:
+        // half a step under keeps float noise from adding one
+        (OrderType::Limit { price }, TimeInForce::Gtc) if buy => OrderRequest::LimitBuy {
+            price,
+            usdc_amount: (shares - SHARE_STEP / 2.0) * price,
+        },
+        (OrderType::Limit { price }, TimeInForce::Gtc) => OrderRequest::LimitSell {
+            price,
+            size: shares,
+        },
+        // A market order is an IOC order at its bound
+        (OrderType::Limit { price }, TimeInForce::Ioc) if buy => OrderRequest::MarketBuyShares {
+            shares,
+            max_price: price,
+        },
+        (OrderType::Limit { price }, TimeInForce::Ioc) => OrderRequest::MarketSell {
+            size: shares,
+            min_price: price,
+        },
+        (OrderType::Limit { .. }, tif) => {
+            return Err(ConnectorError::unsupported(
+                VENUE,
+                &format!("{:?} orders", tif),
+            ))
+        }
+    })
+}
+
+pub struct PolymarketConnector {
+    trader: Arc<Trader>,
+    books: Arc<BookFeed>,
+    /// Task keeping the market channel connected, started by the first
+    /// subscribe
+    book_feed: FeedTask,
+    gate: CallGate,
+}
+
+impl PolymarketConnector {
+    pub const CAPABILITIES: Capabilities = Capabilities {
+        market: true,
+        limit: true,
+        ioc: true,
+        cancel_all: true,
+        balances: true,
+        top_of_book: true,
+        ..Capabilities::NONE
+    };
+
+    /// The Trader's rate limits throttle the calls; the gate only times them
+    pub fn new(trader: Trader) -> Self {
+        Self {
+            trader: Arc::new(trader),
+            books: Arc::new(BookFeed::new()),
+            book_feed: FeedTask::new(),
+            gate: CallGate::new(RateLimit::OFF),
+        }
+    }
+
+    pub fn trader(&self) -> &Arc<Trader> {
+        &self.trader
+    }
+
+    /// Books of the subscribed tokens
+    pub fn books(&self) -> &Arc<BookFeed> {
+        &self.books
+    }
+
+    /// Metrics of the session's calls; their rate limits are the Trader's
+    pub fn gate(&self) -> &CallGate {
+        &self.gate
+    }
+}
+
+/// Apply a market channel message; a gap starts a resync of the book
+fn on_book_message(trader: &Arc<Trader>, books: &Arc<BookFeed>, text: &str) {
+    for update in books.apply(text, trader.drift_log()) {
+        let FeedUpdate::Gap(token_id) = update else {
+            continue;
+        };
+        warn!("[BOOK FEED] gap | token={} | resyncing", token_id);
+        let (trader, books) = (trader.clone(), books.clone());
+        tokio::spawn(async move {
+            match books.resync(&trader, &token_id).await {
+                Ok(true) => info!("[BOOK FEED] resynced | token={}", token_id),
+                Ok(false) => {}
+                // Restored by the venue's next book instead
+                Err(e) => warn!("[BOOK FEED] resync failed | token={} | {}", token_id, e),
+            }
+        });
+    }
+}
+
+impl Connector for PolymarketConnector {
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+
+    fn connect(config: ConnectorConfig) -> BoxFuture<'static, Result<Self, ConnectorError>> {
+        Box::pin(async move {
+            let funder = config
+                .account
+                .as_deref()
+                .ok_or_else(|| ConnectorError::InvalidConfig("funder missing".to_string()))?;
+            let mut options = ConnectOptions::default();
+            if let Some(endpoint) = &config.endpoint {
+                options.clob_host = endpoint.clone();
+            }
+            let trader = Trader::connect_with(config.require_secret()?, funder, options).await?;
+            if config.rate_limit != RateLimit::OFF {
+                for class in [CallClass::Order, CallClass::Account] {
+                    trader
+                        .set_rate_limit(class, config.rate_limit)
+                        .map_err(ConnectorError::InvalidConfig)?;
+                }
+            }
+            Ok(Self::new(trader))
+        })
+    }
+
+    fn subscribe<'a>(&'a self, instrument: &'a str) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(async move {
+            crate::parse_token(instrument)?;
+            if self.books.subscribe(instrument) {
+                info!("[BOOK FEED] subscribed | token={}", instrument);
+            }
+            let (trader, books) = (self.trader.clone(), self.books.clone());
+            self.book_feed.ensure(|| async move {
+                let url = format!("{}{}", trader.options().ws_host, MARKET_CHANNEL_PATH);
+                feed::reconnect("BOOK FEED", || {
+                    books.run(&url, |text| on_book_message(&trader, &books, text))
+                })
+                .await
+            });
+            Ok(())
+        })
+    }
+
+    fn unsubscribe(&self, instrument: &str) -> Result<(), ConnectorError> {
+        if !self.books.unsubscribe(instrument) {
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        }
+        info!("[BOOK FEED] unsubscribed | token={}", instrument);
+        Ok(())
+    }
+
+    fn top_of_book(&self, instrument: &str) -> Result<Option<TopOfBook>, ConnectorError> {
+        let Some(book) = self.books.book(instrument) else {
+            if self.books.is_subscribed(instrument) {
+                return Ok(None);
+            }
+            return Err(ConnectorError::InvalidArgument(format!(
+                "not subscribed: {}",
+                instrument
+            )));
+        };
+        let float = |d| f64::try_from(d).unwrap_or(0.0);
+        let (bid, bid_size) = book
+            .bids
+            .first()
+            .map_or((0.0, 0.0), |l| (float(l.price), float(l.size)));
+        let (ask, ask_size) = book
+            .asks
+            .first()
+            .map_or((0.0, 0.0), |l| (float(l.price), float(l.size)));
+        Ok(Some(TopOfBook {
+            bid,
+            bid_size,
+            ask,
+            ask_size,
+            age: book.age(),
+        }))
+    }
+
+    fn metrics(&self) -> Vec<OpMetrics> {
+        self.gate.metrics()
+    }
+}
+
+impl OrderExecutor for PolymarketConnector {
+    fn place<'a>(
+        &'a self,
+        order: &'a OrderSpec,
+    ) -> BoxFuture<'a, Result<PlacedOrder, ConnectorError>> {
+        Box::pin(async move {
+            let request = order_request(order)?;
+            let place = self.trader.place(&order.instrument, request);
+            let fill = self.gate.run(Call::OrderPost, place).await?;
+            if !fill.success {
+                return Err(ConnectorError::Rejected(fill.error_msg));
+            }
+            Ok(PlacedOrder {
+                order_id: fill.order_id,
+                client_order_id: String::new(),
+            })
+        })
+    }
+
+    fn cancel<'a>(
+        &'a self,
+        _instrument: &'a str,
+        order_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), ConnectorError>> {
+        Box::pin(self.gate.run(Call::Cancel, self.trader.cancel(order_id)))
+    }
+
+    fn cancel_all<'a>(
+        &'a self,
+        instrument: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<Option<u32>, ConnectorError>> {
+        Box::pin(async move {
+            let outcomes = match instrument {
+                Some(token_id) => {
+                    let cancel = self.trader.cancel_market(token_id);
+                    self.gate.run(Call::Cancel, cancel).await?
+                }
+                None => {
+                    self.gate
+                        .run(Call::Cancel, self.trader.cancel_all())
+                        .await?
+                }
+            };
+            let cancelled = outcomes
+                .iter()
+                .filter(|o| o.status == CancelStatus::Cancelled)
+                .count();
+            Ok(Some(cancelled as u32))
+        })
+    }
+
+    fn balances(&self) -> BoxFuture<'_, Result<Vec<Balance>, ConnectorError>> {
+        Box::pin(async move {
+            let balance = self.trader.usdc_balance();
+            let raw = self
+                .gate
+                .run(Call::Balance, async { balance.await.map_err(OrderError::Api) })
+                .await?;
+            // Less the buys in flight and the reserve kept back
+            let free = self.trader.balance().available().unwrap_or(raw);
+            Ok(vec![Balance {
+                asset: "USDC".to_string(),
+                free: free as f64 / DECIMAL_SCALE as f64,
+                total: raw as f64 / DECIMAL_SCALE as f64,
+            }])
+        })
+    }
+}
@@ -0,0 +1,343 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Latency-aware routing of order traffic across CLOB gateways
+//!
+//! A session opens one authenticated client per gateway: `clob_host` and
+//! each of `clob_gateways`. Orders and cancels go to the active one; every
+//! other request stays on `clob_host`. `Trader::probe_endpoints` times an
+//! unauthenticated request to each gateway and keeps a smoothed RTT:
+//! - a gateway is down after `FAILURES_TO_DOWN` failed probes in a row, or
+//!   at once when an order call to it fails in transport (timeout,
+//!   connection error); a successful probe brings it back
+//! - order traffic moves to the fastest healthy gateway, but only once it
+//!   beats the active one by `SWITCH_MARGIN`, so close RTTs do not flap
+//!
+//! A failed order is never retried on another gateway: it may have reached
+//! the venue. Failover applies to the calls after it.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use polymarket_client_sdk::types::U256;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::timeouts::CallClass;
+use crate::{ClobClient, Trader};
+
+/// Consecutive failed probes that mark a gateway down
+pub const FAILURES_TO_DOWN: u32 = 2;
+
+/// A gateway takes over once its RTT is below this share of the active one's
+pub const SWITCH_MARGIN: f64 = 0.8;
+
+/// How long a probe may take before it counts as failed
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Weight of the newest probe in the smoothed RTT
+const RTT_SMOOTHING: f64 = 0.3;
+
+/// Per-gateway state, as reported by `Trader::endpoint_metrics`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct EndpointMetrics {
+    pub host: String,
+    /// Order traffic goes here
+    pub active: bool,
+    pub healthy: bool,
+    /// Smoothed probe RTT, None until a probe succeeds
+    pub rtt_ms: Option<f64>,
+    pub last_rtt_ms: Option<f64>,
+    pub probes: u64,
+    pub probe_failures: u64,
+    /// Orders and cancels sent here
+    pub orders: u64,
+    /// Of those, failed in transport
+    pub order_failures: u64,
+    /// Times order traffic moved here
+    pub activations: u64,
+}
+
+struct Endpoint {
+    metrics: EndpointMetrics,
+    failures_in_row: u32,
+}
+
+impl Endpoint {
+    fn healthy(&self) -> bool {
+        self.failures_in_row < FAILURES_TO_DOWN
+    }
+}
+
+/// Gateways of a session; index 0 is `clob_host`, whose client is the
+/// session's own
+pub(crate) struct EndpointSet {
+    /// Other gateways and their clients, index 1 onwards
+    alternates: Vec<(String, ClobClient)>,
+    endpoints: Mutex<Vec<Endpoint>>,
+    active: AtomicUsize,
+}
+
+impl EndpointSet {
+    pub(crate) fn new(primary_host: &str, alternates: Vec<(String, ClobClient)>) -> Self {
+        let mut endpoints = Vec::with_capacity(alternates.len() + 1);
+        let hosts = std::iter::once(primary_host.to_string())
+            .chain(alternates.iter().map(|(host, _)| host.clone()));
+        for host in hosts {
+            endpoints.push(Endpoint {
+                metrics: EndpointMetrics {
+                    host,
+                    healthy: true,
+                    ..Default::default()
+                },
+                failures_in_row: 0,
+            });
+        }
+        endpoints[0].metrics.active = true;
+        Self {
+            alternates,
+            endpoints: Mutex::new(endpoints),
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    fn active(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    fn update<R>(&self, f: impl FnOnce(&mut Vec<Endpoint>) -> R) -> R {
+        f(&mut self.endpoints.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn record_probe(&self, index: usize, result: Result<Duration, String>) {
+        self.update(|endpoints| {
+            let endpoint = &mut endpoints[index];
+            endpoint.metrics.probes += 1;
+            match result {
+                Ok(rtt) => {
+                    let ms = rtt.as_secs_f64() * 1000.0;
+                    let smoothed = match endpoint.metrics.rtt_ms {
+                        Some(avg) => avg + RTT_SMOOTHING * (ms - avg),
+                        None => ms,
+                    };
+                    endpoint.metrics.rtt_ms = Some(smoothed);
+                    endpoint.metrics.last_rtt_ms = Some(ms);
+                    endpoint.failures_in_row = 0;
+                }
+                Err(e) => {
+                    endpoint.metrics.probe_failures += 1;
+                    endpoint.failures_in_row += 1;
+                    warn!(
+                        "[ENDPOINTS] probe failed | {} | {}",
+                        endpoint.metrics.host, e
+                    );
+                }
+            }
+            endpoint.metrics.healthy = endpoint.healthy();
+        })
+    }
+
+    /// Count an order call; a transport failure marks the gateway down
+    fn record_order(&self, index: usize, transport_failure: bool) {
+        let failed_over = self.update(|endpoints| {
+            let endpoint = &mut endpoints[index];
+            endpoint.metrics.orders += 1;
+            if !transport_failure {
+                return false;
+            }
+            endpoint.metrics.order_failures += 1;
+            endpoint.failures_in_row = FAILURES_TO_DOWN;
+            endpoint.metrics.healthy = false;
+            true
+        });
+        if failed_over {
+            self.reselect();
+        }
+    }
+
+    /// Move order traffic to the fastest healthy gateway if it is clearly
+    /// faster, or if the active one is down
+    fn reselect(&self) {
+        let switched = self.update(|endpoints| {
+            let current = self.active();
+            let rtt = |i: usize| endpoints[i].metrics.rtt_ms.unwrap_or(f64::INFINITY);
+            // Healthy gateways, fastest first; unmeasured ones last, in order
+            let mut healthy: Vec<usize> = (0..endpoints.len())
+                .filter(|&i| endpoints[i].healthy())
+                .collect();
+            healthy.sort_by(|&a, &b| rtt(a).total_cmp(&rtt(b)));
+            let best = *healthy.first()?;
+            let take_over = if !endpoints[current].healthy() {
+                best != current
+            } else {
+                best != current && rtt(best) < rtt(current) * SWITCH_MARGIN
+            };
+            if !take_over {
+                return None;
+            }
+            endpoints[current].metrics.active = false;
+            endpoints[best].metrics.active = true;
+            endpoints[best].metrics.activations += 1;
+            self.active.store(best, Ordering::Release);
+            Some((
+                endpoints[current].metrics.host.clone(),
+                endpoints[best].metrics.host.clone(),
+                endpoints[best].metrics.rtt_ms,
+            ))
+        });
+        if let Some((from, to, rtt)) = switched {
+            info!(
+                "[ENDPOINTS] order traffic {} -> {} | rtt_ms={:?}",
+                from, to, rtt
+            );
+        }
+    }
+
+    fn metrics(&self) -> Vec<EndpointMetrics> {
+        self.update(|endpoints| endpoints.iter().map(|e| e.metrics.clone()).collect())
+    }
+
+    /// Keep the measurements and active gateway of `from`, if it had the
+    /// same gateways
+    pub(crate) fn inherit(&self, from: &EndpointSet) {
+        let previous = from.update(|endpoints| {
+            endpoints
+                .iter()
+                .map(|e| (e.metrics.clone(), e.failures_in_row))
+                .collect::<Vec<_>>()
+        });
+        let inherited = self.update(|endpoints| {
+            let same = endpoints.len() == previous.len()
+                && endpoints
+                    .iter()
+                    .zip(&previous)
+                    .all(|(e, (m, _))| e.metrics.host == m.host);
+            if same {
+                for (endpoint, (metrics, failures_in_row)) in endpoints.iter_mut().zip(previous) {
+                    endpoint.metrics = metrics;
+                    endpoint.failures_in_row = failures_in_row;
+                }
+            }
+            same
+        });
+        if inherited {
+            self.active.store(from.active(), Ordering::Release);
+        }
+    }
+}
+
+/// Timeouts and connection errors: the request may never have reached the
+/// gateway, unlike a venue rejection
+pub(crate) fn is_transport_error(e: &anyhow::Error) -> bool {
+    e.to_string() == "timeout"
+        || e.chain().any(|cause| {
+            cause
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|e| e.is_connect() || e.is_timeout())
+        })
+}
+
+impl Trader {
+    /// Client of gateway `index`
+    fn endpoint_client(&self, index: usize) -> &ClobClient {
+        match index {
+            0 => &self.client,
+            i => &self.endpoints.alternates[i - 1].1,
+        }
+    }
+
+    /// Gateway order traffic goes to, and its client
+    pub(crate) fn order_client(&self) -> (usize, &ClobClient) {
+        let index = self.endpoints.active();
+        (index, self.endpoint_client(index))
+    }
+
+    /// Count an order call made through `order_client`, failing over if it
+    /// failed in transport; the venue circuit breaker and drift log see it too
+    pub(crate) fn routed<T>(&self, index: usize, result: anyhow::Result<T>) -> anyhow::Result<T> {
+        let transport_failure = result.as_ref().err().is_some_and(is_transport_error);
+        self.endpoints.record_order(index, transport_failure);
+        self.breaker.record(result.as_ref().err());
+        self.drift.record_sdk("clob.orders", result)
+    }
+
+    /// Time a request to every gateway, then route order traffic to the
+    /// fastest healthy one
+    pub async fn probe_endpoints(&self) {
+        let count = self.endpoints.alternates.len() + 1;
+        let probes = join_all((0..count).map(|index| async move {
+            let client = self.endpoint_client(index);
+            let start = Instant::now();
+            let result = match tokio::time::timeout(PROBE_TIMEOUT, client.ok()).await {
+                Ok(Ok(_)) => Ok(start.elapsed()),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err("timeout".to_string()),
+            };
+            (index, result)
+        }))
+        .await;
+        for (index, result) in probes {
+            self.endpoints.record_probe(index, result);
+        }
+        self.endpoints.reselect();
+    }
+
+    /// State of every gateway, `clob_host` first
+    pub fn endpoint_metrics(&self) -> Vec<EndpointMetrics> {
+        self.endpoints.metrics()
+    }
+
+    /// One request to each of the other gateways, keeping their pooled
+    /// connections open; a gateway that fails is only logged
+    pub(crate) async fn ping_gateways(&self) {
+        let pings = join_all(
+            self.endpoints
+                .alternates
+                .iter()
+                .map(|(_, client)| self.limited(CallClass::MarketData, client.ok())),
+        )
+        .await;
+        for ((host, _), result) in self.endpoints.alternates.iter().zip(pings) {
+            if let Err(e) = result {
+                warn!("[ENDPOINTS] keep-alive failed | {} | {}", host, e);
+            }
+        }
+    }
+
+    /// Drop the SDK's metadata caches of the other gateways' clients
+    pub(crate) fn invalidate_gateway_caches(&self) {
+        for (_, client) in &self.endpoints.alternates {
+            client.invalidate_internal_caches();
+        }
+    }
+
+    /// Warm the order builder's metadata on the other gateways' clients,
+    /// so a failover pays no extra round trip
+    pub(crate) async fn prefetch_gateways(&self, token_id: &str, token: U256) {
+        let warmed = join_all(self.endpoints.alternates.iter().map(|(_, client)| {
+            self.limited(CallClass::MarketData, async move {
+                client.tick_size(token).await?;
+                client.fee_rate_bps(token).await?;
+                client.neg_risk(token).await?;
+                Ok::<_, anyhow::Error>(())
+            })
+        }))
+        .await;
+        for ((host, _), result) in self.endpoints.alternates.iter().zip(warmed) {
+            if let Err(e) = result {
+                warn!(
+                    "[ENDPOINTS] prefetch failed | {} | {} | {}",
+                    host, token_id, e
+                );
+            }
+        }
+    }
+}
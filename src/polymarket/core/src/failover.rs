@@ -0,0 +1,114 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Detection of credentials the venue no longer accepts
+//!
+//! `CredentialMonitor` watches the outcome of authenticated calls. A 401 or
+//! 403 answer means the API key was rejected; `RATE_LIMITS_TO_BAN` 429
+//! answers in a row, with no other answer between, are taken as a rate ban
+//! rather than a burst. Either is the cue to fail over to backup credentials.
+//!
+//! The SDK does not expose the HTTP status as a type, so it is read from the
+//! error: a reqwest status if one is in the chain, else the status as the
+//! message spells it ("401 Unauthorized", "status: 429", ...).
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use serde::Serialize;
+
+use crate::OrderError;
+
+/// 429 answers in a row taken as a rate ban
+pub const RATE_LIMITS_TO_BAN: u32 = 5;
+
+/// Why the current credentials are considered unusable
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialFault {
+    /// 401/403: revoked or invalid API key
+    Rejected,
+    /// Rate limited `RATE_LIMITS_TO_BAN` times in a row
+    RateBanned,
+}
+
+#[derive(Default)]
+pub struct CredentialMonitor {
+    rate_limited_in_row: AtomicU32,
+}
+
+impl CredentialMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the outcome of an authenticated call
+    /// Some once the credentials look unusable; local rejections (risk,
+    /// balance, tick size) say nothing about them and are ignored
+    pub fn observe(&self, result: Result<(), &OrderError>) -> Option<CredentialFault> {
+        let status = match result {
+            Ok(()) => None,
+            Err(OrderError::Api(e)) => http_status(e),
+            Err(_) => return None,
+        };
+        match status {
+            Some(401 | 403) => {
+                self.reset();
+                Some(CredentialFault::Rejected)
+            }
+            Some(429) => {
+                let in_row = self.rate_limited_in_row.fetch_add(1, Ordering::AcqRel) + 1;
+                if in_row >= RATE_LIMITS_TO_BAN {
+                    self.reset();
+                    Some(CredentialFault::RateBanned)
+                } else {
+                    None
+                }
+            }
+            _ => {
+                self.reset();
+                None
+            }
+        }
+    }
+
+    /// Forget the 429s seen, e.g. once other credentials took over
+    pub fn reset(&self) {
+        self.rate_limited_in_row.store(0, Ordering::Release);
+    }
+}
+
+/// HTTP status of a failed call, if known
+/// From the message only 401, 403 and 429 are recognized
+pub fn http_status(e: &anyhow::Error) -> Option<u16> {
+    let from_reqwest = e
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<reqwest::Error>()?.status());
+    if let Some(status) = from_reqwest {
+        return Some(status.as_u16());
+    }
+
+    let message = e.to_string().to_ascii_lowercase();
+    let spelled = [
+        (401, "401 unauthorized"),
+        (403, "403 forbidden"),
+        (429, "429 too many requests"),
+    ];
+    for (status, line) in spelled {
+        let forms = [
+            line.to_string(),
+            format!("status: {}", status),
+            format!("status {}", status),
+            format!("status code {}", status),
+        ];
+        if forms.iter().any(|form| message.contains(form.as_str())) {
+            return Some(status);
+        }
+    }
+    None
+}
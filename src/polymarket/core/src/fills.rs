@@ -0,0 +1,207 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Per-order fill aggregation
+//!
+//! `FillAggregator` follows the orders it is handed from placement until
+//! they complete, turning what is known of each into a stream of fills and
+//! a running summary: cumulative shares, VWAP and fees. The fill in the
+//! venue's answer to the order is the first; later ones are found by
+//! polling the order's matched size and are priced at its limit (they are
+//! maker fills, so they pay no fee). An order completes once it is no
+//! longer live; FAK orders complete on placement.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use polymarket_client_sdk::clob::types::Side;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::{OrderFill, Trader};
+
+/// One fill of a tracked order
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Fill {
+    pub qty_raw: i64,
+    pub price_raw: i64,
+    /// Taker fee in shares, 0 for maker fills
+    pub fee_raw: i64,
+}
+
+/// Running totals of one order
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FillSummary {
+    pub order_id: String,
+    pub token_id: String,
+    #[serde(serialize_with = "serialize_side")]
+    pub side: Side,
+    pub fills: u32,
+    pub filled_qty_raw: i64,
+    /// Volume-weighted fill price, 0 until something fills
+    pub vwap_raw: i64,
+    /// Taker fees in shares
+    pub fees_raw: i64,
+    /// Venue status once complete (MATCHED, CANCELED, ...), else LIVE
+    pub status: String,
+    /// Id the caller placed the order under, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+}
+
+impl FillSummary {
+    fn add(&mut self, fill: &Fill) {
+        let notional = self.vwap_raw as i128 * self.filled_qty_raw as i128
+            + fill.price_raw as i128 * fill.qty_raw as i128;
+        self.fills += 1;
+        self.filled_qty_raw += fill.qty_raw;
+        self.fees_raw += fill.fee_raw;
+        if self.filled_qty_raw > 0 {
+            self.vwap_raw = (notional / self.filled_qty_raw as i128) as i64;
+        }
+    }
+}
+
+fn serialize_side<S: serde::Serializer>(side: &Side, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(match side {
+        Side::Buy => "buy",
+        _ => "sell",
+    })
+}
+
+#[derive(Debug)]
+pub enum FillEvent<'a> {
+    /// A new fill and the totals including it
+    Fill(&'a Fill, &'a FillSummary),
+    /// The order is no longer live; its final totals
+    Completed(&'a FillSummary),
+}
+
+struct Tracked {
+    summary: FillSummary,
+    /// Price of fills found by polling: the order's limit
+    limit_price_raw: i64,
+    /// Matched size already reported
+    matched_raw: i64,
+}
+
+#[derive(Default)]
+pub struct FillAggregator {
+    orders: Mutex<HashMap<String, Tracked>>,
+}
+
+impl FillAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start following an order the venue accepted, reporting the fill in
+    /// its answer; an order with nothing resting completes right away
+    pub fn track(
+        &self,
+        token_id: &str,
+        side: Side,
+        fill: &OrderFill,
+        mut on_event: impl FnMut(FillEvent),
+    ) {
+        if !fill.success || fill.order_id.is_empty() {
+            return;
+        }
+        let mut tracked = Tracked {
+            summary: FillSummary {
+                order_id: fill.order_id.clone(),
+                token_id: token_id.to_string(),
+                side,
+                fills: 0,
+                filled_qty_raw: 0,
+                vwap_raw: 0,
+                fees_raw: 0,
+                status: "LIVE".to_string(),
+                client_order_id: fill.client_order_id.clone(),
+            },
+            limit_price_raw: fill.avg_price_raw,
+            matched_raw: fill.filled_qty_raw + fill.fee_raw,
+        };
+        if fill.filled_qty_raw > 0 {
+            let first = Fill {
+                qty_raw: fill.filled_qty_raw,
+                price_raw: fill.avg_price_raw,
+                fee_raw: fill.fee_raw,
+            };
+            tracked.summary.add(&first);
+            on_event(FillEvent::Fill(&first, &tracked.summary));
+        }
+
+        if fill.remaining_qty_raw > 0 {
+            let mut orders = self.orders.lock().unwrap_or_else(|e| e.into_inner());
+            orders.insert(fill.order_id.clone(), tracked);
+        } else {
+            let status = if fill.filled_qty_raw > 0 {
+                "MATCHED"
+            } else {
+                "UNMATCHED"
+            };
+            tracked.summary.status = status.to_string();
+            on_event(FillEvent::Completed(&tracked.summary));
+        }
+    }
+
+    /// Number of orders followed
+    pub fn tracked(&self) -> usize {
+        self.orders.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Stop following every order, without reporting them
+    pub fn clear(&self) {
+        self.orders
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Check every followed order once, reporting new fills and completions
+    /// Runs one pass at a time: only the task driving the aggregator may call it
+    pub async fn poll(&self, trader: &Trader, mut on_event: impl FnMut(FillEvent)) {
+        let ids: Vec<String> = {
+            let orders = self.orders.lock().unwrap_or_else(|e| e.into_inner());
+            orders.keys().cloned().collect()
+        };
+
+        for order_id in ids {
+            let status = match trader.order_status(&order_id).await {
+                Ok(status) => status,
+                Err(e) => {
+                    warn!("[FILLS] status check failed | {} | {}", order_id, e);
+                    continue;
+                }
+            };
+
+            let mut orders = self.orders.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(tracked) = orders.get_mut(&order_id) else {
+                continue;
+            };
+            if status.matched_qty_raw > tracked.matched_raw {
+                let fill = Fill {
+                    qty_raw: status.matched_qty_raw - tracked.matched_raw,
+                    price_raw: tracked.limit_price_raw,
+                    fee_raw: 0,
+                };
+                tracked.matched_raw = status.matched_qty_raw;
+                tracked.summary.add(&fill);
+                on_event(FillEvent::Fill(&fill, &tracked.summary));
+            }
+            if !status.is_live() {
+                if let Some(mut done) = orders.remove(&order_id) {
+                    done.summary.status = status.status.to_ascii_uppercase();
+                    on_event(FillEvent::Completed(&done.summary));
+                }
+            }
+        }
+    }
+}
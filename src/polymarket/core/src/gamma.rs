@@ -0,0 +1,302 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Market metadata from the Gamma API
+//!
+//! The CLOB knows tokens; Gamma maps a market to its identifiers - condition
+//! id, question id, slug and outcome tokens - tells whether it belongs to a
+//! neg-risk event, and gives its question, outcome names and end date. It
+//! also lists the markets open for trading. Requests go through the crate's
+//! own HTTP client, so the proxy and TLS options apply.
+//!
+//! Identifiers never change once a market exists, so every market looked up
+//! or listed is cached for the life of the session (and carried over on
+//! rotation); any of its identifiers resolves it without another request.
+//! The end date can move, so it is as of the last request that returned the
+//! market: listing again refreshes it.
+//!
+//! Responses are parsed leniently (see `schema`): a market entry Gamma
+//! changed the shape of is logged as drift and skipped.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::schema::{self, DriftLog};
+use crate::timeouts::CallClass;
+use crate::{OrderError, Trader};
+
+/// A binary market and its two outcome tokens
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarketTokens {
+    /// 0x-prefixed, lowercase
+    pub condition_id: String,
+    /// 0x-prefixed, lowercase; empty if Gamma has none
+    pub question_id: String,
+    pub slug: String,
+    pub yes_token_id: String,
+    pub no_token_id: String,
+    /// Part of a neg-risk event: positions go through the neg-risk adapter
+    pub neg_risk: bool,
+    /// Empty if Gamma has none
+    pub question: String,
+    /// Names of the YES and NO outcomes, in that order ("Yes", "No" or
+    /// e.g. two teams); empty if Gamma has none
+    pub outcomes: Vec<String>,
+    /// When trading is scheduled to end, as Gamma gives it (ISO 8601);
+    /// empty if Gamma has none
+    pub end_date: String,
+}
+
+impl MarketTokens {
+    /// End date in milliseconds since the epoch, None if Gamma gave none or
+    /// one that does not parse; a bare date is midnight UTC
+    pub fn end_date_ms(&self) -> Option<u64> {
+        let at = match DateTime::parse_from_rfc3339(&self.end_date) {
+            Ok(at) => at.with_timezone(&Utc),
+            Err(_) => NaiveDate::parse_from_str(&self.end_date, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)?
+                .and_utc(),
+        };
+        u64::try_from(at.timestamp_millis()).ok()
+    }
+}
+
+/// Identifier a market is looked up by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarketKey<'a> {
+    Condition(&'a str),
+    Question(&'a str),
+    Slug(&'a str),
+    /// Either outcome token
+    Token(&'a str),
+}
+
+impl MarketKey<'_> {
+    /// Gamma query parameter and normalized value
+    fn query(&self) -> Result<(&'static str, String), OrderError> {
+        let invalid = || OrderError::InvalidToken;
+        Ok(match *self {
+            MarketKey::Condition(id) => (
+                "condition_ids",
+                normalize_condition_id(id).ok_or_else(invalid)?,
+            ),
+            MarketKey::Question(id) => (
+                "question_ids",
+                normalize_condition_id(id).ok_or_else(invalid)?,
+            ),
+            MarketKey::Slug(slug) => {
+                let valid = !slug.is_empty()
+                    && slug.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-');
+                if !valid {
+                    return Err(invalid());
+                }
+                ("slug", slug.to_string())
+            }
+            MarketKey::Token(id) => {
+                crate::parse_token(id)?;
+                ("clob_token_ids", id.to_string())
+            }
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GammaMarket {
+    condition_id: String,
+    #[serde(default, rename = "questionID", alias = "questionId")]
+    question_id: Option<String>,
+    #[serde(default)]
+    slug: Option<String>,
+    /// YES first; a JSON-encoded array, or since seen as a plain one
+    #[serde(default, deserialize_with = "schema::lenient_id_list")]
+    clob_token_ids: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "schema::lenient_bool")]
+    neg_risk: Option<bool>,
+    #[serde(default)]
+    question: Option<String>,
+    /// In token order, encoded like the token ids
+    #[serde(default, deserialize_with = "schema::lenient_id_list")]
+    outcomes: Option<Vec<String>>,
+    #[serde(default)]
+    end_date: Option<String>,
+}
+
+impl GammaMarket {
+    fn into_tokens(self) -> Option<MarketTokens> {
+        let condition_id = normalize_condition_id(&self.condition_id)?;
+        let [yes, no] = <[String; 2]>::try_from(self.clob_token_ids?).ok()?;
+        Some(MarketTokens {
+            condition_id,
+            question_id: self
+                .question_id
+                .as_deref()
+                .and_then(normalize_condition_id)
+                .unwrap_or_default(),
+            slug: self.slug.unwrap_or_default(),
+            yes_token_id: yes,
+            no_token_id: no,
+            neg_risk: self.neg_risk.unwrap_or(false),
+            question: self.question.unwrap_or_default(),
+            // Names that do not pair with the tokens would mislabel them
+            outcomes: self.outcomes.filter(|o| o.len() == 2).unwrap_or_default(),
+            end_date: self.end_date.unwrap_or_default(),
+        })
+    }
+}
+
+/// Drift source of Gamma market responses
+const SOURCE: &str = "gamma.markets";
+
+/// Binary markets of a Gamma /markets response
+/// Entries that do not parse, or lack a valid condition id or two outcome
+/// tokens, are recorded in `drift` and skipped
+pub fn parse_markets(body: serde_json::Value, drift: &DriftLog) -> Vec<MarketTokens> {
+    let entries: Vec<GammaMarket> = schema::parse_entries(SOURCE, body, drift);
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            let condition_id = entry.condition_id.clone();
+            let market = entry.into_tokens();
+            if market.is_none() {
+                let error = "no valid condition id or outcome token pair";
+                drift.record(SOURCE, error, &condition_id);
+            }
+            market
+        })
+        .collect()
+}
+
+/// Lowercase 0x-prefixed 32-byte hex, or None
+/// Condition and question ids share this form
+pub fn normalize_condition_id(condition_id: &str) -> Option<String> {
+    let hex = condition_id.strip_prefix("0x")?;
+    if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(format!("0x{}", hex.to_ascii_lowercase()))
+}
+
+/// Markets looked up so far, by every identifier
+#[derive(Default)]
+pub(crate) struct MarketCache {
+    inner: RwLock<HashMap<String, Arc<MarketTokens>>>,
+}
+
+impl MarketCache {
+    fn get(&self, key: &str) -> Option<Arc<MarketTokens>> {
+        self.inner.read().ok()?.get(key).cloned()
+    }
+
+    fn insert(&self, market: Arc<MarketTokens>) {
+        if let Ok(mut cache) = self.inner.write() {
+            let keys = [
+                &market.condition_id,
+                &market.question_id,
+                &market.slug,
+                &market.yes_token_id,
+                &market.no_token_id,
+            ];
+            for key in keys.into_iter().filter(|k| !k.is_empty()) {
+                cache.insert(key.clone(), market.clone());
+            }
+        }
+    }
+
+    #[cfg(feature = "execution")]
+    pub(crate) fn extend_from(&self, other: &MarketCache) {
+        if let (Ok(mut cache), Ok(other)) = (self.inner.write(), other.inner.read()) {
+            cache.extend(other.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+    }
+}
+
+/// Most markets one list request returns
+pub const MAX_LIST_LIMIT: u32 = 500;
+
+impl Trader {
+    /// Outcome tokens of a binary market
+    pub async fn market_tokens(&self, condition_id: &str) -> Result<MarketTokens, OrderError> {
+        let market = self
+            .lookup_market(MarketKey::Condition(condition_id))
+            .await?;
+        Ok((*market).clone())
+    }
+
+    /// A binary market by any of its identifiers, from the cache if known
+    /// InvalidToken if the identifier is malformed or Gamma does not know it
+    pub async fn lookup_market(&self, key: MarketKey<'_>) -> Result<Arc<MarketTokens>, OrderError> {
+        let (param, value) = key.query()?;
+        if let Some(market) = self.markets.get(&value) {
+            return Ok(market);
+        }
+
+        let url = format!(
+            "{}/markets?{}={}",
+            self.options.gamma_host.trim_end_matches('/'),
+            param,
+            value
+        );
+        let body = self
+            .limited(CallClass::MarketData, async {
+                let resp = self.http.get(&url).send().await?.error_for_status()?;
+                resp.json::<serde_json::Value>().await
+            })
+            .await
+            .map_err(OrderError::Api)?;
+
+        let found = parse_markets(body, &self.drift)
+            .into_iter()
+            .find(|market| match key {
+                MarketKey::Slug(_) => market.slug == value,
+                MarketKey::Token(_) => market.yes_token_id == value || market.no_token_id == value,
+                MarketKey::Question(_) => market.question_id == value,
+                MarketKey::Condition(_) => market.condition_id == value,
+            });
+        let market = Arc::new(found.ok_or(OrderError::InvalidToken)?);
+        self.markets.insert(market.clone());
+        Ok(market)
+    }
+
+    /// Binary markets open for trading, `limit` (at most MAX_LIST_LIMIT)
+    /// from `offset` in Gamma's order; page through them until an empty
+    /// list. Every market returned is cached, refreshing its end date
+    pub async fn list_markets(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<Arc<MarketTokens>>, OrderError> {
+        let url = format!(
+            "{}/markets?active=true&closed=false&limit={}&offset={}",
+            self.options.gamma_host.trim_end_matches('/'),
+            limit.min(MAX_LIST_LIMIT),
+            offset
+        );
+        let body = self
+            .limited(CallClass::MarketData, async {
+                let resp = self.http.get(&url).send().await?.error_for_status()?;
+                resp.json::<serde_json::Value>().await
+            })
+            .await
+            .map_err(OrderError::Api)?;
+
+        let markets: Vec<_> = parse_markets(body, &self.drift)
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+        for market in &markets {
+            self.markets.insert(market.clone());
+        }
+        Ok(markets)
+    }
+}
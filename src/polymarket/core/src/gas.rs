@@ -0,0 +1,108 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! POL balance of the signing key
+//!
+//! On-chain calls (splits, merges, conversions, redemptions, approvals) are
+//! sent by the signing key, which pays their gas in POL; with too little
+//! they fail at send. The balance is read after every transaction and on
+//! request. A warning is logged when it falls below the threshold, once
+//! until it is back above, so a host routing logs sees a drained key before
+//! the next transaction fails.
+
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+
+use alloy::primitives::U256;
+use alloy::providers::{Provider, ProviderBuilder};
+use tracing::{info, warn};
+
+use crate::{OrderError, Trader, DECIMAL_SCALE};
+
+/// Default warning threshold: 1 POL (6 decimals)
+pub const DEFAULT_LOW_GAS_RAW: i64 = DECIMAL_SCALE;
+
+/// Wei per raw unit: POL has 18 decimals, raw amounts 6
+const WEI_PER_RAW: u64 = 1_000_000_000_000;
+
+pub(crate) struct GasWatch {
+    /// Warn below this balance (6 decimals); 0 turns warnings off
+    threshold_raw: AtomicI64,
+    /// Below the threshold at the last read, already warned
+    low: AtomicBool,
+}
+
+impl GasWatch {
+    pub(crate) fn new() -> Self {
+        Self {
+            threshold_raw: AtomicI64::new(DEFAULT_LOW_GAS_RAW),
+            low: AtomicBool::new(false),
+        }
+    }
+
+    /// Threshold of the session being replaced; the key may differ, so
+    /// the next read warns afresh
+    pub(crate) fn inherit(&self, from: &GasWatch) {
+        self.threshold_raw.store(
+            from.threshold_raw.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+    }
+}
+
+impl Trader {
+    /// POL balance of the signing key (6 decimals), read on Polygon
+    /// Warns if it is below the low gas threshold
+    pub async fn gas_balance(&self) -> Result<i64, OrderError> {
+        let provider = ProviderBuilder::new().connect_http(self.rpc_url()?);
+        let address = self.signer.address();
+        let wei = provider
+            .get_balance(address)
+            .await
+            .map_err(|e| OrderError::Api(e.into()))?;
+        let raw = i64::try_from(wei / U256::from(WEI_PER_RAW)).unwrap_or(i64::MAX);
+
+        let threshold = self.low_gas_threshold();
+        let low = threshold > 0 && raw < threshold;
+        let was_low = self.gas.low.swap(low, Ordering::Relaxed);
+        if low && !was_low {
+            warn!(
+                "[GAS] signer POL balance low | signer={} | balance={:.6} | threshold={:.6}",
+                address,
+                raw as f64 / DECIMAL_SCALE as f64,
+                threshold as f64 / DECIMAL_SCALE as f64
+            );
+        } else if !low && was_low {
+            info!(
+                "[GAS] signer POL balance restored | signer={} | balance={:.6}",
+                address,
+                raw as f64 / DECIMAL_SCALE as f64
+            );
+        }
+        Ok(raw)
+    }
+
+    /// POL balance below which a warning is logged (6 decimals)
+    pub fn low_gas_threshold(&self) -> i64 {
+        self.gas.threshold_raw.load(Ordering::Relaxed)
+    }
+
+    /// Warn below `threshold_raw` POL (6 decimals) from now on; 0 turns
+    /// warnings off
+    pub fn set_low_gas_threshold(&self, threshold_raw: i64) -> Result<(), OrderError> {
+        if threshold_raw < 0 {
+            return Err(OrderError::InvalidAmount);
+        }
+        self.gas
+            .threshold_raw
+            .store(threshold_raw, Ordering::Relaxed);
+        // Checked against the new threshold at the next read
+        self.gas.low.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Price history of a token
+//!
+//! The CLOB prices-history endpoint returns a token's price sampled every
+//! `fidelity` minutes over a time range - the series its charts draw. The
+//! samples are grouped into OHLC candles aligned to the epoch (daily ones
+//! start at 00:00 UTC), about `SAMPLES_PER_CANDLE` to each; a candle the
+//! series has no sample in is left out rather than made up. One-minute
+//! candles hold one sample each: the raw series.
+
+use std::collections::BTreeMap;
+
+use polymarket_client_sdk::types::Decimal;
+use serde::Deserialize;
+
+use crate::schema::{self, DriftLog};
+use crate::timeouts::CallClass;
+use crate::{parse_token, OrderError, Trader};
+
+/// Samples requested per candle, which sets the fidelity asked for
+pub const SAMPLES_PER_CANDLE: u32 = 10;
+
+/// Shortest candle: the endpoint samples no finer than a minute
+pub const MIN_CANDLE_SECS: u32 = 60;
+
+/// A sampled price
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PricePoint {
+    /// Unix seconds
+    pub ts: i64,
+    pub price: Decimal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Candle {
+    /// Unix seconds, a multiple of the candle length
+    pub start_ts: i64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// Samples it was built from, at least 1
+    pub samples: u32,
+}
+
+#[derive(Deserialize)]
+struct HistoryPoint {
+    t: i64,
+    p: f64,
+}
+
+/// Drift source of prices-history responses
+const SOURCE: &str = "clob.prices_history";
+
+/// Samples of a prices-history response, oldest first
+/// Entries that do not parse or hold no valid price are recorded in `drift`
+/// and skipped, and so is a body without a history array
+pub fn parse_history(body: serde_json::Value, drift: &DriftLog) -> Vec<PricePoint> {
+    let history = match body {
+        serde_json::Value::Object(mut fields) if fields.contains_key("history") => {
+            fields.remove("history").unwrap_or_default()
+        }
+        other => {
+            drift.record(
+                SOURCE,
+                "expected an object with a history",
+                &other.to_string(),
+            );
+            return Vec::new();
+        }
+    };
+    let entries: Vec<HistoryPoint> = schema::parse_entries(SOURCE, history, drift);
+    let mut points: Vec<PricePoint> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let price = Decimal::try_from(entry.p)
+                .ok()
+                .filter(|p| (Decimal::ZERO..=Decimal::ONE).contains(p));
+            if price.is_none() {
+                drift.record(SOURCE, "price not in [0, 1]", &entry.p.to_string());
+            }
+            Some(PricePoint {
+                ts: entry.t,
+                price: price?.round_dp(6),
+            })
+        })
+        .collect();
+    points.sort_by_key(|point| point.ts);
+    points
+}
+
+/// Candles of `interval_secs` (> 0) built from samples oldest first
+pub fn candles(points: &[PricePoint], interval_secs: u32) -> Vec<Candle> {
+    let interval = i64::from(interval_secs.max(1));
+    let mut candles: BTreeMap<i64, Candle> = BTreeMap::new();
+    for point in points {
+        let start_ts = point.ts - point.ts.rem_euclid(interval);
+        candles
+            .entry(start_ts)
+            .and_modify(|candle| {
+                candle.high = candle.high.max(point.price);
+                candle.low = candle.low.min(point.price);
+                candle.close = point.price;
+                candle.samples += 1;
+            })
+            .or_insert(Candle {
+                start_ts,
+                open: point.price,
+                high: point.price,
+                low: point.price,
+                close: point.price,
+                samples: 1,
+            });
+    }
+    candles.into_values().collect()
+}
+
+impl Trader {
+    /// Candles of `interval_secs` (a multiple of MIN_CANDLE_SECS) of a
+    /// token's price between unix seconds `from_ts` and `to_ts`, oldest first
+    /// InvalidAmount for another length or an empty range
+    pub async fn price_history(
+        &self,
+        token_id: &str,
+        interval_secs: u32,
+        from_ts: i64,
+        to_ts: i64,
+    ) -> Result<Vec<Candle>, OrderError> {
+        parse_token(token_id)?;
+        if interval_secs == 0 || !interval_secs.is_multiple_of(MIN_CANDLE_SECS) || from_ts >= to_ts
+        {
+            return Err(OrderError::InvalidAmount);
+        }
+
+        let fidelity_min = (interval_secs / MIN_CANDLE_SECS / SAMPLES_PER_CANDLE).max(1);
+        let url = format!(
+            "{}/prices-history?market={}&startTs={}&endTs={}&fidelity={}",
+            self.options.clob_host.trim_end_matches('/'),
+            token_id,
+            from_ts,
+            to_ts,
+            fidelity_min
+        );
+        let body = self
+            .limited(CallClass::MarketData, async {
+                let resp = self.http.get(&url).send().await?.error_for_status()?;
+                resp.json::<serde_json::Value>().await
+            })
+            .await
+            .map_err(OrderError::Api)?;
+
+        let points = parse_history(body, &self.drift);
+        Ok(candles(&points, interval_secs))
+    }
+}
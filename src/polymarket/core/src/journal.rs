@@ -0,0 +1,173 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Append-only journal of order traffic
+//!
+//! The signed log keeps the exact payloads signed; the journal keeps what
+//! was asked and what came of it. Every order, batch, replace and cancel
+//! request gets a line before it runs and a second line with the venue's
+//! answer or the error once it is done, one JSON object per line:
+//! `{id, ts_ms, kind: "request" | "response" | "error", op, token_id, body}`.
+//! Both lines of a request share its `id`. An id with a request line and
+//! nothing after it was in flight when the process stopped; the signed log
+//! tells whether the order was sent.
+//!
+//! Write-ahead: a request whose line cannot be written does not run. Lines
+//! reach the OS before the call proceeds, so they survive a process crash,
+//! and are fsynced by `sync` (on shutdown).
+
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::{OrderError, Trader};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryKind {
+    Request,
+    Response,
+    Error,
+}
+
+/// One line as written
+#[derive(Serialize)]
+struct Entry<'a> {
+    id: u64,
+    ts_ms: u64,
+    kind: EntryKind,
+    op: &'a str,
+    token_id: &'a str,
+    body: serde_json::Value,
+}
+
+struct Inner {
+    file: File,
+    /// Id of the next request
+    next_id: u64,
+}
+
+pub struct Journal {
+    path: PathBuf,
+    inner: Mutex<Inner>,
+}
+
+impl Journal {
+    /// Open (or create) the journal; ids continue after the existing lines
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let next_id = match File::open(path) {
+            Ok(file) => BufReader::new(file).split(b'\n').count() as u64,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e),
+        };
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        info!("[JOURNAL] opened {} | next id={}", path.display(), next_id);
+        Ok(Self {
+            path: path.to_path_buf(),
+            inner: Mutex::new(Inner { file, next_id }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a line; a request (`id` None) gets a new id, returned
+    pub fn append(
+        &self,
+        id: Option<u64>,
+        kind: EntryKind,
+        op: &str,
+        token_id: &str,
+        body: serde_json::Value,
+    ) -> std::io::Result<u64> {
+        let mut inner = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let id = id.unwrap_or_else(|| {
+            inner.next_id += 1;
+            inner.next_id - 1
+        });
+        let entry = Entry {
+            id,
+            ts_ms: chrono::Utc::now().timestamp_millis() as u64,
+            kind,
+            op,
+            token_id,
+            body,
+        };
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+        // One write on an O_APPEND file: lines never interleave
+        inner.file.write_all(&line)?;
+        Ok(id)
+    }
+
+    /// Flush every line written so far to disk
+    pub fn sync(&self) -> std::io::Result<()> {
+        let inner = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        inner.file.sync_data()
+    }
+}
+
+impl Trader {
+    /// Journal every order and cancel request to `journal` (None: stop)
+    pub fn set_journal(&self, journal: Option<Arc<Journal>>) {
+        if let Ok(mut slot) = self.journal.write() {
+            *slot = journal;
+        }
+    }
+
+    pub fn journal(&self) -> Option<Arc<Journal>> {
+        self.journal.read().ok().and_then(|slot| slot.clone())
+    }
+
+    /// Run `call` between its request line and its outcome line, if a
+    /// journal is set. The call does not run if the request line fails
+    pub(crate) async fn journaled<T>(
+        &self,
+        op: &str,
+        token_id: &str,
+        request: serde_json::Value,
+        call: impl Future<Output = Result<T, OrderError>>,
+        response: impl FnOnce(&T) -> serde_json::Value,
+    ) -> Result<T, OrderError> {
+        let Some(journal) = self.journal() else {
+            return call.await;
+        };
+        let id = journal
+            .append(None, EntryKind::Request, op, token_id, request)
+            .map_err(|e| OrderError::Api(anyhow::anyhow!("journal: {}", e)))?;
+
+        let result = call.await;
+        let (kind, body) = match &result {
+            Ok(value) => (EntryKind::Response, response(value)),
+            Err(e) => (
+                EntryKind::Error,
+                serde_json::json!({ "error": e.to_string() }),
+            ),
+        };
+        if let Err(e) = journal.append(Some(id), kind, op, token_id, body) {
+            warn!(
+                "[JOURNAL] outcome not written | id={} op={} | {}",
+                id, op, e
+            );
+        }
+        result
+    }
+}
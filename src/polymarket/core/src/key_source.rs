@@ -0,0 +1,132 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Signing key sources other than a string handed over by the host
+//!
+//! A key file must be a regular file owned by the current user and not
+//! accessible to group or others (like ssh keys). The OS keyring is read
+//! through the platform tool: `security` on macOS, `secret-tool` (libsecret)
+//! on Linux.
+//!
+//! Keys and every buffer they pass through are `Zeroizing`, so they are
+//! scrubbed when dropped.
+
+use std::io::Read;
+use std::path::Path;
+use std::process::Command;
+
+use zeroize::Zeroizing;
+
+/// Why a key could not be loaded
+#[derive(Debug, thiserror::Error)]
+pub enum KeySourceError {
+    #[error("cannot read key file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("insecure key file: {0}")]
+    Insecure(String),
+    #[error("keyring: {0}")]
+    Keyring(String),
+    #[error("key is empty")]
+    Empty,
+}
+
+/// Read a hex private key from a permission-checked file
+/// Surrounding whitespace (e.g. a trailing newline) is ignored
+pub fn read_key_file(path: &Path) -> Result<Zeroizing<String>, KeySourceError> {
+    let mut file = std::fs::File::open(path)?;
+    let meta = file.metadata()?;
+    check_permissions(&meta)?;
+
+    // Sized upfront so the buffer never reallocates and leaves copies behind
+    let mut key = Zeroizing::new(String::with_capacity(meta.len() as usize + 1));
+    file.read_to_string(&mut key)?;
+    trimmed(&key)
+}
+
+#[cfg(unix)]
+fn check_permissions(meta: &std::fs::Metadata) -> Result<(), KeySourceError> {
+    use std::os::unix::fs::MetadataExt;
+
+    if !meta.is_file() {
+        return Err(KeySourceError::Insecure("not a regular file".to_string()));
+    }
+    // SAFETY: geteuid has no preconditions and cannot fail
+    let euid = unsafe { libc::geteuid() };
+    if meta.uid() != euid {
+        return Err(KeySourceError::Insecure(format!(
+            "owned by uid {}, expected {}",
+            meta.uid(),
+            euid
+        )));
+    }
+    if meta.mode() & 0o077 != 0 {
+        return Err(KeySourceError::Insecure(format!(
+            "mode {:o} is accessible to group/others, expected 600 or 400",
+            meta.mode() & 0o777
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(meta: &std::fs::Metadata) -> Result<(), KeySourceError> {
+    // ACLs are not inspected here; rely on the profile directory's defaults
+    if !meta.is_file() {
+        return Err(KeySourceError::Insecure("not a regular file".to_string()));
+    }
+    Ok(())
+}
+
+/// Read a hex private key stored in the OS keyring under `service`/`account`
+pub fn read_keyring(service: &str, account: &str) -> Result<Zeroizing<String>, KeySourceError> {
+    let mut command = keyring_command(service, account)?;
+    let output = command
+        .output()
+        .map_err(|e| KeySourceError::Keyring(format!("cannot run keyring tool: {}", e)))?;
+    let stdout = Zeroizing::new(output.stdout);
+    if !output.status.success() {
+        return Err(KeySourceError::Keyring(format!(
+            "no entry for service={} account={} ({})",
+            service, account, output.status
+        )));
+    }
+
+    let key = std::str::from_utf8(&stdout)
+        .map_err(|_| KeySourceError::Keyring("entry is not valid UTF-8".to_string()))?;
+    trimmed(key)
+}
+
+#[cfg(target_os = "macos")]
+fn keyring_command(service: &str, account: &str) -> Result<Command, KeySourceError> {
+    let mut command = Command::new("security");
+    command.args(["find-generic-password", "-s", service, "-a", account, "-w"]);
+    Ok(command)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn keyring_command(service: &str, account: &str) -> Result<Command, KeySourceError> {
+    let mut command = Command::new("secret-tool");
+    command.args(["lookup", "service", service, "account", account]);
+    Ok(command)
+}
+
+#[cfg(not(unix))]
+fn keyring_command(_service: &str, _account: &str) -> Result<Command, KeySourceError> {
+    Err(KeySourceError::Keyring(
+        "not supported on this platform".to_string(),
+    ))
+}
+
+fn trimmed(key: &str) -> Result<Zeroizing<String>, KeySourceError> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(KeySourceError::Empty);
+    }
+    Ok(Zeroizing::new(key.to_string()))
+}
@@ -0,0 +1,963 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Polymarket Order Executor - async core
+//!
+//! Order placement, pre-trade checks and account queries as plain async
+//! Rust: no globals, no runtime of its own, no `block_on`. The caller owns
+//! the tokio runtime and the `Trader` (wrap it in an `Arc` to share it).
+//!
+//! The C ABI in `polymarket_executor` (src/polymarket/ffi) is a thin layer
+//! over this crate; native Rust consumers can depend on it directly.
+//! `connector::PolymarketConnector` puts a keyed session behind the shared
+//! connector traits.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::str::FromStr;
+#[cfg(feature = "execution")]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+#[cfg(feature = "execution")]
+use alloy::primitives::Address;
+#[cfg(feature = "execution")]
+use alloy::signers::{local::LocalSigner, Signer};
+use futures::future::join_all;
+#[cfg(not(feature = "execution"))]
+use polymarket_client_sdk::auth::state::Unauthenticated;
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::auth::{state::Authenticated, Normal};
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::request::BalanceAllowanceRequest;
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::request::CancelMarketOrderRequest;
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::{AssetType, SignatureType};
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::types::{Decimal, U256};
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::POLYGON;
+use tokio::sync::Semaphore;
+use tracing::warn;
+
+#[cfg(feature = "execution")]
+pub mod algo;
+#[cfg(feature = "onchain")]
+pub mod allowances;
+pub mod arbitrage;
+#[cfg(feature = "execution")]
+pub mod balance;
+#[cfg(feature = "execution")]
+pub mod basket;
+#[cfg(feature = "execution")]
+pub mod batch;
+pub mod book;
+#[cfg(feature = "execution")]
+pub mod breaker;
+#[cfg(feature = "execution")]
+pub mod cancels;
+#[cfg(feature = "onchain")]
+pub mod chain;
+#[cfg(feature = "execution")]
+pub mod client_orders;
+#[cfg(feature = "execution")]
+pub mod connector;
+#[cfg(feature = "execution")]
+pub mod endpoints;
+#[cfg(feature = "execution")]
+pub mod failover;
+#[cfg(feature = "execution")]
+pub mod fills;
+pub mod gamma;
+pub mod history;
+#[cfg(feature = "onchain")]
+pub mod gas;
+#[cfg(feature = "execution")]
+pub mod journal;
+pub mod key_source;
+pub mod market_ws;
+#[cfg(feature = "onchain")]
+pub mod merge;
+#[cfg(feature = "execution")]
+pub mod metrics;
+#[cfg(feature = "execution")]
+pub mod notifications;
+#[cfg(feature = "execution")]
+pub mod open_orders;
+pub mod options;
+mod orders;
+#[cfg(feature = "execution")]
+pub mod paper;
+#[cfg(feature = "execution")]
+pub mod pnl;
+#[cfg(feature = "execution")]
+pub mod positions;
+pub mod pricing;
+#[cfg(feature = "execution")]
+pub mod quoting;
+pub mod rate_limit;
+#[cfg(feature = "execution")]
+pub mod replace;
+pub mod resolution;
+#[cfg(feature = "execution")]
+pub mod retry;
+#[cfg(feature = "execution")]
+pub mod rewards;
+#[cfg(feature = "execution")]
+pub mod risk;
+pub mod schema;
+#[cfg(feature = "execution")]
+pub mod signed_log;
+pub mod timeouts;
+#[cfg(feature = "execution")]
+pub mod trades;
+#[cfg(feature = "execution")]
+pub mod user_ws;
+#[cfg(feature = "execution")]
+pub mod venue_error;
+pub mod warmup;
+
+#[cfg(feature = "execution")]
+use balance::BalanceGuard;
+use book::BookSnapshot;
+#[cfg(feature = "execution")]
+use breaker::CircuitBreaker;
+#[cfg(feature = "execution")]
+use cancels::CancelOutcome;
+#[cfg(feature = "execution")]
+use client_orders::ClientOrders;
+#[cfg(feature = "execution")]
+use endpoints::EndpointSet;
+use gamma::MarketCache;
+#[cfg(feature = "onchain")]
+use gas::GasWatch;
+#[cfg(feature = "execution")]
+use journal::Journal;
+#[cfg(feature = "execution")]
+use metrics::{Metrics, Op};
+use options::ConnectOptions;
+#[cfg(feature = "execution")]
+use paper::Paper;
+use rate_limit::RateLimiter;
+#[cfg(feature = "execution")]
+use retry::Retries;
+#[cfg(feature = "execution")]
+use risk::RiskManager;
+use schema::DriftLog;
+#[cfg(feature = "execution")]
+use signed_log::{SignedLog, SignedRecord};
+use timeouts::{CallClass, Timeouts};
+
+#[cfg(feature = "execution")]
+pub use connector::PolymarketConnector;
+pub use orders::{OrderError, MARKET_BUY_PRICE, MARKET_SELL_PRICE};
+#[cfg(feature = "execution")]
+pub use orders::{OrderFill, OrderRequest, OrderStatus};
+pub use polymarket_client_sdk::clob::types::Side;
+
+/// Default timeout of every call class (10 seconds)
+pub const API_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Most tokens `Trader::prefetch_many` fetches at once
+pub const PREFETCH_CONCURRENCY: usize = 16;
+
+/// Decimal scale: 6 decimals (1_000_000 = 1.0)
+/// USDC uses 6 decimals, Polymarket shares use 6 decimals
+pub const DECIMAL_SCALE: i64 = 1_000_000;
+
+/// Authenticated with the `execution` feature, keyless without it
+#[cfg(feature = "execution")]
+type ClobClient = Client<Authenticated<Normal>>;
+#[cfg(not(feature = "execution"))]
+type ClobClient = Client<Unauthenticated>;
+#[cfg(feature = "execution")]
+type SignerType = LocalSigner<k256::ecdsa::SigningKey>;
+
+/// Why connect() failed
+#[derive(Debug, thiserror::Error)]
+pub enum ConnectError {
+    #[error("invalid private key")]
+    InvalidKey,
+    #[error("invalid funder address")]
+    InvalidFunder,
+    #[error("{0}")]
+    InvalidOptions(String),
+    #[error("authentication failed: {0}")]
+    Auth(anyhow::Error),
+}
+
+/// What replacing a session's credentials changes
+#[cfg(feature = "execution")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CredentialChange {
+    /// Same key and funder
+    None,
+    /// New key, same funder wallet
+    Signer,
+    /// Another funder wallet: orders, positions and balance are not shared
+    Wallet,
+}
+
+/// Metadata of a prefetched token
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarketMeta {
+    /// Current tick size, also after a tick_size_change
+    pub tick_size: Decimal,
+    /// Smallest order the venue accepts, in shares
+    pub min_order_size: Decimal,
+    pub fee_rate_bps: u32,
+    /// The market belongs to a neg-risk event
+    pub neg_risk: bool,
+}
+
+/// Authenticated trading session for one wallet
+/// Without the `execution` feature: a keyless market-data session
+pub struct Trader {
+    /// Client of `clob_host`
+    client: ClobClient,
+    /// Gateways order traffic is routed across, `clob_host` included
+    #[cfg(feature = "execution")]
+    endpoints: EndpointSet,
+    #[cfg(feature = "execution")]
+    signer: SignerType,
+    /// Proxy wallet the orders trade for
+    #[cfg(feature = "execution")]
+    funder: Address,
+    /// Endpoints, proxy and TLS settings the session was opened with
+    options: ConnectOptions,
+    /// Client for the crate's own REST calls (proxy, extra CAs, mTLS applied)
+    http: reqwest::Client,
+    /// Cached min_order_size per token (in shares)
+    min_order_sizes: RwLock<HashMap<String, Decimal>>,
+    /// Current tick size per token, from prefetch and tick_size_change
+    tick_sizes: RwLock<HashMap<String, Decimal>>,
+    /// Fee rate (bps) and neg-risk flag per token, from prefetch
+    fee_terms: RwLock<HashMap<String, (u32, bool)>>,
+    /// Last order book snapshot per token
+    books: RwLock<HashMap<String, Arc<BookSnapshot>>>,
+    /// Market identifiers resolved through Gamma
+    markets: MarketCache,
+    /// Responses that no longer match the expected shape
+    drift: DriftLog,
+    /// Timeout per call class
+    timeouts: Timeouts,
+    /// Request budget per call class
+    rate_limiter: RateLimiter,
+    /// Backoff of order calls that failed in transit
+    #[cfg(feature = "execution")]
+    retries: Retries,
+    /// Orders placed under client order ids
+    #[cfg(feature = "execution")]
+    client_orders: ClientOrders,
+    /// Low POL balance warning of the signing key
+    #[cfg(feature = "onchain")]
+    gas: GasWatch,
+    /// Cached USDC balance and in-flight buys for the local funds check
+    #[cfg(feature = "execution")]
+    balance: BalanceGuard,
+    /// Hot-reloadable pre-trade risk limits
+    #[cfg(feature = "execution")]
+    risk: RiskManager,
+    /// Set once, never cleared: every order, cancel and on-chain call fails
+    #[cfg(feature = "execution")]
+    read_only: AtomicBool,
+    /// Kill switch: new orders fail while set, cancels still go out
+    #[cfg(feature = "execution")]
+    halted: AtomicBool,
+    /// Dry-run switch and resting paper orders
+    #[cfg(feature = "execution")]
+    paper: Paper,
+    /// Where signed orders are recorded before they are posted
+    #[cfg(feature = "execution")]
+    signed_log: RwLock<Option<Arc<SignedLog>>>,
+    /// Where order and cancel requests and their outcomes are journaled
+    #[cfg(feature = "execution")]
+    journal: RwLock<Option<Arc<Journal>>>,
+    /// Pauses orders while the venue looks down
+    #[cfg(feature = "execution")]
+    breaker: CircuitBreaker,
+    /// Latency and error counts of order, cancel and balance calls
+    #[cfg(feature = "execution")]
+    metrics: Metrics,
+}
+
+/// Convert Decimal to raw i64 (6 decimals)
+/// Polymarket uses 6 decimal places for USDC and shares
+pub fn decimal_to_raw(d: Decimal) -> i64 {
+    // Decimal internally stores mantissa and scale
+    // We need to normalize to 6 decimal places
+    // Example: 1.5 (mantissa=15, scale=1) -> 1_500_000
+    let mantissa = d.mantissa();
+    let scale = d.scale();
+
+    // Target scale is 6 decimals
+    const TARGET_SCALE: u32 = 6;
+
+    if scale == TARGET_SCALE {
+        mantissa as i64
+    } else if scale < TARGET_SCALE {
+        // Need to multiply (e.g., scale=2 -> multiply by 10^4)
+        let factor = 10i128.pow(TARGET_SCALE - scale);
+        (mantissa * factor) as i64
+    } else {
+        // Need to divide (e.g., scale=8 -> divide by 10^2)
+        let factor = 10i128.pow(scale - TARGET_SCALE);
+        (mantissa / factor) as i64
+    }
+}
+
+/// Parse a Polymarket token id (numeric string)
+pub fn parse_token(token_id: &str) -> Result<U256, OrderError> {
+    U256::from_str(token_id).map_err(|_| OrderError::InvalidToken)
+}
+
+/// Bound an API call by `limit`, the timeout of its call class
+async fn with_timeout<T, E>(
+    limit: Duration,
+    fut: impl Future<Output = Result<T, E>>,
+) -> anyhow::Result<T>
+where
+    E: Into<anyhow::Error>,
+{
+    tokio::time::timeout(limit, fut)
+        .await
+        .map_err(|_| anyhow::anyhow!("timeout"))?
+        .map_err(Into::into)
+}
+
+/// Authenticated client of one CLOB gateway
+#[cfg(feature = "execution")]
+async fn authenticate(
+    host: &str,
+    signer: &SignerType,
+    funder: Address,
+) -> Result<ClobClient, ConnectError> {
+    // IMPORTANT: use_server_time(false) to avoid extra HTTP request per order
+    // Server time sync adds ~80-100ms latency per request
+    let config = Config::builder().use_server_time(false).build();
+    Client::new(host, config)
+        .map_err(|e| ConnectError::Auth(e.into()))?
+        .authentication_builder(signer)
+        .funder(funder)
+        .signature_type(SignatureType::Proxy)
+        .authenticate()
+        .await
+        .map_err(|e| ConnectError::Auth(e.into()))
+}
+
+impl Trader {
+    /// Open a session without a key, against the given endpoints
+    /// Market data only: books, fill estimates and market lookups
+    #[cfg(not(feature = "execution"))]
+    pub async fn connect_keyless(options: ConnectOptions) -> Result<Self, ConnectError> {
+        options.validate().map_err(ConnectError::InvalidOptions)?;
+        let http = options
+            .http_client()
+            .map_err(ConnectError::InvalidOptions)?;
+        let config = Config::builder().use_server_time(false).build();
+        let client =
+            Client::new(&options.clob_host, config).map_err(|e| ConnectError::Auth(e.into()))?;
+
+        Ok(Self {
+            client,
+            options,
+            http,
+            min_order_sizes: RwLock::new(HashMap::new()),
+            tick_sizes: RwLock::new(HashMap::new()),
+            fee_terms: RwLock::new(HashMap::new()),
+            books: RwLock::new(HashMap::new()),
+            markets: MarketCache::default(),
+            drift: DriftLog::new(),
+            timeouts: Timeouts::new(),
+            rate_limiter: RateLimiter::new(),
+        })
+    }
+
+    /// Endpoints, proxy and TLS settings of this session
+    pub fn options(&self) -> &ConnectOptions {
+        &self.options
+    }
+
+    /// HTTP client honoring this session's proxy and TLS settings
+    pub fn http(&self) -> &reqwest::Client {
+        &self.http
+    }
+
+    /// Fetch and cache token metadata (tick size, fee rate, neg risk,
+    /// min order size) so order calls need no extra round trip
+    pub async fn prefetch(&self, token_id: &str) -> Result<(), OrderError> {
+        let token = parse_token(token_id)?;
+
+        let (tick, terms, book) = self
+            .limited(CallClass::MarketData, async {
+                // These calls populate the internal cache
+                let tick = self.client.tick_size(token).await?;
+                let fee_rate_bps = self.client.fee_rate_bps(token).await?;
+                let neg_risk = self.client.neg_risk(token).await?;
+
+                // Get order book to fetch min_order_size
+                let book_req = OrderBookSummaryRequest::builder().token_id(token).build();
+                let book = self.client.order_book(&book_req).await?;
+
+                Ok::<_, anyhow::Error>((Decimal::from(tick), (fee_rate_bps, neg_risk), book))
+            })
+            .await
+            .map_err(OrderError::Api)?;
+        #[cfg(feature = "execution")]
+        self.prefetch_gateways(token_id, token).await;
+
+        if let Ok(mut cache) = self.min_order_sizes.write() {
+            cache.insert(token_id.to_string(), book.min_order_size);
+        }
+        if let Ok(mut cache) = self.tick_sizes.write() {
+            cache.insert(token_id.to_string(), tick);
+        }
+        if let Ok(mut cache) = self.fee_terms.write() {
+            cache.insert(token_id.to_string(), terms);
+        }
+        self.cache_book(token_id, Arc::new(book::snapshot_of(&book)));
+        Ok(())
+    }
+
+    /// `prefetch` every token, at most PREFETCH_CONCURRENCY at a time
+    /// One result per token, in the order given
+    pub async fn prefetch_many(&self, token_ids: &[String]) -> Vec<Result<(), OrderError>> {
+        let permits = Semaphore::new(PREFETCH_CONCURRENCY);
+        join_all(token_ids.iter().map(|token_id| async {
+            let _permit = permits.acquire().await;
+            self.prefetch(token_id).await
+        }))
+        .await
+    }
+
+    /// Cached tick size of a prefetched token
+    pub fn tick_size(&self, token_id: &str) -> Option<Decimal> {
+        self.tick_sizes.read().ok()?.get(token_id).copied()
+    }
+
+    /// Tokens whose metadata is cached
+    pub fn prefetched_tokens(&self) -> Vec<String> {
+        self.min_order_sizes
+            .read()
+            .map(|cache| cache.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Re-fetch the metadata of every prefetched token, the SDK's own tick
+    /// size, fee rate and neg-risk caches included. A token whose refresh
+    /// fails keeps its cached values. Returns the tokens whose tick size
+    /// changed, with the previous tick size if it was known
+    pub async fn refresh_meta(&self) -> Vec<(String, Option<Decimal>, Decimal)> {
+        self.client.invalidate_internal_caches();
+        #[cfg(feature = "execution")]
+        self.invalidate_gateway_caches();
+        let tokens = self.prefetched_tokens();
+        let before: Vec<_> = tokens.iter().map(|token| self.tick_size(token)).collect();
+        let results = self.prefetch_many(&tokens).await;
+
+        let mut changed = Vec::new();
+        for ((token_id, before), result) in tokens.into_iter().zip(before).zip(results) {
+            if let Err(e) = result {
+                warn!("[META] refresh failed | token={} | {}", token_id, e);
+                continue;
+            }
+            match self.tick_size(&token_id) {
+                Some(tick) if before != Some(tick) => changed.push((token_id, before, tick)),
+                _ => {}
+            }
+        }
+        changed
+    }
+
+    /// Cached metadata of a prefetched token, None if not prefetched
+    pub fn market_meta(&self, token_id: &str) -> Option<MarketMeta> {
+        let (fee_rate_bps, neg_risk) = *self.fee_terms.read().ok()?.get(token_id)?;
+        Some(MarketMeta {
+            tick_size: self.tick_size(token_id)?,
+            min_order_size: *self.min_order_sizes.read().ok()?.get(token_id)?,
+            fee_rate_bps,
+            neg_risk,
+        })
+    }
+
+    /// Apply a tick size change announced by the venue
+    /// Returns the previous tick size, if it was known
+    pub fn set_tick_size(&self, token_id: &str, tick: Decimal) -> Option<Decimal> {
+        let mut cache = self.tick_sizes.write().ok()?;
+        cache.insert(token_id.to_string(), tick)
+    }
+
+    /// `price` on the tick grid of a token: rounded down for a buy and up for
+    /// a sell, so the price never gets worse, and kept within one tick of
+    /// 0 and 1. None if the tick size is unknown (not prefetched) or the
+    /// price is not between 0 and 1
+    pub fn round_price(&self, token_id: &str, price: f64, side: Side) -> Option<f64> {
+        let tick = self.tick_size(token_id).filter(|tick| !tick.is_zero())?;
+        let price = Decimal::try_from(price).ok()?;
+        if price <= Decimal::ZERO || price >= Decimal::ONE {
+            return None;
+        }
+        let ticks = price / tick;
+        // Float noise just off a tick is not a tick away
+        let nearest = ticks.round();
+        let ticks = if ((ticks - nearest) * tick).abs() <= orders::TICK_TOLERANCE {
+            nearest
+        } else if side == Side::Buy {
+            ticks.floor()
+        } else {
+            ticks.ceil()
+        };
+        let rounded = (ticks * tick).max(tick).min(Decimal::ONE - tick);
+        rounded.try_into().ok()
+    }
+}
+
+/// Keyed session: authentication, orders and account state
+#[cfg(feature = "execution")]
+impl Trader {
+    /// Authenticate `private_key` (hex) trading for the `funder` proxy wallet
+    /// The key string is not retained: hold it in a `Zeroizing` buffer. The
+    /// signer's copy (k256 `SigningKey`) is scrubbed when the Trader drops.
+    pub async fn connect(private_key: &str, funder: &str) -> Result<Self, ConnectError> {
+        Self::connect_with(private_key, funder, ConnectOptions::default()).await
+    }
+
+    /// Like `connect`, against the given endpoints
+    pub async fn connect_with(
+        private_key: &str,
+        funder: &str,
+        options: ConnectOptions,
+    ) -> Result<Self, ConnectError> {
+        options.validate().map_err(ConnectError::InvalidOptions)?;
+        let http = options
+            .http_client()
+            .map_err(ConnectError::InvalidOptions)?;
+
+        let signer = LocalSigner::from_str(private_key)
+            .map_err(|_| ConnectError::InvalidKey)?
+            .with_chain_id(Some(POLYGON));
+
+        // Parse funder address from parameter
+        let funder: Address = funder.parse().map_err(|_| ConnectError::InvalidFunder)?;
+
+        let client = authenticate(&options.clob_host, &signer, funder).await?;
+
+        // A gateway that can't authenticate now is left out, not fatal
+        let gateways = join_all(
+            options
+                .clob_gateways
+                .iter()
+                .map(|host| authenticate(host, &signer, funder)),
+        )
+        .await;
+        let mut alternates = Vec::with_capacity(gateways.len());
+        for (host, gateway) in options.clob_gateways.iter().zip(gateways) {
+            match gateway {
+                Ok(gateway) => alternates.push((host.clone(), gateway)),
+                Err(e) => warn!("[CONNECT] gateway left out | {} | {}", host, e),
+            }
+        }
+        let endpoints = EndpointSet::new(&options.clob_host, alternates);
+
+        Ok(Self {
+            client,
+            endpoints,
+            signer,
+            funder,
+            options,
+            http,
+            min_order_sizes: RwLock::new(HashMap::new()),
+            tick_sizes: RwLock::new(HashMap::new()),
+            fee_terms: RwLock::new(HashMap::new()),
+            books: RwLock::new(HashMap::new()),
+            markets: MarketCache::default(),
+            drift: DriftLog::new(),
+            timeouts: Timeouts::new(),
+            rate_limiter: RateLimiter::new(),
+            retries: Retries::new(),
+            client_orders: ClientOrders::new(),
+            #[cfg(feature = "onchain")]
+            gas: GasWatch::new(),
+            balance: BalanceGuard::new(),
+            risk: RiskManager::new(),
+            read_only: AtomicBool::new(false),
+            halted: AtomicBool::new(false),
+            paper: Paper::new(),
+            signed_log: RwLock::new(None),
+            journal: RwLock::new(None),
+            breaker: CircuitBreaker::new(),
+            metrics: Metrics::new(),
+        })
+    }
+
+    /// Authenticate a replacement session for new credentials
+    ///
+    /// This session keeps working meanwhile. The replacement re-fetches the
+    /// market metadata cached here, so its first orders pay no extra round
+    /// trip; call `take_over` on it at the moment it replaces this one.
+    pub async fn rotate(&self, private_key: &str, funder: &str) -> Result<Self, ConnectError> {
+        let next = Self::connect_with(private_key, funder, self.options.clone()).await?;
+
+        for token_id in self.prefetched_tokens() {
+            if let Err(e) = next.prefetch(&token_id).await {
+                warn!("[ROTATE] prefetch failed | token={} | {}", token_id, e);
+            }
+        }
+        Ok(next)
+    }
+
+    /// What replacing this session's credentials with these would change
+    pub fn credential_change(
+        &self,
+        private_key: &str,
+        funder: &str,
+    ) -> Result<CredentialChange, ConnectError> {
+        let signer = LocalSigner::from_str(private_key).map_err(|_| ConnectError::InvalidKey)?;
+        let funder: Address = funder.parse().map_err(|_| ConnectError::InvalidFunder)?;
+        Ok(if funder != self.funder {
+            CredentialChange::Wallet
+        } else if signer.address() != self.signer.address() {
+            CredentialChange::Signer
+        } else {
+            CredentialChange::None
+        })
+    }
+
+    /// Proxy wallet the session trades for
+    pub fn funder(&self) -> Address {
+        self.funder
+    }
+
+    /// Whether both sessions trade for the same funder wallet
+    pub fn same_wallet(&self, other: &Trader) -> bool {
+        self.funder == other.funder
+    }
+
+    /// Carry the local state of the session being replaced over to this one
+    /// Risk limits, timeouts, retries, the balance buffer, call metrics,
+    /// client order ids, the low gas threshold, paper orders and market data
+    /// (tick sizes, books, market ids) always move; tracked positions only for the same wallet;
+    /// the cached balance never does
+    pub fn take_over(&self, from: &Trader) {
+        self.risk.inherit(&from.risk, self.funder == from.funder);
+        self.balance.set_buffer(from.balance.buffer());
+        // Including changes announced while this session was connecting
+        if let (Ok(mut ticks), Ok(from_ticks)) = (self.tick_sizes.write(), from.tick_sizes.read()) {
+            ticks.extend(
+                from_ticks
+                    .iter()
+                    .map(|(token, tick)| (token.clone(), *tick)),
+            );
+        }
+        // Snapshots fetched while connecting are newer
+        if let (Ok(mut books), Ok(from_books)) = (self.books.write(), from.books.read()) {
+            for (token, book) in from_books.iter() {
+                books.entry(token.clone()).or_insert_with(|| book.clone());
+            }
+        }
+        self.markets.extend_from(&from.markets);
+        self.drift.extend_from(&from.drift);
+        self.timeouts.inherit(&from.timeouts);
+        self.rate_limiter.inherit(&from.rate_limiter);
+        self.retries.inherit(&from.retries);
+        self.client_orders.inherit(&from.client_orders);
+        #[cfg(feature = "onchain")]
+        self.gas.inherit(&from.gas);
+        self.endpoints.inherit(&from.endpoints);
+        self.breaker.inherit(&from.breaker);
+        self.metrics.inherit(&from.metrics);
+        self.set_signed_log(from.signed_log());
+        self.set_journal(from.journal());
+        self.paper.inherit(&from.paper);
+        if from.is_read_only() {
+            self.set_read_only();
+        }
+        if from.is_halted() {
+            self.halt();
+        }
+    }
+
+    /// Block every order, cancel and on-chain call for good
+    /// Queries keep working; there is deliberately no way back
+    pub fn set_read_only(&self) {
+        self.read_only.store(true, Ordering::Release);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::Acquire)
+    }
+
+    /// Gate for every call that can change venue or chain state
+    fn ensure_writable(&self) -> Result<(), OrderError> {
+        if self.is_read_only() {
+            return Err(OrderError::ReadOnly);
+        }
+        Ok(())
+    }
+
+    /// Refuse every new order until `resume`; cancels and on-chain calls
+    /// still go out
+    pub fn halt(&self) {
+        self.halted.store(true, Ordering::Release);
+    }
+
+    pub fn resume(&self) {
+        self.halted.store(false, Ordering::Release);
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted.load(Ordering::Acquire)
+    }
+
+    /// Gate for every call that places orders
+    fn ensure_trading(&self) -> Result<(), OrderError> {
+        self.ensure_writable()?;
+        if self.is_halted() {
+            return Err(OrderError::Halted);
+        }
+        Ok(())
+    }
+
+    /// Record every signed order to `log` before posting it (None: stop)
+    pub fn set_signed_log(&self, log: Option<Arc<SignedLog>>) {
+        if let Ok(mut slot) = self.signed_log.write() {
+            *slot = log;
+        }
+    }
+
+    pub fn signed_log(&self) -> Option<Arc<SignedLog>> {
+        self.signed_log.read().ok().and_then(|slot| slot.clone())
+    }
+
+    /// Log a signed order about to be posted, if a signed log is set
+    /// An error means the order must not be sent
+    fn record_signed<T: serde::Serialize>(
+        &self,
+        op: &str,
+        token_id: &str,
+        signed: &T,
+    ) -> anyhow::Result<()> {
+        let Some(log) = self.signed_log() else {
+            return Ok(());
+        };
+        let payload = serde_json::to_vec(signed)?;
+        log.append(&SignedRecord {
+            op,
+            token_id,
+            signer: self.signer.address().to_string(),
+            funder: self.funder.to_string(),
+            payload: &payload,
+        })?;
+        Ok(())
+    }
+
+    /// Local USDC funds check
+    pub fn balance(&self) -> &BalanceGuard {
+        &self.balance
+    }
+
+    /// Pre-trade risk limits and tracked positions
+    pub fn risk(&self) -> &RiskManager {
+        &self.risk
+    }
+
+    /// Cancel an order by id
+    pub async fn cancel(&self, order_id: &str) -> Result<(), OrderError> {
+        let call = async {
+            self.ensure_writable()?;
+            if self.is_dry_run() {
+                self.paper_cancel(&[order_id]);
+                self.risk.on_closed([order_id]);
+                return Ok(());
+            }
+            let (endpoint, client) = self.order_client();
+            let result = self
+                .timed(
+                    Op::Cancel,
+                    self.retried("CANCEL", || {
+                        self.limited(CallClass::Order, client.cancel_order(order_id))
+                    }),
+                )
+                .await;
+            self.routed(endpoint, result).map_err(OrderError::Api)?;
+            self.risk.on_closed([order_id]);
+            Ok(())
+        };
+        let request = serde_json::json!({ "order_id": order_id });
+        self.journaled("CANCEL", "", request, call, |_| serde_json::json!({}))
+            .await
+    }
+
+    /// Look up an order by id
+    pub async fn order_status(&self, order_id: &str) -> Result<OrderStatus, OrderError> {
+        let result = self
+            .limited(CallClass::Account, self.client.order(order_id))
+            .await;
+        let order = self
+            .drift
+            .record_sdk("clob.order", result)
+            .map_err(OrderError::Api)?;
+        if !["LIVE", "DELAYED"]
+            .iter()
+            .any(|s| order.status.eq_ignore_ascii_case(s))
+        {
+            self.risk.on_closed([order.id.as_str()]);
+        }
+        Ok(OrderStatus {
+            order_id: order.id,
+            status: order.status,
+            original_qty_raw: decimal_to_raw(order.original_size),
+            matched_qty_raw: decimal_to_raw(order.size_matched),
+            price_raw: decimal_to_raw(order.price),
+        })
+    }
+
+    /// Stop counting the orders a cancel took off the book (or found gone)
+    fn closed(&self, outcomes: Vec<CancelOutcome>) -> Vec<CancelOutcome> {
+        self.risk.on_closed(
+            outcomes
+                .iter()
+                .filter(|outcome| !outcome.is_failure())
+                .map(|outcome| outcome.order_id.as_str()),
+        );
+        outcomes
+    }
+
+    /// Cancel all open orders, with the outcome for each
+    pub async fn cancel_all(&self) -> Result<Vec<CancelOutcome>, OrderError> {
+        let call = async {
+            self.ensure_writable()?;
+            if self.is_dry_run() {
+                return Ok(self.closed(self.paper_cancel_all(None)));
+            }
+            let (endpoint, client) = self.order_client();
+            let result = self
+                .timed(
+                    Op::Cancel,
+                    self.retried("CANCEL_ALL", || {
+                        self.limited(CallClass::Order, client.cancel_all_orders())
+                    }),
+                )
+                .await;
+            let resp = self.routed(endpoint, result).map_err(OrderError::Api)?;
+            Ok(self.closed(cancels::outcomes(resp)))
+        };
+        self.journaled("CANCEL_ALL", "", serde_json::json!({}), call, |outcomes| {
+            serde_json::json!(outcomes)
+        })
+        .await
+    }
+
+    /// Cancel orders by id in one request, with the outcome for each
+    pub async fn cancel_orders(
+        &self,
+        order_ids: &[&str],
+    ) -> Result<Vec<CancelOutcome>, OrderError> {
+        let call = async {
+            self.ensure_writable()?;
+            if self.is_dry_run() {
+                return Ok(self.closed(self.paper_cancel(order_ids)));
+            }
+            let (endpoint, client) = self.order_client();
+            let result = self
+                .timed(
+                    Op::Cancel,
+                    self.retried("CANCEL_ORDERS", || {
+                        self.limited(CallClass::Order, client.cancel_orders(order_ids))
+                    }),
+                )
+                .await;
+            let resp = self.routed(endpoint, result).map_err(OrderError::Api)?;
+            Ok(self.closed(cancels::outcomes(resp)))
+        };
+        let request = serde_json::json!({ "order_ids": order_ids });
+        self.journaled("CANCEL_ORDERS", "", request, call, |outcomes| {
+            serde_json::json!(outcomes)
+        })
+        .await
+    }
+
+    /// Cancel the open orders of one token, with the outcome for each
+    pub async fn cancel_market(&self, token_id: &str) -> Result<Vec<CancelOutcome>, OrderError> {
+        let call = async {
+            self.ensure_writable()?;
+            let request = CancelMarketOrderRequest::builder()
+                .asset_id(parse_token(token_id)?)
+                .build();
+            if self.is_dry_run() {
+                return Ok(self.closed(self.paper_cancel_all(Some(token_id))));
+            }
+            let (endpoint, client) = self.order_client();
+            let result = self
+                .timed(
+                    Op::Cancel,
+                    self.retried("CANCEL_MARKET", || {
+                        self.limited(CallClass::Order, client.cancel_market_orders(&request))
+                    }),
+                )
+                .await;
+            let resp = self.routed(endpoint, result).map_err(OrderError::Api)?;
+            Ok(self.closed(cancels::outcomes(resp)))
+        };
+        self.journaled(
+            "CANCEL_MARKET",
+            token_id,
+            serde_json::json!({}),
+            call,
+            |outcomes| serde_json::json!(outcomes),
+        )
+        .await
+    }
+
+    /// USDC balance (raw, 6 decimals); refreshes the local funds check
+    pub async fn usdc_balance(&self) -> anyhow::Result<i64> {
+        let result = self
+            .timed(
+                Op::Balance,
+                self.limited(
+                    CallClass::Account,
+                    self.client
+                        .balance_allowance(BalanceAllowanceRequest::default()),
+                ),
+            )
+            .await;
+        let balance = self.drift.record_sdk("clob.balance", result)?;
+        let raw = decimal_to_raw(balance.balance);
+        self.balance.set_cached(raw);
+        Ok(raw)
+    }
+
+    /// Shares held (raw, 6 decimals); resyncs the position tracked for risk limits
+    pub async fn token_balance(&self, token_id: &str) -> Result<i64, OrderError> {
+        let token = parse_token(token_id)?;
+        let req = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Conditional)
+            .token_id(token)
+            .signature_type(SignatureType::Proxy)
+            .build();
+        let result = self
+            .timed(
+                Op::Balance,
+                self.limited(CallClass::Account, self.client.balance_allowance(req)),
+            )
+            .await;
+        let balance = self
+            .drift
+            .record_sdk("clob.balance", result)
+            .map_err(OrderError::Api)?;
+
+        let raw = decimal_to_raw(balance.balance);
+        self.risk
+            .set_position(token_id, raw as f64 / DECIMAL_SCALE as f64);
+        Ok(raw)
+    }
+}
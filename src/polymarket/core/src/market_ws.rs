@@ -0,0 +1,645 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Local order books kept from the CLOB market channel
+//!
+//! `BookFeed` holds the subscribed tokens and one book per token. `run`
+//! keeps a connection to `<ws_host>/ws/market` open until it fails; the
+//! caller reconnects, and the new connection subscribes every token again.
+//! Tokens added or removed while connected are sent on the live connection.
+//!
+//! The venue sends a full `book` on subscribe and after each trade, then
+//! `price_change` deltas (size 0 removes a level). Until its first `book`
+//! a token has no local book. Each trade also comes as a `last_trade_price`
+//! print (price, size, taker side, time): the token's public tape. Messages
+//! that do not parse are recorded as drift under "ws.market" and skipped.
+//!
+//! The channel has no sequence numbers, so a missed delta is caught by what
+//! it leaves behind: each `price_change` carries the venue's best bid and
+//! ask after it, and a local book whose top differs has a gap. It is then
+//! marked stale until the venue sends a `book` or `resync` restores it over
+//! REST; a connection lost leaves every book stale likewise. A message
+//! older than the book (by venue time) is dropped rather than rolling it
+//! back. Each change bumps the book's generation, so a reader can tell
+//! whether the book moved between two reads.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use flox_connector::feed::{self, Commands, Keepalive};
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::Decimal;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::book::{BookSnapshot, Level};
+use crate::schema::DriftLog;
+use crate::{OrderError, Trader};
+
+/// Path of the market channel under `ws_host`
+pub const MARKET_CHANNEL_PATH: &str = "/ws/market";
+
+/// The venue drops connections that send nothing for longer than this
+pub const PING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A connection with no message (PONG included) for this long is dead
+pub const SILENCE_LIMIT: Duration = Duration::from_secs(30);
+
+/// Keepalive of both channels
+pub(crate) const KEEPALIVE: Keepalive = Keepalive {
+    interval: PING_INTERVAL,
+    ping: Some("PING"),
+    silence_limit: SILENCE_LIMIT,
+};
+
+/// What a market channel message changed
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedUpdate {
+    /// The local book of a token changed
+    Book(String),
+    /// The local book of a token missed updates and is now stale
+    Gap(String),
+    TickSize {
+        token_id: String,
+        tick: Decimal,
+    },
+    /// A trade printed on the venue
+    LastTrade {
+        token_id: String,
+        price: Decimal,
+        size: Decimal,
+        /// The taker's side
+        side: Side,
+        /// Venue time of the trade, 0 if not sent
+        timestamp_ms: u64,
+    },
+}
+
+/// Consistency of a local book
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BookState {
+    /// Bumped by every change of the book, from 1 for the first `book`
+    pub generation: u64,
+    /// Missed updates; waiting for the venue's next `book` or a resync
+    pub stale: bool,
+    /// Gaps detected since subscribed
+    pub gaps: u64,
+    /// Stale books restored over REST since subscribed
+    pub resyncs: u64,
+    /// Venue time of the newest message applied, 0 if none sent one
+    pub timestamp_ms: u64,
+}
+
+impl BookState {
+    /// A message at `timestamp_ms` predates what the book holds
+    fn is_newer_than(&self, timestamp_ms: u64) -> bool {
+        timestamp_ms != 0 && timestamp_ms < self.timestamp_ms
+    }
+
+    /// Record a change applied from a message at `timestamp_ms`
+    fn changed(&mut self, timestamp_ms: u64) {
+        self.generation += 1;
+        self.timestamp_ms = self.timestamp_ms.max(timestamp_ms);
+    }
+}
+
+struct LiveBook {
+    /// Price -> size
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    updated_at: Instant,
+    state: BookState,
+}
+
+impl LiveBook {
+    /// The book's top agrees with the best bid and ask the venue reported
+    /// along with `change`; true if it reported none
+    /// An empty side counts as a bid of 0 and an ask of 1
+    fn top_matches(&self, change: &Value) -> bool {
+        let (Ok(best_bid), Ok(best_ask)) = (
+            decimal_field(change, "best_bid"),
+            decimal_field(change, "best_ask"),
+        ) else {
+            return true;
+        };
+        let bid = self
+            .bids
+            .keys()
+            .next_back()
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        let ask = self.asks.keys().next().copied().unwrap_or(Decimal::ONE);
+        bid == best_bid && ask == best_ask
+    }
+
+    fn snapshot(&self) -> BookSnapshot {
+        let level = |(price, size): (&Decimal, &Decimal)| Level {
+            price: *price,
+            size: *size,
+        };
+        BookSnapshot {
+            bids: self.bids.iter().rev().map(level).collect(),
+            asks: self.asks.iter().map(level).collect(),
+            fetched_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BookFeed {
+    tokens: Mutex<BTreeSet<String>>,
+    books: RwLock<HashMap<String, LiveBook>>,
+    /// Subscription messages for the live connection, if any
+    commands: Commands,
+}
+
+impl BookFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a token; false if it was already subscribed
+    pub fn subscribe(&self, token_id: &str) -> bool {
+        let added = self.lock_tokens().insert(token_id.to_string());
+        if added {
+            self.commands
+                .send(subscription(&[token_id], Some("subscribe")));
+        }
+        added
+    }
+
+    /// Remove a token and drop its book; false if it was not subscribed
+    pub fn unsubscribe(&self, token_id: &str) -> bool {
+        let removed = self.lock_tokens().remove(token_id);
+        if removed {
+            if let Ok(mut books) = self.books.write() {
+                books.remove(token_id);
+            }
+            self.commands
+                .send(subscription(&[token_id], Some("unsubscribe")));
+        }
+        removed
+    }
+
+    pub fn is_subscribed(&self, token_id: &str) -> bool {
+        self.lock_tokens().contains(token_id)
+    }
+
+    pub fn tokens(&self) -> Vec<String> {
+        self.lock_tokens().iter().cloned().collect()
+    }
+
+    /// Local book of a token; None until the venue sent its first `book`
+    /// `fetched_at` is the time of the last message that changed it
+    pub fn book(&self, token_id: &str) -> Option<BookSnapshot> {
+        Some(self.books.read().ok()?.get(token_id)?.snapshot())
+    }
+
+    /// Local book of a token with its state, read together
+    pub fn book_state(&self, token_id: &str) -> Option<(BookSnapshot, BookState)> {
+        let books = self.books.read().ok()?;
+        let book = books.get(token_id)?;
+        Some((book.snapshot(), book.state))
+    }
+
+    pub fn state(&self, token_id: &str) -> Option<BookState> {
+        Some(self.books.read().ok()?.get(token_id)?.state)
+    }
+
+    /// Replace a stale book with `snapshot`; false if it is no longer stale
+    /// (the venue resent it meanwhile) or not subscribed
+    pub fn restore(&self, token_id: &str, snapshot: &BookSnapshot) -> bool {
+        let Ok(mut books) = self.books.write() else {
+            return false;
+        };
+        let Some(book) = books.get_mut(token_id) else {
+            return false;
+        };
+        if !book.state.stale {
+            return false;
+        }
+        let side = |levels: &[Level]| levels.iter().map(|l| (l.price, l.size)).collect();
+        book.bids = side(&snapshot.bids);
+        book.asks = side(&snapshot.asks);
+        book.updated_at = Instant::now();
+        book.state.changed(0);
+        book.state.stale = false;
+        book.state.resyncs += 1;
+        true
+    }
+
+    /// Fetch a stale book over REST and restore it from that
+    /// Deltas applied while the request is out are overwritten; if that
+    /// loses one, the next `price_change` finds the gap again
+    /// Ok(false) if the book was not stale, or no longer by the answer
+    pub async fn resync(&self, trader: &Trader, token_id: &str) -> Result<bool, OrderError> {
+        if !self.state(token_id).is_some_and(|state| state.stale) {
+            return Ok(false);
+        }
+        let snapshot = trader.refresh_book(token_id).await?;
+        Ok(self.restore(token_id, &snapshot))
+    }
+
+    /// Mark every book stale: updates sent while disconnected are lost
+    fn mark_stale(&self) {
+        if let Ok(mut books) = self.books.write() {
+            books.values_mut().for_each(|book| book.state.stale = true);
+        }
+    }
+
+    fn lock_tokens(&self) -> std::sync::MutexGuard<'_, BTreeSet<String>> {
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Apply one text message from the market channel
+    /// Returns what changed; messages for tokens not subscribed are ignored
+    pub fn apply(&self, text: &str, drift: &DriftLog) -> Vec<FeedUpdate> {
+        if text == "PONG" {
+            return Vec::new();
+        }
+        let body: Value = match serde_json::from_str(text) {
+            Ok(body) => body,
+            Err(e) => {
+                drift.record("ws.market", &e.to_string(), text);
+                return Vec::new();
+            }
+        };
+        let events = match body {
+            Value::Array(events) => events,
+            event => vec![event],
+        };
+        let mut updates = Vec::new();
+        for event in &events {
+            if let Err(e) = self.apply_event(event, &mut updates) {
+                drift.record("ws.market", &e, &event.to_string());
+            }
+        }
+        updates
+    }
+
+    fn apply_event(&self, event: &Value, updates: &mut Vec<FeedUpdate>) -> Result<(), String> {
+        let kind = event
+            .get("event_type")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        match kind {
+            "book" => {
+                let token_id = str_field(event, "asset_id")?;
+                if !self.is_subscribed(token_id) {
+                    return Ok(());
+                }
+                // Older servers name the sides buys/sells
+                let bids = event.get("bids").or_else(|| event.get("buys"));
+                let asks = event.get("asks").or_else(|| event.get("sells"));
+                let (bids, asks) = (levels(bids)?, levels(asks)?);
+                let timestamp_ms = timestamp_ms(event);
+                let mut books = self.books.write().map_err(|e| e.to_string())?;
+                let mut state = match books.get(token_id) {
+                    Some(book) if book.state.is_newer_than(timestamp_ms) => return Ok(()),
+                    Some(book) => book.state,
+                    None => BookState::default(),
+                };
+                state.changed(timestamp_ms);
+                state.stale = false;
+                let book = LiveBook {
+                    bids,
+                    asks,
+                    updated_at: Instant::now(),
+                    state,
+                };
+                books.insert(token_id.to_string(), book);
+                updates.push(FeedUpdate::Book(token_id.to_string()));
+            }
+            "price_change" => {
+                // One change per entry, each naming its asset; older servers
+                // send one asset with a `changes` list
+                let changes = match event.get("price_changes") {
+                    Some(Value::Array(changes)) => changes
+                        .iter()
+                        .map(|c| Ok((str_field(c, "asset_id")?, c)))
+                        .collect::<Result<Vec<_>, String>>()?,
+                    _ => {
+                        let token_id = str_field(event, "asset_id")?;
+                        match event.get("changes") {
+                            Some(Value::Array(changes)) => {
+                                changes.iter().map(|c| (token_id, c)).collect()
+                            }
+                            _ => return Err("price_change without changes".to_string()),
+                        }
+                    }
+                };
+                let timestamp_ms = timestamp_ms(event);
+                let mut books = self.books.write().map_err(|e| e.to_string())?;
+                // Last change applied per token, holding the venue's top after it
+                let mut last_changes: Vec<(&str, &Value)> = Vec::new();
+                for (token_id, change) in changes {
+                    // Deltas before the first book have nothing to apply to
+                    let Some(book) = books.get_mut(token_id) else {
+                        continue;
+                    };
+                    if book.state.is_newer_than(timestamp_ms) {
+                        continue;
+                    }
+                    let price = decimal_field(change, "price")?;
+                    let size = decimal_field(change, "size")?;
+                    let side = match str_field(change, "side")? {
+                        "BUY" | "buy" => &mut book.bids,
+                        "SELL" | "sell" => &mut book.asks,
+                        other => return Err(format!("unknown side {}", other)),
+                    };
+                    if size.is_zero() {
+                        side.remove(&price);
+                    } else {
+                        side.insert(price, size);
+                    }
+                    book.updated_at = Instant::now();
+                    book.state.changed(timestamp_ms);
+                    match last_changes
+                        .iter_mut()
+                        .find(|(token, _)| *token == token_id)
+                    {
+                        Some(last) => last.1 = change,
+                        None => last_changes.push((token_id, change)),
+                    }
+                    let update = FeedUpdate::Book(token_id.to_string());
+                    if !updates.contains(&update) {
+                        updates.push(update);
+                    }
+                }
+                for (token_id, change) in last_changes {
+                    let Some(book) = books.get_mut(token_id) else {
+                        continue;
+                    };
+                    if !book.state.stale && !book.top_matches(change) {
+                        book.state.stale = true;
+                        book.state.gaps += 1;
+                        updates.push(FeedUpdate::Gap(token_id.to_string()));
+                    }
+                }
+            }
+            "tick_size_change" => {
+                let token_id = str_field(event, "asset_id")?;
+                if self.is_subscribed(token_id) {
+                    updates.push(FeedUpdate::TickSize {
+                        token_id: token_id.to_string(),
+                        tick: decimal_field(event, "new_tick_size")?,
+                    });
+                }
+            }
+            "last_trade_price" => {
+                let token_id = str_field(event, "asset_id")?;
+                if self.is_subscribed(token_id) {
+                    updates.push(FeedUpdate::LastTrade {
+                        token_id: token_id.to_string(),
+                        price: decimal_field(event, "price")?,
+                        size: decimal_field(event, "size")?,
+                        side: side_field(event)?,
+                        timestamp_ms: timestamp_ms(event),
+                    });
+                }
+            }
+            // Other kinds (best_bid_ask, new_market, ...) carry nothing the
+            // local books need
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Connect to the market channel at `url` and feed each text message to
+    /// `on_message` (pass it to `apply`) until the connection fails
+    /// Subscribes every token on connect, and leaves every book stale when
+    /// it returns
+    pub async fn run(&self, url: &str, mut on_message: impl FnMut(&str)) -> anyhow::Result<()> {
+        let subscriptions = || {
+            let tokens = self.tokens();
+            let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+            vec![subscription(&tokens, None)]
+        };
+        let result = feed::run_ws(
+            &self.commands,
+            || connect(url),
+            subscriptions,
+            KEEPALIVE,
+            |text| {
+                on_message(text);
+                Ok(())
+            },
+        )
+        .await;
+        self.mark_stale();
+        result.map_err(anyhow::Error::msg)
+    }
+}
+
+/// Open a channel's WebSocket
+pub(crate) async fn connect(
+    url: &str,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, String> {
+    let (ws, _) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(ws)
+}
+
+/// Initial subscription (no operation) or a change on a live connection
+fn subscription(tokens: &[&str], operation: Option<&str>) -> String {
+    let mut message = serde_json::json!({ "assets_ids": tokens });
+    match operation {
+        Some(operation) => message["operation"] = operation.into(),
+        None => message["type"] = "market".into(),
+    }
+    message.to_string()
+}
+
+pub(crate) fn str_field<'a>(value: &'a Value, key: &str) -> Result<&'a str, String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("missing {}", key))
+}
+
+/// Decimal from a JSON string or number
+pub(crate) fn decimal_field(value: &Value, key: &str) -> Result<Decimal, String> {
+    let text = match value.get(key) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) => n.to_string(),
+        _ => return Err(format!("missing {}", key)),
+    };
+    text.parse()
+        .map_err(|e| format!("invalid {} {:?}: {}", key, text, e))
+}
+
+pub(crate) fn side_field(event: &Value) -> Result<Side, String> {
+    match str_field(event, "side")? {
+        "BUY" | "buy" => Ok(Side::Buy),
+        "SELL" | "sell" => Ok(Side::Sell),
+        other => Err(format!("unknown side {}", other)),
+    }
+}
+
+/// Event time in ms; the venue sends seconds or milliseconds, as a string
+/// or a number. 0 if missing
+pub(crate) fn timestamp_ms(event: &Value) -> u64 {
+    let value = match event.get("timestamp") {
+        Some(Value::String(s)) => s.parse().unwrap_or(0),
+        Some(Value::Number(n)) => n.as_u64().unwrap_or(0),
+        _ => 0,
+    };
+    // As ms, anything below 1e12 would predate 2002
+    if value < 1_000_000_000_000 {
+        value * 1000
+    } else {
+        value
+    }
+}
+
+fn levels(side: Option<&Value>) -> Result<BTreeMap<Decimal, Decimal>, String> {
+    let Some(Value::Array(entries)) = side else {
+        return Err("missing book side".to_string());
+    };
+    let mut levels = BTreeMap::new();
+    for entry in entries {
+        let size = decimal_field(entry, "size")?;
+        if !size.is_zero() {
+            levels.insert(decimal_field(entry, "price")?, size);
+        }
+    }
+    Ok(levels)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const TOKEN: &str = "123";
+    /// Venue time of the first book (ms)
+    const T0: u64 = 1767225600000;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn book_message(timestamp_ms: u64, bid: &str, ask: &str) -> String {
+        json!({
+            "event_type": "book",
+            "asset_id": TOKEN,
+            "bids": [{"price": bid, "size": "10"}],
+            "asks": [{"price": ask, "size": "10"}],
+            "timestamp": timestamp_ms.to_string(),
+        })
+        .to_string()
+    }
+
+    fn live_book(bids: &[&str], asks: &[&str]) -> LiveBook {
+        let side = |prices: &[&str]| prices.iter().map(|p| (dec(p), dec("10"))).collect();
+        LiveBook {
+            bids: side(bids),
+            asks: side(asks),
+            updated_at: Instant::now(),
+            state: BookState::default(),
+        }
+    }
+
+    #[test]
+    fn top_matches_the_reported_best_prices() {
+        let book = live_book(&["0.47", "0.48"], &["0.52", "0.53"]);
+        assert!(book.top_matches(&json!({"best_bid": "0.48", "best_ask": "0.52"})));
+        assert!(!book.top_matches(&json!({"best_bid": "0.48", "best_ask": "0.51"})));
+        assert!(!book.top_matches(&json!({"best_bid": "0.47", "best_ask": "0.52"})));
+        // Nothing reported, nothing to compare
+        assert!(book.top_matches(&json!({"best_bid": "0.48"})));
+
+        let empty = live_book(&[], &[]);
+        assert!(empty.top_matches(&json!({"best_bid": "0", "best_ask": "1"})));
+        assert!(!empty.top_matches(&json!({"best_bid": "0.48", "best_ask": "1"})));
+    }
+
+    #[test]
+    fn only_older_messages_predate_the_book() {
+        let mut state = BookState::default();
+        // A book without venue time takes anything
+        assert!(!state.is_newer_than(1));
+
+        state.changed(1000);
+        assert!(state.is_newer_than(999));
+        assert!(!state.is_newer_than(1000));
+        assert!(!state.is_newer_than(1001));
+        // Messages without a timestamp are never dropped
+        assert!(!state.is_newer_than(0));
+
+        // An older change does not move the book's time back
+        state.changed(500);
+        assert_eq!((state.generation, state.timestamp_ms), (2, 1000));
+        state.changed(2000);
+        assert_eq!((state.generation, state.timestamp_ms), (3, 2000));
+    }
+
+    #[test]
+    fn stale_book_is_restored_once() {
+        let drift = DriftLog::new();
+        let feed = BookFeed::new();
+        feed.subscribe(TOKEN);
+        feed.apply(&book_message(T0, "0.48", "0.52"), &drift);
+        assert_eq!(feed.state(TOKEN).unwrap().generation, 1);
+
+        let snapshot = BookSnapshot::new(
+            vec![Level {
+                price: dec("0.47"),
+                size: dec("5"),
+            }],
+            vec![],
+        );
+        // Not stale: nothing to restore
+        assert!(!feed.restore(TOKEN, &snapshot));
+
+        // A lost connection leaves the book stale until restored
+        feed.mark_stale();
+        assert!(feed.state(TOKEN).unwrap().stale);
+        assert!(feed.restore(TOKEN, &snapshot));
+        let (book, state) = feed.book_state(TOKEN).unwrap();
+        assert_eq!((book.bids, book.asks), (snapshot.bids.clone(), vec![]));
+        assert_eq!(
+            (state.generation, state.stale, state.resyncs),
+            (2, false, 1)
+        );
+        // Restoring keeps the venue time of the last message
+        assert_eq!(state.timestamp_ms, T0);
+    }
+
+    #[test]
+    fn book_during_resync_wins_over_the_fetched_one() {
+        let drift = DriftLog::new();
+        let feed = BookFeed::new();
+        feed.subscribe(TOKEN);
+        feed.apply(&book_message(T0, "0.48", "0.52"), &drift);
+        feed.mark_stale();
+
+        // The venue resends the book while the REST fetch of `resync` is
+        // out: the answer is not applied and resync reports Ok(false)
+        feed.apply(&book_message(T0 + 1000, "0.5", "0.51"), &drift);
+        let stale_fetch = BookSnapshot::new(
+            vec![Level {
+                price: dec("0.48"),
+                size: dec("10"),
+            }],
+            vec![],
+        );
+        assert!(!feed.restore(TOKEN, &stale_fetch));
+        let (book, state) = feed.book_state(TOKEN).unwrap();
+        assert_eq!(book.bids[0].price, dec("0.5"));
+        assert_eq!(
+            (state.generation, state.stale, state.resyncs),
+            (2, false, 0)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! YES/NO pair merging
+//!
+//! Holding both outcomes of a market ties up collateral: a YES and a NO
+//! share always pay out exactly 1 USDC together, so they can be merged back
+//! into 1 USDC right away. `AutoMerger` watches the markets it is told about
+//! and, per market, either reports mergeable pairs or merges them on-chain.
+//!
+//! A failed merge is not retried until the mergeable amount changes or the
+//! market's rule is set again, so a reverting transaction does not burn gas
+//! on every pass.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tracing::warn;
+
+use crate::gamma::{normalize_condition_id, MarketTokens};
+use crate::{OrderError, Trader, DECIMAL_SCALE};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeMode {
+    /// Not watched
+    Off,
+    /// Report mergeable pairs, leave them alone
+    Detect,
+    /// Merge pairs on-chain once they reach the minimum
+    Auto,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MergeRule {
+    pub mode: MergeMode,
+    /// Pairs below this many shares are ignored
+    pub min_shares: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeAction {
+    /// Mergeable amount changed (Detect mode)
+    Detected,
+    Merged,
+    Failed,
+}
+
+/// What a pass found or did on one market
+#[derive(Debug)]
+pub struct MergeEvent<'a> {
+    pub market: &'a MarketTokens,
+    pub action: MergeAction,
+    /// Pairs mergeable, or merged (6 decimals)
+    pub amount_raw: i64,
+    pub tx_hash: Option<&'a str>,
+    pub error: Option<&'a OrderError>,
+}
+
+struct Watched {
+    rule: MergeRule,
+    /// Looked up on the first pass
+    tokens: Option<MarketTokens>,
+    /// Amount last reported or attempted, so it is acted on once
+    handled_raw: i64,
+}
+
+#[derive(Default)]
+pub struct AutoMerger {
+    markets: Mutex<HashMap<String, Watched>>,
+}
+
+impl Trader {
+    /// Pairs of a market the wallet can merge now (6 decimals)
+    /// Also resyncs both positions tracked for risk limits
+    pub async fn mergeable(&self, market: &MarketTokens) -> Result<i64, OrderError> {
+        let yes = self.token_balance(&market.yes_token_id).await?;
+        let no = self.token_balance(&market.no_token_id).await?;
+        Ok(yes.min(no).max(0))
+    }
+}
+
+impl AutoMerger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watch a market (condition id) under `rule`; `MergeMode::Off` stops
+    pub fn set_rule(&self, condition_id: &str, rule: MergeRule) -> Result<(), OrderError> {
+        let condition_id = normalize_condition_id(condition_id).ok_or(OrderError::InvalidToken)?;
+        if !rule.min_shares.is_finite() || rule.min_shares < 0.0 {
+            return Err(OrderError::InvalidAmount);
+        }
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        if rule.mode == MergeMode::Off {
+            markets.remove(&condition_id);
+            return Ok(());
+        }
+        let tokens = markets.remove(&condition_id).and_then(|w| w.tokens);
+        markets.insert(
+            condition_id,
+            Watched {
+                rule,
+                tokens,
+                handled_raw: 0,
+            },
+        );
+        Ok(())
+    }
+
+    /// Number of markets watched
+    pub fn watched(&self) -> usize {
+        self.markets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Check every watched market once, merging where the rule says so
+    /// Runs one pass at a time: only the task driving the merger may call it
+    pub async fn check(&self, trader: &Trader, mut on_event: impl FnMut(&MergeEvent)) {
+        let watched: Vec<(String, MergeRule, Option<MarketTokens>, i64)> = {
+            let markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+            markets
+                .iter()
+                .map(|(id, w)| (id.clone(), w.rule, w.tokens.clone(), w.handled_raw))
+                .collect()
+        };
+
+        for (condition_id, rule, tokens, handled_raw) in watched {
+            let market = match tokens {
+                Some(market) => market,
+                None => match trader.market_tokens(&condition_id).await {
+                    Ok(market) => {
+                        self.update(&condition_id, |w| w.tokens = Some(market.clone()));
+                        market
+                    }
+                    Err(e) => {
+                        warn!("[MERGE] market lookup failed | {} | {}", condition_id, e);
+                        continue;
+                    }
+                },
+            };
+            let amount_raw = match trader.mergeable(&market).await {
+                Ok(amount) => amount,
+                Err(e) => {
+                    warn!("[MERGE] balance check failed | {} | {}", condition_id, e);
+                    continue;
+                }
+            };
+
+            let min_raw = (rule.min_shares * DECIMAL_SCALE as f64).ceil() as i64;
+            if amount_raw == 0 || amount_raw < min_raw {
+                self.update(&condition_id, |w| w.handled_raw = 0);
+                continue;
+            }
+            if amount_raw == handled_raw {
+                continue;
+            }
+            self.update(&condition_id, |w| w.handled_raw = amount_raw);
+
+            let mut event = MergeEvent {
+                market: &market,
+                action: MergeAction::Detected,
+                amount_raw,
+                tx_hash: None,
+                error: None,
+            };
+            if rule.mode == MergeMode::Detect {
+                on_event(&event);
+                continue;
+            }
+            match trader
+                .merge_positions(&market.condition_id, market.neg_risk, amount_raw)
+                .await
+            {
+                Ok(hash) => {
+                    // Resync positions and the cached USDC balance
+                    if let Err(e) = trader.mergeable(&market).await {
+                        warn!("[MERGE] position resync failed | {}", e);
+                    }
+                    if let Err(e) = trader.usdc_balance().await {
+                        warn!("[MERGE] balance resync failed | {}", e);
+                    }
+                    self.update(&condition_id, |w| w.handled_raw = 0);
+                    event.action = MergeAction::Merged;
+                    event.tx_hash = Some(&hash);
+                    on_event(&event);
+                }
+                Err(e) => {
+                    event.action = MergeAction::Failed;
+                    event.error = Some(&e);
+                    on_event(&event);
+                }
+            }
+        }
+    }
+
+    fn update(&self, condition_id: &str, f: impl FnOnce(&mut Watched)) {
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(watched) = markets.get_mut(condition_id) {
+            f(watched);
+        }
+    }
+}
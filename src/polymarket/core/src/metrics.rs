@@ -0,0 +1,80 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Latency and error counts of venue calls
+//!
+//! Every order post, batch post, cancel and balance read is timed, signing
+//! and timeouts included, into the shared histogram of its operation (see
+//! `flox_connector::metrics`), rendered in the Prometheus text format as
+//! the `polymarket_call_*` series. The counts carry over to the session
+//! that replaces this one.
+
+use std::future::Future;
+
+pub use flox_connector::OpMetrics;
+use flox_connector::Operation;
+
+use crate::Trader;
+
+/// Prefix of the Prometheus series
+const PROMETHEUS_PREFIX: &str = "polymarket";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    /// Single orders: market, limit, replacement
+    OrderPost,
+    /// Batches of limit orders, one request each
+    BatchPost,
+    /// Cancels by id, by market and of everything
+    Cancel,
+    /// USDC and token balance reads
+    Balance,
+}
+
+impl Operation for Op {
+    const ALL: &'static [Self] = &[Op::OrderPost, Op::BatchPost, Op::Cancel, Op::Balance];
+
+    fn name(self) -> &'static str {
+        match self {
+            Op::OrderPost => "order_post",
+            Op::BatchPost => "batch_post",
+            Op::Cancel => "cancel",
+            Op::Balance => "balance",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Histograms of every `Op`
+pub(crate) type Metrics = flox_connector::Metrics<Op>;
+
+impl Trader {
+    /// Run a venue call of `op`, recording how long it took and whether it
+    /// failed
+    pub(crate) async fn timed<T>(
+        &self,
+        op: Op,
+        call: impl Future<Output = anyhow::Result<T>>,
+    ) -> anyhow::Result<T> {
+        self.metrics.timed(op, call).await
+    }
+
+    /// Latency and error counts of every operation so far
+    pub fn metrics(&self) -> Vec<OpMetrics> {
+        self.metrics.snapshot()
+    }
+
+    /// The same counts in the Prometheus text exposition format
+    pub fn metrics_prometheus(&self) -> String {
+        self.metrics.prometheus(PROMETHEUS_PREFIX)
+    }
+}
@@ -0,0 +1,95 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Account notifications
+//!
+//! The venue keeps recent notifications per account (operator cancels,
+//! fills, market resolutions, ...) and returns all of them on every query.
+//! `NotificationTracker` turns those snapshots into a stream of new ones,
+//! without acknowledging them on the venue (other clients still see them).
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::timeouts::CallClass;
+use crate::Trader;
+
+/// Notification types defined by the venue; others are passed through
+pub const NOTIFICATION_ORDER_CANCELLED: i32 = 1;
+pub const NOTIFICATION_ORDER_FILLED: i32 = 2;
+pub const NOTIFICATION_MARKET_RESOLVED: i32 = 4;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Notification {
+    /// NOTIFICATION_* or a newer venue type
+    #[serde(rename = "type")]
+    pub kind: i32,
+    /// Venue payload as is (order id, market, reason, ...)
+    pub payload: serde_json::Value,
+}
+
+impl Notification {
+    /// Identity across snapshots: the venue sends no stable id
+    fn key(&self) -> String {
+        format!("{}:{}", self.kind, self.payload)
+    }
+
+    /// Token the notification is about, if any
+    pub fn token_id(&self) -> Option<&str> {
+        ["asset_id", "token_id"]
+            .iter()
+            .find_map(|field| self.payload.get(field)?.as_str())
+    }
+}
+
+/// Reports each notification once across successive snapshots
+#[derive(Default)]
+pub struct NotificationTracker {
+    seen: HashSet<String>,
+}
+
+impl NotificationTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Notifications of `snapshot` not reported before
+    /// Ones gone from the venue are forgotten, so memory stays bounded
+    pub fn fresh(&mut self, snapshot: Vec<Notification>) -> Vec<Notification> {
+        let mut current = HashSet::with_capacity(snapshot.len());
+        let mut fresh = Vec::new();
+        for notification in snapshot {
+            let key = notification.key();
+            if !self.seen.contains(&key) {
+                fresh.push(notification);
+            }
+            current.insert(key);
+        }
+        self.seen = current;
+        fresh
+    }
+}
+
+impl Trader {
+    /// Notifications the venue currently holds for this account
+    pub async fn notifications(&self) -> anyhow::Result<Vec<Notification>> {
+        let result = self
+            .limited(CallClass::Account, self.client.notifications())
+            .await;
+        let resp = self.drift.record_sdk("clob.notifications", result)?;
+        Ok(resp
+            .into_iter()
+            .map(|n| Notification {
+                kind: n.r#type,
+                payload: n.payload,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Open orders of the account
+//!
+//! `Trader::open_orders` lists the orders resting on the book, of every
+//! market or of one token, so a host can rebuild its local order state after
+//! a restart. The venue pages the list; pages are fetched until the end
+//! cursor. A list longer than `MAX_PAGES` pages is an error rather than
+//! returned in part.
+
+use polymarket_client_sdk::clob::types::request::OrdersRequest;
+use polymarket_client_sdk::clob::types::Side;
+
+use crate::timeouts::CallClass;
+use crate::{decimal_to_raw, parse_token, OrderError, Trader};
+
+/// Cursor the venue returns with the last page
+pub(crate) const END_CURSOR: &str = "LTE=";
+
+/// Pages fetched at most (the venue sends up to 500 orders per page)
+pub const MAX_PAGES: usize = 20;
+
+/// An order resting on the book
+#[derive(Clone, Debug)]
+pub struct OpenOrder {
+    pub order_id: String,
+    pub token_id: String,
+    pub side: Side,
+    pub price_raw: i64,
+    pub original_qty_raw: i64,
+    /// Shares not matched yet
+    pub remaining_qty_raw: i64,
+    /// Unix ms
+    pub created_at_ms: u64,
+}
+
+impl Trader {
+    /// Orders resting on the book, of one token or (None) of every market
+    pub async fn open_orders(&self, token_id: Option<&str>) -> Result<Vec<OpenOrder>, OrderError> {
+        let request = match token_id {
+            Some(token_id) => OrdersRequest::builder()
+                .asset_id(parse_token(token_id)?)
+                .build(),
+            None => OrdersRequest::default(),
+        };
+        if self.is_dry_run() {
+            return Ok(self.listed(token_id, self.paper_open_orders(token_id)));
+        }
+
+        let mut orders = Vec::new();
+        let mut cursor = None;
+        for _ in 0..MAX_PAGES {
+            let result = self
+                .limited(
+                    CallClass::Account,
+                    self.client.orders(&request, cursor.take()),
+                )
+                .await;
+            let page = self
+                .drift
+                .record_sdk("clob.orders.open", result)
+                .map_err(OrderError::Api)?;
+            orders.extend(page.data.into_iter().map(|order| {
+                let created_at = order.created_at.max(0) as u64;
+                OpenOrder {
+                    order_id: order.id,
+                    token_id: order.asset_id.to_string(),
+                    side: order.side,
+                    price_raw: decimal_to_raw(order.price),
+                    original_qty_raw: decimal_to_raw(order.original_size),
+                    remaining_qty_raw: decimal_to_raw(order.original_size - order.size_matched),
+                    // Sent in seconds
+                    created_at_ms: created_at * 1000,
+                }
+            }));
+            if page.next_cursor.is_empty() || page.next_cursor == END_CURSOR {
+                return Ok(self.listed(token_id, orders));
+            }
+            cursor = Some(page.next_cursor);
+        }
+        // A partial list would pass resting orders off as gone
+        Err(OrderError::Api(anyhow::anyhow!(
+            "more than {} pages of open orders",
+            MAX_PAGES
+        )))
+    }
+
+    /// A listing of every market is the full set of resting orders: count
+    /// those for the open orders limit
+    fn listed(&self, token_id: Option<&str>, orders: Vec<OpenOrder>) -> Vec<OpenOrder> {
+        if token_id.is_none() {
+            self.risk
+                .set_open_orders(orders.iter().map(|order| order.order_id.as_str()));
+        }
+        orders
+    }
+}
@@ -0,0 +1,1050 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Order flows
+//!
+//! Each order runs the local checks (minimums, risk limits, funds) before
+//! anything is signed, then posts it with a single round trip (or, in
+//! dry-run mode, fills it on paper: see `paper`). Quantities and prices in
+//! results are raw i64 with 6 decimals.
+//!
+//! GTD expirations are whole seconds on the venue. A deadline with a
+//! sub-second part is sent rounded up to the next second and also reported
+//! in `OrderFill::cancel_at_ms`, for the caller to cancel the order at the
+//! exact millisecond.
+
+#[cfg(feature = "execution")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::response::PostOrderResponse;
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::{Amount, OrderType, Side, SignedOrder};
+use polymarket_client_sdk::types::Decimal;
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::types::U256;
+#[cfg(feature = "execution")]
+use serde::Serialize;
+
+#[cfg(feature = "execution")]
+use crate::balance::Reservation;
+#[cfg(feature = "execution")]
+use crate::metrics::Op;
+#[cfg(feature = "execution")]
+use crate::{decimal_to_raw, parse_token, ClobClient, Trader, DECIMAL_SCALE};
+
+/// Minimum notional of a limit buy (USDC)
+const MIN_ORDER_USDC: f64 = 1.0;
+
+/// Worst price a market order sweeps to unless the caller bounds it
+pub const MARKET_BUY_PRICE: f64 = 0.99;
+pub const MARKET_SELL_PRICE: f64 = 0.01;
+
+/// Distance from the tick grid treated as float noise in a price
+pub(crate) const TICK_TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 9);
+
+/// The venue expires GTD orders this long before their expiration field
+#[cfg(feature = "execution")]
+pub(crate) const GTD_THRESHOLD_SECS: i64 = 60;
+
+/// Order to place, human units (USDC, shares, price 0-1)
+#[cfg(feature = "execution")]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum OrderRequest {
+    /// FAK buy spending `usdc_amount`, sweeps the book no higher than
+    /// `max_price` (MARKET_BUY_PRICE to take whatever the book holds)
+    MarketBuy { usdc_amount: f64, max_price: f64 },
+    /// FAK buy of `shares` (net of the taker fee), spending what the cached
+    /// book asks for them; sweeps no higher than `max_price`
+    MarketBuyShares { shares: f64, max_price: f64 },
+    /// FAK sell of `size` shares, sweeps the book no lower than `min_price`
+    /// (MARKET_SELL_PRICE to take whatever the book holds)
+    MarketSell { size: f64, min_price: f64 },
+    /// FOK buy like MarketBuy: fills in full or not at all
+    MarketBuyFok { usdc_amount: f64, max_price: f64 },
+    /// FOK sell like MarketSell: fills in full or not at all
+    MarketSellFok { size: f64, min_price: f64 },
+    /// MarketBuy bounded by the book: sweeps no higher than the price that
+    /// fills `usdc_amount` on the cached book plus `buffer_bps`; refused if
+    /// the expected average price is over `max_slippage_bps` above the ask
+    MarketBuyProtected {
+        usdc_amount: f64,
+        buffer_bps: u32,
+        max_slippage_bps: u32,
+    },
+    /// FAK sell of the shares whose proceeds reach `usdc_amount` on the
+    /// cached book; sweeps no lower than `min_price`
+    MarketSellUsdc { usdc_amount: f64, min_price: f64 },
+    /// MarketSell bounded by the book, like MarketBuyProtected
+    MarketSellProtected {
+        size: f64,
+        buffer_bps: u32,
+        max_slippage_bps: u32,
+    },
+    /// GTC buy for `usdc_amount` worth of shares at `price`
+    LimitBuy { price: f64, usdc_amount: f64 },
+    /// GTC sell of `size` shares at `price`
+    LimitSell { price: f64, size: f64 },
+    /// GTD buy like LimitBuy, resting until `expires_at_ms` (unix ms)
+    LimitBuyGtd {
+        price: f64,
+        usdc_amount: f64,
+        expires_at_ms: u64,
+    },
+    /// GTD sell like LimitSell, resting until `expires_at_ms` (unix ms)
+    LimitSellGtd {
+        price: f64,
+        size: f64,
+        expires_at_ms: u64,
+    },
+}
+
+#[cfg(feature = "execution")]
+impl OrderRequest {
+    /// Operation name used in logs and events
+    pub fn op(&self) -> &'static str {
+        match self {
+            OrderRequest::MarketBuy { .. } => "MARKET_BUY",
+            OrderRequest::MarketBuyShares { .. } => "MARKET_BUY_SHARES",
+            OrderRequest::MarketSell { .. } => "MARKET_SELL",
+            OrderRequest::MarketBuyFok { .. } => "MARKET_BUY_FOK",
+            OrderRequest::MarketSellFok { .. } => "MARKET_SELL_FOK",
+            OrderRequest::MarketSellUsdc { .. } => "MARKET_SELL_USDC",
+            OrderRequest::MarketBuyProtected { .. } => "MARKET_BUY_PROTECTED",
+            OrderRequest::MarketSellProtected { .. } => "MARKET_SELL_PROTECTED",
+            OrderRequest::LimitBuy { .. } => "LIMIT_BUY",
+            OrderRequest::LimitSell { .. } => "LIMIT_SELL",
+            OrderRequest::LimitBuyGtd { .. } => "LIMIT_BUY_GTD",
+            OrderRequest::LimitSellGtd { .. } => "LIMIT_SELL_GTD",
+        }
+    }
+
+    pub fn side(&self) -> Side {
+        match self {
+            OrderRequest::MarketBuy { .. }
+            | OrderRequest::MarketBuyFok { .. }
+            | OrderRequest::MarketBuyShares { .. }
+            | OrderRequest::MarketBuyProtected { .. }
+            | OrderRequest::LimitBuy { .. }
+            | OrderRequest::LimitBuyGtd { .. } => Side::Buy,
+            _ => Side::Sell,
+        }
+    }
+}
+
+/// Venue answer to a posted order
+#[cfg(feature = "execution")]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderFill {
+    pub success: bool,
+    pub order_id: String,
+    /// Shares filled (net of fee for market buys)
+    pub filled_qty_raw: i64,
+    pub avg_price_raw: i64,
+    /// Shares left resting on the book (accepted GTC orders)
+    pub remaining_qty_raw: i64,
+    /// Taker fee in shares (market buys)
+    pub fee_raw: i64,
+    /// GTD deadline the venue only enforces to the second (unix ms): cancel
+    /// the resting part at this time
+    pub cancel_at_ms: Option<u64>,
+    /// Why the venue refused the order, as it said it; empty on success
+    pub error_msg: String,
+    /// Id the caller placed it under (`place_with_id`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+}
+
+/// State of an order on the venue
+#[cfg(feature = "execution")]
+#[derive(Clone, Debug, Default)]
+pub struct OrderStatus {
+    pub order_id: String,
+    /// Venue status: LIVE, MATCHED, CANCELED, ...
+    pub status: String,
+    pub original_qty_raw: i64,
+    pub matched_qty_raw: i64,
+    /// Limit price
+    pub price_raw: i64,
+}
+
+#[cfg(feature = "execution")]
+impl OrderStatus {
+    /// Still resting on (or about to reach) the book
+    pub fn is_live(&self) -> bool {
+        ["LIVE", "DELAYED"]
+            .iter()
+            .any(|s| self.status.eq_ignore_ascii_case(s))
+    }
+
+    /// Shares still resting on the book, 0 once the order is done
+    pub fn remaining_qty_raw(&self) -> i64 {
+        if self.is_live() {
+            (self.original_qty_raw - self.matched_qty_raw).max(0)
+        } else {
+            0
+        }
+    }
+}
+
+/// Why an order was not placed
+#[derive(Debug, thiserror::Error)]
+pub enum OrderError {
+    #[error("invalid token id")]
+    InvalidToken,
+    #[error("amount or price not representable")]
+    InvalidAmount,
+    #[error("order size below ${MIN_ORDER_USDC} minimum")]
+    MinOrderSize,
+    #[error("shares below market minimum")]
+    MinShares,
+    #[error("risk limit: {0}")]
+    RiskLimit(String),
+    #[error("{0}")]
+    InsufficientBalance(String),
+    #[error("read-only mode")]
+    ReadOnly,
+    #[error("price {price} is not a multiple of tick size {tick}")]
+    TickSize { price: Decimal, tick: Decimal },
+    #[error("book holds {available} shares within the price cap")]
+    InsufficientLiquidity { available: Decimal },
+    #[error("expected slippage {expected_bps} bps over the {cap_bps} bps cap")]
+    Slippage { expected_bps: u32, cap_bps: u32 },
+    #[error("venue unavailable, orders paused")]
+    VenueUnavailable,
+    #[error("trading halted")]
+    Halted,
+    #[error("order not cancelled: {0}")]
+    NotCancelled(String),
+    #[error("client order id {0} already in use")]
+    DuplicateClientOrderId(String),
+    #[error("{0}")]
+    Api(anyhow::Error),
+}
+
+/// Venue terms of a GTD order
+#[cfg(feature = "execution")]
+struct Gtd {
+    expiration: DateTime<Utc>,
+    /// Set if the deadline falls between seconds
+    cancel_at_ms: Option<u64>,
+}
+
+#[cfg(feature = "execution")]
+impl Gtd {
+    /// Terms for an order expiring at `expires_at_ms` (unix ms), in the future
+    fn new(expires_at_ms: u64) -> Result<Self, OrderError> {
+        let now_ms = Utc::now().timestamp_millis();
+        let deadline_ms = i64::try_from(expires_at_ms).map_err(|_| OrderError::InvalidAmount)?;
+        if deadline_ms <= now_ms {
+            return Err(OrderError::InvalidAmount);
+        }
+        let secs = (deadline_ms + 999) / 1000 + GTD_THRESHOLD_SECS;
+        let expiration = DateTime::from_timestamp(secs, 0).ok_or(OrderError::InvalidAmount)?;
+        Ok(Self {
+            expiration,
+            cancel_at_ms: (!expires_at_ms.is_multiple_of(1000)).then_some(expires_at_ms),
+        })
+    }
+
+    fn order_type(gtd: &Option<Gtd>) -> OrderType {
+        match gtd {
+            Some(_) => OrderType::GTD,
+            None => OrderType::GTC,
+        }
+    }
+}
+
+/// The venue's answer to a posted order, or the paper fill standing in for it
+#[cfg(feature = "execution")]
+pub(crate) struct Posted {
+    pub success: bool,
+    pub error_msg: Option<String>,
+    pub order_id: String,
+    /// What the order gave: USDC for a buy, shares for a sell
+    pub making_amount: Decimal,
+    /// What it got
+    pub taking_amount: Decimal,
+}
+
+#[cfg(feature = "execution")]
+impl Posted {
+    /// An order the venue took but did not fill, e.g. a killed FOK
+    pub(crate) fn refused(reason: &str) -> Self {
+        Self {
+            success: false,
+            error_msg: Some(reason.to_string()),
+            order_id: String::new(),
+            making_amount: Decimal::ZERO,
+            taking_amount: Decimal::ZERO,
+        }
+    }
+}
+
+#[cfg(feature = "execution")]
+impl From<PostOrderResponse> for Posted {
+    fn from(resp: PostOrderResponse) -> Self {
+        Self {
+            success: resp.success,
+            error_msg: resp.error_msg,
+            order_id: resp.order_id,
+            making_amount: resp.making_amount,
+            taking_amount: resp.taking_amount,
+        }
+    }
+}
+
+/// A limit order that passed the local checks, ready to sign and post
+#[cfg(feature = "execution")]
+pub(crate) struct LimitOrder<'a> {
+    token_id: &'a str,
+    token: U256,
+    side: Side,
+    price: f64,
+    price_decimal: Decimal,
+    /// Shares
+    size_decimal: Decimal,
+    gtd: Option<Gtd>,
+    /// Buys: USDC held back for the notional, debited once accepted
+    reservation: Option<(Reservation<'a>, i64)>,
+}
+
+#[cfg(feature = "execution")]
+impl LimitOrder<'_> {
+    fn op(&self) -> &'static str {
+        match (self.side, self.gtd.is_some()) {
+            (Side::Buy, false) => "LIMIT_BUY",
+            (Side::Buy, true) => "LIMIT_BUY_GTD",
+            (_, false) => "LIMIT_SELL",
+            (_, true) => "LIMIT_SELL_GTD",
+        }
+    }
+}
+
+pub(crate) fn to_decimal(value: f64) -> Result<Decimal, OrderError> {
+    Decimal::try_from(value).map_err(|_| OrderError::InvalidAmount)
+}
+
+/// Taker fee as a fraction of the shares bought at `avg_price`
+/// fee_shares = shares * 0.25 * (price * (1 - price))^2
+pub(crate) fn taker_fee_factor(avg_price: f64) -> f64 {
+    0.25 * (avg_price * (1.0 - avg_price)).powi(2)
+}
+
+#[cfg(feature = "execution")]
+impl Trader {
+    /// Snap a price to the market's current tick grid before signing
+    /// Float noise (within TICK_TOLERANCE of a tick) is rounded away; prices
+    /// further off the grid are rejected rather than moved. The tick size is
+    /// unknown until prefetch and kept current by `set_tick_size`
+    fn check_tick(&self, token_id: &str, price: Decimal) -> Result<Decimal, OrderError> {
+        match self.tick_size(token_id) {
+            Some(tick) if !tick.is_zero() && !(price % tick).is_zero() => {
+                let snapped = (price / tick).round() * tick;
+                if (price - snapped).abs() > TICK_TOLERANCE {
+                    return Err(OrderError::TickSize { price, tick });
+                }
+                Ok(snapped)
+            }
+            _ => Ok(price),
+        }
+    }
+
+    /// Worst price a market order may sweep to, strictly between 0 and 1
+    /// A bound other than the sweep-everything `default` must be on the tick
+    /// grid, like any other price sent to the venue
+    fn price_bound(&self, token_id: &str, price: f64, default: f64) -> Result<Decimal, OrderError> {
+        let bound = to_decimal(price)?;
+        if bound <= Decimal::ZERO || bound >= Decimal::ONE {
+            return Err(OrderError::InvalidAmount);
+        }
+        if price != default {
+            return self.check_tick(token_id, bound);
+        }
+        Ok(bound)
+    }
+
+    /// Price bound of a market order from the book: the last level that
+    /// fills `amount` (USDC for a buy, shares for a sell) on a snapshot under
+    /// BOOK_MAX_AGE, moved `buffer_bps` further for the book moving before
+    /// the order lands and rounded out to the tick grid
+    /// Slippage if the expected average price is more than
+    /// `max_slippage_bps` off the best price; InsufficientLiquidity if the
+    /// book cannot fill `amount` at all
+    async fn book_bound(
+        &self,
+        token_id: &str,
+        side: Side,
+        amount: f64,
+        buffer_bps: u32,
+        max_slippage_bps: u32,
+    ) -> Result<Decimal, OrderError> {
+        let amount = to_decimal(amount)?;
+        if amount <= Decimal::ZERO {
+            return Err(OrderError::InvalidAmount);
+        }
+        let book = self.recent_book(token_id).await?;
+        let (limit, best) = match side {
+            Side::Buy => (to_decimal(MARKET_BUY_PRICE)?, book.asks.first()),
+            _ => (to_decimal(MARKET_SELL_PRICE)?, book.bids.first()),
+        };
+        let estimate = book.estimate(side, amount, limit);
+        let best = match best {
+            // Decimal division can leave a dust remainder of a full fill
+            Some(level) if estimate.filled_fraction >= 1.0 - 1e-9 => level.price,
+            _ => {
+                return Err(OrderError::InsufficientLiquidity {
+                    available: estimate.filled_shares,
+                })
+            }
+        };
+
+        let bps = Decimal::from(10_000);
+        let expected_bps: u32 = ((estimate.avg_price - best).abs() / best * bps)
+            .ceil()
+            .try_into()
+            .unwrap_or(u32::MAX);
+        if expected_bps > max_slippage_bps {
+            return Err(OrderError::Slippage {
+                expected_bps,
+                cap_bps: max_slippage_bps,
+            });
+        }
+
+        let tick = self.tick_size(token_id).unwrap_or(Decimal::new(1, 2));
+        let buffer = Decimal::from(buffer_bps) / bps;
+        Ok(match side {
+            Side::Buy => {
+                let bound = estimate.worst_price * (Decimal::ONE + buffer);
+                ((bound / tick).ceil() * tick).min(Decimal::ONE - tick)
+            }
+            _ => {
+                let bound = estimate.worst_price * (Decimal::ONE - buffer);
+                ((bound / tick).floor() * tick).max(tick)
+            }
+        })
+    }
+
+    /// Check and place an order
+    pub async fn place(
+        &self,
+        token_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, OrderError> {
+        self.journaled(
+            request.op(),
+            token_id,
+            serde_json::json!(request),
+            self.place_unjournaled(token_id, request),
+            |fill| serde_json::json!(fill),
+        )
+        .await
+    }
+
+    pub(crate) async fn place_unjournaled(
+        &self,
+        token_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, OrderError> {
+        self.ensure_trading()?;
+        self.breaker.admit()?;
+        let token = parse_token(token_id)?;
+        match request {
+            OrderRequest::MarketBuy {
+                usdc_amount,
+                max_price,
+            } => {
+                let max_price = self.price_bound(token_id, max_price, MARKET_BUY_PRICE)?;
+                self.market_buy(token_id, token, usdc_amount, max_price, OrderType::FAK)
+                    .await
+            }
+            OrderRequest::MarketBuyFok {
+                usdc_amount,
+                max_price,
+            } => {
+                let max_price = self.price_bound(token_id, max_price, MARKET_BUY_PRICE)?;
+                self.market_buy(token_id, token, usdc_amount, max_price, OrderType::FOK)
+                    .await
+            }
+            OrderRequest::MarketBuyShares { shares, max_price } => {
+                self.market_buy_shares(token_id, token, shares, max_price)
+                    .await
+            }
+            OrderRequest::MarketSell { size, min_price } => {
+                let min_price = self.price_bound(token_id, min_price, MARKET_SELL_PRICE)?;
+                self.market_sell(token_id, token, size, min_price, OrderType::FAK)
+                    .await
+            }
+            OrderRequest::MarketSellFok { size, min_price } => {
+                let min_price = self.price_bound(token_id, min_price, MARKET_SELL_PRICE)?;
+                self.market_sell(token_id, token, size, min_price, OrderType::FOK)
+                    .await
+            }
+            OrderRequest::MarketSellUsdc {
+                usdc_amount,
+                min_price,
+            } => {
+                self.market_sell_usdc(token_id, token, usdc_amount, min_price)
+                    .await
+            }
+            OrderRequest::MarketBuyProtected {
+                usdc_amount,
+                buffer_bps,
+                max_slippage_bps,
+            } => {
+                let max_price = self
+                    .book_bound(
+                        token_id,
+                        Side::Buy,
+                        usdc_amount,
+                        buffer_bps,
+                        max_slippage_bps,
+                    )
+                    .await?;
+                self.market_buy(token_id, token, usdc_amount, max_price, OrderType::FAK)
+                    .await
+            }
+            OrderRequest::MarketSellProtected {
+                size,
+                buffer_bps,
+                max_slippage_bps,
+            } => {
+                let min_price = self
+                    .book_bound(token_id, Side::Sell, size, buffer_bps, max_slippage_bps)
+                    .await?;
+                self.market_sell(token_id, token, size, min_price, OrderType::FAK)
+                    .await
+            }
+            OrderRequest::LimitBuy { .. }
+            | OrderRequest::LimitSell { .. }
+            | OrderRequest::LimitBuyGtd { .. }
+            | OrderRequest::LimitSellGtd { .. } => {
+                self.risk
+                    .check_open_orders(1)
+                    .map_err(OrderError::RiskLimit)?;
+                let order = self.check_limit(token_id, token, request)?;
+                self.post_limit(order).await
+            }
+        }
+    }
+
+    /// Checks of a GTC/GTD limit order; InvalidAmount for other requests
+    pub(crate) fn check_limit<'a>(
+        &'a self,
+        token_id: &'a str,
+        token: U256,
+        request: OrderRequest,
+    ) -> Result<LimitOrder<'a>, OrderError> {
+        match request {
+            OrderRequest::LimitBuy { price, usdc_amount } => {
+                self.check_limit_buy(token_id, token, price, usdc_amount, None)
+            }
+            OrderRequest::LimitSell { price, size } => {
+                self.check_limit_sell(token_id, token, price, size, None)
+            }
+            OrderRequest::LimitBuyGtd {
+                price,
+                usdc_amount,
+                expires_at_ms,
+            } => {
+                let gtd = Gtd::new(expires_at_ms)?;
+                self.check_limit_buy(token_id, token, price, usdc_amount, Some(gtd))
+            }
+            OrderRequest::LimitSellGtd {
+                price,
+                size,
+                expires_at_ms,
+            } => {
+                let gtd = Gtd::new(expires_at_ms)?;
+                self.check_limit_sell(token_id, token, price, size, Some(gtd))
+            }
+            _ => Err(OrderError::InvalidAmount),
+        }
+    }
+
+    /// Price the shares against the book, then spend exactly that
+    async fn market_buy_shares(
+        &self,
+        token_id: &str,
+        token: U256,
+        shares: f64,
+        max_price: f64,
+    ) -> Result<OrderFill, OrderError> {
+        let target = to_decimal(shares)?;
+        let max_price = to_decimal(max_price)?;
+        if target <= Decimal::ZERO || max_price <= Decimal::ZERO || max_price >= Decimal::ONE {
+            return Err(OrderError::InvalidAmount);
+        }
+        let max_price = self.check_tick(token_id, max_price)?;
+
+        // The fee is taken in shares and depends on the average price, which
+        // depends on how many shares are bought: a few rounds settle it
+        let book = self.recent_book(token_id).await?;
+        let mut gross = target;
+        let mut cost = Decimal::ZERO;
+        for _ in 0..4 {
+            let estimate = book
+                .cost_of_shares(gross, max_price)
+                .map_err(|available| OrderError::InsufficientLiquidity { available })?;
+            cost = estimate.notional;
+            let net = gross - estimate.fee_shares;
+            if net >= target {
+                break;
+            }
+            gross += target - net;
+        }
+
+        // Round up to USDC precision so rounding never under-buys
+        let scale = Decimal::from(DECIMAL_SCALE);
+        let usdc_amount: f64 = ((cost * scale).ceil() / scale)
+            .try_into()
+            .map_err(|_| OrderError::InvalidAmount)?;
+        self.market_buy(token_id, token, usdc_amount, max_price, OrderType::FAK)
+            .await
+    }
+
+    /// Price the proceeds against the book, then sell the shares that raise
+    /// them
+    async fn market_sell_usdc(
+        &self,
+        token_id: &str,
+        token: U256,
+        usdc_amount: f64,
+        min_price: f64,
+    ) -> Result<OrderFill, OrderError> {
+        let target = to_decimal(usdc_amount)?;
+        if target <= Decimal::ZERO {
+            return Err(OrderError::InvalidAmount);
+        }
+        let min_price = self.price_bound(token_id, min_price, MARKET_SELL_PRICE)?;
+
+        let book = self.recent_book(token_id).await?;
+        let estimate = book
+            .shares_for_proceeds(target, min_price)
+            .map_err(|available| OrderError::InsufficientLiquidity { available })?;
+        // Round up to the share grid so rounding never under-raises
+        let hundred = Decimal::from(100);
+        let shares: f64 = ((estimate.filled_shares * hundred).ceil() / hundred)
+            .try_into()
+            .map_err(|_| OrderError::InvalidAmount)?;
+        self.market_sell(token_id, token, shares, min_price, OrderType::FAK)
+            .await
+    }
+
+    /// Market buy as FAK (fill what the book holds, kill the rest) or FOK
+    /// (fill in full or not at all)
+    async fn market_buy(
+        &self,
+        token_id: &str,
+        token: U256,
+        usdc_amount: f64,
+        max_price: Decimal,
+        order_type: OrderType,
+    ) -> Result<OrderFill, OrderError> {
+        let op = match order_type {
+            OrderType::FOK => "MARKET_BUY_FOK",
+            _ => "MARKET_BUY",
+        };
+        // Round USDC to 6 decimal places (USDC precision)
+        let usdc_rounded = (usdc_amount * 1_000_000.0).floor() / 1_000_000.0;
+        let usdc_decimal = to_decimal(usdc_rounded)?;
+
+        self.risk
+            .check_order(token_id, Side::Buy, None, usdc_rounded, None)
+            .map_err(OrderError::RiskLimit)?;
+
+        // Fail fast if cached USDC (minus buffer and other in-flight buys) can't fund it
+        let reservation =
+            self.balance
+                .reserve(decimal_to_raw(usdc_decimal))
+                .map_err(|available_raw| {
+                    OrderError::InsufficientBalance(format!(
+                        "insufficient balance: need ${} available ${:.6}",
+                        usdc_decimal,
+                        available_raw as f64 / DECIMAL_SCALE as f64
+                    ))
+                })?;
+
+        let resp = if self.is_dry_run() {
+            self.paper_market(token_id, Side::Buy, usdc_decimal, max_price, order_type)
+                .await?
+        } else {
+            let (endpoint, client) = self.order_client();
+            let resp = self
+                .timed(
+                    Op::OrderPost,
+                    self.signed_post(
+                        op,
+                        async {
+                            // Amount::usdc means "spend this much USDC to buy shares"
+                            // Sweeps the orderbook up to max_price
+                            // (MARKET_BUY_PRICE unless bounded)
+                            let order = client
+                                .market_order()
+                                .token_id(token)
+                                .amount(Amount::usdc(usdc_decimal)?)
+                                .side(Side::Buy)
+                                .order_type(order_type)
+                                .price(max_price)
+                                .build()
+                                .await?;
+
+                            let signed = client.sign(&self.signer, order).await?;
+                            self.record_signed(op, token_id, &signed)?;
+                            Ok(signed)
+                        },
+                        |signed| client.post_order(signed),
+                    ),
+                )
+                .await;
+            self.routed(endpoint, resp).map_err(OrderError::Api)?.into()
+        };
+
+        // For BUY: taking_amount = shares received, making_amount = USDC paid
+        reservation.commit(decimal_to_raw(resp.making_amount));
+
+        let filled_shares: f64 = resp.taking_amount.try_into().unwrap_or(0.0);
+        let usdc_paid: f64 = resp.making_amount.try_into().unwrap_or(0.0);
+        let avg_price = if filled_shares > 0.0 {
+            usdc_paid / filled_shares
+        } else {
+            0.0
+        };
+
+        // Calculate fee and net shares received (taker fee)
+        let fee_factor = taker_fee_factor(avg_price);
+        let fee_shares = filled_shares * fee_factor;
+        let net_shares = filled_shares - fee_shares;
+
+        // Entry price per share received, so the fee is part of the cost
+        let entry_price = if net_shares > 0.0 {
+            usdc_paid / net_shares
+        } else {
+            0.0
+        };
+        self.risk
+            .on_fill(token_id, Side::Buy, net_shares, entry_price);
+
+        // Return net shares (after fee deduction)
+        Ok(OrderFill {
+            success: resp.success,
+            error_msg: resp.error_msg.unwrap_or_default(),
+            order_id: resp.order_id,
+            filled_qty_raw: (net_shares * 1_000_000.0) as i64,
+            avg_price_raw: (avg_price * 1_000_000.0) as i64,
+            remaining_qty_raw: 0,
+            fee_raw: (fee_shares * 1_000_000.0) as i64,
+            cancel_at_ms: None,
+            client_order_id: None,
+        })
+    }
+
+    /// Checks of a limit buy; holds its notional back from the cached USDC
+    fn check_limit_buy<'a>(
+        &'a self,
+        token_id: &'a str,
+        token: U256,
+        price: f64,
+        usdc_amount: f64,
+        gtd: Option<Gtd>,
+    ) -> Result<LimitOrder<'a>, OrderError> {
+        let price_decimal = self.check_tick(token_id, to_decimal(price)?)?;
+        let price: f64 = price_decimal
+            .try_into()
+            .map_err(|_| OrderError::InvalidAmount)?;
+
+        // Check minimum USDC order size
+        if usdc_amount < MIN_ORDER_USDC {
+            return Err(OrderError::MinOrderSize);
+        }
+
+        // For limit orders, we need to compute shares with proper precision:
+        // - Taker amount (shares): max 2 decimals
+        // - Maker amount (USDC): max 4 decimals
+        // Use ceil to ensure we don't go below min order size ($1)
+        let shares_raw = (usdc_amount / price * 100.0).ceil() / 100.0;
+
+        // Check minimum shares for this market (use try_read to avoid blocking)
+        if let Ok(cache) = self.min_order_sizes.try_read() {
+            if let Some(&min_shares) = cache.get(token_id) {
+                let min_shares_f64: f64 = min_shares.try_into().unwrap_or(0.0);
+                if shares_raw < min_shares_f64 {
+                    return Err(OrderError::MinShares);
+                }
+            }
+        }
+
+        let shares_decimal = to_decimal(shares_raw)?;
+
+        self.risk
+            .check_order(
+                token_id,
+                Side::Buy,
+                Some(price),
+                shares_raw * price,
+                Some(shares_raw),
+            )
+            .map_err(OrderError::RiskLimit)?;
+
+        // Fail fast if cached USDC (minus buffer and other in-flight buys) can't fund it
+        let notional_raw = decimal_to_raw(shares_decimal * price_decimal);
+        let reservation = self
+            .balance
+            .reserve(notional_raw)
+            .map_err(|available_raw| {
+                OrderError::InsufficientBalance(format!(
+                    "insufficient balance: need ${:.6} available ${:.6}",
+                    notional_raw as f64 / DECIMAL_SCALE as f64,
+                    available_raw as f64 / DECIMAL_SCALE as f64
+                ))
+            })?;
+
+        Ok(LimitOrder {
+            token_id,
+            token,
+            side: Side::Buy,
+            price,
+            price_decimal,
+            size_decimal: shares_decimal,
+            gtd,
+            reservation: Some((reservation, notional_raw)),
+        })
+    }
+
+    /// Checks of a limit sell
+    fn check_limit_sell<'a>(
+        &'a self,
+        token_id: &'a str,
+        token: U256,
+        price: f64,
+        size: f64,
+        gtd: Option<Gtd>,
+    ) -> Result<LimitOrder<'a>, OrderError> {
+        let price_decimal = self.check_tick(token_id, to_decimal(price)?)?;
+        let price: f64 = price_decimal
+            .try_into()
+            .map_err(|_| OrderError::InvalidAmount)?;
+
+        // Round size to 2 decimal places (Polymarket requirement)
+        let size_rounded = (size * 100.0).floor() / 100.0;
+        let size_decimal = to_decimal(size_rounded)?;
+
+        self.risk
+            .check_order(
+                token_id,
+                Side::Sell,
+                Some(price),
+                size_rounded * price,
+                Some(size_rounded),
+            )
+            .map_err(OrderError::RiskLimit)?;
+
+        Ok(LimitOrder {
+            token_id,
+            token,
+            side: Side::Sell,
+            price,
+            price_decimal,
+            size_decimal,
+            gtd,
+            reservation: None,
+        })
+    }
+
+    /// Build and sign a checked limit order
+    pub(crate) async fn sign_limit(
+        &self,
+        client: &ClobClient,
+        order: &LimitOrder<'_>,
+    ) -> anyhow::Result<SignedOrder> {
+        let mut builder = client
+            .market_order()
+            .token_id(order.token)
+            .amount(Amount::shares(order.size_decimal)?)
+            .side(order.side)
+            .order_type(Gtd::order_type(&order.gtd))
+            .price(order.price_decimal);
+        if let Some(gtd) = &order.gtd {
+            builder = builder.expiration(gtd.expiration);
+        }
+        let signable = builder.build().await?;
+
+        let signed = client.sign(&self.signer, signable).await?;
+        self.record_signed(order.op(), order.token_id, &signed)?;
+        Ok(signed)
+    }
+
+    /// Post a checked limit order on its own
+    async fn post_limit(&self, order: LimitOrder<'_>) -> Result<OrderFill, OrderError> {
+        if self.is_dry_run() {
+            let resp = self.paper_post(&order).await?;
+            return Ok(self.settle_limit(order, resp));
+        }
+        let (endpoint, client) = self.order_client();
+        let resp = self
+            .timed(
+                Op::OrderPost,
+                self.signed_post(order.op(), self.sign_limit(client, &order), |signed| {
+                    client.post_order(signed)
+                }),
+            )
+            .await;
+        let resp = self.routed(endpoint, resp).map_err(OrderError::Api)?;
+        Ok(self.settle_limit(order, resp.into()))
+    }
+
+    /// Fill a checked limit order on paper instead of posting it
+    pub(crate) async fn paper_post(&self, order: &LimitOrder<'_>) -> Result<Posted, OrderError> {
+        self.paper_limit(
+            order.token_id,
+            order.side,
+            order.price_decimal,
+            order.size_decimal,
+        )
+        .await
+    }
+
+    /// Account for the venue's answer to a limit order
+    pub(crate) fn settle_limit(&self, order: LimitOrder<'_>, resp: Posted) -> OrderFill {
+        // GTC limit orders are maker orders - NO FEE when resting in book
+        // Fee only applies if order filled immediately as taker
+        // We return raw filled amount - fee calculation should be done
+        // by caller based on whether order was maker or taker
+        // Shares are the taking side of a buy, the making side of a sell
+        let filled_qty_raw = match order.side {
+            Side::Buy => decimal_to_raw(resp.taking_amount),
+            _ => decimal_to_raw(resp.making_amount),
+        };
+        self.risk.on_fill(
+            order.token_id,
+            order.side,
+            filled_qty_raw as f64 / DECIMAL_SCALE as f64,
+            order.price,
+        );
+
+        // Resting part stays locked by the venue, so debit the full notional
+        if let Some((reservation, notional_raw)) = order.reservation {
+            if resp.success {
+                reservation.commit(notional_raw);
+            }
+        }
+
+        // Unfilled part of an accepted GTC/GTD order rests on the book
+        let remaining_qty_raw = if resp.success {
+            (decimal_to_raw(order.size_decimal) - filled_qty_raw).max(0)
+        } else {
+            0
+        };
+        if remaining_qty_raw > 0 {
+            self.risk.on_resting(&resp.order_id);
+        }
+
+        OrderFill {
+            success: resp.success,
+            error_msg: resp.error_msg.unwrap_or_default(),
+            order_id: resp.order_id,
+            filled_qty_raw,
+            avg_price_raw: decimal_to_raw(order.price_decimal),
+            remaining_qty_raw,
+            fee_raw: 0,
+            cancel_at_ms: order
+                .gtd
+                .and_then(|gtd| gtd.cancel_at_ms)
+                .filter(|_| remaining_qty_raw > 0),
+            client_order_id: None,
+        }
+    }
+
+    /// Market sell as FAK or FOK, see market_buy
+    async fn market_sell(
+        &self,
+        token_id: &str,
+        token: U256,
+        size: f64,
+        min_price: Decimal,
+        order_type: OrderType,
+    ) -> Result<OrderFill, OrderError> {
+        let op = match order_type {
+            OrderType::FOK => "MARKET_SELL_FOK",
+            _ => "MARKET_SELL",
+        };
+
+        // Round DOWN to 2 decimal places (Polymarket requirement), in Decimal
+        // so a size already on the grid is kept whole
+        let hundred = Decimal::from(100);
+        let size_decimal = (to_decimal(size)? * hundred).floor() / hundred;
+        let size_rounded: f64 = size_decimal
+            .try_into()
+            .map_err(|_| OrderError::InvalidAmount)?;
+
+        // Proceeds are unknown upfront, bound the notional at $1/share
+        self.risk
+            .check_order(token_id, Side::Sell, None, size_rounded, Some(size_rounded))
+            .map_err(OrderError::RiskLimit)?;
+
+        let resp = if self.is_dry_run() {
+            self.paper_market(token_id, Side::Sell, size_decimal, min_price, order_type)
+                .await?
+        } else {
+            let (endpoint, client) = self.order_client();
+            let resp = self
+                .timed(
+                    Op::OrderPost,
+                    self.signed_post(
+                        op,
+                        async {
+                            let order = client
+                                .market_order()
+                                .token_id(token)
+                                .amount(Amount::shares(size_decimal)?)
+                                .side(Side::Sell)
+                                .order_type(order_type)
+                                .price(min_price)
+                                .build()
+                                .await?;
+
+                            let signed = client.sign(&self.signer, order).await?;
+                            self.record_signed(op, token_id, &signed)?;
+                            Ok(signed)
+                        },
+                        |signed| client.post_order(signed),
+                    ),
+                )
+                .await;
+            self.routed(endpoint, resp).map_err(OrderError::Api)?.into()
+        };
+
+        // For SELL: making_amount = shares sold, taking_amount = USDC received
+        let filled_shares = resp.making_amount;
+        let usdc_received = resp.taking_amount;
+        let avg_price = if !filled_shares.is_zero() {
+            usdc_received / filled_shares
+        } else {
+            Decimal::ZERO
+        };
+
+        self.risk.on_fill(
+            token_id,
+            Side::Sell,
+            filled_shares.try_into().unwrap_or(0.0),
+            avg_price.try_into().unwrap_or(0.0),
+        );
+
+        Ok(OrderFill {
+            success: resp.success,
+            error_msg: resp.error_msg.unwrap_or_default(),
+            order_id: resp.order_id,
+            filled_qty_raw: decimal_to_raw(filled_shares),
+            avg_price_raw: decimal_to_raw(avg_price),
+            remaining_qty_raw: 0,
+            fee_raw: 0,
+            cancel_at_ms: None,
+            client_order_id: None,
+        })
+    }
+}
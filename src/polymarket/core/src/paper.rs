@@ -0,0 +1,290 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Paper trading
+//!
+//! In dry-run mode (`Trader::set_dry_run`) orders run every local check
+//! (tick, minimums, risk limits, funds) and are then filled against the
+//! order book instead of being signed and posted: the cached book if it is
+//! under `BOOK_MAX_AGE` (the market channel keeps it so), a REST snapshot
+//! otherwise. Market orders sweep it up to their price bound, FAK keeping
+//! what fills and FOK all or nothing. Limit orders take what crosses at
+//! their price and rest the remainder under a `paper-` id.
+//!
+//! Resting paper orders never fill later: the book is not watched for
+//! them. Cancels, replace and the open order list see them only. Fills move the tracked
+//! positions and the cached balance as real ones do, until the balance is
+//! next read from the venue. Nothing reaches the venue or the chain;
+//! on-chain calls fail as in read-only mode.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use polymarket_client_sdk::clob::types::{OrderType, Side};
+use polymarket_client_sdk::types::Decimal;
+use tracing::info;
+
+use crate::cancels::{CancelOutcome, CancelStatus};
+use crate::open_orders::OpenOrder;
+use crate::orders::Posted;
+use crate::{decimal_to_raw, OrderError, OrderRequest, Trader, DECIMAL_SCALE};
+
+/// Venue's answers to market orders that fill nothing, or not in full
+const FAK_KILLED: &str = "no orders found to match with FAK order. FAK orders are partially \
+                          filled or killed if no match is found.";
+const FOK_KILLED: &str = "order couldn't be fully filled. FOK orders are fully filled or killed.";
+
+/// Unfilled part of a paper limit order
+struct Resting {
+    token_id: String,
+    side: Side,
+    price: Decimal,
+    /// Shares
+    size: Decimal,
+    remaining: Decimal,
+    created_at_ms: u64,
+}
+
+/// Dry-run switch and the paper orders resting so far
+#[derive(Default)]
+pub(crate) struct Paper {
+    enabled: AtomicBool,
+    next_id: AtomicU64,
+    resting: Mutex<HashMap<String, Resting>>,
+}
+
+impl Paper {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    fn order_id(&self) -> String {
+        format!("paper-{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn resting(&self) -> std::sync::MutexGuard<'_, HashMap<String, Resting>> {
+        self.resting
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Take over the switch and resting orders of the session being replaced
+    pub(crate) fn inherit(&self, from: &Paper) {
+        if from.enabled.load(Ordering::Acquire) {
+            self.enabled.store(true, Ordering::Release);
+        }
+        self.next_id
+            .fetch_max(from.next_id.load(Ordering::Relaxed), Ordering::Relaxed);
+        let taken = std::mem::take(&mut *from.resting());
+        self.resting().extend(taken);
+    }
+}
+
+/// Outcomes in the order `cancels::outcomes` gives them
+fn sorted(mut outcomes: Vec<CancelOutcome>) -> Vec<CancelOutcome> {
+    outcomes.sort_by(|a, b| {
+        (a.status != CancelStatus::Cancelled, &a.order_id)
+            .cmp(&(b.status != CancelStatus::Cancelled, &b.order_id))
+    });
+    outcomes
+}
+
+fn cancelled(order_id: String) -> CancelOutcome {
+    CancelOutcome {
+        order_id,
+        status: CancelStatus::Cancelled,
+        reason: String::new(),
+    }
+}
+
+impl Trader {
+    /// Fill orders on paper from now on; like read-only, there is
+    /// deliberately no way back
+    pub fn set_dry_run(&self) {
+        self.paper.enabled.store(true, Ordering::Release);
+        info!("[PAPER] dry run: orders fill against the book, nothing is posted");
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.paper.enabled.load(Ordering::Acquire)
+    }
+
+    /// Fill a market order on the book: `amount` is USDC for a buy and
+    /// shares for a sell, swept no further than `limit`
+    pub(crate) async fn paper_market(
+        &self,
+        token_id: &str,
+        side: Side,
+        amount: Decimal,
+        limit: Decimal,
+        order_type: OrderType,
+    ) -> Result<Posted, OrderError> {
+        let book = self.recent_book(token_id).await?;
+        let estimate = book.estimate(side, amount, limit);
+        // Decimal division can leave a dust remainder of a full fill
+        let full = estimate.filled_fraction >= 1.0 - 1e-9;
+        let killed = match order_type {
+            OrderType::FOK if !full => Some(FOK_KILLED),
+            _ if estimate.filled_shares.is_zero() => Some(FAK_KILLED),
+            _ => None,
+        };
+        if let Some(reason) = killed {
+            return Ok(Posted::refused(reason));
+        }
+
+        let (making_amount, taking_amount) = match side {
+            Side::Buy => (estimate.notional, estimate.filled_shares),
+            _ => (estimate.filled_shares, estimate.notional),
+        };
+        let order_id = self.paper.order_id();
+        info!(
+            "[PAPER] {} {:?} filled {} shares avg {} | token={}",
+            order_id, side, estimate.filled_shares, estimate.avg_price, token_id
+        );
+        Ok(Posted {
+            success: true,
+            error_msg: None,
+            order_id,
+            making_amount,
+            taking_amount,
+        })
+    }
+
+    /// Fill what crosses of a limit order of `size` shares at `price` and
+    /// rest the rest
+    pub(crate) async fn paper_limit(
+        &self,
+        token_id: &str,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+    ) -> Result<Posted, OrderError> {
+        let book = self.recent_book(token_id).await?;
+        let crossed = match side {
+            Side::Buy => book
+                .cost_of_shares(size, price)
+                .map_or_else(|available| available, |estimate| estimate.filled_shares),
+            _ => book.estimate(Side::Sell, size, price).filled_shares,
+        }
+        .min(size);
+
+        let order_id = self.paper.order_id();
+        let remaining = size - crossed;
+        if remaining > Decimal::ZERO {
+            self.paper.resting().insert(
+                order_id.clone(),
+                Resting {
+                    token_id: token_id.to_string(),
+                    side,
+                    price,
+                    size,
+                    remaining,
+                    created_at_ms: chrono::Utc::now().timestamp_millis() as u64,
+                },
+            );
+        }
+        info!(
+            "[PAPER] {} {:?} {} @ {} | filled={} resting={} token={}",
+            order_id, side, size, price, crossed, remaining, token_id
+        );
+        let (making_amount, taking_amount) = match side {
+            Side::Buy => (crossed * price, crossed),
+            _ => (crossed, crossed * price),
+        };
+        Ok(Posted {
+            success: true,
+            error_msg: None,
+            order_id,
+            making_amount,
+            taking_amount,
+        })
+    }
+
+    /// Replacement of a resting paper order, see `replacement`
+    pub(crate) fn paper_replacement(
+        &self,
+        order_id: &str,
+        price: f64,
+        size: f64,
+    ) -> Result<(String, OrderRequest), OrderError> {
+        let resting = self.paper.resting();
+        let Some(order) = resting.get(order_id) else {
+            return Err(OrderError::NotCancelled(format!(
+                "{} is not resting",
+                order_id
+            )));
+        };
+        let size = if size > 0.0 {
+            size
+        } else {
+            decimal_to_raw(order.remaining) as f64 / DECIMAL_SCALE as f64
+        };
+        let request = match order.side {
+            Side::Buy => OrderRequest::LimitBuy {
+                price,
+                usdc_amount: size * price,
+            },
+            _ => OrderRequest::LimitSell { price, size },
+        };
+        Ok((order.token_id.clone(), request))
+    }
+
+    /// Cancel paper orders by id; ids not resting are NotFound
+    pub(crate) fn paper_cancel(&self, order_ids: &[&str]) -> Vec<CancelOutcome> {
+        let mut resting = self.paper.resting();
+        let outcomes = order_ids
+            .iter()
+            .map(|order_id| match resting.remove(*order_id) {
+                Some(_) => cancelled(order_id.to_string()),
+                None => CancelOutcome {
+                    order_id: order_id.to_string(),
+                    status: CancelStatus::NotFound,
+                    reason: "order not found".to_string(),
+                },
+            })
+            .collect();
+        sorted(outcomes)
+    }
+
+    /// Cancel every resting paper order, or those of `token_id`
+    pub(crate) fn paper_cancel_all(&self, token_id: Option<&str>) -> Vec<CancelOutcome> {
+        let mut resting = self.paper.resting();
+        let ids: Vec<String> = resting
+            .iter()
+            .filter(|(_, order)| token_id.is_none_or(|token| order.token_id == token))
+            .map(|(order_id, _)| order_id.clone())
+            .collect();
+        for order_id in &ids {
+            resting.remove(order_id);
+        }
+        sorted(ids.into_iter().map(cancelled).collect())
+    }
+
+    /// Resting paper orders, of one token or (None) of every market
+    pub(crate) fn paper_open_orders(&self, token_id: Option<&str>) -> Vec<OpenOrder> {
+        let mut orders: Vec<OpenOrder> = self
+            .paper
+            .resting()
+            .iter()
+            .filter(|(_, order)| token_id.is_none_or(|token| order.token_id == token))
+            .map(|(order_id, order)| OpenOrder {
+                order_id: order_id.clone(),
+                token_id: order.token_id.clone(),
+                side: order.side,
+                price_raw: decimal_to_raw(order.price),
+                original_qty_raw: decimal_to_raw(order.size),
+                remaining_qty_raw: decimal_to_raw(order.remaining),
+                created_at_ms: order.created_at_ms,
+            })
+            .collect();
+        orders.sort_by_key(|order| order.created_at_ms);
+        orders
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Unrealized PnL of open positions
+//!
+//! Positions are the ones tracked for risk limits: fills of this session
+//! carry their entry price, shares picked up from a balance query do not
+//! and are marked without PnL. Each token is marked at the midpoint of its
+//! book (cached if under a second old) or, if the book is one-sided, at its
+//! last trade.
+
+use futures::future::join_all;
+use polymarket_client_sdk::clob::types::request::LastTradePriceRequest;
+use polymarket_client_sdk::types::Decimal;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::risk::Position;
+use crate::timeouts::CallClass;
+use crate::{parse_token, OrderError, Trader};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkSource {
+    Midpoint,
+    LastTrade,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PositionMark {
+    pub token_id: String,
+    pub shares: f64,
+    /// Average entry price, fees included; None if unknown
+    pub avg_price: Option<f64>,
+    /// None if the token has neither a two-sided book nor a trade
+    pub mark_price: Option<f64>,
+    pub source: Option<MarkSource>,
+    /// Shares at the mark price, USDC
+    pub market_value: Option<f64>,
+    /// Against the entry price, USDC
+    pub unrealized_pnl: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PnlReport {
+    /// By token id
+    pub positions: Vec<PositionMark>,
+    /// Sum over positions with both a mark and an entry price, USDC
+    pub unrealized_pnl: f64,
+    /// Sum over marked positions, USDC
+    pub market_value: f64,
+    /// Positions left out of `unrealized_pnl`
+    pub unpriced: usize,
+}
+
+impl Trader {
+    /// Price a token is marked at: book midpoint, else last trade
+    pub async fn mark_price(&self, token_id: &str) -> Result<(f64, MarkSource), OrderError> {
+        let book = self.recent_book(token_id).await?;
+        if let (Some(bid), Some(ask)) = (book.bids.first(), book.asks.first()) {
+            let mid: f64 = ((bid.price + ask.price) / Decimal::TWO)
+                .try_into()
+                .unwrap_or(0.0);
+            if mid > 0.0 {
+                return Ok((mid, MarkSource::Midpoint));
+            }
+        }
+
+        let token = parse_token(token_id)?;
+        let req = LastTradePriceRequest::builder().token_id(token).build();
+        let last = self
+            .limited(CallClass::MarketData, self.client.last_trade_price(&req))
+            .await
+            .map_err(OrderError::Api)?;
+        let price: f64 = last.price.try_into().unwrap_or(0.0);
+        if price <= 0.0 {
+            return Err(OrderError::Api(anyhow::anyhow!(
+                "no price for {}",
+                token_id
+            )));
+        }
+        Ok((price, MarkSource::LastTrade))
+    }
+
+    /// Mark every tracked position and total the unrealized PnL
+    pub async fn mark_positions(&self) -> PnlReport {
+        let mut positions = self.risk.positions();
+        positions.sort_by(|a, b| a.0.cmp(&b.0));
+        let marks = join_all(
+            positions
+                .iter()
+                .map(|(token_id, _)| self.mark_price(token_id)),
+        )
+        .await;
+
+        let mut report = PnlReport::default();
+        for ((token_id, position), mark) in positions.into_iter().zip(marks) {
+            let mark = match mark {
+                Ok(mark) => Some(mark),
+                Err(e) => {
+                    warn!("[PNL] mark failed | {} | {}", token_id, e);
+                    None
+                }
+            };
+            let marked = mark_position(token_id, position, mark);
+            report.market_value += marked.market_value.unwrap_or(0.0);
+            match marked.unrealized_pnl {
+                Some(pnl) => report.unrealized_pnl += pnl,
+                None => report.unpriced += 1,
+            }
+            report.positions.push(marked);
+        }
+        report
+    }
+}
+
+fn mark_position(
+    token_id: String,
+    position: Position,
+    mark: Option<(f64, MarkSource)>,
+) -> PositionMark {
+    let market_value = mark.map(|(price, _)| position.shares * price);
+    let unrealized_pnl = match (mark, position.avg_price) {
+        (Some((price, _)), Some(avg)) => Some(position.shares * (price - avg)),
+        _ => None,
+    };
+    PositionMark {
+        token_id,
+        shares: position.shares,
+        avg_price: position.avg_price,
+        mark_price: mark.map(|(price, _)| price),
+        source: mark.map(|(_, source)| source),
+        market_value,
+        unrealized_pnl,
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Positions of the funder wallet from the Data API
+//!
+//! The CLOB only answers balances one token at a time; the Data API lists
+//! every conditional token the wallet holds, with its average entry price.
+//! Requests go through the crate's own HTTP client, like Gamma lookups, and
+//! are paged by offset. Entries that do not parse are logged as drift and
+//! skipped (see `schema`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::open_orders::MAX_PAGES;
+use crate::schema::{self, DriftLog};
+use crate::timeouts::CallClass;
+use crate::{OrderError, Trader};
+
+/// Positions per page, the most the Data API sends
+const PAGE_SIZE: usize = 500;
+
+/// Drift source of Data API position responses
+const SOURCE: &str = "data.positions";
+
+/// Conditional tokens held of one outcome
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct WalletPosition {
+    pub token_id: String,
+    /// 0x-prefixed
+    pub condition_id: String,
+    /// Outcome name, e.g. "Yes"
+    pub outcome: String,
+    pub shares: f64,
+    /// Average entry price; None if the Data API has none
+    pub avg_price: Option<f64>,
+    /// The market resolved and the shares can be redeemed
+    pub redeemable: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataPosition {
+    asset: String,
+    condition_id: String,
+    size: f64,
+    #[serde(default)]
+    avg_price: Option<f64>,
+    #[serde(default)]
+    outcome: Option<String>,
+    #[serde(default, deserialize_with = "schema::lenient_bool")]
+    redeemable: Option<bool>,
+}
+
+/// Positions with shares of a Data API /positions response
+/// Entries that do not parse are recorded in `drift` and skipped
+pub fn parse_positions(body: serde_json::Value, drift: &DriftLog) -> Vec<WalletPosition> {
+    let entries: Vec<DataPosition> = schema::parse_entries(SOURCE, body, drift);
+    entries
+        .into_iter()
+        .filter(|entry| entry.size > 0.0)
+        .map(|entry| WalletPosition {
+            token_id: entry.asset,
+            condition_id: entry.condition_id,
+            outcome: entry.outcome.unwrap_or_default(),
+            shares: entry.size,
+            avg_price: entry.avg_price.filter(|price| *price > 0.0),
+            redeemable: entry.redeemable.unwrap_or(false),
+        })
+        .collect()
+}
+
+impl Trader {
+    /// Every conditional token position of the funder wallet
+    pub async fn wallet_positions(&self) -> Result<Vec<WalletPosition>, OrderError> {
+        let base = format!(
+            "{}/positions?user={}&sizeThreshold=0&limit={}",
+            self.options.data_host.trim_end_matches('/'),
+            self.funder,
+            PAGE_SIZE
+        );
+
+        let mut positions = Vec::new();
+        for page in 0..MAX_PAGES {
+            let url = format!("{}&offset={}", base, page * PAGE_SIZE);
+            let body = self
+                .limited(CallClass::Account, async {
+                    let resp = self.http.get(&url).send().await?.error_for_status()?;
+                    resp.json::<serde_json::Value>().await
+                })
+                .await
+                .map_err(OrderError::Api)?;
+
+            let entries = body.as_array().map_or(0, Vec::len);
+            positions.extend(parse_positions(body, &self.drift));
+            if entries < PAGE_SIZE {
+                return Ok(positions);
+            }
+        }
+        // A partial list would pass positions off as gone
+        Err(OrderError::Api(anyhow::anyhow!(
+            "more than {} pages of positions",
+            MAX_PAGES
+        )))
+    }
+}
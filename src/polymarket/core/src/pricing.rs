@@ -0,0 +1,103 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Midpoint and spread of a token
+//!
+//! Taken from the cached book when it is under `BOOK_MAX_AGE` old and has
+//! both sides, else from the CLOB pricing endpoints, which answer with the
+//! one number instead of the whole book. Requests go through the crate's
+//! own HTTP client, so the proxy and TLS options apply.
+
+use std::str::FromStr;
+
+use polymarket_client_sdk::types::Decimal;
+
+use crate::book::{BookSnapshot, BOOK_MAX_AGE};
+use crate::timeouts::CallClass;
+use crate::{parse_token, OrderError, Trader};
+
+/// A CLOB pricing endpoint: path, field of the answer, drift source
+struct Endpoint {
+    path: &'static str,
+    field: &'static str,
+    source: &'static str,
+}
+
+const MIDPOINT: Endpoint = Endpoint {
+    path: "midpoint",
+    field: "mid",
+    source: "clob.midpoint",
+};
+
+const SPREAD: Endpoint = Endpoint {
+    path: "spread",
+    field: "spread",
+    source: "clob.spread",
+};
+
+/// Decimal field of a pricing answer, sent as a string or a number
+fn price_field(body: &serde_json::Value, field: &str) -> Option<Decimal> {
+    match body.get(field)? {
+        serde_json::Value::String(s) => Decimal::from_str(s).ok(),
+        serde_json::Value::Number(n) => Decimal::from_str(&n.to_string()).ok(),
+        _ => None,
+    }
+}
+
+impl Trader {
+    /// Halfway between the best bid and ask of a token
+    pub async fn midpoint(&self, token_id: &str) -> Result<Decimal, OrderError> {
+        self.price_of(token_id, &MIDPOINT, BookSnapshot::midpoint)
+            .await
+    }
+
+    /// Best ask less best bid of a token
+    pub async fn spread(&self, token_id: &str) -> Result<Decimal, OrderError> {
+        self.price_of(token_id, &SPREAD, BookSnapshot::spread).await
+    }
+
+    async fn price_of(
+        &self,
+        token_id: &str,
+        endpoint: &Endpoint,
+        from_book: fn(&BookSnapshot) -> Option<Decimal>,
+    ) -> Result<Decimal, OrderError> {
+        parse_token(token_id)?;
+        let cached = self
+            .cached_book(token_id)
+            .filter(|book| book.age() <= BOOK_MAX_AGE);
+        if let Some(price) = cached.as_deref().and_then(from_book) {
+            return Ok(price);
+        }
+
+        let url = format!(
+            "{}/{}?token_id={}",
+            self.options.clob_host.trim_end_matches('/'),
+            endpoint.path,
+            token_id
+        );
+        let body = self
+            .limited(CallClass::MarketData, async {
+                let resp = self.http.get(&url).send().await?.error_for_status()?;
+                resp.json::<serde_json::Value>().await
+            })
+            .await
+            .map_err(OrderError::Api)?;
+
+        price_field(&body, endpoint.field).ok_or_else(|| {
+            let error = format!("no decimal \"{}\"", endpoint.field);
+            self.drift
+                .record(endpoint.source, &error, &body.to_string());
+            OrderError::Api(anyhow::anyhow!(
+                "{} answer without a price",
+                endpoint.source
+            ))
+        })
+    }
+}
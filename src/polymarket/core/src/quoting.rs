@@ -0,0 +1,385 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Two-sided quoting
+//!
+//! The host sets a target bid and ask (price and size) per token; each
+//! `Quoter::reconcile` pass brings the resting GTC orders in line with the
+//! targets. The venue has no amend, so a changed quote is cancelled and
+//! placed again. A side is touched at most once per `min_replace_interval`,
+//! and resting quotes are checked for fills every `status_interval`. A filled
+//! quote is placed again at its target on the next pass; one pulled outside
+//! the quoter (cancel all, the venue) clears its target instead, so a kill
+//! is not undone.
+//!
+//! Orders go through `Trader::place`, so risk limits (including the order
+//! rate cap) apply to quotes as well.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use polymarket_client_sdk::clob::types::Side;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::{OrderError, OrderRequest, Trader};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuoteSettings {
+    /// Least time between two changes to the same side of a token
+    pub min_replace_interval: Duration,
+    /// How often each resting quote is checked for fills
+    pub status_interval: Duration,
+}
+
+impl Default for QuoteSettings {
+    fn default() -> Self {
+        Self {
+            min_replace_interval: Duration::from_millis(250),
+            status_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// One side of a quote, human units (price 0-1, shares)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct QuoteLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// Wanted quote of a token; None pulls that side
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QuoteTarget {
+    pub bid: Option<QuoteLevel>,
+    pub ask: Option<QuoteLevel>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteAction {
+    /// Quote posted (may have filled partly on arrival)
+    Placed,
+    /// Quote pulled by the quoter
+    Cancelled,
+    /// Resting quote fully filled
+    Filled,
+    /// Resting quote left the book without filling (cancelled elsewhere);
+    /// its target is cleared
+    Gone,
+    /// Placing or pulling a quote failed; retried after the replace interval
+    Failed,
+}
+
+/// What a reconcile pass did, reported per action
+#[derive(Debug)]
+pub struct QuoteEvent<'a> {
+    pub token_id: &'a str,
+    pub side: Side,
+    pub action: QuoteAction,
+    pub level: QuoteLevel,
+    pub order_id: &'a str,
+    /// Shares filled so far
+    pub filled_qty_raw: i64,
+    pub error: Option<&'a OrderError>,
+}
+
+#[derive(Clone, Debug)]
+struct Resting {
+    order_id: String,
+    level: QuoteLevel,
+}
+
+#[derive(Default)]
+struct SideState {
+    resting: Option<Resting>,
+    last_change: Option<Instant>,
+    last_status: Option<Instant>,
+}
+
+#[derive(Default)]
+struct TokenQuotes {
+    target: QuoteTarget,
+    bid: SideState,
+    ask: SideState,
+}
+
+impl TokenQuotes {
+    fn side(&mut self, side: Side) -> (Option<QuoteLevel>, &mut SideState) {
+        match side {
+            Side::Buy => (self.target.bid, &mut self.bid),
+            _ => (self.target.ask, &mut self.ask),
+        }
+    }
+}
+
+/// Work planned for one side in a pass
+enum Step {
+    /// Pull the resting quote, then post the target if any
+    Replace(Resting, Option<QuoteLevel>),
+    /// Check whether the resting quote is still on the book
+    Check(Resting),
+    Place(QuoteLevel),
+}
+
+#[derive(Default)]
+pub struct Quoter {
+    tokens: Mutex<HashMap<String, TokenQuotes>>,
+}
+
+impl Quoter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the quote wanted for a token, applied by the next pass
+    pub fn set_target(&self, token_id: &str, target: QuoteTarget) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens.entry(token_id.to_string()).or_default().target = target;
+    }
+
+    /// Drop every target, so the next pass pulls all quotes
+    pub fn clear_targets(&self) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        for quotes in tokens.values_mut() {
+            quotes.target = QuoteTarget::default();
+        }
+    }
+
+    /// Number of quotes resting on the book
+    pub fn resting(&self) -> usize {
+        let tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens
+            .values()
+            .map(|q| q.bid.resting.is_some() as usize + q.ask.resting.is_some() as usize)
+            .sum()
+    }
+
+    /// Bring resting quotes in line with the targets
+    /// Runs one pass at a time: only the task driving the quoter may call it
+    pub async fn reconcile(
+        &self,
+        trader: &Trader,
+        settings: &QuoteSettings,
+        mut on_event: impl FnMut(&QuoteEvent),
+    ) {
+        for (token_id, side, step) in self.plan(settings, false) {
+            self.run_step(trader, &token_id, side, step, &mut on_event)
+                .await;
+        }
+    }
+
+    /// Drop every target and pull every resting quote now, ignoring the
+    /// replace interval
+    pub async fn cancel_all(&self, trader: &Trader, mut on_event: impl FnMut(&QuoteEvent)) {
+        self.clear_targets();
+        let settings = QuoteSettings::default();
+        for (token_id, side, step) in self.plan(&settings, true) {
+            self.run_step(trader, &token_id, side, step, &mut on_event)
+                .await;
+        }
+    }
+
+    fn plan(&self, settings: &QuoteSettings, force: bool) -> Vec<(String, Side, Step)> {
+        let now = Instant::now();
+        let elapsed = |at: Option<Instant>, interval| at.is_none_or(|t| now - t >= interval);
+
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens.retain(|_, q| {
+            q.target != QuoteTarget::default() || q.bid.resting.is_some() || q.ask.resting.is_some()
+        });
+        let mut steps = Vec::new();
+        for (token_id, quotes) in tokens.iter_mut() {
+            for side in [Side::Buy, Side::Sell] {
+                let (target, state) = quotes.side(side);
+                let ready = force || elapsed(state.last_change, settings.min_replace_interval);
+                let step = match (&state.resting, target) {
+                    (Some(resting), target) if target != Some(resting.level) => {
+                        ready.then(|| Step::Replace(resting.clone(), target))
+                    }
+                    (Some(resting), _) => elapsed(state.last_status, settings.status_interval)
+                        .then(|| Step::Check(resting.clone())),
+                    (None, Some(level)) => ready.then_some(Step::Place(level)),
+                    (None, None) => None,
+                };
+                if let Some(step) = step {
+                    steps.push((token_id.clone(), side, step));
+                }
+            }
+        }
+        steps
+    }
+
+    async fn run_step(
+        &self,
+        trader: &Trader,
+        token_id: &str,
+        side: Side,
+        step: Step,
+        on_event: &mut impl FnMut(&QuoteEvent),
+    ) {
+        let place = match step {
+            Step::Check(resting) => {
+                match trader.order_status(&resting.order_id).await {
+                    Ok(status) if !status.is_live() => {
+                        let action = if status.matched_qty_raw >= status.original_qty_raw {
+                            QuoteAction::Filled
+                        } else {
+                            QuoteAction::Gone
+                        };
+                        // Pulled elsewhere (e.g. cancel all): stay out until re-targeted
+                        self.update(token_id, side, |s| s.resting = None);
+                        if action == QuoteAction::Gone {
+                            self.clear_target(token_id, side);
+                        }
+                        on_event(&quote_event(
+                            token_id,
+                            side,
+                            action,
+                            resting.level,
+                            &resting.order_id,
+                            status.matched_qty_raw,
+                            None,
+                        ));
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        warn!("[QUOTE] status check failed | token={} | {}", token_id, e)
+                    }
+                }
+                self.update(token_id, side, |s| s.last_status = Some(Instant::now()));
+                return;
+            }
+            Step::Replace(resting, target) => {
+                self.update(token_id, side, |s| s.last_change = Some(Instant::now()));
+                if let Err(e) = trader.cancel(&resting.order_id).await {
+                    // Still resting as far as we know: retried next interval
+                    on_event(&quote_event(
+                        token_id,
+                        side,
+                        QuoteAction::Failed,
+                        resting.level,
+                        &resting.order_id,
+                        0,
+                        Some(&e),
+                    ));
+                    return;
+                }
+                self.update(token_id, side, |s| s.resting = None);
+                on_event(&quote_event(
+                    token_id,
+                    side,
+                    QuoteAction::Cancelled,
+                    resting.level,
+                    &resting.order_id,
+                    0,
+                    None,
+                ));
+                match target {
+                    Some(level) => level,
+                    None => return,
+                }
+            }
+            Step::Place(level) => level,
+        };
+
+        self.update(token_id, side, |s| s.last_change = Some(Instant::now()));
+        let request = match side {
+            Side::Buy => OrderRequest::LimitBuy {
+                price: place.price,
+                usdc_amount: place.size * place.price,
+            },
+            _ => OrderRequest::LimitSell {
+                price: place.price,
+                size: place.size,
+            },
+        };
+        match trader.place(token_id, request).await {
+            Ok(fill) if fill.success => {
+                if fill.remaining_qty_raw > 0 {
+                    let resting = Resting {
+                        order_id: fill.order_id.clone(),
+                        level: place,
+                    };
+                    self.update(token_id, side, |s| {
+                        s.resting = Some(resting);
+                        s.last_status = Some(Instant::now());
+                    });
+                }
+                on_event(&quote_event(
+                    token_id,
+                    side,
+                    QuoteAction::Placed,
+                    place,
+                    &fill.order_id,
+                    fill.filled_qty_raw,
+                    None,
+                ));
+            }
+            Ok(fill) => {
+                let error = OrderError::Api(anyhow::anyhow!("quote rejected by the venue"));
+                on_event(&quote_event(
+                    token_id,
+                    side,
+                    QuoteAction::Failed,
+                    place,
+                    &fill.order_id,
+                    0,
+                    Some(&error),
+                ));
+            }
+            Err(e) => on_event(&quote_event(
+                token_id,
+                side,
+                QuoteAction::Failed,
+                place,
+                "",
+                0,
+                Some(&e),
+            )),
+        }
+    }
+
+    fn clear_target(&self, token_id: &str, side: Side) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(quotes) = tokens.get_mut(token_id) {
+            match side {
+                Side::Buy => quotes.target.bid = None,
+                _ => quotes.target.ask = None,
+            }
+        }
+    }
+
+    fn update(&self, token_id: &str, side: Side, f: impl FnOnce(&mut SideState)) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        let quotes = tokens.entry(token_id.to_string()).or_default();
+        f(quotes.side(side).1);
+    }
+}
+
+fn quote_event<'a>(
+    token_id: &'a str,
+    side: Side,
+    action: QuoteAction,
+    level: QuoteLevel,
+    order_id: &'a str,
+    filled_qty_raw: i64,
+    error: Option<&'a OrderError>,
+) -> QuoteEvent<'a> {
+    QuoteEvent {
+        token_id,
+        side,
+        action,
+        level,
+        order_id,
+        filled_qty_raw,
+        error,
+    }
+}
@@ -0,0 +1,133 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Client-side rate limits of venue calls
+//!
+//! Every CLOB and Gamma call takes a token from the bucket of its call
+//! class before it is sent (the shared `flox_connector::TokenBucket`). A
+//! call of a queueing class waits for its token and a call of a rejecting
+//! class fails at once with `RateLimited`; so does a queued call that
+//! would wait longer than its class timeout. The defaults stay under the
+//! venue's published limits, so a burst is slowed here instead of being
+//! answered 429. A call refused here never reached the venue and counts as
+//! no venue failure.
+
+use std::future::Future;
+use std::sync::Mutex;
+
+pub use flox_connector::RateLimit;
+use flox_connector::TokenBucket;
+
+use crate::timeouts::CallClass;
+use crate::{with_timeout, Trader};
+
+/// Order posts and cancels
+pub const DEFAULT_ORDER_LIMIT: RateLimit = RateLimit {
+    per_sec: 50.0,
+    burst: 300,
+    queue: true,
+};
+
+/// Books, prices, lookups
+pub const DEFAULT_MARKET_DATA_LIMIT: RateLimit = RateLimit {
+    per_sec: 20.0,
+    burst: 50,
+    queue: true,
+};
+
+/// Balances, order lookups, rewards
+pub const DEFAULT_ACCOUNT_LIMIT: RateLimit = RateLimit {
+    per_sec: 15.0,
+    burst: 30,
+    queue: true,
+};
+
+/// A call refused by the local rate limit; it was not sent
+#[derive(Debug, thiserror::Error)]
+#[error("rate limited: no {0:?} request budget left")]
+pub struct RateLimited(pub CallClass);
+
+/// Token bucket per call class
+pub(crate) struct RateLimiter([Mutex<TokenBucket>; 3]);
+
+impl RateLimiter {
+    pub(crate) fn new() -> Self {
+        Self(
+            [
+                DEFAULT_ORDER_LIMIT,
+                DEFAULT_MARKET_DATA_LIMIT,
+                DEFAULT_ACCOUNT_LIMIT,
+            ]
+            .map(|limit| Mutex::new(TokenBucket::new(limit))),
+        )
+    }
+
+    fn bucket(&self, class: CallClass) -> std::sync::MutexGuard<'_, TokenBucket> {
+        self.0[class as usize]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Limits and spent budget of the session being replaced
+    #[cfg(feature = "execution")]
+    pub(crate) fn inherit(&self, from: &RateLimiter) {
+        for class in CallClass::ALL {
+            // One lock at a time
+            let from = from.bucket(class).clone();
+            self.bucket(class).inherit(&from);
+        }
+    }
+}
+
+impl Trader {
+    /// Rate limit of calls of `class`
+    pub fn rate_limit(&self, class: CallClass) -> RateLimit {
+        self.rate_limiter.bucket(class).limit()
+    }
+
+    /// Limit calls of `class` to `limit` from now on
+    /// Err if the rate is negative or not finite, or a limit has no burst
+    pub fn set_rate_limit(&self, class: CallClass, limit: RateLimit) -> Result<(), String> {
+        limit.validate()?;
+        self.rate_limiter.bucket(class).set(limit);
+        Ok(())
+    }
+
+    /// Calls of `class` that can be sent right now without waiting; below 0
+    /// while queued calls wait. None if the class is not limited
+    pub fn rate_budget(&self, class: CallClass) -> Option<f64> {
+        self.rate_limiter.bucket(class).tokens()
+    }
+
+    /// Wait for the budget of one call of `class`
+    pub(crate) async fn throttle(&self, class: CallClass) -> Result<(), RateLimited> {
+        let wait = self
+            .rate_limiter
+            .bucket(class)
+            .take(self.timeout(class))
+            .ok_or(RateLimited(class))?;
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+
+    /// A venue call of `class`: rate limited, then bounded by its timeout
+    pub(crate) async fn limited<T, E>(
+        &self,
+        class: CallClass,
+        fut: impl Future<Output = Result<T, E>>,
+    ) -> anyhow::Result<T>
+    where
+        E: Into<anyhow::Error>,
+    {
+        self.throttle(class).await?;
+        with_timeout(self.timeout(class), fut).await
+    }
+}
@@ -0,0 +1,179 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Cancel-and-replace of a resting order
+//!
+//! `Trader::replace` moves a resting limit order to a new price and size
+//! with as short a gap off the book as the venue allows. The replacement is
+//! built from the order's own terms (`Trader::replacement`), then checked
+//! and signed while the old order still rests, so only the cancel and the
+//! post run back to back. It is posted only once the venue says the
+//! old order is cancelled: an order that matched, or that the venue refused
+//! to cancel, is not doubled by its replacement.
+
+use polymarket_client_sdk::clob::types::Side;
+
+use crate::cancels::{self, CancelStatus};
+use crate::metrics::Op;
+use crate::orders::GTD_THRESHOLD_SECS;
+use crate::timeouts::CallClass;
+use crate::{
+    decimal_to_raw, parse_token, OrderError, OrderFill, OrderRequest, Trader, DECIMAL_SCALE,
+};
+
+impl Trader {
+    /// Replacement of a resting order at `price` for `size` shares (<= 0
+    /// keeps the unmatched size): its token and a limit request of the same
+    /// side, GTD with the same deadline if the order has one.
+    /// NotCancelled if the order no longer rests.
+    pub async fn replacement(
+        &self,
+        order_id: &str,
+        price: f64,
+        size: f64,
+    ) -> Result<(String, OrderRequest), OrderError> {
+        if self.is_dry_run() {
+            return self.paper_replacement(order_id, price, size);
+        }
+        let result = self
+            .limited(CallClass::Account, self.client.order(order_id))
+            .await;
+        let old = self
+            .drift
+            .record_sdk("clob.order", result)
+            .map_err(OrderError::Api)?;
+        if !["LIVE", "DELAYED"]
+            .iter()
+            .any(|s| old.status.eq_ignore_ascii_case(s))
+        {
+            return Err(OrderError::NotCancelled(format!(
+                "{} is {}",
+                order_id, old.status
+            )));
+        }
+
+        let size = if size > 0.0 {
+            size
+        } else {
+            decimal_to_raw(old.original_size - old.size_matched) as f64 / DECIMAL_SCALE as f64
+        };
+        // The venue's expiration runs GTD_THRESHOLD_SECS past the deadline
+        let expires_at_ms = old
+            .expiration
+            .parse::<i64>()
+            .ok()
+            .filter(|secs| *secs > 0)
+            .map(|secs| ((secs - GTD_THRESHOLD_SECS).max(0) * 1000) as u64);
+        let request = match (old.side, expires_at_ms) {
+            (Side::Buy, None) => OrderRequest::LimitBuy {
+                price,
+                usdc_amount: size * price,
+            },
+            (Side::Buy, Some(expires_at_ms)) => OrderRequest::LimitBuyGtd {
+                price,
+                usdc_amount: size * price,
+                expires_at_ms,
+            },
+            (_, None) => OrderRequest::LimitSell { price, size },
+            (_, Some(expires_at_ms)) => OrderRequest::LimitSellGtd {
+                price,
+                size,
+                expires_at_ms,
+            },
+        };
+        Ok((old.asset_id.to_string(), request))
+    }
+
+    /// Cancel a resting order and post the limit `request` of `token_id` in
+    /// its place (see `replacement`). NotCancelled if the venue did not
+    /// cancel the old order, in which case nothing is posted.
+    pub async fn replace(
+        &self,
+        order_id: &str,
+        token_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, OrderError> {
+        self.journaled(
+            "REPLACE",
+            token_id,
+            serde_json::json!({ "order_id": order_id, "order": request }),
+            self.replace_unjournaled(order_id, token_id, request),
+            |fill| serde_json::json!(fill),
+        )
+        .await
+    }
+
+    pub(crate) async fn replace_unjournaled(
+        &self,
+        order_id: &str,
+        token_id: &str,
+        request: OrderRequest,
+    ) -> Result<OrderFill, OrderError> {
+        self.ensure_trading()?;
+        self.breaker.admit()?;
+        let token = parse_token(token_id)?;
+        let order = self.check_limit(token_id, token, request)?;
+
+        if self.is_dry_run() {
+            let outcome = self.paper_cancel(&[order_id]).remove(0);
+            if outcome.status != CancelStatus::Cancelled {
+                return Err(OrderError::NotCancelled(format!(
+                    "{} ({})",
+                    order_id, outcome.reason
+                )));
+            }
+            self.risk.on_closed([order_id]);
+            let resp = self.paper_post(&order).await?;
+            return Ok(self.settle_limit(order, resp));
+        }
+        let (endpoint, client) = self.order_client();
+        let resp = self
+            .timed(
+                Op::Cancel,
+                self.limited(CallClass::Order, async {
+                    let signed = self.sign_limit(client, &order).await?;
+                    let cancelled = client.cancel_order(order_id).await?;
+
+                    Ok::<_, anyhow::Error>((signed, cancelled))
+                }),
+            )
+            .await;
+        let (signed, cancelled) = self.routed(endpoint, resp).map_err(OrderError::Api)?;
+        let outcome = cancels::outcomes(cancelled)
+            .into_iter()
+            .find(|outcome| outcome.order_id == order_id);
+        if outcome
+            .as_ref()
+            .is_some_and(|outcome| !outcome.is_failure())
+        {
+            self.risk.on_closed([order_id]);
+        }
+        match outcome {
+            Some(outcome) if outcome.status == CancelStatus::Cancelled => {}
+            Some(outcome) => {
+                return Err(OrderError::NotCancelled(format!(
+                    "{} ({})",
+                    order_id, outcome.reason
+                )))
+            }
+            None => return Err(OrderError::NotCancelled(order_id.to_string())),
+        }
+
+        let result = self
+            .timed(
+                Op::OrderPost,
+                self.retried("REPLACE", || {
+                    self.limited(CallClass::Order, client.post_order(signed.clone()))
+                }),
+            )
+            .await;
+        let resp = self.routed(endpoint, result).map_err(OrderError::Api)?;
+        Ok(self.settle_limit(order, resp.into()))
+    }
+}
@@ -0,0 +1,260 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Market resolution status
+//!
+//! A market is settled through the UMA oracle: an outcome is proposed, may
+//! be disputed (and proposed again), and once it stands the market resolves
+//! and each share pays out. Gamma reports where a market is in that process
+//! and, once resolved, the final outcome prices. `ResolutionWatcher` polls
+//! the watched markets in batches and reports each change of status, so a
+//! host can flatten positions on a proposal or dispute and redeem them once
+//! the market resolved.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::gamma::normalize_condition_id;
+use crate::schema::{self, DriftLog};
+use crate::timeouts::CallClass;
+use crate::{OrderError, Trader, DECIMAL_SCALE};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionStatus {
+    /// Trading, no outcome proposed (or a disputed proposal not yet replaced)
+    Open,
+    /// Outcome proposed to the oracle, final unless disputed
+    Proposed,
+    /// Proposal disputed, outcome pending the oracle's vote
+    Disputed,
+    /// Outcome final and payouts known
+    Resolved,
+}
+
+/// Where a market is in its settlement
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Resolution {
+    /// 0x-prefixed, lowercase
+    pub condition_id: String,
+    pub status: ResolutionStatus,
+    /// Token paying 1 USDC a share once resolved; empty before, and for a
+    /// market settled 50-50
+    pub winning_token_id: String,
+    /// Its outcome name ("Yes", "No", ...), empty likewise or if Gamma has
+    /// no names
+    pub winning_outcome: String,
+    /// What a YES share pays once resolved, raw (6 decimals); 0 before
+    pub yes_payout_raw: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GammaResolution {
+    condition_id: String,
+    /// YES first, like the outcomes and their prices
+    #[serde(default, deserialize_with = "schema::lenient_id_list")]
+    clob_token_ids: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "schema::lenient_id_list")]
+    outcomes: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "schema::lenient_id_list")]
+    outcome_prices: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "schema::lenient_bool")]
+    closed: Option<bool>,
+    #[serde(default)]
+    uma_resolution_status: Option<String>,
+}
+
+impl GammaResolution {
+    fn into_resolution(self) -> Option<Resolution> {
+        let condition_id = normalize_condition_id(&self.condition_id)?;
+        let [yes, no] = <[String; 2]>::try_from(self.clob_token_ids?).ok()?;
+        // Prices are final payouts only once settled: 0, 1 or a 50-50 split
+        let payout = self.outcome_prices.and_then(|prices| {
+            let [yes, no] = <[String; 2]>::try_from(prices).ok()?;
+            let (yes, no) = (yes.parse::<f64>().ok()?, no.parse::<f64>().ok()?);
+            let settled = |p: f64| [0.0, 0.5, 1.0].iter().any(|v| (p - v).abs() < 1e-9);
+            (settled(yes) && settled(no) && (yes + no - 1.0).abs() < 1e-9).then_some(yes)
+        });
+        let proposal = self.uma_resolution_status.as_deref().unwrap_or_default();
+        let resolved = proposal == "resolved" || self.closed.unwrap_or(false);
+
+        let mut resolution = Resolution {
+            condition_id,
+            status: match proposal {
+                _ if resolved && payout.is_some() => ResolutionStatus::Resolved,
+                // Resolved on the oracle, payouts not on Gamma yet
+                "resolved" | "proposed" => ResolutionStatus::Proposed,
+                "disputed" => ResolutionStatus::Disputed,
+                _ => ResolutionStatus::Open,
+            },
+            winning_token_id: String::new(),
+            winning_outcome: String::new(),
+            yes_payout_raw: 0,
+        };
+        if resolution.status == ResolutionStatus::Resolved {
+            let yes_payout = payout.unwrap_or_default();
+            resolution.yes_payout_raw = (yes_payout * DECIMAL_SCALE as f64).round() as i64;
+            let winner = match yes_payout {
+                p if p > 0.75 => Some((yes, 0)),
+                p if p < 0.25 => Some((no, 1)),
+                _ => None,
+            };
+            if let Some((token_id, index)) = winner {
+                resolution.winning_token_id = token_id;
+                resolution.winning_outcome = self
+                    .outcomes
+                    .filter(|o| o.len() == 2)
+                    .map(|mut o| o.swap_remove(index))
+                    .unwrap_or_default();
+            }
+        }
+        Some(resolution)
+    }
+}
+
+/// Drift source of Gamma resolution responses
+const SOURCE: &str = "gamma.resolution";
+
+/// Most markets queried per Gamma request
+const BATCH_SIZE: usize = 20;
+
+/// Resolution status of the binary markets of a Gamma /markets response
+/// Entries that do not parse, or lack a valid condition id or two outcome
+/// tokens, are recorded in `drift` and skipped
+pub fn parse_resolutions(body: serde_json::Value, drift: &DriftLog) -> Vec<Resolution> {
+    let entries: Vec<GammaResolution> = schema::parse_entries(SOURCE, body, drift);
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            let condition_id = entry.condition_id.clone();
+            let resolution = entry.into_resolution();
+            if resolution.is_none() {
+                let error = "no valid condition id or outcome token pair";
+                drift.record(SOURCE, error, &condition_id);
+            }
+            resolution
+        })
+        .collect()
+}
+
+/// Markets watched for resolution and the status last reported of each
+#[derive(Default)]
+pub struct ResolutionWatcher {
+    /// None until the first poll that returned the market
+    markets: Mutex<HashMap<String, Option<ResolutionStatus>>>,
+}
+
+impl ResolutionWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watch a market (condition id); watching it again changes nothing
+    pub fn watch(&self, condition_id: &str) -> Result<(), OrderError> {
+        let condition_id = normalize_condition_id(condition_id).ok_or(OrderError::InvalidToken)?;
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        markets.entry(condition_id).or_default();
+        Ok(())
+    }
+
+    /// Stop watching a market; false if it was not watched
+    pub fn unwatch(&self, condition_id: &str) -> bool {
+        let Some(condition_id) = normalize_condition_id(condition_id) else {
+            return false;
+        };
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        markets.remove(&condition_id).is_some()
+    }
+
+    /// Number of markets watched
+    pub fn watched(&self) -> usize {
+        self.markets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Resolutions of watched markets whose status changed since last
+    /// reported; a market first seen open is not reported
+    /// A resolved market is reported once and no longer watched
+    pub fn changes(&self, resolutions: Vec<Resolution>) -> Vec<Resolution> {
+        let mut markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+        let mut changed = Vec::new();
+        for resolution in resolutions {
+            let Some(last) = markets.get_mut(&resolution.condition_id) else {
+                continue;
+            };
+            let first_open = last.is_none() && resolution.status == ResolutionStatus::Open;
+            if *last == Some(resolution.status) || first_open {
+                *last = Some(resolution.status);
+                continue;
+            }
+            *last = Some(resolution.status);
+            if resolution.status == ResolutionStatus::Resolved {
+                markets.remove(&resolution.condition_id);
+            }
+            changed.push(resolution);
+        }
+        changed
+    }
+
+    /// Query every watched market once, reporting status changes
+    pub async fn poll(&self, trader: &Trader, mut on_change: impl FnMut(&Resolution)) {
+        let condition_ids: Vec<String> = {
+            let markets = self.markets.lock().unwrap_or_else(|e| e.into_inner());
+            markets.keys().cloned().collect()
+        };
+        if condition_ids.is_empty() {
+            return;
+        }
+        match trader.market_resolutions(&condition_ids).await {
+            Ok(resolutions) => self.changes(resolutions).iter().for_each(&mut on_change),
+            Err(e) => warn!("[RESOLUTION] poll failed | {}", e),
+        }
+    }
+}
+
+impl Trader {
+    /// Resolution status of markets by condition id, batched into as few
+    /// Gamma requests as the query length allows
+    /// Ids Gamma does not know are left out; malformed ones are InvalidToken
+    pub async fn market_resolutions(
+        &self,
+        condition_ids: &[String],
+    ) -> Result<Vec<Resolution>, OrderError> {
+        let condition_ids = condition_ids
+            .iter()
+            .map(|id| normalize_condition_id(id).ok_or(OrderError::InvalidToken))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut resolutions = Vec::with_capacity(condition_ids.len());
+        for batch in condition_ids.chunks(BATCH_SIZE) {
+            let query: Vec<String> = batch
+                .iter()
+                .map(|id| format!("condition_ids={}", id))
+                .collect();
+            let url = format!(
+                "{}/markets?limit={}&{}",
+                self.options.gamma_host.trim_end_matches('/'),
+                batch.len(),
+                query.join("&")
+            );
+            let body = self
+                .limited(CallClass::MarketData, async {
+                    let resp = self.http.get(&url).send().await?.error_for_status()?;
+                    resp.json::<serde_json::Value>().await
+                })
+                .await
+                .map_err(OrderError::Api)?;
+            resolutions.extend(parse_resolutions(body, &self.drift));
+        }
+        Ok(resolutions)
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Retries of order calls that failed in transit
+//!
+//! A post or cancel that timed out, lost its connection, was throttled by
+//! the venue (429) or hit a 5xx is sent again after `base_delay`, doubling
+//! per retry up to `max_delay`, at most `max_retries` times. Off (0 retries)
+//! until set. Each attempt takes its own rate budget and timeout; a call
+//! refused by the local rate limit or by the venue is not retried.
+//!
+//! Posts are safe to resend: an order is signed once and every attempt
+//! sends that same signed payload. The venue knows an order by its hash,
+//! so a resend of an order that did land cannot rest a second time.
+//! Cancels are idempotent by nature.
+
+use std::future::Future;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use tracing::warn;
+
+use crate::rate_limit::RateLimited;
+use crate::timeouts::CallClass;
+use crate::venue_error::{classify, ApiFailure};
+use crate::{with_timeout, Trader};
+
+/// Longest delay accepted between two attempts
+pub const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts after the first; 0 turns retries off
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Cap of the doubling delay
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry `retry` (0 for the first)
+    fn delay(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(1u32.checked_shl(retry).unwrap_or(u32::MAX))
+            .min(self.max_delay)
+    }
+}
+
+/// Whether a failed call may succeed if sent again unchanged
+pub(crate) fn is_transient(e: &anyhow::Error) -> bool {
+    if e.chain().any(|cause| cause.is::<RateLimited>()) {
+        return false;
+    }
+    matches!(
+        classify(e),
+        ApiFailure::Network | ApiFailure::RateLimited | ApiFailure::Server
+    )
+}
+
+pub(crate) struct Retries(RwLock<RetryPolicy>);
+
+impl Retries {
+    pub(crate) fn new() -> Self {
+        Self(RwLock::new(RetryPolicy::default()))
+    }
+
+    fn get(&self) -> RetryPolicy {
+        self.0.read().map(|policy| *policy).unwrap_or_default()
+    }
+
+    pub(crate) fn inherit(&self, from: &Retries) {
+        if let Ok(mut policy) = self.0.write() {
+            *policy = from.get();
+        }
+    }
+}
+
+impl Trader {
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retries.get()
+    }
+
+    /// Retry order calls by `policy` from now on
+    /// Err if a delay is zero while retrying, or over `MAX_RETRY_DELAY`
+    pub fn set_retry_policy(&self, policy: RetryPolicy) -> Result<(), String> {
+        if policy.max_retries > 0 && policy.base_delay.is_zero() {
+            return Err("base delay must be above 0".to_string());
+        }
+        if policy.base_delay > MAX_RETRY_DELAY || policy.max_delay > MAX_RETRY_DELAY {
+            return Err(format!("delay over {:?}", MAX_RETRY_DELAY));
+        }
+        if policy.max_delay < policy.base_delay {
+            return Err("max delay below base delay".to_string());
+        }
+        if let Ok(mut slot) = self.retries.0.write() {
+            *slot = policy;
+        }
+        Ok(())
+    }
+
+    /// Run `call` until it succeeds, fails for good or runs out of retries
+    pub(crate) async fn retried<T, Fut>(
+        &self,
+        op: &str,
+        mut call: impl FnMut() -> Fut,
+    ) -> anyhow::Result<T>
+    where
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let policy = self.retry_policy();
+        let mut retry = 0;
+        loop {
+            match call().await {
+                Err(e) if retry < policy.max_retries && is_transient(&e) => {
+                    let delay = policy.delay(retry);
+                    retry += 1;
+                    warn!(
+                        "[RETRY] {} attempt {} failed, retrying in {:?} | {:#}",
+                        op, retry, delay, e
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sign with `sign`, then post what it signed with `post`, resending the
+    /// same payload on transient failures
+    pub(crate) async fn signed_post<S, T, E, Fut>(
+        &self,
+        op: &str,
+        sign: impl Future<Output = anyhow::Result<S>>,
+        post: impl Fn(S) -> Fut,
+    ) -> anyhow::Result<T>
+    where
+        S: Clone,
+        E: Into<anyhow::Error>,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let signed = with_timeout(self.timeout(CallClass::Order), sign).await?;
+        self.retried(op, || self.limited(CallClass::Order, post(signed.clone())))
+            .await
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Liquidity rewards program
+//!
+//! Resting orders earn rewards only while they "score": close enough to the
+//! midpoint and large enough for the market's reward config. Market makers
+//! check their quotes here and adjust spreads until they do.
+//!
+//! Rewards are paid per market per epoch (one UTC day); `rewards_earnings`
+//! reports them so reward income can sit next to trading PnL.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::timeouts::CallClass;
+use crate::{decimal_to_raw, Trader};
+
+/// Most order ids the venue accepts in one scoring query
+pub const MAX_SCORING_IDS: usize = 100;
+
+/// Longest date range of one earnings query (days, inclusive)
+pub const MAX_EARNINGS_DAYS: i64 = 31;
+
+/// Rewards earned on one market in one epoch
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RewardEarning {
+    /// Epoch (UTC day), YYYY-MM-DD
+    pub date: String,
+    pub condition_id: String,
+    /// Token the rewards are paid in
+    pub asset_address: String,
+    /// Raw amount (6 decimals)
+    pub earnings_raw: i64,
+    /// Asset price in USDC when paid
+    pub asset_rate: f64,
+}
+
+impl Trader {
+    /// Whether each resting order currently qualifies for rewards
+    /// Ids the venue does not know are reported as not scoring
+    pub async fn orders_scoring(
+        &self,
+        order_ids: &[&str],
+    ) -> anyhow::Result<HashMap<String, bool>> {
+        let mut scoring = HashMap::with_capacity(order_ids.len());
+        for chunk in order_ids.chunks(MAX_SCORING_IDS) {
+            let resp = self
+                .limited(CallClass::Account, self.client.are_orders_scoring(chunk))
+                .await?;
+            scoring.extend(resp);
+        }
+        for id in order_ids {
+            scoring.entry(id.to_string()).or_insert(false);
+        }
+        Ok(scoring)
+    }
+
+    /// Rewards earned from `from` to `to` (inclusive), per market and epoch
+    /// Epochs with no earnings are absent
+    pub async fn rewards_earnings(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> anyhow::Result<Vec<RewardEarning>> {
+        let days = (to - from).num_days() + 1;
+        if !(1..=MAX_EARNINGS_DAYS).contains(&days) {
+            anyhow::bail!(
+                "date range must be 1 to {} days, got {} to {}",
+                MAX_EARNINGS_DAYS,
+                from,
+                to
+            );
+        }
+
+        let mut earnings = Vec::new();
+        for date in from.iter_days().take(days as usize) {
+            let day = self
+                .limited(
+                    CallClass::Account,
+                    self.client.earnings_for_user_for_day(date),
+                )
+                .await?;
+            earnings.extend(day.into_iter().map(|e| RewardEarning {
+                date: date.to_string(),
+                condition_id: e.condition_id.to_string(),
+                asset_address: e.asset_address.to_string(),
+                earnings_raw: decimal_to_raw(e.earnings),
+                asset_rate: e.asset_rate.try_into().unwrap_or(0.0),
+            }));
+        }
+        Ok(earnings)
+    }
+}
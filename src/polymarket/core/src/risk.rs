@@ -0,0 +1,433 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Pre-trade risk limits
+//!
+//! Limits live behind an `Arc` swap so they can be replaced at runtime
+//! (from a JSON file or the host) while orders are being checked.
+//! Every accepted change bumps a version and is written to the audit log.
+//!
+//! Open orders are counted locally: an order is counted once it rests on the
+//! book after placement, and no longer once a cancel, a replace or a status
+//! lookup says it is gone, or a full open orders listing leaves it out. An
+//! order that fills on the book stays counted until one of those.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use polymarket_client_sdk::clob::types::Side;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// Risk limits, human units (USDC, shares, price 0-1)
+/// Zero means "no limit" for caps and rates
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RiskLimits {
+    /// Max USDC notional of a single order
+    pub max_order_usdc: f64,
+    /// Max shares held per token
+    pub max_position_shares: f64,
+    /// Lowest limit price accepted
+    pub min_price: f64,
+    /// Highest limit price accepted
+    pub max_price: f64,
+    /// Max orders sent per second across all tokens
+    pub max_orders_per_sec: u32,
+    /// Max orders resting on the book at once
+    pub max_open_orders: u32,
+    /// Only these token ids may be traded (empty = any)
+    pub allowed_tokens: BTreeSet<String>,
+    /// These token ids may never be traded, even if allowed
+    pub blocked_tokens: BTreeSet<String>,
+}
+
+impl Default for RiskLimits {
+    fn default() -> Self {
+        Self {
+            max_order_usdc: 0.0,
+            max_position_shares: 0.0,
+            min_price: 0.0,
+            max_price: 1.0,
+            max_orders_per_sec: 0,
+            max_open_orders: 0,
+            allowed_tokens: BTreeSet::new(),
+            blocked_tokens: BTreeSet::new(),
+        }
+    }
+}
+
+impl RiskLimits {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.max_order_usdc.is_finite() || self.max_order_usdc < 0.0 {
+            return Err(format!(
+                "max_order_usdc must be >= 0, got {}",
+                self.max_order_usdc
+            ));
+        }
+        if !self.max_position_shares.is_finite() || self.max_position_shares < 0.0 {
+            return Err(format!(
+                "max_position_shares must be >= 0, got {}",
+                self.max_position_shares
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.min_price) || !(0.0..=1.0).contains(&self.max_price) {
+            return Err(format!(
+                "price band must be within [0, 1], got [{}, {}]",
+                self.min_price, self.max_price
+            ));
+        }
+        if self.min_price > self.max_price {
+            return Err(format!(
+                "min_price {} above max_price {}",
+                self.min_price, self.max_price
+            ));
+        }
+        if let Some(bad) = self
+            .allowed_tokens
+            .iter()
+            .chain(&self.blocked_tokens)
+            .find(|t| t.is_empty() || !t.bytes().all(|b| b.is_ascii_digit()))
+        {
+            return Err(format!("token id must be numeric, got {:?}", bad));
+        }
+        Ok(())
+    }
+
+    /// Allowlist/blocklist check
+    fn check_token(&self, token: &str) -> Result<(), String> {
+        if self.blocked_tokens.contains(token) {
+            return Err("token is in blocked_tokens".to_string());
+        }
+        if !self.allowed_tokens.is_empty() && !self.allowed_tokens.contains(token) {
+            return Err("token is not in allowed_tokens".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Venue share step
+const SHARE_STEP: f64 = 0.01;
+
+/// Shares held of a token and what they cost
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    pub shares: f64,
+    /// Average entry price, fees included; None if some of the shares came
+    /// from a balance query rather than a tracked fill
+    pub avg_price: Option<f64>,
+}
+
+/// Accepted limits change, for auditing and notifications
+pub struct RiskUpdate {
+    pub version: u64,
+    pub source: String,
+    pub old: Arc<RiskLimits>,
+    pub new: Arc<RiskLimits>,
+}
+
+pub struct RiskManager {
+    limits: RwLock<Arc<RiskLimits>>,
+    /// Bumped on every accepted update
+    version: AtomicU64,
+    /// Position per token, tracked from fills and balance queries
+    positions: Mutex<HashMap<String, Position>>,
+    /// Send times of orders within the last second
+    recent_orders: Mutex<VecDeque<Instant>>,
+    /// Ids of the orders resting on the book
+    open_orders: Mutex<HashSet<String>>,
+}
+
+impl Default for RiskManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RiskManager {
+    pub fn new() -> Self {
+        Self {
+            limits: RwLock::new(Arc::new(RiskLimits::default())),
+            version: AtomicU64::new(0),
+            positions: Mutex::new(HashMap::new()),
+            recent_orders: Mutex::new(VecDeque::new()),
+            open_orders: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn limits(&self) -> Arc<RiskLimits> {
+        self.limits.read().map(|l| l.clone()).unwrap_or_default()
+    }
+
+    /// Validate and swap in new limits
+    /// On error the active limits are left untouched
+    pub fn update(&self, limits: RiskLimits, source: &str) -> Result<RiskUpdate, String> {
+        limits.validate()?;
+
+        let new = Arc::new(limits);
+        let mut guard = self
+            .limits
+            .write()
+            .map_err(|_| "risk limits lock poisoned".to_string())?;
+        let old = std::mem::replace(&mut *guard, new.clone());
+        let version = self.version.fetch_add(1, Ordering::AcqRel) + 1;
+        drop(guard);
+
+        info!(
+            target: "polymarket_executor::audit",
+            "[RISK CONFIG] version={} source={} old={} new={}",
+            version,
+            source,
+            serde_json::to_string(&*old).unwrap_or_default(),
+            serde_json::to_string(&*new).unwrap_or_default()
+        );
+        Ok(RiskUpdate {
+            version,
+            source: source.to_string(),
+            old,
+            new,
+        })
+    }
+
+    /// Set the order notional, position and open orders caps (0 = no
+    /// limit), keeping the other limits
+    pub fn set_caps(
+        &self,
+        max_order_usdc: f64,
+        max_position_shares: f64,
+        max_open_orders: u32,
+        source: &str,
+    ) -> Result<RiskUpdate, String> {
+        let limits = RiskLimits {
+            max_order_usdc,
+            max_position_shares,
+            max_open_orders,
+            ..(*self.limits()).clone()
+        };
+        self.update(limits, source)
+    }
+
+    /// Parse limits from a JSON document and apply them
+    pub fn update_json(&self, json: &str, source: &str) -> Result<RiskUpdate, String> {
+        let limits: RiskLimits =
+            serde_json::from_str(json).map_err(|e| format!("invalid risk config: {}", e))?;
+        self.update(limits, source)
+    }
+
+    /// (Re)load limits from a JSON file
+    pub fn load_file(&self, path: &str) -> Result<RiskUpdate, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+        self.update_json(&json, path)
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    /// Take over the active limits (and their version) of another manager,
+    /// plus its tracked positions and open orders when both trade the same
+    /// wallet
+    pub fn inherit(&self, from: &RiskManager, positions: bool) {
+        if let Ok(mut guard) = self.limits.write() {
+            *guard = from.limits();
+            self.version.store(from.version(), Ordering::Release);
+        }
+        if positions {
+            let held = from.positions.lock().map(|p| p.clone()).unwrap_or_default();
+            if let Ok(mut own) = self.positions.lock() {
+                *own = held;
+            }
+            let open = from
+                .open_orders
+                .lock()
+                .map(|o| o.clone())
+                .unwrap_or_default();
+            if let Ok(mut own) = self.open_orders.lock() {
+                *own = open;
+            }
+        }
+    }
+
+    /// Check an order against the active limits
+    /// `price` is None for market orders (no price band), `shares` is None when
+    /// the filled size is unknown upfront (market buy)
+    pub fn check_order(
+        &self,
+        token: &str,
+        side: Side,
+        price: Option<f64>,
+        notional_usdc: f64,
+        shares: Option<f64>,
+    ) -> Result<(), String> {
+        let limits = self.limits();
+
+        if let Err(reason) = limits.check_token(token) {
+            info!(
+                target: "polymarket_executor::audit",
+                "[RISK REJECT] token={} | {}",
+                token,
+                reason
+            );
+            return Err(reason);
+        }
+
+        if let Some(p) = price {
+            if p < limits.min_price || p > limits.max_price {
+                return Err(format!(
+                    "price {} outside band [{}, {}]",
+                    p, limits.min_price, limits.max_price
+                ));
+            }
+        }
+
+        if limits.max_order_usdc > 0.0 && notional_usdc > limits.max_order_usdc {
+            return Err(format!(
+                "notional ${:.2} above max ${:.2}",
+                notional_usdc, limits.max_order_usdc
+            ));
+        }
+
+        if limits.max_position_shares > 0.0 && side == Side::Buy {
+            let held = self.position(token);
+            let after = held + shares.unwrap_or(0.0);
+            if held >= limits.max_position_shares || after > limits.max_position_shares {
+                return Err(format!(
+                    "position {:.2} -> {:.2} above max {:.2}",
+                    held, after, limits.max_position_shares
+                ));
+            }
+        }
+
+        if limits.max_orders_per_sec > 0 {
+            let mut recent = self
+                .recent_orders
+                .lock()
+                .map_err(|_| "rate window lock poisoned".to_string())?;
+            let now = Instant::now();
+            while recent
+                .front()
+                .is_some_and(|t| now.duration_since(*t) >= Duration::from_secs(1))
+            {
+                recent.pop_front();
+            }
+            if recent.len() >= limits.max_orders_per_sec as usize {
+                return Err(format!("order rate above {}/s", limits.max_orders_per_sec));
+            }
+            recent.push_back(now);
+        }
+
+        Ok(())
+    }
+
+    /// Check that `placing` more resting orders stay within max_open_orders
+    pub fn check_open_orders(&self, placing: usize) -> Result<(), String> {
+        let max = self.limits().max_open_orders as usize;
+        if max == 0 {
+            return Ok(());
+        }
+        let open = self.open_orders();
+        if open + placing > max {
+            return Err(format!(
+                "open orders {} + {} above max {}",
+                open, placing, max
+            ));
+        }
+        Ok(())
+    }
+
+    /// Orders counted as resting on the book
+    pub fn open_orders(&self) -> usize {
+        self.open_orders.lock().map(|o| o.len()).unwrap_or(0)
+    }
+
+    /// Count `order_id` as resting on the book
+    pub fn on_resting(&self, order_id: &str) {
+        if let Ok(mut open) = self.open_orders.lock() {
+            open.insert(order_id.to_string());
+        }
+    }
+
+    /// Stop counting orders no longer on the book
+    pub fn on_closed<'a>(&self, order_ids: impl IntoIterator<Item = &'a str>) {
+        if let Ok(mut open) = self.open_orders.lock() {
+            for order_id in order_ids {
+                open.remove(order_id);
+            }
+        }
+    }
+
+    /// Replace the counted orders with a full listing from the venue
+    pub fn set_open_orders<'a>(&self, order_ids: impl IntoIterator<Item = &'a str>) {
+        if let Ok(mut open) = self.open_orders.lock() {
+            *open = order_ids.into_iter().map(str::to_string).collect();
+        }
+    }
+
+    pub fn position(&self, token: &str) -> f64 {
+        self.positions
+            .lock()
+            .ok()
+            .and_then(|p| p.get(token).map(|p| p.shares))
+            .unwrap_or(0.0)
+    }
+
+    /// Every tracked position with shares left
+    pub fn positions(&self) -> Vec<(String, Position)> {
+        let Ok(positions) = self.positions.lock() else {
+            return Vec::new();
+        };
+        positions
+            .iter()
+            .filter(|(_, p)| p.shares > 0.0)
+            .map(|(token, p)| (token.clone(), *p))
+            .collect()
+    }
+
+    /// Replace the tracked position with a value fetched from the venue
+    /// Shares beyond the tracked ones (by more than fee rounding) have no
+    /// known entry price
+    pub fn set_position(&self, token: &str, shares: f64) {
+        if let Ok(mut positions) = self.positions.lock() {
+            let held = positions.entry(token.to_string()).or_default();
+            if shares > held.shares + SHARE_STEP || shares <= 0.0 {
+                held.avg_price = None;
+            }
+            held.shares = shares;
+        }
+    }
+
+    /// Apply a fill of `shares` at `price` (per share, fees included) to the
+    /// tracked position
+    pub fn on_fill(&self, token: &str, side: Side, shares: f64, price: f64) {
+        if let Ok(mut positions) = self.positions.lock() {
+            let held = positions.entry(token.to_string()).or_default();
+            match side {
+                Side::Buy => {
+                    let total = held.shares + shares;
+                    held.avg_price = match held.avg_price {
+                        _ if total <= 0.0 => None,
+                        _ if held.shares <= 0.0 => Some(price),
+                        Some(avg) => Some((avg * held.shares + price * shares) / total),
+                        None => None,
+                    };
+                    held.shares = total;
+                }
+                Side::Sell => {
+                    held.shares = (held.shares - shares).max(0.0);
+                    if held.shares <= 0.0 {
+                        held.avg_price = None;
+                    }
+                }
+            }
+        }
+    }
+}
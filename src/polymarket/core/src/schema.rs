@@ -0,0 +1,208 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Tolerant parsing of venue responses and schema-drift detection
+//!
+//! Responses the crate decodes itself are parsed leniently: unknown fields
+//! are ignored, optional fields may be missing or null, and a list entry
+//! that does not parse is skipped instead of failing the whole response.
+//! Every such skip is drift: the venue API changed under us. So is an SDK
+//! call that failed to decode its response. `DriftLog` counts drift per
+//! source and logs each new error once, with a sample of the JSON, so a
+//! venue change shows up in the logs before it shows up as failed orders.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::Trader;
+
+/// Longest JSON sample kept and logged, in bytes
+pub const SAMPLE_LEN: usize = 256;
+
+/// Distinct errors logged per source; later ones are only counted
+const MAX_LOGGED_PER_SOURCE: usize = 16;
+
+/// Drift seen from one source, as reported by `DriftLog::report`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DriftReport {
+    /// Response kind, e.g. "gamma.markets"
+    pub source: String,
+    pub count: u64,
+    pub last_error: String,
+    /// Start of the JSON that failed to parse; empty if the SDK decoded it
+    pub last_sample: String,
+    pub last_at_ms: u64,
+}
+
+#[derive(Default)]
+struct Source {
+    report: DriftReport,
+    logged: HashSet<String>,
+}
+
+#[derive(Default)]
+pub struct DriftLog {
+    sources: Mutex<HashMap<&'static str, Source>>,
+}
+
+impl DriftLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a response from `source` that did not match the expected shape
+    pub fn record(&self, source: &'static str, error: &str, sample: &str) {
+        let mut sources = self.sources.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = sources.entry(source).or_default();
+        entry.report.source = source.to_string();
+        entry.report.count += 1;
+        entry.report.last_error = error.to_string();
+        entry.report.last_sample = truncate(sample).to_string();
+        entry.report.last_at_ms = chrono::Utc::now().timestamp_millis() as u64;
+        if entry.logged.len() < MAX_LOGGED_PER_SOURCE && entry.logged.insert(error.to_string()) {
+            warn!(
+                "[SCHEMA DRIFT] source={} count={} error={} sample={}",
+                source,
+                entry.report.count,
+                error,
+                truncate(sample)
+            );
+        }
+    }
+
+    /// Count a failed SDK call if it failed to decode the response
+    pub(crate) fn record_sdk<T>(
+        &self,
+        source: &'static str,
+        result: anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        if let Err(e) = &result {
+            if is_decode_error(e) {
+                self.record(source, &e.to_string(), "");
+            }
+        }
+        result
+    }
+
+    /// Drift per source, by source name
+    pub fn report(&self) -> Vec<DriftReport> {
+        let sources = self.sources.lock().unwrap_or_else(|e| e.into_inner());
+        let mut report: Vec<DriftReport> = sources.values().map(|s| s.report.clone()).collect();
+        report.sort_by(|a, b| a.source.cmp(&b.source));
+        report
+    }
+
+    /// Keep what the session being replaced saw
+    #[cfg(feature = "execution")]
+    pub(crate) fn extend_from(&self, other: &DriftLog) {
+        let other = other.sources.lock().unwrap_or_else(|e| e.into_inner());
+        let mut sources = self.sources.lock().unwrap_or_else(|e| e.into_inner());
+        for (source, entry) in other.iter() {
+            sources.entry(*source).or_insert_with(|| Source {
+                report: entry.report.clone(),
+                logged: entry.logged.clone(),
+            });
+        }
+    }
+}
+
+/// A response body the SDK could not decode
+pub fn is_decode_error(e: &anyhow::Error) -> bool {
+    e.chain().any(|cause| {
+        cause.is::<serde_json::Error>()
+            || cause
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(reqwest::Error::is_decode)
+    })
+}
+
+/// At most SAMPLE_LEN bytes, cut on a char boundary
+fn truncate(s: &str) -> &str {
+    if s.len() <= SAMPLE_LEN {
+        return s;
+    }
+    let mut end = SAMPLE_LEN;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Parse each entry of a JSON array on its own; entries that do not parse
+/// are recorded as drift and skipped, and so is a body that is no array
+pub fn parse_entries<T: DeserializeOwned>(
+    source: &'static str,
+    body: Value,
+    drift: &DriftLog,
+) -> Vec<T> {
+    let Value::Array(entries) = body else {
+        drift.record(source, "expected an array", &body.to_string());
+        return Vec::new();
+    };
+    entries
+        .into_iter()
+        .filter_map(|entry| match T::deserialize(&entry) {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                drift.record(source, &e.to_string(), &entry.to_string());
+                None
+            }
+        })
+        .collect()
+}
+
+/// A bool, or its string form; None for null or anything else
+pub(crate) fn lenient_bool<'de, D: Deserializer<'de>>(d: D) -> Result<Option<bool>, D::Error> {
+    Ok(match Value::deserialize(d)? {
+        Value::Bool(b) => Some(b),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    })
+}
+
+/// A list of strings (ids, outcome names), as an array or as a JSON-encoded
+/// array in a string; None for anything else (ids too long for a JSON number
+/// included)
+pub(crate) fn lenient_id_list<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<Vec<String>>, D::Error> {
+    let items = match Value::deserialize(d)? {
+        Value::Array(items) => items,
+        Value::String(s) => match serde_json::from_str(&s) {
+            Ok(Value::Array(items)) => items,
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(items
+        .into_iter()
+        .map(|item| match item {
+            Value::String(s) => Some(s),
+            _ => None,
+        })
+        .collect())
+}
+
+impl Trader {
+    /// Drift seen in venue responses this session, by source
+    pub fn schema_drift(&self) -> Vec<DriftReport> {
+        self.drift.report()
+    }
+
+    /// Where responses parsed outside the SDK record drift, e.g. market
+    /// channel messages
+    pub fn drift_log(&self) -> &DriftLog {
+        &self.drift
+    }
+}
@@ -0,0 +1,331 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Append-only log of every signed order, written before it is posted
+//!
+//! One JSON object per line: sequence number, timestamp, signer and funder
+//! addresses, salt, keccak256 of the exact payload sent and the payload
+//! itself. The venue order id (EIP-712 order hash) can be recomputed from
+//! the payload.
+//!
+//! Lines are hash-chained: `prev` holds the keccak256 of the previous line's
+//! bytes, so editing, dropping or reordering any line breaks the chain at the
+//! next one (`verify`). The hash of the newest line is also logged on every
+//! append, anchoring the tail in the regular log.
+//!
+//! Lines reach the OS before the order is sent; they survive a process crash
+//! but are only fsynced by `sync` (on shutdown).
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use alloy::primitives::{keccak256, B256};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use tracing::info;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignedLogError {
+    #[error("signed log: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("signed log: cannot encode payload: {0}")]
+    Encode(#[from] serde_json::Error),
+    #[error("signed log corrupt at line {line}: {reason}")]
+    Corrupt { line: u64, reason: String },
+}
+
+/// One line as written
+#[derive(Serialize)]
+struct Entry<'a> {
+    seq: u64,
+    ts_ms: u64,
+    op: &'a str,
+    token_id: &'a str,
+    signer: String,
+    funder: String,
+    salt: Option<&'a RawValue>,
+    payload_hash: String,
+    payload: &'a RawValue,
+    prev: String,
+}
+
+/// Chain fields of a line as read back
+#[derive(Deserialize)]
+struct Link {
+    seq: u64,
+    prev: String,
+}
+
+/// Wire shape of a posted order, only what is copied out of it
+#[derive(Deserialize)]
+struct Wire<'a> {
+    #[serde(borrow)]
+    order: Option<WireOrder<'a>>,
+}
+
+#[derive(Deserialize)]
+struct WireOrder<'a> {
+    #[serde(borrow)]
+    salt: Option<&'a RawValue>,
+}
+
+/// Signed order about to be posted
+pub struct SignedRecord<'a> {
+    pub op: &'a str,
+    pub token_id: &'a str,
+    pub signer: String,
+    pub funder: String,
+    /// The order exactly as serialized for the venue
+    pub payload: &'a [u8],
+}
+
+struct Chain {
+    file: File,
+    /// Sequence number of the next line
+    seq: u64,
+    /// Hash of the last line written
+    prev: B256,
+}
+
+pub struct SignedLog {
+    path: PathBuf,
+    chain: Mutex<Chain>,
+}
+
+impl SignedLog {
+    /// Open (or create) the log and continue its chain
+    /// Fails if the existing file does not end in a well-formed line
+    pub fn open(path: &Path) -> Result<Self, SignedLogError> {
+        let (seq, prev) = match File::open(path) {
+            Ok(file) => tail(file)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (0, B256::ZERO),
+            Err(e) => return Err(e.into()),
+        };
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        info!(
+            "[SIGNED LOG] opened {} | next seq={} prev={}",
+            path.display(),
+            seq,
+            prev
+        );
+        Ok(Self {
+            path: path.to_path_buf(),
+            chain: Mutex::new(Chain { file, seq, prev }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a record; the order must not be sent if this fails
+    pub fn append(&self, record: &SignedRecord) -> Result<(), SignedLogError> {
+        let payload: &RawValue = serde_json::from_slice(record.payload)?;
+        let salt = serde_json::from_slice::<Wire>(record.payload)
+            .ok()
+            .and_then(|wire| wire.order)
+            .and_then(|order| order.salt);
+        let ts_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+
+        let mut chain = self
+            .chain
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let entry = Entry {
+            seq: chain.seq,
+            ts_ms,
+            op: record.op,
+            token_id: record.token_id,
+            signer: record.signer.clone(),
+            funder: record.funder.clone(),
+            salt,
+            payload_hash: keccak256(record.payload).to_string(),
+            payload,
+            prev: chain.prev.to_string(),
+        };
+        let mut line = serde_json::to_vec(&entry)?;
+        let hash = keccak256(&line);
+        line.push(b'\n');
+        // One write on an O_APPEND file: lines never interleave
+        chain.file.write_all(&line)?;
+
+        info!(
+            "[SIGNED LOG] seq={} op={} token={} hash={}",
+            chain.seq, record.op, record.token_id, hash
+        );
+        chain.seq += 1;
+        chain.prev = hash;
+        Ok(())
+    }
+
+    /// Flush every line written so far to disk
+    pub fn sync(&self) -> Result<(), SignedLogError> {
+        let chain = self
+            .chain
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        chain.file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// Check the whole chain of a log file
+/// Returns the number of lines and the hash of the last one
+pub fn verify(path: &Path) -> Result<(u64, B256), SignedLogError> {
+    let mut prev = B256::ZERO;
+    let mut count = 0u64;
+    for line in BufReader::new(File::open(path)?).split(b'\n') {
+        let line = line?;
+        let link = parse_link(&line, count + 1)?;
+        check_link(&link, count, prev, count + 1)?;
+        prev = keccak256(&line);
+        count += 1;
+    }
+    Ok((count, prev))
+}
+
+/// Sequence number and hash following the last line of an existing log
+fn tail(file: File) -> Result<(u64, B256), SignedLogError> {
+    let mut last = None;
+    let mut count = 0u64;
+    for line in BufReader::new(file).split(b'\n') {
+        last = Some(line?);
+        count += 1;
+    }
+    match last {
+        None => Ok((0, B256::ZERO)),
+        Some(line) => {
+            let link = parse_link(&line, count)?;
+            if link.seq + 1 != count {
+                return Err(SignedLogError::Corrupt {
+                    line: count,
+                    reason: format!("seq {} on line {}", link.seq, count),
+                });
+            }
+            Ok((count, keccak256(&line)))
+        }
+    }
+}
+
+fn parse_link(line: &[u8], number: u64) -> Result<Link, SignedLogError> {
+    serde_json::from_slice(line).map_err(|e| SignedLogError::Corrupt {
+        line: number,
+        reason: e.to_string(),
+    })
+}
+
+fn check_link(link: &Link, seq: u64, prev: B256, number: u64) -> Result<(), SignedLogError> {
+    if link.seq != seq {
+        return Err(SignedLogError::Corrupt {
+            line: number,
+            reason: format!("seq {}, expected {}", link.seq, seq),
+        });
+    }
+    if link.prev != prev.to_string() {
+        return Err(SignedLogError::Corrupt {
+            line: number,
+            reason: "previous line was altered or removed".to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fresh log path under the temp dir, unique per test
+    fn log_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("signed_log_{}_{}.jsonl", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn append(log: &SignedLog, salt: u64) {
+        let payload = format!(
+            r#"{{"order":{{"salt":{},"side":"BUY"}},"owner":"k"}}"#,
+            salt
+        );
+        log.append(&SignedRecord {
+            op: "POST",
+            token_id: "123",
+            signer: "0x01".to_string(),
+            funder: "0x02".to_string(),
+            payload: payload.as_bytes(),
+        })
+        .unwrap();
+    }
+
+    fn corrupt_line(path: &Path) -> u64 {
+        match verify(path) {
+            Err(SignedLogError::Corrupt { line, .. }) => line,
+            other => panic!("expected a broken chain, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn chain_verifies_and_continues_on_reopen() {
+        let path = log_path("chain");
+        let log = SignedLog::open(&path).unwrap();
+        append(&log, 1);
+        append(&log, 2);
+        drop(log);
+
+        let (count, last) = verify(&path).unwrap();
+        assert_eq!(count, 2);
+        let text = std::fs::read_to_string(&path).unwrap();
+        let second = text.lines().nth(1).unwrap();
+        assert_eq!(last, keccak256(second.as_bytes()));
+        assert!(second.contains(r#""salt":2"#));
+
+        let log = SignedLog::open(&path).unwrap();
+        append(&log, 3);
+        drop(log);
+        assert_eq!(verify(&path).unwrap().0, 3);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn tampering_breaks_the_chain() {
+        let path = log_path("tamper");
+        let log = SignedLog::open(&path).unwrap();
+        for salt in 1..=3 {
+            append(&log, salt);
+        }
+        drop(log);
+        let text = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        // An edited line is caught at the next one
+        let edited = text.replacen(r#""side":"BUY""#, r#""side":"SELL""#, 1);
+        std::fs::write(&path, edited).unwrap();
+        assert_eq!(corrupt_line(&path), 2);
+
+        // So are dropped and reordered lines
+        std::fs::write(&path, format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+        assert_eq!(corrupt_line(&path), 2);
+        std::fs::write(&path, format!("{}\n{}\n{}\n", lines[1], lines[0], lines[2])).unwrap();
+        assert_eq!(corrupt_line(&path), 1);
+
+        // A truncated tail is refused on open
+        std::fs::write(&path, format!("{}\n{{\"seq\":", lines[0])).unwrap();
+        assert!(matches!(
+            SignedLog::open(&path),
+            Err(SignedLogError::Corrupt { line: 2, .. })
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+}
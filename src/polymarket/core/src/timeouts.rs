@@ -0,0 +1,89 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Per-class timeouts of venue calls
+//!
+//! Every call to the CLOB or Gamma is bounded by the timeout of its class,
+//! `API_TIMEOUT` until set. Order calls can then fail fast on a tight
+//! budget while lookups and account reads keep a longer one. A changed
+//! timeout applies to calls started after the change.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{Trader, API_TIMEOUT};
+
+/// Shortest timeout accepted
+pub const MIN_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Longest timeout accepted
+pub const MAX_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallClass {
+    /// Order posts (single and batch) and cancels
+    Order,
+    /// Books, tick sizes and prefetch, prices, Gamma lookups, warmup
+    MarketData,
+    /// Balance, order lookups, rewards and notifications
+    Account,
+}
+
+impl CallClass {
+    pub const ALL: [CallClass; 3] = [CallClass::Order, CallClass::MarketData, CallClass::Account];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Timeout per call class, in ms
+pub(crate) struct Timeouts([AtomicU64; 3]);
+
+impl Timeouts {
+    pub(crate) fn new() -> Self {
+        let default = API_TIMEOUT.as_millis() as u64;
+        Self(std::array::from_fn(|_| AtomicU64::new(default)))
+    }
+
+    fn get(&self, class: CallClass) -> Duration {
+        Duration::from_millis(self.0[class.index()].load(Ordering::Relaxed))
+    }
+
+    fn set(&self, class: CallClass, timeout: Duration) {
+        self.0[class.index()].store(timeout.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "execution")]
+    pub(crate) fn inherit(&self, from: &Timeouts) {
+        for class in CallClass::ALL {
+            self.set(class, from.get(class));
+        }
+    }
+}
+
+impl Trader {
+    /// Timeout of calls of `class`
+    pub fn timeout(&self, class: CallClass) -> Duration {
+        self.timeouts.get(class)
+    }
+
+    /// Bound calls of `class` started from now on by `timeout`
+    /// Err if outside `MIN_TIMEOUT` - `MAX_TIMEOUT`
+    pub fn set_timeout(&self, class: CallClass, timeout: Duration) -> Result<(), String> {
+        if !(MIN_TIMEOUT..=MAX_TIMEOUT).contains(&timeout) {
+            return Err(format!(
+                "timeout must be {:?} - {:?}",
+                MIN_TIMEOUT, MAX_TIMEOUT
+            ));
+        }
+        self.timeouts.set(class, timeout);
+        Ok(())
+    }
+}
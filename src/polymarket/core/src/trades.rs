@@ -0,0 +1,146 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Fills of the account from the CLOB trades history
+//!
+//! `Trader::trades` recovers the fills of our orders since a time, also
+//! those matched while no user channel was connected. A trade lists the
+//! taker order and every maker order it matched: it is our fill as taker
+//! when `trader_side` says so, and one fill per maker order of ours (owned
+//! by our API key). Pages are fetched like `open_orders`, and a history
+//! longer than `MAX_PAGES` pages is an error rather than returned in part.
+
+use polymarket_client_sdk::clob::types::request::TradesRequest;
+use polymarket_client_sdk::clob::types::response::TradeResponse;
+use polymarket_client_sdk::clob::types::Side;
+
+use crate::open_orders::{END_CURSOR, MAX_PAGES};
+use crate::orders::taker_fee_factor;
+use crate::timeouts::CallClass;
+use crate::{decimal_to_raw, parse_token, OrderError, Trader};
+
+/// One fill of one of our orders
+#[derive(Clone, Debug)]
+pub struct OwnTrade {
+    pub trade_id: String,
+    pub order_id: String,
+    pub token_id: String,
+    pub side: Side,
+    pub price_raw: i64,
+    /// Shares filled
+    pub size_raw: i64,
+    /// Taker fee in shares (buys), 0 for maker fills, sells or no fee
+    pub fee_raw: i64,
+    /// Our order rested on the book when the trade matched it
+    pub maker: bool,
+    /// MATCHED, MINED, CONFIRMED, RETRYING, FAILED
+    pub status: String,
+    /// Unix ms
+    pub matched_at_ms: u64,
+}
+
+impl Trader {
+    /// Our fills matched at or after `since_ms` (unix ms), of one token or
+    /// (None) of every market, oldest first
+    pub async fn trades(
+        &self,
+        token_id: Option<&str>,
+        since_ms: u64,
+    ) -> Result<Vec<OwnTrade>, OrderError> {
+        // The venue filters by the second
+        let after = (since_ms / 1000) as i64;
+        let request = match token_id {
+            Some(token_id) => TradesRequest::builder()
+                .asset_id(parse_token(token_id)?)
+                .after(after)
+                .build(),
+            None => TradesRequest::builder().after(after).build(),
+        };
+        let owner = self.client.credentials().key.to_string();
+
+        let mut trades = Vec::new();
+        let mut cursor = None;
+        for _ in 0..MAX_PAGES {
+            let result = self
+                .limited(
+                    CallClass::Account,
+                    self.client.trades(&request, cursor.take()),
+                )
+                .await;
+            let page = self
+                .drift
+                .record_sdk("clob.trades", result)
+                .map_err(OrderError::Api)?;
+            for trade in &page.data {
+                own_fills(trade, &owner, &mut trades);
+            }
+            if page.next_cursor.is_empty() || page.next_cursor == END_CURSOR {
+                trades.retain(|trade| trade.matched_at_ms >= since_ms);
+                trades.sort_by_key(|trade| trade.matched_at_ms);
+                return Ok(trades);
+            }
+            cursor = Some(page.next_cursor);
+        }
+        Err(OrderError::Api(anyhow::anyhow!(
+            "more than {} pages of trades",
+            MAX_PAGES
+        )))
+    }
+}
+
+/// Our fills in one trade: as taker, and as each maker order of ours
+fn own_fills(trade: &TradeResponse, owner: &str, out: &mut Vec<OwnTrade>) {
+    let token_id = trade.asset_id.to_string();
+    // Sent in seconds
+    let matched_at_ms = trade.match_time.max(0) as u64 * 1000;
+    let fill = OwnTrade {
+        trade_id: trade.id.clone(),
+        order_id: String::new(),
+        token_id: token_id.clone(),
+        side: trade.side,
+        price_raw: decimal_to_raw(trade.price),
+        size_raw: decimal_to_raw(trade.size),
+        fee_raw: 0,
+        maker: false,
+        status: trade.status.clone(),
+        matched_at_ms,
+    };
+
+    if trade.trader_side.eq_ignore_ascii_case("TAKER") {
+        let fee_raw = if trade.side == Side::Buy && !trade.fee_rate_bps.is_zero() {
+            let price = fill.price_raw as f64 / 1_000_000.0;
+            (fill.size_raw as f64 * taker_fee_factor(price)) as i64
+        } else {
+            0
+        };
+        out.push(OwnTrade {
+            order_id: trade.taker_order_id.clone(),
+            fee_raw,
+            ..fill.clone()
+        });
+    }
+    for maker in trade.maker_orders.iter().filter(|m| m.owner == owner) {
+        let maker_token = maker.asset_id.to_string();
+        out.push(OwnTrade {
+            order_id: maker.order_id.clone(),
+            // The taker's side; a maker of the same token traded the other
+            // one, a maker of the complement (matched by minting) the same
+            side: match (maker_token == token_id, trade.side) {
+                (true, Side::Buy) => Side::Sell,
+                (true, _) => Side::Buy,
+                (false, side) => side,
+            },
+            token_id: maker_token,
+            price_raw: decimal_to_raw(maker.price),
+            size_raw: decimal_to_raw(maker.matched_amount),
+            maker: true,
+            ..fill.clone()
+        });
+    }
+}
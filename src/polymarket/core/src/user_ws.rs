@@ -0,0 +1,223 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Order and trade updates from the CLOB user channel
+//!
+//! `Trader::run_user_channel` authenticates `<ws_host>/ws/user` with the
+//! session's API credentials and streams every order of the account:
+//! - `order` messages: an order was placed, partly matched or cancelled
+//! - `trade` messages: a fill, sent again as it moves from MATCHED to
+//!   MINED and CONFIRMED (or RETRYING / FAILED)
+//!
+//! A trade can fill several of our orders: as taker, and as maker on each
+//! of our resting orders it crossed. Each gives its own update. Messages
+//! that do not parse are recorded as drift under "ws.user" and skipped.
+
+use flox_connector::feed::{self, Commands};
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::Decimal;
+use serde_json::Value;
+
+use crate::market_ws::{connect, decimal_field, side_field, str_field, timestamp_ms, KEEPALIVE};
+use crate::schema::DriftLog;
+use crate::Trader;
+
+/// Path of the user channel under `ws_host`
+pub const USER_CHANNEL_PATH: &str = "/ws/user";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderUpdateKind {
+    Placed,
+    /// A resting order was partly or fully matched
+    Updated,
+    Cancelled,
+    /// A fill of one of our orders
+    Trade,
+}
+
+/// One order event of the account
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderUpdate {
+    pub kind: OrderUpdateKind,
+    pub order_id: String,
+    pub token_id: String,
+    pub side: Side,
+    /// Order limit price; the fill price for trades
+    pub price: Decimal,
+    /// Order size in shares; zero for trades
+    pub original_size: Decimal,
+    /// Shares matched so far; zero for trades
+    pub size_matched: Decimal,
+    /// Shares of this fill; zero for order events
+    pub trade_size: Decimal,
+    /// Empty for order events
+    pub trade_id: String,
+    /// Trade status (MATCHED, MINED, CONFIRMED, RETRYING, FAILED); empty for
+    /// order events
+    pub trade_status: String,
+    /// Our order rested on the book when the trade matched it
+    pub maker: bool,
+    pub timestamp_ms: u64,
+}
+
+impl Trader {
+    /// Connect the user channel and hand each update of the account's
+    /// orders to `on_update` until the connection fails
+    pub async fn run_user_channel(
+        &self,
+        mut on_update: impl FnMut(OrderUpdate),
+    ) -> anyhow::Result<()> {
+        let credentials = self.client.credentials();
+        let subscribe = serde_json::json!({
+            "auth": {
+                "apiKey": credentials.key,
+                "secret": credentials.secret,
+                "passphrase": credentials.passphrase,
+            },
+            "markets": [],
+            "type": "user",
+        });
+        let url = format!("{}{}", self.options.ws_host, USER_CHANNEL_PATH);
+        let owner = credentials.key.to_string();
+
+        // Nothing to change on a live user subscription
+        let commands = Commands::new();
+        let subscriptions = || vec![subscribe.to_string()];
+        feed::run_ws(
+            &commands,
+            || connect(&url),
+            subscriptions,
+            KEEPALIVE,
+            |text| {
+                for update in parse_user_message(text, &owner, &self.drift) {
+                    on_update(update);
+                }
+                Ok(())
+            },
+        )
+        .await
+        .map_err(anyhow::Error::msg)
+    }
+}
+
+/// Updates in one user channel message; `owner` is our API key, which tells
+/// our maker orders apart from other makers' in a trade
+pub fn parse_user_message(text: &str, owner: &str, drift: &DriftLog) -> Vec<OrderUpdate> {
+    if text == "PONG" {
+        return Vec::new();
+    }
+    let body: Value = match serde_json::from_str(text) {
+        Ok(body) => body,
+        Err(e) => {
+            drift.record("ws.user", &e.to_string(), text);
+            return Vec::new();
+        }
+    };
+    let events = match body {
+        Value::Array(events) => events,
+        event => vec![event],
+    };
+    let mut updates = Vec::new();
+    for event in &events {
+        let parsed = match event.get("event_type").and_then(Value::as_str) {
+            Some("order") => order_update(event).map(|update| updates.push(update)),
+            Some("trade") => trade_updates(event, owner, &mut updates),
+            _ => Ok(()),
+        };
+        if let Err(e) = parsed {
+            drift.record("ws.user", &e, &event.to_string());
+        }
+    }
+    updates
+}
+
+fn order_update(event: &Value) -> Result<OrderUpdate, String> {
+    let kind = match str_field(event, "type")? {
+        "PLACEMENT" => OrderUpdateKind::Placed,
+        "UPDATE" => OrderUpdateKind::Updated,
+        "CANCELLATION" => OrderUpdateKind::Cancelled,
+        other => return Err(format!("unknown order event type {}", other)),
+    };
+    Ok(OrderUpdate {
+        kind,
+        order_id: str_field(event, "id")?.to_string(),
+        token_id: str_field(event, "asset_id")?.to_string(),
+        side: side_field(event)?,
+        price: decimal_field(event, "price")?,
+        original_size: decimal_field(event, "original_size")?,
+        size_matched: decimal_field(event, "size_matched").unwrap_or_default(),
+        trade_size: Decimal::ZERO,
+        trade_id: String::new(),
+        trade_status: String::new(),
+        maker: false,
+        timestamp_ms: timestamp_ms(event),
+    })
+}
+
+fn trade_updates(event: &Value, owner: &str, updates: &mut Vec<OrderUpdate>) -> Result<(), String> {
+    let token_id = str_field(event, "asset_id")?;
+    let side = side_field(event)?;
+    let trade = OrderUpdate {
+        kind: OrderUpdateKind::Trade,
+        order_id: String::new(),
+        token_id: token_id.to_string(),
+        side,
+        price: decimal_field(event, "price")?,
+        original_size: Decimal::ZERO,
+        size_matched: Decimal::ZERO,
+        trade_size: decimal_field(event, "size")?,
+        trade_id: str_field(event, "id")?.to_string(),
+        trade_status: str_field(event, "status")?.to_string(),
+        maker: false,
+        timestamp_ms: timestamp_ms(event),
+    };
+
+    let trader_side = event.get("trader_side").and_then(Value::as_str);
+    let taker = match trader_side {
+        Some(trader_side) => trader_side == "TAKER",
+        None => event.get("owner").and_then(Value::as_str) == Some(owner),
+    };
+    if taker {
+        updates.push(OrderUpdate {
+            order_id: str_field(event, "taker_order_id")?.to_string(),
+            ..trade.clone()
+        });
+    }
+    let makers = match event.get("maker_orders") {
+        Some(Value::Array(makers)) => makers.as_slice(),
+        _ => &[],
+    };
+    for maker in makers {
+        // Without an owner, trust trader_side: all makers are ours
+        let ours = match maker.get("owner").and_then(Value::as_str) {
+            Some(maker_owner) => maker_owner == owner,
+            None => trader_side == Some("MAKER"),
+        };
+        if !ours {
+            continue;
+        }
+        let maker_token = str_field(maker, "asset_id").unwrap_or(token_id);
+        updates.push(OrderUpdate {
+            order_id: str_field(maker, "order_id")?.to_string(),
+            token_id: maker_token.to_string(),
+            // The taker's side; a maker of the same token traded the other
+            // one, a maker of the complement (matched by minting) the same
+            side: match (maker_token == token_id, side) {
+                (true, Side::Buy) => Side::Sell,
+                (true, _) => Side::Buy,
+                (false, side) => side,
+            },
+            price: decimal_field(maker, "price")?,
+            trade_size: decimal_field(maker, "matched_amount")?,
+            maker: true,
+            ..trade.clone()
+        });
+    }
+    Ok(())
+}
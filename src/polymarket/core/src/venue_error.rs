@@ -0,0 +1,69 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! How a venue call failed
+//!
+//! `classify` sorts the error of a failed CLOB call: the request never got
+//! an answer (network), the venue (or the local rate limit) throttled it,
+//! failed on its side, or refused the request. The HTTP status comes from `failover::http_status`,
+//! or from the message when it spells a 4xx/5xx status ("status: 400").
+
+use serde::Serialize;
+
+use crate::endpoints::is_transport_error;
+use crate::failover::http_status;
+use crate::rate_limit::RateLimited;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiFailure {
+    /// Timeout or connection error: the venue may never have seen the call
+    Network,
+    /// 429, or refused by the local rate limit before it was sent
+    RateLimited,
+    /// 5xx
+    Server,
+    /// 4xx other than 429: the venue refused the request
+    Rejected,
+    /// Failed with nothing telling how
+    Unknown,
+}
+
+pub fn classify(e: &anyhow::Error) -> ApiFailure {
+    if e.chain().any(|cause| cause.is::<RateLimited>()) {
+        return ApiFailure::RateLimited;
+    }
+    if is_transport_error(e) {
+        return ApiFailure::Network;
+    }
+    match http_status(e).or_else(|| status_in_message(e)) {
+        Some(429) => ApiFailure::RateLimited,
+        Some(500..=599) => ApiFailure::Server,
+        Some(400..=499) => ApiFailure::Rejected,
+        _ => ApiFailure::Unknown,
+    }
+}
+
+/// 4xx/5xx status spelled in the message: "status: 400", "status code 502"
+fn status_in_message(e: &anyhow::Error) -> Option<u16> {
+    let message = format!("{:#}", e).to_ascii_lowercase();
+    ["status code ", "status: ", "status "]
+        .iter()
+        .flat_map(|prefix| {
+            message
+                .match_indices(prefix)
+                .map(|(at, _)| at + prefix.len())
+        })
+        .find_map(|start| {
+            let digits = message.get(start..start + 3)?;
+            let status: u16 = digits.parse().ok()?;
+            let ends = !message[start + 3..].starts_with(|c: char| c.is_ascii_digit());
+            ((400..=599).contains(&status) && ends).then_some(status)
+        })
+}
@@ -0,0 +1,219 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Connection warmup
+//!
+//! The first order of a session otherwise pays for DNS, TLS handshakes, the
+//! authenticated request path and the order builder's metadata lookups.
+//! A `WarmupProfile` picks which of those to pay upfront:
+//! - `resolve`: look up every configured host (gateways included), warming the system resolver
+//!   cache (the HTTP clients resolve on their own, through it)
+//! - `connections`: concurrent unauthenticated requests to the CLOB; over
+//!   HTTP/1.1 each opens a pooled connection, over HTTP/2 they share one
+//! - `signed_request`: an authenticated read (the USDC balance, which also
+//!   refreshes the cached balance)
+//! - `presign_token_id`: build and sign a throwaway order for that token,
+//!   never posted, warming the builder's lookups and the signer
+//!
+//! The last two need a key: without the `execution` feature a profile
+//! asking for them is rejected.
+//!
+//! Idle pooled connections are closed after a while; `keep_alive`, run
+//! periodically, keeps them open between orders.
+
+use std::time::Instant;
+
+use futures::future::try_join_all;
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::clob::types::{Amount, OrderType, Side};
+#[cfg(feature = "execution")]
+use polymarket_client_sdk::types::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::timeouts::CallClass;
+use crate::{parse_token, Trader};
+
+/// Most concurrent warmup requests
+pub const MAX_WARMUP_CONNECTIONS: u32 = 32;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WarmupProfile {
+    pub resolve: bool,
+    /// Concurrent CLOB requests, 1 - MAX_WARMUP_CONNECTIONS
+    pub connections: u32,
+    pub signed_request: bool,
+    pub presign_token_id: Option<String>,
+}
+
+/// Three unauthenticated requests, as warmup always did
+impl Default for WarmupProfile {
+    fn default() -> Self {
+        Self {
+            resolve: false,
+            connections: 3,
+            signed_request: false,
+            presign_token_id: None,
+        }
+    }
+}
+
+impl WarmupProfile {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let profile: Self =
+            serde_json::from_str(json).map_err(|e| format!("invalid warmup profile: {}", e))?;
+        profile.validate()?;
+        Ok(profile)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.connections == 0 || self.connections > MAX_WARMUP_CONNECTIONS {
+            return Err(format!(
+                "connections must be 1 - {}, got {}",
+                MAX_WARMUP_CONNECTIONS, self.connections
+            ));
+        }
+        if let Some(token_id) = &self.presign_token_id {
+            parse_token(token_id)
+                .map_err(|_| format!("presign_token_id is not a token id: {:?}", token_id))?;
+        }
+        if cfg!(not(feature = "execution"))
+            && (self.signed_request || self.presign_token_id.is_some())
+        {
+            return Err("signed_request and presign_token_id need the execution feature".into());
+        }
+        Ok(())
+    }
+}
+
+/// What a warmup did; step durations are None for steps not in the profile
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WarmupReport {
+    pub resolved_hosts: u32,
+    pub resolve_ms: Option<u64>,
+    pub connections_ms: u64,
+    pub signed_request_ms: Option<u64>,
+    pub presign_ms: Option<u64>,
+    pub total_ms: u64,
+}
+
+impl Trader {
+    /// Warm up the connection pool with three requests
+    /// Call this after connect to pre-establish the TLS connection
+    pub async fn warmup(&self) -> anyhow::Result<()> {
+        self.warmup_with(&WarmupProfile::default())
+            .await
+            .map(|_| ())
+    }
+
+    /// One request to the CLOB, and to each gateway, so their pooled
+    /// connections are not closed as idle and the next order pays no TLS
+    /// handshake. Fails if the CLOB request does; gateways are only logged
+    pub async fn keep_alive(&self) -> anyhow::Result<()> {
+        let ping = self.limited(CallClass::MarketData, self.client.ok());
+        #[cfg(feature = "execution")]
+        let (result, ()) = futures::join!(ping, self.ping_gateways());
+        #[cfg(not(feature = "execution"))]
+        let result = ping.await;
+        result.map(|_| ())
+    }
+
+    /// Warm up the paths selected by `profile`; fails on the first step
+    /// that fails
+    pub async fn warmup_with(&self, profile: &WarmupProfile) -> anyhow::Result<WarmupReport> {
+        profile.validate().map_err(anyhow::Error::msg)?;
+        let start = Instant::now();
+        let mut report = WarmupReport::default();
+
+        if profile.resolve {
+            let step = Instant::now();
+            report.resolved_hosts = self.resolve_hosts().await?;
+            report.resolve_ms = Some(step.elapsed().as_millis() as u64);
+        }
+
+        let step = Instant::now();
+        self.limited(
+            CallClass::MarketData,
+            try_join_all((0..profile.connections).map(|_| self.client.ok())),
+        )
+        .await?;
+        report.connections_ms = step.elapsed().as_millis() as u64;
+
+        #[cfg(feature = "execution")]
+        if profile.signed_request {
+            let step = Instant::now();
+            self.usdc_balance().await?;
+            report.signed_request_ms = Some(step.elapsed().as_millis() as u64);
+        }
+
+        #[cfg(feature = "execution")]
+        if let Some(token_id) = &profile.presign_token_id {
+            let step = Instant::now();
+            self.presign(token_id).await?;
+            report.presign_ms = Some(step.elapsed().as_millis() as u64);
+        }
+
+        report.total_ms = start.elapsed().as_millis() as u64;
+        Ok(report)
+    }
+
+    /// Look up every configured host; returns how many were resolved
+    async fn resolve_hosts(&self) -> anyhow::Result<u32> {
+        let urls = [
+            &self.options.clob_host,
+            &self.options.ws_host,
+            &self.options.gamma_host,
+            &self.options.data_host,
+            &self.options.rpc_url,
+        ];
+        let urls = urls.into_iter().chain(&self.options.clob_gateways);
+        let mut targets: Vec<(String, u16)> = Vec::new();
+        for url in urls {
+            let parsed = reqwest::Url::parse(url)?;
+            let host = parsed
+                .host_str()
+                .ok_or_else(|| anyhow::anyhow!("no host in {}", url))?;
+            let port = parsed.port_or_known_default().unwrap_or(443);
+            if !targets.iter().any(|(h, p)| h == host && *p == port) {
+                targets.push((host.to_string(), port));
+            }
+        }
+        self.limited(
+            CallClass::MarketData,
+            try_join_all(targets.iter().map(|(host, port)| async move {
+                tokio::net::lookup_host((host.as_str(), *port))
+                    .await
+                    .map_err(|e| anyhow::anyhow!("resolve {}: {}", host, e))
+            })),
+        )
+        .await?;
+        Ok(targets.len() as u32)
+    }
+
+    /// Build and sign a minimal order for `token_id`, then drop it
+    #[cfg(feature = "execution")]
+    async fn presign(&self, token_id: &str) -> anyhow::Result<()> {
+        let token = parse_token(token_id)?;
+        self.limited(CallClass::MarketData, async {
+            let order = self
+                .client
+                .market_order()
+                .token_id(token)
+                .amount(Amount::shares(Decimal::new(5, 0))?)
+                .side(Side::Buy)
+                .order_type(OrderType::GTC)
+                .price(Decimal::new(1, 2))
+                .build()
+                .await?;
+            self.client.sign(&self.signer, order).await?;
+            Ok::<_, anyhow::Error>(())
+        })
+        .await
+    }
+}
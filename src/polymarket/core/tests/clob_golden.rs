@@ -0,0 +1,90 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Golden files of CLOB public data responses
+
+use polymarket_client_sdk::types::Decimal;
+use polymarket_executor_core::history::{candles, parse_history, Candle};
+use polymarket_executor_core::schema::DriftLog;
+
+fn golden(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn price(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn price_history_candles() {
+    let drift = DriftLog::new();
+    let points = parse_history(golden("clob_prices_history.json"), &drift);
+
+    // Sorted by time; the string price and the one above 1 are drift
+    let times: Vec<i64> = points.iter().map(|p| p.ts).collect();
+    assert_eq!(
+        times,
+        [1767225600, 1767225960, 1767226320, 1767228540, 1767228900, 1767229260, 1767240000]
+    );
+    let report = drift.report();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].source, "clob.prices_history");
+    assert_eq!(report[0].count, 2);
+
+    assert_eq!(
+        candles(&points, 3600),
+        [
+            Candle {
+                start_ts: 1767225600,
+                open: price("0.52"),
+                high: price("0.545"),
+                low: price("0.51"),
+                close: price("0.53"),
+                samples: 5,
+            },
+            Candle {
+                start_ts: 1767229200,
+                open: price("0.5"),
+                high: price("0.5"),
+                low: price("0.5"),
+                close: price("0.5"),
+                samples: 1,
+            },
+            Candle {
+                start_ts: 1767240000,
+                open: price("0.61"),
+                high: price("0.61"),
+                low: price("0.61"),
+                close: price("0.61"),
+                samples: 1,
+            },
+        ]
+    );
+
+    // One-minute candles are the samples themselves
+    let minutes = candles(&points, 60);
+    assert_eq!(minutes.len(), points.len());
+    assert!(minutes
+        .iter()
+        .zip(&points)
+        .all(|(c, p)| c.close == p.price && c.samples == 1));
+}
+
+#[test]
+fn price_history_without_history() {
+    let drift = DriftLog::new();
+    let body = serde_json::json!({"error": "invalid token id"});
+
+    assert!(parse_history(body, &drift).is_empty());
+    let report = drift.report();
+    assert_eq!(report.len(), 1);
+    assert!(report[0].last_sample.contains("invalid token id"));
+}
@@ -0,0 +1,66 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Golden file of a Data API /positions response, with the kinds of drift
+//! parsing has to survive
+
+#![cfg(feature = "execution")]
+
+use polymarket_executor_core::positions::{parse_positions, WalletPosition};
+use polymarket_executor_core::schema::DriftLog;
+
+fn golden(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+#[test]
+fn positions_as_served() {
+    let drift = DriftLog::new();
+    let positions = parse_positions(golden("data_positions.json"), &drift);
+
+    assert_eq!(
+        positions,
+        vec![
+            WalletPosition {
+                token_id:
+                    "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                        .into(),
+                condition_id: "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
+                    .into(),
+                outcome: "Yes".into(),
+                shares: 152.5,
+                avg_price: Some(0.5812),
+                redeemable: false,
+            },
+            // No entry price known, redeemable as a string
+            WalletPosition {
+                token_id:
+                    "98372103847561029384756102938475610293847561029384756102938475610293847561"
+                        .into(),
+                condition_id: "0x0b4cbe6fdc0d5d1e4bd3f38d0b1c7a5c9d0e6f2a3b4c5d6e7f8091a2b3c4d5e6"
+                    .into(),
+                outcome: "No".into(),
+                shares: 40.0,
+                avg_price: None,
+                redeemable: true,
+            },
+        ]
+    );
+
+    // The empty position is dropped quietly, the one sized as a string is
+    // drift
+    let report = drift.report();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].source, "data.positions");
+    assert_eq!(report[0].count, 1);
+    assert!(report[0].last_error.contains("expected f64"));
+    assert!(report[0].last_sample.contains("3333333333"));
+}
@@ -0,0 +1,196 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Golden files of Gamma /markets responses: as served today, and with the
+//! kinds of drift parsing has to survive
+
+use polymarket_executor_core::gamma::{parse_markets, MarketTokens};
+use polymarket_executor_core::resolution::{
+    parse_resolutions, Resolution, ResolutionStatus, ResolutionWatcher,
+};
+use polymarket_executor_core::schema::DriftLog;
+
+fn golden(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+#[test]
+fn markets_as_served() {
+    let drift = DriftLog::new();
+    let markets = parse_markets(golden("gamma_markets.json"), &drift);
+
+    assert_eq!(
+        markets,
+        vec![
+            MarketTokens {
+                condition_id: "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
+                    .into(),
+                question_id: "0x8a8e8bb9c7b1a4e2ef49d0a7a3b7d4c2c8f1e6a90d5b3c2a1f0e9d8c7b6a5f40"
+                    .into(),
+                slug: "will-the-fed-cut-rates-in-december".into(),
+                yes_token_id:
+                    "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                        .into(),
+                no_token_id:
+                    "52114319501245915516055106046884209969926127482827954674443846427813813222426"
+                        .into(),
+                neg_risk: false,
+                question: "Will the Fed cut rates in December?".into(),
+                outcomes: vec!["Yes".into(), "No".into()],
+                end_date: "2026-12-10T19:00:00Z".into(),
+            },
+            MarketTokens {
+                condition_id: "0x0b4cbe6fdc0d5d1e4bd3f38d0b1c7a5c9d0e6f2a3b4c5d6e7f8091a2b3c4d5e6"
+                    .into(),
+                question_id: "0x1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d"
+                    .into(),
+                slug: "will-alice-win-the-election".into(),
+                yes_token_id:
+                    "10429785371840124935416052815329485710934881278432912317125392844301128812345"
+                        .into(),
+                no_token_id:
+                    "98372103847561029384756102938475610293847561029384756102938475610293847561"
+                        .into(),
+                neg_risk: true,
+                question: "Will Alice win the election?".into(),
+                outcomes: vec![],
+                end_date: "".into(),
+            },
+        ]
+    );
+    assert_eq!(markets[0].end_date_ms(), Some(1_796_929_200_000));
+    assert_eq!(markets[1].end_date_ms(), None);
+    assert!(drift.report().is_empty());
+}
+
+#[test]
+fn markets_with_drift() {
+    let drift = DriftLog::new();
+    let markets = parse_markets(golden("gamma_markets_drift.json"), &drift);
+
+    let slugs: Vec<&str> = markets.iter().map(|m| m.slug.as_str()).collect();
+    assert_eq!(slugs, ["tokens-as-a-plain-array", "optional-fields-null"]);
+
+    let plain = &markets[0];
+    assert_eq!(
+        plain.question_id,
+        "0x3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3f2e"
+    );
+    assert_eq!(plain.yes_token_id, "1".repeat(77));
+    assert_eq!(plain.no_token_id, "2".repeat(77));
+    assert!(plain.neg_risk);
+    assert_eq!(plain.outcomes, ["Up", "Down"]);
+    assert_eq!(plain.end_date_ms(), Some(1_774_915_200_000));
+
+    let nulls = &markets[1];
+    assert_eq!(nulls.question_id, "");
+    assert!(!nulls.neg_risk);
+    assert_eq!(nulls.question, "");
+    assert!(nulls.outcomes.is_empty());
+    assert_eq!(nulls.end_date, "");
+
+    // The entry without a condition id and the three-outcome one
+    let report = drift.report();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].source, "gamma.markets");
+    assert_eq!(report[0].count, 2);
+    assert!(report[0].last_sample.contains("0x4a3b2c1d"));
+}
+
+#[test]
+fn markets_body_not_an_array() {
+    let drift = DriftLog::new();
+    let body = serde_json::json!({"error": "service unavailable"});
+
+    assert!(parse_markets(body, &drift).is_empty());
+    let report = drift.report();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].last_error, "expected an array");
+    assert!(report[0].last_sample.contains("service unavailable"));
+}
+
+#[test]
+fn resolutions_as_served() {
+    let drift = DriftLog::new();
+    let resolutions = parse_resolutions(golden("gamma_resolutions.json"), &drift);
+
+    let statuses: Vec<ResolutionStatus> = resolutions.iter().map(|r| r.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            ResolutionStatus::Open,
+            ResolutionStatus::Proposed,
+            ResolutionStatus::Disputed,
+            ResolutionStatus::Resolved,
+            ResolutionStatus::Resolved,
+            // Resolved on the oracle, prices not settled on Gamma yet
+            ResolutionStatus::Proposed,
+        ]
+    );
+    assert_eq!(
+        resolutions[0].condition_id,
+        "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
+    );
+    assert_eq!(
+        resolutions[3],
+        Resolution {
+            condition_id: "0x7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e"
+                .into(),
+            status: ResolutionStatus::Resolved,
+            winning_token_id: "8".repeat(77),
+            winning_outcome: "No".into(),
+            yes_payout_raw: 0,
+        }
+    );
+    // Settled 50-50: no winner, half a USDC a share
+    assert_eq!(resolutions[4].winning_token_id, "");
+    assert_eq!(resolutions[4].yes_payout_raw, 500_000);
+    assert!(resolutions
+        .iter()
+        .filter(|r| r.status != ResolutionStatus::Resolved)
+        .all(|r| r.winning_token_id.is_empty() && r.yes_payout_raw == 0));
+    assert!(drift.report().is_empty());
+}
+
+#[test]
+fn resolution_changes_reported_once() {
+    let drift = DriftLog::new();
+    let resolutions = parse_resolutions(golden("gamma_resolutions.json"), &drift);
+    let watcher = ResolutionWatcher::new();
+    for resolution in &resolutions[..5] {
+        watcher.watch(&resolution.condition_id).unwrap();
+    }
+
+    // Open at first sight is no change; the unwatched market is ignored
+    let changed: Vec<ResolutionStatus> = watcher
+        .changes(resolutions.clone())
+        .iter()
+        .map(|r| r.status)
+        .collect();
+    assert_eq!(
+        changed,
+        [
+            ResolutionStatus::Proposed,
+            ResolutionStatus::Disputed,
+            ResolutionStatus::Resolved,
+            ResolutionStatus::Resolved,
+        ]
+    );
+    // Resolved markets are no longer watched
+    assert_eq!(watcher.watched(), 3);
+    assert!(watcher.changes(resolutions.clone()).is_empty());
+
+    let mut proposed = resolutions[0].clone();
+    proposed.status = ResolutionStatus::Proposed;
+    assert_eq!(watcher.changes(vec![proposed.clone()]), [proposed]);
+    assert!(watcher.unwatch(&resolutions[0].condition_id));
+    assert!(!watcher.unwatch(&resolutions[3].condition_id));
+}
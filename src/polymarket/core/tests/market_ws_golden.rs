@@ -0,0 +1,133 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Golden messages of the CLOB market channel
+
+use polymarket_client_sdk::types::Decimal;
+use polymarket_executor_core::book::{BookSnapshot, Level};
+use polymarket_executor_core::market_ws::{BookFeed, FeedUpdate};
+use polymarket_executor_core::schema::DriftLog;
+use polymarket_executor_core::Side;
+
+const TOKEN: &str = "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+fn message(name: &str) -> String {
+    let path = format!(
+        "{}/tests/golden/ws_market_messages.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let messages: serde_json::Value =
+        serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    messages[name].to_string()
+}
+
+fn price(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+fn top(feed: &BookFeed) -> (Decimal, Decimal) {
+    let book = feed.book(TOKEN).unwrap();
+    (book.bids[0].price, book.asks[0].price)
+}
+
+#[test]
+fn book_gap_detection() {
+    let drift = DriftLog::new();
+    let feed = BookFeed::new();
+    assert!(feed.subscribe(TOKEN));
+
+    let updates = feed.apply(&message("book"), &drift);
+    assert_eq!(updates, [FeedUpdate::Book(TOKEN.to_string())]);
+    let state = feed.state(TOKEN).unwrap();
+    assert_eq!((state.generation, state.stale), (1, false));
+    assert_eq!(state.timestamp_ms, 1767225600000);
+
+    // The venue's top after the delta agrees with the local one
+    feed.apply(&message("bid_added"), &drift);
+    assert_eq!(top(&feed), (price("0.49"), price("0.52")));
+    assert_eq!(feed.state(TOKEN).unwrap().generation, 2);
+
+    // Older than the book: dropped, the ask stays
+    assert!(feed.apply(&message("late_delta"), &drift).is_empty());
+    assert_eq!(top(&feed), (price("0.49"), price("0.52")));
+    assert_eq!(feed.state(TOKEN).unwrap().generation, 2);
+
+    // The venue's best ask is 0.5: the delta that added it was missed
+    let updates = feed.apply(&message("ask_after_gap"), &drift);
+    assert_eq!(
+        updates,
+        [
+            FeedUpdate::Book(TOKEN.to_string()),
+            FeedUpdate::Gap(TOKEN.to_string())
+        ]
+    );
+    let state = feed.state(TOKEN).unwrap();
+    assert_eq!((state.generation, state.stale, state.gaps), (4, true, 1));
+
+    // Reported once while stale
+    let updates = feed.apply(&message("ask_after_gap"), &drift);
+    assert_eq!(updates, [FeedUpdate::Book(TOKEN.to_string())]);
+    assert_eq!(feed.state(TOKEN).unwrap().gaps, 1);
+
+    let updates = feed.apply(&message("trade"), &drift);
+    assert_eq!(
+        updates,
+        [FeedUpdate::LastTrade {
+            token_id: TOKEN.to_string(),
+            price: price("0.5"),
+            size: price("25"),
+            side: Side::Sell,
+            timestamp_ms: 1767225604000,
+        }]
+    );
+
+    // A resent book clears the gap
+    feed.apply(&message("book_resent"), &drift);
+    assert_eq!(top(&feed), (price("0.49"), price("0.5")));
+    let state = feed.state(TOKEN).unwrap();
+    assert_eq!((state.generation, state.stale, state.gaps), (7, false, 1));
+    assert!(drift.report().is_empty());
+}
+
+#[test]
+fn stale_book_restore() {
+    let drift = DriftLog::new();
+    let feed = BookFeed::new();
+    feed.subscribe(TOKEN);
+    feed.apply(&message("book"), &drift);
+    feed.apply(&message("bid_added"), &drift);
+    feed.apply(&message("ask_after_gap"), &drift);
+
+    let level = |p: &str, s: &str| Level {
+        price: price(p),
+        size: price(s),
+    };
+    let snapshot = BookSnapshot::new(
+        vec![level("0.48", "100"), level("0.49", "10")],
+        vec![level("0.51", "7"), level("0.5", "15")],
+    );
+    assert!(feed.restore(TOKEN, &snapshot));
+    let (book, state) = feed.book_state(TOKEN).unwrap();
+    assert_eq!(book.bids, snapshot.bids);
+    assert_eq!(book.asks, snapshot.asks);
+    assert_eq!(
+        (state.generation, state.stale, state.resyncs),
+        (5, false, 1)
+    );
+
+    // Only a stale book is restored
+    assert!(!feed.restore(TOKEN, &snapshot));
+    assert_eq!(feed.state(TOKEN).unwrap().resyncs, 1);
+
+    // Unsubscribing drops the book and its state
+    assert!(feed.unsubscribe(TOKEN));
+    assert!(feed.state(TOKEN).is_none());
+    assert!(!feed.restore(TOKEN, &snapshot));
+}
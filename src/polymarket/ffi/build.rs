@@ -0,0 +1,26 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Check polymarket_ffi_generated.h against the Rust definitions (see
+//! flox_headers), then ship the C headers and the header-only C++ wrapper
+//! next to the built library (`<target>/<profile>/include/`), so consumers
+//! building the crate directly with cargo get a matching header set without
+//! the CMake tree.
+
+const HEADER_DIR: &str = "../../../include/flox-connectors/polymarket";
+const HEADERS: &[&str] = &[
+    "polymarket_ffi.h",
+    "polymarket_ffi_generated.h",
+    "polymarket_ffi.hpp",
+];
+
+fn main() {
+    flox_headers::generate(&format!("{}/polymarket_ffi_generated.h", HEADER_DIR));
+    flox_headers::ship(HEADER_DIR, HEADERS);
+}
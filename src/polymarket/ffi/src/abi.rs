@@ -0,0 +1,128 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Versioned FFI structs
+//!
+//! Extensible structs start with `struct_size: u32, version: u32`. The
+//! caller sets `struct_size` to the size it was compiled with; the library
+//! only writes that many bytes, so fields appended in later versions never
+//! overrun an older binary's struct. `version` is set by the library to the
+//! ABI version negotiated with the host via polymarket_negotiate_abi(), and
+//! fields added after that version are left zeroed.
+//!
+//! Rules for new fields: append only, never reorder or resize, bump
+//! `ABI_VERSION` and return the old size from `Versioned::size_at`.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Latest layout version this library knows
+/// v1: PolymarketOrderResult fields
+/// v2: + remaining_qty_raw, fee_raw
+/// v3: + client_order_id
+/// v4: + generation, stale of market channel books
+pub const ABI_VERSION: u32 = 4;
+
+/// Version agreed with the host, v1 until negotiated
+static NEGOTIATED: AtomicU32 = AtomicU32::new(1);
+
+/// Agree on min(host, library) version, returns the agreed version
+pub fn negotiate(host_version: u32) -> u32 {
+    let agreed = host_version.clamp(1, ABI_VERSION);
+    NEGOTIATED.store(agreed, Ordering::Release);
+    agreed
+}
+
+pub fn negotiated() -> u32 {
+    NEGOTIATED.load(Ordering::Acquire)
+}
+
+/// Header shared by all versioned structs
+#[repr(C)]
+struct Header {
+    struct_size: u32,
+    version: u32,
+}
+
+/// Struct written with write_sized()
+pub trait Versioned: Sized {
+    /// Bytes of the layout at ABI `version`
+    fn size_at(_version: u32) -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+/// Copy `value` into the caller's struct, bounded by the caller's struct_size
+/// Fields past the negotiated version's layout are zeroed
+/// Returns false (nothing written) if `out` is null or too small for the header
+///
+/// # Safety
+/// `T` must be `#[repr(C)]` and start with `struct_size: u32, version: u32`;
+/// `out` must be null or valid for writes of its own `struct_size` bytes
+pub unsafe fn write_sized<T: Versioned>(value: &T, out: *mut T) -> bool {
+    if out.is_null() {
+        return false;
+    }
+    let header = out as *mut Header;
+    let struct_size = (*header).struct_size as usize;
+    if struct_size < std::mem::size_of::<Header>() {
+        return false;
+    }
+
+    let version = negotiated();
+    let len = struct_size.min(std::mem::size_of::<T>());
+    let known = len.min(T::size_at(version));
+    let out_bytes = out as *mut u8;
+    std::ptr::copy_nonoverlapping(value as *const T as *const u8, out_bytes, known);
+    std::ptr::write_bytes(out_bytes.add(known), 0, len - known);
+    (*header).struct_size = struct_size as u32;
+    (*header).version = version;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::os::raw::c_char;
+
+    use crate::PolymarketOrderResultEx;
+
+    #[test]
+    fn fields_past_the_negotiated_version_are_zeroed() {
+        let value = PolymarketOrderResultEx {
+            filled_qty_raw: 5_000_000,
+            remaining_qty_raw: 2_000_000,
+            fee_raw: 10_000,
+            client_order_id: [b'c' as c_char; 64],
+            ..Default::default()
+        };
+        // Left over in the host's struct from an earlier call
+        let mut out = PolymarketOrderResultEx {
+            remaining_qty_raw: -1,
+            fee_raw: -1,
+            client_order_id: [1; 64],
+            ..Default::default()
+        };
+
+        negotiate(1);
+        assert!(unsafe { write_sized(&value, &mut out) });
+        assert_eq!(out.version, 1);
+        assert_eq!(out.filled_qty_raw, 5_000_000);
+        assert_eq!(out.remaining_qty_raw, 0);
+        assert_eq!(out.fee_raw, 0);
+        assert_eq!(out.client_order_id, [0; 64]);
+
+        negotiate(ABI_VERSION);
+        assert!(unsafe { write_sized(&value, &mut out) });
+        negotiate(1);
+        assert_eq!(out.version, ABI_VERSION);
+        assert_eq!(out.remaining_qty_raw, 2_000_000);
+        assert_eq!(out.client_order_id, [b'c' as c_char; 64]);
+    }
+}
@@ -0,0 +1,573 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Event callbacks
+//!
+//! Hosts register a C callback plus an opaque `user_data` pointer for a set
+//! of event kinds. Threading is fixed per registration:
+//! - `POLYMARKET_DELIVER_THREAD`: invoked on the single "polymarket-events" thread, one
+//!   event at a time, in emission order. Never on runtime workers or on the
+//!   thread that issued the request.
+//! - `POLYMARKET_DELIVER_POLL`: queued; invoked on whichever thread calls
+//!   polymarket_poll_events(), for hosts that forbid foreign threads. A poll
+//!   made from inside a callback delivers nothing: the callback is not
+//!   re-entered and its event stays the one being handled.
+//!
+//! Once unregister returns, the callback is not invoked again.
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event as TracingEvent, Level, Subscriber as TracingSubscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+// Event kinds, usable as a bit mask
+/// Order result: code = error code
+pub const POLYMARKET_EVENT_ORDER: u32 = 1;
+/// Book, trade or tick size update: code = 0
+pub const POLYMARKET_EVENT_MARKET_DATA: u32 = 2;
+/// Log line: code = level, 1 (ERROR) .. 5 (TRACE)
+pub const POLYMARKET_EVENT_LOG: u32 = 4;
+/// Risk rejection or limits change: code = error code
+pub const POLYMARKET_EVENT_RISK: u32 = 8;
+/// Account notification: code = notification type
+pub const POLYMARKET_EVENT_NOTIFICATION: u32 = 16;
+/// Execution algo progress: code = error code
+pub const POLYMARKET_EVENT_ALGO: u32 = 32;
+/// Quoting action: code = error code
+pub const POLYMARKET_EVENT_QUOTE: u32 = 64;
+/// YES/NO pair found or merged: code = error code
+pub const POLYMARKET_EVENT_MERGE: u32 = 128;
+/// YES+NO mispricing opened, changed or closed
+pub const POLYMARKET_EVENT_ARBITRAGE: u32 = 256;
+/// Fill of a tracked order, with running totals
+pub const POLYMARKET_EVENT_FILL: u32 = 512;
+/// Tracked order completed: final totals
+pub const POLYMARKET_EVENT_ORDER_SUMMARY: u32 = 1024;
+/// Credential failover: code = error code
+pub const POLYMARKET_EVENT_CREDENTIALS: u32 = 2048;
+/// Venue circuit breaker opened or closed
+pub const POLYMARKET_EVENT_VENUE: u32 = 4096;
+/// Watched market's resolution status changed: code = POLYMARKET_RESOLUTION_*
+pub const POLYMARKET_EVENT_RESOLUTION: u32 = 8192;
+
+// Delivery modes
+pub const POLYMARKET_DELIVER_THREAD: i32 = 0;
+pub const POLYMARKET_DELIVER_POLL: i32 = 1;
+
+/// Max events buffered per delivery mode before the oldest are dropped
+const QUEUE_CAPACITY: usize = 65_536;
+
+/// Event passed to callbacks; pointers are valid only during the call
+#[repr(C)]
+pub struct PolymarketEvent {
+    /// sizeof(PolymarketEvent) as known by the library
+    pub struct_size: u32,
+    /// Negotiated ABI version
+    pub version: u32,
+    /// One POLYMARKET_EVENT_* bit
+    pub kind: u32,
+    /// Kind-specific, see POLYMARKET_EVENT_*
+    pub code: i32,
+    /// Unix time of emission
+    pub timestamp_ms: u64,
+    /// NULL if not token-specific
+    pub token_id: *const c_char,
+    /// JSON object, never NULL
+    pub payload: *const c_char,
+}
+
+pub type EventCallback = extern "C" fn(event: *const PolymarketEvent, user_data: *mut c_void);
+
+/// Owned event as emitted from Rust
+#[derive(Clone, Debug)]
+pub struct Event {
+    pub kind: u32,
+    pub code: i32,
+    pub timestamp_ms: u64,
+    pub token_id: Option<String>,
+    pub payload: String,
+}
+
+impl Event {
+    pub fn new(kind: u32, code: i32, token_id: Option<&str>, payload: serde_json::Value) -> Self {
+        Self {
+            kind,
+            code,
+            timestamp_ms: chrono::Utc::now().timestamp_millis() as u64,
+            token_id: token_id.map(str::to_string),
+            payload: payload.to_string(),
+        }
+    }
+}
+
+/// Host pointer handed back to callbacks, never dereferenced here
+struct UserData(*mut c_void);
+
+// SAFETY: the pointer is opaque to us; the host owns its thread-safety
+unsafe impl Send for UserData {}
+unsafe impl Sync for UserData {}
+
+struct Subscription {
+    id: i32,
+    mask: u32,
+    delivery: i32,
+    callback: EventCallback,
+    user_data: UserData,
+    active: AtomicBool,
+    /// Held while the callback runs, so unregister can wait for it
+    in_call: Mutex<()>,
+}
+
+thread_local! {
+    /// Set while this thread is inside a callback
+    static DELIVERING: Cell<bool> = const { Cell::new(false) };
+    /// Set while this thread is inside poll()
+    static POLLING: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Subscription {
+    fn deliver(&self, event: &Event) {
+        if self.mask & event.kind == 0 {
+            return;
+        }
+        let _in_call = self.in_call.lock().unwrap_or_else(|e| e.into_inner());
+        if !self.active.load(Ordering::Acquire) {
+            return;
+        }
+
+        let token_id = event.token_id.as_deref().and_then(|t| CString::new(t).ok());
+        let payload = CString::new(event.payload.as_str()).unwrap_or_default();
+        let raw = PolymarketEvent {
+            struct_size: std::mem::size_of::<PolymarketEvent>() as u32,
+            version: crate::abi::negotiated(),
+            kind: event.kind,
+            code: event.code,
+            timestamp_ms: event.timestamp_ms,
+            token_id: token_id.as_ref().map_or(std::ptr::null(), |t| t.as_ptr()),
+            payload: payload.as_ptr(),
+        };
+
+        // A THREAD callback may poll POLL callbacks: restore, don't clear
+        let outer = DELIVERING.with(|d| d.replace(true));
+        (self.callback)(&raw, self.user_data.0);
+        DELIVERING.with(|d| d.set(outer));
+    }
+}
+
+struct EventBus {
+    subscriptions: RwLock<Vec<Arc<Subscription>>>,
+    next_id: AtomicI32,
+    /// Union of masks per delivery mode, to skip work nobody listens to
+    thread_mask: AtomicU32,
+    poll_mask: AtomicU32,
+    /// Channel to the dispatcher thread, spawned on first POLYMARKET_DELIVER_THREAD registration
+    dispatcher: Mutex<Option<SyncSender<Event>>>,
+    poll_queue: Mutex<VecDeque<Event>>,
+    dropped: AtomicU64,
+}
+
+static BUS: OnceLock<EventBus> = OnceLock::new();
+
+fn bus() -> &'static EventBus {
+    BUS.get_or_init(|| EventBus {
+        subscriptions: RwLock::new(Vec::new()),
+        next_id: AtomicI32::new(1),
+        thread_mask: AtomicU32::new(0),
+        poll_mask: AtomicU32::new(0),
+        dispatcher: Mutex::new(None),
+        poll_queue: Mutex::new(VecDeque::new()),
+        dropped: AtomicU64::new(0),
+    })
+}
+
+impl EventBus {
+    fn recompute_masks(&self, subs: &[Arc<Subscription>]) {
+        let mask_of = |mode| {
+            subs.iter()
+                .filter(|s| s.delivery == mode)
+                .fold(0, |m, s| m | s.mask)
+        };
+        self.thread_mask
+            .store(mask_of(POLYMARKET_DELIVER_THREAD), Ordering::Release);
+        self.poll_mask
+            .store(mask_of(POLYMARKET_DELIVER_POLL), Ordering::Release);
+    }
+
+    fn subscribers(&self, delivery: i32) -> Vec<Arc<Subscription>> {
+        self.subscriptions
+            .read()
+            .map(|subs| {
+                subs.iter()
+                    .filter(|s| s.delivery == delivery)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn ensure_dispatcher(&self) -> bool {
+        let mut dispatcher = self.dispatcher.lock().unwrap_or_else(|e| e.into_inner());
+        if dispatcher.is_some() {
+            return true;
+        }
+        let (tx, rx) = mpsc::sync_channel::<Event>(QUEUE_CAPACITY);
+        let spawned = std::thread::Builder::new()
+            .name("polymarket-events".to_string())
+            .spawn(move || {
+                let bus = bus();
+                while let Ok(event) = rx.recv() {
+                    for sub in bus.subscribers(POLYMARKET_DELIVER_THREAD) {
+                        sub.deliver(&event);
+                    }
+                }
+            });
+        if spawned.is_err() {
+            return false;
+        }
+        *dispatcher = Some(tx);
+        true
+    }
+}
+
+/// Whether any subscriber listens to `kind`; lets emitters skip building payloads
+pub fn wants(kind: u32) -> bool {
+    match BUS.get() {
+        Some(bus) => {
+            (bus.thread_mask.load(Ordering::Acquire) | bus.poll_mask.load(Ordering::Acquire)) & kind
+                != 0
+        }
+        None => false,
+    }
+}
+
+/// Publish an event to all matching subscribers
+/// Never blocks: when a queue is full the event is dropped and counted
+pub fn emit(event: Event) {
+    let bus = match BUS.get() {
+        Some(b) => b,
+        None => return,
+    };
+
+    if bus.poll_mask.load(Ordering::Acquire) & event.kind != 0 {
+        let mut queue = bus.poll_queue.lock().unwrap_or_else(|e| e.into_inner());
+        if queue.len() >= QUEUE_CAPACITY {
+            queue.pop_front();
+            bus.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        queue.push_back(event.clone());
+    }
+
+    if bus.thread_mask.load(Ordering::Acquire) & event.kind != 0 {
+        let dispatcher = bus.dispatcher.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(tx) = dispatcher.as_ref() {
+            if let Err(TrySendError::Full(_)) = tx.try_send(event) {
+                bus.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Register a callback, returns its id (> 0)
+pub fn register(
+    mask: u32,
+    delivery: i32,
+    callback: EventCallback,
+    user_data: *mut c_void,
+) -> Option<i32> {
+    if delivery != POLYMARKET_DELIVER_THREAD && delivery != POLYMARKET_DELIVER_POLL {
+        return None;
+    }
+    let bus = bus();
+    if delivery == POLYMARKET_DELIVER_THREAD && !bus.ensure_dispatcher() {
+        return None;
+    }
+
+    let id = bus.next_id.fetch_add(1, Ordering::Relaxed);
+    let sub = Arc::new(Subscription {
+        id,
+        mask,
+        delivery,
+        callback,
+        user_data: UserData(user_data),
+        active: AtomicBool::new(true),
+        in_call: Mutex::new(()),
+    });
+
+    let mut subs = bus.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+    subs.push(sub);
+    bus.recompute_masks(&subs);
+    Some(id)
+}
+
+/// Remove a callback; waits for an in-progress invocation unless called
+/// from inside a callback. Returns false for unknown ids
+pub fn unregister(id: i32) -> bool {
+    let bus = match BUS.get() {
+        Some(b) => b,
+        None => return false,
+    };
+
+    let removed = {
+        let mut subs = bus.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+        let pos = match subs.iter().position(|s| s.id == id) {
+            Some(p) => p,
+            None => return false,
+        };
+        let removed = subs.remove(pos);
+        bus.recompute_masks(&subs);
+        removed
+    };
+
+    removed.active.store(false, Ordering::Release);
+    if !DELIVERING.with(|d| d.get()) {
+        // Wait for a concurrent invocation to finish
+        drop(removed.in_call.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+    true
+}
+
+/// Deliver up to `max` queued events to POLYMARKET_DELIVER_POLL subscribers on the
+/// calling thread, returns the number of events delivered
+/// 0 from inside a poll: a callback would re-lock its own `in_call`
+pub fn poll(max: usize) -> usize {
+    let bus = match BUS.get() {
+        Some(b) => b,
+        None => return 0,
+    };
+    if POLLING.with(|p| p.replace(true)) {
+        return 0;
+    }
+    let delivered = poll_queue(bus, max);
+    POLLING.with(|p| p.set(false));
+    delivered
+}
+
+fn poll_queue(bus: &EventBus, max: usize) -> usize {
+
+    let subs = bus.subscribers(POLYMARKET_DELIVER_POLL);
+    let mut delivered = 0;
+    while delivered < max {
+        // Pop one at a time so callbacks may emit without deadlocking
+        let event = match bus
+            .poll_queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+        {
+            Some(e) => e,
+            None => break,
+        };
+        for sub in &subs {
+            sub.deliver(&event);
+        }
+        delivered += 1;
+    }
+    delivered
+}
+
+/// Count an event a consumer's own queue had no room for
+#[cfg(all(unix, feature = "ipc"))]
+pub fn count_dropped() {
+    if let Some(bus) = BUS.get() {
+        bus.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Events dropped because a queue was full
+pub fn dropped() -> u64 {
+    BUS.get().map_or(0, |b| b.dropped.load(Ordering::Relaxed))
+}
+
+/// Forwards tracing events as POLYMARKET_EVENT_LOG events (code = level, 1=ERROR .. 5=TRACE)
+pub struct LogLayer;
+
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        use std::fmt::Write;
+        if field.name() == "message" {
+            let _ = write!(self.0, "{:?}", value);
+        } else {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+pub fn level_code(level: &Level) -> i32 {
+    match *level {
+        Level::ERROR => 1,
+        Level::WARN => 2,
+        Level::INFO => 3,
+        Level::DEBUG => 4,
+        Level::TRACE => 5,
+    }
+}
+
+impl<S: TracingSubscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &TracingEvent<'_>, _ctx: Context<'_, S>) {
+        if !wants(POLYMARKET_EVENT_LOG) {
+            return;
+        }
+        let mut visitor = MessageVisitor(String::new());
+        event.record(&mut visitor);
+        let meta = event.metadata();
+        emit(Event::new(
+            POLYMARKET_EVENT_LOG,
+            level_code(meta.level()),
+            None,
+            serde_json::json!({
+                "level": meta.level().as_str(),
+                "target": meta.target(),
+                "message": visitor.0,
+            }),
+        ));
+    }
+}
+
+/// Host log sink: `line` is one JSON object, valid only during the call
+pub type LogCallback = extern "C" fn(level: i32, line: *const c_char, user_data: *mut c_void);
+
+struct LogSink {
+    callback: LogCallback,
+    user_data: UserData,
+}
+
+/// Callback set by polymarket_set_log_callback(), held while it runs
+static LOG_SINK: RwLock<Option<LogSink>> = RwLock::new(None);
+
+/// Most verbose level code passed to the log callback, 0 if none is set
+static LOG_SINK_LEVEL: AtomicI32 = AtomicI32::new(0);
+
+/// Route log lines at `min_level` (1=ERROR .. 5=TRACE) and above to
+/// `callback`, None to stop. Waits for a running invocation of the previous one
+pub fn set_log_callback(callback: Option<LogCallback>, min_level: i32, user_data: *mut c_void) {
+    let mut sink = LOG_SINK.write().unwrap_or_else(|e| e.into_inner());
+    *sink = callback.map(|callback| LogSink {
+        callback,
+        user_data: UserData(user_data),
+    });
+    let level = if sink.is_some() { min_level } else { 0 };
+    LOG_SINK_LEVEL.store(level, Ordering::Release);
+}
+
+/// Whether a log callback is set
+pub fn has_log_callback() -> bool {
+    LOG_SINK_LEVEL.load(Ordering::Acquire) > 0
+}
+
+/// Whether the log callback takes events of this crate (and core) at `level`
+pub fn log_callback_wants(target: &str, level: &Level) -> bool {
+    target.starts_with("polymarket_executor")
+        && level_code(level) <= LOG_SINK_LEVEL.load(Ordering::Acquire)
+}
+
+/// Fields of an event as JSON values
+struct JsonVisitor(serde_json::Map<String, serde_json::Value>);
+
+impl Visit for JsonVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value).into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+}
+
+/// Hands tracing events to the log callback as JSON lines:
+/// {"ts_ms", "level", "target", "message", ...fields}
+/// Runs on the thread that logged, runtime workers included
+pub struct CallbackLayer;
+
+impl<S: TracingSubscriber> Layer<S> for CallbackLayer {
+    fn on_event(&self, event: &TracingEvent<'_>, _ctx: Context<'_, S>) {
+        let sink = LOG_SINK.read().unwrap_or_else(|e| e.into_inner());
+        let sink = match sink.as_ref() {
+            Some(sink) => sink,
+            None => return,
+        };
+        let meta = event.metadata();
+        let mut fields = serde_json::Map::new();
+        fields.insert(
+            "ts_ms".to_string(),
+            chrono::Utc::now().timestamp_millis().into(),
+        );
+        fields.insert("level".to_string(), meta.level().as_str().into());
+        fields.insert("target".to_string(), meta.target().into());
+        let mut visitor = JsonVisitor(fields);
+        event.record(&mut visitor);
+
+        let line = serde_json::Value::Object(visitor.0).to_string();
+        let line = CString::new(line).unwrap_or_default();
+        (sink.callback)(level_code(meta.level()), line.as_ptr(), sink.user_data.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::AtomicUsize;
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static NESTED: AtomicUsize = AtomicUsize::new(usize::MAX);
+    static STILL_DELIVERING: AtomicBool = AtomicBool::new(false);
+
+    extern "C" fn polling_callback(_event: *const PolymarketEvent, _user_data: *mut c_void) {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        NESTED.store(poll(16), Ordering::SeqCst);
+        STILL_DELIVERING.store(DELIVERING.with(|d| d.get()), Ordering::SeqCst);
+    }
+
+    #[test]
+    fn poll_from_a_callback_delivers_nothing() {
+        let id = register(
+            POLYMARKET_EVENT_QUOTE,
+            POLYMARKET_DELIVER_POLL,
+            polling_callback,
+            std::ptr::null_mut(),
+        )
+        .unwrap();
+        for _ in 0..2 {
+            emit(Event::new(POLYMARKET_EVENT_QUOTE, 0, None, serde_json::json!({})));
+        }
+
+        assert_eq!(poll(16), 2);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+        assert_eq!(NESTED.load(Ordering::SeqCst), 0);
+        assert!(STILL_DELIVERING.load(Ordering::SeqCst));
+        assert!(!DELIVERING.with(|d| d.get()));
+
+        // The guard is released: the next poll delivers again
+        emit(Event::new(POLYMARKET_EVENT_QUOTE, 0, None, serde_json::json!({})));
+        assert_eq!(poll(16), 1);
+        assert!(unregister(id));
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Panic containment for FFI entry points
+//!
+//! A panic unwinding out of an `extern "C"` function aborts the host
+//! process. Every entry point runs its body through `catch`, which turns a
+//! panic into a fallback return value (POLYMARKET_ERR_PANIC where the
+//! signature allows it). The panic hook keeps the message, location and a
+//! backtrace of the last panic for polymarket_last_panic().
+//!
+//! Requires `panic = "unwind"`; with `panic = "abort"` nothing can be caught.
+
+use std::backtrace::Backtrace;
+#[cfg(feature = "execution")]
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "execution")]
+use std::pin::Pin;
+use std::sync::{Mutex, Once};
+#[cfg(feature = "execution")]
+use std::task::{Context, Poll};
+
+use tracing::error;
+
+static HOOK: Once = Once::new();
+
+/// Message, location and backtrace of the last panic, from any thread
+static LAST_PANIC: Mutex<String> = Mutex::new(String::new());
+
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let backtrace = Backtrace::force_capture();
+            let thread = std::thread::current();
+            let report = format!(
+                "thread '{}' {}\nbacktrace:\n{}",
+                thread.name().unwrap_or("<unnamed>"),
+                info,
+                backtrace
+            );
+            error!("[PANIC] {}", info);
+            if let Ok(mut last) = LAST_PANIC.lock() {
+                *last = report;
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Run an FFI body, returning `fallback` if it panics
+pub fn catch<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    install_hook();
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(fallback)
+}
+
+/// Report of the last panic caught (empty if none)
+pub fn with_last_panic<R>(f: impl FnOnce(&str) -> R) -> R {
+    let last = LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner());
+    f(&last)
+}
+
+/// Future adapter resolving to Err if polling the inner future panics
+/// Used for submitted tasks, whose panic would otherwise only end the task
+#[cfg(feature = "execution")]
+pub struct CatchUnwind<F>(pub F);
+
+#[cfg(feature = "execution")]
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: structural pinning, the inner future is never moved
+        let inner = unsafe { self.map_unchecked_mut(|s| &mut s.0) };
+        match panic::catch_unwind(AssertUnwindSafe(|| inner.poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
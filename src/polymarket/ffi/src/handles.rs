@@ -0,0 +1,574 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Executors behind opaque handles
+//!
+//! polymarket_init*() keep one executor in a global slot, so a process
+//! trades one wallet. polymarket_create() builds an executor of its own
+//! (session, requests, pollers) and hands it out as a
+//! `PolymarketHandle`; any number can live side by side, each created and
+//! destroyed independently of the others and of the global one. All of
+//! them run on the shared runtime of flox_connector.
+//!
+//! polymarket_h_*() are the order and balance entry points taking a handle.
+//! Each runs the global entry point of the same name with the handle's
+//! executor bound to the calling thread for the duration of the call, so
+//! both share one implementation. Events and callbacks stay process-wide;
+//! request ids are unique across executors. Named wallets (wallets.rs) are
+//! bound the same way: both families come from one list below.
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::Arc;
+
+use polymarket_executor_core::options::ConnectOptions;
+use polymarket_executor_core::Trader;
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+use crate::{
+    connect, connect_error_code, guard, init_tracing, new_executor, shut_down, wallets, Executor,
+    PolymarketBatchOrder, PolymarketCancelOutcome, PolymarketOpenOrder, PolymarketOrderResultEx,
+    PolymarketOrderStatus, PolymarketTrade, POLYMARKET_ERR_AUTH_FAILED,
+    POLYMARKET_ERR_INVALID_ARGUMENT, POLYMARKET_ERR_INVALID_CONFIG, POLYMARKET_ERR_INVALID_PK,
+    POLYMARKET_ERR_NOT_INITIALIZED, POLYMARKET_ERR_PANIC, POLYMARKET_INIT_DRY_RUN,
+    POLYMARKET_INIT_READ_ONLY, POLYMARKET_OK, POLYMARKET_SHUTDOWN_CANCEL_ALL,
+};
+
+/// Executor created by polymarket_create(), opaque to C
+pub struct PolymarketHandle {
+    executor: Arc<Executor>,
+}
+
+thread_local! {
+    /// Executor of the handle a polymarket_h_*() call on this thread runs on
+    static BOUND: RefCell<Option<Arc<Executor>>> = const { RefCell::new(None) };
+}
+
+/// Executor bound to the calling thread, if a handle call is running on it
+pub fn bound() -> Option<Arc<Executor>> {
+    BOUND.with(|bound| bound.borrow().clone())
+}
+
+/// Restores the previous binding, also if the call panicked
+struct Rebind(Option<Arc<Executor>>);
+
+impl Drop for Rebind {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        BOUND.with(|bound| *bound.borrow_mut() = previous);
+    }
+}
+
+/// Run a global entry point on `executor`, bound to the calling thread
+pub(crate) fn on_executor<T>(executor: Arc<Executor>, call: impl FnOnce() -> T) -> T {
+    let _rebind = Rebind(BOUND.with(|bound| bound.replace(Some(executor))));
+    call()
+}
+
+/// Run a global entry point on `handle`'s executor
+/// `fallback` is the entry point's not-initialized value, for a NULL handle
+fn on_handle<T>(handle: *const PolymarketHandle, fallback: T, call: impl FnOnce() -> T) -> T {
+    let Some(handle) = (unsafe { handle.as_ref() }) else {
+        return fallback;
+    };
+    on_executor(handle.executor.clone(), call)
+}
+
+/// Connect an executor of its own
+/// `options_json` as for polymarket_set_connect_options() (NULL for the
+/// defaults), `flags` POLYMARKET_INIT_*. Staged options and init flags are
+/// not used. Returns NULL on failure, with the error code in `error` (if not
+/// NULL); POLYMARKET_OK there on success
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_create(
+    private_key: *const c_char,
+    funder_wallet: *const c_char,
+    options_json: *const c_char,
+    flags: u32,
+    error: *mut i32,
+) -> *mut PolymarketHandle {
+    let created = guard::catch(Err(POLYMARKET_ERR_PANIC), || {
+        create(private_key, funder_wallet, options_json, flags)
+    });
+    let (handle, code) = match created {
+        Ok(handle) => (Box::into_raw(handle), POLYMARKET_OK),
+        Err(code) => (std::ptr::null_mut(), code),
+    };
+    if !error.is_null() {
+        unsafe { *error = code };
+    }
+    handle
+}
+
+fn create(
+    private_key: *const c_char,
+    funder_wallet: *const c_char,
+    options_json: *const c_char,
+    flags: u32,
+) -> Result<Box<PolymarketHandle>, i32> {
+    if flags & !(POLYMARKET_INIT_READ_ONLY | POLYMARKET_INIT_DRY_RUN) != 0 {
+        return Err(POLYMARKET_ERR_INVALID_ARGUMENT);
+    }
+    init_tracing();
+
+    let options = if options_json.is_null() {
+        ConnectOptions::default()
+    } else {
+        let json = unsafe { CStr::from_ptr(options_json) }
+            .to_str()
+            .map_err(|_| POLYMARKET_ERR_INVALID_CONFIG)?;
+        ConnectOptions::from_json(json).map_err(|e| {
+            error!("[CREATE] {}", e);
+            POLYMARKET_ERR_INVALID_CONFIG
+        })?
+    };
+    if private_key.is_null() {
+        return Err(POLYMARKET_ERR_INVALID_PK);
+    }
+    let pk = match unsafe { CStr::from_ptr(private_key) }.to_str() {
+        Ok(s) => Zeroizing::new(s.to_string()),
+        Err(_) => return Err(POLYMARKET_ERR_INVALID_PK),
+    };
+    if funder_wallet.is_null() {
+        return Err(POLYMARKET_ERR_AUTH_FAILED);
+    }
+    let funder = unsafe { CStr::from_ptr(funder_wallet) }
+        .to_str()
+        .map_err(|_| POLYMARKET_ERR_AUTH_FAILED)?;
+
+    let connecting = funder.to_string();
+    let (runtime, connected) =
+        connect(async move { Trader::connect_with(&pk, &connecting, options).await })?;
+    let trader = connected.map_err(connect_error_code)?;
+    if flags & POLYMARKET_INIT_READ_ONLY != 0 {
+        trader.set_read_only();
+    }
+    if flags & POLYMARKET_INIT_DRY_RUN != 0 {
+        trader.set_dry_run();
+    }
+    info!("[CREATE] funder={} read_only={} dry_run={}",
+        funder, trader.is_read_only(), trader.is_dry_run());
+
+    Ok(Box::new(PolymarketHandle {
+        executor: new_executor(runtime, trader),
+    }))
+}
+
+/// Shut down and free a handle's executor, as polymarket_shutdown_ex() does
+/// for the global one; the handle is freed whatever the result, unless
+/// `flags` are invalid (POLYMARKET_ERR_INVALID_ARGUMENT)
+/// No call may use the handle once this starts; calls already running get
+/// `drain_timeout_ms` to finish. NULL returns POLYMARKET_ERR_NOT_INITIALIZED
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_destroy(
+    handle: *mut PolymarketHandle,
+    flags: u32,
+    drain_timeout_ms: u32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if handle.is_null() {
+            return POLYMARKET_ERR_NOT_INITIALIZED;
+        }
+        if flags & !POLYMARKET_SHUTDOWN_CANCEL_ALL != 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let handle = unsafe { Box::from_raw(handle) };
+        shut_down(handle.executor, flags, drain_timeout_ms)
+    })
+}
+
+/// Order and balance entry points on a handle (polymarket_h_*) and on a
+/// named wallet (polymarket_w_*), each running the global entry point it
+/// is listed under; `else` is returned for a NULL handle or an unknown
+/// wallet. cbindgen does not expand macros, so polymarket_ffi.h declares
+/// them by hand and a test checks it declares every one
+macro_rules! bound_entry_points {
+    ($(
+        $(#[doc = $doc:literal])*
+        $global:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty
+            => $on_handle:ident, $on_wallet:ident
+            else $fallback:expr;
+    )*) => {
+        $(
+            #[doc = concat!(stringify!($global), "() on `handle`")]
+            $(#[doc = $doc])*
+            #[unsafe(no_mangle)]
+            pub extern "C" fn $on_handle(handle: *const PolymarketHandle, $($arg: $ty),*) -> $ret {
+                on_handle(handle, $fallback, || crate::$global($($arg),*))
+            }
+
+            #[doc = concat!(stringify!($global), "() on `wallet`")]
+            $(#[doc = $doc])*
+            #[unsafe(no_mangle)]
+            pub extern "C" fn $on_wallet(wallet: *const c_char, $($arg: $ty),*) -> $ret {
+                wallets::on_wallet(wallet, $fallback, || crate::$global($($arg),*))
+            }
+        )*
+
+        /// (handle, wallet) entry point names, for the header check
+        #[cfg(test)]
+        const BOUND_ENTRY_POINTS: &[(&str, &str)] =
+            &[$((stringify!($on_handle), stringify!($on_wallet))),*];
+    };
+}
+
+bound_entry_points! {
+    polymarket_market_buy_ex(
+        token_id: *const c_char,
+        usdc_amount: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_buy_ex, polymarket_w_market_buy_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_buy_capped_ex(
+        token_id: *const c_char,
+        usdc_amount: f64,
+        max_price: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_buy_capped_ex, polymarket_w_market_buy_capped_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_buy_fok_ex(
+        token_id: *const c_char,
+        usdc_amount: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_buy_fok_ex, polymarket_w_market_buy_fok_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_buy_fok_capped_ex(
+        token_id: *const c_char,
+        usdc_amount: f64,
+        max_price: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_buy_fok_capped_ex, polymarket_w_market_buy_fok_capped_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_buy_shares_ex(
+        token_id: *const c_char,
+        shares: f64,
+        max_price: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_buy_shares_ex, polymarket_w_market_buy_shares_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_buy_protected_ex(
+        token_id: *const c_char,
+        usdc_amount: f64,
+        buffer_bps: u32,
+        max_slippage_bps: u32,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_buy_protected_ex, polymarket_w_market_buy_protected_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_sell_ex(
+        token_id: *const c_char,
+        size: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_sell_ex, polymarket_w_market_sell_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_sell_capped_ex(
+        token_id: *const c_char,
+        size: f64,
+        min_price: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_sell_capped_ex, polymarket_w_market_sell_capped_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_sell_fok_ex(
+        token_id: *const c_char,
+        size: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_sell_fok_ex, polymarket_w_market_sell_fok_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_sell_fok_capped_ex(
+        token_id: *const c_char,
+        size: f64,
+        min_price: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_sell_fok_capped_ex, polymarket_w_market_sell_fok_capped_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_sell_protected_ex(
+        token_id: *const c_char,
+        size: f64,
+        buffer_bps: u32,
+        max_slippage_bps: u32,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_sell_protected_ex, polymarket_w_market_sell_protected_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_market_sell_usdc_ex(
+        token_id: *const c_char,
+        usdc_amount: f64,
+        min_price: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_market_sell_usdc_ex, polymarket_w_market_sell_usdc_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_limit_buy_ex(
+        token_id: *const c_char,
+        price: f64,
+        usdc_amount: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_limit_buy_ex, polymarket_w_limit_buy_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_limit_sell_ex(
+        token_id: *const c_char,
+        price: f64,
+        size: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_limit_sell_ex, polymarket_w_limit_sell_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_limit_buy_gtd_ex(
+        token_id: *const c_char,
+        price: f64,
+        usdc_amount: f64,
+        expires_at_ms: u64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_limit_buy_gtd_ex, polymarket_w_limit_buy_gtd_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_limit_sell_gtd_ex(
+        token_id: *const c_char,
+        price: f64,
+        size: f64,
+        expires_at_ms: u64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_limit_sell_gtd_ex, polymarket_w_limit_sell_gtd_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_post_orders_batch(
+        orders: *const PolymarketBatchOrder,
+        count: usize,
+        results: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_post_orders_batch, polymarket_w_post_orders_batch
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_cancel(order_id: *const c_char) -> i32
+        => polymarket_h_cancel, polymarket_w_cancel
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_cancel_all_ex(
+        out: *mut PolymarketCancelOutcome,
+        capacity: usize,
+        out_count: *mut usize,
+    ) -> i32
+        => polymarket_h_cancel_all_ex, polymarket_w_cancel_all_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_cancel_orders_ex(
+        order_ids: *const *const c_char,
+        count: usize,
+        out: *mut PolymarketCancelOutcome,
+        capacity: usize,
+        out_count: *mut usize,
+    ) -> i32
+        => polymarket_h_cancel_orders_ex, polymarket_w_cancel_orders_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_cancel_market_ex(
+        token_id: *const c_char,
+        out: *mut PolymarketCancelOutcome,
+        capacity: usize,
+        out_count: *mut usize,
+    ) -> i32
+        => polymarket_h_cancel_market_ex, polymarket_w_cancel_market_ex
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_replace_order(
+        order_id: *const c_char,
+        new_price: f64,
+        new_size: f64,
+        out: *mut PolymarketOrderResultEx,
+    ) -> i32
+        => polymarket_h_replace_order, polymarket_w_replace_order
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_get_open_orders(
+        token_id: *const c_char,
+        out: *mut PolymarketOpenOrder,
+        capacity: usize,
+        out_count: *mut usize,
+    ) -> i32
+        => polymarket_h_get_open_orders, polymarket_w_get_open_orders
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_get_trades(
+        token_id: *const c_char,
+        since_ms: u64,
+        out: *mut PolymarketTrade,
+        capacity: usize,
+        out_count: *mut usize,
+    ) -> i32
+        => polymarket_h_get_trades, polymarket_w_get_trades
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_get_order(order_id: *const c_char, out: *mut PolymarketOrderStatus) -> i32
+        => polymarket_h_get_order, polymarket_w_get_order
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_submit_market_buy(token_id: *const c_char, usdc_amount: f64) -> i64
+        => polymarket_h_submit_market_buy, polymarket_w_submit_market_buy
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_submit_market_sell(token_id: *const c_char, size: f64) -> i64
+        => polymarket_h_submit_market_sell, polymarket_w_submit_market_sell
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_submit_limit_buy(token_id: *const c_char, price: f64, usdc_amount: f64) -> i64
+        => polymarket_h_submit_limit_buy, polymarket_w_submit_limit_buy
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_submit_limit_sell(token_id: *const c_char, price: f64, size: f64) -> i64
+        => polymarket_h_submit_limit_sell, polymarket_w_submit_limit_sell
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_submit_cancel(order_id: *const c_char) -> i64
+        => polymarket_h_submit_cancel, polymarket_w_submit_cancel
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    /// For requests submitted through the same handle or wallet
+    polymarket_poll_result(request_id: u64, out: *mut PolymarketOrderResultEx) -> i32
+        => polymarket_h_poll_result, polymarket_w_poll_result
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    /// For requests submitted through the same handle or wallet
+    polymarket_abort_request(request_id: u64) -> i32
+        => polymarket_h_abort_request, polymarket_w_abort_request
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_get_balance() -> i64
+        => polymarket_h_get_balance, polymarket_w_get_balance
+        else -1;
+
+    polymarket_get_available_balance() -> i64
+        => polymarket_h_get_available_balance, polymarket_w_get_available_balance
+        else -1;
+
+    polymarket_get_token_balance(token_id: *const c_char) -> i64
+        => polymarket_h_get_token_balance, polymarket_w_get_token_balance
+        else -1;
+
+    polymarket_get_client_order(client_order_id: *const c_char) -> i64
+        => polymarket_h_get_client_order, polymarket_w_get_client_order
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_get_positions() -> i64
+        => polymarket_h_get_positions, polymarket_w_get_positions
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_split_position(condition_id: *const c_char, neg_risk: bool, amount_raw: i64) -> i64
+        => polymarket_h_split_position, polymarket_w_split_position
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_merge_positions(condition_id: *const c_char, neg_risk: bool, amount_raw: i64) -> i64
+        => polymarket_h_merge_positions, polymarket_w_merge_positions
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_convert_positions(
+        question_ids: *const *const c_char,
+        count: usize,
+        amount_raw: i64,
+    ) -> i64
+        => polymarket_h_convert_positions, polymarket_w_convert_positions
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_redeem(condition_id: *const c_char) -> i64
+        => polymarket_h_redeem, polymarket_w_redeem
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_check_allowances() -> i64
+        => polymarket_h_check_allowances, polymarket_w_check_allowances
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_approve_allowances() -> i64
+        => polymarket_h_approve_allowances, polymarket_w_approve_allowances
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_get_gas_balance() -> i64
+        => polymarket_h_get_gas_balance, polymarket_w_get_gas_balance
+        else POLYMARKET_ERR_NOT_INITIALIZED as i64;
+
+    polymarket_set_low_gas_threshold(threshold_pol: f64) -> i32
+        => polymarket_h_set_low_gas_threshold, polymarket_w_set_low_gas_threshold
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_set_balance_buffer(buffer_usdc: f64) -> i32
+        => polymarket_h_set_balance_buffer, polymarket_w_set_balance_buffer
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    /// The session is live, so the journal opens at once
+    polymarket_set_journal(path: *const c_char) -> i32
+        => polymarket_h_set_journal, polymarket_w_set_journal
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_halt_trading(cancel_open: bool) -> i32
+        => polymarket_h_halt_trading, polymarket_w_halt_trading
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_resume_trading() -> i32
+        => polymarket_h_resume_trading, polymarket_w_resume_trading
+        else POLYMARKET_ERR_NOT_INITIALIZED;
+
+    polymarket_is_trading_halted() -> i32
+        => polymarket_h_is_trading_halted, polymarket_w_is_trading_halted
+        else -1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str =
+        include_str!("../../../../include/flox-connectors/polymarket/polymarket_ffi.h");
+
+    #[test]
+    fn header_declares_every_bound_entry_point() {
+        for (on_handle, on_wallet) in BOUND_ENTRY_POINTS {
+            for name in [on_handle, on_wallet] {
+                assert!(
+                    HEADER.contains(&format!(" {}(", name)),
+                    "{} missing from polymarket_ffi.h",
+                    name
+                );
+            }
+        }
+        // And nothing the list lacks
+        for family in ["polymarket_h_", "polymarket_w_"] {
+            let declared = ["int32_t ", "int64_t "]
+                .iter()
+                .map(|ret| HEADER.matches(&format!("{}{}", ret, family)).count())
+                .sum::<usize>();
+            assert_eq!(declared, BOUND_ENTRY_POINTS.len(), "{}*", family);
+        }
+    }
+}
@@ -0,0 +1,535 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Unix domain socket server (feature `ipc`)
+//!
+//! Lets a co-located process trade through this executor without loading
+//! it in-process, so a crash on either side doesn't take the other down.
+//!
+//! Framing: every message is `u32 length` followed by `length` bytes; all
+//! integers little-endian, strings are `u16 length` + UTF-8 bytes (events
+//! payload: `u32 length`). A message starts with `u8 type, u64 tag`; the
+//! tag is chosen by the client and echoed in the reply.
+//!
+//! Client -> server:
+//! - 1 MARKET_BUY   token, f64 usdc_amount
+//! - 2 MARKET_SELL  token, f64 size
+//! - 3 LIMIT_BUY    token, f64 price, f64 usdc_amount
+//! - 4 LIMIT_SELL   token, f64 price, f64 size
+//! - 5 CANCEL       order_id
+//! - 6 CANCEL_ALL
+//! - 7 SUBSCRIBE    u32 kind_mask (POLYMARKET_EVENT_*, 0 = unsubscribe)
+//! - 8 PING
+//!
+//! Server -> client:
+//! - 0x81 ORDER_RESULT  i32 error_code, u8 success, i64 filled_qty_raw,
+//!   i64 avg_price_raw, i64 remaining_qty_raw, i64 fee_raw, u64 latency_ms,
+//!   order_id (for MARKET_*/LIMIT_*/CANCEL)
+//! - 0x82 ACK    i32 error_code (for CANCEL_ALL/SUBSCRIBE/PING)
+//! - 0x83 EVENT  (tag 0) u32 kind, i32 code, u64 timestamp_ms, token, payload
+//!
+//! Requests are handled concurrently; replies may arrive out of order.
+//! A malformed or oversized frame closes the connection. Frames wait for
+//! the client in a queue of WRITE_QUEUE: an event that does not fit is
+//! dropped (counted by polymarket_events_dropped), a reply that does not
+//! fit closes the connection. Stopping the server closes every connection
+//! once the replies of its requests in flight are written.
+
+use std::ffi::c_void;
+use std::ffi::CStr;
+use std::os::unix::fs::PermissionsExt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use polymarket_executor_core::{
+    parse_token, OrderError, OrderRequest, MARKET_BUY_PRICE, MARKET_SELL_PRICE,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::unix::OwnedReadHalf;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::sync::{watch, Notify};
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::events::{self, PolymarketEvent, POLYMARKET_DELIVER_THREAD};
+use crate::{
+    cancel_task, emit_order_event, get_executor, order_task, Executor, OrderOutcome,
+    POLYMARKET_ERR_CANCEL_FAILED,
+    POLYMARKET_ERR_INVALID_ARGUMENT, POLYMARKET_ERR_INVALID_TOKEN, POLYMARKET_ERR_NOT_INITIALIZED,
+    POLYMARKET_ERR_READ_ONLY, POLYMARKET_OK,
+};
+
+/// Largest frame accepted from a client
+const MAX_FRAME: usize = 64 * 1024;
+
+/// Frames queued for a client before it counts as not reading
+const WRITE_QUEUE: usize = 1024;
+
+/// Longest a closing connection waits to write the replies of its
+/// requests in flight
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(15);
+
+const MSG_MARKET_BUY: u8 = 1;
+const MSG_MARKET_SELL: u8 = 2;
+const MSG_LIMIT_BUY: u8 = 3;
+const MSG_LIMIT_SELL: u8 = 4;
+const MSG_CANCEL: u8 = 5;
+const MSG_CANCEL_ALL: u8 = 6;
+const MSG_SUBSCRIBE: u8 = 7;
+const MSG_PING: u8 = 8;
+
+const MSG_ORDER_RESULT: u8 = 0x81;
+const MSG_ACK: u8 = 0x82;
+const MSG_EVENT: u8 = 0x83;
+
+struct Server {
+    path: String,
+    task: JoinHandle<()>,
+    /// Set on stop: every connection closes
+    stopped: watch::Sender<bool>,
+}
+
+static SERVER: Mutex<Option<Server>> = Mutex::new(None);
+
+/// Bind `path` and serve on the executor runtime
+/// Replaces a stale socket file; the socket is made owner-only (0600)
+pub fn start(executor: &Arc<Executor>, path: &str) -> std::io::Result<()> {
+    let mut server = SERVER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(old) = server.take() {
+        stop_server(old);
+    }
+
+    let _ = std::fs::remove_file(path);
+    let listener = {
+        let _guard = executor.runtime.enter();
+        UnixListener::bind(path)?
+    };
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+
+    info!("[IPC] listening on {}", path);
+    let (stopped, stop) = watch::channel(false);
+    let tasks = executor.clone();
+    let task = executor.spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tasks.spawn(serve(stream, stop.clone()));
+                }
+                Err(e) => {
+                    error!("[IPC ERROR] accept | error={}", e);
+                    break;
+                }
+            }
+        }
+    });
+
+    *server = Some(Server {
+        path: path.to_string(),
+        task,
+        stopped,
+    });
+    Ok(())
+}
+
+/// Stop accepting connections, close the open ones and remove the socket
+/// file
+/// Returns false if no server was running
+pub fn stop() -> bool {
+    let server = SERVER.lock().unwrap_or_else(|e| e.into_inner()).take();
+    match server {
+        Some(server) => {
+            stop_server(server);
+            true
+        }
+        None => false,
+    }
+}
+
+fn stop_server(server: Server) {
+    server.task.abort();
+    let _ = server.stopped.send(true);
+    let _ = std::fs::remove_file(&server.path);
+    info!("[IPC] stopped {}", server.path);
+}
+
+/// Writer queue of one connection
+#[derive(Clone)]
+struct Outbox {
+    tx: mpsc::Sender<Vec<u8>>,
+    /// Notified when a reply did not fit: the client stopped reading
+    stuck: Arc<Notify>,
+}
+
+impl Outbox {
+    /// Queue a reply; a client too far behind is disconnected
+    fn reply(&self, frame: Vec<u8>) {
+        if let Err(TrySendError::Full(_)) = self.tx.try_send(frame) {
+            self.stuck.notify_one();
+        }
+    }
+
+    /// Queue an event; dropped and counted if the client is behind
+    fn event(&self, frame: Vec<u8>) {
+        if let Err(TrySendError::Full(_)) = self.tx.try_send(frame) {
+            events::count_dropped();
+        }
+    }
+}
+
+/// Event subscription of one connection, owned by the event bus callback
+struct Subscription {
+    id: i32,
+    user_data: *mut Outbox,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        // No invocation is running or will run once unregister returns
+        events::unregister(self.id);
+        drop(unsafe { Box::from_raw(self.user_data) });
+    }
+}
+
+// SAFETY: the outbox behind the pointer is Send + Sync
+unsafe impl Send for Subscription {}
+
+extern "C" fn on_event(event: *const PolymarketEvent, user_data: *mut c_void) {
+    let (event, outbox) = unsafe { (&*event, &*(user_data as *const Outbox)) };
+    let token = if event.token_id.is_null() {
+        ""
+    } else {
+        unsafe { CStr::from_ptr(event.token_id) }
+            .to_str()
+            .unwrap_or("")
+    };
+    let payload = unsafe { CStr::from_ptr(event.payload) }.to_bytes();
+
+    let mut frame = Frame::new(MSG_EVENT, 0);
+    frame.u32(event.kind);
+    frame.i32(event.code);
+    frame.u64(event.timestamp_ms);
+    frame.str(token);
+    frame.u32(payload.len() as u32);
+    frame.buf.extend_from_slice(payload);
+    outbox.event(frame.finish());
+}
+
+async fn serve(stream: UnixStream, mut stop: watch::Receiver<bool>) {
+    let (mut reader, mut writer) = stream.into_split();
+    let (tx, mut rx) = mpsc::channel::<Vec<u8>>(WRITE_QUEUE);
+    let outbox = Outbox {
+        tx,
+        stuck: Arc::new(Notify::new()),
+    };
+
+    let mut writer_task = tokio::spawn(async move {
+        while let Some(frame) = rx.recv().await {
+            if writer.write_all(&frame).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut subscription: Option<Subscription> = None;
+    let mut buf = Vec::new();
+    let mut flush = true;
+    loop {
+        tokio::select! {
+            read = read_frame(&mut reader, &mut buf) => {
+                if !read {
+                    break;
+                }
+            }
+            // Also when the server is gone without a stop
+            _ = stop.wait_for(|stopped| *stopped) => {
+                info!("[IPC] server stopped, closing connection");
+                break;
+            }
+            _ = outbox.stuck.notified() => {
+                warn!("[IPC] client not reading its replies, closing");
+                flush = false;
+                break;
+            }
+        }
+        if !handle(&buf, &outbox, &mut subscription) {
+            warn!("[IPC] malformed frame, closing");
+            break;
+        }
+    }
+
+    drop(subscription);
+    drop(outbox);
+    let flushed = flush
+        && tokio::time::timeout(FLUSH_TIMEOUT, &mut writer_task)
+            .await
+            .is_ok();
+    if !flushed {
+        writer_task.abort();
+    }
+}
+
+/// Read one frame into `buf`; false once the connection is done
+async fn read_frame(reader: &mut OwnedReadHalf, buf: &mut Vec<u8>) -> bool {
+    let Ok(len) = reader.read_u32_le().await else {
+        return false;
+    };
+    let len = len as usize;
+    if len > MAX_FRAME {
+        warn!(
+            "[IPC] frame of {} bytes exceeds {}, closing",
+            len, MAX_FRAME
+        );
+        return false;
+    }
+    buf.resize(len, 0);
+    reader.read_exact(buf).await.is_ok()
+}
+
+/// Dispatch one request; false if it can't be parsed
+fn handle(msg: &[u8], outbox: &Outbox, subscription: &mut Option<Subscription>) -> bool {
+    let mut r = Reader { buf: msg };
+    let (kind, tag) = match (r.u8(), r.u64()) {
+        (Some(k), Some(t)) => (k, t),
+        _ => return false,
+    };
+
+    match kind {
+        MSG_MARKET_BUY | MSG_MARKET_SELL | MSG_LIMIT_BUY | MSG_LIMIT_SELL => {
+            let token_id = match r.str() {
+                Some(t) => t.to_string(),
+                None => return false,
+            };
+            let (a, b) = match kind {
+                MSG_LIMIT_BUY | MSG_LIMIT_SELL => (r.f64(), r.f64()),
+                _ => (r.f64(), Some(0.0)),
+            };
+            let (a, b) = match (a, b) {
+                (Some(a), Some(b)) => (a, b),
+                _ => return false,
+            };
+            spawn_order(kind, tag, token_id, a, b, outbox.clone());
+        }
+        MSG_CANCEL => {
+            let order_id = match r.str() {
+                Some(id) => id.to_string(),
+                None => return false,
+            };
+            let executor = match get_executor() {
+                Some(e) => e,
+                None => {
+                    reply_error(outbox, tag, POLYMARKET_ERR_NOT_INITIALIZED);
+                    return true;
+                }
+            };
+            let outbox = outbox.clone();
+            executor.clone().spawn(async move {
+                let outcome = cancel_task(executor, order_id).await;
+                emit_order_event("CANCEL", "", None, &outcome);
+                outbox.reply(order_result(tag, &outcome));
+            });
+        }
+        MSG_CANCEL_ALL => {
+            let executor = match get_executor() {
+                Some(e) => e,
+                None => {
+                    reply_ack(outbox, tag, POLYMARKET_ERR_NOT_INITIALIZED);
+                    return true;
+                }
+            };
+            let outbox = outbox.clone();
+            executor.clone().spawn(async move {
+                let code = match executor.trader().cancel_all().await {
+                    Ok(outcomes) if outcomes.iter().any(|o| o.is_failure()) => {
+                        POLYMARKET_ERR_CANCEL_FAILED
+                    }
+                    Ok(_) => POLYMARKET_OK,
+                    Err(OrderError::ReadOnly) => POLYMARKET_ERR_READ_ONLY,
+                    Err(_) => POLYMARKET_ERR_CANCEL_FAILED,
+                };
+                reply_ack(&outbox, tag, code);
+            });
+        }
+        MSG_SUBSCRIBE => {
+            let mask = match r.u32() {
+                Some(m) => m,
+                None => return false,
+            };
+            *subscription = None;
+            let code = if mask == 0 {
+                POLYMARKET_OK
+            } else {
+                let user_data = Box::into_raw(Box::new(outbox.clone()));
+                match events::register(
+                    mask,
+                    POLYMARKET_DELIVER_THREAD,
+                    on_event,
+                    user_data as *mut c_void,
+                ) {
+                    Some(id) => {
+                        *subscription = Some(Subscription { id, user_data });
+                        POLYMARKET_OK
+                    }
+                    None => {
+                        drop(unsafe { Box::from_raw(user_data) });
+                        POLYMARKET_ERR_INVALID_ARGUMENT
+                    }
+                }
+            };
+            reply_ack(outbox, tag, code);
+        }
+        MSG_PING => reply_ack(outbox, tag, POLYMARKET_OK),
+        _ => return false,
+    }
+    true
+}
+
+fn spawn_order(kind: u8, tag: u64, token_id: String, a: f64, b: f64, outbox: Outbox) {
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return reply_error(&outbox, tag, POLYMARKET_ERR_NOT_INITIALIZED),
+    };
+    if parse_token(&token_id).is_err() {
+        return reply_error(&outbox, tag, POLYMARKET_ERR_INVALID_TOKEN);
+    }
+
+    let request = match kind {
+        MSG_MARKET_BUY => OrderRequest::MarketBuy {
+            usdc_amount: a,
+            max_price: MARKET_BUY_PRICE,
+        },
+        MSG_MARKET_SELL => OrderRequest::MarketSell {
+            size: a,
+            min_price: MARKET_SELL_PRICE,
+        },
+        MSG_LIMIT_BUY => OrderRequest::LimitBuy {
+            price: a,
+            usdc_amount: b,
+        },
+        _ => OrderRequest::LimitSell { price: a, size: b },
+    };
+
+    executor.clone().spawn(async move {
+        let outcome = order_task(executor, token_id.clone(), request, None).await;
+        emit_order_event(request.op(), &token_id, None, &outcome);
+        outbox.reply(order_result(tag, &outcome));
+    });
+}
+
+fn order_result(tag: u64, outcome: &OrderOutcome) -> Vec<u8> {
+    let result = &outcome.result;
+    let mut frame = Frame::new(MSG_ORDER_RESULT, tag);
+    frame.i32(result.error_code);
+    frame.buf.push(result.success as u8);
+    frame.i64(result.filled_qty_raw);
+    frame.i64(result.avg_price_raw);
+    frame.i64(result.remaining_qty_raw);
+    frame.i64(result.fee_raw);
+    frame.u64(result.latency_ms);
+    frame.str(&outcome.order_id);
+    frame.finish()
+}
+
+fn reply_error(outbox: &Outbox, tag: u64, code: i32) {
+    outbox.reply(order_result(tag, &OrderOutcome::error(code)));
+}
+
+fn reply_ack(outbox: &Outbox, tag: u64, code: i32) {
+    let mut frame = Frame::new(MSG_ACK, tag);
+    frame.i32(code);
+    outbox.reply(frame.finish());
+}
+
+/// Outgoing frame, length prefix filled in by finish()
+struct Frame {
+    buf: Vec<u8>,
+}
+
+impl Frame {
+    fn new(kind: u8, tag: u64) -> Self {
+        let mut frame = Self {
+            buf: Vec::with_capacity(64),
+        };
+        frame.u32(0);
+        frame.buf.push(kind);
+        frame.u64(tag);
+        frame
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn i32(&mut self, v: i32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u64(&mut self, v: u64) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn i64(&mut self, v: i64) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    /// u16-prefixed string, cut at 65535 bytes
+    fn str(&mut self, s: &str) {
+        let len = s.len().min(u16::MAX as usize);
+        self.buf.extend_from_slice(&(len as u16).to_le_bytes());
+        self.buf.extend_from_slice(&s.as_bytes()[..len]);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let len = (self.buf.len() - 4) as u32;
+        self.buf[..4].copy_from_slice(&len.to_le_bytes());
+        self.buf
+    }
+}
+
+/// Cursor over an incoming frame
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.buf.len() < N {
+            return None;
+        }
+        let (head, rest) = self.buf.split_at(N);
+        self.buf = rest;
+        head.try_into().ok()
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take().map(u64::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        self.take().map(f64::from_le_bytes)
+    }
+
+    fn str(&mut self) -> Option<&'a str> {
+        let len = self.take().map(u16::from_le_bytes)? as usize;
+        if self.buf.len() < len {
+            return None;
+        }
+        let (s, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        std::str::from_utf8(s).ok()
+    }
+}
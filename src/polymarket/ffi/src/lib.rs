@@ -11,69 +11,391 @@
 //!
 //! C-compatible API for integration with C++ trader.
 //! Provides direct function calls instead of socket IPC.
+//!
+//! Thread safety: every call grabs an `Arc` to the executor and runs as its
+//! own task on the shared runtime of flox_connector, so calls from different
+//! host threads proceed concurrently. Only init/shutdown replace the global
+//! session. Submitted requests, pollers, handle executors and the IPC server
+//! spawn tasks on the same runtime that outlive the call that started them;
+//! each executor keeps track of its tasks and aborts those still running
+//! once it is shut down.
+//!
+//! polymarket_submit_*() spawn the same tasks without waiting; results come
+//! back as POLYMARKET_EVENT_ORDER events tagged with the request id or are
+//! polled by id, and in-flight requests can be aborted by id. The
+//! polymarket_*_async() variants also hand the result to a per-request C
+//! callback.
+//!
+//! Besides the global executor (polymarket_init*()), polymarket_create()
+//! gives executors of their own behind opaque handles, e.g. one per wallet;
+//! see handles.rs.
+//!
+//! No panic crosses the C boundary: every entry point catches it and
+//! returns POLYMARKET_ERR_PANIC (see guard.rs).
+
+// Entry points take C pointers and check them for null before reading
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use std::collections::HashMap;
 use std::ffi::CStr;
+use std::future::Future;
 use std::os::raw::c_char;
-use std::str::FromStr;
-use std::sync::{OnceLock, RwLock};
+#[cfg(feature = "execution")]
+use std::path::Path;
+use std::collections::HashMap;
+#[cfg(feature = "execution")]
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
 
-use alloy::primitives::Address;
-use alloy::signers::local::LocalSigner;
-use alloy::signers::Signer;
-use polymarket_client_sdk::auth::{state::Authenticated, Normal};
-use polymarket_client_sdk::clob::types::request::{BalanceAllowanceRequest, OrderBookSummaryRequest};
-use polymarket_client_sdk::clob::types::{Amount, AssetType, OrderType, Side, SignatureType};
-use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::{Decimal, U256};
-use polymarket_client_sdk::POLYGON;
-use tokio::runtime::Runtime;
-use tracing::error;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::algo::{AlgoControl, AlgoProgress, AlgoSpec};
+use polymarket_executor_core::arbitrage::{ArbAction, ArbOpportunity, ArbScanner};
+use polymarket_executor_core::book::{BookSnapshot, Level};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::basket::{BasketLeg, BasketMode, BasketOutcome, MAX_BASKET_LEGS};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::batch::MAX_BATCH_ORDERS;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::breaker::BreakerState;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::cancels::{CancelOutcome, CancelStatus};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::client_orders::ClientOrderState;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::failover::{CredentialFault, CredentialMonitor};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::fills::{FillAggregator, FillEvent};
+use polymarket_executor_core::gamma::{self, MarketKey, MarketTokens};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::journal::Journal;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::key_source;
+use polymarket_executor_core::market_ws::{BookFeed, BookState, FeedUpdate, MARKET_CHANNEL_PATH};
+#[cfg(feature = "onchain")]
+use polymarket_executor_core::merge::{AutoMerger, MergeEvent, MergeMode, MergeRule};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::notifications::{Notification, NotificationTracker};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::open_orders::OpenOrder;
+use polymarket_executor_core::options::ConnectOptions;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::trades::OwnTrade;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::quoting::{QuoteEvent, QuoteLevel, QuoteSettings, QuoteTarget, Quoter};
+use polymarket_executor_core::rate_limit::RateLimit;
+use polymarket_executor_core::resolution::{Resolution, ResolutionStatus, ResolutionWatcher};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::retry::RetryPolicy;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::risk::RiskUpdate;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::signed_log::{self, SignedLog};
+use polymarket_executor_core::timeouts::CallClass;
+#[cfg(feature = "execution")]
+use polymarket_executor_core::user_ws::{OrderUpdate, OrderUpdateKind};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::venue_error::{self, ApiFailure};
+use polymarket_executor_core::warmup::WarmupProfile;
+use polymarket_executor_core::{decimal_to_raw, parse_token, ConnectError, OrderError, Side, Trader};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::{CredentialChange, OrderFill, OrderRequest, OrderStatus};
+#[cfg(feature = "execution")]
+use polymarket_executor_core::{MARKET_BUY_PRICE, MARKET_SELL_PRICE};
+use flox_connector::ffi::session;
+use flox_connector::runtime;
+use tokio::runtime::{Handle, Runtime};
+use tokio::task::{AbortHandle, JoinHandle};
+use tracing::{error, info, warn};
+#[cfg(feature = "execution")]
+use zeroize::Zeroizing;
 
-const CLOB_HOST: &str = "https://clob.polymarket.com";
+mod abi;
+mod events;
+mod guard;
+#[cfg(feature = "execution")]
+mod handles;
+#[cfg(all(unix, feature = "ipc"))]
+mod ipc;
+mod out_buf;
+#[cfg(feature = "execution")]
+mod requests;
+#[cfg(all(unix, feature = "shm"))]
+mod shm;
+#[cfg(feature = "execution")]
+mod wallets;
 
-type AuthClient = Client<Authenticated<Normal>>;
-type SignerType = LocalSigner<k256::ecdsa::SigningKey>;
+pub use events::{
+    POLYMARKET_DELIVER_POLL, POLYMARKET_DELIVER_THREAD, POLYMARKET_EVENT_ALGO,
+    POLYMARKET_EVENT_ARBITRAGE, POLYMARKET_EVENT_CREDENTIALS, POLYMARKET_EVENT_FILL,
+    POLYMARKET_EVENT_LOG, POLYMARKET_EVENT_MARKET_DATA, POLYMARKET_EVENT_MERGE,
+    POLYMARKET_EVENT_NOTIFICATION, POLYMARKET_EVENT_ORDER, POLYMARKET_EVENT_ORDER_SUMMARY,
+    POLYMARKET_EVENT_QUOTE, POLYMARKET_EVENT_RESOLUTION, POLYMARKET_EVENT_RISK,
+    POLYMARKET_EVENT_VENUE,
+};
+#[cfg(feature = "execution")]
+use requests::{Notify, Polled, Requests};
 
-/// Global executor state
+/// Executor state: the global one, or one per handle
+/// Shared (via Arc) with the runtime task spawned for each FFI call
 struct Executor {
-    client: AuthClient,
-    signer: SignerType,
-    runtime: Runtime,
-    /// Cached min_order_size per token (in shares)
-    min_order_sizes: RwLock<HashMap<String, Decimal>>,
+    /// Authenticated session, order flows and pre-trade checks
+    /// Swapped as a whole by polymarket_rotate_credentials()
+    trader: RwLock<Arc<Trader>>,
+    /// The shared runtime of flox_connector, which runs every executor
+    runtime: &'static Runtime,
+    /// Tasks spawned for the executor; those still running when it is
+    /// closed are aborted
+    tasks: Mutex<Vec<AbortHandle>>,
+    /// Submitted requests: abortable while in flight, then pollable
+    #[cfg(feature = "execution")]
+    requests: Requests<OrderOutcome>,
+    /// Account notifications poller, if started
+    #[cfg(feature = "execution")]
+    notifications: Mutex<Option<JoinHandle<()>>>,
+    /// Running execution algos by id
+    #[cfg(feature = "execution")]
+    algos: Mutex<HashMap<u64, Arc<AlgoControl>>>,
+    #[cfg(feature = "execution")]
+    next_algo_id: AtomicU64,
+    /// Quote targets and resting quotes, kept across quoting restarts
+    #[cfg(feature = "execution")]
+    quoter: Arc<Quoter>,
+    /// Quoting loop, if started, and its stop flag
+    #[cfg(feature = "execution")]
+    quoting: Mutex<Option<(Arc<AtomicBool>, JoinHandle<()>)>>,
+    /// Markets watched for YES/NO pairs, kept across auto-merge restarts
+    #[cfg(feature = "onchain")]
+    merger: Arc<AutoMerger>,
+    /// Auto-merge poller, if started
+    #[cfg(feature = "onchain")]
+    merging: Mutex<Option<JoinHandle<()>>>,
+    /// Markets watched for YES+NO mispricing, kept across scanner restarts
+    arb_scanner: Arc<ArbScanner>,
+    /// Mispricing scanner, if started
+    arb_scan: Mutex<Option<JoinHandle<()>>>,
+    /// Markets watched for resolution, kept across watcher restarts
+    resolutions: Arc<ResolutionWatcher>,
+    /// Resolution poller, if started
+    resolution_watch: Mutex<Option<JoinHandle<()>>>,
+    /// Subscribed tokens and their market channel books
+    books: Arc<BookFeed>,
+    /// Market channel connection, started by the first subscription
+    book_feed: Mutex<Option<JoinHandle<()>>>,
+    /// Host callbacks of public trades by token, held while one runs
+    trade_tapes: Mutex<HashMap<String, TradeTapeCallback>>,
+    /// Metadata refresher of prefetched tokens, if a TTL is set
+    meta_refresh: Mutex<Option<JoinHandle<()>>>,
+    /// Connection keep-alive pinger, if started
+    keep_alive: Mutex<Option<JoinHandle<()>>>,
+    /// Orders followed for fills while fill tracking runs
+    #[cfg(feature = "execution")]
+    fills: Arc<FillAggregator>,
+    /// Fill poller, if started
+    #[cfg(feature = "execution")]
+    fill_tracking: Mutex<Option<JoinHandle<()>>>,
+    /// Gateway RTT prober, if started
+    #[cfg(feature = "execution")]
+    endpoint_probing: Mutex<Option<JoinHandle<()>>>,
+    /// Credentials failed over to, if set
+    #[cfg(feature = "execution")]
+    backup: Mutex<Option<BackupCredentials>>,
+    /// Watches order and cancel outcomes for rejected credentials
+    #[cfg(feature = "execution")]
+    credential_monitor: CredentialMonitor,
+    /// Set while a failover is under way
+    #[cfg(feature = "execution")]
+    failing_over: AtomicBool,
+    /// Venue circuit breaker watch, started with the session
+    #[cfg(feature = "execution")]
+    venue_watch: Mutex<Option<JoinHandle<()>>>,
+    /// Host callback of order updates, held while it runs
+    #[cfg(feature = "execution")]
+    order_updates: Mutex<Option<OrderUpdateCallback>>,
+    /// User channel connection, if subscribed
+    #[cfg(feature = "execution")]
+    user_channel: Mutex<Option<JoinHandle<()>>>,
+    /// Named wallets added on this executor, closed with it
+    #[cfg(feature = "execution")]
+    wallets: wallets::Wallets,
+    /// Blocking calls, submitted requests and algos still running
+    /// Shutdown waits for them to drain
+    in_flight: AtomicUsize,
+}
+
+/// Counts one call, request or algo as in flight until dropped
+struct Busy(Arc<Executor>);
+
+impl Busy {
+    fn new(executor: &Arc<Executor>) -> Self {
+        executor.in_flight.fetch_add(1, Ordering::AcqRel);
+        Self(executor.clone())
+    }
 }
 
-static EXECUTOR: OnceLock<RwLock<Option<Executor>>> = OnceLock::new();
+impl Drop for Busy {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
 
-/// Default timeout for API operations (10 seconds)
-const API_TIMEOUT: Duration = Duration::from_secs(10);
+/// Venue id the global session is kept under
+const VENUE: &str = "polymarket";
 
 /// Get executor reference, returns None if not initialized
-fn get_executor() -> Option<std::sync::RwLockReadGuard<'static, Option<Executor>>> {
-    let lock = EXECUTOR.get_or_init(|| RwLock::new(None));
-    let guard = lock.read().ok()?;
-    if guard.is_some() {
-        Some(guard)
-    } else {
-        None
+/// The executor of a handle while a call runs on it (see handles.rs), else
+/// the global one. The session lock is released before returning, so calls
+/// never hold it across a network round trip
+fn get_executor() -> Option<Arc<Executor>> {
+    #[cfg(feature = "execution")]
+    if let Some(executor) = handles::bound() {
+        return Some(executor);
+    }
+    session::get(VENUE)
+}
+
+impl Executor {
+    /// Current session; requests keep the one they started on
+    fn trader(&self) -> Arc<Trader> {
+        self.trader.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Run `f` on the current session while holding off rotation
+    /// For local state changes (limits, buffer) that must not be lost in a swap
+    fn with_trader<R>(&self, f: impl FnOnce(&Trader) -> R) -> R {
+        let trader = self.trader.read().unwrap_or_else(|e| e.into_inner());
+        f(&trader)
+    }
+
+    /// Spawn a task of the executor on the shared runtime
+    fn spawn<F>(&self, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let task = self.runtime.spawn(task);
+        let mut tasks = self.tasks.lock().unwrap_or_else(|e| e.into_inner());
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(task.abort_handle());
+        task
+    }
+
+    /// Run one operation as its own task on the runtime and wait for it
+    ///
+    /// Each host thread gets an independent task, so concurrent calls
+    /// overlap their round trips instead of queueing behind each other.
+    /// Calling from a runtime thread (e.g. from inside a callback) would
+    /// deadlock the worker, so `runtime::block_on` rejects it instead.
+    fn run<F, Fut, T>(self: &Arc<Self>, op: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(Arc<Executor>) -> Fut,
+        Fut: Future<Output = anyhow::Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let _busy = Busy::new(self);
+        let op = op(self.clone());
+        // Spawned as a task of the executor, so shutdown can abort it
+        let executor = self.clone();
+        match runtime::block_on(async move { executor.spawn(op).await }) {
+            Ok(Ok(result)) => result,
+            // Re-raised on the calling thread, where the FFI guard reports it
+            Ok(Err(e)) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Ok(Err(e)) => Err(anyhow::anyhow!("task aborted: {}", e)),
+            Err(e) => Err(anyhow::anyhow!(e)),
+        }
+    }
+
+    /// Run an order task and hand its outcome to the calling thread
+    #[cfg(feature = "execution")]
+    fn run_order<F, Fut>(
+        self: &Arc<Self>,
+        op: &'static str,
+        token_id: String,
+        task: F,
+    ) -> PolymarketOrderResultEx
+    where
+        F: FnOnce(Arc<Executor>, String) -> Fut,
+        Fut: Future<Output = OrderOutcome> + Send + 'static,
+    {
+        let token_owned = token_id.clone();
+        let outcome = self
+            .run(move |executor| {
+                let fut = task(executor, token_owned);
+                async move { Ok(fut.await) }
+            })
+            .unwrap_or_else(|e| {
+                error!("[FFI ORDER ERROR] {} | error={}", op, e);
+                OrderOutcome::failed(POLYMARKET_ERR_ORDER_FAILED, e.to_string())
+            });
+
+        outcome.deliver();
+        emit_order_event(op, &token_id, None, &outcome);
+        outcome.result
+    }
+
+    /// Spawn an order task without waiting for it
+    /// The outcome goes to POLYMARKET_EVENT_ORDER callbacks tagged with the
+    /// returned request id, unless the request is aborted first
+    #[cfg(feature = "execution")]
+    fn submit_order<F, Fut>(self: &Arc<Self>, op: &'static str, token_id: String, task: F) -> u64
+    where
+        F: FnOnce(Arc<Executor>, String) -> Fut,
+        Fut: Future<Output = OrderOutcome> + Send + 'static,
+    {
+        self.submit_notified(op, token_id, None, task)
+    }
+
+    /// submit_order() that also runs `notify` with the outcome, aborted or not
+    #[cfg(feature = "execution")]
+    fn submit_notified<F, Fut>(
+        self: &Arc<Self>,
+        op: &'static str,
+        token_id: String,
+        notify: Option<Notify<OrderOutcome>>,
+        task: F,
+    ) -> u64
+    where
+        F: FnOnce(Arc<Executor>, String) -> Fut,
+        Fut: Future<Output = OrderOutcome> + Send + 'static,
+    {
+        let fut = task(self.clone(), token_id.clone());
+        let executor = self.clone();
+        let token_owned = token_id.clone();
+        let busy = Busy::new(self);
+        let spawn = |task| self.spawn(task);
+        self.requests.spawn(spawn, op, token_id, notify, move |request_id| async move {
+            let _busy = busy;
+            let outcome = match guard::CatchUnwind(fut).await {
+                Ok(outcome) => outcome,
+                Err(_) => OrderOutcome::error(POLYMARKET_ERR_PANIC),
+            };
+            // Lost the race against polymarket_abort_request(), which reported it
+            if executor.requests.complete(request_id, outcome.clone()) {
+                emit_order_event(op, &token_owned, Some(request_id), &outcome);
+            }
+        })
     }
 }
 
 /// Decimal scale: 6 decimals (1_000_000 = 1.0)
 /// USDC uses 6 decimals, Polymarket shares use 6 decimals
-pub const DECIMAL_SCALE: i64 = 1_000_000;
+pub use polymarket_executor_core::DECIMAL_SCALE;
 
 /// Result structure returned to C++
 /// All monetary values are in raw units (6 decimals)
 #[repr(C)]
 pub struct PolymarketOrderResult {
+    /// True if order was successful
     pub success: bool,
-    pub filled_qty_raw: i64,  // Raw value, divide by 1_000_000 for actual
-    pub avg_price_raw: i64,   // Raw value, divide by 1_000_000 for actual
+    /// Shares filled in raw units (divide by 1M for actual)
+    pub filled_qty_raw: i64,
+    /// Average price in raw units (divide by 1M for actual)
+    pub avg_price_raw: i64,
+    /// Total execution latency
     pub latency_ms: u64,
+    /// Error code if failed
     pub error_code: i32,
+    /// Order ID string (see polymarket_last_order_id for the full id)
     pub order_id: [c_char; 128],
 }
 
@@ -90,7 +412,118 @@ impl Default for PolymarketOrderResult {
     }
 }
 
-impl PolymarketOrderResult {
+/// Versioned order result, filled by the polymarket_*_ex() entry points
+/// Starts with the struct_size/version header (see abi.rs); new fields are
+/// only ever appended. The caller sets struct_size and the library writes
+/// at most that many bytes; version is the ABI version negotiated with
+/// polymarket_negotiate_abi(), fields added after it are left zeroed
+#[derive(Clone)]
+#[repr(C)]
+pub struct PolymarketOrderResultEx {
+    /// Set by caller: sizeof(PolymarketOrderResultEx)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    // v1
+    /// True if order was successful
+    pub success: bool,
+    /// Shares filled in raw units (divide by 1M for actual)
+    pub filled_qty_raw: i64,
+    /// Average price in raw units (divide by 1M for actual)
+    pub avg_price_raw: i64,
+    /// Total execution latency
+    pub latency_ms: u64,
+    /// Error code if failed
+    pub error_code: i32,
+    /// Order ID string
+    pub order_id: [c_char; 128],
+    // v2
+    /// Shares still resting on the book (0 for FAK)
+    pub remaining_qty_raw: i64,
+    /// Taker fee in shares, 0 if none or unknown
+    pub fee_raw: i64,
+    // v3
+    /// Client order id it was placed under, empty if none
+    pub client_order_id: [c_char; 64],
+}
+
+impl abi::Versioned for PolymarketOrderResultEx {
+    fn size_at(version: u32) -> usize {
+        match version {
+            1 => std::mem::offset_of!(Self, remaining_qty_raw),
+            2 => std::mem::offset_of!(Self, client_order_id),
+            _ => std::mem::size_of::<Self>(),
+        }
+    }
+}
+
+impl Default for PolymarketOrderResultEx {
+    fn default() -> Self {
+        Self {
+            struct_size: std::mem::size_of::<Self>() as u32,
+            version: abi::ABI_VERSION,
+            success: false,
+            filled_qty_raw: 0,
+            avg_price_raw: 0,
+            latency_ms: 0,
+            error_code: 0,
+            order_id: [0; 128],
+            remaining_qty_raw: 0,
+            fee_raw: 0,
+            client_order_id: [0; 64],
+        }
+    }
+}
+
+/// v1 view of the full result, for the by-value entry points
+impl From<&PolymarketOrderResultEx> for PolymarketOrderResult {
+    fn from(r: &PolymarketOrderResultEx) -> Self {
+        Self {
+            success: r.success,
+            filled_qty_raw: r.filled_qty_raw,
+            avg_price_raw: r.avg_price_raw,
+            latency_ms: r.latency_ms,
+            error_code: r.error_code,
+            order_id: r.order_id,
+        }
+    }
+}
+
+/// Completion callback of the polymarket_*_async() entry points
+/// `request_id` is the caller's, passed through unchanged; `result` is only
+/// valid during the call, `user_data` is as passed with the request
+pub type PolymarketOrderCallback = extern "C" fn(
+    request_id: u64,
+    result: *const PolymarketOrderResult,
+    user_data: *mut std::ffi::c_void,
+);
+
+/// Host callback and its user data, moved to the runtime thread completing the request
+#[cfg(feature = "execution")]
+struct OrderCallback {
+    callback: PolymarketOrderCallback,
+    request_id: u64,
+    user_data: *mut std::ffi::c_void,
+}
+
+// SAFETY: user_data is opaque to us; the host owns its thread-safety
+#[cfg(feature = "execution")]
+unsafe impl Send for OrderCallback {}
+
+#[cfg(feature = "execution")]
+impl OrderCallback {
+    fn call(&self, outcome: &OrderOutcome) {
+        let result: PolymarketOrderResult = (&outcome.result).into();
+        (self.callback)(self.request_id, &result, self.user_data);
+    }
+
+    fn into_notify(self) -> Notify<OrderOutcome> {
+        Box::new(move |outcome: &OrderOutcome| self.call(outcome))
+    }
+}
+
+#[cfg(feature = "execution")]
+impl PolymarketOrderResultEx {
     fn with_error(code: i32) -> Self {
         Self {
             success: false,
@@ -102,780 +535,7133 @@ impl PolymarketOrderResult {
     fn set_order_id(&mut self, id: &str) {
         let bytes = id.as_bytes();
         let len = bytes.len().min(127);
+        if bytes.len() > len {
+            warn!("[FFI] order_id truncated (len={}), full id via polymarket_last_order_id",
+                bytes.len());
+        }
         for (i, &b) in bytes[..len].iter().enumerate() {
             self.order_id[i] = b as c_char;
         }
         self.order_id[len] = 0;
     }
+
+    /// Ids are checked to fit when staged
+    fn set_client_order_id(&mut self, id: &str) {
+        let len = id.len().min(self.client_order_id.len() - 1);
+        for (i, &b) in id.as_bytes()[..len].iter().enumerate() {
+            self.client_order_id[i] = b as c_char;
+        }
+        self.client_order_id[len] = 0;
+    }
 }
 
-/// Convert Decimal to raw i64 (6 decimals)
-/// Polymarket uses 6 decimal places for USDC and shares
-fn decimal_to_raw(d: Decimal) -> i64 {
-    // Decimal internally stores mantissa and scale
-    // We need to normalize to 6 decimal places
-    // Example: 1.5 (mantissa=15, scale=1) -> 1_500_000
-    let mantissa = d.mantissa();
-    let scale = d.scale();
+/// Pre-trade fill estimate, filled by polymarket_estimate_fill()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call;
+/// raw values in 6 decimals (divide by 1M for actual)
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketFillEstimate {
+    /// Set by caller: sizeof(PolymarketFillEstimate)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// Shares bought/sold, before fees
+    pub filled_qty_raw: i64,
+    /// USDC spent (buy) or received (sell)
+    pub notional_raw: i64,
+    /// 0 if nothing fills
+    pub avg_price_raw: i64,
+    /// Deepest level reached, 0 if nothing fills
+    pub worst_price_raw: i64,
+    /// Taker fee in shares (buys), 0 for sells
+    pub fee_raw: i64,
+    /// Part of the amount that fills, 0.0 - 1.0
+    pub filled_fraction: f64,
+    /// Age of the book snapshot walked
+    pub book_age_ms: u64,
+}
 
-    // Target scale is 6 decimals
-    const TARGET_SCALE: u32 = 6;
+impl abi::Versioned for PolymarketFillEstimate {}
 
-    if scale == TARGET_SCALE {
-        mantissa as i64
-    } else if scale < TARGET_SCALE {
-        // Need to multiply (e.g., scale=2 -> multiply by 10^4)
-        let factor = 10i128.pow(TARGET_SCALE - scale);
-        (mantissa * factor) as i64
-    } else {
-        // Need to divide (e.g., scale=8 -> divide by 10^2)
-        let factor = 10i128.pow(scale - TARGET_SCALE);
-        (mantissa / factor) as i64
-    }
+/// Cached token metadata, filled by polymarket_get_market_meta()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call;
+/// raw values in 6 decimals (divide by 1M for actual)
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketMarketMeta {
+    /// Set by caller: sizeof(PolymarketMarketMeta)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// Current tick size
+    pub tick_size_raw: i64,
+    /// Smallest order in shares
+    pub min_order_size_raw: i64,
+    /// Fee rate the orders are signed with
+    pub fee_rate_bps: u32,
+    /// Market of a neg-risk event
+    pub neg_risk: bool,
 }
 
-/// Error codes
-pub const POLYMARKET_OK: i32 = 0;
-pub const POLYMARKET_ERR_NOT_INITIALIZED: i32 = -1;
-pub const POLYMARKET_ERR_INVALID_PK: i32 = -2;
-pub const POLYMARKET_ERR_AUTH_FAILED: i32 = -3;
-pub const POLYMARKET_ERR_INVALID_TOKEN: i32 = -4;
-pub const POLYMARKET_ERR_ORDER_FAILED: i32 = -5;
-pub const POLYMARKET_ERR_CANCEL_FAILED: i32 = -6;
-pub const POLYMARKET_ERR_MIN_ORDER_SIZE: i32 = -7;  // Order below $1 minimum
-pub const POLYMARKET_ERR_MIN_SHARES: i32 = -8;      // Shares below market minimum
+impl abi::Versioned for PolymarketMarketMeta {}
 
-/// Warm up connection pool by making simple requests
-/// Call this after init to pre-establish TLS connection
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_warmup() -> i32 {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return POLYMARKET_ERR_NOT_INITIALIZED,
-    };
-    let executor = guard.as_ref().unwrap();
-
-    // Make 3 requests to warm up TLS connection pool
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, async {
-            executor.client.ok().await?;
-            executor.client.ok().await?;
-            executor.client.ok().await?;
-            Ok::<_, anyhow::Error>(())
-        }).await.map_err(|_| anyhow::anyhow!("timeout"))?
-    });
+/// Top of a market channel book, filled by polymarket_get_best_bid_ask()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call;
+/// raw values in 6 decimals (divide by 1M for actual)
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketBestBidAsk {
+    /// Set by caller: sizeof(PolymarketBestBidAsk)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// 0 if no bids
+    pub bid_price_raw: i64,
+    /// Shares at the best bid
+    pub bid_size_raw: i64,
+    /// 0 if no asks
+    pub ask_price_raw: i64,
+    /// Shares at the best ask
+    pub ask_size_raw: i64,
+    /// Since the last update of the book
+    pub age_ms: u64,
+    /// v4: generation of the book these were read from
+    pub generation: u64,
+    /// v4: the book missed updates and waits for a resync
+    pub stale: bool,
+}
 
-    match result {
-        Ok(_) => POLYMARKET_OK,
-        Err(e) => {
-            error!("[WARMUP ERROR] {}", e);
-            POLYMARKET_ERR_AUTH_FAILED
+impl abi::Versioned for PolymarketBestBidAsk {
+    fn size_at(version: u32) -> usize {
+        if version < 4 {
+            std::mem::offset_of!(Self, generation)
+        } else {
+            std::mem::size_of::<Self>()
         }
     }
 }
 
-/// Initialize the executor with a private key and funder wallet
-/// Returns 0 on success, negative error code on failure
-/// Can be called again after polymarket_shutdown() to re-initialize
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_init(
-    private_key: *const c_char,
-    funder_wallet: *const c_char,
-) -> i32 {
-    // Check if already initialized
-    let lock = EXECUTOR.get_or_init(|| RwLock::new(None));
-    {
-        let guard = match lock.read() {
-            Ok(g) => g,
-            Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
-        };
-        if guard.is_some() {
-            return POLYMARKET_OK; // Already initialized
-        }
-    }
+/// Request budget of a call class, filled by polymarket_get_rate_budget()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketRateBudget {
+    /// Set by caller: sizeof(PolymarketRateBudget)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// Sustained rate, 0 if the class is not limited
+    pub per_sec: f64,
+    /// Calls that can go back to back
+    pub burst: u32,
+    /// POLYMARKET_RATE_*
+    pub flags: u32,
+    /// Calls that can go now; below 0 while calls wait
+    pub available: f64,
+}
 
-    // Setup tracing
-    let _ = tracing_subscriber::fmt()
-        .with_env_filter("polymarket_executor=info")
-        .try_init();
+impl abi::Versioned for PolymarketRateBudget {}
 
-    let pk = unsafe {
-        if private_key.is_null() {
-            return POLYMARKET_ERR_INVALID_PK;
-        }
-        match CStr::from_ptr(private_key).to_str() {
-            Ok(s) => s.to_string(),
-            Err(_) => return POLYMARKET_ERR_INVALID_PK,
-        }
-    };
+/// Levels per side in a PolymarketBookSnapshot
+pub const POLYMARKET_BOOK_DEPTH: usize = 10;
 
-    let funder_str = unsafe {
-        if funder_wallet.is_null() {
-            return POLYMARKET_ERR_AUTH_FAILED;
-        }
-        match CStr::from_ptr(funder_wallet).to_str() {
-            Ok(s) => s.to_string(),
-            Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
-        }
-    };
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct PolymarketBookLevel {
+    pub price_raw: i64,
+    /// Shares
+    pub size_raw: i64,
+}
 
-    // Create tokio runtime
-    let runtime = match Runtime::new() {
-        Ok(rt) => rt,
-        Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
-    };
+/// Candle of a token's price, filled by polymarket_get_price_history()
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct PolymarketCandle {
+    /// Unix seconds, a multiple of the candle length
+    pub start_ts: i64,
+    pub open_raw: i64,
+    pub high_raw: i64,
+    pub low_raw: i64,
+    pub close_raw: i64,
+    /// Price samples it was built from, at least 1
+    pub samples: u32,
+}
 
-    // Initialize client in runtime
-    let result = runtime.block_on(async {
-        let signer = LocalSigner::from_str(&pk)
-            .map_err(|_| POLYMARKET_ERR_INVALID_PK)?
-            .with_chain_id(Some(POLYGON));
-
-        // Parse funder address from parameter
-        let funder: Address = funder_str.parse()
-            .map_err(|_| POLYMARKET_ERR_AUTH_FAILED)?;
-
-        // IMPORTANT: use_server_time(false) to avoid extra HTTP request per order
-        // Server time sync adds ~80-100ms latency per request
-        let config = Config::builder().use_server_time(false).build();
-        let client = Client::new(CLOB_HOST, config)
-            .map_err(|_| POLYMARKET_ERR_AUTH_FAILED)?
-            .authentication_builder(&signer)
-            .funder(funder)
-            .signature_type(SignatureType::Proxy)
-            .authenticate()
-            .await
-            .map_err(|_| POLYMARKET_ERR_AUTH_FAILED)?;
-
-        Ok::<_, i32>((client, signer))
-    });
+/// Top levels of a market channel book, filled by
+/// polymarket_get_book_snapshot()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketBookSnapshot {
+    /// Set by caller: sizeof(PolymarketBookSnapshot)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// Levels set in bids, best (highest) first
+    pub bid_count: u32,
+    /// Levels set in asks, best (lowest) first
+    pub ask_count: u32,
+    /// Since the last update of the book
+    pub age_ms: u64,
+    pub bids: [PolymarketBookLevel; POLYMARKET_BOOK_DEPTH],
+    pub asks: [PolymarketBookLevel; POLYMARKET_BOOK_DEPTH],
+    /// v4: generation of the book these were read from
+    pub generation: u64,
+    /// v4: the book missed updates and waits for a resync
+    pub stale: bool,
+}
 
-    match result {
-        Ok((client, signer)) => {
-            let executor = Executor {
-                client,
-                signer,
-                runtime,
-                min_order_sizes: RwLock::new(HashMap::new()),
-            };
-            // Store in RwLock
-            if let Ok(mut guard) = lock.write() {
-                *guard = Some(executor);
-                POLYMARKET_OK
-            } else {
-                POLYMARKET_ERR_AUTH_FAILED
-            }
+impl abi::Versioned for PolymarketBookSnapshot {
+    fn size_at(version: u32) -> usize {
+        if version < 4 {
+            std::mem::offset_of!(Self, generation)
+        } else {
+            std::mem::size_of::<Self>()
         }
-        Err(code) => code,
     }
 }
 
-/// Prefetch token metadata to avoid HTTP calls during order execution
-/// Call this for each token before trading to warm up the cache
-/// Returns 0 on success, negative error code on failure
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_prefetch(token_id: *const c_char) -> i32 {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return POLYMARKET_ERR_NOT_INITIALIZED,
-    };
-    let executor = guard.as_ref().unwrap();
+/// Consistency of a market channel book, filled by
+/// polymarket_get_book_state()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketBookState {
+    /// Set by caller: sizeof(PolymarketBookState)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// Bumped by every change of the book, from 1 for the first snapshot
+    pub generation: u64,
+    /// Gaps detected since subscribed
+    pub gaps: u64,
+    /// Stale books restored from a REST snapshot since subscribed
+    pub resyncs: u64,
+    /// Venue time of the newest message applied, 0 if none sent one
+    pub timestamp_ms: u64,
+    /// Since the last update of the book
+    pub age_ms: u64,
+    /// Missed updates; waiting for the venue to resend the book or a resync
+    pub stale: bool,
+}
 
-    let token_str = unsafe {
-        if token_id.is_null() {
-            return POLYMARKET_ERR_INVALID_TOKEN;
-        }
-        match CStr::from_ptr(token_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
-        }
-    };
+impl abi::Versioned for PolymarketBookState {}
 
-    let token = match U256::from_str(token_str) {
-        Ok(t) => t,
-        Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
-    };
+// PolymarketOrderStatus.status
+/// Resting, nothing matched yet
+pub const POLYMARKET_ORDER_STATUS_LIVE: i32 = 0;
+/// Resting, partly matched
+pub const POLYMARKET_ORDER_STATUS_PARTIALLY_FILLED: i32 = 1;
+/// Fully matched
+pub const POLYMARKET_ORDER_STATUS_MATCHED: i32 = 2;
+/// Cancelled, possibly partly matched
+pub const POLYMARKET_ORDER_STATUS_CANCELLED: i32 = 3;
+/// See status_text
+pub const POLYMARKET_ORDER_STATUS_OTHER: i32 = 4;
 
-    // Fetch and cache all metadata with timeout
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, async {
-            // These calls populate the internal cache
-            let tick = executor.client.tick_size(token).await?;
-            let fee = executor.client.fee_rate_bps(token).await?;
-            let neg = executor.client.neg_risk(token).await?;
-
-            // Get order book to fetch min_order_size
-            let book_req = OrderBookSummaryRequest::builder()
-                .token_id(token)
-                .build();
-            let book = executor.client.order_book(&book_req).await?;
-
-            Ok::<_, anyhow::Error>((tick, fee, neg, book.min_order_size))
-        }).await.map_err(|_| anyhow::anyhow!("timeout"))?
-    });
+/// State of an order, filled by polymarket_get_order()
+/// Versioned like PolymarketOrderResultEx: set struct_size before the call;
+/// raw values in 6 decimals (divide by 1M for actual)
+#[derive(Clone, Default)]
+#[repr(C)]
+pub struct PolymarketOrderStatus {
+    /// Set by caller: sizeof(PolymarketOrderStatus)
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// POLYMARKET_ORDER_STATUS_*
+    pub status: i32,
+    /// Limit price
+    pub price_raw: i64,
+    /// Order size in shares
+    pub original_size_raw: i64,
+    /// Shares matched so far
+    pub filled_size_raw: i64,
+    /// Shares still resting, 0 once matched or cancelled
+    pub remaining_size_raw: i64,
+    /// Venue status: LIVE, MATCHED, CANCELED, ...
+    pub status_text: [c_char; 16],
+}
 
-    match result {
-        Ok((_tick, _fee, _neg, min_size)) => {
-            // Cache min_order_size
-            if let Ok(mut cache) = executor.min_order_sizes.write() {
-                cache.insert(token_str.to_string(), min_size);
+impl abi::Versioned for PolymarketOrderStatus {}
+
+#[cfg(feature = "execution")]
+impl PolymarketOrderStatus {
+    fn new(order: &OrderStatus) -> Self {
+        let status = if order.is_live() {
+            if order.matched_qty_raw > 0 {
+                POLYMARKET_ORDER_STATUS_PARTIALLY_FILLED
+            } else {
+                POLYMARKET_ORDER_STATUS_LIVE
             }
-            POLYMARKET_OK
-        }
-        Err(e) => {
-            error!("[PREFETCH ERROR] token={} | error={}", token_str, e);
-            POLYMARKET_ERR_ORDER_FAILED
-        }
+        } else if order.status.eq_ignore_ascii_case("MATCHED") {
+            POLYMARKET_ORDER_STATUS_MATCHED
+        } else if order.status.to_ascii_uppercase().starts_with("CANCEL") {
+            POLYMARKET_ORDER_STATUS_CANCELLED
+        } else {
+            POLYMARKET_ORDER_STATUS_OTHER
+        };
+        let mut value = Self {
+            status,
+            price_raw: order.price_raw,
+            original_size_raw: order.original_qty_raw,
+            filled_size_raw: order.matched_qty_raw,
+            remaining_size_raw: order.remaining_qty_raw(),
+            ..Default::default()
+        };
+        copy_truncated(&mut value.status_text, &order.status);
+        value
     }
 }
 
-/// Execute a market buy order (FAK - Fill and Kill)
-/// Sweeps orderbook at price 0.99 to fill immediately
-/// Returns result with filled quantity, average price, and latency
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_market_buy(
-    token_id: *const c_char,
-    usdc_amount: f64,  // amount in USDC to spend
-) -> PolymarketOrderResult {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return PolymarketOrderResult::with_error(POLYMARKET_ERR_NOT_INITIALIZED),
-    };
-    let executor = guard.as_ref().unwrap();
+// Kinds of PolymarketOrderUpdate
+pub const POLYMARKET_ORDER_UPDATE_PLACED: i32 = 0;
+/// Resting order partly or fully matched
+pub const POLYMARKET_ORDER_UPDATE_MATCHED: i32 = 1;
+pub const POLYMARKET_ORDER_UPDATE_CANCELLED: i32 = 2;
+/// A fill, once per trade status
+pub const POLYMARKET_ORDER_UPDATE_TRADE: i32 = 3;
 
-    let token_str = unsafe {
-        if token_id.is_null() {
-            return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN);
-        }
-        match CStr::from_ptr(token_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
-        }
-    };
+/// Order or fill of the account, from the user channel
+/// Passed to PolymarketOrderUpdateCallback; versioned like
+/// PolymarketOrderResultEx, struct_size is set by the library. Raw values
+/// in 6 decimals (divide by 1M for actual)
+#[repr(C)]
+pub struct PolymarketOrderUpdate {
+    /// Set by library: sizeof the struct it was built with
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// POLYMARKET_ORDER_UPDATE_*
+    pub kind: i32,
+    /// POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+    pub side: i32,
+    /// Order limit price; fill price for trades
+    pub price_raw: i64,
+    /// Order size in shares, 0 for trades
+    pub original_size_raw: i64,
+    /// Shares matched so far, 0 for trades
+    pub size_matched_raw: i64,
+    /// Shares of this fill, 0 for order events
+    pub trade_size_raw: i64,
+    /// Venue time of the event
+    pub timestamp_ms: u64,
+    /// Trades: our order was resting on the book
+    pub maker: bool,
+    pub order_id: [c_char; 128],
+    pub token_id: [c_char; 128],
+    /// Empty for order events
+    pub trade_id: [c_char; 128],
+    /// Trades: MATCHED, MINED, CONFIRMED, RETRYING, FAILED
+    pub trade_status: [c_char; 16],
+}
 
-    let token = match U256::from_str(token_str) {
-        Ok(t) => t,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
-    };
+/// Order update callback of polymarket_subscribe_order_updates(); `update`
+/// is only valid during the call, `user_data` is as passed when subscribing
+pub type PolymarketOrderUpdateCallback =
+    extern "C" fn(update: *const PolymarketOrderUpdate, user_data: *mut std::ffi::c_void);
 
-    let start = Instant::now();
+/// Host callback of order updates and its user data
+#[cfg(feature = "execution")]
+struct OrderUpdateCallback {
+    callback: PolymarketOrderUpdateCallback,
+    user_data: *mut std::ffi::c_void,
+}
 
-    // Round USDC to 6 decimal places (USDC precision)
-    let usdc_rounded = (usdc_amount * 1_000_000.0).floor() / 1_000_000.0;
-    let usdc_decimal = match Decimal::try_from(usdc_rounded) {
-        Ok(d) => d,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED),
-    };
+// SAFETY: user_data is opaque to us; the host owns its thread-safety
+#[cfg(feature = "execution")]
+unsafe impl Send for OrderUpdateCallback {}
 
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, async {
-            // Amount::usdc means "spend this much USDC to buy shares"
-            // Use price 0.99 to sweep entire orderbook (aggressive market buy)
-            let order = executor
-                .client
-                .market_order()
-                .token_id(token)
-                .amount(Amount::usdc(usdc_decimal)?)
-                .side(Side::Buy)
-                .order_type(OrderType::FAK)
-                .price(Decimal::try_from(0.99).unwrap())
-                .build()
-                .await?;
-
-            let signed = executor.client.sign(&executor.signer, order).await?;
-            let response = executor.client.post_order(signed).await?;
-
-            Ok::<_, anyhow::Error>(response)
-        }).await.map_err(|_| anyhow::anyhow!("timeout"))?
-    });
+#[cfg(feature = "execution")]
+impl OrderUpdateCallback {
+    fn call(&self, update: &OrderUpdate) {
+        let mut out = PolymarketOrderUpdate {
+            struct_size: std::mem::size_of::<PolymarketOrderUpdate>() as u32,
+            version: abi::negotiated(),
+            kind: match update.kind {
+                OrderUpdateKind::Placed => POLYMARKET_ORDER_UPDATE_PLACED,
+                OrderUpdateKind::Updated => POLYMARKET_ORDER_UPDATE_MATCHED,
+                OrderUpdateKind::Cancelled => POLYMARKET_ORDER_UPDATE_CANCELLED,
+                OrderUpdateKind::Trade => POLYMARKET_ORDER_UPDATE_TRADE,
+            },
+            side: match update.side {
+                Side::Buy => POLYMARKET_SIDE_BUY,
+                _ => POLYMARKET_SIDE_SELL,
+            },
+            price_raw: decimal_to_raw(update.price),
+            original_size_raw: decimal_to_raw(update.original_size),
+            size_matched_raw: decimal_to_raw(update.size_matched),
+            trade_size_raw: decimal_to_raw(update.trade_size),
+            timestamp_ms: update.timestamp_ms,
+            maker: update.maker,
+            order_id: [0; 128],
+            token_id: [0; 128],
+            trade_id: [0; 128],
+            trade_status: [0; 16],
+        };
+        copy_c_str(&mut out.order_id, &update.order_id);
+        copy_c_str(&mut out.token_id, &update.token_id);
+        copy_c_str(&mut out.trade_id, &update.trade_id);
+        copy_c_str(&mut out.trade_status, &update.trade_status);
+        (self.callback)(&out, self.user_data);
+    }
+}
 
-    let latency_ms = start.elapsed().as_millis() as u64;
+/// A trade printed on a token's public tape, from the market channel
+/// Passed to PolymarketTradeTapeCallback; versioned like
+/// PolymarketOrderUpdate. Raw values in 6 decimals
+#[repr(C)]
+pub struct PolymarketTradePrint {
+    /// Set by library: sizeof the struct it was built with
+    pub struct_size: u32,
+    /// Set by library: negotiated ABI version
+    pub version: u32,
+    /// Taker side: POLYMARKET_SIDE_BUY lifted an ask, POLYMARKET_SIDE_SELL
+    /// hit a bid
+    pub side: i32,
+    pub price_raw: i64,
+    /// Shares traded
+    pub size_raw: i64,
+    /// Venue time of the trade, 0 if not sent
+    pub timestamp_ms: u64,
+    pub token_id: [c_char; 128],
+}
 
-    match result {
-        Ok(resp) => {
-            // For BUY: taking_amount = shares received, making_amount = USDC paid
-            let filled_shares: f64 = resp.taking_amount.try_into().unwrap_or(0.0);
-            let usdc_paid: f64 = resp.making_amount.try_into().unwrap_or(0.0);
-            let avg_price = if filled_shares > 0.0 {
-                usdc_paid / filled_shares
-            } else {
-                0.0
-            };
+/// Trade tape callback of polymarket_subscribe_trade_tape(); `trade` is only
+/// valid during the call, `user_data` is as passed when subscribing
+pub type PolymarketTradeTapeCallback =
+    extern "C" fn(trade: *const PolymarketTradePrint, user_data: *mut std::ffi::c_void);
 
-            // Calculate fee and net shares received (taker fee)
-            // fee_shares = shares * 0.25 * (price * (1 - price))^2
-            let fee_factor = 0.25 * (avg_price * (1.0 - avg_price)).powi(2);
-            let fee_shares = filled_shares * fee_factor;
-            let net_shares = filled_shares - fee_shares;
+/// Host callback of a token's trades and its user data
+struct TradeTapeCallback {
+    callback: PolymarketTradeTapeCallback,
+    user_data: *mut std::ffi::c_void,
+}
 
-            // Return net shares (after fee deduction)
-            let net_shares_raw = (net_shares * 1_000_000.0) as i64;
-            let avg_price_raw = (avg_price * 1_000_000.0) as i64;
+// SAFETY: user_data is opaque to us; the host owns its thread-safety
+unsafe impl Send for TradeTapeCallback {}
 
-            let mut result = PolymarketOrderResult {
-                success: resp.success,
-                filled_qty_raw: net_shares_raw,
-                avg_price_raw,
-                latency_ms,
-                error_code: POLYMARKET_OK,
-                order_id: [0; 128],
-            };
-            result.set_order_id(&resp.order_id);
-            result
-        }
-        Err(e) => {
-            error!("[FFI ORDER ERROR] BUY | error={} | latency={}ms", e, latency_ms);
-            let mut result = PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED);
-            result.latency_ms = latency_ms;
-            result
-        }
+impl TradeTapeCallback {
+    fn call(
+        &self,
+        token_id: &str,
+        price: rust_decimal::Decimal,
+        size: rust_decimal::Decimal,
+        side: Side,
+        timestamp_ms: u64,
+    ) {
+        let mut out = PolymarketTradePrint {
+            struct_size: std::mem::size_of::<PolymarketTradePrint>() as u32,
+            version: abi::negotiated(),
+            side: match side {
+                Side::Buy => POLYMARKET_SIDE_BUY,
+                _ => POLYMARKET_SIDE_SELL,
+            },
+            price_raw: decimal_to_raw(price),
+            size_raw: decimal_to_raw(size),
+            timestamp_ms,
+            token_id: [0; 128],
+        };
+        copy_c_str(&mut out.token_id, token_id);
+        (self.callback)(&out, self.user_data);
     }
 }
 
-/// Place a GTC limit buy order
-/// Returns result with order info
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_limit_buy(
-    token_id: *const c_char,
-    price: f64,       // limit price (0.01-0.99)
-    usdc_amount: f64, // amount in USDC to spend
-) -> PolymarketOrderResult {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return PolymarketOrderResult::with_error(POLYMARKET_ERR_NOT_INITIALIZED),
-    };
-    let executor = guard.as_ref().unwrap();
-
-    let token_str = unsafe {
-        if token_id.is_null() {
-            return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN);
-        }
-        match CStr::from_ptr(token_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
-        }
-    };
+/// Copy `s` into a fixed C string field, truncated to fit its terminator
+fn copy_c_str(dst: &mut [c_char], s: &str) {
+    let len = s.len().min(dst.len() - 1);
+    for (d, &b) in dst.iter_mut().zip(&s.as_bytes()[..len]) {
+        *d = b as c_char;
+    }
+    dst[len] = 0;
+}
 
-    let token = match U256::from_str(token_str) {
-        Ok(t) => t,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
-    };
+// Call classes of polymarket_set_timeout_ms()
+/// Order posts (single and batch) and cancels
+pub const POLYMARKET_CALL_ORDER: i32 = 0;
+/// Books, prefetch, prices, market lookups, warmup
+pub const POLYMARKET_CALL_MARKET_DATA: i32 = 1;
+/// Balances, order lookups, rewards, notifications
+pub const POLYMARKET_CALL_ACCOUNT: i32 = 2;
 
-    let start = Instant::now();
+/// Rate limit flags (polymarket_set_rate_limit)
+pub const POLYMARKET_RATE_REJECT: u32 = 1;
 
-    // Check minimum USDC order size
-    const MIN_ORDER_USDC: f64 = 1.0;
-    if usdc_amount < MIN_ORDER_USDC {
-        error!("[FFI LIMIT BUY] order size ${:.4} below minimum ${}", usdc_amount, MIN_ORDER_USDC);
-        return PolymarketOrderResult::with_error(POLYMARKET_ERR_MIN_ORDER_SIZE);
-    }
+// Order sides (polymarket_estimate_fill, execution algos, baskets, batches)
+pub const POLYMARKET_SIDE_BUY: i32 = 0;
+pub const POLYMARKET_SIDE_SELL: i32 = 1;
 
-    // For limit orders, we need to compute shares with proper precision:
-    // - Taker amount (shares): max 2 decimals
-    // - Maker amount (USDC): max 4 decimals
-    // Use ceil to ensure we don't go below min order size ($1)
-    let shares_raw = (usdc_amount / price * 100.0).ceil() / 100.0;
+/// One leg of a basket order (polymarket_place_basket)
+#[repr(C)]
+pub struct PolymarketBasketLeg {
+    /// Polymarket token ID (numeric string)
+    pub token_id: *const c_char,
+    /// POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+    pub side: i32,
+    /// Shares to buy (net of fees) or sell
+    pub shares: f64,
+    /// Highest price paid (buy) or lowest accepted (sell)
+    pub limit_price: f64,
+}
 
-    // Check minimum shares for this market (use try_read to avoid blocking)
-    if let Some(cache) = executor.min_order_sizes.try_read().ok() {
-        if let Some(&min_shares) = cache.get(token_str) {
-            let min_shares_f64: f64 = min_shares.try_into().unwrap_or(0.0);
-            if shares_raw < min_shares_f64 {
-                error!("[FFI LIMIT BUY] shares {} below market minimum {}", shares_raw, min_shares);
-                return PolymarketOrderResult::with_error(POLYMARKET_ERR_MIN_SHARES);
-            }
-        }
-    }
+/// One limit order of a batch (polymarket_post_orders_batch)
+#[repr(C)]
+pub struct PolymarketBatchOrder {
+    /// Numeric token id
+    pub token_id: *const c_char,
+    /// POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+    pub side: i32,
+    /// Limit price
+    pub price: f64,
+    /// USDC to spend (buy) or shares to sell
+    pub amount: f64,
+    /// 0 for GTC, else GTD deadline (unix ms)
+    pub expires_at_ms: u64,
+}
 
-    let shares_decimal = match Decimal::try_from(shares_raw) {
-        Ok(d) => d,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED),
-    };
+// polymarket_place_basket() flags
+/// Send only if every leg can fill, else unwind
+pub const POLYMARKET_BASKET_ALL_OR_NOTHING: u32 = 1;
+/// Best effort: unwind every fill on any shortfall
+pub const POLYMARKET_BASKET_UNWIND: u32 = 2;
 
-    let price_decimal = match Decimal::try_from(price) {
-        Ok(d) => d,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED),
-    };
+/// Outcome of one order in a bulk cancel (polymarket_cancel_all_ex)
+#[repr(C)]
+pub struct PolymarketCancelOutcome {
+    /// NUL-terminated
+    pub order_id: [c_char; 128],
+    /// POLYMARKET_CANCEL_*
+    pub status: i32,
+    /// Venue's reason, truncated; empty when cancelled
+    pub reason: [c_char; 128],
+}
 
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, async {
-            let order = executor
-                .client
-                .market_order()
-                .token_id(token)
-                .amount(Amount::shares(shares_decimal)?)
-                .side(Side::Buy)
-                .order_type(OrderType::GTC)
-                .price(price_decimal)
-                .build()
-                .await?;
-
-            let signed = executor.client.sign(&executor.signer, order).await?;
-            let response = executor.client.post_order(signed).await?;
-
-            Ok::<_, anyhow::Error>(response)
-        }).await.map_err(|_| anyhow::anyhow!("timeout"))?
-    });
+// PolymarketCancelOutcome statuses
+/// Cancelled
+pub const POLYMARKET_CANCEL_CANCELLED: i32 = 0;
+/// Matched before the cancel arrived
+pub const POLYMARKET_CANCEL_ALREADY_FILLED: i32 = 1;
+/// Unknown to the venue, or already cancelled
+pub const POLYMARKET_CANCEL_NOT_FOUND: i32 = 2;
+/// Refused by the venue: may still be resting
+pub const POLYMARKET_CANCEL_FAILED: i32 = 3;
 
-    let latency_ms = start.elapsed().as_millis() as u64;
+#[cfg(feature = "execution")]
+impl PolymarketCancelOutcome {
+    fn write(out: &mut Self, outcome: &CancelOutcome) {
+        copy_truncated(&mut out.order_id, &outcome.order_id);
+        copy_truncated(&mut out.reason, &outcome.reason);
+        out.status = match outcome.status {
+            CancelStatus::Cancelled => POLYMARKET_CANCEL_CANCELLED,
+            CancelStatus::AlreadyFilled => POLYMARKET_CANCEL_ALREADY_FILLED,
+            CancelStatus::NotFound => POLYMARKET_CANCEL_NOT_FOUND,
+            CancelStatus::Failed => POLYMARKET_CANCEL_FAILED,
+        };
+    }
+}
 
-    match result {
-        Ok(resp) => {
-            // GTC limit orders are maker orders - NO FEE when resting in book
-            // Fee only applies if order filled immediately as taker
-            // We return raw filled amount - fee calculation should be done
-            // by caller based on whether order was maker or taker
-            let filled_qty_raw = decimal_to_raw(resp.taking_amount);
-
-            let mut result = PolymarketOrderResult {
-                success: resp.success,
-                filled_qty_raw,
-                avg_price_raw: decimal_to_raw(price_decimal),
-                latency_ms,
-                error_code: POLYMARKET_OK,
-                order_id: [0; 128],
-            };
-            result.set_order_id(&resp.order_id);
-            result
+/// Order resting on the book (polymarket_get_open_orders)
+#[repr(C)]
+pub struct PolymarketOpenOrder {
+    /// NUL-terminated
+    pub order_id: [c_char; 128],
+    /// NUL-terminated
+    pub token_id: [c_char; 128],
+    /// POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+    pub side: i32,
+    /// Limit price (6 decimals)
+    pub price_raw: i64,
+    /// Order size in shares (6 decimals)
+    pub original_size_raw: i64,
+    /// Shares not matched yet (6 decimals)
+    pub remaining_size_raw: i64,
+    /// Unix ms
+    pub created_at_ms: u64,
+}
+
+#[cfg(feature = "execution")]
+impl PolymarketOpenOrder {
+    fn write(out: &mut Self, order: &OpenOrder) {
+        copy_truncated(&mut out.order_id, &order.order_id);
+        copy_truncated(&mut out.token_id, &order.token_id);
+        out.side = match order.side {
+            Side::Buy => POLYMARKET_SIDE_BUY,
+            _ => POLYMARKET_SIDE_SELL,
+        };
+        out.price_raw = order.price_raw;
+        out.original_size_raw = order.original_qty_raw;
+        out.remaining_size_raw = order.remaining_qty_raw;
+        out.created_at_ms = order.created_at_ms;
+    }
+}
+
+/// One fill of one of our orders (polymarket_get_trades)
+#[repr(C)]
+pub struct PolymarketTrade {
+    /// NUL-terminated
+    pub trade_id: [c_char; 128],
+    /// NUL-terminated
+    pub order_id: [c_char; 128],
+    /// NUL-terminated
+    pub token_id: [c_char; 128],
+    /// POLYMARKET_SIDE_BUY or POLYMARKET_SIDE_SELL
+    pub side: i32,
+    /// Fill price (6 decimals)
+    pub price_raw: i64,
+    /// Shares filled (6 decimals)
+    pub size_raw: i64,
+    /// Taker fee in shares (buys), 0 if none
+    pub fee_raw: i64,
+    /// Our order rested on the book
+    pub maker: bool,
+    /// MATCHED, MINED, CONFIRMED, RETRYING, FAILED
+    pub status: [c_char; 16],
+    /// Unix ms
+    pub matched_at_ms: u64,
+}
+
+#[cfg(feature = "execution")]
+impl PolymarketTrade {
+    fn write(out: &mut Self, trade: &OwnTrade) {
+        copy_truncated(&mut out.trade_id, &trade.trade_id);
+        copy_truncated(&mut out.order_id, &trade.order_id);
+        copy_truncated(&mut out.token_id, &trade.token_id);
+        out.side = match trade.side {
+            Side::Buy => POLYMARKET_SIDE_BUY,
+            _ => POLYMARKET_SIDE_SELL,
+        };
+        out.price_raw = trade.price_raw;
+        out.size_raw = trade.size_raw;
+        out.fee_raw = trade.fee_raw;
+        out.maker = trade.maker;
+        copy_truncated(&mut out.status, &trade.status);
+        out.matched_at_ms = trade.matched_at_ms;
+    }
+}
+
+/// Copy `s` into a fixed C string field, truncated to fit with its NUL
+#[cfg(feature = "execution")]
+fn copy_truncated(dst: &mut [c_char], s: &str) {
+    let len = s.len().min(dst.len() - 1);
+    for (d, &b) in dst.iter_mut().zip(&s.as_bytes()[..len]) {
+        *d = b as c_char;
+    }
+    dst[len] = 0;
+}
+
+/// Result of an order task plus the untruncated order id and error message
+/// Built on a runtime thread; the id and message reach the host's
+/// thread-local copies (polymarket_last_order_id,
+/// polymarket_last_error_message) only once handed back to the calling thread
+#[cfg(feature = "execution")]
+#[derive(Clone)]
+struct OrderOutcome {
+    result: PolymarketOrderResultEx,
+    order_id: String,
+    /// Why it failed: the venue's message or the local rejection; empty on
+    /// success
+    error_message: String,
+    /// Client order id the order was placed under, empty if none
+    client_order_id: String,
+}
+
+#[cfg(feature = "execution")]
+impl OrderOutcome {
+    fn new(mut result: PolymarketOrderResultEx, order_id: &str) -> Self {
+        result.set_order_id(order_id);
+        Self {
+            result,
+            order_id: order_id.to_string(),
+            error_message: String::new(),
+            client_order_id: String::new(),
         }
-        Err(e) => {
-            error!("[FFI ORDER ERROR] LIMIT BUY | error={} | latency={}ms", e, latency_ms);
-            let mut result = PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED);
-            result.latency_ms = latency_ms;
-            result
+    }
+
+    fn error(code: i32) -> Self {
+        Self {
+            result: PolymarketOrderResultEx::with_error(code),
+            order_id: String::new(),
+            error_message: String::new(),
+            client_order_id: String::new(),
+        }
+    }
+
+    /// Echo the client order id the order was placed under
+    fn tagged(mut self, client_order_id: Option<String>) -> Self {
+        if let Some(id) = client_order_id {
+            self.result.set_client_order_id(&id);
+            self.client_order_id = id;
+        }
+        self
+    }
+
+    fn failed(code: i32, message: String) -> Self {
+        Self {
+            error_message: message,
+            ..Self::error(code)
         }
     }
+
+    /// Hand the id and message to the calling thread's copies
+    fn deliver(&self) {
+        out_buf::set_last_order_id(&self.order_id);
+        out_buf::set_last_error(&self.error_message);
+    }
 }
 
-/// Place a GTC limit sell order
-/// Returns result with order info
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_limit_sell(
-    token_id: *const c_char,
-    price: f64,   // limit price (0.01-0.99)
-    size: f64,    // number of shares to sell
-) -> PolymarketOrderResult {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return PolymarketOrderResult::with_error(POLYMARKET_ERR_NOT_INITIALIZED),
-    };
-    let executor = guard.as_ref().unwrap();
+/// Result for an order rejected before it reached the runtime
+#[cfg(feature = "execution")]
+fn rejected(code: i32) -> PolymarketOrderResultEx {
+    out_buf::set_last_order_id("");
+    out_buf::set_last_error("");
+    PolymarketOrderResultEx::with_error(code)
+}
+
+/// order_target() of an order entry point, taking the client order id
+/// staged for it; the staged id is used up even if the call is refused
+#[cfg(feature = "execution")]
+fn order_call(token_id: *const c_char) -> Result<(Arc<Executor>, String, Option<String>), i32> {
+    let client_order_id = take_client_order_id();
+    let (executor, token_str) = order_target(token_id)?;
+    Ok((executor, token_str, client_order_id))
+}
+
+/// Resolve the executor and validate the token id of an order call
+fn order_target(token_id: *const c_char) -> Result<(Arc<Executor>, String), i32> {
+    let executor = get_executor().ok_or(POLYMARKET_ERR_NOT_INITIALIZED)?;
 
     let token_str = unsafe {
         if token_id.is_null() {
-            return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN);
-        }
-        match CStr::from_ptr(token_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
+            return Err(POLYMARKET_ERR_INVALID_TOKEN);
         }
+        CStr::from_ptr(token_id).to_str().map_err(|_| POLYMARKET_ERR_INVALID_TOKEN)?
     };
 
-    let token = match U256::from_str(token_str) {
-        Ok(t) => t,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
-    };
+    parse_token(token_str).map_err(|_| POLYMARKET_ERR_INVALID_TOKEN)?;
+    Ok((executor, token_str.to_string()))
+}
 
-    let start = Instant::now();
+/// Publish the outcome of an order request to POLYMARKET_EVENT_ORDER callbacks
+/// `request_id` is set for requests submitted with polymarket_submit_*()
+#[cfg(feature = "execution")]
+fn emit_order_event(op: &str, token: &str, request_id: Option<u64>, outcome: &OrderOutcome) {
+    if !events::wants(POLYMARKET_EVENT_ORDER) {
+        return;
+    }
+    let result = &outcome.result;
+    let mut payload = serde_json::json!({
+        "op": op,
+        "success": result.success,
+        "order_id": outcome.order_id,
+        "filled_qty_raw": result.filled_qty_raw,
+        "avg_price_raw": result.avg_price_raw,
+        "remaining_qty_raw": result.remaining_qty_raw,
+        "latency_ms": result.latency_ms,
+    });
+    if let Some(id) = request_id {
+        payload["request_id"] = id.into();
+    }
+    if !outcome.client_order_id.is_empty() {
+        payload["client_order_id"] = outcome.client_order_id.as_str().into();
+    }
+    if !outcome.error_message.is_empty() {
+        payload["error"] = outcome.error_message.as_str().into();
+    }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_ORDER,
+        result.error_code,
+        Some(token).filter(|t| !t.is_empty()),
+        payload,
+    ));
+}
 
-    // Round size to 2 decimal places (Polymarket requirement)
-    let size_rounded = (size * 100.0).floor() / 100.0;
-    let size_decimal = match Decimal::try_from(size_rounded) {
-        Ok(d) => d,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED),
-    };
+/// Publish a local pre-trade rejection to POLYMARKET_EVENT_RISK callbacks
+#[cfg(feature = "execution")]
+fn emit_risk_event(token: &str, code: i32, reason: &str) {
+    if !events::wants(POLYMARKET_EVENT_RISK) {
+        return;
+    }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_RISK,
+        code,
+        Some(token),
+        serde_json::json!({ "reason": reason }),
+    ));
+}
 
-    let price_decimal = match Decimal::try_from(price) {
-        Ok(d) => d,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED),
-    };
+/// Publish a halt (POLYMARKET_ERR_HALTED) or resume (POLYMARKET_OK) to
+/// POLYMARKET_EVENT_RISK callbacks
+#[cfg(feature = "execution")]
+fn emit_halt_event(halted: bool) {
+    if !events::wants(POLYMARKET_EVENT_RISK) {
+        return;
+    }
+    let code = if halted { POLYMARKET_ERR_HALTED } else { POLYMARKET_OK };
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_RISK,
+        code,
+        None,
+        serde_json::json!({ "halted": halted }),
+    ));
+}
 
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, async {
-            let order = executor
-                .client
-                .market_order()
-                .token_id(token)
-                .amount(Amount::shares(size_decimal)?)
-                .side(Side::Sell)
-                .order_type(OrderType::GTC)
-                .price(price_decimal)
-                .build()
-                .await?;
-
-            let signed = executor.client.sign(&executor.signer, order).await?;
-            let response = executor.client.post_order(signed).await?;
-
-            Ok::<_, anyhow::Error>(response)
-        }).await.map_err(|_| anyhow::anyhow!("timeout"))?
-    });
+/// Publish an accepted risk limits change to POLYMARKET_EVENT_RISK callbacks
+#[cfg(feature = "execution")]
+fn emit_risk_config_event(update: &RiskUpdate) {
+    if !events::wants(POLYMARKET_EVENT_RISK) {
+        return;
+    }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_RISK,
+        POLYMARKET_OK,
+        None,
+        serde_json::json!({
+            "config_version": update.version,
+            "source": update.source,
+            "old": &*update.old,
+            "new": &*update.new,
+        }),
+    ));
+}
 
-    let latency_ms = start.elapsed().as_millis() as u64;
+/// Publish an account notification to POLYMARKET_EVENT_NOTIFICATION callbacks
+#[cfg(feature = "execution")]
+fn emit_notification_event(notification: &Notification) {
+    if !events::wants(POLYMARKET_EVENT_NOTIFICATION) {
+        return;
+    }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_NOTIFICATION,
+        notification.kind,
+        notification.token_id(),
+        serde_json::json!({
+            "type": notification.kind,
+            "payload": &notification.payload,
+        }),
+    ));
+}
 
-    match result {
-        Ok(resp) => {
-            let mut result = PolymarketOrderResult {
-                success: resp.success,
-                filled_qty_raw: decimal_to_raw(resp.making_amount),
-                avg_price_raw: decimal_to_raw(price_decimal),
-                latency_ms,
-                error_code: POLYMARKET_OK,
-                order_id: [0; 128],
-            };
-            result.set_order_id(&resp.order_id);
-            result
+/// Poll account notifications until aborted, publishing each new one
+/// Follows credential rotation: every poll uses the current session
+#[cfg(feature = "execution")]
+async fn notifications_task(executor: Arc<Executor>, interval: Duration) {
+    let mut tracker = NotificationTracker::new();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        match executor.trader().notifications().await {
+            Ok(snapshot) => {
+                for notification in tracker.fresh(snapshot) {
+                    info!(
+                        "[NOTIFICATION] type={} payload={}",
+                        notification.kind, notification.payload
+                    );
+                    emit_notification_event(&notification);
+                }
+            }
+            Err(e) => warn!("[NOTIFICATION] poll failed | {}", e),
         }
-        Err(e) => {
-            error!("[FFI ORDER ERROR] LIMIT SELL | error={} | latency={}ms", e, latency_ms);
-            let mut result = PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED);
-            result.latency_ms = latency_ms;
-            result
+    }
+}
+
+/// Publish what an auto-merge pass found or did to POLYMARKET_EVENT_MERGE callbacks
+#[cfg(feature = "onchain")]
+fn emit_merge_event(event: &MergeEvent) {
+    let market = &event.market.condition_id;
+    let code = match (event.error, event.tx_hash) {
+        (Some(e), _) => {
+            error!("[MERGE] failed | market={} | amount={} | {}", market, event.amount_raw, e);
+            order_error_code("MERGE", &event.market.yes_token_id, e)
+        }
+        (None, Some(hash)) => {
+            info!("[MERGE] merged | market={} | amount={} | tx={}", market, event.amount_raw,
+                hash);
+            POLYMARKET_OK
+        }
+        (None, None) => {
+            info!("[MERGE] mergeable | market={} | amount={}", market, event.amount_raw);
+            POLYMARKET_OK
         }
+    };
+    if !events::wants(POLYMARKET_EVENT_MERGE) {
+        return;
     }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_MERGE,
+        code,
+        None,
+        serde_json::json!({
+            "condition_id": market,
+            "action": event.action,
+            "yes_token_id": &event.market.yes_token_id,
+            "no_token_id": &event.market.no_token_id,
+            "neg_risk": event.market.neg_risk,
+            "amount_raw": event.amount_raw,
+            "tx_hash": event.tx_hash,
+        }),
+    ));
 }
 
-/// Cancel an order by ID
-/// Returns 0 on success, negative error code on failure
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_cancel(order_id: *const c_char) -> i32 {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return POLYMARKET_ERR_NOT_INITIALIZED,
+/// Check watched markets for YES/NO pairs until aborted
+#[cfg(feature = "onchain")]
+async fn merging_task(executor: Arc<Executor>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        executor.merger.check(&executor.trader(), emit_merge_event).await;
+    }
+}
+
+/// Publish a YES+NO mispricing to POLYMARKET_EVENT_ARBITRAGE callbacks
+/// With the `execution` feature the payload carries the basket legs that
+/// trade it
+fn emit_arb_event(action: ArbAction, opportunity: &ArbOpportunity) {
+    info!(
+        "[ARB] {:?} {:?} | market={} | shares={} | edge={:.4} | profit={:.2}",
+        action, opportunity.direction, opportunity.condition_id, opportunity.shares,
+        opportunity.edge, opportunity.profit
+    );
+    if !events::wants(POLYMARKET_EVENT_ARBITRAGE) {
+        return;
+    }
+    let mut payload = serde_json::to_value(opportunity).unwrap_or_default();
+    payload["action"] = serde_json::to_value(action).unwrap_or_default();
+    #[cfg(feature = "execution")]
+    {
+        let legs: Vec<_> = opportunity
+            .basket_legs()
+            .iter()
+            .map(|leg| {
+                let side = match leg.side {
+                    Side::Buy => POLYMARKET_SIDE_BUY,
+                    _ => POLYMARKET_SIDE_SELL,
+                };
+                serde_json::json!({
+                    "token_id": &leg.token_id,
+                    "side": side,
+                    "shares": leg.shares,
+                    "limit_price": leg.limit_price,
+                })
+            })
+            .collect();
+        payload["legs"] = legs.into();
+    }
+    events::emit(events::Event::new(POLYMARKET_EVENT_ARBITRAGE, POLYMARKET_OK, None, payload));
+}
+
+/// Scan watched markets for mispricing until aborted
+async fn arb_scan_task(executor: Arc<Executor>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        executor.arb_scanner.scan(&executor.trader(), emit_arb_event).await;
+    }
+}
+
+/// Publish a resolution status change to POLYMARKET_EVENT_RESOLUTION callbacks
+fn emit_resolution_event(resolution: &Resolution) {
+    info!(
+        "[RESOLUTION] {:?} | market={} | winner={} | yes_payout_raw={}",
+        resolution.status, resolution.condition_id, resolution.winning_outcome,
+        resolution.yes_payout_raw
+    );
+    if !events::wants(POLYMARKET_EVENT_RESOLUTION) {
+        return;
+    }
+    let code = match resolution.status {
+        ResolutionStatus::Open => POLYMARKET_RESOLUTION_OPEN,
+        ResolutionStatus::Proposed => POLYMARKET_RESOLUTION_PROPOSED,
+        ResolutionStatus::Disputed => POLYMARKET_RESOLUTION_DISPUTED,
+        ResolutionStatus::Resolved => POLYMARKET_RESOLUTION_RESOLVED,
     };
-    let executor = guard.as_ref().unwrap();
+    let winner = Some(resolution.winning_token_id.as_str()).filter(|id| !id.is_empty());
+    let payload = serde_json::to_value(resolution).unwrap_or_default();
+    events::emit(events::Event::new(POLYMARKET_EVENT_RESOLUTION, code, winner, payload));
+}
 
-    let order_str = unsafe {
-        if order_id.is_null() {
-            return POLYMARKET_ERR_CANCEL_FAILED;
-        }
-        match CStr::from_ptr(order_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return POLYMARKET_ERR_CANCEL_FAILED,
+/// Poll the resolution status of watched markets until aborted
+async fn resolution_watch_task(executor: Arc<Executor>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        executor.resolutions.poll(&executor.trader(), emit_resolution_event).await;
+    }
+}
+
+/// First reconnect delay of a WebSocket channel, doubled up to the max
+const CHANNEL_MIN_BACKOFF: Duration = Duration::from_secs(1);
+const CHANNEL_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Next reconnect delay of a channel that was up since `started`
+fn channel_backoff(backoff: Duration, started: Instant) -> Duration {
+    if started.elapsed() > CHANNEL_MAX_BACKOFF {
+        CHANNEL_MIN_BACKOFF
+    } else {
+        (backoff * 2).min(CHANNEL_MAX_BACKOFF)
+    }
+}
+
+/// Keep the market channel connected until aborted
+async fn book_feed_task(executor: Arc<Executor>) {
+    let url = format!("{}{}", executor.trader().options().ws_host, MARKET_CHANNEL_PATH);
+    let mut backoff = CHANNEL_MIN_BACKOFF / 2;
+    loop {
+        let started = Instant::now();
+        let result = executor.books.run(&url, |text| on_book_message(&executor, text)).await;
+        backoff = channel_backoff(backoff, started);
+        if let Err(e) = result {
+            warn!("[BOOK FEED] disconnected | {} | retry in {:?}", e, backoff);
         }
-    };
+        tokio::time::sleep(backoff).await;
+    }
+}
 
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, executor.client.cancel_order(order_str))
-            .await
-            .map_err(|_| anyhow::anyhow!("timeout"))?
-            .map_err(|e| anyhow::anyhow!("{}", e))
-    });
+/// Keep the user channel connected until aborted, handing order updates to
+/// the registered callback
+/// Each connection authenticates with the session current at the time, so
+/// rotated credentials apply from the next reconnect
+#[cfg(feature = "execution")]
+async fn user_channel_task(executor: Arc<Executor>) {
+    let mut backoff = CHANNEL_MIN_BACKOFF / 2;
+    loop {
+        let started = Instant::now();
+        let trader = executor.trader();
+        let result = trader
+            .run_user_channel(|update| {
+                let callback = executor.order_updates.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(callback) = callback.as_ref() {
+                    callback.call(&update);
+                }
+            })
+            .await;
+        drop(trader);
+        backoff = channel_backoff(backoff, started);
+        if let Err(e) = result {
+            warn!("[USER CHANNEL] disconnected | {} | retry in {:?}", e, backoff);
+        }
+        tokio::time::sleep(backoff).await;
+    }
+}
 
-    match result {
-        Ok(_) => POLYMARKET_OK,
-        Err(e) => {
-            error!("[FFI CANCEL ERROR] order_id={} error={}", order_str, e);
-            POLYMARKET_ERR_CANCEL_FAILED
+/// Apply a market channel message: books feed the session's book cache,
+/// tick size changes its tick cache, trades the token's tape callback, and
+/// a gap starts a resync; all go to POLYMARKET_EVENT_MARKET_DATA
+fn on_book_message(executor: &Arc<Executor>, text: &str) {
+    let trader = executor.trader();
+    for update in executor.books.apply(text, trader.drift_log()) {
+        match update {
+            FeedUpdate::Book(token_id) => publish_book(executor, &trader, &token_id),
+            FeedUpdate::Gap(token_id) => {
+                let generation = executor.books.state(&token_id).map(|s| s.generation);
+                warn!("[BOOK FEED] gap | token={} | resyncing", token_id);
+                if events::wants(POLYMARKET_EVENT_MARKET_DATA) {
+                    events::emit(events::Event::new(
+                        POLYMARKET_EVENT_MARKET_DATA,
+                        POLYMARKET_OK,
+                        Some(&token_id),
+                        serde_json::json!({ "event": "book_gap", "generation": generation }),
+                    ));
+                }
+                executor.spawn(book_resync_task(executor.clone(), token_id));
+            }
+            FeedUpdate::TickSize { token_id, tick } => apply_tick_size(executor, &token_id, tick),
+            FeedUpdate::LastTrade { token_id, price, size, side, timestamp_ms } => {
+                let tapes = executor.trade_tapes.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(callback) = tapes.get(&token_id) {
+                    callback.call(&token_id, price, size, side, timestamp_ms);
+                }
+                drop(tapes);
+                if events::wants(POLYMARKET_EVENT_MARKET_DATA) {
+                    events::emit(events::Event::new(
+                        POLYMARKET_EVENT_MARKET_DATA,
+                        POLYMARKET_OK,
+                        Some(&token_id),
+                        serde_json::json!({
+                            "event": "last_trade_price",
+                            "price": price.to_string(),
+                            "size": size.to_string(),
+                            "side": if side == Side::Buy { "BUY" } else { "SELL" },
+                            "timestamp_ms": timestamp_ms,
+                        }),
+                    ));
+                }
+            }
         }
     }
 }
 
-/// Cancel all open orders
-/// Returns 0 on success, negative error code on failure
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_cancel_all() -> i32 {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return POLYMARKET_ERR_NOT_INITIALIZED,
+/// Cache a market channel book that is not stale and publish its top to
+/// POLYMARKET_EVENT_MARKET_DATA
+fn publish_book(executor: &Executor, trader: &Trader, token_id: &str) {
+    let Some((book, state)) = executor.books.book_state(token_id) else {
+        return;
     };
-    let executor = guard.as_ref().unwrap();
-
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, executor.client.cancel_all_orders())
-            .await
-            .map_err(|_| anyhow::anyhow!("timeout"))?
-            .map_err(|e| anyhow::anyhow!("{}", e))
-    });
+    let (bid, ask) = (book.bids.first().copied(), book.asks.first().copied());
+    // A stale book ages out of the cache, so estimates fetch a fresh one
+    if !state.stale {
+        trader.cache_book(token_id, Arc::new(book));
+    }
+    if events::wants(POLYMARKET_EVENT_MARKET_DATA) {
+        events::emit(events::Event::new(
+            POLYMARKET_EVENT_MARKET_DATA,
+            POLYMARKET_OK,
+            Some(token_id),
+            serde_json::json!({
+                "event": "book",
+                "best_bid": bid.map(|l| l.price.to_string()),
+                "best_bid_size": bid.map(|l| l.size.to_string()),
+                "best_ask": ask.map(|l| l.price.to_string()),
+                "best_ask_size": ask.map(|l| l.size.to_string()),
+                "generation": state.generation,
+                "stale": state.stale,
+            }),
+        ));
+    }
+}
 
-    match result {
-        Ok(_) => POLYMARKET_OK,
-        Err(e) => {
-            error!("[FFI CANCEL_ALL ERROR] error={}", e);
-            POLYMARKET_ERR_CANCEL_FAILED
+/// Restore a book that missed updates from a REST snapshot, retrying with
+/// backoff until restored, no longer stale or unsubscribed
+async fn book_resync_task(executor: Arc<Executor>, token_id: String) {
+    let mut backoff = CHANNEL_MIN_BACKOFF;
+    loop {
+        let trader = executor.trader();
+        match executor.books.resync(&trader, &token_id).await {
+            Ok(true) => {
+                info!("[BOOK FEED] resynced | token={}", token_id);
+                publish_book(&executor, &trader, &token_id);
+                return;
+            }
+            Ok(false) => return,
+            Err(e) => warn!(
+                "[BOOK FEED] resync failed | token={} | {} | retry in {:?}",
+                token_id, e, backoff
+            ),
         }
+        drop(trader);
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(CHANNEL_MAX_BACKOFF);
     }
 }
 
-/// Get USDC balance
-/// Returns raw balance (6 decimals), or negative on error
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_get_balance() -> i64 {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return -1,
+/// Publish a fill to POLYMARKET_EVENT_FILL callbacks and a completed order
+/// to POLYMARKET_EVENT_ORDER_SUMMARY callbacks
+#[cfg(feature = "execution")]
+fn emit_fill_event(event: FillEvent) {
+    let (kind, summary, payload) = match event {
+        FillEvent::Fill(fill, summary) => {
+            let mut payload = serde_json::to_value(summary).unwrap_or_default();
+            payload["fill"] = serde_json::to_value(fill).unwrap_or_default();
+            (POLYMARKET_EVENT_FILL, summary, payload)
+        }
+        FillEvent::Completed(summary) => {
+            info!(
+                "[FILLS] order complete | order_id={} | status={} | filled={} | vwap={}",
+                summary.order_id, summary.status, summary.filled_qty_raw, summary.vwap_raw
+            );
+            let payload = serde_json::to_value(summary).unwrap_or_default();
+            (POLYMARKET_EVENT_ORDER_SUMMARY, summary, payload)
+        }
     };
-    let executor = guard.as_ref().unwrap();
+    if !events::wants(kind) {
+        return;
+    }
+    events::emit(events::Event::new(kind, POLYMARKET_OK, Some(&summary.token_id), payload));
+}
 
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(
-            API_TIMEOUT,
-            executor
-                .client
-                .balance_allowance(BalanceAllowanceRequest::default()),
-        )
-        .await
-        .map_err(|_| anyhow::anyhow!("timeout"))?
-        .map_err(|e| anyhow::anyhow!("{}", e))
-    });
+/// Poll followed orders for fills until aborted
+#[cfg(feature = "execution")]
+async fn fill_tracking_task(executor: Arc<Executor>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        executor.fills.poll(&executor.trader(), emit_fill_event).await;
+    }
+}
 
-    match result {
-        Ok(balance) => {
-            // Use decimal_to_raw for consistency
-            decimal_to_raw(balance.balance)
+/// Probe the CLOB gateways and reroute order traffic until aborted
+#[cfg(feature = "execution")]
+async fn endpoint_probing_task(executor: Arc<Executor>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        executor.trader().probe_endpoints().await;
+    }
+}
+
+/// Keep the CLOB and gateway connections open until aborted
+async fn keep_alive_task(executor: Arc<Executor>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        if let Err(e) = executor.trader().keep_alive().await {
+            warn!("[KEEPALIVE] ping failed | {}", e);
         }
-        Err(_) => -1,
     }
 }
 
-/// Get token balance (shares held)
-/// Returns raw balance (6 decimals), or negative on error
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_get_token_balance(token_id: *const c_char) -> i64 {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return -1,
-    };
-    let executor = guard.as_ref().unwrap();
+/// How often the venue watch looks at the circuit breaker
+#[cfg(feature = "execution")]
+const VENUE_WATCH_INTERVAL: Duration = Duration::from_millis(250);
 
-    let token_str = unsafe {
-        if token_id.is_null() {
-            return -1;
+/// Announce circuit breaker trips and probe the venue back to health, for
+/// the life of the executor
+/// Follows rotations: the breaker state moves to the new session
+#[cfg(feature = "execution")]
+async fn venue_watch_task(executor: Arc<Executor>) {
+    let mut ticker = tokio::time::interval(VENUE_WATCH_INTERVAL);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut announced_trips = 0;
+    loop {
+        ticker.tick().await;
+        let trader = executor.trader();
+        let breaker = trader.venue_breaker();
+        if !breaker.is_open() {
+            continue;
         }
-        match CStr::from_ptr(token_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return -1,
+        let state = breaker.state();
+        if state.trips != announced_trips {
+            announced_trips = state.trips;
+            emit_venue_event(POLYMARKET_ERR_VENUE_UNAVAILABLE, &state);
         }
-    };
+        if breaker.probe_due() && trader.probe_venue().await {
+            emit_venue_event(POLYMARKET_OK, &breaker.state());
+        }
+    }
+}
+
+/// Publish a circuit breaker change to POLYMARKET_EVENT_VENUE callbacks
+#[cfg(feature = "execution")]
+fn emit_venue_event(code: i32, state: &BreakerState) {
+    if !events::wants(POLYMARKET_EVENT_VENUE) {
+        return;
+    }
+    let payload = serde_json::to_value(state).unwrap_or_default();
+    events::emit(events::Event::new(POLYMARKET_EVENT_VENUE, code, None, payload));
+}
+
+/// Publish the progress of an execution algo to POLYMARKET_EVENT_ALGO callbacks
+#[cfg(feature = "execution")]
+fn emit_algo_event(algo_id: u64, algo: &str, token: &str, code: i32, progress: &AlgoProgress) {
+    if !events::wants(POLYMARKET_EVENT_ALGO) {
+        return;
+    }
+    let mut payload = serde_json::to_value(progress).unwrap_or_default();
+    payload["algo_id"] = algo_id.into();
+    payload["algo"] = algo.into();
+    events::emit(events::Event::new(POLYMARKET_EVENT_ALGO, code, Some(token), payload));
+}
+
+/// Work an algo to its end, publishing its progress
+/// Keeps the session it started on, like a submitted request
+#[cfg(feature = "execution")]
+async fn algo_task(
+    executor: Arc<Executor>,
+    algo_id: u64,
+    token_id: String,
+    spec: AlgoSpec,
+    control: Arc<AlgoControl>,
+) {
+    let name = spec.name();
+    let result = executor
+        .trader()
+        .run_algo(&token_id, spec, &control, |progress| {
+            emit_algo_event(algo_id, name, &token_id, POLYMARKET_OK, progress)
+        })
+        .await;
+    executor.algos.lock().unwrap_or_else(|e| e.into_inner()).remove(&algo_id);
+
+    match result {
+        Ok(progress) => {
+            info!("[ALGO] {} #{} {:?} | token={} orders={} executed={:.6}/{}",
+                name, algo_id, progress.state, token_id, progress.orders, progress.executed,
+                progress.total);
+            emit_algo_event(algo_id, name, &token_id, POLYMARKET_OK, &progress);
+        }
+        Err(failed) => {
+            let code = order_error_code(name, &token_id, &failed.error);
+            error!("[ALGO] {} #{} failed | token={} orders={} | {}",
+                name, algo_id, token_id, failed.progress.orders, failed.error);
+            emit_algo_event(algo_id, name, &token_id, code, &failed.progress);
+        }
+    }
+}
 
-    let token = match U256::from_str(token_str) {
+/// Validate and spawn an algo, returning its id or an error code
+#[cfg(feature = "execution")]
+fn start_algo(token_id: *const c_char, spec: AlgoSpec) -> i64 {
+    let (executor, token_str) = match order_target(token_id) {
         Ok(t) => t,
-        Err(_) => return -1,
+        Err(code) => return code as i64,
     };
+    if spec.validate().is_err() {
+        return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+    }
+    if executor.with_trader(|t| t.is_read_only()) {
+        return POLYMARKET_ERR_READ_ONLY as i64;
+    }
 
-    let result = executor.runtime.block_on(async {
-        let req = BalanceAllowanceRequest::builder()
-            .asset_type(AssetType::Conditional)
-            .token_id(token)
-            .signature_type(SignatureType::Proxy)
-            .build();
-        tokio::time::timeout(API_TIMEOUT, executor.client.balance_allowance(req))
-            .await
-            .map_err(|_| anyhow::anyhow!("timeout"))?
-            .map_err(|e| anyhow::anyhow!("{}", e))
+    let algo_id = executor.next_algo_id.fetch_add(1, Ordering::Relaxed);
+    let control = Arc::new(AlgoControl::new());
+    executor
+        .algos
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(algo_id, control.clone());
+    info!("[ALGO] {} #{} started | token={} | {:?}", spec.name(), algo_id, token_str, spec);
+    let busy = Busy::new(&executor);
+    let task = algo_task(executor.clone(), algo_id, token_str, spec, control);
+    executor.spawn(async move {
+        let _busy = busy;
+        task.await
     });
+    algo_id as i64
+}
 
-    match result {
-        Ok(balance) => {
-            // Use decimal_to_raw for consistency
-            decimal_to_raw(balance.balance)
+/// Publish a quoting action to POLYMARKET_EVENT_QUOTE callbacks
+#[cfg(feature = "execution")]
+fn emit_quote_event(event: &QuoteEvent) {
+    let code = match event.error {
+        Some(e) => {
+            warn!("[QUOTE] {:?} {:?} failed | token={} | {}", event.side, event.action,
+                event.token_id, e);
+            order_error_code("QUOTE", event.token_id, e)
         }
-        Err(_) => -1,
+        None => POLYMARKET_OK,
+    };
+    if !events::wants(POLYMARKET_EVENT_QUOTE) {
+        return;
     }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_QUOTE,
+        code,
+        Some(event.token_id),
+        serde_json::json!({
+            "side": if event.side == Side::Buy { "bid" } else { "ask" },
+            "action": event.action,
+            "price": event.level.price,
+            "size": event.level.size,
+            "order_id": event.order_id,
+            "filled_qty_raw": event.filled_qty_raw,
+        }),
+    ));
 }
 
-/// Execute a market sell order (FAK - Fill and Kill)
-/// Sells at price 0.01 to fill immediately
-/// Returns result with filled quantity, average price, and latency
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_market_sell(
-    token_id: *const c_char,
-    size: f64,    // fractional shares supported
-) -> PolymarketOrderResult {
-    let guard = match get_executor() {
-        Some(g) => g,
-        None => return PolymarketOrderResult::with_error(POLYMARKET_ERR_NOT_INITIALIZED),
-    };
-    let executor = guard.as_ref().unwrap();
+/// Reconcile quotes every `interval` until `stop` is set
+/// A pass is never cut short, so no posted quote goes untracked
+#[cfg(feature = "execution")]
+async fn quoting_task(
+    executor: Arc<Executor>,
+    settings: QuoteSettings,
+    interval: Duration,
+    stop: Arc<AtomicBool>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        if stop.load(Ordering::Acquire) {
+            break;
+        }
+        executor
+            .quoter
+            .reconcile(&executor.trader(), &settings, emit_quote_event)
+            .await;
+    }
+}
 
-    let token_str = unsafe {
-        if token_id.is_null() {
-            return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN);
+/// Stop the quoting loop after its current pass; quotes stay on the book
+#[cfg(feature = "execution")]
+fn stop_quoting_task(executor: &Arc<Executor>) -> anyhow::Result<()> {
+    let running = executor.quoting.lock().unwrap_or_else(|e| e.into_inner()).take();
+    match running {
+        Some((stop, task)) => {
+            stop.store(true, Ordering::Release);
+            executor.run(move |_| async move {
+                task.await.ok();
+                Ok(())
+            })
         }
-        match CStr::from_ptr(token_id).to_str() {
-            Ok(s) => s,
-            Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
+        None => Ok(()),
+    }
+}
+
+/// Stop quoting and pull every resting quote
+/// Returns the number of quotes still resting (cancels that failed)
+#[cfg(feature = "execution")]
+fn pull_quotes(executor: &Arc<Executor>) -> anyhow::Result<usize> {
+    stop_quoting_task(executor)?;
+    executor.run(move |executor| async move {
+        executor.quoter.cancel_all(&executor.trader(), emit_quote_event).await;
+        Ok(executor.quoter.resting())
+    })
+}
+
+/// Side argument of the C API
+fn side_of(side: i32) -> Option<Side> {
+    match side {
+        POLYMARKET_SIDE_BUY => Some(Side::Buy),
+        POLYMARKET_SIDE_SELL => Some(Side::Sell),
+        _ => None,
+    }
+}
+
+/// Map an order rejected or failed in the core to its error code
+/// Local pre-trade rejections are also published as POLYMARKET_EVENT_RISK
+#[cfg(feature = "execution")]
+fn order_error_code(op: &str, token: &str, err: &OrderError) -> i32 {
+    match err {
+        OrderError::InvalidToken => POLYMARKET_ERR_INVALID_TOKEN,
+        OrderError::InvalidAmount => POLYMARKET_ERR_INVALID_ORDER,
+        OrderError::Api(e) => api_error_code(e, POLYMARKET_ERR_ORDER_FAILED),
+        OrderError::MinOrderSize => POLYMARKET_ERR_MIN_ORDER_SIZE,
+        OrderError::MinShares => POLYMARKET_ERR_MIN_SHARES,
+        OrderError::RiskLimit(reason) => {
+            error!("[RISK] {} rejected | token={} | {}", op, token, reason);
+            emit_risk_event(token, POLYMARKET_ERR_RISK_LIMIT, reason);
+            POLYMARKET_ERR_RISK_LIMIT
         }
-    };
+        OrderError::InsufficientBalance(reason) => {
+            emit_risk_event(token, POLYMARKET_ERR_INSUFFICIENT_BALANCE, reason);
+            POLYMARKET_ERR_INSUFFICIENT_BALANCE
+        }
+        OrderError::ReadOnly => POLYMARKET_ERR_READ_ONLY,
+        OrderError::TickSize { .. } => {
+            error!("[TICK SIZE] {} rejected | token={} | {}", op, token, err);
+            POLYMARKET_ERR_TICK_SIZE
+        }
+        OrderError::InsufficientLiquidity { .. } => {
+            error!("[LIQUIDITY] {} rejected | token={} | {}", op, token, err);
+            POLYMARKET_ERR_LIQUIDITY
+        }
+        OrderError::Slippage { .. } => {
+            error!("[SLIPPAGE] {} rejected | token={} | {}", op, token, err);
+            POLYMARKET_ERR_SLIPPAGE
+        }
+        OrderError::VenueUnavailable => POLYMARKET_ERR_VENUE_UNAVAILABLE,
+        OrderError::Halted => {
+            error!("[HALT] {} rejected | token={}", op, token);
+            emit_risk_event(token, POLYMARKET_ERR_HALTED, "trading halted");
+            POLYMARKET_ERR_HALTED
+        }
+        OrderError::NotCancelled(_) => POLYMARKET_ERR_CANCEL_FAILED,
+        OrderError::DuplicateClientOrderId(_) => {
+            error!("[CLIENT ID] {} rejected | token={} | {}", op, token, err);
+            POLYMARKET_ERR_DUPLICATE_ORDER
+        }
+    }
+}
 
-    let token = match U256::from_str(token_str) {
-        Ok(t) => t,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_INVALID_TOKEN),
-    };
+/// Error code of a failed venue call; `fallback` if nothing tells how it
+/// failed
+#[cfg(feature = "execution")]
+fn api_error_code(e: &anyhow::Error, fallback: i32) -> i32 {
+    match venue_error::classify(e) {
+        ApiFailure::Network => POLYMARKET_ERR_NETWORK,
+        ApiFailure::RateLimited => POLYMARKET_ERR_RATE_LIMITED,
+        ApiFailure::Server => POLYMARKET_ERR_VENUE_ERROR,
+        ApiFailure::Rejected => POLYMARKET_ERR_REJECTED,
+        ApiFailure::Unknown => fallback,
+    }
+}
+
+/// Message of an order error; venue errors with their full chain, so the
+/// CLOB's own error string is kept
+#[cfg(feature = "execution")]
+fn order_error_message(err: &OrderError) -> String {
+    match err {
+        OrderError::Api(e) => format!("{:#}", e),
+        e => e.to_string(),
+    }
+}
 
+/// Order flow, run as a runtime task (blocking or submitted)
+#[cfg(feature = "execution")]
+async fn order_task(
+    executor: Arc<Executor>,
+    token_id: String,
+    request: OrderRequest,
+    client_order_id: Option<String>,
+) -> OrderOutcome {
     let start = Instant::now();
-    // Use aggressive price for true market order - will fill at best available
-    let market_price = 0.01;
-
-    // Convert f64 size to Decimal, rounded to 2 decimal places (Polymarket requirement)
-    let size_rounded = (size * 100.0).floor() / 100.0;  // Round DOWN to 2 decimals
-    let size_decimal = match Decimal::try_from(size_rounded) {
-        Ok(d) => d,
-        Err(_) => return PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED),
+    let trader = executor.trader();
+    let result = match &client_order_id {
+        Some(id) => trader.place_with_id(id, &token_id, request).await,
+        None => trader.place(&token_id, request).await,
     };
-
-    let result = executor.runtime.block_on(async {
-        tokio::time::timeout(API_TIMEOUT, async {
-            let order = executor
-                .client
-                .market_order()
-                .token_id(token)
-                .amount(Amount::shares(size_decimal)?)
-                .side(Side::Sell)
-                .order_type(OrderType::FAK)
-                .price(Decimal::try_from(market_price).unwrap())
-                .build()
-                .await?;
-
-            let signed = executor.client.sign(&executor.signer, order).await?;
-            let response = executor.client.post_order(signed).await?;
-
-            Ok::<_, anyhow::Error>(response)
-        }).await.map_err(|_| anyhow::anyhow!("timeout"))?
-    });
-
     let latency_ms = start.elapsed().as_millis() as u64;
+    watch_credentials(&executor, &result);
+    order_outcome(&executor, &token_id, request, result, latency_ms).tagged(client_order_id)
+}
 
+/// Outcome of a placed order; follows up on the fill (fill tracking, GTD
+/// local cancel) and logs failures
+#[cfg(feature = "execution")]
+fn order_outcome(
+    executor: &Arc<Executor>,
+    token_id: &str,
+    request: OrderRequest,
+    result: Result<OrderFill, OrderError>,
+    latency_ms: u64,
+) -> OrderOutcome {
     match result {
-        Ok(resp) => {
-            // For SELL: making_amount = shares sold, taking_amount = USDC received
-            let filled_shares = resp.making_amount;
-            let usdc_received = resp.taking_amount;
-            let avg_price = if !filled_shares.is_zero() {
-                usdc_received / filled_shares
-            } else {
-                Decimal::ZERO
-            };
-
-            let mut result = PolymarketOrderResult {
-                success: resp.success,
-                filled_qty_raw: decimal_to_raw(filled_shares),
-                avg_price_raw: decimal_to_raw(avg_price),
+        Ok(fill) => {
+            if executor.fill_tracking.lock().is_ok_and(|task| task.is_some()) {
+                executor.fills.track(token_id, request.side(), &fill, emit_fill_event);
+            }
+            if let Some(cancel_at_ms) = fill.cancel_at_ms {
+                let task = gtd_expiry_task(
+                    executor.clone(),
+                    token_id.to_string(),
+                    fill.order_id.clone(),
+                    cancel_at_ms,
+                );
+                executor.spawn(task);
+            }
+            // Answered, but refused: the venue says why in error_msg
+            let error_code = if fill.success { POLYMARKET_OK } else { POLYMARKET_ERR_REJECTED };
+            if !fill.success {
+                error!("[FFI ORDER REJECTED] {} | error={} | latency={}ms",
+                    request.op(), fill.error_msg, latency_ms);
+            }
+            let result = PolymarketOrderResultEx {
+                success: fill.success,
+                filled_qty_raw: fill.filled_qty_raw,
+                avg_price_raw: fill.avg_price_raw,
                 latency_ms,
-                error_code: POLYMARKET_OK,
-                order_id: [0; 128],
+                error_code,
+                remaining_qty_raw: fill.remaining_qty_raw,
+                fee_raw: fill.fee_raw,
+                ..Default::default()
             };
-            result.set_order_id(&resp.order_id);
-            result
+            let mut outcome = OrderOutcome::new(result, &fill.order_id);
+            outcome.error_message = fill.error_msg;
+            outcome
         }
         Err(e) => {
-            error!("[FFI ORDER ERROR] SELL | error={} | latency={}ms", e, latency_ms);
-            let mut result = PolymarketOrderResult::with_error(POLYMARKET_ERR_ORDER_FAILED);
-            result.latency_ms = latency_ms;
-            result
+            let op = request.op();
+            let code = order_error_code(op, token_id, &e);
+            error!("[FFI ORDER ERROR] {} | error={} | latency={}ms", op, e, latency_ms);
+            let mut outcome = OrderOutcome::failed(code, order_error_message(&e));
+            outcome.result.latency_ms = latency_ms;
+            outcome
         }
     }
 }
 
-/// Shutdown and cleanup
-/// After calling this, polymarket_init() can be called again to re-initialize
-#[unsafe(no_mangle)]
-pub extern "C" fn polymarket_shutdown() {
-    let lock = match EXECUTOR.get() {
-        Some(l) => l,
-        None => return,
-    };
+/// Cancel a GTD order at a deadline the venue only enforces to the second
+/// The outcome goes to POLYMARKET_EVENT_ORDER callbacks as GTD_EXPIRE
+#[cfg(feature = "execution")]
+async fn gtd_expiry_task(
+    executor: Arc<Executor>,
+    token_id: String,
+    order_id: String,
+    cancel_at_ms: u64,
+) {
+    let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+    tokio::time::sleep(Duration::from_millis(cancel_at_ms.saturating_sub(now_ms))).await;
+    let outcome = cancel_task(executor, order_id).await;
+    emit_order_event("GTD_EXPIRE", &token_id, None, &outcome);
+}
+
+// Error codes
+pub const POLYMARKET_OK: i32 = 0;
+/// Not an error: still in flight, or no data yet
+pub const POLYMARKET_PENDING: i32 = 1;
+pub const POLYMARKET_ERR_NOT_INITIALIZED: i32 = -1;
+pub const POLYMARKET_ERR_INVALID_PK: i32 = -2;
+pub const POLYMARKET_ERR_AUTH_FAILED: i32 = -3;
+pub const POLYMARKET_ERR_INVALID_TOKEN: i32 = -4;
+pub const POLYMARKET_ERR_ORDER_FAILED: i32 = -5;
+pub const POLYMARKET_ERR_CANCEL_FAILED: i32 = -6;
+/// Order below $1 minimum
+pub const POLYMARKET_ERR_MIN_ORDER_SIZE: i32 = -7;
+/// Shares below market minimum
+pub const POLYMARKET_ERR_MIN_SHARES: i32 = -8;
+/// Cached USDC can't fund the buy
+pub const POLYMARKET_ERR_INSUFFICIENT_BALANCE: i32 = -9;
+/// Config rejected by validation
+pub const POLYMARKET_ERR_INVALID_CONFIG: i32 = -10;
+/// Order rejected by risk limits
+pub const POLYMARKET_ERR_RISK_LIMIT: i32 = -11;
+/// Null or malformed out-parameter
+pub const POLYMARKET_ERR_INVALID_ARGUMENT: i32 = -12;
+/// Aborted locally, venue state unknown
+pub const POLYMARKET_ERR_ABORTED: i32 = -13;
+/// Internal panic, see polymarket_last_panic()
+pub const POLYMARKET_ERR_PANIC: i32 = -14;
+/// Blocked: initialized with POLYMARKET_INIT_READ_ONLY
+pub const POLYMARKET_ERR_READ_ONLY: i32 = -15;
+/// Price not a multiple of the market's current tick size
+pub const POLYMARKET_ERR_TICK_SIZE: i32 = -16;
+/// Book too thin for the shares within the price cap
+pub const POLYMARKET_ERR_LIQUIDITY: i32 = -17;
+/// Orders paused while the venue is down
+pub const POLYMARKET_ERR_VENUE_UNAVAILABLE: i32 = -18;
+/// Venue refused the request (4xx, or success=false)
+pub const POLYMARKET_ERR_REJECTED: i32 = -19;
+/// Timeout or connection error, venue state unknown
+pub const POLYMARKET_ERR_NETWORK: i32 = -20;
+/// Venue answered 429, or over the local budget
+pub const POLYMARKET_ERR_RATE_LIMITED: i32 = -21;
+/// Venue answered 5xx
+pub const POLYMARKET_ERR_VENUE_ERROR: i32 = -22;
+/// Amount, price or expiry not valid (checked locally)
+pub const POLYMARKET_ERR_INVALID_ORDER: i32 = -23;
+/// Expected slippage on the book over the caller's cap
+pub const POLYMARKET_ERR_SLIPPAGE: i32 = -24;
+/// Client order id in use by an order that may be live
+pub const POLYMARKET_ERR_DUPLICATE_ORDER: i32 = -25;
+/// Wallet has not approved USDC.e for the exchanges
+pub const POLYMARKET_ERR_USDC_ALLOWANCE: i32 = -26;
+/// Wallet has not approved outcome tokens for them
+pub const POLYMARKET_ERR_TOKEN_APPROVAL: i32 = -27;
+/// Trading halted (polymarket_halt_trading)
+pub const POLYMARKET_ERR_HALTED: i32 = -28;
+
+// Init flags (polymarket_set_init_flags)
+/// Queries and market data only; every order, cancel and on-chain call
+/// fails with POLYMARKET_ERR_READ_ONLY
+pub const POLYMARKET_INIT_READ_ONLY: u32 = 1;
+/// Install no tracing subscriber, for hosts that install their own; nothing
+/// is logged to stdout, POLYMARKET_EVENT_LOG or the log callback. Only the
+/// first init of the process installs one
+pub const POLYMARKET_INIT_NO_TRACING: u32 = 2;
+/// Paper trading; orders pass every local check and are filled against the
+/// order book (the market channel's if fresh, else a REST snapshot) instead
+/// of being posted, with order ids "paper-<n>". Limit orders fill what
+/// crosses and rest the remainder, which never fills later. Cancels,
+/// replace and polymarket_get_open_orders() see paper orders only; fills
+/// update positions and the cached balance. On-chain calls fail with
+/// POLYMARKET_ERR_READ_ONLY. Queries and market data stay live
+pub const POLYMARKET_INIT_DRY_RUN: u32 = 4;
+
+/// Flags applied by the next polymarket_init*() call
+static INIT_FLAGS: AtomicU32 = AtomicU32::new(0);
+
+/// Endpoints, proxy and TLS settings used by the next polymarket_init*() call
+static CONNECT_OPTIONS: Mutex<Option<ConnectOptions>> = Mutex::new(None);
 
-    if let Ok(mut guard) = lock.write() {
-        *guard = None;
+/// Order journal opened by the next polymarket_init*() call
+#[cfg(feature = "execution")]
+static JOURNAL_PATH: Mutex<Option<String>> = Mutex::new(None);
+
+/// Steps run by polymarket_warmup(), None for the default profile
+static WARMUP_PROFILE: Mutex<Option<WarmupProfile>> = Mutex::new(None);
+
+/// Set what polymarket_warmup() warms, as JSON: {"resolve", "connections",
+/// "signed_request", "presign_token_id"}, all optional
+/// NULL restores the default (3 plain requests)
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_warmup_profile(json: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let profile = if json.is_null() {
+            None
+        } else {
+            let json = match unsafe { CStr::from_ptr(json) }.to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            };
+            match WarmupProfile::from_json(json) {
+                Ok(profile) => Some(profile),
+                Err(e) => {
+                    error!("[WARMUP PROFILE] {}", e);
+                    return POLYMARKET_ERR_INVALID_CONFIG;
+                }
+            }
+        };
+        match WARMUP_PROFILE.lock() {
+            Ok(mut staged) => {
+                *staged = profile;
+                POLYMARKET_OK
+            }
+            Err(_) => POLYMARKET_ERR_INVALID_CONFIG,
+        }
+    })
+}
+
+/// Warm up the connections and request paths selected by the warmup profile
+/// Call this after init to pre-establish TLS connections; the step timings
+/// are left in polymarket_last_response()
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_warmup() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let profile = WARMUP_PROFILE
+            .lock()
+            .map(|staged| staged.clone().unwrap_or_default())
+            .unwrap_or_default();
+        let result = executor.run(move |executor| async move {
+            executor.trader().warmup_with(&profile).await
+        });
+
+        match result {
+            Ok(report) => {
+                info!("[WARMUP] {:?}", report);
+                out_buf::set_last_response(serde_json::to_string(&report).unwrap_or_default());
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[WARMUP ERROR] {}", e);
+                POLYMARKET_ERR_AUTH_FAILED
+            }
+        }
+    })
+}
+
+/// Setup tracing, unless POLYMARKET_INIT_NO_TRACING is set
+/// Log lines go to stdout (unless a log callback takes them instead), to
+/// POLYMARKET_EVENT_LOG callbacks and to the log callback at its own level
+fn init_tracing() {
+    use tracing_subscriber::filter::{filter_fn, EnvFilter};
+    use tracing_subscriber::prelude::*;
+    if INIT_FLAGS.load(Ordering::Acquire) & POLYMARKET_INIT_NO_TRACING != 0 {
+        return;
+    }
+    let _ = tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_filter(EnvFilter::new("polymarket_executor=info"))
+                .with_filter(filter_fn(|_| !events::has_log_callback())),
+        )
+        .with(events::LogLayer.with_filter(EnvFilter::new("polymarket_executor=info")))
+        .with(events::CallbackLayer.with_filter(filter_fn(|meta| {
+            events::log_callback_wants(meta.target(), meta.level())
+        })))
+        .try_init();
+}
+
+/// Options staged by polymarket_set_connect_options(), logged if not default
+fn staged_connect_options() -> Option<ConnectOptions> {
+    let options = CONNECT_OPTIONS.lock().ok()?.clone().unwrap_or_default();
+    if options != ConnectOptions::default() {
+        info!(
+            "[INIT] clob={} ws={} gamma={} proxy={}",
+            options.clob_host,
+            options.ws_host,
+            options.gamma_host,
+            if options.proxy.is_some() { "set" } else { "none" }
+        );
+    }
+    if options.ca_file.is_some() || options.client_cert_file.is_some() {
+        info!(
+            "[INIT] tls ca_file={} client_cert={}",
+            options.ca_file.as_deref().unwrap_or("none"),
+            options.client_cert_file.as_deref().unwrap_or("none")
+        );
+    }
+    Some(options)
+}
+
+/// Executor around a connected session, on the shared `runtime`
+fn new_executor(runtime: &'static Runtime, trader: Trader) -> Arc<Executor> {
+    let executor = Executor {
+        trader: RwLock::new(Arc::new(trader)),
+        runtime,
+        tasks: Mutex::new(Vec::new()),
+        #[cfg(feature = "execution")]
+        requests: Requests::new(),
+        #[cfg(feature = "execution")]
+        notifications: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        algos: Mutex::new(HashMap::new()),
+        #[cfg(feature = "execution")]
+        next_algo_id: AtomicU64::new(1),
+        #[cfg(feature = "execution")]
+        quoter: Arc::new(Quoter::new()),
+        #[cfg(feature = "execution")]
+        quoting: Mutex::new(None),
+        #[cfg(feature = "onchain")]
+        merger: Arc::new(AutoMerger::new()),
+        #[cfg(feature = "onchain")]
+        merging: Mutex::new(None),
+        arb_scanner: Arc::new(ArbScanner::new()),
+        arb_scan: Mutex::new(None),
+        resolutions: Arc::new(ResolutionWatcher::new()),
+        resolution_watch: Mutex::new(None),
+        books: Arc::new(BookFeed::new()),
+        book_feed: Mutex::new(None),
+        trade_tapes: Mutex::new(HashMap::new()),
+        meta_refresh: Mutex::new(None),
+        keep_alive: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        fills: Arc::new(FillAggregator::new()),
+        #[cfg(feature = "execution")]
+        fill_tracking: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        endpoint_probing: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        backup: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        credential_monitor: CredentialMonitor::new(),
+        #[cfg(feature = "execution")]
+        failing_over: AtomicBool::new(false),
+        #[cfg(feature = "execution")]
+        venue_watch: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        order_updates: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        user_channel: Mutex::new(None),
+        #[cfg(feature = "execution")]
+        wallets: wallets::Wallets::new(),
+        in_flight: AtomicUsize::new(0),
+    };
+    let executor = Arc::new(executor);
+    #[cfg(feature = "execution")]
+    {
+        let task = executor.spawn(venue_watch_task(executor.clone()));
+        *executor.venue_watch.lock().unwrap_or_else(|e| e.into_inner()) = Some(task);
+    }
+    executor
+}
+
+/// Error code of a failed connect
+fn connect_error_code(e: ConnectError) -> i32 {
+    match e {
+        ConnectError::InvalidKey => POLYMARKET_ERR_INVALID_PK,
+        ConnectError::InvalidOptions(e) => {
+            error!("[INIT ERROR] {}", e);
+            POLYMARKET_ERR_INVALID_CONFIG
+        }
+        e => {
+            error!("[INIT ERROR] {}", e);
+            POLYMARKET_ERR_AUTH_FAILED
+        }
+    }
+}
+
+/// Connect on the shared runtime, waiting on the calling thread
+/// Err with the error code if the runtime could not run it
+fn connect<Fut>(connecting: Fut) -> Result<(&'static Runtime, Fut::Output), i32>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    let shared = runtime::shared();
+    match shared.and_then(|shared| runtime::block_on(connecting).map(|result| (shared, result))) {
+        Ok(connected) => Ok(connected),
+        Err(e) => {
+            error!("[INIT ERROR] {}", e);
+            Err(POLYMARKET_ERR_AUTH_FAILED)
+        }
+    }
+}
+
+/// Store a connected session as the global one
+fn install(runtime: &'static Runtime, connected: Result<Trader, ConnectError>) -> i32 {
+    match connected {
+        Ok(trader) => {
+            let executor = new_executor(runtime, trader);
+            drop(session::install(VENUE, executor));
+            POLYMARKET_OK
+        }
+        Err(e) => connect_error_code(e),
+    }
+}
+
+/// Shared body of the polymarket_init*() variants
+/// The key is scrubbed once the signer is built (the signer scrubs its own
+/// copy on drop). On a live executor, other credentials are swapped in
+#[cfg(feature = "execution")]
+fn init_executor(
+    funder_wallet: *const c_char,
+    load_key: impl FnOnce() -> Result<Zeroizing<String>, i32>,
+) -> i32 {
+    let live = session::get::<Arc<Executor>>(VENUE);
+
+    init_tracing();
+
+    let pk = match load_key() {
+        Ok(pk) => pk,
+        Err(code) => return code,
+    };
+
+    let funder_str = unsafe {
+        if funder_wallet.is_null() {
+            return POLYMARKET_ERR_AUTH_FAILED;
+        }
+        match CStr::from_ptr(funder_wallet).to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
+        }
+    };
+
+    if let Some(executor) = live {
+        return switch_credentials(&executor, pk, funder_str);
+    }
+
+    let options = match staged_connect_options() {
+        Some(options) => options,
+        None => return POLYMARKET_ERR_AUTH_FAILED,
+    };
+    // Opened before connecting: no session runs without the journal asked for
+    let journal = match staged_journal() {
+        Ok(journal) => journal,
+        Err(code) => return code,
+    };
+
+    let (runtime, result) =
+        match connect(async move { Trader::connect_with(&pk, &funder_str, options).await }) {
+            Ok(connected) => connected,
+            Err(code) => return code,
+        };
+    if let Ok(trader) = &result {
+        if INIT_FLAGS.load(Ordering::Acquire) & POLYMARKET_INIT_READ_ONLY != 0 {
+            trader.set_read_only();
+            info!("[INIT] read-only mode: orders, cancels and on-chain calls are blocked");
+        }
+        if INIT_FLAGS.load(Ordering::Acquire) & POLYMARKET_INIT_DRY_RUN != 0 {
+            trader.set_dry_run();
+        }
+        trader.set_journal(journal);
+    }
+    install(runtime, result)
+}
+
+/// Initialize a keyless executor for market data, lookups and estimates
+/// Only in builds without the `execution` feature, which have no
+/// polymarket_init*(). Returns 0 on success (and on a live executor),
+/// negative error code on failure
+#[cfg(not(feature = "execution"))]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_init_keyless() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if session::get::<Arc<Executor>>(VENUE).is_some() {
+            return POLYMARKET_OK;
+        }
+
+        init_tracing();
+
+        let options = match staged_connect_options() {
+            Some(options) => options,
+            None => return POLYMARKET_ERR_AUTH_FAILED,
+        };
+        match connect(Trader::connect_keyless(options)) {
+            Ok((runtime, result)) => install(runtime, result),
+            Err(code) => code,
+        }
+    })
+}
+
+/// Set POLYMARKET_INIT_* flags for the next polymarket_init*() call
+/// They stay with that executor until shutdown; a live one is not affected
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_init_flags(flags: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let known =
+            POLYMARKET_INIT_READ_ONLY | POLYMARKET_INIT_NO_TRACING | POLYMARKET_INIT_DRY_RUN;
+        if flags & !known != 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        INIT_FLAGS.store(flags, Ordering::Release);
+        POLYMARKET_OK
+    })
+}
+
+/// Set endpoints, proxy and TLS for the next polymarket_init*() call, as JSON:
+/// {"clob_host", "clob_gateways", "ws_host", "gamma_host", "data_host", "rpc_url", "proxy",
+/// "ca_file", "client_cert_file", "client_key_file"}, all optional
+/// NULL restores the defaults; a live executor is not affected
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_connect_options(json: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let options = if json.is_null() {
+            None
+        } else {
+            let json = match unsafe { CStr::from_ptr(json) }.to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            };
+            match ConnectOptions::from_json(json) {
+                Ok(options) => Some(options),
+                Err(e) => {
+                    error!("[CONNECT OPTIONS] {}", e);
+                    return POLYMARKET_ERR_INVALID_CONFIG;
+                }
+            }
+        };
+        match CONNECT_OPTIONS.lock() {
+            Ok(mut staged) => {
+                *staged = options;
+                POLYMARKET_OK
+            }
+            Err(_) => POLYMARKET_ERR_INVALID_CONFIG,
+        }
+    })
+}
+
+/// Initialize the executor with a private key and funder wallet
+/// Returns 0 on success, negative error code on failure
+/// Can be called again after polymarket_shutdown() to re-initialize, or on
+/// a live executor to switch it to other credentials (see switch_credentials)
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_init(
+    private_key: *const c_char,
+    funder_wallet: *const c_char,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        init_executor(funder_wallet, || unsafe {
+            if private_key.is_null() {
+                return Err(POLYMARKET_ERR_INVALID_PK);
+            }
+            match CStr::from_ptr(private_key).to_str() {
+                Ok(s) => Ok(Zeroizing::new(s.to_string())),
+                Err(_) => Err(POLYMARKET_ERR_INVALID_PK),
+            }
+        })
+    })
+}
+
+/// Initialize the executor with the private key read from a file
+/// The file must be owned by the current user and not accessible to
+/// group/others (mode 600 or 400); otherwise POLYMARKET_ERR_INVALID_PK
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_init_from_file(
+    key_path: *const c_char,
+    funder_wallet: *const c_char,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        init_executor(funder_wallet, || {
+            let path = unsafe {
+                if key_path.is_null() {
+                    return Err(POLYMARKET_ERR_INVALID_PK);
+                }
+                CStr::from_ptr(key_path).to_str().map_err(|_| POLYMARKET_ERR_INVALID_PK)?
+            };
+            key_source::read_key_file(Path::new(path)).map_err(|e| {
+                error!("[INIT ERROR] key file {} | {}", path, e);
+                POLYMARKET_ERR_INVALID_PK
+            })
+        })
+    })
+}
+
+/// Initialize the executor with the private key stored in the OS keyring
+/// (macOS Keychain via `security`, libsecret via `secret-tool` on Linux)
+/// Returns POLYMARKET_ERR_INVALID_PK if the entry can't be read
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_init_from_keyring(
+    service: *const c_char,
+    account: *const c_char,
+    funder_wallet: *const c_char,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        init_executor(funder_wallet, || {
+            let (service, account) = unsafe {
+                if service.is_null() || account.is_null() {
+                    return Err(POLYMARKET_ERR_INVALID_PK);
+                }
+                (
+                    CStr::from_ptr(service).to_str().map_err(|_| POLYMARKET_ERR_INVALID_PK)?,
+                    CStr::from_ptr(account).to_str().map_err(|_| POLYMARKET_ERR_INVALID_PK)?,
+                )
+            };
+            key_source::read_keyring(service, account).map_err(|e| {
+                error!("[INIT ERROR] keyring service={} account={} | {}", service, account, e);
+                POLYMARKET_ERR_INVALID_PK
+            })
+        })
+    })
+}
+
+/// Authenticate new credentials in the background and swap them in
+/// Every call keeps using the current session until the new one is ready;
+/// requests already running finish on the session they started on.
+/// Returns a request id (> 0) like polymarket_submit_*(), or negative error
+/// code; the outcome (op "ROTATE") is delivered the same way
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_rotate_credentials(
+    new_private_key: *const c_char,
+    new_funder_wallet: *const c_char,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let pk = unsafe {
+            if new_private_key.is_null() {
+                return POLYMARKET_ERR_INVALID_PK as i64;
+            }
+            match CStr::from_ptr(new_private_key).to_str() {
+                Ok(s) => Zeroizing::new(s.to_string()),
+                Err(_) => return POLYMARKET_ERR_INVALID_PK as i64,
+            }
+        };
+
+        let funder = unsafe {
+            if new_funder_wallet.is_null() {
+                return POLYMARKET_ERR_AUTH_FAILED as i64;
+            }
+            match CStr::from_ptr(new_funder_wallet).to_str() {
+                Ok(s) => s.to_string(),
+                Err(_) => return POLYMARKET_ERR_AUTH_FAILED as i64,
+            }
+        };
+
+        if switch_blocked_by_quotes(&executor, &pk, &funder) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+        executor.submit_order("ROTATE", String::new(), move |executor, _| {
+            rotate_task(executor, pk, funder)
+        }) as i64
+    })
+}
+
+/// Quotes rest for the current wallet only: switching wallets under them
+/// would leave them on the book with nothing tracking them
+#[cfg(feature = "execution")]
+fn switch_blocked_by_quotes(executor: &Executor, pk: &str, funder: &str) -> bool {
+    let change = executor.trader().credential_change(pk, funder);
+    let quoting = executor.quoter.resting() > 0
+        || executor.quoting.lock().is_ok_and(|q| q.is_some());
+    if quoting && matches!(change, Ok(CredentialChange::Wallet)) {
+        error!("[ROTATE] quotes are resting for the current wallet: stop quoting first");
+        return true;
+    }
+    false
+}
+
+/// Replace the current session with `next`, carrying its local state over
+/// For another wallet, fill tracking starts over: the orders it followed
+/// belong to the previous one
+#[cfg(feature = "execution")]
+fn swap_session(executor: &Executor, next: Trader) {
+    let mut slot = executor.trader.write().unwrap_or_else(|e| e.into_inner());
+    // From the latest session, in case state changed while connecting
+    next.take_over(&slot);
+    let same_wallet = next.same_wallet(&slot);
+    *slot = Arc::new(next);
+    drop(slot);
+
+    if !same_wallet {
+        let dropped = executor.fills.tracked();
+        executor.fills.clear();
+        if dropped > 0 {
+            warn!("[ROTATE] wallet changed: no longer following {} orders", dropped);
+        }
+    }
+}
+
+/// Swap a live executor over to new credentials, blocking until done
+/// The current session keeps serving every call until the new one has
+/// authenticated; the same credentials are a no-op
+#[cfg(feature = "execution")]
+fn switch_credentials(executor: &Arc<Executor>, pk: Zeroizing<String>, funder: String) -> i32 {
+    match executor.trader().credential_change(&pk, &funder) {
+        Ok(CredentialChange::None) => return POLYMARKET_OK,
+        Ok(_) => {}
+        Err(ConnectError::InvalidKey) => return POLYMARKET_ERR_INVALID_PK,
+        Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
+    }
+    if switch_blocked_by_quotes(executor, &pk, &funder) {
+        return POLYMARKET_ERR_INVALID_ARGUMENT;
+    }
+
+    let funder_owned = funder.clone();
+    let result = executor
+        .run(move |executor| async move {
+            let next = executor.trader().rotate(&pk, &funder_owned).await;
+            drop(pk);
+            Ok(next)
+        })
+        .unwrap_or_else(|e| Err(ConnectError::Auth(e)));
+    match result {
+        Ok(next) => {
+            swap_session(executor, next);
+            info!("[INIT] live executor switched | funder={}", funder);
+            POLYMARKET_OK
+        }
+        Err(ConnectError::InvalidKey) => POLYMARKET_ERR_INVALID_PK,
+        Err(e) => {
+            error!("[INIT ERROR] switch failed, current session kept | {}", e);
+            POLYMARKET_ERR_AUTH_FAILED
+        }
+    }
+}
+
+/// Rotation flow, run as a submitted runtime task
+#[cfg(feature = "execution")]
+async fn rotate_task(
+    executor: Arc<Executor>,
+    pk: Zeroizing<String>,
+    funder: String,
+) -> OrderOutcome {
+    let start = Instant::now();
+
+    let result = executor.trader().rotate(&pk, &funder).await;
+    drop(pk);
+
+    let mut outcome = match result {
+        Ok(next) => {
+            swap_session(&executor, next);
+            info!("[ROTATE] credentials swapped | funder={}", funder);
+            OrderOutcome::new(
+                PolymarketOrderResultEx {
+                    success: true,
+                    error_code: POLYMARKET_OK,
+                    ..Default::default()
+                },
+                "",
+            )
+        }
+        Err(ConnectError::InvalidKey) => OrderOutcome::error(POLYMARKET_ERR_INVALID_PK),
+        Err(e) => {
+            error!("[ROTATE ERROR] {}", e);
+            OrderOutcome::error(POLYMARKET_ERR_AUTH_FAILED)
+        }
+    };
+    outcome.result.latency_ms = start.elapsed().as_millis() as u64;
+    outcome
+}
+
+/// Credentials swapped in when the venue rejects or rate-bans the current ones
+#[cfg(feature = "execution")]
+struct BackupCredentials {
+    pk: Zeroizing<String>,
+    funder: String,
+}
+
+/// Set credentials to fail over to when the venue rejects (401/403) or
+/// rate-bans (repeated 429) the current ones; NULL private_key clears them
+/// Used once: after a failover, set another backup to be covered again
+/// Returns 0 on success, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_backup_credentials(
+    private_key: *const c_char,
+    funder_wallet: *const c_char,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if private_key.is_null() {
+            *executor.backup.lock().unwrap_or_else(|e| e.into_inner()) = None;
+            info!("[FAILOVER] backup credentials cleared");
+            return POLYMARKET_OK;
+        }
+
+        let pk = match unsafe { CStr::from_ptr(private_key) }.to_str() {
+            Ok(s) => Zeroizing::new(s.to_string()),
+            Err(_) => return POLYMARKET_ERR_INVALID_PK,
+        };
+        let funder = unsafe {
+            if funder_wallet.is_null() {
+                return POLYMARKET_ERR_AUTH_FAILED;
+            }
+            match CStr::from_ptr(funder_wallet).to_str() {
+                Ok(s) => s.to_string(),
+                Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
+            }
+        };
+        match executor.trader().credential_change(&pk, &funder) {
+            // Failing over to the credentials that failed would gain nothing
+            Ok(CredentialChange::None) => return POLYMARKET_ERR_INVALID_ARGUMENT,
+            Ok(_) => {}
+            Err(ConnectError::InvalidKey) => return POLYMARKET_ERR_INVALID_PK,
+            Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
+        }
+
+        info!("[FAILOVER] backup credentials set | funder={}", funder);
+        *executor.backup.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(BackupCredentials { pk, funder });
+        POLYMARKET_OK
+    })
+}
+
+/// Publish a credential failover to POLYMARKET_EVENT_CREDENTIALS callbacks
+#[cfg(feature = "execution")]
+fn emit_credentials_event(fault: CredentialFault, code: i32, result: &str, funder: Option<&str>) {
+    if !events::wants(POLYMARKET_EVENT_CREDENTIALS) {
+        return;
+    }
+    events::emit(events::Event::new(
+        POLYMARKET_EVENT_CREDENTIALS,
+        code,
+        None,
+        serde_json::json!({
+            "reason": fault,
+            "result": result,
+            "funder": funder,
+        }),
+    ));
+}
+
+/// Watch an order or cancel outcome for rejected or rate-banned credentials
+#[cfg(feature = "execution")]
+fn watch_credentials<T>(executor: &Arc<Executor>, result: &Result<T, OrderError>) {
+    if let Some(fault) = executor.credential_monitor.observe(result.as_ref().map(|_| ())) {
+        start_failover(executor, fault);
+    }
+}
+
+/// Swap the backup credentials in, in the background; one failover at a time
+#[cfg(feature = "execution")]
+fn start_failover(executor: &Arc<Executor>, fault: CredentialFault) {
+    if executor.failing_over.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    let backup = executor.backup.lock().unwrap_or_else(|e| e.into_inner()).take();
+    let Some(backup) = backup else {
+        error!("[FAILOVER] credentials {:?}, no backup configured", fault);
+        emit_credentials_event(fault, POLYMARKET_ERR_AUTH_FAILED, "no_backup", None);
+        executor.failing_over.store(false, Ordering::Release);
+        return;
+    };
+    if switch_blocked_by_quotes(executor, &backup.pk, &backup.funder) {
+        error!("[FAILOVER] credentials {:?}, backup wallet blocked by resting quotes", fault);
+        emit_credentials_event(
+            fault,
+            POLYMARKET_ERR_INVALID_ARGUMENT,
+            "blocked",
+            Some(&backup.funder),
+        );
+        restore_backup(executor, backup);
+        executor.failing_over.store(false, Ordering::Release);
+        return;
+    }
+    let busy = Busy::new(executor);
+    executor.spawn(failover_task(executor.clone(), fault, backup, busy));
+}
+
+/// Put an unused backup back, unless another one was set meanwhile
+#[cfg(feature = "execution")]
+fn restore_backup(executor: &Executor, backup: BackupCredentials) {
+    let mut slot = executor.backup.lock().unwrap_or_else(|e| e.into_inner());
+    if slot.is_none() {
+        *slot = Some(backup);
+    }
+}
+
+/// Failover flow, run as a runtime task
+#[cfg(feature = "execution")]
+async fn failover_task(
+    executor: Arc<Executor>,
+    fault: CredentialFault,
+    backup: BackupCredentials,
+    _busy: Busy,
+) {
+    warn!("[FAILOVER] credentials {:?}, switching to backup | funder={}", fault, backup.funder);
+    match executor.trader().rotate(&backup.pk, &backup.funder).await {
+        Ok(next) => {
+            swap_session(&executor, next);
+            executor.credential_monitor.reset();
+            info!("[FAILOVER] backup credentials in use | funder={}", backup.funder);
+            emit_credentials_event(fault, POLYMARKET_OK, "switched", Some(&backup.funder));
+        }
+        Err(e) => {
+            let code = match e {
+                ConnectError::InvalidKey => POLYMARKET_ERR_INVALID_PK,
+                _ => POLYMARKET_ERR_AUTH_FAILED,
+            };
+            error!("[FAILOVER] backup failed, current session kept | {}", e);
+            emit_credentials_event(fault, code, "failed", Some(&backup.funder));
+            restore_backup(&executor, backup);
+        }
+    }
+    executor.failing_over.store(false, Ordering::Release);
+}
+
+/// Prefetch token metadata to avoid HTTP calls during order execution
+/// Call this for each token before trading to warm up the cache
+/// Returns 0 on success, negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_prefetch(token_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let token_str = unsafe {
+            if token_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN;
+            }
+            match CStr::from_ptr(token_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        // Fetch and cache all metadata with timeout
+        let token_owned = token_str.to_string();
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().prefetch(&token_owned).await)
+        });
+
+        match result {
+            Ok(result) => prefetch_code(token_str, result),
+            Err(e) => {
+                error!("[PREFETCH ERROR] token={} | error={}", token_str, e);
+                POLYMARKET_ERR_ORDER_FAILED
+            }
+        }
+    })
+}
+
+/// Code of one token's prefetch
+fn prefetch_code(token_id: &str, result: Result<(), OrderError>) -> i32 {
+    match result {
+        Ok(()) => POLYMARKET_OK,
+        Err(OrderError::InvalidToken) => POLYMARKET_ERR_INVALID_TOKEN,
+        Err(e) => {
+            error!("[PREFETCH ERROR] token={} | error={}", token_id, e);
+            POLYMARKET_ERR_ORDER_FAILED
+        }
+    }
+}
+
+/// Prefetch `count` tokens in one call, up to 16 at a time: out_codes[i]
+/// gets the code polymarket_prefetch() would return for token_ids[i]
+/// Returns the number of tokens that failed, negative error code if none
+/// were fetched
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_prefetch_many(
+    token_ids: *const *const c_char,
+    count: usize,
+    out_codes: *mut i32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        if count == 0 {
+            return POLYMARKET_OK;
+        }
+        if out_codes.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let tokens = match id_list(token_ids, count) {
+            Ok(tokens) => tokens,
+            Err(code) => return code,
+        };
+
+        let fetched = tokens.clone();
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().prefetch_many(&fetched).await)
+        });
+
+        match result {
+            Ok(results) => {
+                let mut failed = 0;
+                for (i, (token, result)) in tokens.iter().zip(results).enumerate() {
+                    let code = prefetch_code(token, result);
+                    failed += (code != POLYMARKET_OK) as i32;
+                    unsafe { *out_codes.add(i) = code };
+                }
+                info!("[PREFETCH] tokens={} | failed={}", count, failed);
+                failed
+            }
+            Err(e) => {
+                error!("[PREFETCH ERROR] tokens={} | error={}", count, e);
+                POLYMARKET_ERR_ORDER_FAILED
+            }
+        }
+    })
+}
+
+/// Longest client order id, so it fits PolymarketOrderResultEx with its NUL
+#[cfg(feature = "execution")]
+const MAX_CLIENT_ORDER_ID: usize = 63;
+
+#[cfg(feature = "execution")]
+thread_local! {
+    /// Client order id of the next order placed from this thread
+    static CLIENT_ORDER_ID: std::cell::RefCell<Option<String>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+#[cfg(feature = "execution")]
+fn take_client_order_id() -> Option<String> {
+    CLIENT_ORDER_ID.with(|staged| staged.borrow_mut().take())
+}
+
+/// Place the next order of this thread under `client_order_id`
+/// Taken by the next single order entry point (sync, _ex, submit or async)
+/// or polymarket_replace_order() called from this thread, whether or not
+/// it is accepted; NULL drops a staged id. The id is echoed in the
+/// PolymarketOrderResultEx (v3), POLYMARKET_EVENT_ORDER, fill and summary
+/// events. An id still in use by an order that may be live fails the order
+/// with POLYMARKET_ERR_DUPLICATE_ORDER, nothing sent
+/// POLYMARKET_ERR_INVALID_ARGUMENT if empty, longer than 63 bytes or not
+/// printable ASCII
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_client_order_id(client_order_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let id = if client_order_id.is_null() {
+            None
+        } else {
+            match unsafe { CStr::from_ptr(client_order_id) }.to_str() {
+                Ok(id) if (1..=MAX_CLIENT_ORDER_ID).contains(&id.len())
+                    && id.bytes().all(|b| b.is_ascii_graphic()) => Some(id.to_string()),
+                _ => return POLYMARKET_ERR_INVALID_ARGUMENT,
+            }
+        };
+        CLIENT_ORDER_ID.with(|staged| *staged.borrow_mut() = id);
+        POLYMARKET_OK
+    })
+}
+
+/// What came of the order placed under `client_order_id`
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds {client_order_id, state: "pending" | "placed" | "refused" |
+/// "unknown", order_id} (order_id once placed); POLYMARKET_ERR_INVALID_ARGUMENT
+/// if the id was never used or is forgotten. "unknown": the post failed
+/// without an answer and the order may rest on the venue
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_client_order(client_order_id: *const c_char) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+        if client_order_id.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+        let id = match unsafe { CStr::from_ptr(client_order_id) }.to_str() {
+            Ok(id) => id,
+            Err(_) => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+        };
+
+        let Some(state) = executor.with_trader(|trader| trader.client_order(id)) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        };
+        let (state, order_id) = match state {
+            ClientOrderState::Pending => ("pending", None),
+            ClientOrderState::Placed(order_id) => ("placed", Some(order_id)),
+            ClientOrderState::Refused => ("refused", None),
+            ClientOrderState::Unknown => ("unknown", None),
+        };
+        let json = serde_json::json!({
+            "client_order_id": id,
+            "state": state,
+            "order_id": order_id,
+        });
+        out_buf::set_last_response(json.to_string()) as i64
+    })
+}
+
+/// Shared body of the polymarket_market_buy*() and polymarket_market_sell*()
+/// variants
+#[cfg(feature = "execution")]
+fn market_order(token_id: *const c_char, request: OrderRequest) -> PolymarketOrderResultEx {
+    let (executor, token_str, client_order_id) = match order_call(token_id) {
+        Ok(t) => t,
+        Err(code) => return rejected(code),
+    };
+    executor.run_order(request.op(), token_str, move |executor, token_id| {
+        order_task(executor, token_id, request, client_order_id)
+    })
+}
+
+/// Execute a market buy order (FAK - Fill and Kill)
+/// Sweeps orderbook at price 0.99 to fill immediately
+/// Returns result with filled quantity, average price, and latency
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy(
+    token_id: *const c_char,
+    usdc_amount: f64,  // amount in USDC to spend
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        let request = OrderRequest::MarketBuy { usdc_amount, max_price: MARKET_BUY_PRICE };
+        (&market_order(token_id, request)).into()
+    })
+}
+
+/// Same as polymarket_market_buy(), filling a versioned result
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_ex(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    polymarket_market_buy_capped_ex(token_id, usdc_amount, MARKET_BUY_PRICE, out)
+}
+
+/// Same as polymarket_market_buy_ex(), sweeping the book no higher than `max_price`
+/// POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+/// POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_capped_ex(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    max_price: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = market_order(token_id, OrderRequest::MarketBuy { usdc_amount, max_price });
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Execute a market buy as FOK (Fill or Kill)
+/// Like polymarket_market_buy(), but the whole amount fills at once or
+/// nothing does: if the book cannot take it all up to 0.99 the venue kills
+/// the order and it fails with POLYMARKET_ERR_REJECTED, nothing filled
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_fok(
+    token_id: *const c_char,
+    usdc_amount: f64,
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        let request = OrderRequest::MarketBuyFok { usdc_amount, max_price: MARKET_BUY_PRICE };
+        (&market_order(token_id, request)).into()
+    })
+}
+
+/// Same as polymarket_market_buy_fok(), filling a versioned result
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_fok_ex(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    polymarket_market_buy_fok_capped_ex(token_id, usdc_amount, MARKET_BUY_PRICE, out)
+}
+
+/// Same as polymarket_market_buy_fok_ex(), sweeping the book no higher than `max_price`
+/// POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+/// POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_fok_capped_ex(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    max_price: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = market_order(token_id, OrderRequest::MarketBuyFok { usdc_amount, max_price });
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Buy a number of shares (net of the taker fee) with a FAK order spending
+/// what the book asks for them, sweeping no higher than `max_price`
+/// The book cached by prefetch is used if under 1s old, else refetched;
+/// fails with POLYMARKET_ERR_LIQUIDITY if it holds too few shares up to the cap
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_shares_ex(
+    token_id: *const c_char,
+    shares: f64,
+    max_price: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = match order_call(token_id) {
+            Ok((executor, token_str, client_order_id)) => {
+                let request = OrderRequest::MarketBuyShares { shares, max_price };
+                executor.run_order(request.op(), token_str, move |executor, token_id| {
+                    order_task(executor, token_id, request, client_order_id)
+                })
+            }
+            Err(code) => rejected(code),
+        };
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Market buy (FAK) bounded by the book instead of sweeping to 0.99
+/// The book cached by prefetch or the market feed is used if under 1s old,
+/// else refetched: the order sweeps no higher than the price that fills
+/// `usdc_amount` on it, plus `buffer_bps`. Fails before signing with
+/// POLYMARKET_ERR_SLIPPAGE if the expected average price is more than
+/// `max_slippage_bps` above the best ask, POLYMARKET_ERR_LIQUIDITY if the book
+/// cannot fill `usdc_amount` at all
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_protected_ex(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    buffer_bps: u32,
+    max_slippage_bps: u32,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let request = OrderRequest::MarketBuyProtected {
+            usdc_amount,
+            buffer_bps,
+            max_slippage_bps,
+        };
+        let result = market_order(token_id, request);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Shared body of polymarket_limit_buy() and polymarket_limit_buy_ex()
+#[cfg(feature = "execution")]
+fn limit_buy(token_id: *const c_char, price: f64, usdc_amount: f64) -> PolymarketOrderResultEx {
+    let (executor, token_str, client_order_id) = match order_call(token_id) {
+        Ok(t) => t,
+        Err(code) => return rejected(code),
+    };
+    let request = OrderRequest::LimitBuy { price, usdc_amount };
+    executor.run_order(request.op(), token_str, move |executor, token_id| {
+        order_task(executor, token_id, request, client_order_id)
+    })
+}
+
+/// Place a GTC limit buy order
+/// Returns result with order info
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_buy(
+    token_id: *const c_char,
+    price: f64,       // limit price (0.01-0.99)
+    usdc_amount: f64, // amount in USDC to spend
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        (&limit_buy(token_id, price, usdc_amount)).into()
+    })
+}
+
+/// Same as polymarket_limit_buy(), filling a versioned result
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_buy_ex(
+    token_id: *const c_char,
+    price: f64,
+    usdc_amount: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = limit_buy(token_id, price, usdc_amount);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Shared body of polymarket_limit_sell() and polymarket_limit_sell_ex()
+#[cfg(feature = "execution")]
+fn limit_sell(token_id: *const c_char, price: f64, size: f64) -> PolymarketOrderResultEx {
+    let (executor, token_str, client_order_id) = match order_call(token_id) {
+        Ok(t) => t,
+        Err(code) => return rejected(code),
+    };
+    let request = OrderRequest::LimitSell { price, size };
+    executor.run_order(request.op(), token_str, move |executor, token_id| {
+        order_task(executor, token_id, request, client_order_id)
+    })
+}
+
+/// Place a GTC limit sell order
+/// Returns result with order info
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_sell(
+    token_id: *const c_char,
+    price: f64,   // limit price (0.01-0.99)
+    size: f64,    // number of shares to sell
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        (&limit_sell(token_id, price, size)).into()
+    })
+}
+
+/// Same as polymarket_limit_sell(), filling a versioned result
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_sell_ex(
+    token_id: *const c_char,
+    price: f64,
+    size: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = limit_sell(token_id, price, size);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Shared body of the GTD limit order entry points
+#[cfg(feature = "execution")]
+fn limit_gtd(token_id: *const c_char, request: OrderRequest) -> PolymarketOrderResultEx {
+    match order_call(token_id) {
+        Ok((executor, token_str, client_order_id)) => {
+            executor.run_order(request.op(), token_str, move |executor, token_id| {
+                order_task(executor, token_id, request, client_order_id)
+            })
+        }
+        Err(code) => rejected(code),
+    }
+}
+
+/// Deadline `expires_in_secs` from now (unix ms), None for 0
+#[cfg(feature = "execution")]
+fn deadline_in(expires_in_secs: u32) -> Option<u64> {
+    if expires_in_secs == 0 {
+        return None;
+    }
+    let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+    Some(now_ms + u64::from(expires_in_secs) * 1000)
+}
+
+/// Place a GTD limit buy that expires `expires_in_secs` (at least 1) from now
+/// Returns result with order info
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_buy_gtd(
+    token_id: *const c_char,
+    price: f64,
+    usdc_amount: f64,
+    expires_in_secs: u32,
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        let Some(expires_at_ms) = deadline_in(expires_in_secs) else {
+            return (&rejected(POLYMARKET_ERR_INVALID_ARGUMENT)).into();
+        };
+        let request = OrderRequest::LimitBuyGtd { price, usdc_amount, expires_at_ms };
+        (&limit_gtd(token_id, request)).into()
+    })
+}
+
+/// Place a GTD limit sell that expires `expires_in_secs` (at least 1) from now
+/// Returns result with order info
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_sell_gtd(
+    token_id: *const c_char,
+    price: f64,
+    size: f64,
+    expires_in_secs: u32,
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        let Some(expires_at_ms) = deadline_in(expires_in_secs) else {
+            return (&rejected(POLYMARKET_ERR_INVALID_ARGUMENT)).into();
+        };
+        let request = OrderRequest::LimitSellGtd { price, size, expires_at_ms };
+        (&limit_gtd(token_id, request)).into()
+    })
+}
+
+/// Place a GTD limit buy resting until `expires_at_ms` (unix ms, in the future)
+/// The venue expires orders on whole seconds: a deadline between seconds is
+/// also enforced locally by cancelling the order at that millisecond
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_buy_gtd_ex(
+    token_id: *const c_char,
+    price: f64,
+    usdc_amount: f64,
+    expires_at_ms: u64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let request = OrderRequest::LimitBuyGtd { price, usdc_amount, expires_at_ms };
+        let result = limit_gtd(token_id, request);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Place a GTD limit sell resting until `expires_at_ms`, see
+/// polymarket_limit_buy_gtd_ex()
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_sell_gtd_ex(
+    token_id: *const c_char,
+    price: f64,
+    size: f64,
+    expires_at_ms: u64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let request = OrderRequest::LimitSellGtd { price, size, expires_at_ms };
+        let result = limit_gtd(token_id, request);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Place up to MAX_BATCH_ORDERS limit orders in one request, e.g. both
+/// sides of a quote; each runs the usual checks, those that pass are signed
+/// and posted together
+/// `results` holds `count` entries, each with struct_size set; entry i gets
+/// the outcome of order i, also published as a POLYMARKET_EVENT_ORDER event.
+/// The venue answers each order on its own, and a request failing in
+/// transport fails every order sent with it.
+/// Returns 0 once the batch ran (see each result's error_code), or a
+/// negative error code if it was refused as a whole
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_post_orders_batch(
+    orders: *const PolymarketBatchOrder,
+    count: usize,
+    results: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if orders.is_null() || results.is_null() || !(1..=MAX_BATCH_ORDERS).contains(&count) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        // Entries are laid out with the caller's struct size
+        let stride = unsafe { (*results).struct_size } as usize;
+        if stride < 2 * std::mem::size_of::<u32>()
+            || !stride.is_multiple_of(std::mem::align_of::<PolymarketOrderResultEx>())
+        {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let mut batch = Vec::with_capacity(count);
+        for i in 0..count {
+            let order = unsafe { &*orders.add(i) };
+            // A bad token id fails that order alone, as InvalidToken
+            let token_id = if order.token_id.is_null() {
+                String::new()
+            } else {
+                unsafe { CStr::from_ptr(order.token_id) }.to_str().unwrap_or("").to_string()
+            };
+            let (price, amount, expires_at_ms) = (order.price, order.amount, order.expires_at_ms);
+            let request = match (side_of(order.side), expires_at_ms) {
+                (Some(Side::Buy), 0) => OrderRequest::LimitBuy { price, usdc_amount: amount },
+                (Some(Side::Buy), _) => {
+                    OrderRequest::LimitBuyGtd { price, usdc_amount: amount, expires_at_ms }
+                }
+                (Some(_), 0) => OrderRequest::LimitSell { price, size: amount },
+                (Some(_), _) => OrderRequest::LimitSellGtd { price, size: amount, expires_at_ms },
+                (None, _) => return POLYMARKET_ERR_INVALID_ARGUMENT,
+            };
+            batch.push((token_id, request));
+        }
+
+        let placed = executor.run(move |executor| async move {
+            let start = Instant::now();
+            let placed = executor.trader().place_batch(&batch).await;
+            let latency_ms = start.elapsed().as_millis() as u64;
+            Ok((batch, placed, latency_ms))
+        });
+        let (batch, placed, latency_ms) = match placed {
+            Ok(placed) => placed,
+            Err(e) => {
+                error!("[BATCH] {} orders | error={}", count, e);
+                return POLYMARKET_ERR_ORDER_FAILED;
+            }
+        };
+        let fills = match placed {
+            Ok(fills) => fills,
+            Err(e) => {
+                error!("[BATCH] rejected | orders={} | {}", count, e);
+                return match e {
+                    OrderError::InvalidAmount => POLYMARKET_ERR_INVALID_ARGUMENT,
+                    e => order_error_code("BATCH", "", &e),
+                };
+            }
+        };
+
+        // The batch went out as one call: let its answer count once
+        if let Some(sent) = fills.iter().find(|r| matches!(r, Ok(_) | Err(OrderError::Api(_)))) {
+            watch_credentials(&executor, sent);
+        }
+        for (i, ((token_id, request), fill)) in batch.into_iter().zip(fills).enumerate() {
+            let outcome = order_outcome(&executor, &token_id, request, fill, latency_ms);
+            emit_order_event(request.op(), &token_id, None, &outcome);
+            unsafe { abi::write_sized(&outcome.result, results.byte_add(i * stride)) };
+        }
+        info!("[BATCH] {} orders | latency={}ms", count, latency_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Copy the full id of the last order placed from the calling thread
+/// Returns the required buffer size (including NUL); the id is written only
+/// if `len` is large enough, pass NULL/0 to query the size.
+/// Empty string if the last order from this thread failed.
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_last_order_id(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_order_id(|id| unsafe { out_buf::write_str(id, buf, len) })
+    })
+}
+
+/// Copy the message of the last failed order or cancel call from the
+/// calling thread (blocking calls, and polymarket_poll_result()): the
+/// venue's own error string for orders it refused, else the local reason.
+/// Same buffer convention as polymarket_last_order_id(); empty string if
+/// that call succeeded or its error code says it all
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_last_error_message(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_error(|message| unsafe { out_buf::write_str(message, buf, len) })
+    })
+}
+
+/// Copy the report of the last panic caught at the FFI boundary: message,
+/// location and backtrace. Calls that panicked returned POLYMARKET_ERR_PANIC
+/// (or their documented error value). Same buffer convention as
+/// polymarket_last_order_id(); empty string if nothing panicked
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_last_panic(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        guard::with_last_panic(|report| unsafe { out_buf::write_str(report, buf, len) })
+    })
+}
+
+/// Copy the JSON result of the last query made from the calling thread
+/// (polymarket_get_rewards_earnings() etc.). Same buffer convention as
+/// polymarket_last_order_id(); the result stays until the next query
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_last_response(buf: *mut c_char, len: usize) -> usize {
+    guard::catch(0, || {
+        out_buf::with_last_response(|json| unsafe { out_buf::write_str(json, buf, len) })
+    })
+}
+
+/// Cancel an order by ID
+/// Returns 0 on success, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel(order_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let order_str = unsafe {
+            if order_id.is_null() {
+                return POLYMARKET_ERR_CANCEL_FAILED;
+            }
+            match CStr::from_ptr(order_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_CANCEL_FAILED,
+            }
+        };
+
+        let order_owned = order_str.to_string();
+        let result = executor.run(move |executor| async move {
+            Ok(cancel_task(executor, order_owned).await)
+        });
+
+        match result {
+            Ok(outcome) => {
+                out_buf::set_last_error(&outcome.error_message);
+                outcome.result.error_code
+            }
+            Err(e) => {
+                error!("[FFI CANCEL ERROR] order_id={} error={}", order_str, e);
+                out_buf::set_last_error(&e.to_string());
+                POLYMARKET_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Cancel flow, run as a runtime task (blocking or submitted)
+#[cfg(feature = "execution")]
+async fn cancel_task(executor: Arc<Executor>, order_id: String) -> OrderOutcome {
+    let start = Instant::now();
+
+    let result = executor.trader().cancel(&order_id).await;
+
+    let latency_ms = start.elapsed().as_millis() as u64;
+    watch_credentials(&executor, &result);
+
+    let mut outcome = match result {
+        Ok(_) => OrderOutcome::new(
+            PolymarketOrderResultEx {
+                success: true,
+                error_code: POLYMARKET_OK,
+                ..Default::default()
+            },
+            &order_id,
+        ),
+        Err(OrderError::ReadOnly) => OrderOutcome::error(POLYMARKET_ERR_READ_ONLY),
+        Err(e) => {
+            error!("[FFI CANCEL ERROR] order_id={} error={}", order_id, e);
+            let code = match &e {
+                OrderError::Api(e) => api_error_code(e, POLYMARKET_ERR_CANCEL_FAILED),
+                _ => POLYMARKET_ERR_CANCEL_FAILED,
+            };
+            OrderOutcome::failed(code, order_error_message(&e))
+        }
+    };
+    outcome.result.latency_ms = latency_ms;
+    outcome
+}
+
+/// Cancel all open orders
+/// Returns 0 on success, POLYMARKET_ERR_CANCEL_FAILED if the call or any
+/// order failed, other negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_all() -> i32 {
+    polymarket_cancel_all_ex(std::ptr::null_mut(), 0, std::ptr::null_mut())
+}
+
+/// Cancel all open orders and report the outcome of each
+/// The first `capacity` outcomes are written to `out`; `out_count`, if not
+/// NULL, receives how many there are, which may exceed capacity
+/// Returns 0 if every order is gone (cancelled, filled or unknown),
+/// POLYMARKET_ERR_CANCEL_FAILED if the call or any order failed, other
+/// negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_all_ex(
+    out: *mut PolymarketCancelOutcome,
+    capacity: usize,
+    out_count: *mut usize,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if out.is_null() && capacity > 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        // Keep the quoter from re-posting what is about to be pulled
+        executor.quoter.clear_targets();
+        let result = executor.run(move |executor| async move {
+            executor.trader().cancel_all().await.map_err(anyhow::Error::from)
+        });
+        report_cancels("CANCEL_ALL", result, out, capacity, out_count)
+    })
+}
+
+/// Cancel the open orders of one token only, leaving other markets alone
+/// Returns like polymarket_cancel_all(); POLYMARKET_ERR_INVALID_TOKEN for a
+/// malformed token id
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_market(token_id: *const c_char) -> i32 {
+    polymarket_cancel_market_ex(token_id, std::ptr::null_mut(), 0, std::ptr::null_mut())
+}
+
+/// Cancel the open orders of one token and report the outcome of each
+/// Outcomes and return value like polymarket_cancel_all_ex()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_market_ex(
+    token_id: *const c_char,
+    out: *mut PolymarketCancelOutcome,
+    capacity: usize,
+    out_count: *mut usize,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        if out.is_null() && capacity > 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        // Keep the quoter from re-posting what is about to be pulled
+        executor.quoter.set_target(&token_str, QuoteTarget::default());
+        let result = executor.run(move |executor| async move {
+            executor.trader().cancel_market(&token_str).await.map_err(anyhow::Error::from)
+        });
+        report_cancels("CANCEL_MARKET", result, out, capacity, out_count)
+    })
+}
+
+/// Cancel `count` orders by id in one request, e.g. to pull a set of quotes
+/// Returns like polymarket_cancel_all()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_orders(order_ids: *const *const c_char, count: usize) -> i32 {
+    polymarket_cancel_orders_ex(order_ids, count, std::ptr::null_mut(), 0, std::ptr::null_mut())
+}
+
+/// Cancel `count` orders by id in one request and report the outcome of each
+/// Outcomes and return value like polymarket_cancel_all_ex()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_orders_ex(
+    order_ids: *const *const c_char,
+    count: usize,
+    out: *mut PolymarketCancelOutcome,
+    capacity: usize,
+    out_count: *mut usize,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if out.is_null() && capacity > 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        if count == 0 {
+            if !out_count.is_null() {
+                unsafe { *out_count = 0 };
+            }
+            return POLYMARKET_OK;
+        }
+        let ids = match id_list(order_ids, count) {
+            Ok(ids) => ids,
+            Err(code) => return code,
+        };
+
+        let result = executor.run(move |executor| async move {
+            let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+            executor.trader().cancel_orders(&ids).await.map_err(anyhow::Error::from)
+        });
+        report_cancels("CANCEL_ORDERS", result, out, capacity, out_count)
+    })
+}
+
+/// `count` ids (orders, tokens) from a C array of strings
+/// POLYMARKET_ERR_INVALID_ARGUMENT if the array or an id is NULL or not UTF-8
+fn id_list(ids: *const *const c_char, count: usize) -> Result<Vec<String>, i32> {
+    if ids.is_null() {
+        return Err(POLYMARKET_ERR_INVALID_ARGUMENT);
+    }
+    (0..count)
+        .map(|i| {
+            let id = unsafe { *ids.add(i) };
+            if id.is_null() {
+                return Err(POLYMARKET_ERR_INVALID_ARGUMENT);
+            }
+            let id = unsafe { CStr::from_ptr(id) }.to_str();
+            id.map(str::to_string).map_err(|_| POLYMARKET_ERR_INVALID_ARGUMENT)
+        })
+        .collect()
+}
+
+/// Move a resting order to `new_price` for `new_size` shares (<= 0 keeps the
+/// unmatched size): cancel it and post the replacement back to back in one
+/// runtime task, instead of two calls with a round trip between them
+/// Same token and side; a GTD order keeps its deadline. The replacement is
+/// posted only if the venue cancelled the old order, otherwise
+/// POLYMARKET_ERR_CANCEL_FAILED and nothing is posted
+/// Returns the error code, also stored in `out`; the new order id via
+/// polymarket_last_order_id()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_replace_order(
+    order_id: *const c_char,
+    new_price: f64,
+    new_size: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let client_order_id = take_client_order_id();
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if order_id.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let order_str = match unsafe { CStr::from_ptr(order_id) }.to_str() {
+            Ok(s) if !s.is_empty() => s.to_string(),
+            _ => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+
+        let (token_id, outcome) = executor
+            .run(move |executor| async move {
+                let task = replace_task(executor, order_str, new_price, new_size, client_order_id);
+                Ok(task.await)
+            })
+            .unwrap_or_else(|e| {
+                error!("[FFI ORDER ERROR] REPLACE_ORDER | error={}", e);
+                (String::new(), OrderOutcome::failed(POLYMARKET_ERR_ORDER_FAILED, e.to_string()))
+            });
+        outcome.deliver();
+        emit_order_event("REPLACE_ORDER", &token_id, None, &outcome);
+
+        if !unsafe { abi::write_sized(&outcome.result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        outcome.result.error_code
+    })
+}
+
+/// Replace flow, run as a runtime task: the token of the order and the
+/// outcome of its replacement
+#[cfg(feature = "execution")]
+async fn replace_task(
+    executor: Arc<Executor>,
+    order_id: String,
+    price: f64,
+    size: f64,
+    client_order_id: Option<String>,
+) -> (String, OrderOutcome) {
+    let start = Instant::now();
+    let trader = executor.trader();
+    let (token_id, request) = match trader.replacement(&order_id, price, size).await {
+        Ok(replacement) => replacement,
+        Err(e) => {
+            error!("[FFI ORDER ERROR] REPLACE_ORDER | order_id={} error={}", order_id, e);
+            let code = order_error_code("REPLACE_ORDER", "", &e);
+            let mut outcome = OrderOutcome::failed(code, order_error_message(&e));
+            outcome.result.latency_ms = start.elapsed().as_millis() as u64;
+            return (String::new(), outcome.tagged(client_order_id));
+        }
+    };
+
+    let result = match &client_order_id {
+        Some(id) => trader.replace_with_id(id, &order_id, &token_id, request).await,
+        None => trader.replace(&order_id, &token_id, request).await,
+    };
+    let latency_ms = start.elapsed().as_millis() as u64;
+    watch_credentials(&executor, &result);
+    let outcome = order_outcome(&executor, &token_id, request, result, latency_ms);
+    (token_id, outcome.tagged(client_order_id))
+}
+
+/// Write the outcomes of a bulk cancel and turn them into an error code:
+/// POLYMARKET_ERR_CANCEL_FAILED if the call or any order failed
+#[cfg(feature = "execution")]
+fn report_cancels(
+    op: &str,
+    result: anyhow::Result<Vec<CancelOutcome>>,
+    out: *mut PolymarketCancelOutcome,
+    capacity: usize,
+    out_count: *mut usize,
+) -> i32 {
+    match result {
+        Ok(outcomes) => {
+            if !out_count.is_null() {
+                unsafe { *out_count = outcomes.len() };
+            }
+            for (i, outcome) in outcomes.iter().take(capacity).enumerate() {
+                PolymarketCancelOutcome::write(unsafe { &mut *out.add(i) }, outcome);
+            }
+            let failed = outcomes.iter().filter(|o| o.is_failure()).count();
+            for outcome in outcomes.iter().filter(|o| o.is_failure()) {
+                warn!("[FFI {}] not cancelled | {} | {}", op, outcome.order_id, outcome.reason);
+            }
+            info!("[FFI {}] orders={} failed={}", op, outcomes.len(), failed);
+            if failed > 0 {
+                POLYMARKET_ERR_CANCEL_FAILED
+            } else {
+                POLYMARKET_OK
+            }
+        }
+        Err(e) if matches!(e.downcast_ref(), Some(OrderError::ReadOnly)) => {
+            POLYMARKET_ERR_READ_ONLY
+        }
+        Err(e) => {
+            error!("[FFI {} ERROR] error={}", op, e);
+            POLYMARKET_ERR_CANCEL_FAILED
+        }
+    }
+}
+
+/// List the account's orders resting on the book, of one token or (NULL)
+/// of every market, e.g. to reconcile local order state after a restart
+/// The first `capacity` orders are written to `out`; `out_count`, if not
+/// NULL, receives how many there are, which may exceed capacity
+/// Returns 0 on success, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_open_orders(
+    token_id: *const c_char,
+    out: *mut PolymarketOpenOrder,
+    capacity: usize,
+    out_count: *mut usize,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if out.is_null() && capacity > 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let token = if token_id.is_null() {
+            None
+        } else {
+            match unsafe { CStr::from_ptr(token_id) }.to_str() {
+                Ok(s) if parse_token(s).is_ok() => Some(s.to_string()),
+                _ => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        let label = token.clone().unwrap_or_default();
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().open_orders(token.as_deref()).await)
+        });
+
+        match result {
+            Ok(Ok(orders)) => {
+                out_buf::set_last_error("");
+                if !out_count.is_null() {
+                    unsafe { *out_count = orders.len() };
+                }
+                for (i, order) in orders.iter().take(capacity).enumerate() {
+                    PolymarketOpenOrder::write(unsafe { &mut *out.add(i) }, order);
+                }
+                POLYMARKET_OK
+            }
+            Ok(Err(e)) => {
+                error!("[FFI OPEN ORDERS ERROR] token={} error={}", label, e);
+                out_buf::set_last_error(&order_error_message(&e));
+                order_error_code("OPEN ORDERS", &label, &e)
+            }
+            Err(e) => {
+                error!("[FFI OPEN ORDERS ERROR] error={}", e);
+                out_buf::set_last_error(&e.to_string());
+                POLYMARKET_ERR_ORDER_FAILED
+            }
+        }
+    })
+}
+
+/// Our fills matched at or after `since_ms` (unix ms), of one token or
+/// (NULL) of every market, oldest first; recovers fills of orders matched
+/// while the user channel was down
+/// Array output like polymarket_get_open_orders()
+/// Returns 0 on success, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_trades(
+    token_id: *const c_char,
+    since_ms: u64,
+    out: *mut PolymarketTrade,
+    capacity: usize,
+    out_count: *mut usize,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if out.is_null() && capacity > 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let token = if token_id.is_null() {
+            None
+        } else {
+            match unsafe { CStr::from_ptr(token_id) }.to_str() {
+                Ok(s) if parse_token(s).is_ok() => Some(s.to_string()),
+                _ => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        let label = token.clone().unwrap_or_default();
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().trades(token.as_deref(), since_ms).await)
+        });
+
+        match result {
+            Ok(Ok(trades)) => {
+                out_buf::set_last_error("");
+                if !out_count.is_null() {
+                    unsafe { *out_count = trades.len() };
+                }
+                for (i, trade) in trades.iter().take(capacity).enumerate() {
+                    PolymarketTrade::write(unsafe { &mut *out.add(i) }, trade);
+                }
+                POLYMARKET_OK
+            }
+            Ok(Err(e)) => {
+                error!("[FFI TRADES ERROR] token={} error={}", label, e);
+                out_buf::set_last_error(&order_error_message(&e));
+                order_error_code("TRADES", &label, &e)
+            }
+            Err(e) => {
+                error!("[FFI TRADES ERROR] error={}", e);
+                out_buf::set_last_error(&e.to_string());
+                POLYMARKET_ERR_ORDER_FAILED
+            }
+        }
+    })
+}
+
+/// Look up an order by id: its state and how much of it matched, e.g. to
+/// poll a resting GTC order
+/// Returns 0 on success, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_order(
+    order_id: *const c_char,
+    out: *mut PolymarketOrderStatus,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if order_id.is_null() || out.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let order_str = match unsafe { CStr::from_ptr(order_id) }.to_str() {
+            Ok(s) if !s.is_empty() => s.to_string(),
+            _ => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+
+        let id = order_str.clone();
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().order_status(&id).await)
+        });
+
+        match result {
+            Ok(Ok(order)) => {
+                out_buf::set_last_error("");
+                if !unsafe { abi::write_sized(&PolymarketOrderStatus::new(&order), out) } {
+                    return POLYMARKET_ERR_INVALID_ARGUMENT;
+                }
+                POLYMARKET_OK
+            }
+            Ok(Err(e)) => {
+                error!("[FFI GET ORDER ERROR] order_id={} error={}", order_str, e);
+                out_buf::set_last_error(&order_error_message(&e));
+                order_error_code("GET ORDER", "", &e)
+            }
+            Err(e) => {
+                error!("[FFI GET ORDER ERROR] order_id={} error={}", order_str, e);
+                out_buf::set_last_error(&e.to_string());
+                POLYMARKET_ERR_ORDER_FAILED
+            }
+        }
+    })
+}
+
+/// Get USDC balance
+/// Returns raw balance (6 decimals), or negative on error
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_balance() -> i64 {
+    guard::catch(-1, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return -1,
+        };
+
+        executor
+            .run(move |executor| async move { executor.trader().usdc_balance().await })
+            .unwrap_or(-1)
+    })
+}
+
+/// Bound venue calls of `op_class` (POLYMARKET_CALL_*) started from now on
+/// by `timeout_ms` (100 - 120000); every class starts at 10s
+/// Kept across credential rotation, reset by a new polymarket_init*()
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_timeout_ms(op_class: i32, timeout_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let Some(class) = call_class(op_class) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        };
+
+        let timeout = Duration::from_millis(timeout_ms as u64);
+        match executor.with_trader(|trader| trader.set_timeout(class, timeout)) {
+            Ok(()) => {
+                info!("[TIMEOUT] {:?} calls | {}ms", class, timeout_ms);
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[TIMEOUT] {}", e);
+                POLYMARKET_ERR_INVALID_ARGUMENT
+            }
+        }
+    })
+}
+
+/// Call class of a POLYMARKET_CALL_* value
+fn call_class(op_class: i32) -> Option<CallClass> {
+    match op_class {
+        POLYMARKET_CALL_ORDER => Some(CallClass::Order),
+        POLYMARKET_CALL_MARKET_DATA => Some(CallClass::MarketData),
+        POLYMARKET_CALL_ACCOUNT => Some(CallClass::Account),
+        _ => None,
+    }
+}
+
+/// Limit venue calls of `op_class` (POLYMARKET_CALL_*) to `per_sec` on
+/// average, `burst` back to back; 0 per_sec lifts the limit
+/// Over budget, calls wait for it (at most their timeout) or, with
+/// POLYMARKET_RATE_REJECT, fail at once; either way with
+/// POLYMARKET_ERR_RATE_LIMITED when refused, without reaching the venue
+/// Kept across credential rotation, reset by a new polymarket_init*()
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_rate_limit(
+    op_class: i32,
+    per_sec: f64,
+    burst: u32,
+    flags: u32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let Some(class) = call_class(op_class) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        };
+        if flags & !POLYMARKET_RATE_REJECT != 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let limit = RateLimit { per_sec, burst, queue: flags & POLYMARKET_RATE_REJECT == 0 };
+        match executor.with_trader(|trader| trader.set_rate_limit(class, limit)) {
+            Ok(()) => {
+                info!("[RATE LIMIT] {:?} calls | {}/s burst={} queue={}",
+                    class, per_sec, burst, limit.queue);
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[RATE LIMIT] {}", e);
+                POLYMARKET_ERR_INVALID_ARGUMENT
+            }
+        }
+    })
+}
+
+/// Rate limit of `op_class` (POLYMARKET_CALL_*) and the budget left now
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_rate_budget(op_class: i32, out: *mut PolymarketRateBudget) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let Some(class) = call_class(op_class) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        };
+
+        let (limit, available) = executor
+            .with_trader(|trader| (trader.rate_limit(class), trader.rate_budget(class)));
+        let value = PolymarketRateBudget {
+            per_sec: available.map_or(0.0, |_| limit.per_sec),
+            burst: limit.burst,
+            flags: if limit.queue { 0 } else { POLYMARKET_RATE_REJECT },
+            available: available.unwrap_or(0.0),
+            ..Default::default()
+        };
+        if !unsafe { abi::write_sized(&value, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Resend order posts and cancels that timed out, lost the connection or
+/// got a 429/5xx up to `max_retries` times, `base_delay_ms` apart at first
+/// and doubling up to `max_delay_ms`; 0 max_retries turns retries off
+/// A post resends the payload signed once, so it cannot fill twice
+/// Kept across credential rotation, reset by a new polymarket_init*()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_retry_policy(
+    max_retries: u32,
+    base_delay_ms: u32,
+    max_delay_ms: u32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let policy = RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(base_delay_ms as u64),
+            max_delay: Duration::from_millis(max_delay_ms as u64),
+        };
+        match executor.with_trader(|trader| trader.set_retry_policy(policy)) {
+            Ok(()) => {
+                info!("[RETRY] up to {} retries | delay {}-{}ms",
+                    max_retries, base_delay_ms, max_delay_ms);
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[RETRY] {}", e);
+                POLYMARKET_ERR_INVALID_ARGUMENT
+            }
+        }
+    })
+}
+
+/// Set the USDC safety buffer kept out of reach of buys
+/// Buys are rejected locally with POLYMARKET_ERR_INSUFFICIENT_BALANCE when
+/// cached balance - buffer - in-flight buys can't fund them.
+/// The check is active once polymarket_get_balance() has been called.
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_balance_buffer(buffer_usdc: f64) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let buffer_raw = (buffer_usdc * DECIMAL_SCALE as f64) as i64;
+        executor.with_trader(|trader| trader.balance().set_buffer(buffer_raw));
+        POLYMARKET_OK
+    })
+}
+
+/// Get USDC available for new buys: cached balance - buffer - in-flight buys
+/// Returns raw value (6 decimals), or -1 if not initialized or balance never fetched
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_available_balance() -> i64 {
+    guard::catch(-1, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return -1,
+        };
+
+        executor.trader().balance().available().map(|a| a.max(0)).unwrap_or(-1)
+    })
+}
+
+/// Get token balance (shares held)
+/// Returns raw balance (6 decimals), or negative on error
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_token_balance(token_id: *const c_char) -> i64 {
+    guard::catch(-1, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return -1,
+        };
+
+        let token_str = unsafe {
+            if token_id.is_null() {
+                return -1;
+            }
+            match CStr::from_ptr(token_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return -1,
+            }
+        };
+
+        let token_owned = token_str.to_string();
+        // Also resyncs the position tracked for risk limits
+        executor
+            .run(move |executor| async move {
+                executor.trader().token_balance(&token_owned).await.map_err(anyhow::Error::from)
+            })
+            .unwrap_or(-1)
+    })
+}
+
+/// Every conditional token position of the funder wallet, from the Data API
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds [{token_id, condition_id, outcome, shares, avg_price, redeemable}]
+/// (avg_price null if unknown), or negative error code
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_positions() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().wallet_positions().await)
+        });
+        match result {
+            Ok(Ok(positions)) => {
+                out_buf::set_last_response(serde_json::to_string(&positions).unwrap_or_default())
+                    as i64
+            }
+            Ok(Err(e)) => {
+                error!("[POSITIONS ERROR] {}", e);
+                out_buf::set_last_error(&order_error_message(&e));
+                order_error_code("POSITIONS", "", &e) as i64
+            }
+            Err(e) => {
+                error!("[POSITIONS ERROR] {}", e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Mark every tracked position to its midpoint (last trade if the book is
+/// one-sided) and total the unrealized PnL
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds {positions: [{token_id, shares, avg_price, mark_price, source,
+/// market_value, unrealized_pnl}], unrealized_pnl, market_value, unpriced}
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_mark_positions() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().mark_positions().await)
+        });
+        match result.and_then(|report| Ok(serde_json::to_string(&report)?)) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(e) => {
+                error!("[PNL ERROR] {}", e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Estimate a market order against the token's book without placing it
+/// `amount` is USDC for POLYMARKET_SIDE_BUY, shares for POLYMARKET_SIDE_SELL;
+/// the book cached by prefetch is used if under 1s old, else refetched
+/// Returns the error code; `out` is filled on success
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_estimate_fill(
+    token_id: *const c_char,
+    side: i32,
+    amount: f64,
+    out: *mut PolymarketFillEstimate,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        let side = match side_of(side) {
+            Some(side) => side,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+        if out.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let token = token_str.clone();
+        let result = executor.run(move |executor| async move {
+            executor
+                .trader()
+                .estimate_fill(&token, side, amount)
+                .await
+                .map_err(anyhow::Error::from)
+        });
+
+        let (estimate, age) = match result {
+            Ok(r) => r,
+            Err(e) if matches!(e.downcast_ref(), Some(OrderError::InvalidAmount)) => {
+                return POLYMARKET_ERR_INVALID_ARGUMENT;
+            }
+            Err(e) => {
+                error!("[ESTIMATE ERROR] token={} | error={}", token_str, e);
+                return POLYMARKET_ERR_ORDER_FAILED;
+            }
+        };
+        let value = PolymarketFillEstimate {
+            filled_qty_raw: decimal_to_raw(estimate.filled_shares),
+            notional_raw: decimal_to_raw(estimate.notional),
+            avg_price_raw: decimal_to_raw(estimate.avg_price),
+            worst_price_raw: decimal_to_raw(estimate.worst_price),
+            fee_raw: decimal_to_raw(estimate.fee_shares),
+            filled_fraction: estimate.filled_fraction,
+            book_age_ms: age.as_millis() as u64,
+            ..Default::default()
+        };
+        if !unsafe { abi::write_sized(&value, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Keep a local book of a token from the CLOB market channel
+/// The first subscription opens the connection; it reconnects on its own
+/// and closes on shutdown. Updates go to POLYMARKET_EVENT_MARKET_DATA
+/// callbacks and refresh the book polymarket_estimate_fill() walks
+/// Returns POLYMARKET_OK, also if already subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_subscribe_book(token_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+
+        subscribe_market_channel(&executor, &token_str);
+        POLYMARKET_OK
+    })
+}
+
+/// Subscribe a token on the market channel, connecting it if not running
+fn subscribe_market_channel(executor: &Arc<Executor>, token_id: &str) {
+    if executor.books.subscribe(token_id) {
+        info!("[BOOK FEED] subscribed | token={}", token_id);
+    }
+    let mut slot = executor.book_feed.lock().unwrap_or_else(|e| e.into_inner());
+    if slot.as_ref().is_none_or(|task| task.is_finished()) {
+        *slot = Some(executor.spawn(book_feed_task(executor.clone())));
+    }
+}
+
+/// Stop keeping a token's book; the connection stays open
+/// Returns POLYMARKET_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_unsubscribe_book(token_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+
+        if !executor.books.unsubscribe(&token_str) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        info!("[BOOK FEED] unsubscribed | token={}", token_str);
+        POLYMARKET_OK
+    })
+}
+
+/// Stream a token's public trades from the CLOB market channel to
+/// `callback`, replacing any callback set before for the token
+/// Subscribes the token like polymarket_subscribe_book(). The callback
+/// runs on a runtime worker thread, one trade at a time; it must not block
+/// or call back into the library
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_subscribe_trade_tape(
+    token_id: *const c_char,
+    callback: Option<PolymarketTradeTapeCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+
+        let tape = TradeTapeCallback { callback, user_data };
+        let mut tapes = executor.trade_tapes.lock().unwrap_or_else(|e| e.into_inner());
+        if tapes.insert(token_str.clone(), tape).is_none() {
+            info!("[BOOK FEED] trade tape subscribed | token={}", token_str);
+        }
+        drop(tapes);
+        subscribe_market_channel(&executor, &token_str);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop passing a token's trades to its callback; once this returns it is
+/// not invoked again. The token's book stays subscribed
+/// Returns POLYMARKET_ERR_INVALID_ARGUMENT if no callback was set
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_unsubscribe_trade_tape(token_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+
+        // Waits for a callback in progress
+        let mut tapes = executor.trade_tapes.lock().unwrap_or_else(|e| e.into_inner());
+        if tapes.remove(&token_str).is_none() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        info!("[BOOK FEED] trade tape unsubscribed | token={}", token_str);
+        POLYMARKET_OK
+    })
+}
+
+/// Local book of a subscribed token and its state, or the code to return
+/// instead
+fn subscribed_book(token_id: *const c_char) -> Result<(BookSnapshot, BookState), i32> {
+    let (executor, token_str) = order_target(token_id)?;
+    match executor.books.book_state(&token_str) {
+        Some(book) => Ok(book),
+        None if executor.books.is_subscribed(&token_str) => Err(POLYMARKET_PENDING),
+        None => Err(POLYMARKET_ERR_INVALID_ARGUMENT),
+    }
+}
+
+/// Best bid and ask of a token subscribed with polymarket_subscribe_book()
+/// Returns POLYMARKET_PENDING until the venue sent its book (`out`
+/// untouched), POLYMARKET_ERR_INVALID_ARGUMENT if not subscribed
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_best_bid_ask(
+    token_id: *const c_char,
+    out: *mut PolymarketBestBidAsk,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (book, state) = match subscribed_book(token_id) {
+            Ok(book) => book,
+            Err(code) => return code,
+        };
+
+        let mut value = PolymarketBestBidAsk {
+            age_ms: book.age().as_millis() as u64,
+            generation: state.generation,
+            stale: state.stale,
+            ..Default::default()
+        };
+        if let Some(bid) = book.bids.first() {
+            value.bid_price_raw = decimal_to_raw(bid.price);
+            value.bid_size_raw = decimal_to_raw(bid.size);
+        }
+        if let Some(ask) = book.asks.first() {
+            value.ask_price_raw = decimal_to_raw(ask.price);
+            value.ask_size_raw = decimal_to_raw(ask.size);
+        }
+        if !unsafe { abi::write_sized(&value, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Generation, staleness and gap counts of a subscribed token's book
+/// Returns like polymarket_get_best_bid_ask()
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_book_state(
+    token_id: *const c_char,
+    out: *mut PolymarketBookState,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (book, state) = match subscribed_book(token_id) {
+            Ok(book) => book,
+            Err(code) => return code,
+        };
+
+        let value = PolymarketBookState {
+            generation: state.generation,
+            gaps: state.gaps,
+            resyncs: state.resyncs,
+            timestamp_ms: state.timestamp_ms,
+            age_ms: book.age().as_millis() as u64,
+            stale: state.stale,
+            ..Default::default()
+        };
+        if !unsafe { abi::write_sized(&value, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Top POLYMARKET_BOOK_DEPTH levels per side of a subscribed token's book
+/// Returns like polymarket_get_best_bid_ask()
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_book_snapshot(
+    token_id: *const c_char,
+    out: *mut PolymarketBookSnapshot,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (book, state) = match subscribed_book(token_id) {
+            Ok(book) => book,
+            Err(code) => return code,
+        };
+
+        let mut value = PolymarketBookSnapshot {
+            age_ms: book.age().as_millis() as u64,
+            generation: state.generation,
+            stale: state.stale,
+            ..Default::default()
+        };
+        let fill = |out: &mut [PolymarketBookLevel], levels: &[Level]| {
+            for (slot, level) in out.iter_mut().zip(levels) {
+                *slot = PolymarketBookLevel {
+                    price_raw: decimal_to_raw(level.price),
+                    size_raw: decimal_to_raw(level.size),
+                };
+            }
+            levels.len().min(POLYMARKET_BOOK_DEPTH) as u32
+        };
+        value.bid_count = fill(&mut value.bids, &book.bids);
+        value.ask_count = fill(&mut value.asks, &book.asks);
+        if !unsafe { abi::write_sized(&value, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Top `depth` levels per side of a token's book: the subscribed one if
+/// the venue sent it, else the cached one if under 1s old, else fetched
+/// Bids go to out_levels[0, depth) and asks to out_levels[depth, 2 * depth),
+/// best first, slots past a side's last level zeroed; `max` is the slots
+/// out_levels holds, at least 2 * depth
+/// Returns the number of levels written, or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_order_book(
+    token_id: *const c_char,
+    depth: u32,
+    out_levels: *mut PolymarketBookLevel,
+    max: usize,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        let depth = depth as usize;
+        let slots = match depth.checked_mul(2) {
+            Some(slots) if depth > 0 && slots <= max && !out_levels.is_null() => slots,
+            _ => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+        };
+
+        let book = match executor.books.book(&token_str) {
+            Some(book) => Arc::new(book),
+            None => {
+                let token = token_str.clone();
+                let result = executor.run(move |executor| async move {
+                    executor.trader().recent_book(&token).await.map_err(anyhow::Error::from)
+                });
+                match result {
+                    Ok(book) => book,
+                    Err(e) => {
+                        error!("[BOOK ERROR] token={} | error={}", token_str, e);
+                        return POLYMARKET_ERR_ORDER_FAILED as i64;
+                    }
+                }
+            }
+        };
+
+        let out = unsafe { std::slice::from_raw_parts_mut(out_levels, slots) };
+        let (bids, asks) = out.split_at_mut(depth);
+        let fill = |out: &mut [PolymarketBookLevel], levels: &[Level]| {
+            out.fill(PolymarketBookLevel::default());
+            for (slot, level) in out.iter_mut().zip(levels) {
+                *slot = PolymarketBookLevel {
+                    price_raw: decimal_to_raw(level.price),
+                    size_raw: decimal_to_raw(level.size),
+                };
+            }
+            levels.len().min(depth) as i64
+        };
+        fill(bids, &book.bids) + fill(asks, &book.asks)
+    })
+}
+
+/// Midpoint, or the spread if `spread` is set, of a token as raw (6
+/// decimals), or a negative error code
+/// A subscribed token's book answers without a request
+fn token_price(token_id: *const c_char, spread: bool) -> i64 {
+    let (executor, token_str) = match order_target(token_id) {
+        Ok(t) => t,
+        Err(code) => return code as i64,
+    };
+    let local = executor
+        .books
+        .book(&token_str)
+        .and_then(|book| if spread { book.spread() } else { book.midpoint() });
+    if let Some(price) = local {
+        return decimal_to_raw(price);
+    }
+
+    let token = token_str.clone();
+    let result = executor.run(move |executor| async move {
+        let trader = executor.trader();
+        let price = if spread {
+            trader.spread(&token).await
+        } else {
+            trader.midpoint(&token).await
+        };
+        price.map_err(anyhow::Error::from)
+    });
+    match result {
+        Ok(price) => decimal_to_raw(price),
+        Err(e) => {
+            error!("[PRICE ERROR] token={} | spread={} | error={}", token_str, spread, e);
+            POLYMARKET_ERR_ORDER_FAILED as i64
+        }
+    }
+}
+
+/// Midpoint of a token's best bid and ask
+/// From the subscribed or a recent cached book if it has both sides, else
+/// the CLOB midpoint endpoint
+/// Returns the price raw (6 decimals), or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_midpoint(token_id: *const c_char) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || token_price(token_id, false))
+}
+
+/// Best ask less best bid of a token, from the same sources as
+/// polymarket_get_midpoint()
+/// Returns the spread raw (6 decimals), or a negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_spread(token_id: *const c_char) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || token_price(token_id, true))
+}
+
+/// OHLC candles of `interval_secs` (a multiple of 60) of a token's price
+/// between unix seconds `from_ts` and `to_ts`, from the CLOB price history
+/// The newest `max` are written to `out`, oldest first
+/// Returns the number of candles, which may exceed max, or a negative
+/// error code
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_price_history(
+    token_id: *const c_char,
+    interval_secs: u32,
+    from_ts: i64,
+    to_ts: i64,
+    out: *mut PolymarketCandle,
+    max: usize,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        if out.is_null() && max > 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+
+        let token = token_str.clone();
+        let result = executor.run(move |executor| async move {
+            let trader = executor.trader();
+            let history = trader.price_history(&token, interval_secs, from_ts, to_ts).await;
+            history.map_err(anyhow::Error::from)
+        });
+        let candles = match result {
+            Ok(candles) => candles,
+            Err(e) if matches!(e.downcast_ref(), Some(OrderError::InvalidAmount)) => {
+                return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+            }
+            Err(e) => {
+                error!("[HISTORY ERROR] token={} | error={}", token_str, e);
+                return POLYMARKET_ERR_ORDER_FAILED as i64;
+            }
+        };
+
+        let newest = &candles[candles.len().saturating_sub(max)..];
+        for (i, candle) in newest.iter().enumerate() {
+            let value = PolymarketCandle {
+                start_ts: candle.start_ts,
+                open_raw: decimal_to_raw(candle.open),
+                high_raw: decimal_to_raw(candle.high),
+                low_raw: decimal_to_raw(candle.low),
+                close_raw: decimal_to_raw(candle.close),
+                samples: candle.samples,
+            };
+            unsafe { out.add(i).write(value) };
+        }
+        candles.len() as i64
+    })
+}
+
+/// Stream the account's order updates and fills from the CLOB user channel
+/// to `callback`, replacing any callback set before
+/// The callback runs on a runtime worker thread, one update at a time; it
+/// must not block or call back into the library
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_subscribe_order_updates(
+    callback: Option<PolymarketOrderUpdateCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+
+        *executor.order_updates.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(OrderUpdateCallback { callback, user_data });
+        let mut slot = executor.user_channel.lock().unwrap_or_else(|e| e.into_inner());
+        if slot.as_ref().is_none_or(|task| task.is_finished()) {
+            *slot = Some(executor.spawn(user_channel_task(executor.clone())));
+            info!("[USER CHANNEL] subscribed");
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Close the user channel; once this returns the callback is not invoked
+/// again. No-op if not subscribed
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_unsubscribe_order_updates() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.user_channel.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[USER CHANNEL] unsubscribed");
+        }
+        // Waits for a callback in progress
+        executor.order_updates.lock().unwrap_or_else(|e| e.into_inner()).take();
+        POLYMARKET_OK
+    })
+}
+
+/// Start a TWAP: `total` (USDC for buys, shares for sells) split into
+/// `slices` market orders sent `interval_ms` apart, the first one now
+/// What a slice leaves unfilled is carried into the next one
+/// Returns an algo id (> 0), or negative error code if rejected upfront.
+/// Progress goes to POLYMARKET_EVENT_ALGO callbacks after every order and
+/// once more when the algo ends
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_twap(
+    token_id: *const c_char,
+    side: i32,
+    total: f64,
+    slices: u32,
+    interval_ms: u64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let side = match side_of(side) {
+            Some(side) => side,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+        };
+        start_algo(token_id, AlgoSpec::Twap {
+            side,
+            total,
+            slices,
+            interval: Duration::from_millis(interval_ms),
+        })
+    })
+}
+
+/// Start an iceberg: `total` shares at `price`, resting at most `show_size`
+/// at a time; the next clip is posted once the resting one has filled,
+/// checked every `poll_ms` (>= 500)
+/// Returns an algo id (> 0), or negative error code, see polymarket_start_twap()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_iceberg(
+    token_id: *const c_char,
+    side: i32,
+    price: f64,
+    total: f64,
+    show_size: f64,
+    poll_ms: u32,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let side = match side_of(side) {
+            Some(side) => side,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+        };
+        start_algo(token_id, AlgoSpec::Iceberg {
+            side,
+            price,
+            total,
+            show_size,
+            poll: Duration::from_millis(poll_ms as u64),
+        })
+    })
+}
+
+/// Stop a running algo; an iceberg's resting clip is cancelled on the venue
+/// Returns immediately; the final state arrives as a POLYMARKET_EVENT_ALGO
+/// event. POLYMARKET_ERR_INVALID_ARGUMENT if the id is unknown or done
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_algo(algo_id: u64) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let algos = executor.algos.lock().unwrap_or_else(|e| e.into_inner());
+        match algos.get(&algo_id) {
+            Some(control) => {
+                control.cancel();
+                info!("[ALGO] #{} cancel requested", algo_id);
+                POLYMARKET_OK
+            }
+            None => POLYMARKET_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Shortest quoting pass interval accepted (ms)
+#[cfg(feature = "execution")]
+const MIN_QUOTING_INTERVAL_MS: u32 = 50;
+
+/// Start maintaining the quotes set with polymarket_set_quote()
+/// Every `interval_ms` (>= 50) resting quotes are brought in line with their
+/// targets; a side is changed at most once per `min_replace_ms` (0 = 250).
+/// Each action is published as a POLYMARKET_EVENT_QUOTE event.
+/// Restarts the loop if it is running
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_quoting(interval_ms: u32, min_replace_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_QUOTING_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        if executor.with_trader(|t| t.is_read_only()) {
+            return POLYMARKET_ERR_READ_ONLY;
+        }
+        if let Err(e) = stop_quoting_task(&executor) {
+            error!("[QUOTE] cannot restart | {}", e);
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let mut settings = QuoteSettings::default();
+        if min_replace_ms > 0 {
+            settings.min_replace_interval = Duration::from_millis(min_replace_ms as u64);
+        }
+        let interval = Duration::from_millis(interval_ms as u64);
+        let stop = Arc::new(AtomicBool::new(false));
+        let task = executor.spawn(quoting_task(
+            executor.clone(),
+            settings,
+            interval,
+            stop.clone(),
+        ));
+        *executor.quoting.lock().unwrap_or_else(|e| e.into_inner()) = Some((stop, task));
+        info!("[QUOTE] quoting every {}ms | {:?}", interval_ms, settings);
+        POLYMARKET_OK
+    })
+}
+
+/// Set the quote to maintain for a token; a size <= 0 pulls that side
+/// Sizes are in shares, prices in (0, 1). Applied by the next quoting pass
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_quote(
+    token_id: *const c_char,
+    bid_price: f64,
+    bid_size: f64,
+    ask_price: f64,
+    ask_size: f64,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        let level = |price: f64, size: f64| -> Result<Option<QuoteLevel>, i32> {
+            if size.is_nan() || size <= 0.0 {
+                return Ok(None);
+            }
+            if price.is_nan() || price <= 0.0 || price >= 1.0 || !size.is_finite() {
+                return Err(POLYMARKET_ERR_INVALID_ARGUMENT);
+            }
+            Ok(Some(QuoteLevel { price, size }))
+        };
+        let target = match (level(bid_price, bid_size), level(ask_price, ask_size)) {
+            (Ok(bid), Ok(ask)) => QuoteTarget { bid, ask },
+            _ => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+        if let (Some(bid), Some(ask)) = (target.bid, target.ask) {
+            if bid.price >= ask.price {
+                return POLYMARKET_ERR_INVALID_ARGUMENT;
+            }
+        }
+
+        executor.quoter.set_target(&token_str, target);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop quoting and cancel every resting quote; targets are dropped
+/// Blocks until the cancels are done. POLYMARKET_ERR_CANCEL_FAILED if some
+/// quotes could not be pulled (see POLYMARKET_EVENT_QUOTE events)
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_quoting() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        match pull_quotes(&executor) {
+            Ok(0) => {
+                info!("[QUOTE] quoting stopped, quotes pulled");
+                POLYMARKET_OK
+            }
+            Ok(left) => {
+                error!("[QUOTE] quoting stopped, {} quotes still resting", left);
+                POLYMARKET_ERR_CANCEL_FAILED
+            }
+            Err(e) => {
+                error!("[QUOTE] stop failed | {}", e);
+                POLYMARKET_ERR_CANCEL_FAILED
+            }
+        }
+    })
+}
+
+/// Basket outcome as returned by polymarket_place_basket()
+#[cfg(feature = "execution")]
+fn basket_json(outcome: &BasketOutcome) -> serde_json::Value {
+    let legs: Vec<_> = outcome
+        .legs
+        .iter()
+        .map(|leg| {
+            let token = &leg.leg.token_id;
+            let fill = leg.fill.clone().unwrap_or_default();
+            let code = |e: &Option<OrderError>| match e {
+                Some(e) => order_error_code("BASKET", token, e),
+                None => POLYMARKET_OK,
+            };
+            serde_json::json!({
+                "token_id": token,
+                "side": if leg.leg.side == Side::Buy { "buy" } else { "sell" },
+                "state": leg.state,
+                "order_id": fill.order_id,
+                "filled_qty_raw": fill.filled_qty_raw,
+                "avg_price_raw": fill.avg_price_raw,
+                "fee_raw": fill.fee_raw,
+                "error_code": code(&leg.error),
+                "unwound_qty_raw": leg.unwound_qty_raw,
+                "unwind_error_code": code(&leg.unwind_error),
+            })
+        })
+        .collect();
+    serde_json::json!({ "state": outcome.state, "legs": legs })
+}
+
+/// Buy or sell several outcome tokens together (e.g. candidates of one
+/// neg-risk event) as FAK orders, sent at once after a liquidity check
+/// POLYMARKET_BASKET_ALL_OR_NOTHING sends nothing unless every leg can fill
+/// within its limit and unwinds the fills if one still falls short;
+/// otherwise the legs that can fill are sent, and POLYMARKET_BASKET_UNWIND
+/// unwinds on any shortfall. Blocks until done, unwinds included.
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds {state, legs: [...]}; negative error code if rejected upfront
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_place_basket(
+    legs: *const PolymarketBasketLeg,
+    count: usize,
+    flags: u32,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+        if legs.is_null() || !(2..=MAX_BASKET_LEGS).contains(&count) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+        if flags & !(POLYMARKET_BASKET_ALL_OR_NOTHING | POLYMARKET_BASKET_UNWIND) != 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+
+        let mut basket = Vec::with_capacity(count);
+        for i in 0..count {
+            let leg = unsafe { &*legs.add(i) };
+            if leg.token_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN as i64;
+            }
+            let token_id = match unsafe { CStr::from_ptr(leg.token_id) }.to_str() {
+                Ok(s) => s.to_string(),
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN as i64,
+            };
+            let side = match side_of(leg.side) {
+                Some(side) => side,
+                None => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+            };
+            basket.push(BasketLeg {
+                token_id,
+                side,
+                shares: leg.shares,
+                limit_price: leg.limit_price,
+            });
+        }
+        let mode = if flags & POLYMARKET_BASKET_ALL_OR_NOTHING != 0 {
+            BasketMode::AllOrNothing
+        } else {
+            BasketMode::BestEffort { unwind_on_partial: flags & POLYMARKET_BASKET_UNWIND != 0 }
+        };
+
+        let result = executor.run(move |executor| async move {
+            executor.trader().place_basket(basket, mode).await.map_err(anyhow::Error::from)
+        });
+        match result {
+            Ok(outcome) => {
+                info!("[BASKET] {:?} | legs={} | {:?}", mode, count, outcome.state);
+                out_buf::set_last_response(basket_json(&outcome).to_string()) as i64
+            }
+            Err(e) => {
+                error!("[BASKET] rejected | legs={} | {}", count, e);
+                match e.downcast_ref() {
+                    Some(OrderError::InvalidAmount) => POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+                    Some(e) => order_error_code("BASKET", "", e) as i64,
+                    None => POLYMARKET_ERR_ORDER_FAILED as i64,
+                }
+            }
+        }
+    })
+}
+
+/// Tick sizes the venue uses
+const TICK_SIZES: [&str; 4] = ["0.1", "0.01", "0.001", "0.0001"];
+
+/// Apply a tick size change from the market channel (tick_size_change)
+/// Later limit orders off the new grid fail with POLYMARKET_ERR_TICK_SIZE;
+/// the change is published as a POLYMARKET_EVENT_MARKET_DATA event
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_update_tick_size(token_id: *const c_char, tick_size: f64) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        let tick = match TICK_SIZES
+            .iter()
+            .filter_map(|t| t.parse::<rust_decimal::Decimal>().ok())
+            .find(|t| (f64::try_from(*t).unwrap_or(0.0) - tick_size).abs() < 1e-9)
+        {
+            Some(t) => t,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+
+        apply_tick_size(&executor, &token_str, tick);
+        POLYMARKET_OK
+    })
+}
+
+/// Cache a token's new tick size and publish the change, if it is one
+fn apply_tick_size(executor: &Executor, token_id: &str, tick: rust_decimal::Decimal) {
+    let old = executor.with_trader(|trader| trader.set_tick_size(token_id, tick));
+    if old != Some(tick) {
+        announce_tick_size(token_id, old, tick);
+    }
+}
+
+/// Log a tick size change and publish it to POLYMARKET_EVENT_MARKET_DATA
+fn announce_tick_size(
+    token_id: &str,
+    old: Option<rust_decimal::Decimal>,
+    tick: rust_decimal::Decimal,
+) {
+    warn!(
+        "[TICK SIZE] changed | token={} | {} -> {}",
+        token_id,
+        old.map_or("unknown".to_string(), |t| t.to_string()),
+        tick
+    );
+    if events::wants(POLYMARKET_EVENT_MARKET_DATA) {
+        events::emit(events::Event::new(
+            POLYMARKET_EVENT_MARKET_DATA,
+            POLYMARKET_OK,
+            Some(token_id),
+            serde_json::json!({
+                "event": "tick_size_change",
+                "old_tick_size": old.map(|t| t.to_string()),
+                "new_tick_size": tick.to_string(),
+            }),
+        ));
+    }
+}
+
+/// Shortest metadata TTL accepted (seconds)
+const MIN_META_TTL_SECS: u32 = 10;
+
+/// Re-fetch the metadata of every prefetched token each `ttl` until
+/// aborted, announcing tick size changes like polymarket_update_tick_size()
+async fn meta_refresh_task(executor: Arc<Executor>, ttl: Duration) {
+    let mut ticker = tokio::time::interval(ttl);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick is immediate; the cache is fresh from prefetch
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        for (token_id, old, tick) in executor.trader().refresh_meta().await {
+            announce_tick_size(&token_id, old, tick);
+        }
+    }
+}
+
+/// Refresh the metadata of every prefetched token (tick size, fee rate,
+/// neg risk, min order size) each `ttl_secs` (>= 10) in the background;
+/// 0 stops refreshing. Restarts the refresher if it is running
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_meta_ttl_secs(ttl_secs: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if ttl_secs != 0 && ttl_secs < MIN_META_TTL_SECS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let task = (ttl_secs != 0).then(|| {
+            let ttl = Duration::from_secs(ttl_secs as u64);
+            executor.spawn(meta_refresh_task(executor.clone(), ttl))
+        });
+        let previous = std::mem::replace(
+            &mut *executor.meta_refresh.lock().unwrap_or_else(|e| e.into_inner()),
+            task,
+        );
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+        if ttl_secs == 0 {
+            info!("[META] refresh stopped");
+        } else {
+            info!("[META] refreshing every {}s", ttl_secs);
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Round a price onto a token's cached tick grid, e.g. before a limit order
+/// Buys round down and sells up, so the price never gets worse; limit orders
+/// themselves only absorb float noise and fail with POLYMARKET_ERR_TICK_SIZE
+/// further off the grid
+/// Returns 0 with the price in `out_price`, POLYMARKET_ERR_TICK_SIZE if the
+/// tick size is unknown (prefetch first), POLYMARKET_ERR_INVALID_ORDER if the
+/// price is not between 0 and 1
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_round_price(
+    token_id: *const c_char,
+    price: f64,
+    side: i32,
+    out_price: *mut f64,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        let side = match side_of(side) {
+            Some(side) => side,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+        if out_price.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let rounded = executor.with_trader(|trader| {
+            trader.tick_size(&token_str).map(|_| trader.round_price(&token_str, price, side))
+        });
+        match rounded {
+            Some(Some(rounded)) => {
+                unsafe { *out_price = rounded };
+                POLYMARKET_OK
+            }
+            Some(None) => POLYMARKET_ERR_INVALID_ORDER,
+            None => POLYMARKET_ERR_TICK_SIZE,
+        }
+    })
+}
+
+/// Metadata polymarket_prefetch() cached for a token
+/// Returns POLYMARKET_ERR_INVALID_ARGUMENT if the token was not prefetched
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_market_meta(
+    token_id: *const c_char,
+    out: *mut PolymarketMarketMeta,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let (executor, token_str) = match order_target(token_id) {
+            Ok(t) => t,
+            Err(code) => return code,
+        };
+        let meta = match executor.with_trader(|trader| trader.market_meta(&token_str)) {
+            Some(meta) => meta,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+
+        let value = PolymarketMarketMeta {
+            tick_size_raw: decimal_to_raw(meta.tick_size),
+            min_order_size_raw: decimal_to_raw(meta.min_order_size),
+            fee_rate_bps: meta.fee_rate_bps,
+            neg_risk: meta.neg_risk,
+            ..Default::default()
+        };
+        if !unsafe { abi::write_sized(&value, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Whether a resting order qualifies for liquidity rewards
+/// Returns 1 if scoring, 0 if not, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_is_order_scoring(order_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let mut scoring = 0u8;
+        match polymarket_orders_scoring(&order_id, 1, &mut scoring) {
+            POLYMARKET_OK => scoring as i32,
+            code => code,
+        }
+    })
+}
+
+/// Scoring status of `count` resting orders: out_scoring[i] is set to 1 if
+/// order_ids[i] qualifies for liquidity rewards, 0 if not
+/// Returns 0 on success, negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_orders_scoring(
+    order_ids: *const *const c_char,
+    count: usize,
+    out_scoring: *mut u8,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        if count == 0 {
+            return POLYMARKET_OK;
+        }
+        if out_scoring.is_null() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let ids = match id_list(order_ids, count) {
+            Ok(ids) => ids,
+            Err(code) => return code,
+        };
+
+        let queried = ids.clone();
+        let result = executor.run(move |executor| async move {
+            let ids: Vec<&str> = queried.iter().map(String::as_str).collect();
+            executor.trader().orders_scoring(&ids).await
+        });
+
+        match result {
+            Ok(scoring) => {
+                for (i, id) in ids.iter().enumerate() {
+                    let value = scoring.get(id).copied().unwrap_or(false);
+                    unsafe { *out_scoring.add(i) = value as u8 };
+                }
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[SCORING ERROR] orders={} | error={}", count, e);
+                POLYMARKET_ERR_ORDER_FAILED
+            }
+        }
+    })
+}
+
+/// Fetch liquidity rewards earned per market and epoch (UTC day), dates
+/// YYYY-MM-DD inclusive, at most 31 days
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds a JSON array of {date, condition_id, asset_address, earnings_raw,
+/// asset_rate}; negative error code on failure
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_rewards_earnings(
+    from_date: *const c_char,
+    to_date: *const c_char,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let parse_date = |date: *const c_char| {
+            if date.is_null() {
+                return None;
+            }
+            let date = unsafe { CStr::from_ptr(date) }.to_str().ok()?;
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+        };
+        let (from, to) = match (parse_date(from_date), parse_date(to_date)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+        };
+        if to < from
+            || (to - from).num_days() >= polymarket_executor_core::rewards::MAX_EARNINGS_DAYS
+        {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+
+        let result = executor.run(move |executor| async move {
+            executor.trader().rewards_earnings(from, to).await
+        });
+
+        match result.and_then(|earnings| Ok(serde_json::to_string(&earnings)?)) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(e) => {
+                error!("[REWARDS ERROR] from={} to={} | error={}", from, to, e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+// Market identifier kinds (polymarket_lookup_market, polymarket_convert_id)
+/// Condition ID (0x-prefixed 32-byte hex)
+pub const POLYMARKET_ID_CONDITION: i32 = 0;
+/// Question ID (0x-prefixed 32-byte hex)
+pub const POLYMARKET_ID_QUESTION: i32 = 1;
+/// Market slug
+pub const POLYMARKET_ID_SLUG: i32 = 2;
+/// YES token ID; as input, either token
+pub const POLYMARKET_ID_YES_TOKEN: i32 = 3;
+/// NO token ID; as input, either token
+pub const POLYMARKET_ID_NO_TOKEN: i32 = 4;
+
+/// Resolve a market identifier of `kind` through Gamma (cached per session)
+fn lookup_market(id: *const c_char, kind: i32) -> Result<Arc<MarketTokens>, i32> {
+    let executor = get_executor().ok_or(POLYMARKET_ERR_NOT_INITIALIZED)?;
+    if id.is_null() {
+        return Err(POLYMARKET_ERR_INVALID_TOKEN);
+    }
+    let id = unsafe { CStr::from_ptr(id) }
+        .to_str()
+        .map_err(|_| POLYMARKET_ERR_INVALID_TOKEN)?
+        .to_string();
+    if !(POLYMARKET_ID_CONDITION..=POLYMARKET_ID_NO_TOKEN).contains(&kind) {
+        return Err(POLYMARKET_ERR_INVALID_ARGUMENT);
+    }
+
+    let queried = id.clone();
+    let result = executor.run(move |executor| async move {
+        let key = match kind {
+            POLYMARKET_ID_CONDITION => MarketKey::Condition(&queried),
+            POLYMARKET_ID_QUESTION => MarketKey::Question(&queried),
+            POLYMARKET_ID_SLUG => MarketKey::Slug(&queried),
+            _ => MarketKey::Token(&queried),
+        };
+        executor.trader().lookup_market(key).await.map_err(anyhow::Error::from)
+    });
+    result.map_err(|e| match e.downcast_ref() {
+        Some(OrderError::InvalidToken) => POLYMARKET_ERR_INVALID_TOKEN,
+        _ => {
+            error!("[MARKET LOOKUP ERROR] id={} | error={}", id, e);
+            POLYMARKET_ERR_ORDER_FAILED
+        }
+    })
+}
+
+/// A market as polymarket_lookup_market() and polymarket_list_markets()
+/// report it
+fn market_json(market: &MarketTokens) -> serde_json::Value {
+    serde_json::json!({
+        "condition_id": &market.condition_id,
+        "question_id": &market.question_id,
+        "slug": &market.slug,
+        "yes_token_id": &market.yes_token_id,
+        "no_token_id": &market.no_token_id,
+        "neg_risk": market.neg_risk,
+        "question": &market.question,
+        "outcomes": &market.outcomes,
+        "end_date": &market.end_date,
+        "end_date_ms": market.end_date_ms().unwrap_or(0),
+    })
+}
+
+/// Look up a binary market by any identifier: condition id, question id,
+/// slug or either token id (`kind` = POLYMARKET_ID_*)
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds {condition_id, question_id, slug, yes_token_id, no_token_id,
+/// neg_risk, question, outcomes, end_date, end_date_ms};
+/// POLYMARKET_ERR_INVALID_TOKEN if malformed or unknown
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_lookup_market(id: *const c_char, kind: i32) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let market = match lookup_market(id, kind) {
+            Ok(market) => market,
+            Err(code) => return code as i64,
+        };
+        out_buf::set_last_response(market_json(&market).to_string()) as i64
+    })
+}
+
+/// List the binary markets open for trading, `limit` (1..=500) from
+/// `offset` in Gamma's order; page until an empty list
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds an array of what polymarket_lookup_market() returns
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_list_markets(limit: u32, offset: u32) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+        if !(1..=gamma::MAX_LIST_LIMIT).contains(&limit) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+
+        let result = executor.run(move |executor| async move {
+            let trader = executor.trader();
+            trader.list_markets(limit, offset).await.map_err(anyhow::Error::from)
+        });
+        match result {
+            Ok(markets) => {
+                let json: Vec<_> = markets.iter().map(|m| market_json(m)).collect();
+                out_buf::set_last_response(serde_json::Value::from(json).to_string()) as i64
+            }
+            Err(e) => {
+                error!("[MARKET LIST ERROR] offset={} | error={}", offset, e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Venue responses that no longer match the expected shape, per source
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds [{source, count, last_error, last_sample, last_at_ms}]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_schema_drift() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let report = executor.trader().schema_drift();
+        match serde_json::to_string(&report) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(e) => {
+                error!("[SCHEMA DRIFT ERROR] {}", e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Convert a market identifier of kind `from` into kind `to`
+/// (POLYMARKET_ID_*), e.g. a slug into its YES token id
+/// Returns the required buffer size (including NUL), the id is written only
+/// if `len` is large enough; negative error code on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_convert_id(
+    id: *const c_char,
+    from: i32,
+    to: i32,
+    buf: *mut c_char,
+    len: usize,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        if !(POLYMARKET_ID_CONDITION..=POLYMARKET_ID_NO_TOKEN).contains(&to) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT as i64;
+        }
+        let market = match lookup_market(id, from) {
+            Ok(market) => market,
+            Err(code) => return code as i64,
+        };
+        let converted = match to {
+            POLYMARKET_ID_CONDITION => &market.condition_id,
+            POLYMARKET_ID_QUESTION => &market.question_id,
+            POLYMARKET_ID_SLUG => &market.slug,
+            POLYMARKET_ID_YES_TOKEN => &market.yes_token_id,
+            _ => &market.no_token_id,
+        };
+        unsafe { out_buf::write_str(converted, buf, len) as i64 }
+    })
+}
+
+/// Shortest notifications poll interval accepted (ms)
+#[cfg(feature = "execution")]
+const MIN_NOTIFICATIONS_INTERVAL_MS: u32 = 1_000;
+
+/// Start polling account notifications every `interval_ms` (>= 1000)
+/// Each new one is logged and published as a POLYMARKET_EVENT_NOTIFICATION
+/// event; the ones already held by the venue are published on the first poll.
+/// Restarts the poller if it is running; it stops on shutdown
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_notifications(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_NOTIFICATIONS_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(notifications_task(executor.clone(), interval));
+        let mut slot = executor.notifications.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!("[NOTIFICATION] polling every {}ms", interval_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop polling account notifications; no-op if not started
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_notifications() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.notifications.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[NOTIFICATION] polling stopped");
+        }
+        POLYMARKET_OK
+    })
+}
+
+// Auto-merge modes
+/// Stop watching the market
+pub const POLYMARKET_MERGE_OFF: i32 = 0;
+/// Report mergeable pairs only
+pub const POLYMARKET_MERGE_DETECT: i32 = 1;
+/// Merge pairs on-chain into USDC
+pub const POLYMARKET_MERGE_AUTO: i32 = 2;
+
+/// Shortest auto-merge poll interval accepted (ms)
+#[cfg(feature = "onchain")]
+const MIN_MERGE_INTERVAL_MS: u32 = 10_000;
+
+/// Watch a market (condition id, 0x hex) for offsetting YES and NO positions
+/// DETECT publishes the mergeable amount when it changes; AUTO merges pairs
+/// on-chain into USDC; OFF stops watching. Pairs below `min_shares` are ignored
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_auto_merge(
+    condition_id: *const c_char,
+    mode: i32,
+    min_shares: f64,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let condition_id = unsafe {
+            if condition_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN;
+            }
+            match CStr::from_ptr(condition_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+        let mode = match mode {
+            POLYMARKET_MERGE_OFF => MergeMode::Off,
+            POLYMARKET_MERGE_DETECT => MergeMode::Detect,
+            POLYMARKET_MERGE_AUTO => MergeMode::Auto,
+            _ => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+        if mode == MergeMode::Auto && executor.with_trader(|t| t.is_read_only()) {
+            return POLYMARKET_ERR_READ_ONLY;
+        }
+
+        match executor.merger.set_rule(condition_id, MergeRule { mode, min_shares }) {
+            Ok(()) => {
+                info!("[MERGE] market={} | mode={:?} | min_shares={}", condition_id, mode,
+                    min_shares);
+                POLYMARKET_OK
+            }
+            Err(OrderError::InvalidToken) => POLYMARKET_ERR_INVALID_TOKEN,
+            Err(_) => POLYMARKET_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Start checking the markets set with polymarket_set_auto_merge() every
+/// `interval_ms` (>= 10000); findings and merges are published as
+/// POLYMARKET_EVENT_MERGE events
+/// Restarts the poller if it is running; it stops on shutdown
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_auto_merge(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_MERGE_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(merging_task(executor.clone(), interval));
+        let mut slot = executor.merging.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!("[MERGE] checking {} markets every {}ms", executor.merger.watched(), interval_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop the auto-merge poller; no-op if not started
+/// A merge transaction already sent still completes on-chain
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_auto_merge() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.merging.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[MERGE] auto-merge stopped");
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Run the on-chain call `op` on the current session and wait until it is mined
+/// Leaves {"tx_hash"} (null if nothing was sent) for polymarket_last_response()
+/// and returns its size, or a negative error code (message in
+/// polymarket_last_error_message())
+#[cfg(feature = "onchain")]
+fn chain_call<F, Fut, T>(op: &'static str, subject: &str, call: F) -> i64
+where
+    F: FnOnce(Arc<Trader>) -> Fut,
+    Fut: Future<Output = Result<T, OrderError>> + Send + 'static,
+    T: Into<Option<String>> + Send + 'static,
+{
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+    };
+    let result = executor.run(move |executor| {
+        let fut = call(executor.trader());
+        async move { Ok(fut.await) }
+    });
+
+    match result {
+        Ok(Ok(tx_hash)) => {
+            let tx_hash: Option<String> = tx_hash.into();
+            info!("[CHAIN] {} | {} | tx={}", op, subject, tx_hash.as_deref().unwrap_or("none"));
+            out_buf::set_last_error("");
+            out_buf::set_last_response(serde_json::json!({ "tx_hash": tx_hash }).to_string())
+                as i64
+        }
+        Ok(Err(e)) => {
+            error!("[CHAIN ERROR] {} | {} | error={}", op, subject, e);
+            out_buf::set_last_error(&order_error_message(&e));
+            order_error_code(op, subject, &e) as i64
+        }
+        Err(e) => {
+            error!("[CHAIN ERROR] {} | {} | error={}", op, subject, e);
+            out_buf::set_last_error(&e.to_string());
+            POLYMARKET_ERR_ORDER_FAILED as i64
+        }
+    }
+}
+
+/// Condition id argument of an on-chain call
+#[cfg(feature = "onchain")]
+fn condition_arg(condition_id: *const c_char) -> Result<String, i32> {
+    if condition_id.is_null() {
+        return Err(POLYMARKET_ERR_INVALID_TOKEN);
+    }
+    let id = unsafe { CStr::from_ptr(condition_id) }.to_str();
+    id.map(str::to_string).map_err(|_| POLYMARKET_ERR_INVALID_TOKEN)
+}
+
+/// Split `amount_raw` USDC (6 decimals) into as many YES and NO shares of a
+/// market (condition id, 0x hex); `neg_risk` markets go through the neg-risk
+/// adapter. Blocks until mined (at most 120 s)
+/// Returns the size of {"tx_hash"} (polymarket_last_response()), or a negative
+/// error code
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_split_position(
+    condition_id: *const c_char,
+    neg_risk: bool,
+    amount_raw: i64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let condition_id = match condition_arg(condition_id) {
+            Ok(id) => id,
+            Err(code) => return code as i64,
+        };
+        let id = condition_id.clone();
+        chain_call("SPLIT", &condition_id, move |trader| async move {
+            trader.split_position(&id, neg_risk, amount_raw).await
+        })
+    })
+}
+
+/// Merge `amount_raw` (6 decimals) YES and NO shares of a market back into as
+/// much USDC; the wallet must hold both. Blocks until mined (at most 120 s)
+/// Returns the size of {"tx_hash"} (polymarket_last_response()), or a negative
+/// error code
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_merge_positions(
+    condition_id: *const c_char,
+    neg_risk: bool,
+    amount_raw: i64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let condition_id = match condition_arg(condition_id) {
+            Ok(id) => id,
+            Err(code) => return code as i64,
+        };
+        let id = condition_id.clone();
+        chain_call("MERGE", &condition_id, move |trader| async move {
+            trader.merge_positions(&id, neg_risk, amount_raw).await
+        })
+    })
+}
+
+/// Convert `amount_raw` (6 decimals) NO shares of each of `count` questions
+/// (question ids, 0x hex) of one neg-risk event into as many YES shares of
+/// every other question of the event, plus USDC for all but one of the NO
+/// shares. Blocks until mined (at most 120 s)
+/// Returns the size of {"tx_hash"} (polymarket_last_response()), or a negative
+/// error code
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_convert_positions(
+    question_ids: *const *const c_char,
+    count: usize,
+    amount_raw: i64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let ids = match id_list(question_ids, count) {
+            Ok(ids) if !ids.is_empty() => ids,
+            Ok(_) => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+            Err(code) => return code as i64,
+        };
+        let subject = ids.join(",");
+        chain_call("CONVERT", &subject, move |trader| async move {
+            let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+            trader.convert_positions(&ids, amount_raw).await
+        })
+    })
+}
+
+/// Redeem the wallet's shares of a resolved market (condition id, 0x hex) for
+/// the USDC they pay out, then resync its positions and the cached balance
+/// Blocks until mined (at most 120 s). Returns the size of {"tx_hash"}
+/// (polymarket_last_response()), or a negative error code
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_redeem(condition_id: *const c_char) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let condition_id = match condition_arg(condition_id) {
+            Ok(id) => id,
+            Err(code) => return code as i64,
+        };
+        let id = condition_id.clone();
+        chain_call("REDEEM", &condition_id, move |trader| async move {
+            trader.redeem_positions(&id).await
+        })
+    })
+}
+
+/// Approvals the funder wallet gave the exchanges, the neg-risk adapter and
+/// the Conditional Tokens contract, read on Polygon; allowed in read-only mode
+/// Leaves {"wallet", "spenders": [{"spender", "address", "usdc", "tokens"}]}
+/// for polymarket_last_response() and returns its size, or
+/// POLYMARKET_ERR_USDC_ALLOWANCE / POLYMARKET_ERR_TOKEN_APPROVAL if one is
+/// missing (the JSON still tells which), or another negative error code
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_check_allowances() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().check_allowances().await)
+        });
+
+        match result {
+            Ok(Ok(allowances)) => {
+                out_buf::set_last_error("");
+                let size = out_buf::set_last_response(
+                    serde_json::to_string(&allowances).unwrap_or_default(),
+                ) as i64;
+                if allowances.usdc_missing() {
+                    warn!("[ALLOWANCE] USDC.e allowance missing | wallet={}", allowances.wallet);
+                    POLYMARKET_ERR_USDC_ALLOWANCE as i64
+                } else if allowances.tokens_missing() {
+                    warn!("[ALLOWANCE] token approval missing | wallet={}", allowances.wallet);
+                    POLYMARKET_ERR_TOKEN_APPROVAL as i64
+                } else {
+                    size
+                }
+            }
+            Ok(Err(e)) => {
+                error!("[ALLOWANCE ERROR] error={}", e);
+                out_buf::set_last_error(&order_error_message(&e));
+                order_error_code("ALLOWANCES", "", &e) as i64
+            }
+            Err(e) => {
+                error!("[ALLOWANCE ERROR] error={}", e);
+                out_buf::set_last_error(&e.to_string());
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Set every approval polymarket_check_allowances() finds missing, unlimited,
+/// in one transaction sent through the proxy wallet; blocks until mined (at
+/// most 120 s). Returns the size of {"tx_hash"} (null if nothing was missing)
+/// for polymarket_last_response(), or a negative error code
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_approve_allowances() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        chain_call("APPROVE", "allowances", |trader| async move {
+            trader.approve_allowances().await
+        })
+    })
+}
+
+/// POL balance of the signing key, which pays the gas of on-chain calls
+/// Returns raw POL (6 decimals), or a negative error code; logs a warning if
+/// it is below the polymarket_set_low_gas_threshold() threshold
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_gas_balance() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+        let result = executor.run(move |executor| async move {
+            Ok(executor.trader().gas_balance().await)
+        });
+
+        match result {
+            Ok(Ok(raw)) => raw,
+            Ok(Err(e)) => {
+                error!("[GAS ERROR] error={}", e);
+                out_buf::set_last_error(&order_error_message(&e));
+                order_error_code("GAS BALANCE", "", &e) as i64
+            }
+            Err(e) => {
+                error!("[GAS ERROR] error={}", e);
+                out_buf::set_last_error(&e.to_string());
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Warn (a WARN log line, once until the balance recovers) when the signing
+/// key's POL balance is below `threshold_pol`; 0 turns warnings off
+/// Checked after every on-chain transaction and polymarket_get_gas_balance()
+#[cfg(feature = "onchain")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_low_gas_threshold(threshold_pol: f64) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if !threshold_pol.is_finite() {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let threshold_raw = (threshold_pol * DECIMAL_SCALE as f64) as i64;
+        match executor.with_trader(|trader| trader.set_low_gas_threshold(threshold_raw)) {
+            Ok(()) => {
+                info!("[GAS] low balance threshold={} POL", threshold_pol);
+                POLYMARKET_OK
+            }
+            Err(_) => POLYMARKET_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Shortest mispricing scan interval accepted (ms)
+const MIN_ARB_SCAN_INTERVAL_MS: u32 = 500;
+
+/// Watch a market (condition id, 0x hex) for YES+NO prices summing away
+/// from 1 by at least `min_edge` USDC per pair, fees counted
+/// Watching it again replaces `min_edge`
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_watch_arbitrage(condition_id: *const c_char, min_edge: f64) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let condition_id = unsafe {
+            if condition_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN;
+            }
+            match CStr::from_ptr(condition_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        match executor.arb_scanner.watch(condition_id, min_edge) {
+            Ok(()) => POLYMARKET_OK,
+            Err(OrderError::InvalidToken) => POLYMARKET_ERR_INVALID_TOKEN,
+            Err(_) => POLYMARKET_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Stop watching a market for mispricing
+/// POLYMARKET_ERR_INVALID_ARGUMENT if it was not watched
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_unwatch_arbitrage(condition_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let condition_id = unsafe {
+            if condition_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN;
+            }
+            match CStr::from_ptr(condition_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        if executor.arb_scanner.unwatch(condition_id) {
+            POLYMARKET_OK
+        } else {
+            POLYMARKET_ERR_INVALID_ARGUMENT
+        }
+    })
+}
+
+/// Scan the markets set with polymarket_watch_arbitrage() every
+/// `interval_ms` (>= 500); opportunities that open, change or close are
+/// published as POLYMARKET_EVENT_ARBITRAGE events
+/// Restarts the scanner if it is running; it stops on shutdown
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_arbitrage_scan(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_ARB_SCAN_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(arb_scan_task(executor.clone(), interval));
+        let mut slot = executor.arb_scan.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!("[ARB] scanning {} markets every {}ms", executor.arb_scanner.watched(), interval_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop the mispricing scanner; no-op if not started
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_arbitrage_scan() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.arb_scan.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[ARB] scanning stopped");
+        }
+        POLYMARKET_OK
+    })
+}
+
+// Resolution statuses (code of POLYMARKET_EVENT_RESOLUTION)
+/// No outcome proposed, or a disputed proposal not yet replaced
+pub const POLYMARKET_RESOLUTION_OPEN: i32 = 0;
+/// Outcome proposed to the oracle, final unless disputed
+pub const POLYMARKET_RESOLUTION_PROPOSED: i32 = 1;
+/// Proposal disputed, outcome pending the oracle's vote
+pub const POLYMARKET_RESOLUTION_DISPUTED: i32 = 2;
+/// Outcome final: winning shares can be redeemed
+pub const POLYMARKET_RESOLUTION_RESOLVED: i32 = 3;
+
+/// Shortest resolution poll interval accepted (ms)
+const MIN_RESOLUTION_WATCH_INTERVAL_MS: u32 = 1_000;
+
+/// Watch a market (condition id, 0x hex) for changes of its resolution
+/// status; no-op if already watched
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_watch_resolution(condition_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let condition_id = unsafe {
+            if condition_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN;
+            }
+            match CStr::from_ptr(condition_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        match executor.resolutions.watch(condition_id) {
+            Ok(()) => POLYMARKET_OK,
+            Err(_) => POLYMARKET_ERR_INVALID_TOKEN,
+        }
+    })
+}
+
+/// Stop watching a market for resolution
+/// POLYMARKET_ERR_INVALID_ARGUMENT if it was not watched
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_unwatch_resolution(condition_id: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let condition_id = unsafe {
+            if condition_id.is_null() {
+                return POLYMARKET_ERR_INVALID_TOKEN;
+            }
+            match CStr::from_ptr(condition_id).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_TOKEN,
+            }
+        };
+
+        if executor.resolutions.unwatch(condition_id) {
+            POLYMARKET_OK
+        } else {
+            POLYMARKET_ERR_INVALID_ARGUMENT
+        }
+    })
+}
+
+/// Poll the markets set with polymarket_watch_resolution() every
+/// `interval_ms` (>= 1000); each status change is published as a
+/// POLYMARKET_EVENT_RESOLUTION event
+/// Restarts the watcher if it is running; it stops on shutdown
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_resolution_watch(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_RESOLUTION_WATCH_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(resolution_watch_task(executor.clone(), interval));
+        let mut slot = executor.resolution_watch.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!(
+            "[RESOLUTION] watching {} markets every {}ms",
+            executor.resolutions.watched(),
+            interval_ms
+        );
+        POLYMARKET_OK
+    })
+}
+
+/// Stop the resolution watcher; no-op if not started
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_resolution_watch() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.resolution_watch.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[RESOLUTION] watching stopped");
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Shortest fill poll interval accepted (ms)
+#[cfg(feature = "execution")]
+const MIN_FILL_POLL_INTERVAL_MS: u32 = 500;
+
+/// Follow every order placed from now on until it completes: fills go to
+/// POLYMARKET_EVENT_FILL callbacks with the order's running totals, and a
+/// final summary to POLYMARKET_EVENT_ORDER_SUMMARY callbacks
+/// Resting orders are polled every `interval_ms` (>= 500) for new fills;
+/// restarts the poller if it is running, keeping the orders followed
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_fill_tracking(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_FILL_POLL_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(fill_tracking_task(executor.clone(), interval));
+        let mut slot = executor.fill_tracking.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!("[FILLS] tracking, polling every {}ms", interval_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop fill tracking and forget the orders followed, without summaries;
+/// no-op if not started
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_fill_tracking() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.fill_tracking.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[FILLS] tracking stopped, {} orders dropped", executor.fills.tracked());
+        }
+        executor.fills.clear();
+        POLYMARKET_OK
+    })
+}
+
+/// Shortest gateway probe interval accepted (ms)
+#[cfg(feature = "execution")]
+const MIN_ENDPOINT_PROBE_INTERVAL_MS: u32 = 1_000;
+
+/// Time a request to `clob_host` and every `clob_gateways` entry each
+/// `interval_ms` (>= 1000) and send orders and cancels to the fastest healthy
+/// one; a gateway also fails over at once when an order call to it times out
+/// or can't connect. Restarts the prober if it is running
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_endpoint_probing(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_ENDPOINT_PROBE_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(endpoint_probing_task(executor.clone(), interval));
+        let mut slot = executor.endpoint_probing.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!("[ENDPOINTS] probing every {}ms", interval_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop probing; order traffic stays on the current gateway, failing over
+/// only on transport errors. No-op if not started
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_endpoint_probing() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.endpoint_probing.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[ENDPOINTS] probing stopped");
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// Shortest keep-alive interval accepted (ms)
+const MIN_KEEPALIVE_INTERVAL_MS: u32 = 1_000;
+
+/// Ping `clob_host` and every `clob_gateways` entry each `interval_ms`
+/// (>= 1000) so idle pooled connections stay open and the next order after
+/// a quiet spell pays no TLS handshake. Keep it under the pool's idle
+/// timeout (90s). Restarts the pinger if it is running
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_start_keepalive(interval_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if interval_ms < MIN_KEEPALIVE_INTERVAL_MS {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        let interval = Duration::from_millis(interval_ms as u64);
+        let task = executor.spawn(keep_alive_task(executor.clone(), interval));
+        let mut slot = executor.keep_alive.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = slot.replace(task) {
+            previous.abort();
+        }
+        info!("[KEEPALIVE] pinging every {}ms", interval_ms);
+        POLYMARKET_OK
+    })
+}
+
+/// Stop the keep-alive pinger. No-op if not started
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_stop_keepalive() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let task = executor.keep_alive.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(task) = task {
+            task.abort();
+            info!("[KEEPALIVE] stopped");
+        }
+        POLYMARKET_OK
+    })
+}
+
+/// State of every CLOB gateway, `clob_host` first
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds [{host, active, healthy, rtt_ms, last_rtt_ms, probes, probe_failures,
+/// orders, order_failures, activations}]
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_endpoint_metrics() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let metrics = executor.trader().endpoint_metrics();
+        match serde_json::to_string(&metrics) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(e) => {
+                error!("[ENDPOINTS ERROR] {}", e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Latency and error counts of order posts, batch posts, cancels and
+/// balance reads since the session started, copied into `buf` as JSON:
+/// [{op, count, errors, mean_ms, p50_ms, p90_ms, p99_ms, max_ms}]
+/// Returns the required buffer size (including NUL), negative error code on
+/// failure; written only if `len` is large enough, pass NULL/0 to query
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_metrics(buf: *mut c_char, len: usize) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        match serde_json::to_string(&executor.trader().metrics()) {
+            Ok(json) => unsafe { out_buf::write_str(&json, buf, len) as i64 },
+            Err(e) => {
+                error!("[METRICS ERROR] {}", e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// The counts of polymarket_get_metrics() in the Prometheus text format,
+/// to serve from a scrape endpoint of the host. Same buffer convention
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_metrics_prometheus(buf: *mut c_char, len: usize) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let text = executor.trader().metrics_prometheus();
+        unsafe { out_buf::write_str(&text, buf, len) as i64 }
+    })
+}
+
+/// Venue circuit breaker state
+/// Returns the buffer size needed for polymarket_last_response(), which
+/// holds {open, reason, opened_at_ms, trips, probes, window_failures,
+/// window_calls}
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_venue_status() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let state = executor.trader().venue_breaker().state();
+        match serde_json::to_string(&state) {
+            Ok(json) => out_buf::set_last_response(json) as i64,
+            Err(e) => {
+                error!("[BREAKER ERROR] {}", e);
+                POLYMARKET_ERR_ORDER_FAILED as i64
+            }
+        }
+    })
+}
+
+/// Load risk limits from a JSON file, replacing the active ones
+/// Can be called at any time to hot-reload; on validation failure the
+/// previous limits stay active and POLYMARKET_ERR_INVALID_CONFIG is returned
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_load_risk_config(path: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let path_str = unsafe {
+            if path.is_null() {
+                return POLYMARKET_ERR_INVALID_CONFIG;
+            }
+            match CStr::from_ptr(path).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            }
+        };
+
+        match executor.with_trader(|trader| trader.risk().load_file(path_str)) {
+            Ok(update) => {
+                emit_risk_config_event(&update);
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[RISK CONFIG ERROR] path={} | {}", path_str, e);
+                POLYMARKET_ERR_INVALID_CONFIG
+            }
+        }
+    })
+}
+
+/// Replace risk limits from a JSON string (same schema as the config file)
+/// Omitted fields fall back to defaults (no limit)
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_risk_config(json: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let json_str = unsafe {
+            if json.is_null() {
+                return POLYMARKET_ERR_INVALID_CONFIG;
+            }
+            match CStr::from_ptr(json).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            }
+        };
+
+        match executor.with_trader(|trader| trader.risk().update_json(json_str, "ffi")) {
+            Ok(update) => {
+                emit_risk_config_event(&update);
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[RISK CONFIG ERROR] {}", e);
+                POLYMARKET_ERR_INVALID_CONFIG
+            }
+        }
+    })
+}
+
+/// Set the order notional (USDC), position (shares per token) and open
+/// orders caps, 0 = no limit; the other risk limits stay as they are
+/// POLYMARKET_ERR_INVALID_CONFIG for negative or non-finite caps
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_risk_limits(
+    max_order_usdc: f64,
+    max_position_shares: f64,
+    max_open_orders: u32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let update = executor.with_trader(|trader| {
+            trader
+                .risk()
+                .set_caps(max_order_usdc, max_position_shares, max_open_orders, "ffi")
+        });
+        match update {
+            Ok(update) => {
+                emit_risk_config_event(&update);
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[RISK CONFIG ERROR] {}", e);
+                POLYMARKET_ERR_INVALID_CONFIG
+            }
+        }
+    })
+}
+
+/// Kill switch: refuse every new order (POLYMARKET_ERR_HALTED) until
+/// polymarket_resume_trading() and stop quoting; with `cancel_open`, also
+/// pull the quotes and cancel every open order
+/// Returns POLYMARKET_ERR_CANCEL_FAILED if some order could not be cancelled;
+/// trading stays halted either way
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_halt_trading(cancel_open: bool) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        executor.with_trader(|trader| trader.halt());
+        warn!("[HALT] trading halted | cancel_open={}", cancel_open);
+        emit_halt_event(true);
+
+        if !cancel_open {
+            if let Err(e) = stop_quoting_task(&executor) {
+                error!("[HALT] stopping quoting failed | {}", e);
+            }
+            return POLYMARKET_OK;
+        }
+        if executor.with_trader(|trader| trader.is_read_only()) {
+            return POLYMARKET_OK;
+        }
+        let mut code = match pull_quotes(&executor) {
+            Ok(0) => POLYMARKET_OK,
+            Ok(left) => {
+                error!("[HALT] {} quotes still resting", left);
+                POLYMARKET_ERR_CANCEL_FAILED
+            }
+            Err(e) => {
+                error!("[HALT] pulling quotes failed | {}", e);
+                POLYMARKET_ERR_CANCEL_FAILED
+            }
+        };
+        let cancelled = polymarket_cancel_all_ex(std::ptr::null_mut(), 0, std::ptr::null_mut());
+        if cancelled != POLYMARKET_OK {
+            code = POLYMARKET_ERR_CANCEL_FAILED;
+        }
+        code
+    })
+}
+
+/// Lift the kill switch; quoting stopped by the halt is not restarted
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_resume_trading() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        executor.with_trader(|trader| trader.resume());
+        info!("[HALT] trading resumed");
+        emit_halt_event(false);
+        POLYMARKET_OK
+    })
+}
+
+/// 1 while trading is halted, 0 otherwise; -1 if not initialized
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_is_trading_halted() -> i32 {
+    guard::catch(-1, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return -1,
+        };
+
+        executor.with_trader(|trader| trader.is_halted()) as i32
+    })
+}
+
+/// Get the version of the active risk limits (0 = defaults, +1 per accepted update)
+/// Returns version, or -1 if not initialized
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_get_risk_config_version() -> i64 {
+    guard::catch(-1, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return -1,
+        };
+
+        executor.trader().risk().version() as i64
+    })
+}
+
+/// Record every signed order to an append-only, hash-chained file before it
+/// is posted; an existing file is continued. NULL stops recording
+/// If a line cannot be written the order is not sent (ORDER_FAILED)
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_signed_log(path: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        if path.is_null() {
+            executor.with_trader(|trader| trader.set_signed_log(None));
+            info!("[SIGNED LOG] disabled");
+            return POLYMARKET_OK;
+        }
+        let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
+            Ok(s) => s,
+            Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+        };
+
+        match SignedLog::open(Path::new(path_str)) {
+            Ok(log) => {
+                executor.with_trader(|trader| trader.set_signed_log(Some(Arc::new(log))));
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[SIGNED LOG ERROR] path={} | {}", path_str, e);
+                POLYMARKET_ERR_INVALID_CONFIG
+            }
+        }
+    })
+}
+
+/// Journal every order, batch, replace and cancel request and its outcome
+/// (response or error) to an append-only JSONL file; an existing file is
+/// continued. Before init, the path is kept for the next polymarket_init*()
+/// call, which fails with POLYMARKET_ERR_INVALID_CONFIG if it cannot open
+/// it. NULL stops journaling (or clears the staged path)
+/// If a request line cannot be written the request is not sent
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_journal(path: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let path_str = if path.is_null() {
+            None
+        } else {
+            match unsafe { CStr::from_ptr(path) }.to_str() {
+                Ok(s) => Some(s.to_string()),
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            }
+        };
+
+        let Some(executor) = get_executor() else {
+            return match JOURNAL_PATH.lock() {
+                Ok(mut staged) => {
+                    *staged = path_str;
+                    POLYMARKET_OK
+                }
+                Err(_) => POLYMARKET_ERR_INVALID_CONFIG,
+            };
+        };
+        let Some(path_str) = path_str else {
+            executor.with_trader(|trader| trader.set_journal(None));
+            info!("[JOURNAL] disabled");
+            return POLYMARKET_OK;
+        };
+        match open_journal(&path_str) {
+            Ok(journal) => {
+                executor.with_trader(|trader| trader.set_journal(Some(journal)));
+                POLYMARKET_OK
+            }
+            Err(code) => code,
+        }
+    })
+}
+
+#[cfg(feature = "execution")]
+fn open_journal(path: &str) -> Result<Arc<Journal>, i32> {
+    match Journal::open(Path::new(path)) {
+        Ok(journal) => Ok(Arc::new(journal)),
+        Err(e) => {
+            error!("[JOURNAL ERROR] path={} | {}", path, e);
+            Err(POLYMARKET_ERR_INVALID_CONFIG)
+        }
+    }
+}
+
+/// Journal staged by polymarket_set_journal(), opened
+#[cfg(feature = "execution")]
+fn staged_journal() -> Result<Option<Arc<Journal>>, i32> {
+    let path = JOURNAL_PATH.lock().map_err(|_| POLYMARKET_ERR_INVALID_CONFIG)?.clone();
+    path.map(|path| open_journal(&path)).transpose()
+}
+
+/// Check the hash chain of a signed log file; works without init
+/// Returns the number of records, or POLYMARKET_ERR_INVALID_CONFIG if the
+/// file cannot be read or the chain is broken (details in the log)
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_verify_signed_log(path: *const c_char) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        if path.is_null() {
+            return POLYMARKET_ERR_INVALID_CONFIG as i64;
+        }
+        let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
+            Ok(s) => s,
+            Err(_) => return POLYMARKET_ERR_INVALID_CONFIG as i64,
+        };
+
+        match signed_log::verify(Path::new(path_str)) {
+            Ok((count, last)) => {
+                info!("[SIGNED LOG] verified {} | records={} last={}", path_str, count, last);
+                count as i64
+            }
+            Err(e) => {
+                error!("[SIGNED LOG ERROR] path={} | {}", path_str, e);
+                POLYMARKET_ERR_INVALID_CONFIG as i64
+            }
+        }
+    })
+}
+
+/// Execute a market sell order (FAK - Fill and Kill)
+/// Sells at price 0.01 to fill immediately
+/// Returns result with filled quantity, average price, and latency
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell(
+    token_id: *const c_char,
+    size: f64,    // fractional shares supported
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        let request = OrderRequest::MarketSell { size, min_price: MARKET_SELL_PRICE };
+        (&market_order(token_id, request)).into()
+    })
+}
+
+/// Same as polymarket_market_sell(), filling a versioned result
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_ex(
+    token_id: *const c_char,
+    size: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    polymarket_market_sell_capped_ex(token_id, size, MARKET_SELL_PRICE, out)
+}
+
+/// Same as polymarket_market_sell_ex(), sweeping the book no lower than `min_price`
+/// POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+/// POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_capped_ex(
+    token_id: *const c_char,
+    size: f64,
+    min_price: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = market_order(token_id, OrderRequest::MarketSell { size, min_price });
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Execute a market sell as FOK (Fill or Kill)
+/// Like polymarket_market_sell(), but all `size` shares fill at once or
+/// none do: if the book cannot take them all down to 0.01 the venue kills
+/// the order and it fails with POLYMARKET_ERR_REJECTED, nothing filled
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_fok(
+    token_id: *const c_char,
+    size: f64,
+) -> PolymarketOrderResult {
+    guard::catch((&PolymarketOrderResultEx::with_error(POLYMARKET_ERR_PANIC)).into(), || {
+        let request = OrderRequest::MarketSellFok { size, min_price: MARKET_SELL_PRICE };
+        (&market_order(token_id, request)).into()
+    })
+}
+
+/// Same as polymarket_market_sell_fok(), filling a versioned result
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_fok_ex(
+    token_id: *const c_char,
+    size: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    polymarket_market_sell_fok_capped_ex(token_id, size, MARKET_SELL_PRICE, out)
+}
+
+/// Same as polymarket_market_sell_fok_ex(), sweeping the book no lower than `min_price`
+/// POLYMARKET_ERR_INVALID_ORDER if the bound is not strictly between 0 and 1,
+/// POLYMARKET_ERR_TICK_SIZE if it is off the market's tick grid
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_fok_capped_ex(
+    token_id: *const c_char,
+    size: f64,
+    min_price: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let result = market_order(token_id, OrderRequest::MarketSellFok { size, min_price });
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Market sell (FAK) bounded by the book instead of sweeping to 0.01, see
+/// polymarket_market_buy_protected_ex(); slippage is measured below the best bid
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_protected_ex(
+    token_id: *const c_char,
+    size: f64,
+    buffer_bps: u32,
+    max_slippage_bps: u32,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let request = OrderRequest::MarketSellProtected {
+            size,
+            buffer_bps,
+            max_slippage_bps,
+        };
+        let result = market_order(token_id, request);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Sell (FAK) the shares that raise `usdc_amount`, sized from the book and
+/// sweeping no lower than `min_price`
+/// The book cached by prefetch or the market feed is used if under 1s old,
+/// else refetched; fails with POLYMARKET_ERR_LIQUIDITY if the bids down to
+/// the floor raise too little
+/// Returns the error code, also stored in `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_usdc_ex(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    min_price: f64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let request = OrderRequest::MarketSellUsdc {
+            usdc_amount,
+            min_price,
+        };
+        let result = market_order(token_id, request);
+        if !unsafe { abi::write_sized(&result, out) } {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        result.error_code
+    })
+}
+
+/// Submit a market buy without waiting for it
+/// Returns a request id (> 0), or negative error code if rejected upfront.
+/// The result is delivered to POLYMARKET_EVENT_ORDER callbacks with
+/// "request_id" in the payload and kept for polymarket_poll_result();
+/// polymarket_abort_request() can abort it.
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_submit_market_buy(token_id: *const c_char, usdc_amount: f64) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str, client_order_id) = match order_call(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        let request = OrderRequest::MarketBuy { usdc_amount, max_price: MARKET_BUY_PRICE };
+        executor.submit_order(request.op(), token_str, move |executor, token_id| {
+            order_task(executor, token_id, request, client_order_id)
+        }) as i64
+    })
+}
+
+/// Submit a share-targeted market buy without waiting for it, see
+/// polymarket_market_buy_shares_ex() and polymarket_submit_market_buy()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_submit_market_buy_shares(
+    token_id: *const c_char,
+    shares: f64,
+    max_price: f64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str, client_order_id) = match order_call(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        let request = OrderRequest::MarketBuyShares { shares, max_price };
+        executor.submit_order(request.op(), token_str, move |executor, token_id| {
+            order_task(executor, token_id, request, client_order_id)
+        }) as i64
+    })
+}
+
+/// Submit a market sell without waiting for it, see polymarket_submit_market_buy()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_submit_market_sell(token_id: *const c_char, size: f64) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str, client_order_id) = match order_call(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        let request = OrderRequest::MarketSell { size, min_price: MARKET_SELL_PRICE };
+        executor.submit_order(request.op(), token_str, move |executor, token_id| {
+            order_task(executor, token_id, request, client_order_id)
+        }) as i64
+    })
+}
+
+/// Submit a GTC limit buy without waiting for it, see polymarket_submit_market_buy()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_submit_limit_buy(
+    token_id: *const c_char,
+    price: f64,
+    usdc_amount: f64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str, client_order_id) = match order_call(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        let request = OrderRequest::LimitBuy { price, usdc_amount };
+        executor.submit_order(request.op(), token_str, move |executor, token_id| {
+            order_task(executor, token_id, request, client_order_id)
+        }) as i64
+    })
+}
+
+/// Submit a GTC limit sell without waiting for it, see polymarket_submit_market_buy()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_submit_limit_sell(
+    token_id: *const c_char,
+    price: f64,
+    size: f64,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let (executor, token_str, client_order_id) = match order_call(token_id) {
+            Ok(t) => t,
+            Err(code) => return code as i64,
+        };
+        let request = OrderRequest::LimitSell { price, size };
+        executor.submit_order(request.op(), token_str, move |executor, token_id| {
+            order_task(executor, token_id, request, client_order_id)
+        }) as i64
+    })
+}
+
+/// Submit a cancel without waiting for it, see polymarket_submit_market_buy()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_submit_cancel(order_id: *const c_char) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let order_owned = unsafe {
+            if order_id.is_null() {
+                return POLYMARKET_ERR_CANCEL_FAILED as i64;
+            }
+            match CStr::from_ptr(order_id).to_str() {
+                Ok(s) => s.to_string(),
+                Err(_) => return POLYMARKET_ERR_CANCEL_FAILED as i64,
+            }
+        };
+
+        executor.submit_order("CANCEL", String::new(), move |executor, _| {
+            cancel_task(executor, order_owned)
+        }) as i64
+    })
+}
+
+/// Shared body of the polymarket_*_async() order entry points
+#[cfg(feature = "execution")]
+fn submit_async(
+    token_id: *const c_char,
+    request: OrderRequest,
+    request_id: u64,
+    callback: Option<PolymarketOrderCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i64 {
+    let (executor, token_str, client_order_id) = match order_call(token_id) {
+        Ok(t) => t,
+        Err(code) => return code as i64,
+    };
+    let callback = match callback {
+        Some(callback) => OrderCallback { callback, request_id, user_data },
+        None => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+    };
+    let notify = Some(callback.into_notify());
+    executor.submit_notified(request.op(), token_str, notify, move |executor, token_id| {
+        order_task(executor, token_id, request, client_order_id)
+    }) as i64
+}
+
+/// Submit a market buy and return at once; `callback` gets the result
+/// The callback runs once on a runtime worker thread with the caller's
+/// `request_id` (any tag, not interpreted), also when the request is aborted
+/// (POLYMARKET_ERR_ABORTED). It must not block or call blocking polymarket_*
+/// functions. POLYMARKET_EVENT_ORDER callbacks and polymarket_poll_result()
+/// see the request as for polymarket_submit_market_buy().
+/// Returns the library's id for the request (> 0, for
+/// polymarket_abort_request()), or negative error code if rejected upfront,
+/// in which case the callback is not called
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_buy_async(
+    token_id: *const c_char,
+    usdc_amount: f64,
+    request_id: u64,
+    callback: Option<PolymarketOrderCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let request = OrderRequest::MarketBuy { usdc_amount, max_price: MARKET_BUY_PRICE };
+        submit_async(token_id, request, request_id, callback, user_data)
+    })
+}
+
+/// Submit a market sell and return at once, see polymarket_market_buy_async()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_market_sell_async(
+    token_id: *const c_char,
+    size: f64,
+    request_id: u64,
+    callback: Option<PolymarketOrderCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let request = OrderRequest::MarketSell { size, min_price: MARKET_SELL_PRICE };
+        submit_async(token_id, request, request_id, callback, user_data)
+    })
+}
+
+/// Submit a GTC limit buy and return at once, see polymarket_market_buy_async()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_buy_async(
+    token_id: *const c_char,
+    price: f64,
+    usdc_amount: f64,
+    request_id: u64,
+    callback: Option<PolymarketOrderCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let request = OrderRequest::LimitBuy { price, usdc_amount };
+        submit_async(token_id, request, request_id, callback, user_data)
+    })
+}
+
+/// Submit a GTC limit sell and return at once, see polymarket_market_buy_async()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_limit_sell_async(
+    token_id: *const c_char,
+    price: f64,
+    size: f64,
+    request_id: u64,
+    callback: Option<PolymarketOrderCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let request = OrderRequest::LimitSell { price, size };
+        submit_async(token_id, request, request_id, callback, user_data)
+    })
+}
+
+/// Submit a cancel and return at once, see polymarket_market_buy_async()
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_cancel_async(
+    order_id: *const c_char,
+    request_id: u64,
+    callback: Option<PolymarketOrderCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let callback = match callback {
+            Some(callback) => OrderCallback { callback, request_id, user_data },
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT as i64,
+        };
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+
+        let order_owned = unsafe {
+            if order_id.is_null() {
+                return POLYMARKET_ERR_CANCEL_FAILED as i64;
+            }
+            match CStr::from_ptr(order_id).to_str() {
+                Ok(s) => s.to_string(),
+                Err(_) => return POLYMARKET_ERR_CANCEL_FAILED as i64,
+            }
+        };
+
+        let notify = Some(callback.into_notify());
+        executor.submit_notified("CANCEL", String::new(), notify, move |executor, _| {
+            cancel_task(executor, order_owned)
+        }) as i64
+    })
+}
+
+/// Abort a submitted request that has not completed yet
+/// Its future is dropped mid-flight, so whether the order/cancel reached the
+/// venue is unknown: its POLYMARKET_EVENT_ORDER event carries
+/// POLYMARKET_ERR_ABORTED instead of a venue result, and the host should
+/// reconcile open orders and balances.
+/// Returns 0, or POLYMARKET_ERR_INVALID_ARGUMENT if the id is unknown or the
+/// request already completed (its result was or will be delivered)
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_abort_request(request_id: u64) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let outcome = OrderOutcome::error(POLYMARKET_ERR_ABORTED);
+        match executor.requests.abort(request_id, outcome.clone()) {
+            Some(aborted) => {
+                warn!("[FFI ABORT] {} | request_id={} | venue state unknown",
+                    aborted.op, request_id);
+                emit_order_event(aborted.op, &aborted.token_id, Some(request_id), &outcome);
+                POLYMARKET_OK
+            }
+            None => POLYMARKET_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Number of submitted requests not completed or aborted yet
+/// Returns count, or -1 if not initialized
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_requests_in_flight() -> i64 {
+    guard::catch(-1, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return -1,
+        };
+
+        executor.requests.in_flight() as i64
+    })
+}
+
+/// Take the result of a submitted request, for hosts that poll from their
+/// own event loop instead of registering callbacks
+/// Returns POLYMARKET_PENDING while in flight (`out` untouched); once done,
+/// fills `out`, makes the full order id available via
+/// polymarket_last_order_id() (and its error message via
+/// polymarket_last_error_message()) and returns the result's error code.
+/// A result can be taken once; POLYMARKET_ERR_INVALID_ARGUMENT for unknown,
+/// already taken or evicted ids, or a NULL/too small `out`
+#[cfg(feature = "execution")]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_poll_result(
+    request_id: u64,
+    out: *mut PolymarketOrderResultEx,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        // Checked before taking the result, so a bad `out` doesn't lose it
+        if out.is_null() || unsafe { (*out).struct_size } < 2 * std::mem::size_of::<u32>() as u32 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+
+        match executor.requests.poll(request_id) {
+            Polled::Pending => POLYMARKET_PENDING,
+            Polled::Done(outcome) => {
+                outcome.deliver();
+                unsafe { abi::write_sized(&outcome.result, out) };
+                outcome.result.error_code
+            }
+            Polled::Unknown => POLYMARKET_ERR_INVALID_ARGUMENT,
+        }
+    })
+}
+
+/// Register a callback for a set of event kinds (POLYMARKET_EVENT_* bit mask)
+/// `delivery` selects the invoking thread: POLYMARKET_DELIVER_THREAD (dedicated
+/// "polymarket-events" thread, serialized) or POLYMARKET_DELIVER_POLL (the
+/// thread calling polymarket_poll_events). Can be called before polymarket_init().
+/// Returns the subscription id (> 0), or negative error code
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_register_callback(
+    kind_mask: u32,
+    callback: Option<events::EventCallback>,
+    user_data: *mut std::ffi::c_void,
+    delivery: i32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let callback = match callback {
+            Some(cb) => cb,
+            None => return POLYMARKET_ERR_INVALID_ARGUMENT,
+        };
+        events::register(kind_mask, delivery, callback, user_data)
+            .unwrap_or(POLYMARKET_ERR_INVALID_ARGUMENT)
+    })
+}
+
+/// Route log lines at `min_level` (1=ERROR .. 5=TRACE) and more severe to
+/// `callback` as JSON lines, NULL to stop; stdout gets none while one is set
+/// Called on the thread that logged, runtime workers included: it must be
+/// quick and must not call into this library. Can be called before
+/// polymarket_init(). Returns 0, POLYMARKET_ERR_INVALID_ARGUMENT for a bad level
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_set_log_callback(
+    callback: Option<events::LogCallback>,
+    min_level: i32,
+    user_data: *mut std::ffi::c_void,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if callback.is_some() && !(1..=5).contains(&min_level) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        events::set_log_callback(callback, min_level, user_data);
+        POLYMARKET_OK
+    })
+}
+
+/// Unregister a callback; once this returns it is not invoked again
+/// Returns 0 on success, POLYMARKET_ERR_INVALID_ARGUMENT for unknown ids
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_unregister_callback(subscription_id: i32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if events::unregister(subscription_id) {
+            POLYMARKET_OK
+        } else {
+            POLYMARKET_ERR_INVALID_ARGUMENT
+        }
+    })
+}
+
+/// Deliver up to `max_events` queued events to POLYMARKET_DELIVER_POLL
+/// callbacks on the calling thread. Returns the number of events delivered
+/// Called from inside a POLYMARKET_DELIVER_POLL callback it delivers
+/// nothing and returns 0
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_poll_events(max_events: u32) -> u32 {
+    guard::catch(0, || {
+        events::poll(max_events as usize) as u32
+    })
+}
+
+/// Number of events dropped because a delivery queue was full
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_events_dropped() -> u64 {
+    guard::catch(0, || {
+        events::dropped()
+    })
+}
+
+/// Negotiate the layout version of versioned structs (PolymarketOrderResultEx)
+/// Call once at startup, before or right after polymarket_init(), with the
+/// POLYMARKET_ABI_VERSION the host was compiled against.
+/// Returns the agreed version: min(host_version, library version)
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_negotiate_abi(host_version: u32) -> u32 {
+    guard::catch(0, || {
+        abi::negotiate(host_version)
+    })
+}
+
+/// Serve order entry and event streaming on a Unix domain socket
+/// (length-prefixed binary protocol, see ipc.rs) for co-located processes
+/// that want crash isolation. Replaces a running server and a stale socket
+/// file; the socket is created owner-only. Requires the `ipc` feature.
+/// Returns 0 on success, negative error code on failure
+#[cfg(all(unix, feature = "ipc"))]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_ipc_start(path: *const c_char) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+
+        let path_str = unsafe {
+            if path.is_null() {
+                return POLYMARKET_ERR_INVALID_CONFIG;
+            }
+            match CStr::from_ptr(path).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            }
+        };
+
+        match ipc::start(&executor, path_str) {
+            Ok(()) => POLYMARKET_OK,
+            Err(e) => {
+                error!("[IPC ERROR] path={} | error={}", path_str, e);
+                POLYMARKET_ERR_INVALID_CONFIG
+            }
+        }
+    })
+}
+
+/// Stop the socket server and remove its socket file
+/// Open connections are closed once the replies of their requests in
+/// flight are written, or after 15 seconds.
+/// Also done by polymarket_shutdown().
+/// Returns 0, or POLYMARKET_ERR_INVALID_ARGUMENT if no server was running
+#[cfg(all(unix, feature = "ipc"))]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_ipc_stop() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if ipc::stop() {
+            POLYMARKET_OK
+        } else {
+            POLYMARKET_ERR_INVALID_ARGUMENT
+        }
+    })
+}
+
+/// Create the shared-memory order entry region `name` (POSIX shm name, e.g.
+/// "/polymarket-orders") with `capacity` records per ring (power of two) and
+/// start serving it; replaces an open region, and one of that name left by
+/// a process that has exited. Layout and protocol in shm.rs and
+/// polymarket_ffi.h. `out_base` (may be NULL) receives the mapping
+/// address for hosts in the same process. Requires the `shm` feature.
+/// Returns 0 on success, negative error code on failure
+#[cfg(all(unix, feature = "shm"))]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_shm_open(
+    name: *const c_char,
+    capacity: u32,
+    out_base: *mut *mut std::ffi::c_void,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let name_str = unsafe {
+            if name.is_null() {
+                return POLYMARKET_ERR_INVALID_CONFIG;
+            }
+            match CStr::from_ptr(name).to_str() {
+                Ok(s) => s,
+                Err(_) => return POLYMARKET_ERR_INVALID_CONFIG,
+            }
+        };
+
+        match shm::open(name_str, capacity as usize) {
+            Ok(base) => {
+                if !out_base.is_null() {
+                    unsafe { *out_base = base as *mut std::ffi::c_void };
+                }
+                POLYMARKET_OK
+            }
+            Err(e) => {
+                error!("[SHM ERROR] name={} | error={}", name_str, e);
+                POLYMARKET_ERR_INVALID_CONFIG
+            }
+        }
+    })
+}
+
+/// Stop serving and unlink the shared-memory region
+/// Returns 0, or POLYMARKET_ERR_INVALID_ARGUMENT if none was open
+#[cfg(all(unix, feature = "shm"))]
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_shm_close() -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if shm::close() {
+            POLYMARKET_OK
+        } else {
+            POLYMARKET_ERR_INVALID_ARGUMENT
+        }
+    })
+}
+
+// polymarket_shutdown_ex() flags
+/// Cancel every resting order once running calls have drained
+pub const POLYMARKET_SHUTDOWN_CANCEL_ALL: u32 = 1;
+
+/// How long polymarket_shutdown() waits for running calls
+const DEFAULT_DRAIN_TIMEOUT_MS: u32 = 5_000;
+
+/// Stop background work and wait up to `timeout` for running calls, then
+/// pull quotes and, with POLYMARKET_SHUTDOWN_CANCEL_ALL, every resting order
+fn drain(executor: &Arc<Executor>, flags: u32, timeout: Duration) -> i32 {
+    let pollers = [
+        #[cfg(feature = "execution")]
+        &executor.notifications,
+        #[cfg(feature = "onchain")]
+        &executor.merging,
+        &executor.arb_scan,
+        &executor.resolution_watch,
+        &executor.book_feed,
+        &executor.meta_refresh,
+        &executor.keep_alive,
+        #[cfg(feature = "execution")]
+        &executor.fill_tracking,
+        #[cfg(feature = "execution")]
+        &executor.endpoint_probing,
+        #[cfg(feature = "execution")]
+        &executor.venue_watch,
+        #[cfg(feature = "execution")]
+        &executor.user_channel,
+    ];
+    for poller in pollers {
+        if let Some(task) = poller.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            task.abort();
+        }
+    }
+    // Cancelled algos end on their next step; an iceberg pulls its clip
+    #[cfg(feature = "execution")]
+    for control in executor.algos.lock().unwrap_or_else(|e| e.into_inner()).values() {
+        control.cancel();
+    }
+
+    if Handle::try_current().is_ok() {
+        warn!("[SHUTDOWN] called from the executor runtime: not draining, orders left as they are");
+        return if executor.in_flight.load(Ordering::Acquire) > 0 {
+            POLYMARKET_ERR_ABORTED
+        } else {
+            POLYMARKET_OK
+        };
+    }
+
+    let mut code = POLYMARKET_OK;
+    let deadline = Instant::now() + timeout;
+    while executor.in_flight.load(Ordering::Acquire) > 0 && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    let running = executor.in_flight.load(Ordering::Acquire);
+    if running > 0 {
+        warn!("[SHUTDOWN] {} calls still running after {}ms, aborting them", running,
+            timeout.as_millis());
+        code = POLYMARKET_ERR_ABORTED;
+    }
+
+    // Quotes would otherwise outlive the process on the book
+    #[cfg(feature = "execution")]
+    if executor.quoter.resting() > 0 || executor.quoting.lock().is_ok_and(|q| q.is_some()) {
+        match pull_quotes(executor) {
+            Ok(0) => {}
+            Ok(resting) => {
+                error!("[SHUTDOWN] {} quotes still resting", resting);
+                code = POLYMARKET_ERR_CANCEL_FAILED;
+            }
+            Err(e) => {
+                error!("[SHUTDOWN] pulling quotes failed | {}", e);
+                code = POLYMARKET_ERR_CANCEL_FAILED;
+            }
+        }
+    }
+
+    // Without the execution feature there is nothing resting to cancel
+    #[cfg(not(feature = "execution"))]
+    let _ = flags;
+    #[cfg(feature = "execution")]
+    if flags & POLYMARKET_SHUTDOWN_CANCEL_ALL != 0 && !executor.with_trader(|t| t.is_read_only()) {
+        let result = executor.run(move |executor| async move {
+            executor.trader().cancel_all().await.map_err(anyhow::Error::from)
+        });
+        match result {
+            Ok(outcomes) if outcomes.iter().any(CancelOutcome::is_failure) => {
+                for outcome in outcomes.iter().filter(|o| o.is_failure()) {
+                    error!("[SHUTDOWN] order not cancelled | {} | {}",
+                        outcome.order_id, outcome.reason);
+                }
+                code = POLYMARKET_ERR_CANCEL_FAILED;
+            }
+            Ok(_) => info!("[SHUTDOWN] resting orders cancelled"),
+            Err(e) => {
+                error!("[SHUTDOWN] cancel all failed | {}", e);
+                code = POLYMARKET_ERR_CANCEL_FAILED;
+            }
+        }
+    }
+    code
+}
+
+/// Shutdown and cleanup, waiting up to 5 s for running calls
+/// After calling this, polymarket_init() can be called again to re-initialize
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_shutdown() {
+    polymarket_shutdown_ex(0, DEFAULT_DRAIN_TIMEOUT_MS);
+}
+
+/// Shutdown the executor gracefully
+/// New calls fail with POLYMARKET_ERR_NOT_INITIALIZED at once; running ones
+/// get `drain_timeout_ms` to finish, then the executor's remaining tasks are
+/// aborted and the signed log is synced. A callback may call this too
+/// (without the drain and cancels). Returns POLYMARKET_ERR_ABORTED if calls
+/// were still running at the deadline, POLYMARKET_ERR_CANCEL_FAILED if a
+/// cancel failed; the executor is shut down either way
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_shutdown_ex(flags: u32, drain_timeout_ms: u32) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        if flags & !POLYMARKET_SHUTDOWN_CANCEL_ALL != 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        #[cfg(all(unix, feature = "ipc"))]
+        ipc::stop();
+        #[cfg(all(unix, feature = "shm"))]
+        shm::close();
+
+        // Out of the global slot first, so nothing new reaches it
+        match session::take(VENUE) {
+            Some(executor) => shut_down(executor, flags, drain_timeout_ms),
+            None => POLYMARKET_ERR_NOT_INITIALIZED,
+        }
+    })
+}
+
+/// Drain an executor taken out of reach of new calls, abort its remaining
+/// tasks and sync its signed log and journal
+fn close(executor: Arc<Executor>, flags: u32, drain_timeout_ms: u32) -> i32 {
+    info!("[SHUTDOWN] draining | in_flight={} timeout={}ms",
+        executor.in_flight.load(Ordering::Acquire), drain_timeout_ms);
+
+    let code = drain(&executor, flags, Duration::from_millis(drain_timeout_ms as u64));
+
+    // The runtime is shared and outlives the executor: what the drain left
+    // running (timed out calls, requests, connections) ends here
+    let tasks = std::mem::take(&mut *executor.tasks.lock().unwrap_or_else(|e| e.into_inner()));
+    let running: Vec<_> = tasks.into_iter().filter(|task| !task.is_finished()).collect();
+    if !running.is_empty() {
+        info!("[SHUTDOWN] aborting {} tasks", running.len());
+    }
+    running.iter().for_each(AbortHandle::abort);
+
+    #[cfg(feature = "execution")]
+    if let Some(log) = executor.trader().signed_log() {
+        if let Err(e) = log.sync() {
+            error!("[SHUTDOWN] {}", e);
+        }
+    }
+    #[cfg(feature = "execution")]
+    if let Some(journal) = executor.trader().journal() {
+        if let Err(e) = journal.sync() {
+            error!("[SHUTDOWN] journal {} | {}", journal.path().display(), e);
+        }
+    }
+    code
+}
+
+/// Close an executor taken out of reach of new calls, its wallets first
+fn shut_down(executor: Arc<Executor>, flags: u32, drain_timeout_ms: u32) -> i32 {
+    let mut code = POLYMARKET_OK;
+    // They go with the executor that added them
+    #[cfg(feature = "execution")]
+    for (name, wallet) in executor.wallets.take_all() {
+        info!("[SHUTDOWN] wallet {}", name);
+        let wallet_code = close(wallet, flags, drain_timeout_ms);
+        if wallet_code != POLYMARKET_OK {
+            code = wallet_code;
+        }
+    }
+    let main_code = close(executor, flags, drain_timeout_ms);
+    if main_code != POLYMARKET_OK {
+        code = main_code;
     }
+    info!("[SHUTDOWN] done | code={}", code);
+    code
 }
@@ -0,0 +1,84 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Caller-provided output buffers
+//!
+//! Convention for every string returned over the FFI: the caller passes a
+//! buffer and its length, the function returns the size it needs (bytes
+//! including the NUL terminator). The string is written only if it fits,
+//! so a too-small buffer never yields a silently truncated value - call
+//! again with a buffer of the returned size (or pass NULL/0 to query it).
+
+use std::cell::RefCell;
+use std::os::raw::c_char;
+
+thread_local! {
+    /// Full id of the last order placed from this thread
+    #[cfg(feature = "execution")]
+    static LAST_ORDER_ID: RefCell<String> = const { RefCell::new(String::new()) };
+    /// Error message of the last order or cancel call from this thread
+    #[cfg(feature = "execution")]
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+    /// JSON result of the last query made from this thread
+    static LAST_RESPONSE: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Copy `s` into `buf` as a NUL-terminated string if it fits
+/// Returns the required buffer size (s.len() + 1)
+///
+/// # Safety
+/// `buf` must be null or valid for writes of `len` bytes
+pub unsafe fn write_str(s: &str, buf: *mut c_char, len: usize) -> usize {
+    let required = s.len() + 1;
+    if !buf.is_null() && len >= required {
+        std::ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, buf, s.len());
+        *buf.add(s.len()) = 0;
+    }
+    required
+}
+
+#[cfg(feature = "execution")]
+pub fn set_last_order_id(id: &str) {
+    LAST_ORDER_ID.with(|last| {
+        let mut last = last.borrow_mut();
+        last.clear();
+        last.push_str(id);
+    });
+}
+
+#[cfg(feature = "execution")]
+pub fn with_last_order_id<R>(f: impl FnOnce(&str) -> R) -> R {
+    LAST_ORDER_ID.with(|last| f(&last.borrow()))
+}
+
+#[cfg(feature = "execution")]
+pub fn set_last_error(message: &str) {
+    LAST_ERROR.with(|last| {
+        let mut last = last.borrow_mut();
+        last.clear();
+        last.push_str(message);
+    });
+}
+
+#[cfg(feature = "execution")]
+pub fn with_last_error<R>(f: impl FnOnce(&str) -> R) -> R {
+    LAST_ERROR.with(|last| f(&last.borrow()))
+}
+
+/// Keep a query result for polymarket_last_response()
+/// Returns the buffer size needed to read it
+pub fn set_last_response(json: String) -> usize {
+    let required = json.len() + 1;
+    LAST_RESPONSE.with(|last| *last.borrow_mut() = json);
+    required
+}
+
+pub fn with_last_response<R>(f: impl FnOnce(&str) -> R) -> R {
+    LAST_RESPONSE.with(|last| f(&last.borrow()))
+}
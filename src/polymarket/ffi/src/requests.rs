@@ -0,0 +1,202 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! In-flight request registry
+//!
+//! Requests submitted without waiting (polymarket_submit_*) get an id and
+//! stay here until their task completes. Aborting one drops its future at
+//! the next await point: whether the order/cancel reached the venue is
+//! unknown, so the host must reconcile (open orders, balances) afterwards.
+//!
+//! Exactly one of `complete` and `abort` wins for each id, so every request
+//! reports a single outcome. That outcome is kept until the host takes it
+//! with polymarket_poll_result(); hosts that only use callbacks never do, so
+//! at most `MAX_COMPLETED` outcomes are kept and the oldest are evicted.
+//! A request can also carry a `Notify` hook, run with whichever outcome wins,
+//! outside the registry lock.
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use tokio::task::{AbortHandle, JoinHandle};
+
+/// Completed outcomes kept for polling before the oldest are evicted
+const MAX_COMPLETED: usize = 65_536;
+
+/// Hook run once with the outcome of a request
+pub type Notify<T> = Box<dyn FnOnce(&T) + Send>;
+
+struct InFlight<T> {
+    abort: AbortHandle,
+    op: &'static str,
+    token_id: String,
+    notify: Option<Notify<T>>,
+}
+
+enum State<T> {
+    InFlight(InFlight<T>),
+    Done(T),
+}
+
+/// Request that was aborted before completing
+pub struct Aborted {
+    pub op: &'static str,
+    pub token_id: String,
+}
+
+/// State of a request as seen by the host
+pub enum Polled<T> {
+    Pending,
+    Done(T),
+    Unknown,
+}
+
+struct Registry<T> {
+    states: HashMap<u64, State<T>>,
+    /// Ids of `Done` entries in completion order, for eviction
+    completed: VecDeque<u64>,
+}
+
+impl<T> Registry<T> {
+    fn finish(&mut self, id: u64, outcome: T) {
+        self.states.insert(id, State::Done(outcome));
+        self.completed.push_back(id);
+        while self.completed.len() > MAX_COMPLETED {
+            if let Some(old) = self.completed.pop_front() {
+                if matches!(self.states.get(&old), Some(State::Done(_))) {
+                    self.states.remove(&old);
+                }
+            }
+        }
+    }
+}
+
+/// Ids are unique across executors, so events of requests submitted through
+/// different handles never share one
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+pub struct Requests<T> {
+    registry: Mutex<Registry<T>>,
+}
+
+impl<T> Requests<T> {
+    pub fn new() -> Self {
+        Self {
+            registry: Mutex::new(Registry {
+                states: HashMap::new(),
+                completed: VecDeque::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Registry<T>> {
+        self.registry.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Spawn the future built by `make` with `spawn` and register it,
+    /// returns its id
+    pub fn spawn<S, F, Fut>(
+        &self,
+        spawn: S,
+        op: &'static str,
+        token_id: String,
+        notify: Option<Notify<T>>,
+        make: F,
+    ) -> u64
+    where
+        S: FnOnce(Fut) -> JoinHandle<()>,
+        F: FnOnce(u64) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        // Registered under the lock, so a task finishing right away still
+        // finds its entry in complete()
+        let mut registry = self.lock();
+        let task = spawn(make(id));
+        let in_flight = InFlight {
+            abort: task.abort_handle(),
+            op,
+            token_id,
+            notify,
+        };
+        registry.states.insert(id, State::InFlight(in_flight));
+        id
+    }
+
+    /// Called by the task when done; false if the request was aborted meanwhile
+    pub fn complete(&self, id: u64, outcome: T) -> bool
+    where
+        T: Clone,
+    {
+        let mut registry = self.lock();
+        let notify = match registry.states.get_mut(&id) {
+            Some(State::InFlight(entry)) => entry.notify.take(),
+            _ => return false,
+        };
+        registry.finish(id, outcome.clone());
+        drop(registry);
+        if let Some(notify) = notify {
+            notify(&outcome);
+        }
+        true
+    }
+
+    /// Abort a request still in flight, recording `outcome` as its result
+    /// None if unknown or already completed
+    pub fn abort(&self, id: u64, outcome: T) -> Option<Aborted>
+    where
+        T: Clone,
+    {
+        let mut registry = self.lock();
+        let entry = match registry.states.remove(&id) {
+            Some(State::InFlight(entry)) => entry,
+            Some(done) => {
+                registry.states.insert(id, done);
+                return None;
+            }
+            None => return None,
+        };
+        entry.abort.abort();
+        registry.finish(id, outcome.clone());
+        drop(registry);
+        if let Some(notify) = entry.notify {
+            notify(&outcome);
+        }
+        Some(Aborted {
+            op: entry.op,
+            token_id: entry.token_id,
+        })
+    }
+
+    /// Take the outcome of a completed request; it can be taken only once
+    pub fn poll(&self, id: u64) -> Polled<T> {
+        let mut registry = self.lock();
+        match registry.states.remove(&id) {
+            // Its id stays in `completed` until evicted, skipped there as gone
+            Some(State::Done(outcome)) => Polled::Done(outcome),
+            Some(in_flight) => {
+                registry.states.insert(id, in_flight);
+                Polled::Pending
+            }
+            None => Polled::Unknown,
+        }
+    }
+
+    /// Number of submitted requests not completed yet
+    pub fn in_flight(&self) -> usize {
+        let registry = self.lock();
+        registry
+            .states
+            .values()
+            .filter(|s| matches!(s, State::InFlight(_)))
+            .count()
+    }
+}
@@ -0,0 +1,506 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Shared-memory order entry queues (feature `shm`)
+//!
+//! A POSIX shared-memory region holds two single-producer/single-consumer
+//! rings of fixed-size records: commands written by the host, responses
+//! written by the library. The host submits by filling a command slot and
+//! bumping `cmd_head`, then busy-polls `resp_head` for the response with the
+//! same tag - no FFI call and no callback on the hot path.
+//!
+//! Layout: `ShmHeader`, then `capacity` commands, then `capacity`
+//! responses. Head/tail are monotonically increasing counters (slot =
+//! counter & (capacity - 1)); a producer publishes a slot with a release
+//! store of its head, the consumer frees it with a release store of its
+//! tail. The library side is one poller thread, which spins while busy and
+//! backs off to short sleeps when idle.
+//!
+//! A region of the same name is replaced only if it was left behind: never
+//! initialized, or its `owner_pid` has exited. Responses the host has no
+//! room for wait in a backlog of up to `capacity` records; past that they
+//! are dropped and counted in `responses_dropped`.
+
+use std::collections::VecDeque;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use polymarket_executor_core::{parse_token, OrderRequest, MARKET_BUY_PRICE, MARKET_SELL_PRICE};
+use tracing::{error, info, warn};
+
+use crate::{
+    cancel_task, emit_order_event, get_executor, order_task, OrderOutcome,
+    POLYMARKET_ERR_INVALID_ARGUMENT, POLYMARKET_ERR_INVALID_TOKEN, POLYMARKET_ERR_NOT_INITIALIZED,
+};
+
+/// "PMSHMQ01", set last once the region is initialized
+pub const SHM_MAGIC: u64 = 0x3130_514d_4853_4d50;
+pub const SHM_VERSION: u32 = 1;
+
+pub const SHM_CMD_MARKET_BUY: u32 = 1;
+pub const SHM_CMD_MARKET_SELL: u32 = 2;
+pub const SHM_CMD_LIMIT_BUY: u32 = 3;
+pub const SHM_CMD_LIMIT_SELL: u32 = 4;
+pub const SHM_CMD_CANCEL: u32 = 5;
+
+/// Idle polls spent spinning, then yielding, before sleeping between polls
+const SPIN_POLLS: u32 = 1_000;
+const YIELD_POLLS: u32 = 100_000;
+const IDLE_SLEEP: Duration = Duration::from_micros(100);
+
+/// Counter on its own cache line, so host and library writes don't false-share
+#[repr(C, align(64))]
+pub struct Counter(pub AtomicU64);
+
+#[repr(C, align(64))]
+pub struct ShmHeader {
+    pub magic: AtomicU64,
+    pub version: u32,
+    pub capacity: u32,
+    /// Process serving the region
+    pub owner_pid: u32,
+    pub _reserved: u32,
+    /// Responses lost because the host fell a whole ring behind; written by
+    /// the library
+    pub responses_dropped: AtomicU64,
+    /// Written by the host
+    pub cmd_head: Counter,
+    /// Written by the library
+    pub cmd_tail: Counter,
+    /// Written by the library
+    pub resp_head: Counter,
+    /// Written by the host
+    pub resp_tail: Counter,
+}
+
+/// Command record written by the host
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PolymarketShmCommand {
+    /// Chosen by the host, echoed in the response
+    pub tag: u64,
+    /// POLYMARKET_SHM_CMD_*
+    pub kind: u32,
+    pub _reserved: u32,
+    /// Limit price (LIMIT_*), ignored otherwise
+    pub price: f64,
+    /// USDC for buys, shares for sells
+    pub amount: f64,
+    /// Token id, or order id for CANCEL (NUL-terminated)
+    pub id: [c_char; 128],
+}
+
+/// Response record written by the library
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PolymarketShmResponse {
+    pub tag: u64,
+    pub error_code: i32,
+    pub success: u32,
+    pub filled_qty_raw: i64,
+    pub avg_price_raw: i64,
+    pub remaining_qty_raw: i64,
+    pub fee_raw: i64,
+    pub latency_ms: u64,
+    pub order_id: [c_char; 128],
+}
+
+/// Mapped region, unmapped and unlinked on drop
+struct Region {
+    base: NonNull<u8>,
+    len: usize,
+    name: CString,
+    capacity: usize,
+}
+
+// SAFETY: all shared state is accessed through atomics or slots handed over
+// by the ring protocol
+unsafe impl Send for Region {}
+unsafe impl Sync for Region {}
+
+impl Region {
+    fn create(name: &str, capacity: usize) -> std::io::Result<Self> {
+        let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidInput, msg);
+        if !capacity.is_power_of_two() || capacity > u32::MAX as usize {
+            return Err(invalid("capacity must be a power of two"));
+        }
+        let name = CString::new(name).map_err(|_| invalid("name contains NUL"))?;
+        let len = std::mem::size_of::<ShmHeader>()
+            + capacity * std::mem::size_of::<PolymarketShmCommand>()
+            + capacity * std::mem::size_of::<PolymarketShmResponse>();
+
+        unsafe {
+            let fd = open_exclusive(&name)?;
+            if libc::ftruncate(fd, len as libc::off_t) != 0 {
+                let err = std::io::Error::last_os_error();
+                libc::close(fd);
+                libc::shm_unlink(name.as_ptr());
+                return Err(err);
+            }
+            let base = libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd,
+                0,
+            );
+            libc::close(fd);
+            if base == libc::MAP_FAILED {
+                let err = std::io::Error::last_os_error();
+                libc::shm_unlink(name.as_ptr());
+                return Err(err);
+            }
+
+            // Fresh mapping is zeroed; publish geometry, then the magic
+            let header = base as *mut ShmHeader;
+            (*header).version = SHM_VERSION;
+            (*header).capacity = capacity as u32;
+            (*header).owner_pid = std::process::id();
+            (*header).magic.store(SHM_MAGIC, Ordering::Release);
+
+            Ok(Self {
+                base: NonNull::new_unchecked(base as *mut u8),
+                len,
+                name,
+                capacity,
+            })
+        }
+    }
+
+    fn header(&self) -> &ShmHeader {
+        unsafe { &*(self.base.as_ptr() as *const ShmHeader) }
+    }
+
+    fn command(&self, index: u64) -> *const PolymarketShmCommand {
+        let slot = index as usize & (self.capacity - 1);
+        unsafe {
+            let commands = self.base.as_ptr().add(std::mem::size_of::<ShmHeader>());
+            (commands as *const PolymarketShmCommand).add(slot)
+        }
+    }
+
+    fn response(&self, index: u64) -> *mut PolymarketShmResponse {
+        let slot = index as usize & (self.capacity - 1);
+        unsafe {
+            let responses = self
+                .base
+                .as_ptr()
+                .add(std::mem::size_of::<ShmHeader>())
+                .add(self.capacity * std::mem::size_of::<PolymarketShmCommand>());
+            (responses as *mut PolymarketShmResponse).add(slot)
+        }
+    }
+
+    /// Take the next command, if the host published one
+    fn pop_command(&self) -> Option<PolymarketShmCommand> {
+        let header = self.header();
+        let tail = header.cmd_tail.0.load(Ordering::Relaxed);
+        if tail == header.cmd_head.0.load(Ordering::Acquire) {
+            return None;
+        }
+        let command = unsafe { std::ptr::read_volatile(self.command(tail)) };
+        header.cmd_tail.0.store(tail + 1, Ordering::Release);
+        Some(command)
+    }
+
+    /// Publish a response; false if the host hasn't drained the ring
+    fn push_response(&self, response: &PolymarketShmResponse) -> bool {
+        let header = self.header();
+        let head = header.resp_head.0.load(Ordering::Relaxed);
+        let tail = header.resp_tail.0.load(Ordering::Acquire);
+        if head - tail >= self.capacity as u64 {
+            return false;
+        }
+        unsafe { std::ptr::write_volatile(self.response(head), *response) };
+        header.resp_head.0.store(head + 1, Ordering::Release);
+        true
+    }
+}
+
+/// Create `name`, replacing a region of that name only if it is stale
+unsafe fn open_exclusive(name: &CStr) -> std::io::Result<libc::c_int> {
+    let create = || unsafe {
+        libc::shm_open(
+            name.as_ptr(),
+            libc::O_CREAT | libc::O_EXCL | libc::O_RDWR,
+            0o600,
+        )
+    };
+    let fd = create();
+    if fd >= 0 {
+        return Ok(fd);
+    }
+    let err = std::io::Error::last_os_error();
+    if err.raw_os_error() != Some(libc::EEXIST) || !is_stale(name) {
+        return Err(err);
+    }
+    warn!("[SHM] replacing stale region {:?}", name);
+    libc::shm_unlink(name.as_ptr());
+    let fd = create();
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(fd)
+}
+
+/// Whether the existing region `name` was left behind: never initialized,
+/// or served by a process that is gone. Our own pid counts as gone, since
+/// open() closes this process's region first
+unsafe fn is_stale(name: &CStr) -> bool {
+    let fd = libc::shm_open(name.as_ptr(), libc::O_RDONLY, 0);
+    if fd < 0 {
+        return false;
+    }
+    let len = std::mem::size_of::<ShmHeader>();
+    let mut stat: libc::stat = std::mem::zeroed();
+    if libc::fstat(fd, &mut stat) != 0 {
+        libc::close(fd);
+        return false;
+    }
+    if (stat.st_size as usize) < len {
+        libc::close(fd);
+        return true;
+    }
+    let base = libc::mmap(
+        std::ptr::null_mut(),
+        len,
+        libc::PROT_READ,
+        libc::MAP_SHARED,
+        fd,
+        0,
+    );
+    libc::close(fd);
+    if base == libc::MAP_FAILED {
+        return false;
+    }
+    let header = &*(base as *const ShmHeader);
+    let stale = header.magic.load(Ordering::Acquire) != SHM_MAGIC
+        || header.owner_pid == std::process::id()
+        || !process_alive(header.owner_pid);
+    libc::munmap(base, len);
+    stale
+}
+
+fn process_alive(pid: u32) -> bool {
+    if pid == 0 || pid > libc::pid_t::MAX as u32 {
+        return false;
+    }
+    // Signal 0 only checks the process exists; EPERM means it does
+    unsafe {
+        libc::kill(pid as libc::pid_t, 0) == 0
+            || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+    }
+}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.base.as_ptr() as *mut libc::c_void, self.len);
+            libc::shm_unlink(self.name.as_ptr());
+        }
+    }
+}
+
+struct Queues {
+    region: Arc<Region>,
+    stop: Arc<AtomicBool>,
+    poller: JoinHandle<()>,
+}
+
+static QUEUES: Mutex<Option<Queues>> = Mutex::new(None);
+
+/// Create the region `name` (e.g. "/polymarket-orders") with `capacity`
+/// slots per ring and start serving it, replacing an open one
+/// Returns the address of the mapping, for hosts in the same process
+pub fn open(name: &str, capacity: usize) -> std::io::Result<*mut u8> {
+    let mut queues = QUEUES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(old) = queues.take() {
+        close_queues(old);
+    }
+
+    let region = Arc::new(Region::create(name, capacity)?);
+    let stop = Arc::new(AtomicBool::new(false));
+    let poller = {
+        let region = region.clone();
+        let stop = stop.clone();
+        std::thread::Builder::new()
+            .name("polymarket-shm".to_string())
+            .spawn(move || poll_loop(&region, &stop))?
+    };
+
+    info!("[SHM] serving {} | capacity={}", name, capacity);
+    let base = region.base.as_ptr();
+    *queues = Some(Queues {
+        region,
+        stop,
+        poller,
+    });
+    Ok(base)
+}
+
+/// Stop the poller and unlink the region; false if none was open
+pub fn close() -> bool {
+    let queues = QUEUES.lock().unwrap_or_else(|e| e.into_inner()).take();
+    match queues {
+        Some(queues) => {
+            close_queues(queues);
+            true
+        }
+        None => false,
+    }
+}
+
+fn close_queues(queues: Queues) {
+    queues.stop.store(true, Ordering::Release);
+    let _ = queues.poller.join();
+    info!("[SHM] closed {:?}", queues.region.name);
+}
+
+fn poll_loop(region: &Arc<Region>, stop: &AtomicBool) {
+    let (tx, rx): (
+        Sender<PolymarketShmResponse>,
+        Receiver<PolymarketShmResponse>,
+    ) = mpsc::channel();
+    let mut backlog: VecDeque<PolymarketShmResponse> = VecDeque::new();
+    let mut idle: u32 = 0;
+
+    while !stop.load(Ordering::Acquire) {
+        let mut busy = false;
+
+        while let Some(command) = region.pop_command() {
+            dispatch(command, tx.clone());
+            busy = true;
+        }
+
+        while let Some(response) = backlog.front() {
+            if !region.push_response(response) {
+                break;
+            }
+            backlog.pop_front();
+            busy = true;
+        }
+        for response in rx.try_iter() {
+            busy = true;
+            if backlog.is_empty() && region.push_response(&response) {
+                continue;
+            }
+            if backlog.len() < region.capacity {
+                backlog.push_back(response);
+                continue;
+            }
+            region
+                .header()
+                .responses_dropped
+                .fetch_add(1, Ordering::Release);
+            error!(
+                "[SHM] host a ring behind, response dropped | tag={}",
+                response.tag
+            );
+        }
+
+        if busy {
+            idle = 0;
+        } else {
+            idle = idle.saturating_add(1);
+            if idle < SPIN_POLLS {
+                std::hint::spin_loop();
+            } else if idle < YIELD_POLLS {
+                std::thread::yield_now();
+            } else {
+                std::thread::sleep(IDLE_SLEEP);
+            }
+        }
+    }
+}
+
+fn response(tag: u64, outcome: &OrderOutcome) -> PolymarketShmResponse {
+    let result = &outcome.result;
+    PolymarketShmResponse {
+        tag,
+        error_code: result.error_code,
+        success: result.success as u32,
+        filled_qty_raw: result.filled_qty_raw,
+        avg_price_raw: result.avg_price_raw,
+        remaining_qty_raw: result.remaining_qty_raw,
+        fee_raw: result.fee_raw,
+        latency_ms: result.latency_ms,
+        order_id: result.order_id,
+    }
+}
+
+/// Run one command on the executor runtime, its response goes to `tx`
+fn dispatch(command: PolymarketShmCommand, tx: Sender<PolymarketShmResponse>) {
+    let reject = |code| {
+        let _ = tx.send(response(command.tag, &OrderOutcome::error(code)));
+    };
+
+    let executor = match get_executor() {
+        Some(e) => e,
+        None => return reject(POLYMARKET_ERR_NOT_INITIALIZED),
+    };
+
+    let bytes =
+        unsafe { std::slice::from_raw_parts(command.id.as_ptr() as *const u8, command.id.len()) };
+    let id = match std::ffi::CStr::from_bytes_until_nul(bytes).map(|id| id.to_str()) {
+        Ok(Ok(id)) => id.to_string(),
+        _ => return reject(POLYMARKET_ERR_INVALID_ARGUMENT),
+    };
+
+    let (tag, price, amount) = (command.tag, command.price, command.amount);
+    let tasks = executor.clone();
+
+    if command.kind == SHM_CMD_CANCEL {
+        tasks.spawn(async move {
+            let outcome = cancel_task(executor, id).await;
+            emit_order_event("CANCEL", "", None, &outcome);
+            let _ = tx.send(response(tag, &outcome));
+        });
+        return;
+    }
+
+    if parse_token(&id).is_err() {
+        return reject(POLYMARKET_ERR_INVALID_TOKEN);
+    }
+
+    let request = match command.kind {
+        SHM_CMD_MARKET_BUY => OrderRequest::MarketBuy {
+            usdc_amount: amount,
+            max_price: MARKET_BUY_PRICE,
+        },
+        SHM_CMD_MARKET_SELL => OrderRequest::MarketSell {
+            size: amount,
+            min_price: MARKET_SELL_PRICE,
+        },
+        SHM_CMD_LIMIT_BUY => OrderRequest::LimitBuy {
+            price,
+            usdc_amount: amount,
+        },
+        SHM_CMD_LIMIT_SELL => OrderRequest::LimitSell {
+            price,
+            size: amount,
+        },
+        kind => {
+            error!("[SHM] unknown command kind {} | tag={}", kind, tag);
+            return reject(POLYMARKET_ERR_INVALID_ARGUMENT);
+        }
+    };
+
+    tasks.spawn(async move {
+        let outcome = order_task(executor, id.clone(), request, None).await;
+        emit_order_event(request.op(), &id, None, &outcome);
+        let _ = tx.send(response(tag, &outcome));
+    });
+}
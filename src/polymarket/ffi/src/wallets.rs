@@ -0,0 +1,241 @@
+/*
+ * Flox Engine
+ * Developed by FLOX Foundation (https://github.com/FLOX-Foundation)
+ *
+ * Copyright (c) 2026 FLOX Foundation
+ * Licensed under the MIT License. See LICENSE file in the project root for full
+ * license information.
+ */
+
+//! Named wallets beside the global session
+//!
+//! polymarket_add_wallet() connects another funder wallet under a name of
+//! the caller's choosing, as an executor of its own (session, requests,
+//! pollers) like a handle's. It takes the global session's connect options
+//! and read-only / dry-run mode.
+//!
+//! polymarket_w_*() run the global entry point of the same name on a wallet,
+//! the way polymarket_h_*() run it on a handle, and are generated with them
+//! in handles.rs; an unknown name fails as a NULL handle does. Wallets are
+//! shut down before the global executor.
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::{Arc, RwLock};
+
+use polymarket_executor_core::Trader;
+use tracing::{error, info};
+use zeroize::Zeroizing;
+
+use crate::{
+    close, connect_error_code, get_executor, guard, handles, new_executor, out_buf, Executor,
+    POLYMARKET_ERR_AUTH_FAILED, POLYMARKET_ERR_INVALID_ARGUMENT, POLYMARKET_ERR_INVALID_PK,
+    POLYMARKET_ERR_NOT_INITIALIZED, POLYMARKET_ERR_PANIC, POLYMARKET_OK,
+    POLYMARKET_SHUTDOWN_CANCEL_ALL,
+};
+
+/// Most wallets registered at once
+const MAX_WALLETS: usize = 64;
+
+/// Longest wallet name accepted (bytes)
+const MAX_WALLET_NAME: usize = 63;
+
+/// Wallets by name
+pub(crate) struct Wallets(RwLock<HashMap<String, Arc<Executor>>>);
+
+impl Wallets {
+    pub(crate) fn new() -> Self {
+        Self(RwLock::new(HashMap::new()))
+    }
+
+    fn get(&self, name: &str) -> Option<Arc<Executor>> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).get(name).cloned()
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).contains_key(name)
+    }
+
+    /// Register `executor` under `name`; given back if the name is taken or
+    /// the registry is full
+    fn insert(&self, name: String, executor: Arc<Executor>) -> Result<(), Arc<Executor>> {
+        let mut wallets = self.0.write().unwrap_or_else(|e| e.into_inner());
+        if wallets.contains_key(&name) || wallets.len() >= MAX_WALLETS {
+            return Err(executor);
+        }
+        wallets.insert(name, executor);
+        Ok(())
+    }
+
+    fn remove(&self, name: &str) -> Option<Arc<Executor>> {
+        self.0.write().unwrap_or_else(|e| e.into_inner()).remove(name)
+    }
+
+    /// Every wallet, out of reach of new calls
+    pub(crate) fn take_all(&self) -> Vec<(String, Arc<Executor>)> {
+        std::mem::take(&mut *self.0.write().unwrap_or_else(|e| e.into_inner()))
+            .into_iter()
+            .collect()
+    }
+
+    /// (name, session) of every wallet, by name
+    fn sessions(&self) -> Vec<(String, Arc<Trader>)> {
+        let wallets = self.0.read().unwrap_or_else(|e| e.into_inner());
+        let mut sessions: Vec<_> =
+            wallets.iter().map(|(name, wallet)| (name.clone(), wallet.trader())).collect();
+        sessions.sort_by(|a, b| a.0.cmp(&b.0));
+        sessions
+    }
+}
+
+/// Wallet name argument: 1-63 printable ASCII characters
+fn name_arg(name: *const c_char) -> Option<String> {
+    if name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(name) }.to_str().ok()?;
+    let valid = (1..=MAX_WALLET_NAME).contains(&name.len())
+        && name.bytes().all(|b| b.is_ascii_graphic());
+    valid.then(|| name.to_string())
+}
+
+/// Run a global entry point on the wallet named `wallet`
+/// `fallback` is the entry point's not-initialized value, for an unknown name
+pub(crate) fn on_wallet<T>(wallet: *const c_char, fallback: T, call: impl FnOnce() -> T) -> T {
+    let executor = name_arg(wallet)
+        .and_then(|name| get_executor().and_then(|executor| executor.wallets.get(&name)));
+    match executor {
+        Some(executor) => handles::on_executor(executor, call),
+        None => fallback,
+    }
+}
+
+/// Connect `funder_wallet` with `private_key` as the wallet `name` (1-63
+/// printable ASCII characters) beside the global executor
+/// POLYMARKET_ERR_INVALID_ARGUMENT if the name is malformed or taken, or
+/// MAX_WALLETS are registered; connect errors as polymarket_init()
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_add_wallet(
+    name: *const c_char,
+    private_key: *const c_char,
+    funder_wallet: *const c_char,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        let Some(name) = name_arg(name) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        };
+        if executor.wallets.contains(&name) {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        if private_key.is_null() {
+            return POLYMARKET_ERR_INVALID_PK;
+        }
+        let pk = match unsafe { CStr::from_ptr(private_key) }.to_str() {
+            Ok(s) => Zeroizing::new(s.to_string()),
+            Err(_) => return POLYMARKET_ERR_INVALID_PK,
+        };
+        if funder_wallet.is_null() {
+            return POLYMARKET_ERR_AUTH_FAILED;
+        }
+        let funder = match unsafe { CStr::from_ptr(funder_wallet) }.to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => return POLYMARKET_ERR_AUTH_FAILED,
+        };
+
+        let parent = executor.trader();
+        let options = parent.options().clone();
+        let connecting = funder.clone();
+        let connected = executor.run(move |_| async move {
+            Ok(Trader::connect_with(&pk, &connecting, options).await)
+        });
+        let trader = match connected {
+            Ok(Ok(trader)) => trader,
+            Ok(Err(e)) => return connect_error_code(e),
+            Err(e) => {
+                error!("[WALLET] {} | connect failed | {}", name, e);
+                return POLYMARKET_ERR_AUTH_FAILED;
+            }
+        };
+        if parent.is_read_only() {
+            trader.set_read_only();
+        }
+        if parent.is_dry_run() {
+            trader.set_dry_run();
+        }
+
+        let wallet = new_executor(executor.runtime, trader);
+        match executor.wallets.insert(name.clone(), wallet) {
+            Ok(()) => {
+                info!("[WALLET] added | name={} | funder={}", name, funder);
+                POLYMARKET_OK
+            }
+            Err(wallet) => {
+                // Taken while connecting, or the registry filled up
+                close(wallet, 0, 0);
+                POLYMARKET_ERR_INVALID_ARGUMENT
+            }
+        }
+    })
+}
+
+/// Shut the wallet `name` down and forget it, as polymarket_destroy() does
+/// for a handle: running calls get `drain_timeout_ms`, then
+/// POLYMARKET_SHUTDOWN_* `flags` apply. POLYMARKET_ERR_INVALID_ARGUMENT for
+/// an unknown name or invalid flags
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_remove_wallet(
+    name: *const c_char,
+    flags: u32,
+    drain_timeout_ms: u32,
+) -> i32 {
+    guard::catch(POLYMARKET_ERR_PANIC, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED,
+        };
+        if flags & !POLYMARKET_SHUTDOWN_CANCEL_ALL != 0 {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        }
+        let Some(name) = name_arg(name) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        };
+        let Some(wallet) = executor.wallets.remove(&name) else {
+            return POLYMARKET_ERR_INVALID_ARGUMENT;
+        };
+        let code = close(wallet, flags, drain_timeout_ms);
+        info!("[WALLET] removed | name={} | code={}", name, code);
+        code
+    })
+}
+
+/// Registered wallets as JSON [{"name", "funder", "read_only", "dry_run"}],
+/// by name; returns its size (polymarket_last_response()) or a negative
+/// error code
+#[unsafe(no_mangle)]
+pub extern "C" fn polymarket_list_wallets() -> i64 {
+    guard::catch(POLYMARKET_ERR_PANIC as i64, || {
+        let executor = match get_executor() {
+            Some(e) => e,
+            None => return POLYMARKET_ERR_NOT_INITIALIZED as i64,
+        };
+        let wallets: Vec<_> = executor
+            .wallets
+            .sessions()
+            .into_iter()
+            .map(|(name, trader)| {
+                serde_json::json!({
+                    "name": name,
+                    "funder": trader.funder().to_string(),
+                    "read_only": trader.is_read_only(),
+                    "dry_run": trader.is_dry_run(),
+                })
+            })
+            .collect();
+        out_buf::set_last_response(serde_json::Value::from(wallets).to_string()) as i64
+    })
+}
@@ -11,6 +11,10 @@
 #include "flox-connectors/net/ix_websocket_client.h"
 #include "flox-connectors/util/safe_parse.h"
 
+#ifdef FLOX_POLYMARKET_ORDER_EXECUTOR_ENABLED
+#include "flox-connectors/polymarket/polymarket_ffi.h"
+#endif
+
 #include <flox/log/log.h>
 #include <flox/util/base/hash.h>
 
@@ -229,6 +233,10 @@ void PolymarketExchangeConnector::handleMessage(std::string_view payload)
     {
       processBookSnapshot(std::move(obj), recvNs);
     }
+    else if (eventType == "tick_size_change")
+    {
+      processTickSizeChange(std::move(obj));
+    }
     else if (eventType == "last_trade_price" || eventType == "trade")
     {
       auto assetIdField = obj["asset_id"];
@@ -307,6 +315,47 @@ void PolymarketExchangeConnector::sendSubscribe(const std::vector<std::string>&
   }
 }
 
+void PolymarketExchangeConnector::processTickSizeChange(simdjson::ondemand::object obj)
+{
+  auto assetIdField = obj["asset_id"];
+  if (assetIdField.error())
+  {
+    return;
+  }
+  std::string tokenId(assetIdField.get_string().value());
+
+  std::optional<double> oldTick;
+  std::optional<double> newTick;
+  if (auto f = obj["old_tick_size"]; !f.error())
+  {
+    oldTick = parseStringOrDouble(f.value());
+  }
+  if (auto f = obj["new_tick_size"]; !f.error())
+  {
+    newTick = parseStringOrDouble(f.value());
+  }
+  if (!newTick)
+  {
+    return;
+  }
+
+  if (_logger)
+  {
+    _logger->warn("[Polymarket] Tick size change for " + tokenId + ": " +
+                  std::to_string(oldTick.value_or(0.0)) + " -> " + std::to_string(*newTick));
+  }
+
+#ifdef FLOX_POLYMARKET_ORDER_EXECUTOR_ENABLED
+  // Keep the executor's price check in sync; harmless if it isn't initialized
+  polymarket_update_tick_size(tokenId.c_str(), *newTick);
+#endif
+
+  if (_onTickSizeChange)
+  {
+    _onTickSizeChange(resolveSymbolId(tokenId), tokenId, oldTick.value_or(0.0), *newTick);
+  }
+}
+
 void PolymarketExchangeConnector::processBookSnapshot(simdjson::ondemand::object obj,
                                                       uint64_t recvNs)
 {
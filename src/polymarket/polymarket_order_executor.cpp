@@ -13,6 +13,23 @@
 namespace flox
 {
 
+namespace
+{
+
+/// Overwrite a copy of a secret before it is released
+/// Volatile writes, so the compiler can't drop them as dead stores
+void scrubSecret(std::string& secret)
+{
+  volatile char* p = secret.data();
+  for (std::size_t i = 0; i < secret.size(); ++i)
+  {
+    p[i] = '\0';
+  }
+  secret.clear();
+}
+
+}  // namespace
+
 const char* PolymarketOrderResult::errorMessage() const
 {
   switch (errorCode)
@@ -35,6 +52,46 @@ const char* PolymarketOrderResult::errorMessage() const
       return "Order size below minimum ($1)";
     case POLYMARKET_ERR_MIN_SHARES:
       return "Shares below market minimum (call prefetch first)";
+    case POLYMARKET_ERR_INSUFFICIENT_BALANCE:
+      return "Insufficient USDC balance (cached balance minus buffer)";
+    case POLYMARKET_ERR_INVALID_CONFIG:
+      return "Invalid configuration";
+    case POLYMARKET_ERR_RISK_LIMIT:
+      return "Order rejected by risk limits";
+    case POLYMARKET_ERR_INVALID_ARGUMENT:
+      return "Invalid argument";
+    case POLYMARKET_ERR_ABORTED:
+      return "Aborted locally, venue state unknown";
+    case POLYMARKET_ERR_PANIC:
+      return "Internal panic (see polymarket_last_panic)";
+    case POLYMARKET_ERR_READ_ONLY:
+      return "Blocked by read-only mode";
+    case POLYMARKET_ERR_TICK_SIZE:
+      return "Price not on the market's tick size";
+    case POLYMARKET_ERR_LIQUIDITY:
+      return "Not enough liquidity within the price cap";
+    case POLYMARKET_ERR_VENUE_UNAVAILABLE:
+      return "Venue unavailable, orders paused";
+    case POLYMARKET_ERR_REJECTED:
+      return "Rejected by the venue";
+    case POLYMARKET_ERR_NETWORK:
+      return "Network error or timeout, venue state unknown";
+    case POLYMARKET_ERR_RATE_LIMITED:
+      return "Rate limited by the venue or the local budget";
+    case POLYMARKET_ERR_VENUE_ERROR:
+      return "Venue server error";
+    case POLYMARKET_ERR_INVALID_ORDER:
+      return "Invalid amount, price or expiry";
+    case POLYMARKET_ERR_SLIPPAGE:
+      return "Expected slippage over the cap";
+    case POLYMARKET_ERR_DUPLICATE_ORDER:
+      return "Client order id already in use";
+    case POLYMARKET_ERR_USDC_ALLOWANCE:
+      return "USDC allowance not set for the exchange";
+    case POLYMARKET_ERR_TOKEN_APPROVAL:
+      return "Outcome token approval not set for the exchange";
+    case POLYMARKET_ERR_HALTED:
+      return "Trading halted";
     default:
       return "Unknown error";
   }
@@ -54,6 +111,7 @@ PolymarketOrderExecutor::~PolymarketOrderExecutor()
   {
     polymarket_shutdown();
   }
+  scrubSecret(_privateKey);
 }
 
 bool PolymarketOrderExecutor::init()
@@ -73,6 +131,8 @@ bool PolymarketOrderExecutor::init()
     return false;
   }
 
+  // Only the library signer needs the key from here on
+  scrubSecret(_privateKey);
   _initialized = true;
   if (_logger)
   {
@@ -163,6 +223,60 @@ PolymarketOrderResult PolymarketOrderExecutor::sell(const std::string& tokenId,
   return result;
 }
 
+PolymarketOrderResult PolymarketOrderExecutor::buyFok(const std::string& tokenId, Volume usdcAmount)
+{
+  PolymarketOrderResult result;
+
+  if (!_initialized)
+  {
+    result.errorCode = POLYMARKET_ERR_NOT_INITIALIZED;
+    return result;
+  }
+
+  // Convert Volume (8 decimals) to double for FFI
+  double usdc = static_cast<double>(usdcAmount.raw()) / static_cast<double>(FLOX_SCALE);
+
+  ::PolymarketOrderResult ffiResult = polymarket_market_buy_fok(tokenId.c_str(), usdc);
+
+  result.success = ffiResult.success;
+  result.latencyMs = ffiResult.latency_ms;
+  result.errorCode = ffiResult.error_code;
+  result.orderId = std::string(ffiResult.order_id);
+
+  // Convert raw i64 (6 decimals) to flox types (8 decimals)
+  result.filledQty = Quantity::fromRaw(ffiResult.filled_qty_raw * SCALE_FACTOR);
+  result.avgPrice = Price::fromRaw(ffiResult.avg_price_raw * SCALE_FACTOR);
+
+  return result;
+}
+
+PolymarketOrderResult PolymarketOrderExecutor::sellFok(const std::string& tokenId, Quantity size)
+{
+  PolymarketOrderResult result;
+
+  if (!_initialized)
+  {
+    result.errorCode = POLYMARKET_ERR_NOT_INITIALIZED;
+    return result;
+  }
+
+  // Convert Quantity (8 decimals) to double for FFI
+  double shares = static_cast<double>(size.raw()) / static_cast<double>(FLOX_SCALE);
+
+  ::PolymarketOrderResult ffiResult = polymarket_market_sell_fok(tokenId.c_str(), shares);
+
+  result.success = ffiResult.success;
+  result.latencyMs = ffiResult.latency_ms;
+  result.errorCode = ffiResult.error_code;
+  result.orderId = std::string(ffiResult.order_id);
+
+  // Convert raw i64 (6 decimals) to flox types (8 decimals)
+  result.filledQty = Quantity::fromRaw(ffiResult.filled_qty_raw * SCALE_FACTOR);
+  result.avgPrice = Price::fromRaw(ffiResult.avg_price_raw * SCALE_FACTOR);
+
+  return result;
+}
+
 PolymarketOrderResult PolymarketOrderExecutor::limitBuy(const std::string& tokenId, Price price,
                                                         Volume usdcAmount)
 {
@@ -221,6 +335,78 @@ PolymarketOrderResult PolymarketOrderExecutor::limitSell(const std::string& toke
   return result;
 }
 
+PolymarketOrderResult PolymarketOrderExecutor::limitBuyGtd(const std::string& tokenId, Price price,
+                                                           Volume usdcAmount,
+                                                           std::chrono::seconds expiresIn)
+{
+  PolymarketOrderResult result;
+
+  if (!_initialized)
+  {
+    result.errorCode = POLYMARKET_ERR_NOT_INITIALIZED;
+    return result;
+  }
+  if (expiresIn.count() < 1 || expiresIn.count() > UINT32_MAX)
+  {
+    result.errorCode = POLYMARKET_ERR_INVALID_ARGUMENT;
+    return result;
+  }
+
+  // Convert Volume and Price (8 decimals) to double for FFI
+  double usdc = static_cast<double>(usdcAmount.raw()) / static_cast<double>(FLOX_SCALE);
+  double priceDouble = static_cast<double>(price.raw()) / static_cast<double>(FLOX_SCALE);
+
+  ::PolymarketOrderResult ffiResult = polymarket_limit_buy_gtd(
+      tokenId.c_str(), priceDouble, usdc, static_cast<uint32_t>(expiresIn.count()));
+
+  result.success = ffiResult.success;
+  result.latencyMs = ffiResult.latency_ms;
+  result.errorCode = ffiResult.error_code;
+  result.orderId = std::string(ffiResult.order_id);
+
+  // Convert raw i64 (6 decimals) to flox types (8 decimals)
+  result.filledQty = Quantity::fromRaw(ffiResult.filled_qty_raw * SCALE_FACTOR);
+  result.avgPrice = Price::fromRaw(ffiResult.avg_price_raw * SCALE_FACTOR);
+
+  return result;
+}
+
+PolymarketOrderResult PolymarketOrderExecutor::limitSellGtd(const std::string& tokenId, Price price,
+                                                            Quantity size,
+                                                            std::chrono::seconds expiresIn)
+{
+  PolymarketOrderResult result;
+
+  if (!_initialized)
+  {
+    result.errorCode = POLYMARKET_ERR_NOT_INITIALIZED;
+    return result;
+  }
+  if (expiresIn.count() < 1 || expiresIn.count() > UINT32_MAX)
+  {
+    result.errorCode = POLYMARKET_ERR_INVALID_ARGUMENT;
+    return result;
+  }
+
+  // Convert Quantity and Price (8 decimals) to double for FFI
+  double shares = static_cast<double>(size.raw()) / static_cast<double>(FLOX_SCALE);
+  double priceDouble = static_cast<double>(price.raw()) / static_cast<double>(FLOX_SCALE);
+
+  ::PolymarketOrderResult ffiResult = polymarket_limit_sell_gtd(
+      tokenId.c_str(), priceDouble, shares, static_cast<uint32_t>(expiresIn.count()));
+
+  result.success = ffiResult.success;
+  result.latencyMs = ffiResult.latency_ms;
+  result.errorCode = ffiResult.error_code;
+  result.orderId = std::string(ffiResult.order_id);
+
+  // Convert raw i64 (6 decimals) to flox types (8 decimals)
+  result.filledQty = Quantity::fromRaw(ffiResult.filled_qty_raw * SCALE_FACTOR);
+  result.avgPrice = Price::fromRaw(ffiResult.avg_price_raw * SCALE_FACTOR);
+
+  return result;
+}
+
 bool PolymarketOrderExecutor::cancel(const std::string& orderId)
 {
   if (!_initialized)
@@ -258,6 +444,47 @@ Volume PolymarketOrderExecutor::getBalance()
   return Volume::fromRaw(raw * SCALE_FACTOR);
 }
 
+void PolymarketOrderExecutor::setBalanceBuffer(Volume buffer)
+{
+  if (!_initialized)
+  {
+    return;
+  }
+
+  double usdc = static_cast<double>(buffer.raw()) / static_cast<double>(FLOX_SCALE);
+  polymarket_set_balance_buffer(usdc);
+}
+
+bool PolymarketOrderExecutor::loadRiskConfig(const std::string& path)
+{
+  if (!_initialized)
+  {
+    return false;
+  }
+
+  int32_t result = polymarket_load_risk_config(path.c_str());
+  if (result != POLYMARKET_OK && _logger)
+  {
+    _logger->error("[PolymarketOrderExecutor] Risk config rejected: " + path);
+  }
+  return result == POLYMARKET_OK;
+}
+
+bool PolymarketOrderExecutor::setRiskConfig(const std::string& json)
+{
+  if (!_initialized)
+  {
+    return false;
+  }
+
+  int32_t result = polymarket_set_risk_config(json.c_str());
+  if (result != POLYMARKET_OK && _logger)
+  {
+    _logger->error("[PolymarketOrderExecutor] Risk config rejected");
+  }
+  return result == POLYMARKET_OK;
+}
+
 Quantity PolymarketOrderExecutor::getTokenBalance(const std::string& tokenId)
 {
   if (!_initialized)